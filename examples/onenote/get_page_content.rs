@@ -34,6 +34,20 @@ pub async fn get_page_html_content() {
     println!("{html_string:#?}");
 }
 
+pub async fn get_page_html() -> GraphResult<()> {
+    let client = GraphClient::new(ACCESS_TOKEN);
+
+    let html = client
+        .user(USER_ID)
+        .onenote()
+        .page(PAGE_ID)
+        .get_page_html()
+        .await?;
+
+    println!("{html}");
+    Ok(())
+}
+
 pub async fn download_page_as_html() {
     let client = GraphClient::new(ACCESS_TOKEN);
 
@@ -1,5 +1,5 @@
 use graph_rs_sdk::header::{HeaderValue, CONTENT_TYPE};
-use graph_rs_sdk::http::FileConfig;
+use graph_rs_sdk::http::{FileConfig, MultipartFormData};
 use graph_rs_sdk::*;
 use std::fs::OpenOptions;
 use std::io::Read;
@@ -11,8 +11,10 @@ static USER_ID: &str = "USER_ID";
 
 static FILE_PATH: &str = "./FILE.html";
 
-// There is currently no way to upload the binary contents of a page via multipart as shown
-// here: https://learn.microsoft.com/en-us/graph/api/section-post-pages?view=graph-rest-1.0
+// Section id of the section to create the page in.
+static SECTION_ID: &str = "SECTION_ID";
+
+static IMAGE_PATH: &str = "./IMAGE.png";
 
 pub async fn upload_page_content() {
     let client = GraphClient::new(ACCESS_TOKEN);
@@ -52,3 +54,35 @@ pub async fn upload_page_content_using_file() -> GraphResult<()> {
 
     Ok(())
 }
+
+// Create a page with an image using a multipart request as shown
+// here: https://learn.microsoft.com/en-us/graph/api/section-post-pages?view=graph-rest-1.0
+pub async fn upload_page_with_image() -> GraphResult<()> {
+    let client = GraphClient::new(ACCESS_TOKEN);
+
+    let presentation = r#"<!DOCTYPE html>
+<html>
+  <head>
+    <title>A page with an image</title>
+  </head>
+  <body>
+    <img src="name:image-block" alt="an image on the page" />
+  </body>
+</html>"#;
+
+    let form = MultipartFormData::new()
+        .part("Presentation", "text/html", presentation)
+        .part("image-block", "image/png", std::fs::read(IMAGE_PATH)?);
+
+    let response = client
+        .user(USER_ID)
+        .onenote()
+        .section(SECTION_ID)
+        .pages()
+        .create_page_multipart(form)
+        .send()
+        .await?;
+
+    println!("{response:#?}");
+    Ok(())
+}
@@ -1,6 +1,8 @@
 mod pkce;
+mod secret_string;
 
 pub use pkce::*;
+pub use secret_string::*;
 
 use base64::engine::general_purpose::URL_SAFE_NO_PAD;
 use base64::Engine;
@@ -14,3 +16,33 @@ pub fn secure_random_32() -> String {
 
     URL_SAFE_NO_PAD.encode(buf)
 }
+
+/// Compares two values in constant time for equal length inputs.
+///
+/// Use when comparing secrets such as the state of an authorization request, the nonce
+/// of an id token, or the client state of a change notification so that the comparison
+/// does not leak timing information. Only the length of the values can be learned.
+///
+/// # Example
+/// ```rust
+/// use graph_core::crypto::ct_eq;
+///
+/// assert!(ct_eq("12345", "12345"));
+/// assert!(!ct_eq("12345", "54321"));
+/// ```
+pub fn ct_eq(a: impl AsRef<[u8]>, b: impl AsRef<[u8]>) -> bool {
+    let (a, b) = (a.as_ref(), b.as_ref());
+    if a.len() != b.len() {
+        return false;
+    }
+    let difference = a.iter().zip(b.iter()).fold(0, |acc, (x, y)| acc | (x ^ y));
+    std::hint::black_box(difference) == 0
+}
+
+/// The base64url encoded left-most half of the SHA-256 hash of a value. Used to
+/// validate the c_hash and at_hash claims of id tokens signed using RS256.
+pub fn left_half_sha256(value: &str) -> String {
+    let digest = ring::digest::digest(&ring::digest::SHA256, value.as_bytes());
+    let bytes = digest.as_ref();
+    URL_SAFE_NO_PAD.encode(&bytes[..bytes.len() / 2])
+}
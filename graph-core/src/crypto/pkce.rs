@@ -3,6 +3,8 @@ use base64::Engine;
 use graph_error::{IdentityResult, AF};
 use ring::rand::SecureRandom;
 
+use crate::crypto::SecretString;
+
 /*
 pub(crate) fn sha256_secure_string() -> IdentityResult<(String, String)> {
     let mut buf = [0; 32];
@@ -86,7 +88,7 @@ pub struct ProofKeyCodeExchange {
     /// The code verifier is not included in the authorization URL.
     /// You can set the code verifier here and then use the From trait
     /// for [AuthorizationCodeCredential] which does use the code verifier.
-    pub code_verifier: String,
+    pub code_verifier: SecretString,
     /// Used to secure authorization code grants by using Proof Key for Code Exchange (PKCE).
     /// Required if code_challenge_method is included. For more information, see the PKCE RFC.
     /// This parameter is now recommended for all application types, both public and confidential
@@ -114,7 +116,7 @@ impl ProofKeyCodeExchange {
             return Err(AF::msg_err("code_challenge", "Must be 43-octet sequence"));
         }
         Ok(ProofKeyCodeExchange {
-            code_verifier: code_verifier.as_ref().to_owned(),
+            code_verifier: SecretString::new(code_verifier.as_ref()),
             code_challenge,
             code_challenge_method: code_challenge_method.as_ref().to_owned(),
         })
@@ -135,8 +137,8 @@ mod test {
     fn validate_pkce_challenge_and_verifier() {
         let pkce = ProofKeyCodeExchange::oneshot().unwrap();
         let mut context = ring::digest::Context::new(&ring::digest::SHA256);
-        context.update(pkce.code_verifier.as_bytes());
+        context.update(pkce.code_verifier.expose_secret().as_bytes());
         let verifier = URL_SAFE_NO_PAD.encode(context.finish().as_ref());
-        assert_eq!(verifier, pkce.code_challenge);
+        assert!(crate::crypto::ct_eq(verifier, &pkce.code_challenge));
     }
 }
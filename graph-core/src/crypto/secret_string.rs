@@ -0,0 +1,112 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use zeroize::Zeroizing;
+
+use crate::crypto::ct_eq;
+
+/// A string holding a secret such as a client secret, password, access token, refresh
+/// token, or PKCE code verifier. The memory of the string is zeroed when it is dropped
+/// and the value is redacted in debug output.
+///
+/// The value is only available through [SecretString::expose_secret] so that every
+/// place a secret is read is explicit.
+#[derive(Clone, Default)]
+pub struct SecretString(Zeroizing<String>);
+
+impl SecretString {
+    pub fn new(secret: impl Into<String>) -> SecretString {
+        SecretString(Zeroizing::new(secret.into()))
+    }
+
+    /// The value of the secret.
+    pub fn expose_secret(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl PartialEq for SecretString {
+    fn eq(&self, other: &Self) -> bool {
+        ct_eq(self.expose_secret(), other.expose_secret())
+    }
+}
+
+impl Eq for SecretString {}
+
+impl PartialOrd for SecretString {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SecretString {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.expose_secret().cmp(other.expose_secret())
+    }
+}
+
+impl Hash for SecretString {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.expose_secret().hash(state);
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(value: String) -> Self {
+        SecretString::new(value)
+    }
+}
+
+impl From<&str> for SecretString {
+    fn from(value: &str) -> Self {
+        SecretString::new(value)
+    }
+}
+
+impl From<&String> for SecretString {
+    fn from(value: &String) -> Self {
+        SecretString::new(value.as_str())
+    }
+}
+
+impl Debug for SecretString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[REDACTED]")
+    }
+}
+
+impl Serialize for SecretString {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.expose_secret())
+    }
+}
+
+impl<'de> Deserialize<'de> for SecretString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(SecretString::new)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn secret_is_redacted() {
+        let secret = SecretString::from("client-secret");
+        assert_eq!("[REDACTED]", format!("{secret:?}"));
+        assert_eq!("client-secret", secret.expose_secret());
+        assert_eq!(SecretString::from("client-secret"), secret);
+        assert_ne!(SecretString::from("client-secreT"), secret);
+    }
+
+    #[test]
+    fn serialized_as_string() {
+        let secret = SecretString::from("refresh-token");
+        let json = serde_json::to_string(&secret).unwrap();
+        assert_eq!("\"refresh-token\"", json);
+        assert_eq!(secret, serde_json::from_str::<SecretString>(&json).unwrap());
+    }
+}
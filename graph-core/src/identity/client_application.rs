@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 use dyn_clone::DynClone;
-use graph_error::AuthExecutionResult;
+use graph_error::{AuthExecutionError, AuthExecutionResult, AF};
 
 #[derive(Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub enum ForceTokenRefresh {
@@ -26,6 +26,43 @@ pub trait ClientApplication: DynClone + Send + Sync {
     async fn get_token_silent_async(&mut self) -> AuthExecutionResult<String>;
 
     fn with_force_token_refresh(&mut self, force_token_refresh: ForceTokenRefresh);
+
+    /// Get a new token by signing in the user instead of using the token cache.
+    /// Client applications that cannot sign in a user return an error.
+    fn get_token_interactive(&mut self) -> AuthExecutionResult<String> {
+        Err(interactive_not_supported())
+    }
+
+    /// Get a new token by signing in the user instead of using the token cache.
+    /// Client applications that cannot sign in a user return an error.
+    async fn get_token_interactive_async(&mut self) -> AuthExecutionResult<String> {
+        Err(interactive_not_supported())
+    }
+
+    /// The scopes requested by the client application.
+    fn scopes(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// The authority uri, such as `https://login.microsoftonline.com/common`, that
+    /// tokens are requested from.
+    fn authority_uri(&self) -> Option<String> {
+        None
+    }
+
+    /// The base url of the Microsoft Graph endpoint of the cloud that tokens are
+    /// requested for, such as `https://graph.microsoft.us` for US Government GCC High.
+    /// Used by the client when no base url is set.
+    fn graph_base_url(&self) -> Option<String> {
+        None
+    }
+}
+
+fn interactive_not_supported() -> AuthExecutionError {
+    AuthExecutionError::Authorization(AF::msg_err(
+        "get_token_interactive",
+        "interactive authentication is not supported by this client application",
+    ))
 }
 
 #[async_trait]
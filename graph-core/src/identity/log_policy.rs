@@ -0,0 +1,105 @@
+use std::fmt::{Debug, DebugStruct};
+
+const REDACTED: &str = "[REDACTED]";
+
+/// How personally identifiable information and secrets such as access tokens,
+/// refresh tokens, id tokens, and client ids are written to [Debug] output and
+/// log events.
+///
+/// The default is [LogPolicy::Redacted].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum LogPolicy {
+    /// Sensitive values are left out of debug output and log events.
+    None,
+    /// Sensitive values are replaced with `[REDACTED]`.
+    #[default]
+    Redacted,
+    /// Sensitive values are logged. Only use this policy when debugging because
+    /// anyone with access to the logs can use the logged tokens.
+    Full,
+}
+
+impl LogPolicy {
+    /// [LogPolicy::Full] when true and [LogPolicy::Redacted] otherwise.
+    pub fn from_pii_logging(log_pii: bool) -> LogPolicy {
+        if log_pii {
+            LogPolicy::Full
+        } else {
+            LogPolicy::Redacted
+        }
+    }
+
+    pub fn is_full(&self) -> bool {
+        matches!(self, LogPolicy::Full)
+    }
+
+    /// Add a sensitive field to a [DebugStruct] according to the policy.
+    pub fn debug_field(&self, debug: &mut DebugStruct<'_, '_>, name: &str, value: &dyn Debug) {
+        match self {
+            LogPolicy::None => {}
+            LogPolicy::Redacted => {
+                debug.field(name, &REDACTED);
+            }
+            LogPolicy::Full => {
+                debug.field(name, value);
+            }
+        }
+    }
+
+    /// A sensitive value as it should appear in a log event.
+    pub fn redact<'a>(&self, value: &'a str) -> &'a str {
+        match self {
+            LogPolicy::None => "",
+            LogPolicy::Redacted => REDACTED,
+            LogPolicy::Full => value,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Secret {
+        log_policy: LogPolicy,
+    }
+
+    impl Debug for Secret {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let mut debug = f.debug_struct("Secret");
+            debug.field("name", &"name");
+            self.log_policy.debug_field(&mut debug, "value", &"value");
+            debug.finish()
+        }
+    }
+
+    #[test]
+    fn debug_field_follows_policy() {
+        let none = Secret {
+            log_policy: LogPolicy::None,
+        };
+        let redacted = Secret {
+            log_policy: LogPolicy::Redacted,
+        };
+        let full = Secret {
+            log_policy: LogPolicy::Full,
+        };
+
+        assert_eq!(r#"Secret { name: "name" }"#, format!("{none:?}"));
+        assert_eq!(
+            r#"Secret { name: "name", value: "[REDACTED]" }"#,
+            format!("{redacted:?}")
+        );
+        assert_eq!(
+            r#"Secret { name: "name", value: "value" }"#,
+            format!("{full:?}")
+        );
+    }
+
+    #[test]
+    fn redact_follows_policy() {
+        assert_eq!("", LogPolicy::None.redact("token"));
+        assert_eq!("[REDACTED]", LogPolicy::Redacted.redact("token"));
+        assert_eq!("token", LogPolicy::Full.redact("token"));
+    }
+}
@@ -1,7 +1,9 @@
 mod client_application;
 mod jwk;
 mod jwks;
+mod log_policy;
 
 pub use client_application::*;
 pub use jwk::*;
 pub use jwks::*;
+pub use log_policy::*;
@@ -1,4 +1,7 @@
-use crate::{ErrorMessage, IdentityResult, WebViewDeviceCodeError};
+use crate::{
+    ErrorMessage, IdentityResult, MsalErrorClassification, MsalErrorResponse,
+    WebViewDeviceCodeError,
+};
 use tokio::sync::mpsc::error::SendTimeoutError;
 use url::ParseError;
 
@@ -120,6 +123,24 @@ impl AuthExecutionError {
             response,
         }
     }
+
+    /// The error response of the token endpoint when a token request failed.
+    pub fn msal_error_response(&self) -> Option<MsalErrorResponse> {
+        match self {
+            AuthExecutionError::SilentTokenAuth { response, .. } => {
+                MsalErrorResponse::from_json(response.body().as_ref().ok()?)
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether the failed token request can be retried, requires interaction
+    /// from the user, or will fail again. Returns None if the error is not an
+    /// error response of the token endpoint.
+    pub fn classification(&self) -> Option<MsalErrorClassification> {
+        self.msal_error_response()
+            .map(|error_response| error_response.classification())
+    }
 }
 
 impl From<serde_json::error::Error> for AuthExecutionError {
@@ -1,6 +1,6 @@
 use crate::download::AsyncDownloadError;
 use crate::internal::GraphRsError;
-use crate::{AuthExecutionError, AuthorizationFailure, ErrorMessage};
+use crate::{AuthExecutionError, AuthorizationFailure, ErrorMessage, IntegrityError};
 use reqwest::header::HeaderMap;
 use std::cell::BorrowMutError;
 use std::error::Error;
@@ -55,6 +55,11 @@ pub enum GraphFailure {
     #[error("{0:#?}")]
     AsyncDownloadError(#[from] AsyncDownloadError),
 
+    /// The content of an uploaded or downloaded file does not match the hashes of
+    /// the drive item.
+    #[error("{0}")]
+    IntegrityError(#[from] IntegrityError),
+
     #[error(
         "Error building or processing request prior to being sent:\n{0:#?}",
         error
@@ -90,6 +95,30 @@ pub enum GraphFailure {
 
     #[error("{0:#?}")]
     JsonWebToken(#[from] jsonwebtoken::errors::Error),
+
+    /// The `If-Match` or `If-None-Match` header of the request did not match the
+    /// current ETag of the resource (412 Precondition Failed).
+    #[error("Precondition Failed:\n{0:#?}")]
+    PreconditionFailed(ErrorMessage),
+
+    /// The access token has none of the permissions required by the request. Returned
+    /// before sending the request when permission checks are enabled.
+    #[error(
+        "Missing permission: the access token has none of the permissions {required:?} required by the request, granted permissions: {granted:?}"
+    )]
+    MissingPermission {
+        url: Option<reqwest::Url>,
+        required: Vec<String>,
+        granted: Vec<String>,
+    },
+
+    /// The endpoint, such as `/me`, only supports delegated permissions and the access
+    /// token is an application token. Returned before sending the request when permission
+    /// checks are enabled.
+    #[error(
+        "Delegated-only endpoint: the request requires a delegated access token for a signed-in user but the access token is an application token"
+    )]
+    DelegatedOnlyEndpoint { url: Option<reqwest::Url> },
 }
 
 impl GraphFailure {
@@ -109,6 +138,20 @@ impl GraphFailure {
     pub fn invalid(msg: &str) -> Self {
         GraphFailure::internal(GraphRsError::InvalidOrMissing { msg: msg.into() })
     }
+
+    /// Error for a Microsoft Graph error response. Responses with status
+    /// 412 Precondition Failed are returned as [GraphFailure::PreconditionFailed].
+    pub fn from_error_message(status: http::StatusCode, error_message: ErrorMessage) -> Self {
+        if status == http::StatusCode::PRECONDITION_FAILED {
+            GraphFailure::PreconditionFailed(error_message)
+        } else {
+            GraphFailure::ErrorMessage(error_message)
+        }
+    }
+
+    pub fn is_precondition_failed(&self) -> bool {
+        matches!(self, GraphFailure::PreconditionFailed(_))
+    }
 }
 
 impl Default for GraphFailure {
@@ -0,0 +1,14 @@
+/// The content of a file does not match the hashes reported by the drive item.
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum IntegrityError {
+    #[error("{algorithm} of the content does not match: expected {expected}, computed {computed}")]
+    Mismatch {
+        /// The hash that did not match such as `quickXorHash`, `sha256Hash`, or `size`.
+        algorithm: String,
+        expected: String,
+        computed: String,
+    },
+
+    #[error("the drive item does not have a quickXorHash or sha256Hash to verify the content")]
+    MissingHash,
+}
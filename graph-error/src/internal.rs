@@ -15,4 +15,19 @@ pub enum GraphRsError {
     InvalidOrMissing { msg: String },
     #[error("Invalid file extension. Requires {requires} but found {found}")]
     InvalidFileExtension { requires: String, found: String },
+    #[error(
+        "Attachment size {size} exceeds the maximum of {max} bytes. \
+         Use an attachment upload session for large attachments"
+    )]
+    AttachmentTooLarge { size: usize, max: usize },
+    #[error("Long running operation ended with status {status}: {message}")]
+    AsyncOperationFailed { status: String, message: String },
+    #[error("Long running operation did not complete within {timeout_secs} seconds")]
+    AsyncOperationTimeout { timeout_secs: u64 },
+    #[error("Long running operation was cancelled before it completed")]
+    AsyncOperationCancelled,
+    #[error("Change notification clientState does not match for subscription: {subscription_id}")]
+    InvalidClientState { subscription_id: String },
+    #[error("Change notification dataSignature does not match the encrypted resource data")]
+    InvalidDataSignature,
 }
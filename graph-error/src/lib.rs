@@ -7,14 +7,18 @@ mod authorization_failure;
 pub mod download;
 mod error;
 mod graph_failure;
+mod integrity_error;
 mod internal;
 pub mod io_error;
+mod msal_error;
 mod webview_error;
 
 pub use authorization_failure::*;
 pub use error::*;
 pub use graph_failure::*;
+pub use integrity_error::*;
 pub use internal::*;
+pub use msal_error::*;
 pub use webview_error::*;
 
 pub type GraphResult<T> = Result<T, GraphFailure>;
@@ -0,0 +1,164 @@
+use std::fmt::{Display, Formatter};
+
+/// AADSTS codes of errors that can be resolved by the user through an interactive request.
+const INTERACTION_REQUIRED_CODES: [i64; 11] = [
+    50058,  // Session information is not sufficient for single-sign-on.
+    50074,  // Strong authentication is required.
+    50076,  // Multi-factor authentication is required due to a configuration change.
+    50079,  // The user is required to enroll for multi-factor authentication.
+    50097,  // Device authentication is required.
+    50158,  // External security challenge was not satisfied.
+    53003,  // Access has been blocked by Conditional Access policies.
+    65001,  // The user or administrator has not consented to use the application.
+    70043,  // The refresh token has expired or is invalid due to sign-in frequency checks.
+    700082, // The refresh token has expired due to inactivity.
+    50173,  // The refresh token was revoked because the user changed their password.
+];
+
+/// AADSTS codes of errors caused by a temporary condition on the identity platform.
+///
+/// AADSTS500011, the resource principal was not found, is not retryable. It is usually
+/// caused by a wrong resource or tenant and retrying only delays the error.
+const RETRYABLE_CODES: [i64; 2] = [
+    50196, // Client request loop detected. Retry after a delay.
+    90033, // A transient error has occurred.
+];
+
+/// How the error of a token request can be resolved.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum MsalErrorClassification {
+    /// A temporary error that may succeed if the request is retried.
+    Retryable,
+    /// The user must sign in interactively, for example to complete multi-factor
+    /// authentication or consent to the application.
+    InteractionRequired,
+    /// An error caused by the request or the app configuration that will fail again
+    /// if the request is retried.
+    NonRetryable,
+}
+
+/// The error response returned by the token endpoint of the Microsoft identity platform.
+///
+/// See [error response](https://learn.microsoft.com/en-us/entra/identity-platform/v2-oauth2-auth-code-flow#error-response-1)
+/// and [AADSTS error codes](https://learn.microsoft.com/en-us/entra/identity-platform/reference-error-codes)
+///
+/// # Example
+/// ```rust
+/// use graph_error::{MsalErrorClassification, MsalErrorResponse};
+///
+/// let error: MsalErrorResponse = serde_json::from_str(r#"{
+///     "error": "invalid_grant",
+///     "error_description": "AADSTS50076: Due to a configuration change made by your administrator, or because you moved to a new location, you must use multi-factor authentication.",
+///     "error_codes": [50076],
+///     "suberror": "basic_action"
+/// }"#).unwrap();
+///
+/// assert_eq!(Some(50076), error.aadsts_code());
+/// assert_eq!(MsalErrorClassification::InteractionRequired, error.classification());
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MsalErrorResponse {
+    /// An error code string such as `invalid_grant` or `interaction_required`.
+    pub error: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_description: Option<String>,
+    /// The AADSTS error codes of the error.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub error_codes: Vec<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<String>,
+    /// Further classifies errors that require interaction such as `basic_action`,
+    /// `additional_action`, `message_only`, `consent_required`, and `user_password_expired`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suberror: Option<String>,
+    /// A claims challenge that must be included in the next authorization request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub claims: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_uri: Option<String>,
+}
+
+impl MsalErrorResponse {
+    /// Parse the error response from the json body of a token request.
+    ///
+    /// Returns None if the body is not an error response of the token endpoint.
+    pub fn from_json(value: &serde_json::Value) -> Option<MsalErrorResponse> {
+        value.get("error")?.as_str()?;
+        serde_json::from_value(value.clone()).ok()
+    }
+
+    /// The AADSTS code of the error taken from the error codes or, if the error
+    /// codes are not returned, parsed from the error description.
+    pub fn aadsts_code(&self) -> Option<i64> {
+        if let Some(code) = self.error_codes.first() {
+            return Some(*code);
+        }
+
+        let description = self.error_description.as_deref()?;
+        let index = description.find("AADSTS")? + "AADSTS".len();
+        let digits: String = description[index..]
+            .chars()
+            .take_while(|c| c.is_ascii_digit())
+            .collect();
+        digits.parse().ok()
+    }
+
+    /// The AADSTS code of the error formatted as it is in the error description
+    /// such as `AADSTS50076`.
+    pub fn aadsts(&self) -> Option<String> {
+        self.aadsts_code().map(|code| format!("AADSTS{code}"))
+    }
+
+    pub fn classification(&self) -> MsalErrorClassification {
+        let code = self.aadsts_code();
+
+        match self.error.as_str() {
+            "interaction_required" | "login_required" | "consent_required" => {
+                return MsalErrorClassification::InteractionRequired;
+            }
+            "temporarily_unavailable" | "server_error" => {
+                return MsalErrorClassification::Retryable;
+            }
+            _ => {}
+        }
+
+        // Other suberrors such as client_mismatch or bad_token are not resolved by
+        // signing in again.
+        let interaction_suberror = matches!(
+            self.suberror.as_deref(),
+            Some("basic_action" | "additional_action")
+        );
+
+        if interaction_suberror
+            || self.claims.is_some()
+            || code.is_some_and(|code| INTERACTION_REQUIRED_CODES.contains(&code))
+        {
+            MsalErrorClassification::InteractionRequired
+        } else if code.is_some_and(|code| RETRYABLE_CODES.contains(&code)) {
+            MsalErrorClassification::Retryable
+        } else {
+            MsalErrorClassification::NonRetryable
+        }
+    }
+
+    pub fn is_retryable(&self) -> bool {
+        self.classification() == MsalErrorClassification::Retryable
+    }
+
+    pub fn is_interaction_required(&self) -> bool {
+        self.classification() == MsalErrorClassification::InteractionRequired
+    }
+}
+
+impl Display for MsalErrorResponse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.error_description.as_ref() {
+            Some(description) => write!(f, "{}: {}", self.error, description),
+            None => write!(f, "{}", self.error),
+        }
+    }
+}
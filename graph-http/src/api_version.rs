@@ -0,0 +1,42 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use graph_error::GraphFailure;
+
+/// The version of the Microsoft Graph API.
+///
+/// See [Versioning, support, and breaking change policies for Microsoft Graph](https://learn.microsoft.com/en-us/graph/versioning-and-support)
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum ApiVersion {
+    #[default]
+    V1,
+    Beta,
+}
+
+impl ApiVersion {
+    /// The path segment of the version such as `v1.0`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiVersion::V1 => "v1.0",
+            ApiVersion::Beta => "beta",
+        }
+    }
+}
+
+impl Display for ApiVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for ApiVersion {
+    type Err = GraphFailure;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim_matches('/').to_lowercase().as_str() {
+            "v1.0" | "v1" => Ok(ApiVersion::V1),
+            "beta" => Ok(ApiVersion::Beta),
+            _ => Err(GraphFailure::invalid("api version")),
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use crate::blocking::blocking_client::BlockingClient;
 use crate::internal::*;
 use graph_error::{ErrorMessage, GraphFailure, GraphResult};
-use http::header::CONTENT_TYPE;
+use http::header::{CONTENT_TYPE, IF_MATCH, IF_NONE_MATCH};
 use http::{HeaderMap, HeaderName, HeaderValue};
 use serde::de::DeserializeOwned;
 use std::collections::VecDeque;
@@ -13,6 +13,7 @@ pub struct BlockingRequestHandler {
     pub(crate) request_components: RequestComponents,
     pub(crate) error: Option<GraphFailure>,
     pub(crate) body: Option<BodyRead>,
+    pub(crate) permission_check: bool,
 }
 
 impl BlockingRequestHandler {
@@ -40,6 +41,7 @@ impl BlockingRequestHandler {
             request_components,
             error,
             body,
+            permission_check: false,
         }
     }
 
@@ -103,6 +105,29 @@ impl BlockingRequestHandler {
         self
     }
 
+    /// Use a different version of the Microsoft Graph API for this request than
+    /// the version used by the client.
+    pub fn api_version(mut self, api_version: ApiVersion) -> Self {
+        self.request_components.set_api_version(api_version);
+        self
+    }
+
+    /// Use the beta endpoint of the Microsoft Graph API for this request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let client = Graph::new("ACCESS_TOKEN");
+    /// let response = client.me().get_user().beta().send()?;
+    /// ```
+    pub fn beta(self) -> Self {
+        self.api_version(ApiVersion::Beta)
+    }
+
+    /// Use the v1.0 endpoint of the Microsoft Graph API for this request.
+    pub fn v1(self) -> Self {
+        self.api_version(ApiVersion::V1)
+    }
+
     /// Insert a header for the request.
     #[inline]
     pub fn header<K: Into<HeaderName>, V: Into<HeaderValue>>(
@@ -129,6 +154,109 @@ impl BlockingRequestHandler {
         self.request_components.as_mut()
     }
 
+    /// Set the `ConsistencyLevel: eventual` header. Advanced queries on directory objects,
+    /// such as `$count`, `$search`, and `$filter` with `ne`, `not`, or `endsWith`,
+    /// are only supported with this header.
+    pub fn consistency_level_eventual(self) -> Self {
+        self.header(CONSISTENCY_LEVEL, HeaderValue::from_static("eventual"))
+    }
+
+    /// Only apply the request if the ETag of the resource matches the given ETag, such as
+    /// the `@odata.etag` of a previous response. Use this to avoid overwriting changes
+    /// made by another client. The request fails with [GraphFailure::PreconditionFailed]
+    /// if the resource has changed.
+    pub fn if_match(self, etag: impl AsRef<str>) -> Self {
+        self.etag_header(IF_MATCH, etag.as_ref())
+    }
+
+    /// Only apply the request if the ETag of the resource does not match the given ETag.
+    /// Use `*` to only create a resource if it does not already exist.
+    pub fn if_none_match(self, etag: impl AsRef<str>) -> Self {
+        self.etag_header(IF_NONE_MATCH, etag.as_ref())
+    }
+
+    fn etag_header(mut self, header_name: HeaderName, etag: &str) -> Self {
+        match HeaderValue::from_str(etag) {
+            Ok(value) => {
+                self.request_components.headers.insert(header_name, value);
+            }
+            Err(err) => {
+                if self.error.is_none() {
+                    self.error = Some(GraphFailure::from(http::Error::from(err)));
+                }
+            }
+        }
+        self
+    }
+
+    /// Set the `Prefer` header for the request. Replaces any `Prefer` header set
+    /// for the client.
+    pub fn prefer(mut self, prefer: &Prefer) -> Self {
+        match prefer.header_value() {
+            Ok(value) => {
+                self.request_components.headers.insert(PREFER, value);
+            }
+            Err(err) => {
+                if self.error.is_none() {
+                    self.error = Some(GraphFailure::from(http::Error::from(err)));
+                }
+            }
+        }
+        self
+    }
+
+    /// Set the number of items returned in each page of a collection. See
+    /// [RequestHandler::page_size].
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        if !is_delta_request(&self.request_components.url) {
+            return self.top(page_size.to_string());
+        }
+
+        let preference = format!("odata.maxpagesize={page_size}");
+        if let Err(err) = add_preference(&mut self.request_components.headers, &preference) {
+            if self.error.is_none() {
+                self.error = Some(GraphFailure::from(http::Error::from(err)));
+            }
+        }
+        self
+    }
+
+    /// The permissions of which at least one is required by the request or None if the
+    /// permissions of the endpoint are not known.
+    pub fn required_permissions(&self) -> Option<&'static RequiredPermissions> {
+        RequiredPermissions::find(
+            &self.request_components.method,
+            &self.request_components.url,
+        )
+    }
+
+    /// Check the scp and roles claims of the access token against the permissions required
+    /// by the request before sending it. The request fails with
+    /// [GraphFailure::MissingPermission] instead of a 403 Forbidden response if the token
+    /// has none of the required permissions, or [GraphFailure::DelegatedOnlyEndpoint] if
+    /// an application token is used for an endpoint such as `/me`.
+    pub fn check_permissions(mut self) -> Self {
+        self.permission_check = true;
+        self
+    }
+
+    /// A redacted representation of the request, without the access token, that
+    /// can be printed as HTTP text or a curl command for debugging. See [RequestDump].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let request_handler = client.me().get_user().select(&["displayName"]);
+    /// println!("{}", request_handler.dump_request());
+    /// ```
+    pub fn dump_request(&self) -> RequestDump {
+        RequestDump::new(
+            &self.request_components.method,
+            &self.request_components.url,
+            &self.request_components.headers,
+            self.body.as_ref(),
+        )
+    }
+
     pub fn paging(self) -> BlockingPaging {
         BlockingPaging(self)
     }
@@ -136,6 +264,13 @@ impl BlockingRequestHandler {
     #[inline]
     fn default_request_builder(&mut self) -> GraphResult<reqwest::blocking::RequestBuilder> {
         let access_token = self.inner.client_application.get_token_silent()?;
+        if self.permission_check {
+            check_permissions(
+                &self.request_components.method,
+                &self.request_components.url,
+                access_token.as_str(),
+            )?;
+        }
 
         let request_builder = self
             .inner
@@ -148,7 +283,12 @@ impl BlockingRequestHandler {
             .headers(self.request_components.headers.clone());
 
         if let Some(body) = self.body.take() {
-            if body.has_byte_buf() {
+            if let Some(content_type) = body.content_type() {
+                self.request_components
+                    .headers
+                    .entry(CONTENT_TYPE)
+                    .or_insert(content_type.clone());
+            } else if body.has_byte_buf() {
                 self.request_components
                     .headers
                     .entry(CONTENT_TYPE)
@@ -180,6 +320,40 @@ impl BlockingRequestHandler {
         let request_builder = self.build()?;
         request_builder.send().map_err(GraphFailure::from)
     }
+
+    /// Send the request and deserialize the response body into a [GraphResponse]
+    /// which keeps the status, headers, and url of the response.
+    pub fn send_json<T: DeserializeOwned>(self) -> GraphResult<GraphResponse<T>> {
+        let response = self.send()?;
+        response.into_graph_response()
+    }
+
+    /// Send a `$count` request and parse the plain text integer returned in the response
+    /// body. See [RequestHandler::send_count].
+    pub fn send_count(mut self) -> GraphResult<u64> {
+        self.request_components
+            .headers
+            .entry(CONSISTENCY_LEVEL)
+            .or_insert(HeaderValue::from_static("eventual"));
+
+        let response = self.send()?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(GraphFailure::from_error_message(
+                status,
+                response.into_graph_error_message()?,
+            ));
+        }
+
+        parse_count(response.text()?.as_str())
+    }
+
+    /// Get the number of items in a collection without fetching a page of the items.
+    /// See [RequestHandler::count_only].
+    pub fn count_only(mut self) -> GraphResult<u64> {
+        count_segment(&mut self.request_components.url);
+        self.send_count()
+    }
 }
 
 impl ODataQuery for BlockingRequestHandler {
@@ -1,5 +1,10 @@
 use crate::blocking::BlockingClient;
-use graph_core::identity::{ClientApplication, ForceTokenRefresh};
+use crate::internal::{
+    ApiVersion, Prefer, RequestLimiter, RequestLimiterService, ResponseCache, ResponseCacheService,
+    ThrottleBudget, ThrottleBudgetService, PREFER,
+};
+use graph_core::identity::{ClientApplication, ForceTokenRefresh, LogPolicy};
+use graph_error::{GraphFailure, GraphResult};
 use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, USER_AGENT};
 use reqwest::redirect::Policy;
 use reqwest::tls::Version;
@@ -8,22 +13,95 @@ use reqwest::{Request, Response};
 use std::env::VarError;
 use std::ffi::OsStr;
 use std::fmt::{Debug, Formatter};
+use std::sync::{Arc, Mutex, OnceLock};
 use std::time::Duration;
 use tower::limit::ConcurrencyLimitLayer;
 use tower::retry::RetryLayer;
 use tower::util::BoxCloneService;
-use tower::ServiceExt;
+use tower::{Service, ServiceExt};
+use url::Url;
+
+const GRAPH_BASE_URL: &str = "https://graph.microsoft.com";
 
 fn user_agent_header_from_env() -> Option<HeaderValue> {
     let header = std::option_env!("GRAPH_CLIENT_USER_AGENT")?;
     HeaderValue::from_str(header).ok()
 }
 
+pub(crate) type HttpService =
+    BoxCloneService<Request, Response, Box<dyn std::error::Error + Send + Sync>>;
+
+/// Creates the service that sends requests in place of the reqwest client.
+type TransportFactory = Arc<dyn Fn() -> HttpService + Send + Sync>;
+
 #[derive(Default, Clone)]
 struct ServiceLayersConfiguration {
     concurrency_limit: Option<usize>,
     retry: Option<usize>,
     wait_for_retry_after_headers: Option<()>,
+    transport: Option<TransportFactory>,
+    throttle_budget: ThrottleBudget,
+    throttle_threshold: Option<u64>,
+    concurrency_limit_per_host: Option<usize>,
+    request_limiter: Option<RequestLimiter>,
+    response_cache: Option<ResponseCache>,
+    #[cfg(feature = "fault-injection")]
+    fault_injection: Option<crate::fault_injection::FaultInjection>,
+    #[cfg(feature = "har-capture")]
+    har_recorder: Option<crate::har::HarRecorder>,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum TlsBackend {
+    #[cfg(feature = "native-tls")]
+    NativeTls,
+    #[cfg(any(
+        feature = "rustls-tls",
+        feature = "rustls-tls-native-roots",
+        feature = "rustls-tls-webpki-roots"
+    ))]
+    RustlsTls,
+}
+
+/// Applies the settings of a [ClientConfiguration] to a reqwest async or blocking
+/// client builder. Both builders have the same methods but no common trait.
+macro_rules! configure_client_builder {
+    ($builder:expr, $config:expr, $redirect:expr) => {{
+        let config: &ClientConfiguration = $config;
+        let mut builder = $builder
+            .referer(config.referer)
+            .connection_verbose(config.connection_verbose)
+            .https_only(config.https_only)
+            .min_tls_version(config.min_tls_version)
+            .redirect($redirect)
+            .default_headers(config.headers.clone());
+
+        if let Some(timeout) = config.timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        if let Some(connect_timeout) = config.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+
+        if let Some(proxy) = config.proxy.clone() {
+            builder = builder.proxy(proxy);
+        }
+
+        match config.tls_backend {
+            #[cfg(feature = "native-tls")]
+            Some(TlsBackend::NativeTls) => builder = builder.use_native_tls(),
+            #[cfg(any(
+                feature = "rustls-tls",
+                feature = "rustls-tls-native-roots",
+                feature = "rustls-tls-webpki-roots"
+            ))]
+            Some(TlsBackend::RustlsTls) => builder = builder.use_rustls_tls(),
+            _ => {}
+        }
+
+        builder
+    }};
 }
 
 #[derive(Clone)]
@@ -40,6 +118,10 @@ struct ClientConfiguration {
     min_tls_version: Version,
     service_layers_configuration: ServiceLayersConfiguration,
     proxy: Option<Proxy>,
+    tls_backend: Option<TlsBackend>,
+    base_url: Option<Url>,
+    api_version: ApiVersion,
+    log_policy: LogPolicy,
 }
 
 impl ClientConfiguration {
@@ -62,6 +144,10 @@ impl ClientConfiguration {
             min_tls_version: Version::TLS_1_2,
             service_layers_configuration: ServiceLayersConfiguration::default(),
             proxy: None,
+            tls_backend: None,
+            base_url: None,
+            api_version: ApiVersion::default(),
+            log_policy: LogPolicy::default(),
         }
     }
 }
@@ -76,6 +162,10 @@ impl Debug for ClientConfiguration {
             .field("https_only", &self.https_only)
             .field("min_tls_version", &self.min_tls_version)
             .field("proxy", &self.proxy)
+            .field("tls_backend", &self.tls_backend)
+            .field("base_url", &self.base_url)
+            .field("api_version", &self.api_version)
+            .field("log_policy", &self.log_policy)
             .finish()
     }
 }
@@ -109,6 +199,68 @@ impl GraphClientConfiguration {
         self
     }
 
+    /// Set the `Prefer` header for every request of the client.
+    ///
+    /// Returns an error if the preferences are not a valid header value.
+    /// Use `RequestHandler::prefer` to set the header for a single request.
+    pub fn prefer(mut self, prefer: &Prefer) -> GraphResult<GraphClientConfiguration> {
+        let value = prefer
+            .header_value()
+            .map_err(|err| GraphFailure::from(http::Error::from(err)))?;
+        self.config.headers.insert(PREFER, value);
+        Ok(self)
+    }
+
+    /// The scheme and host, with an optional path, of the Microsoft Graph service root
+    /// endpoint without the api version such as `https://graph.microsoft.us` for the
+    /// U.S. Government cloud. Defaults to `https://graph.microsoft.com`.
+    ///
+    /// Unlike `GraphClient::use_endpoint`, the host is not required to be one of the
+    /// Microsoft Graph hosts so that a proxy or national cloud host can be used.
+    pub fn base_url(mut self, base_url: Url) -> GraphClientConfiguration {
+        self.config.base_url = Some(base_url);
+        self
+    }
+
+    /// The version of the Microsoft Graph API used by requests of the client.
+    /// Defaults to [ApiVersion::V1]. Use `RequestHandler::api_version` to use a
+    /// different version for a single request.
+    pub fn api_version(mut self, api_version: ApiVersion) -> GraphClientConfiguration {
+        self.config.api_version = api_version;
+        self
+    }
+
+    /// How request urls, which can contain personally identifiable information such as
+    /// user principal names, are written to request log events.
+    /// Defaults to [LogPolicy::Redacted] which logs the method and resource but not the url.
+    pub fn log_policy(mut self, log_policy: LogPolicy) -> GraphClientConfiguration {
+        self.config.log_policy = log_policy;
+        self
+    }
+
+    /// Use native TLS for the http client.
+    ///
+    /// Only needed when both the `native-tls` and a rustls feature are enabled.
+    /// Native TLS is used by default when both are enabled.
+    #[cfg(feature = "native-tls")]
+    pub fn use_native_tls(mut self) -> GraphClientConfiguration {
+        self.config.tls_backend = Some(TlsBackend::NativeTls);
+        self
+    }
+
+    /// Use rustls for the http client.
+    ///
+    /// Only needed when both the `native-tls` and a rustls feature are enabled.
+    #[cfg(any(
+        feature = "rustls-tls",
+        feature = "rustls-tls-native-roots",
+        feature = "rustls-tls-webpki-roots"
+    ))]
+    pub fn use_rustls_tls(mut self) -> GraphClientConfiguration {
+        self.config.tls_backend = Some(TlsBackend::RustlsTls);
+        self
+    }
+
     /// Enable or disable automatic setting of the `Referer` header.
     ///
     /// Default is `true`.
@@ -172,6 +324,29 @@ impl GraphClientConfiguration {
         self
     }
 
+    /// Allow http urls such as `http://localhost:8080` for the base url and requests
+    /// so that the client can be used with a mock server or a local proxy such as
+    /// Dev Proxy. Host validation of the base url is skipped as well.
+    ///
+    /// Access tokens are sent in plain text over http. Do not use this outside of tests
+    /// and local development.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use graph_rs_sdk::{http::Url, Graph, GraphClientConfiguration};
+    ///
+    /// let client = Graph::from(
+    ///     GraphClientConfiguration::new()
+    ///         .access_token("ACCESS_TOKEN")
+    ///         .base_url(Url::parse("http://localhost:8080").unwrap())
+    ///         .danger_accept_http(),
+    /// );
+    /// ```
+    pub fn danger_accept_http(mut self) -> GraphClientConfiguration {
+        self.config.https_only = false;
+        self
+    }
+
     #[cfg(feature = "test-util")]
     pub fn https_only(mut self, https_only: bool) -> GraphClientConfiguration {
         self.config.https_only = https_only;
@@ -213,6 +388,20 @@ impl GraphClientConfiguration {
         self
     }
 
+    /// Delay requests when the remaining resource units of the `RateLimit-Remaining`
+    /// header are at or below the threshold until the resource units are replenished
+    /// as given by the `RateLimit-Reset` header. Requests are always delayed until the
+    /// `Retry-After` of a 429 Too Many Requests response has passed when a threshold is set.
+    ///
+    /// The [ThrottleBudget] of the client is updated from the response headers
+    /// regardless of this setting.
+    ///
+    /// Default is no delay.
+    pub fn throttle_threshold(mut self, remaining: Option<u64>) -> GraphClientConfiguration {
+        self.config.service_layers_configuration.throttle_threshold = remaining;
+        self
+    }
+
     /// Enable a concurrency limit on the client.
     ///
     /// Every request through this client will be subject to a concurrency limit.
@@ -227,11 +416,138 @@ impl GraphClientConfiguration {
         self
     }
 
-    pub(crate) fn build_tower_service(
-        &self,
-        client: &reqwest::Client,
-    ) -> BoxCloneService<Request, Response, Box<dyn std::error::Error + Send + Sync>> {
+    /// Send requests using a different transport than the http client such as the
+    /// [MockTransport](crate::test_util::MockTransport) in unit tests. The retry and
+    /// concurrency limit layers of the client are still applied.
+    ///
+    /// Only requests of the async client use the transport.
+    #[cfg(feature = "test-util")]
+    pub fn transport<S>(mut self, transport: S) -> GraphClientConfiguration
+    where
+        S: tower::Service<Request, Response = Response, Error = tower::BoxError>
+            + Clone
+            + Send
+            + Sync
+            + 'static,
+        S::Future: Send + 'static,
+    {
+        self.config.service_layers_configuration.transport =
+            Some(Arc::new(move || transport.clone().boxed_clone()));
+        self
+    }
+
+    /// Limit the number of in-flight requests per host for the client and all of its
+    /// clones. Requests over the limit wait in a first in, first out queue until a
+    /// previous request to the same host has received a response.
+    ///
+    /// Unlike [GraphClientConfiguration::concurrency_limit], the limit is shared by
+    /// every request of the client. Use [Client::request_limiter] to get the number
+    /// of queued requests.
+    ///
+    /// Only requests of the async client are limited.
+    ///
+    /// Default is no limit.
+    pub fn concurrency_limit_per_host(mut self, limit: Option<usize>) -> GraphClientConfiguration {
+        self.config
+            .service_layers_configuration
+            .concurrency_limit_per_host = limit;
+        self
+    }
+
+    /// Cache the responses of GET requests that have an `ETag` or `Last-Modified` header
+    /// and revalidate them with conditional requests, returning the cached response when
+    /// the resource has not been modified. See [ResponseCache].
+    ///
+    /// Only requests of the async client are cached.
+    ///
+    /// Default is no cache.
+    pub fn response_cache(mut self, response_cache: ResponseCache) -> GraphClientConfiguration {
+        self.config.service_layers_configuration.response_cache = Some(response_cache);
+        self
+    }
+
+    /// Inject faults such as 429 Too Many Requests responses and disconnects into the
+    /// requests of the client to test retry and resume logic. Injected faults pass through
+    /// the retry, concurrency limit, and throttle budget layers of the client like responses
+    /// from Microsoft Graph.
+    ///
+    /// Only requests of the async client are affected.
+    ///
+    /// Requires feature `fault-injection`
+    #[cfg(feature = "fault-injection")]
+    pub fn fault_injection(
+        mut self,
+        fault_injection: crate::fault_injection::FaultInjection,
+    ) -> GraphClientConfiguration {
+        self.config.service_layers_configuration.fault_injection = Some(fault_injection);
+        self
+    }
+
+    /// Capture every request sent by the client, including retries, and its response
+    /// in the [HarRecorder](crate::har::HarRecorder) to save the session as an HTTP
+    /// Archive (.har) file. Secrets such as the access token are redacted.
+    ///
+    /// Only requests of the async client are captured.
+    ///
+    /// Requires feature `har-capture`
+    #[cfg(feature = "har-capture")]
+    pub fn har_capture(
+        mut self,
+        har_recorder: crate::har::HarRecorder,
+    ) -> GraphClientConfiguration {
+        self.config.service_layers_configuration.har_recorder = Some(har_recorder);
+        self
+    }
+
+    pub(crate) fn build_tower_service(&self, client: &reqwest::Client) -> HttpService {
+        let transport = match self.config.service_layers_configuration.transport.as_ref() {
+            Some(transport) => transport(),
+            None => client
+                .clone()
+                .map_err(|err| -> tower::BoxError { Box::new(err) })
+                .boxed_clone(),
+        };
+
+        #[cfg(feature = "fault-injection")]
+        let transport = match self
+            .config
+            .service_layers_configuration
+            .fault_injection
+            .as_ref()
+        {
+            Some(fault_injection) => crate::fault_injection::FaultInjectionService::new(
+                transport,
+                fault_injection.clone(),
+            )
+            .boxed_clone(),
+            None => transport,
+        };
+
+        #[cfg(feature = "har-capture")]
+        let transport = match self
+            .config
+            .service_layers_configuration
+            .har_recorder
+            .as_ref()
+        {
+            Some(har_recorder) => {
+                crate::har::HarService::new(transport, har_recorder.clone()).boxed_clone()
+            }
+            None => transport,
+        };
+
         tower::ServiceBuilder::new()
+            .option_layer(
+                self.config
+                    .service_layers_configuration
+                    .response_cache
+                    .clone()
+                    .map(|cache| {
+                        tower::layer::layer_fn(move |service| {
+                            ResponseCacheService::new(service, cache.clone())
+                        })
+                    }),
+            )
             .option_layer(
                 self.config
                     .service_layers_configuration
@@ -250,34 +566,49 @@ impl GraphClientConfiguration {
                     .concurrency_limit
                     .map(ConcurrencyLimitLayer::new),
             )
-            .service(client.clone())
+            .option_layer(
+                self.config
+                    .service_layers_configuration
+                    .request_limiter
+                    .clone()
+                    .map(|limiter| {
+                        tower::layer::layer_fn(move |service| {
+                            RequestLimiterService::new(service, limiter.clone())
+                        })
+                    }),
+            )
+            .layer_fn(|service| {
+                ThrottleBudgetService::new(
+                    service,
+                    self.config
+                        .service_layers_configuration
+                        .throttle_budget
+                        .clone(),
+                    self.config.service_layers_configuration.throttle_threshold,
+                )
+            })
+            .service(transport)
             .boxed_clone()
     }
 
-    pub fn build(self) -> Client {
+    /// The http client of the configuration with the given redirect policy.
+    fn reqwest_client(&self, redirect: Policy) -> reqwest::Client {
+        configure_client_builder!(reqwest::ClientBuilder::new(), &self.config, redirect)
+            .build()
+            .unwrap()
+    }
+
+    pub fn build(mut self) -> Client {
+        // Clients built from the same configuration do not share a budget or limiter.
+        self.config.service_layers_configuration.throttle_budget = ThrottleBudget::default();
+        self.config.service_layers_configuration.request_limiter = self
+            .config
+            .service_layers_configuration
+            .concurrency_limit_per_host
+            .map(RequestLimiter::new);
         let config = self.clone();
         let headers = self.config.headers.clone();
-        let mut builder = reqwest::ClientBuilder::new()
-            .referer(self.config.referer)
-            .connection_verbose(self.config.connection_verbose)
-            .https_only(self.config.https_only)
-            .min_tls_version(self.config.min_tls_version)
-            .redirect(Policy::limited(2))
-            .default_headers(self.config.headers);
-
-        if let Some(timeout) = self.config.timeout {
-            builder = builder.timeout(timeout);
-        }
-
-        if let Some(connect_timeout) = self.config.connect_timeout {
-            builder = builder.connect_timeout(connect_timeout);
-        }
-
-        if let Some(proxy) = self.config.proxy {
-            builder = builder.proxy(proxy);
-        }
-
-        let client = builder.build().unwrap();
+        let client = self.reqwest_client(Policy::limited(2));
 
         if let Some(client_application) = self.config.client_application {
             Client {
@@ -285,6 +616,8 @@ impl GraphClientConfiguration {
                 inner: client,
                 headers,
                 builder: config,
+                blocking: Default::default(),
+                pre_authenticated: Default::default(),
             }
         } else {
             Client {
@@ -292,33 +625,21 @@ impl GraphClientConfiguration {
                 inner: client,
                 headers,
                 builder: config,
+                blocking: Default::default(),
+                pre_authenticated: Default::default(),
             }
         }
     }
 
     pub(crate) fn build_blocking(self) -> BlockingClient {
         let headers = self.config.headers.clone();
-        let mut builder = reqwest::blocking::ClientBuilder::new()
-            .referer(self.config.referer)
-            .connection_verbose(self.config.connection_verbose)
-            .https_only(self.config.https_only)
-            .min_tls_version(self.config.min_tls_version)
-            .redirect(Policy::limited(2))
-            .default_headers(self.config.headers);
-
-        if let Some(timeout) = self.config.timeout {
-            builder = builder.timeout(timeout);
-        }
-
-        if let Some(connect_timeout) = self.config.connect_timeout {
-            builder = builder.connect_timeout(connect_timeout);
-        }
-
-        if let Some(proxy) = self.config.proxy {
-            builder = builder.proxy(proxy);
-        }
-
-        let client = builder.build().unwrap();
+        let client = configure_client_builder!(
+            reqwest::blocking::ClientBuilder::new(),
+            &self.config,
+            Policy::limited(2)
+        )
+        .build()
+        .unwrap();
 
         if let Some(client_application) = self.config.client_application {
             BlockingClient {
@@ -342,12 +663,21 @@ impl Default for GraphClientConfiguration {
     }
 }
 
+/// The http client and service stack used for pre-authenticated urls.
+pub(crate) struct PreAuthenticatedClient {
+    client: reqwest::Client,
+    // BoxCloneService is not Sync so the service is cloned out of a mutex.
+    service: Mutex<HttpService>,
+}
+
 #[derive(Clone)]
 pub struct Client {
     pub(crate) client_application: Box<dyn ClientApplication>,
     pub(crate) inner: reqwest::Client,
     pub(crate) headers: HeaderMap,
     pub(crate) builder: GraphClientConfiguration,
+    pub(crate) blocking: Arc<OnceLock<BlockingClient>>,
+    pub(crate) pre_authenticated: Arc<OnceLock<PreAuthenticatedClient>>,
 }
 
 impl Client {
@@ -379,10 +709,101 @@ impl Client {
         &self.headers
     }
 
+    /// The Microsoft Graph endpoint of the client which is the base url followed
+    /// by the api version such as `https://graph.microsoft.com/v1.0`.
+    ///
+    /// When no base url is set the Microsoft Graph endpoint of the cloud of the
+    /// client application is used.
+    pub fn endpoint(&self) -> Url {
+        let mut endpoint = self
+            .builder
+            .config
+            .base_url
+            .clone()
+            .or_else(|| {
+                self.client_application
+                    .graph_base_url()
+                    .and_then(|base_url| Url::parse(base_url.as_str()).ok())
+            })
+            .unwrap_or_else(|| Url::parse(GRAPH_BASE_URL).expect("Unable to parse graph base url"));
+        let path = format!(
+            "{}/{}",
+            endpoint.path().trim_end_matches('/'),
+            self.builder.config.api_version
+        );
+        endpoint.set_path(path.as_str());
+        endpoint
+    }
+
+    pub fn log_policy(&self) -> LogPolicy {
+        self.builder.config.log_policy
+    }
+
+    /// Returns false when http urls are allowed. See [GraphClientConfiguration::danger_accept_http]
+    pub fn https_only(&self) -> bool {
+        self.builder.config.https_only
+    }
+
+    /// The per host request limiter of the client or None if
+    /// [GraphClientConfiguration::concurrency_limit_per_host] was not set.
+    pub fn request_limiter(&self) -> Option<RequestLimiter> {
+        self.builder
+            .config
+            .service_layers_configuration
+            .request_limiter
+            .clone()
+    }
+
+    /// The throttling budget of the client updated from the response headers of
+    /// every request of the async client.
+    pub fn throttle_budget(&self) -> ThrottleBudget {
+        self.builder
+            .config
+            .service_layers_configuration
+            .throttle_budget
+            .clone()
+    }
+
     pub fn with_force_token_refresh(&mut self, force_token_refresh: ForceTokenRefresh) {
         self.client_application
             .with_force_token_refresh(force_token_refresh);
     }
+
+    /// The blocking client with the same configuration as this client. The blocking
+    /// client is created once and shared by all clones of this client.
+    pub(crate) fn blocking_client(&self) -> BlockingClient {
+        self.blocking
+            .get_or_init(|| self.builder.clone().build_blocking())
+            .clone()
+    }
+
+    /// Send a GET request to a pre-authenticated url, such as the download url of a
+    /// drive item or the monitor url of a copy, that must not be sent the access token.
+    ///
+    /// The request uses an http client with the same configuration, such as the proxy,
+    /// TLS settings, and timeouts, and the same service layers as this client. Redirects
+    /// are not followed. The http client and its service layers are created once and
+    /// shared by all clones of this client.
+    pub async fn send_pre_authenticated(&self, url: Url) -> GraphResult<Response> {
+        let pre_authenticated = self.pre_authenticated.get_or_init(|| {
+            let client = self.builder.reqwest_client(Policy::none());
+            let service = Mutex::new(self.builder.build_tower_service(&client));
+            PreAuthenticatedClient { client, service }
+        });
+        let request = pre_authenticated.client.get(url).build()?;
+        let mut service = pre_authenticated
+            .service
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .clone();
+        service
+            .ready()
+            .await
+            .map_err(GraphFailure::from)?
+            .call(request)
+            .await
+            .map_err(GraphFailure::from)
+    }
 }
 
 impl Default for Client {
@@ -431,4 +852,52 @@ mod test {
         let user_agent_header = client.builder.config.headers.get(USER_AGENT).unwrap();
         assert_eq!("user_agent", user_agent_header.to_str().unwrap());
     }
+
+    #[test]
+    fn endpoint_from_base_url_and_api_version() {
+        let client = GraphClientConfiguration::new()
+            .access_token("access_token")
+            .build();
+        assert_eq!(
+            "https://graph.microsoft.com/v1.0",
+            client.endpoint().as_str()
+        );
+
+        let client = GraphClientConfiguration::new()
+            .access_token("access_token")
+            .base_url(Url::parse("https://graph.microsoft.us/").unwrap())
+            .api_version(ApiVersion::Beta)
+            .build();
+        assert_eq!(
+            "https://graph.microsoft.us/beta",
+            client.endpoint().as_str()
+        );
+    }
+
+    #[test]
+    fn danger_accept_http_base_url() {
+        let client = GraphClientConfiguration::new()
+            .access_token("access_token")
+            .base_url(Url::parse("http://localhost:8080").unwrap())
+            .danger_accept_http()
+            .build();
+        assert!(!client.https_only());
+        assert_eq!("http://localhost:8080/v1.0", client.endpoint().as_str());
+    }
+
+    #[test]
+    fn blocking_client_is_shared_by_clones() {
+        let client = GraphClientConfiguration::new()
+            .access_token("access_token")
+            .build();
+        let clone = client.clone();
+        assert!(client.blocking.get().is_none());
+
+        let blocking_client = clone.blocking_client();
+        assert!(client.blocking.get().is_some());
+        assert_eq!(
+            blocking_client.headers(),
+            client.blocking_client().headers()
+        );
+    }
 }
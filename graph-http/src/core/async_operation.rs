@@ -0,0 +1,276 @@
+use crate::internal::{retry_after, Client, RequestComponents, RequestHandler, ResponseExt};
+use graph_core::resource::ResourceIdentity;
+use graph_error::{GraphFailure, GraphResult, GraphRsError};
+use reqwest::header::LOCATION;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use std::future::Future;
+use std::time::{Duration, Instant};
+use url::Url;
+
+/// The state of a long running operation as reported by its status resource.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AsyncOperationState {
+    /// The operation has not started or is still in progress.
+    Running,
+    /// The operation completed successfully.
+    Completed,
+    /// The operation ended without completing.
+    Failed { status: String, message: String },
+}
+
+/// The status resource returned by the monitor url of a long running operation such
+/// as the `asyncJobStatus` of a drive item copy or the `teamsAsyncOperation` of a
+/// team archive.
+pub trait AsyncOperationResource: DeserializeOwned {
+    fn operation_state(&self) -> AsyncOperationState;
+
+    /// The status resource of a completed operation whose monitor url redirects to
+    /// the resource created by the operation. Returns None by default which means
+    /// the monitor url is not expected to redirect.
+    fn from_redirect(_location: &Url) -> Option<Self> {
+        None
+    }
+}
+
+/// Polls the monitor url returned in the Location header of a 202 Accepted response
+/// until the long running operation ends.
+///
+/// The monitor url is polled every poll interval, or for the duration of a Retry-After
+/// header when one is returned, until the status resource reports that the operation
+/// has completed or failed. An operation that fails returns
+/// [`GraphRsError::AsyncOperationFailed`] and an operation that does not end within
+/// the timeout returns [`GraphRsError::AsyncOperationTimeout`].
+///
+/// Monitor urls that are Microsoft Graph urls are sent the access token of the client
+/// and must be on the same host as the client. Pre-authenticated monitor urls, such as
+/// the monitor url of a drive item copy, are never sent the access token.
+///
+/// See [Working with long running actions](https://learn.microsoft.com/en-us/graph/long-running-actions-overview)
+///
+/// # Example
+/// ```rust,ignore
+/// let response = client.drive("DRIVE_ID").item("ITEM_ID").copy(&copy).send().await?;
+/// let monitor_url = Url::parse(response.headers()[LOCATION].to_str()?)?;
+///
+/// let monitor = AsyncOperationMonitor::pre_authenticated(&client, monitor_url);
+/// let job_status: AsyncJobStatus = monitor
+///     .poll_interval(Duration::from_secs(5))
+///     .timeout(Duration::from_secs(300))
+///     .wait()
+///     .await?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct AsyncOperationMonitor {
+    monitor_url: Url,
+    client: Client,
+    pre_authenticated: bool,
+    poll_interval: Duration,
+    timeout: Duration,
+}
+
+impl AsyncOperationMonitor {
+    /// Monitor an operation whose status resource is a Microsoft Graph resource.
+    ///
+    /// The `location` may be an absolute url or a path relative to the `endpoint`,
+    /// such as `/teams('{id}')/operations('{id}')`, and is sent the access token of
+    /// the client. An absolute url on a different host than the `endpoint` is an error.
+    pub fn new(
+        client: &Client,
+        endpoint: &Url,
+        location: &str,
+    ) -> GraphResult<AsyncOperationMonitor> {
+        let monitor_url = match Url::parse(location) {
+            Ok(url) => url,
+            Err(_) => Url::parse(&format!(
+                "{}/{}",
+                endpoint.as_str().trim_end_matches('/'),
+                location.trim_start_matches('/')
+            ))?,
+        };
+
+        if monitor_url.origin() != endpoint.origin() {
+            return Err(GraphFailure::invalid(
+                "Location header with monitor url on the host of the client",
+            ));
+        }
+
+        Ok(AsyncOperationMonitor {
+            monitor_url,
+            client: client.clone(),
+            pre_authenticated: false,
+            poll_interval: Duration::from_secs(1),
+            timeout: Duration::from_secs(300),
+        })
+    }
+
+    /// Monitor an operation using the Location header of the 202 Accepted response of
+    /// the action that started it. See [`AsyncOperationMonitor::new`]
+    pub fn from_response(
+        client: &Client,
+        endpoint: &Url,
+        response: &reqwest::Response,
+    ) -> GraphResult<AsyncOperationMonitor> {
+        let location = response
+            .headers()
+            .get(LOCATION)
+            .and_then(|location| location.to_str().ok())
+            .ok_or_else(|| GraphFailure::invalid("Location header with monitor url"))?;
+        AsyncOperationMonitor::new(client, endpoint, location)
+    }
+
+    /// Monitor an operation whose monitor url is pre-authenticated. The access token
+    /// is never sent to the monitor url and redirects are not followed. Requests use
+    /// the http client configuration of the client, see [`Client::send_pre_authenticated`].
+    pub fn pre_authenticated(client: &Client, monitor_url: Url) -> AsyncOperationMonitor {
+        AsyncOperationMonitor {
+            monitor_url,
+            client: client.clone(),
+            pre_authenticated: true,
+            poll_interval: Duration::from_secs(1),
+            timeout: Duration::from_secs(300),
+        }
+    }
+
+    pub fn monitor_url(&self) -> &Url {
+        &self.monitor_url
+    }
+
+    /// The time to wait between requests when the monitor does not return a
+    /// Retry-After header. Defaults to 1 second.
+    pub fn poll_interval(mut self, poll_interval: Duration) -> AsyncOperationMonitor {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// The maximum time to wait for the operation to end. Defaults to 5 minutes.
+    pub fn timeout(mut self, timeout: Duration) -> AsyncOperationMonitor {
+        self.timeout = timeout;
+        self
+    }
+
+    async fn send(&self) -> GraphResult<reqwest::Response> {
+        if self.pre_authenticated {
+            return self
+                .client
+                .send_pre_authenticated(self.monitor_url.clone())
+                .await;
+        }
+
+        RequestHandler::new(
+            self.client.clone(),
+            RequestComponents::new(
+                ResourceIdentity::Custom,
+                self.monitor_url.clone(),
+                Method::GET,
+            ),
+            None,
+            None,
+        )
+        .send()
+        .await
+    }
+
+    /// Poll the monitor url until the operation ends and return the status resource
+    /// of the completed operation.
+    pub async fn wait<T: AsyncOperationResource>(&self) -> GraphResult<T> {
+        let start = Instant::now();
+
+        loop {
+            let response = self.send().await?;
+
+            if response.status().is_redirection() {
+                return response
+                    .headers()
+                    .get(LOCATION)
+                    .and_then(|location| location.to_str().ok())
+                    .and_then(|location| Url::parse(location).ok())
+                    .and_then(|location| T::from_redirect(&location))
+                    .ok_or_else(|| GraphFailure::invalid("status resource of monitor url"));
+            }
+
+            let response = response.error_for_graph_status().await?;
+
+            let wait = retry_after(response.headers()).unwrap_or(self.poll_interval);
+            let resource: T = response.json().await?;
+            match resource.operation_state() {
+                AsyncOperationState::Completed => return Ok(resource),
+                AsyncOperationState::Failed { status, message } => {
+                    return Err(GraphFailure::internal(GraphRsError::AsyncOperationFailed {
+                        status,
+                        message,
+                    }));
+                }
+                AsyncOperationState::Running => {}
+            }
+
+            if start.elapsed() + wait > self.timeout {
+                return Err(GraphFailure::internal(
+                    GraphRsError::AsyncOperationTimeout {
+                        timeout_secs: self.timeout.as_secs(),
+                    },
+                ));
+            }
+
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Poll the monitor url until the operation ends or the `cancel` future completes,
+    /// such as a `tokio::sync::oneshot::Receiver` or `tokio::signal::ctrl_c()`.
+    ///
+    /// Cancelling stops polling and returns [`GraphRsError::AsyncOperationCancelled`].
+    /// The operation itself continues to run on the server.
+    pub async fn wait_or_cancel<T, F>(&self, cancel: F) -> GraphResult<T>
+    where
+        T: AsyncOperationResource,
+        F: Future,
+    {
+        tokio::select! {
+            result = self.wait() => result,
+            _ = cancel => Err(GraphFailure::internal(GraphRsError::AsyncOperationCancelled)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn relative_location_is_resolved_against_endpoint() {
+        let client = Client::from_access_token("token");
+        let endpoint = Url::parse("https://graph.microsoft.com/v1.0").unwrap();
+
+        let monitor =
+            AsyncOperationMonitor::new(&client, &endpoint, "/teams('1')/operations('2')").unwrap();
+        assert_eq!(
+            "https://graph.microsoft.com/v1.0/teams('1')/operations('2')",
+            monitor.monitor_url().as_str()
+        );
+
+        let monitor = AsyncOperationMonitor::new(
+            &client,
+            &endpoint,
+            "https://graph.microsoft.com/v1.0/security/cases/ediscoveryCases/1/operations/2",
+        )
+        .unwrap();
+        assert_eq!(
+            "/v1.0/security/cases/ediscoveryCases/1/operations/2",
+            monitor.monitor_url().path()
+        );
+    }
+
+    #[test]
+    fn location_on_another_host_is_rejected() {
+        let client = Client::from_access_token("token");
+        let endpoint = Url::parse("https://graph.microsoft.com/v1.0").unwrap();
+
+        assert!(AsyncOperationMonitor::new(
+            &client,
+            &endpoint,
+            "https://example.com/teams('1')/operations('2')"
+        )
+        .is_err());
+    }
+}
@@ -1,15 +1,22 @@
 use crate::api_impl::FileConfig;
 use crate::traits::BodyExt;
-use bytes::{Buf, BytesMut};
+use bytes::{Buf, Bytes, BytesMut};
+use futures::Stream;
 use graph_error::{GraphFailure, GraphResult};
+use reqwest::header::HeaderValue;
 use reqwest::Body;
 use std::io::{BufReader, Read};
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// The size of the chunks read from an [AsyncRead] body.
+const READ_CHUNK_SIZE: usize = 64 * 1024;
 
 pub struct BodyRead {
     buf: String,
     bytes_buf: Option<Vec<u8>>,
     blocking_body: Option<reqwest::blocking::Body>,
     async_body: Option<Body>,
+    content_type: Option<HeaderValue>,
 }
 
 impl BodyRead {
@@ -19,6 +26,7 @@ impl BodyRead {
             bytes_buf: None,
             blocking_body: None,
             async_body: None,
+            content_type: None,
         }
     }
 
@@ -30,6 +38,26 @@ impl BodyRead {
         self.bytes_buf.is_some()
     }
 
+    /// The body when it is a string. Byte and streamed bodies are not read.
+    pub(crate) fn text(&self) -> Option<&str> {
+        if self.has_string_buf() {
+            Some(self.buf.as_str())
+        } else {
+            None
+        }
+    }
+
+    /// Set the `Content-Type` header that is sent with the body unless the request
+    /// already has a `Content-Type` header.
+    pub fn with_content_type(mut self, content_type: HeaderValue) -> Self {
+        self.content_type = Some(content_type);
+        self
+    }
+
+    pub fn content_type(&self) -> Option<&HeaderValue> {
+        self.content_type.as_ref()
+    }
+
     pub fn from_serialize<T: serde::Serialize>(body: &T) -> GraphResult<BodyRead> {
         let body = serde_json::to_string(body)?;
         Ok(BodyRead::new(body))
@@ -48,6 +76,48 @@ impl BodyRead {
         reader.read_to_end(&mut byte_buf).await?;
         Ok(BodyRead::from(reqwest::Body::from(byte_buf)))
     }
+
+    /// Send the bytes of the reader as they are read instead of reading the whole
+    /// body into memory first, such as to upload generated content or a download from
+    /// another service without writing a temporary file.
+    ///
+    /// Only the async client can send a streamed body.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let reader = tokio::fs::File::open("./report.csv").await?;
+    /// let response = client
+    ///     .me()
+    ///     .drive()
+    ///     .item_by_path(":/report.csv:")
+    ///     .update_items_content(BodyRead::from_async_read_stream(reader))
+    ///     .send()
+    ///     .await?;
+    /// ```
+    pub fn from_async_read_stream<T: AsyncRead + Send + Unpin + 'static>(reader: T) -> BodyRead {
+        let stream = futures::stream::unfold(Some(reader), |reader| async move {
+            let mut reader = reader?;
+            let mut buf = vec![0u8; READ_CHUNK_SIZE];
+            match reader.read(&mut buf).await {
+                Ok(0) => None,
+                Ok(n) => {
+                    buf.truncate(n);
+                    Some((Ok(Bytes::from(buf)), Some(reader)))
+                }
+                Err(err) => Some((Err(err), None)),
+            }
+        });
+        BodyRead::from(Body::wrap_stream(stream))
+    }
+
+    /// Send the chunks of a stream as they are received. See [BodyRead::from_async_read_stream].
+    pub fn from_stream<S, E>(stream: S) -> BodyRead
+    where
+        S: Stream<Item = Result<Bytes, E>> + Send + 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync>> + 'static,
+    {
+        BodyRead::from(Body::wrap_stream(stream))
+    }
 }
 
 impl From<BodyRead> for Body {
@@ -99,6 +169,7 @@ impl From<tokio::fs::File> for BodyRead {
             bytes_buf: None,
             blocking_body: None,
             async_body: Some(reqwest::Body::from(file)),
+            content_type: None,
         }
     }
 }
@@ -128,6 +199,7 @@ impl From<Vec<u8>> for BodyRead {
             bytes_buf: Some(value),
             blocking_body: None,
             async_body: None,
+            content_type: None,
         }
     }
 }
@@ -139,6 +211,7 @@ impl From<Body> for BodyRead {
             bytes_buf: None,
             blocking_body: None,
             async_body: Some(body),
+            content_type: None,
         }
     }
 }
@@ -150,6 +223,7 @@ impl From<reqwest::blocking::Body> for BodyRead {
             bytes_buf: None,
             blocking_body: Some(body),
             async_body: None,
+            content_type: None,
         }
     }
 }
@@ -0,0 +1,330 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use graph_error::IntegrityError;
+use std::fmt::{Debug, Formatter};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite};
+
+const WIDTH_IN_BITS: usize = 160;
+const SHIFT: usize = 11;
+
+/// The QuickXorHash used by OneDrive for Business and SharePoint to report the hash
+/// of the content of a file in the `quickXorHash` property of the `hashes` facet.
+///
+/// Each byte is xored into a circular 160 bit register, shifted 11 bits from the
+/// previous byte, and the length of the content is xored into the last 64 bits.
+///
+/// See [QuickXorHash](https://learn.microsoft.com/en-us/onedrive/developer/code-snippets/quickxorhash)
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct QuickXorHash {
+    data: [u64; 3],
+    shift_so_far: usize,
+    length_so_far: u64,
+}
+
+impl QuickXorHash {
+    pub fn new() -> QuickXorHash {
+        QuickXorHash::default()
+    }
+
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut vector_array_index = self.shift_so_far / 64;
+        let mut vector_offset = self.shift_so_far % 64;
+        let iterations = bytes.len().min(WIDTH_IN_BITS);
+
+        for i in 0..iterations {
+            let is_last_cell = vector_array_index == self.data.len() - 1;
+            let bits_in_vector_cell = if is_last_cell { WIDTH_IN_BITS % 64 } else { 64 };
+
+            // Every byte at the same position modulo 160 is xored into the same bits.
+            let xored = bytes[i..]
+                .iter()
+                .step_by(WIDTH_IN_BITS)
+                .fold(0u8, |xored, byte| xored ^ byte);
+
+            if vector_offset <= bits_in_vector_cell - 8 {
+                self.data[vector_array_index] ^= u64::from(xored) << vector_offset;
+            } else {
+                let next_index = if is_last_cell {
+                    0
+                } else {
+                    vector_array_index + 1
+                };
+                let low = bits_in_vector_cell - vector_offset;
+                self.data[vector_array_index] ^= u64::from(xored) << vector_offset;
+                self.data[next_index] ^= u64::from(xored) >> low;
+            }
+
+            vector_offset += SHIFT;
+            while vector_offset >= bits_in_vector_cell {
+                vector_array_index = if is_last_cell {
+                    0
+                } else {
+                    vector_array_index + 1
+                };
+                vector_offset -= bits_in_vector_cell;
+            }
+        }
+
+        self.shift_so_far =
+            (self.shift_so_far + SHIFT * (bytes.len() % WIDTH_IN_BITS)) % WIDTH_IN_BITS;
+        self.length_so_far += bytes.len() as u64;
+    }
+
+    pub fn finalize(&self) -> [u8; 20] {
+        let mut hash = [0u8; 20];
+        hash[..8].copy_from_slice(&self.data[0].to_le_bytes());
+        hash[8..16].copy_from_slice(&self.data[1].to_le_bytes());
+        hash[16..].copy_from_slice(&self.data[2].to_le_bytes()[..4]);
+
+        for (i, byte) in self.length_so_far.to_le_bytes().iter().enumerate() {
+            hash[12 + i] ^= byte;
+        }
+        hash
+    }
+
+    /// The hash encoded as base64 in the same format as the `quickXorHash` property.
+    pub fn finalize_base64(&self) -> String {
+        STANDARD.encode(self.finalize())
+    }
+}
+
+/// The size and hashes of content computed locally to compare with the `hashes` facet
+/// of a drive item.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ContentHashes {
+    pub size: u64,
+    /// Base64 encoded QuickXorHash.
+    pub quick_xor_hash: String,
+    /// Uppercase hex encoded SHA-256 hash.
+    pub sha256_hash: String,
+}
+
+impl ContentHashes {
+    /// Read the reader to the end and hash the content, such as a file before it is
+    /// uploaded.
+    pub async fn from_async_read<R: AsyncRead + Unpin>(
+        mut reader: R,
+    ) -> std::io::Result<ContentHashes> {
+        let mut hasher = ContentHasher::new();
+        let mut buf = vec![0u8; 64 * 1024];
+        loop {
+            let n = reader.read(&mut buf).await?;
+            if n == 0 {
+                return Ok(hasher.finish());
+            }
+            hasher.update(&buf[..n]);
+        }
+    }
+
+    /// Compare the hashes with the hashes reported by a drive item. Every hash that is
+    /// reported is compared and at least one of them must be reported.
+    ///
+    /// OneDrive for Business and SharePoint report the `quickXorHash`. OneDrive
+    /// personal may report the `sha256Hash`.
+    pub fn verify(
+        &self,
+        quick_xor_hash: Option<&str>,
+        sha256_hash: Option<&str>,
+    ) -> Result<(), IntegrityError> {
+        if quick_xor_hash.is_none() && sha256_hash.is_none() {
+            return Err(IntegrityError::MissingHash);
+        }
+
+        if let Some(expected) = quick_xor_hash {
+            if expected != self.quick_xor_hash {
+                return Err(IntegrityError::Mismatch {
+                    algorithm: "quickXorHash".to_owned(),
+                    expected: expected.to_owned(),
+                    computed: self.quick_xor_hash.clone(),
+                });
+            }
+        }
+
+        if let Some(expected) = sha256_hash {
+            if !expected.eq_ignore_ascii_case(self.sha256_hash.as_str()) {
+                return Err(IntegrityError::Mismatch {
+                    algorithm: "sha256Hash".to_owned(),
+                    expected: expected.to_owned(),
+                    computed: self.sha256_hash.clone(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Computes the [ContentHashes] of content as it is read or written.
+#[derive(Clone)]
+pub struct ContentHasher {
+    size: u64,
+    quick_xor_hash: QuickXorHash,
+    sha256: ring::digest::Context,
+}
+
+impl ContentHasher {
+    pub fn new() -> ContentHasher {
+        ContentHasher {
+            size: 0,
+            quick_xor_hash: QuickXorHash::new(),
+            sha256: ring::digest::Context::new(&ring::digest::SHA256),
+        }
+    }
+
+    pub fn update(&mut self, bytes: &[u8]) {
+        self.size += bytes.len() as u64;
+        self.quick_xor_hash.update(bytes);
+        self.sha256.update(bytes);
+    }
+
+    /// The hashes of the content so far.
+    pub fn finish(&self) -> ContentHashes {
+        let sha256 = self.sha256.clone().finish();
+        ContentHashes {
+            size: self.size,
+            quick_xor_hash: self.quick_xor_hash.finalize_base64(),
+            sha256_hash: sha256
+                .as_ref()
+                .iter()
+                .map(|byte| format!("{byte:02X}"))
+                .collect(),
+        }
+    }
+}
+
+impl Default for ContentHasher {
+    fn default() -> Self {
+        ContentHasher::new()
+    }
+}
+
+impl Debug for ContentHasher {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ContentHasher")
+            .field("size", &self.size)
+            .finish()
+    }
+}
+
+/// Hashes the bytes written to the inner writer, such as to verify a download
+/// written with `ResponseExt::download_to_writer`.
+///
+/// # Example
+/// ```rust,ignore
+/// let file = tokio::fs::File::create("./report.docx").await?;
+/// let mut writer = HashingWriter::new(file);
+/// response.download_to_writer(&mut writer).await?;
+///
+/// let quick_xor_hash = drive_item.file.and_then(|file| file.hashes)
+///     .and_then(|hashes| hashes.quick_xor_hash);
+/// writer.hashes().verify(quick_xor_hash.as_deref(), None)?;
+/// ```
+#[derive(Debug)]
+pub struct HashingWriter<W> {
+    inner: W,
+    hasher: ContentHasher,
+}
+
+impl<W> HashingWriter<W> {
+    pub fn new(inner: W) -> HashingWriter<W> {
+        HashingWriter {
+            inner,
+            hasher: ContentHasher::new(),
+        }
+    }
+
+    /// The hashes of the bytes written so far.
+    pub fn hashes(&self) -> ContentHashes {
+        self.hasher.finish()
+    }
+
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: AsyncWrite + Unpin> AsyncWrite for HashingWriter<W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        let this = self.get_mut();
+        let poll = Pin::new(&mut this.inner).poll_write(cx, buf);
+        if let Poll::Ready(Ok(n)) = poll {
+            this.hasher.update(&buf[..n]);
+        }
+        poll
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_shutdown(cx)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use tokio::io::AsyncWriteExt;
+
+    fn content() -> Vec<u8> {
+        (0..=255u8).cycle().take(1024).collect()
+    }
+
+    #[test]
+    fn quick_xor_hash_of_known_content() {
+        let hash = |bytes: &[u8]| {
+            let mut hash = QuickXorHash::new();
+            hash.update(bytes);
+            hash.finalize_base64()
+        };
+
+        assert_eq!("AAAAAAAAAAAAAAAAAAAAAAAAAAA=", hash(b""));
+        assert_eq!("YRDDGAAAAAAAAAAAAwAAAAAAAAA=", hash(b"abc"));
+        assert_eq!("SCgDG9jwBhaA4ApvnQMbyBACAAA=", hash(b"Hello, World!"));
+        assert_eq!("h7xr2dbCayZCQYR9KKhlwDuT4UI=", hash(&content()));
+    }
+
+    #[test]
+    fn quick_xor_hash_does_not_depend_on_chunks() {
+        let content = content();
+        for chunk_size in [1, 7, 160, 333] {
+            let mut hash = QuickXorHash::new();
+            for chunk in content.chunks(chunk_size) {
+                hash.update(chunk);
+            }
+            assert_eq!("h7xr2dbCayZCQYR9KKhlwDuT4UI=", hash.finalize_base64());
+        }
+    }
+
+    #[tokio::test]
+    async fn hashing_writer_verifies_written_content() {
+        let mut writer = HashingWriter::new(Vec::new());
+        writer.write_all(b"Hello, World!").await.unwrap();
+
+        let hashes = writer.hashes();
+        assert_eq!(13, hashes.size);
+        assert_eq!(
+            "DFFD6021BB2BD5B0AF676290809EC3A53191DD81C7F70A4B28688A362182986F",
+            hashes.sha256_hash
+        );
+        assert!(hashes
+            .verify(Some("SCgDG9jwBhaA4ApvnQMbyBACAAA="), None)
+            .is_ok());
+        assert!(hashes
+            .verify(None, Some(&hashes.sha256_hash.to_lowercase()))
+            .is_ok());
+        assert_eq!(Err(IntegrityError::MissingHash), hashes.verify(None, None));
+        assert!(matches!(
+            hashes.verify(Some("AAAAAAAAAAAAAAAAAAAAAAAAAAA="), None),
+            Err(IntegrityError::Mismatch { .. })
+        ));
+        assert_eq!(b"Hello, World!".to_vec(), writer.into_inner());
+    }
+}
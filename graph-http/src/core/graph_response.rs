@@ -0,0 +1,247 @@
+use crate::internal::{preference_applied, HttpResponseBuilderExt, HttpResponseExt};
+use graph_error::GraphFailure;
+use reqwest::header::{HeaderMap, HeaderName, ETAG, RETRY_AFTER};
+use reqwest::StatusCode;
+use std::time::Duration;
+use url::Url;
+
+const REQUEST_ID: HeaderName = HeaderName::from_static("request-id");
+const CLIENT_REQUEST_ID: HeaderName = HeaderName::from_static("client-request-id");
+const RATE_LIMIT_LIMIT: HeaderName = HeaderName::from_static("ratelimit-limit");
+const RATE_LIMIT_REMAINING: HeaderName = HeaderName::from_static("ratelimit-remaining");
+const RATE_LIMIT_RESET: HeaderName = HeaderName::from_static("ratelimit-reset");
+
+fn header_str<'a>(headers: &'a HeaderMap, name: &HeaderName) -> Option<&'a str> {
+    headers.get(name).and_then(|value| value.to_str().ok())
+}
+
+fn header_u64(headers: &HeaderMap, name: &HeaderName) -> Option<u64> {
+    header_str(headers, name).and_then(|value| value.trim().parse::<u64>().ok())
+}
+
+/// The number of seconds to wait before sending another request as given by the
+/// `Retry-After` header of a throttled response.
+pub fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    header_u64(headers, &RETRY_AFTER).map(Duration::from_secs)
+}
+
+/// Resource unit limits returned in the `RateLimit` headers of some APIs such as
+/// the Outlook and Teams APIs.
+///
+/// See [Throttling guidance](https://learn.microsoft.com/en-us/graph/throttling#regular-responses-requests)
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct RateLimit {
+    /// The number of resource units allowed within the time window.
+    pub limit: Option<u64>,
+    /// The number of resource units remaining within the time window.
+    pub remaining: Option<u64>,
+    /// The number of seconds until the resource units are replenished.
+    pub reset: Option<Duration>,
+}
+
+impl RateLimit {
+    pub fn from_headers(headers: &HeaderMap) -> Option<RateLimit> {
+        let rate_limit = RateLimit {
+            limit: header_u64(headers, &RATE_LIMIT_LIMIT),
+            remaining: header_u64(headers, &RATE_LIMIT_REMAINING),
+            reset: header_u64(headers, &RATE_LIMIT_RESET).map(Duration::from_secs),
+        };
+
+        if rate_limit == RateLimit::default() {
+            None
+        } else {
+            Some(rate_limit)
+        }
+    }
+}
+
+/// A deserialized response body and the metadata of the response.
+///
+/// Use `RequestHandler::send_json` to send a request and get a [GraphResponse] or
+/// `ResponseExt::into_graph_response` to convert a [reqwest::Response].
+///
+/// # Example
+/// ```rust,ignore
+/// let response: GraphResponse<serde_json::Value> = client
+///     .users()
+///     .list_user()
+///     .send_json()
+///     .await?;
+///
+/// println!("{:#?}", response.request_id());
+/// println!("{:#?}", response.rate_limit());
+/// let users = response.into_body();
+/// ```
+#[derive(Clone, Debug)]
+pub struct GraphResponse<T> {
+    status: StatusCode,
+    headers: HeaderMap,
+    url: Url,
+    body: T,
+}
+
+impl<T> GraphResponse<T> {
+    pub fn new(status: StatusCode, headers: HeaderMap, url: Url, body: T) -> GraphResponse<T> {
+        GraphResponse {
+            status,
+            headers,
+            url,
+            body,
+        }
+    }
+
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// The url of the response which may differ from the url of the request
+    /// if the request was redirected.
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    pub fn body(&self) -> &T {
+        &self.body
+    }
+
+    pub fn into_body(self) -> T {
+        self.body
+    }
+
+    /// The `request-id` header used by Microsoft support to find the request.
+    pub fn request_id(&self) -> Option<&str> {
+        header_str(&self.headers, &REQUEST_ID)
+    }
+
+    /// The `client-request-id` header which echoes the id sent with the request.
+    pub fn client_request_id(&self) -> Option<&str> {
+        header_str(&self.headers, &CLIENT_REQUEST_ID)
+    }
+
+    /// The `ETag` header of the response. Use the value with `if_match` on a request
+    /// to only update the resource if it has not changed.
+    pub fn etag(&self) -> Option<&str> {
+        header_str(&self.headers, &ETAG)
+    }
+
+    pub fn retry_after(&self) -> Option<Duration> {
+        retry_after(&self.headers)
+    }
+
+    pub fn rate_limit(&self) -> Option<RateLimit> {
+        RateLimit::from_headers(&self.headers)
+    }
+
+    /// The preferences of the `Prefer` request header that were applied.
+    pub fn preference_applied(&self) -> Vec<String> {
+        preference_applied(&self.headers)
+    }
+
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> GraphResponse<U> {
+        GraphResponse {
+            status: self.status,
+            headers: self.headers,
+            url: self.url,
+            body: f(self.body),
+        }
+    }
+}
+
+impl GraphResponse<serde_json::Value> {
+    /// The `@odata.etag` of the resource in the response body or the `ETag`
+    /// header if the body does not have one.
+    pub fn odata_etag(&self) -> Option<&str> {
+        self.body
+            .get("@odata.etag")
+            .and_then(|etag| etag.as_str())
+            .or_else(|| self.etag())
+    }
+}
+
+impl<T> AsRef<T> for GraphResponse<T> {
+    fn as_ref(&self) -> &T {
+        &self.body
+    }
+}
+
+/// The url of the response is kept in the extensions of the [http::Response] and
+/// can be read using `HttpResponseExt::url`.
+impl<T> From<GraphResponse<T>> for http::Response<T> {
+    fn from(value: GraphResponse<T>) -> Self {
+        let (mut parts, _) = http::Response::builder()
+            .url(value.url)
+            .body(())
+            .unwrap_or_default()
+            .into_parts();
+        parts.status = value.status;
+        parts.headers = value.headers;
+        http::Response::from_parts(parts, value.body)
+    }
+}
+
+/// Requires the url of the response to be set in the extensions of the [http::Response]
+/// using `HttpResponseBuilderExt::url`.
+impl<T> TryFrom<http::Response<T>> for GraphResponse<T> {
+    type Error = GraphFailure;
+
+    fn try_from(value: http::Response<T>) -> Result<Self, Self::Error> {
+        let url = value
+            .url()
+            .ok_or_else(|| GraphFailure::invalid("http::Response is missing the response url"))?;
+        let (parts, body) = value.into_parts();
+        Ok(GraphResponse::new(parts.status, parts.headers, url, body))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn odata_etag() {
+        let url = Url::parse("https://graph.microsoft.com/v1.0/me/drive/items/1").unwrap();
+        let mut headers = HeaderMap::new();
+        headers.insert(ETAG, HeaderValue::from_static("\"header-etag\""));
+
+        let response = GraphResponse::new(
+            StatusCode::OK,
+            headers.clone(),
+            url.clone(),
+            serde_json::json!({ "@odata.etag": "W/\"body-etag\"" }),
+        );
+        assert_eq!(Some("W/\"body-etag\""), response.odata_etag());
+        assert_eq!(Some("\"header-etag\""), response.etag());
+
+        let response = GraphResponse::new(StatusCode::OK, headers, url, serde_json::json!({}));
+        assert_eq!(Some("\"header-etag\""), response.odata_etag());
+    }
+
+    #[test]
+    fn http_response_round_trip() {
+        let url = Url::parse("https://graph.microsoft.com/v1.0/me").unwrap();
+        let mut headers = HeaderMap::new();
+        headers.insert(REQUEST_ID, HeaderValue::from_static("request-id"));
+
+        let response = GraphResponse::new(
+            StatusCode::CREATED,
+            headers,
+            url.clone(),
+            serde_json::json!({ "id": "1" }),
+        );
+        let http_response: http::Response<serde_json::Value> = response.into();
+        assert_eq!(StatusCode::CREATED, http_response.status());
+        assert_eq!(Some(url.clone()), http_response.url());
+
+        let response = GraphResponse::try_from(http_response).unwrap();
+        assert_eq!(&url, response.url());
+        assert_eq!(Some("request-id"), response.request_id());
+        assert_eq!(&serde_json::json!({ "id": "1" }), response.body());
+
+        assert!(GraphResponse::try_from(http::Response::new(())).is_err());
+    }
+}
@@ -1,5 +1,29 @@
+mod async_operation;
 mod body_read;
+mod content_hash;
 mod file_config;
+mod graph_response;
+mod multipart;
+mod odata_filter;
+mod page_decoder;
+mod permissions;
+mod prefer;
+mod request_dump;
+mod request_limiter;
+mod response_cache;
+mod throttle_budget;
 
+pub use async_operation::*;
 pub use body_read::*;
+pub use content_hash::*;
 pub use file_config::*;
+pub use graph_response::*;
+pub use multipart::*;
+pub use odata_filter::*;
+pub use page_decoder::*;
+pub use permissions::*;
+pub use prefer::*;
+pub use request_dump::*;
+pub use request_limiter::*;
+pub use response_cache::*;
+pub use throttle_budget::*;
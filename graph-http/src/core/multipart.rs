@@ -0,0 +1,148 @@
+use crate::api_impl::BodyRead;
+use crate::traits::BodyExt;
+use graph_error::{GraphFailure, GraphResult};
+use reqwest::header::HeaderValue;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+static BOUNDARY_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+fn generate_boundary() -> String {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_nanos())
+        .unwrap_or_default();
+    let count = BOUNDARY_COUNTER.fetch_add(1, Ordering::Relaxed);
+    format!("graph-rs-sdk-{nanos:x}-{count:x}")
+}
+
+#[derive(Clone, Debug)]
+struct MultipartPart {
+    name: String,
+    content_type: String,
+    data: Vec<u8>,
+}
+
+/// A `multipart/form-data` request body.
+///
+/// The body is sent with a `Content-Type` header that has the boundary
+/// of the body unless a `Content-Type` header is set for the request.
+///
+/// # Example
+/// ```rust
+/// use graph_http::api_impl::MultipartFormData;
+///
+/// let form = MultipartFormData::new()
+///     .part("Presentation", "text/html", "<html><body><img src=\"name:image\" /></body></html>")
+///     .part("image", "image/png", vec![0u8; 8]);
+///
+/// assert!(form.content_type().starts_with("multipart/form-data; boundary="));
+/// ```
+#[derive(Clone, Debug)]
+pub struct MultipartFormData {
+    boundary: String,
+    parts: Vec<MultipartPart>,
+}
+
+impl MultipartFormData {
+    pub fn new() -> MultipartFormData {
+        MultipartFormData::with_boundary(generate_boundary())
+    }
+
+    /// Use the given boundary instead of a generated boundary. The boundary must not
+    /// appear in the data of any part.
+    pub fn with_boundary(boundary: impl Into<String>) -> MultipartFormData {
+        MultipartFormData {
+            boundary: boundary.into(),
+            parts: Vec::new(),
+        }
+    }
+
+    /// Add a part with the given name and content type.
+    pub fn part(
+        mut self,
+        name: impl Into<String>,
+        content_type: impl Into<String>,
+        data: impl Into<Vec<u8>>,
+    ) -> Self {
+        self.parts.push(MultipartPart {
+            name: name.into(),
+            content_type: content_type.into(),
+            data: data.into(),
+        });
+        self
+    }
+
+    pub fn boundary(&self) -> &str {
+        self.boundary.as_str()
+    }
+
+    /// The value of the `Content-Type` header for the body.
+    pub fn content_type(&self) -> String {
+        format!("multipart/form-data; boundary={}", self.boundary)
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for part in self.parts.iter() {
+            bytes.extend_from_slice(format!("--{}\r\n", self.boundary).as_bytes());
+            bytes.extend_from_slice(
+                format!(
+                    "Content-Disposition: form-data; name=\"{}\"\r\n",
+                    part.name.replace('"', "\\\"")
+                )
+                .as_bytes(),
+            );
+            bytes.extend_from_slice(
+                format!("Content-Type: {}\r\n\r\n", part.content_type).as_bytes(),
+            );
+            bytes.extend_from_slice(part.data.as_slice());
+            bytes.extend_from_slice(b"\r\n");
+        }
+        bytes.extend_from_slice(format!("--{}--\r\n", self.boundary).as_bytes());
+        bytes
+    }
+}
+
+impl Default for MultipartFormData {
+    fn default() -> Self {
+        MultipartFormData::new()
+    }
+}
+
+impl BodyExt for MultipartFormData {
+    fn into_body(self) -> GraphResult<BodyRead> {
+        let content_type = HeaderValue::from_str(self.content_type().as_str())
+            .map_err(|err| GraphFailure::from(http::Error::from(err)))?;
+        Ok(BodyRead::from(self.to_bytes()).with_content_type(content_type))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn multipart_body() {
+        let form = MultipartFormData::with_boundary("boundary")
+            .part("Presentation", "text/html", "<html></html>")
+            .part("image", "image/png", vec![1u8, 2u8]);
+
+        let mut expected = b"--boundary\r\nContent-Disposition: form-data; name=\"Presentation\"\r\nContent-Type: text/html\r\n\r\n<html></html>\r\n--boundary\r\nContent-Disposition: form-data; name=\"image\"\r\nContent-Type: image/png\r\n\r\n".to_vec();
+        expected.extend_from_slice(&[1u8, 2u8]);
+        expected.extend_from_slice(b"\r\n--boundary--\r\n");
+        assert_eq!(expected, form.to_bytes());
+        assert_eq!(
+            "multipart/form-data; boundary=boundary",
+            form.content_type()
+        );
+    }
+
+    #[test]
+    fn generated_boundaries_are_unique() {
+        assert_ne!(
+            MultipartFormData::new().boundary(),
+            MultipartFormData::new().boundary()
+        );
+    }
+}
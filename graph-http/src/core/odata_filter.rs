@@ -0,0 +1,229 @@
+use graph_error::{GraphFailure, GraphResult};
+
+/// Quote a string literal for an OData `$filter`. Single quotes are escaped by doubling
+/// them so that the value cannot end the literal and change the expression.
+///
+/// Characters with a meaning in a url such as `&`, `#`, `+`, and `%` do not need to be
+/// escaped because query parameters are percent-encoded when they are added to the url.
+///
+/// # Example
+/// ```rust
+/// use graph_http::api_impl::odata_string;
+///
+/// assert_eq!("'O''Brien'", odata_string("O'Brien"));
+/// ```
+pub fn odata_string(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/// A value that can be bound to a placeholder of an [ODataFilter].
+///
+/// Strings are quoted and escaped, numbers and booleans are written as is, and `None`
+/// is written as `null`.
+pub trait ODataLiteral {
+    fn to_odata_literal(&self) -> String;
+}
+
+impl ODataLiteral for str {
+    fn to_odata_literal(&self) -> String {
+        odata_string(self)
+    }
+}
+
+impl ODataLiteral for String {
+    fn to_odata_literal(&self) -> String {
+        odata_string(self.as_str())
+    }
+}
+
+impl ODataLiteral for char {
+    fn to_odata_literal(&self) -> String {
+        odata_string(self.to_string().as_str())
+    }
+}
+
+impl ODataLiteral for bool {
+    fn to_odata_literal(&self) -> String {
+        self.to_string()
+    }
+}
+
+macro_rules! odata_integer_literal {
+    ($($ty:ty),*) => {
+        $(
+            impl ODataLiteral for $ty {
+                fn to_odata_literal(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+odata_integer_literal!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl ODataLiteral for f32 {
+    fn to_odata_literal(&self) -> String {
+        f64::from(*self).to_odata_literal()
+    }
+}
+
+impl ODataLiteral for f64 {
+    fn to_odata_literal(&self) -> String {
+        if self.is_nan() {
+            "NaN".to_owned()
+        } else if self.is_infinite() && self.is_sign_positive() {
+            "INF".to_owned()
+        } else if self.is_infinite() {
+            "-INF".to_owned()
+        } else {
+            self.to_string()
+        }
+    }
+}
+
+impl<T: ODataLiteral> ODataLiteral for Option<T> {
+    fn to_odata_literal(&self) -> String {
+        match self {
+            Some(value) => value.to_odata_literal(),
+            None => "null".to_owned(),
+        }
+    }
+}
+
+impl<T: ODataLiteral + ?Sized> ODataLiteral for &T {
+    fn to_odata_literal(&self) -> String {
+        (**self).to_odata_literal()
+    }
+}
+
+/// Builds a `$filter` expression from a template with `{}` placeholders and values
+/// bound to the placeholders in order.
+///
+/// Bound values are written as OData literals using [ODataLiteral], so values from user
+/// input cannot end a string literal and add conditions to the expression. Placeholders
+/// must not be quoted in the template because string values are quoted when they are bound.
+///
+/// The [filter!](crate::filter) macro builds the same expression in a single call.
+///
+/// # Example
+/// ```rust
+/// use graph_http::api_impl::ODataFilter;
+///
+/// let filter = ODataFilter::new("displayName eq {} and accountEnabled eq {}")
+///     .bind("O'Brien")
+///     .bind(true)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!("displayName eq 'O''Brien' and accountEnabled eq true", filter);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ODataFilter {
+    template: String,
+    values: Vec<String>,
+}
+
+impl ODataFilter {
+    pub fn new(template: impl Into<String>) -> ODataFilter {
+        ODataFilter {
+            template: template.into(),
+            values: Vec::new(),
+        }
+    }
+
+    /// Bind a value to the next placeholder of the template.
+    pub fn bind<T: ODataLiteral>(mut self, value: T) -> ODataFilter {
+        self.values.push(value.to_odata_literal());
+        self
+    }
+
+    /// Replace the placeholders of the template with the bound values.
+    ///
+    /// Returns an error if the number of placeholders and bound values are not the same
+    /// or if a placeholder is quoted.
+    pub fn build(&self) -> GraphResult<String> {
+        let mut values = self.values.iter();
+        let mut parts = self.template.split("{}").peekable();
+        let mut filter = String::with_capacity(self.template.len());
+
+        while let Some(part) = parts.next() {
+            filter.push_str(part);
+            if parts.peek().is_none() {
+                break;
+            }
+
+            if part.ends_with('\'') {
+                return Err(GraphFailure::invalid(
+                    "filter template with placeholders that are not quoted",
+                ));
+            }
+
+            let value = values.next().ok_or_else(|| {
+                GraphFailure::invalid("value for every placeholder of the filter template")
+            })?;
+            filter.push_str(value);
+        }
+
+        if values.next().is_some() {
+            return Err(GraphFailure::invalid(
+                "placeholder in the filter template for every bound value",
+            ));
+        }
+
+        Ok(filter)
+    }
+}
+
+/// Build a `$filter` expression from a template with `{}` placeholders and the values
+/// of the placeholders. Values are quoted and escaped as OData literals. See [ODataFilter].
+///
+/// # Example
+/// ```rust
+/// use graph_http::filter;
+///
+/// let display_name = "O'Brien') or (1 eq 1";
+/// let filter = filter!("displayName eq {} and accountEnabled eq {}", display_name, true).unwrap();
+///
+/// assert_eq!(
+///     "displayName eq 'O''Brien'') or (1 eq 1' and accountEnabled eq true",
+///     filter
+/// );
+/// ```
+#[macro_export]
+macro_rules! filter {
+    ($template:expr $(, $value:expr)* $(,)?) => {
+        $crate::api_impl::ODataFilter::new($template)
+            $(.bind($value))*
+            .build()
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn values_are_escaped_literals() {
+        assert_eq!("'it''s'", "it's".to_odata_literal());
+        assert_eq!("'it''s'", String::from("it's").to_odata_literal());
+        assert_eq!("null", None::<&str>.to_odata_literal());
+        assert_eq!("-5", (-5i64).to_odata_literal());
+        assert_eq!("1.5", 1.5f64.to_odata_literal());
+        assert_eq!("INF", f64::INFINITY.to_odata_literal());
+    }
+
+    #[test]
+    fn placeholders_and_values_must_match() {
+        assert!(ODataFilter::new("a eq {} and b eq {}")
+            .bind(1)
+            .build()
+            .is_err());
+        assert!(ODataFilter::new("a eq {}").bind(1).bind(2).build().is_err());
+        assert!(ODataFilter::new("a eq '{}'").bind("x").build().is_err());
+        assert_eq!(
+            "startswith(mail, 'a''b')",
+            filter!("startswith(mail, {})", "a'b").unwrap()
+        );
+    }
+}
@@ -0,0 +1,256 @@
+use graph_error::{GraphFailure, GraphResult};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+const VALUE: &str = "value";
+const NEXT_LINK: &str = "@odata.nextLink";
+const BYTE_ORDER_MARK: [u8; 3] = [0xEF, 0xBB, 0xBF];
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum DecoderState {
+    Start,
+    FirstMember,
+    Member,
+    Key,
+    Colon(String),
+    ValueArray,
+    Value(String),
+    Items { after_item: bool },
+    End,
+}
+
+/// Deserializes the items of the `value` array of a collection page as the bytes of
+/// the response body are received.
+///
+/// Only the bytes of the item that is being received are buffered so the memory used
+/// does not depend on the size of the page. Other members of the page, such as
+/// `@odata.nextLink`, may come before or after the `value` array.
+#[derive(Debug)]
+pub(crate) struct PageDecoder {
+    buffer: Vec<u8>,
+    position: usize,
+    state: DecoderState,
+    next_link: Option<String>,
+}
+
+impl PageDecoder {
+    pub(crate) fn new() -> PageDecoder {
+        PageDecoder {
+            buffer: Vec::new(),
+            position: 0,
+            state: DecoderState::Start,
+            next_link: None,
+        }
+    }
+
+    /// Add the next chunk of the response body and return the items that are complete.
+    pub(crate) fn decode<T: DeserializeOwned>(&mut self, chunk: &[u8]) -> GraphResult<Vec<T>> {
+        self.buffer.extend_from_slice(chunk);
+
+        let mut items = Vec::new();
+        while self.step(&mut items)? {}
+
+        self.buffer.drain(..self.position);
+        self.position = 0;
+        Ok(items)
+    }
+
+    /// Returns the next link of the page once the whole response body is decoded.
+    pub(crate) fn finish(self) -> GraphResult<Option<String>> {
+        if self.state != DecoderState::End {
+            return Err(GraphFailure::invalid(
+                "complete collection page in the response body",
+            ));
+        }
+        Ok(self.next_link)
+    }
+
+    /// Decode the next token. Returns false when more bytes are needed.
+    fn step<T: DeserializeOwned>(&mut self, items: &mut Vec<T>) -> GraphResult<bool> {
+        let Some(byte) = self.skip_whitespace() else {
+            return Ok(false);
+        };
+
+        match self.state.clone() {
+            DecoderState::Start if byte == BYTE_ORDER_MARK[0] => {
+                if self.buffer.len() - self.position < BYTE_ORDER_MARK.len() {
+                    return Ok(false);
+                }
+                self.position += BYTE_ORDER_MARK.len();
+            }
+            DecoderState::Start => {
+                self.expect(byte, b'{')?;
+                self.state = DecoderState::FirstMember;
+            }
+            DecoderState::FirstMember if byte == b'}' => {
+                self.position += 1;
+                self.state = DecoderState::End;
+            }
+            DecoderState::FirstMember => self.state = DecoderState::Key,
+            DecoderState::Member if byte == b',' => {
+                self.position += 1;
+                self.state = DecoderState::Key;
+            }
+            DecoderState::Member => {
+                self.expect(byte, b'}')?;
+                self.state = DecoderState::End;
+            }
+            DecoderState::Key => match self.parse::<String>()? {
+                Some(key) => self.state = DecoderState::Colon(key),
+                None => return Ok(false),
+            },
+            DecoderState::Colon(key) => {
+                self.expect(byte, b':')?;
+                self.state = if key == VALUE {
+                    DecoderState::ValueArray
+                } else {
+                    DecoderState::Value(key)
+                };
+            }
+            DecoderState::ValueArray if byte == b'[' => {
+                self.position += 1;
+                self.state = DecoderState::Items { after_item: false };
+            }
+            DecoderState::ValueArray => self.state = DecoderState::Value(VALUE.to_owned()),
+            DecoderState::Value(key) => match self.parse::<Value>()? {
+                Some(value) => {
+                    if key == NEXT_LINK {
+                        self.next_link = value.as_str().map(String::from);
+                    }
+                    self.state = DecoderState::Member;
+                }
+                None => return Ok(false),
+            },
+            DecoderState::Items { .. } if byte == b']' => {
+                self.position += 1;
+                self.state = DecoderState::Member;
+            }
+            DecoderState::Items { after_item: true } => {
+                self.expect(byte, b',')?;
+                self.state = DecoderState::Items { after_item: false };
+            }
+            DecoderState::Items { after_item: false } => match self.parse::<T>()? {
+                Some(item) => {
+                    items.push(item);
+                    self.state = DecoderState::Items { after_item: true };
+                }
+                None => return Ok(false),
+            },
+            DecoderState::End => {
+                self.position = self.buffer.len();
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn skip_whitespace(&mut self) -> Option<u8> {
+        while let Some(byte) = self.buffer.get(self.position) {
+            if !byte.is_ascii_whitespace() {
+                return Some(*byte);
+            }
+            self.position += 1;
+        }
+        None
+    }
+
+    fn expect(&mut self, byte: u8, expected: u8) -> GraphResult<()> {
+        if byte != expected {
+            return Err(GraphFailure::invalid(
+                "collection page with a JSON object in the response body",
+            ));
+        }
+        self.position += 1;
+        Ok(())
+    }
+
+    /// Deserialize the value at the current position. Returns None if the value is not
+    /// complete yet.
+    fn parse<T: DeserializeOwned>(&mut self) -> GraphResult<Option<T>> {
+        let mut values =
+            serde_json::Deserializer::from_slice(&self.buffer[self.position..]).into_iter::<T>();
+
+        match values.next() {
+            Some(Ok(value)) => {
+                let end = self.position + values.byte_offset();
+                // A number at the end of the buffer may continue in the next chunk so
+                // values are only complete once the byte after them has been received.
+                if self.buffer[end..]
+                    .iter()
+                    .all(|byte| byte.is_ascii_whitespace())
+                {
+                    return Ok(None);
+                }
+                self.position = end;
+                Ok(Some(value))
+            }
+            Some(Err(err)) if err.is_eof() => Ok(None),
+            Some(Err(err)) => Err(err.into()),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const PAGE: &str = r#"{
+        "@odata.context": "https://graph.microsoft.com/v1.0/$metadata#users",
+        "@odata.nextLink": "https://graph.microsoft.com/v1.0/users?$skiptoken=token",
+        "value": [
+            { "id": "1", "displayName": "Adele [Vance]" },
+            { "id": "2", "displayName": "Alex \"W\" Wilber" }
+        ]
+    }"#;
+
+    fn decode_in_chunks(body: &[u8], size: usize) -> (Vec<Value>, GraphResult<Option<String>>) {
+        let mut decoder = PageDecoder::new();
+        let mut items = Vec::new();
+        for chunk in body.chunks(size) {
+            items.extend(decoder.decode::<Value>(chunk).unwrap());
+        }
+        (items, decoder.finish())
+    }
+
+    #[test]
+    fn items_are_decoded_across_chunks() {
+        for size in 1..PAGE.len() {
+            let (items, next_link) = decode_in_chunks(PAGE.as_bytes(), size);
+            assert_eq!(2, items.len());
+            assert_eq!("Alex \"W\" Wilber", items[1]["displayName"]);
+            assert_eq!(
+                Some("https://graph.microsoft.com/v1.0/users?$skiptoken=token".to_owned()),
+                next_link.unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn next_link_after_items_and_numbers() {
+        let body = "\u{feff}{\"value\":[12,345],\"@odata.nextLink\":\"https://graph.microsoft.com/v1.0/next\"}";
+        for size in 1..body.len() {
+            let mut decoder = PageDecoder::new();
+            let mut items = Vec::new();
+            for chunk in body.as_bytes().chunks(size) {
+                items.extend(decoder.decode::<u64>(chunk).unwrap());
+            }
+            assert_eq!(vec![12, 345], items);
+            assert_eq!(
+                Some("https://graph.microsoft.com/v1.0/next".to_owned()),
+                decoder.finish().unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn incomplete_or_invalid_page_is_an_error() {
+        let (items, next_link) = decode_in_chunks(&PAGE.as_bytes()[..PAGE.len() - 5], 16);
+        assert_eq!(2, items.len());
+        assert!(next_link.is_err());
+
+        let mut decoder = PageDecoder::new();
+        assert!(decoder.decode::<Value>(b"[1, 2]").is_err());
+    }
+}
@@ -0,0 +1,458 @@
+use crate::api_version::ApiVersion;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use graph_error::{GraphFailure, GraphResult};
+use http::Method;
+use url::Url;
+
+const USER_READ: &[&str] = &[
+    "User.ReadBasic.All",
+    "User.Read.All",
+    "User.ReadWrite.All",
+    "Directory.Read.All",
+    "Directory.ReadWrite.All",
+    "Directory.AccessAsUser.All",
+];
+const USER_READ_APP: &[&str] = &[
+    "User.Read.All",
+    "User.ReadWrite.All",
+    "Directory.Read.All",
+    "Directory.ReadWrite.All",
+];
+const USER_WRITE: &[&str] = &[
+    "User.ReadWrite.All",
+    "Directory.ReadWrite.All",
+    "Directory.AccessAsUser.All",
+];
+const USER_WRITE_APP: &[&str] = &["User.ReadWrite.All", "Directory.ReadWrite.All"];
+const MAIL_READ: &[&str] = &["Mail.ReadBasic", "Mail.Read", "Mail.ReadWrite"];
+const MAIL_READ_SHARED: &[&str] = &[
+    "Mail.ReadBasic",
+    "Mail.Read",
+    "Mail.ReadWrite",
+    "Mail.Read.Shared",
+    "Mail.ReadWrite.Shared",
+];
+const MAIL_READ_APP: &[&str] = &["Mail.ReadBasic.All", "Mail.Read", "Mail.ReadWrite"];
+const MAIL_SEND: &[&str] = &["Mail.Send"];
+const MAIL_SEND_SHARED: &[&str] = &["Mail.Send", "Mail.Send.Shared"];
+const CALENDARS_READ: &[&str] = &[
+    "Calendars.ReadBasic",
+    "Calendars.Read",
+    "Calendars.ReadWrite",
+];
+const CALENDARS_READ_SHARED: &[&str] = &[
+    "Calendars.ReadBasic",
+    "Calendars.Read",
+    "Calendars.ReadWrite",
+    "Calendars.Read.Shared",
+    "Calendars.ReadWrite.Shared",
+];
+const CALENDARS_WRITE: &[&str] = &["Calendars.ReadWrite"];
+const CALENDARS_WRITE_SHARED: &[&str] = &["Calendars.ReadWrite", "Calendars.ReadWrite.Shared"];
+const CONTACTS_READ: &[&str] = &["Contacts.Read", "Contacts.ReadWrite"];
+const CONTACTS_READ_SHARED: &[&str] = &[
+    "Contacts.Read",
+    "Contacts.ReadWrite",
+    "Contacts.Read.Shared",
+    "Contacts.ReadWrite.Shared",
+];
+const FILES_READ: &[&str] = &[
+    "Files.Read",
+    "Files.ReadWrite",
+    "Files.Read.All",
+    "Files.ReadWrite.All",
+    "Sites.Read.All",
+    "Sites.ReadWrite.All",
+];
+const FILES_READ_APP: &[&str] = &[
+    "Files.Read.All",
+    "Files.ReadWrite.All",
+    "Sites.Read.All",
+    "Sites.ReadWrite.All",
+];
+const FILES_WRITE: &[&str] = &[
+    "Files.ReadWrite",
+    "Files.ReadWrite.All",
+    "Sites.ReadWrite.All",
+];
+const FILES_WRITE_APP: &[&str] = &["Files.ReadWrite.All", "Sites.ReadWrite.All"];
+const GROUP_READ: &[&str] = &[
+    "GroupMember.Read.All",
+    "GroupMember.ReadWrite.All",
+    "Group.Read.All",
+    "Group.ReadWrite.All",
+    "Directory.Read.All",
+    "Directory.ReadWrite.All",
+    "Directory.AccessAsUser.All",
+];
+const GROUP_WRITE: &[&str] = &[
+    "Group.ReadWrite.All",
+    "Directory.ReadWrite.All",
+    "Directory.AccessAsUser.All",
+];
+const SITES_READ: &[&str] = &[
+    "Sites.Read.All",
+    "Sites.ReadWrite.All",
+    "Sites.Manage.All",
+    "Sites.FullControl.All",
+];
+const TEAMS_READ: &[&str] = &[
+    "Team.ReadBasic.All",
+    "TeamSettings.Read.All",
+    "TeamSettings.ReadWrite.All",
+    "User.Read.All",
+    "User.ReadWrite.All",
+    "Directory.Read.All",
+    "Directory.ReadWrite.All",
+];
+const CHANNELS_READ: &[&str] = &[
+    "Channel.ReadBasic.All",
+    "ChannelSettings.Read.All",
+    "ChannelSettings.ReadWrite.All",
+    "Group.Read.All",
+    "Group.ReadWrite.All",
+    "Directory.Read.All",
+    "Directory.ReadWrite.All",
+];
+const APPLICATIONS_READ: &[&str] = &[
+    "Application.Read.All",
+    "Application.ReadWrite.All",
+    "Directory.Read.All",
+    "Directory.ReadWrite.All",
+    "Directory.AccessAsUser.All",
+];
+const ORGANIZATION_READ: &[&str] = &[
+    "User.Read",
+    "Organization.Read.All",
+    "Organization.ReadWrite.All",
+    "Directory.Read.All",
+    "Directory.ReadWrite.All",
+    "Directory.AccessAsUser.All",
+];
+const ORGANIZATION_READ_APP: &[&str] = &[
+    "Organization.Read.All",
+    "Organization.ReadWrite.All",
+    "Directory.Read.All",
+    "Directory.ReadWrite.All",
+];
+const TASKS_READ: &[&str] = &["Tasks.Read", "Tasks.ReadWrite"];
+const NOTES_READ: &[&str] = &[
+    "Notes.Read",
+    "Notes.ReadWrite",
+    "Notes.Read.All",
+    "Notes.ReadWrite.All",
+];
+const NONE: &[&str] = &[];
+
+/// The permissions of which at least one is required to call an endpoint of the
+/// Microsoft Graph API. The least privileged permission is listed first.
+///
+/// The permissions are a superset of the permissions in the permissions reference,
+/// including higher privileged permissions, so that a request is not rejected for a
+/// token that the API would accept. Endpoints that are not listed are not checked.
+///
+/// See [Microsoft Graph permissions reference](https://learn.microsoft.com/en-us/graph/permissions-reference)
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RequiredPermissions {
+    pub method: Method,
+    /// The path of the endpoint without the api version. Segments in braces, such as
+    /// `{id}`, match any segment.
+    pub path: &'static str,
+    /// Permissions for tokens of a signed in user (the scp claim).
+    pub delegated: &'static [&'static str],
+    /// Permissions for tokens of an application without a signed in user (the roles claim).
+    /// Empty if the endpoint does not support application permissions.
+    pub application: &'static [&'static str],
+}
+
+macro_rules! required {
+    ($method:ident, $path:literal, $delegated:expr, $application:expr) => {
+        RequiredPermissions {
+            method: Method::$method,
+            path: $path,
+            delegated: $delegated,
+            application: $application,
+        }
+    };
+}
+
+static REQUIRED_PERMISSIONS: &[RequiredPermissions] = &[
+    required!(
+        GET,
+        "/me",
+        &[
+            "User.Read",
+            "User.ReadWrite",
+            "User.ReadBasic.All",
+            "User.Read.All",
+            "User.ReadWrite.All",
+            "Directory.Read.All",
+            "Directory.ReadWrite.All",
+            "Directory.AccessAsUser.All"
+        ],
+        NONE
+    ),
+    required!(GET, "/users", USER_READ, USER_READ_APP),
+    required!(POST, "/users", USER_WRITE, USER_WRITE_APP),
+    required!(GET, "/users/{id}", USER_READ, USER_READ_APP),
+    required!(PATCH, "/users/{id}", USER_WRITE, USER_WRITE_APP),
+    required!(
+        DELETE,
+        "/users/{id}",
+        &["User.ReadWrite.All", "Directory.AccessAsUser.All"],
+        &["User.ReadWrite.All"]
+    ),
+    required!(GET, "/me/messages", MAIL_READ, NONE),
+    required!(GET, "/me/messages/{id}", MAIL_READ, NONE),
+    required!(GET, "/users/{id}/messages", MAIL_READ_SHARED, MAIL_READ_APP),
+    required!(
+        GET,
+        "/users/{id}/messages/{id}",
+        MAIL_READ_SHARED,
+        MAIL_READ_APP
+    ),
+    required!(POST, "/me/sendMail", MAIL_SEND, NONE),
+    required!(POST, "/users/{id}/sendMail", MAIL_SEND_SHARED, MAIL_SEND),
+    required!(GET, "/me/events", CALENDARS_READ, NONE),
+    required!(POST, "/me/events", CALENDARS_WRITE, NONE),
+    required!(
+        GET,
+        "/users/{id}/events",
+        CALENDARS_READ_SHARED,
+        CALENDARS_READ
+    ),
+    required!(
+        POST,
+        "/users/{id}/events",
+        CALENDARS_WRITE_SHARED,
+        CALENDARS_WRITE
+    ),
+    required!(GET, "/me/contacts", CONTACTS_READ, NONE),
+    required!(
+        GET,
+        "/users/{id}/contacts",
+        CONTACTS_READ_SHARED,
+        CONTACTS_READ
+    ),
+    required!(GET, "/me/drive", FILES_READ, NONE),
+    required!(GET, "/me/drive/root/children", FILES_READ, NONE),
+    required!(GET, "/me/drive/items/{id}", FILES_READ, NONE),
+    required!(DELETE, "/me/drive/items/{id}", FILES_WRITE, NONE),
+    required!(GET, "/drives/{id}", FILES_READ, FILES_READ_APP),
+    required!(GET, "/drives/{id}/items/{id}", FILES_READ, FILES_READ_APP),
+    required!(
+        GET,
+        "/drives/{id}/items/{id}/children",
+        FILES_READ,
+        FILES_READ_APP
+    ),
+    required!(
+        DELETE,
+        "/drives/{id}/items/{id}",
+        FILES_WRITE,
+        FILES_WRITE_APP
+    ),
+    required!(GET, "/groups", GROUP_READ, GROUP_READ),
+    required!(POST, "/groups", GROUP_WRITE, GROUP_WRITE),
+    required!(GET, "/groups/{id}", GROUP_READ, GROUP_READ),
+    required!(GET, "/groups/{id}/members", GROUP_READ, GROUP_READ),
+    required!(GET, "/sites/{id}", SITES_READ, SITES_READ),
+    required!(GET, "/sites/{id}/lists", SITES_READ, SITES_READ),
+    required!(GET, "/me/joinedTeams", TEAMS_READ, NONE),
+    required!(GET, "/teams/{id}/channels", CHANNELS_READ, CHANNELS_READ),
+    required!(GET, "/applications", APPLICATIONS_READ, APPLICATIONS_READ),
+    required!(
+        GET,
+        "/organization",
+        ORGANIZATION_READ,
+        ORGANIZATION_READ_APP
+    ),
+    required!(GET, "/me/todo/lists", TASKS_READ, NONE),
+    required!(GET, "/me/onenote/notebooks", NOTES_READ, NONE),
+];
+
+impl RequiredPermissions {
+    /// Find the required permissions for a request to the url. Returns None
+    /// for endpoints that are not known.
+    ///
+    /// The path is matched after the api version segment so that a base url with
+    /// a path, such as a proxy, is supported.
+    pub fn find(method: &Method, url: &Url) -> Option<&'static RequiredPermissions> {
+        let segments: Vec<&str> = url
+            .path_segments()?
+            .skip_while(|segment| segment.parse::<ApiVersion>().is_err())
+            .skip(1)
+            .collect();
+        if segments.is_empty() {
+            return None;
+        }
+        REQUIRED_PERMISSIONS.iter().find(|required| {
+            required.method.eq(method)
+                && RequiredPermissions::path_matches(required.path, &segments)
+        })
+    }
+
+    fn path_matches(path: &str, segments: &[&str]) -> bool {
+        let template: Vec<&str> = path.trim_start_matches('/').split('/').collect();
+        template.len() == segments.len()
+            && template
+                .iter()
+                .zip(segments.iter())
+                .all(|(expected, segment)| {
+                    (expected.starts_with('{') && !segment.is_empty())
+                        || expected.eq_ignore_ascii_case(segment)
+                })
+    }
+
+    /// Returns true if any of the granted permissions is one of the delegated
+    /// or application permissions.
+    pub fn is_satisfied_by(&self, granted: &GrantedPermissions) -> bool {
+        let matches_any = |required: &[&str], granted: &[String]| {
+            granted.iter().any(|permission| {
+                required
+                    .iter()
+                    .any(|required| required.eq_ignore_ascii_case(permission))
+            })
+        };
+        matches_any(self.delegated, &granted.scopes)
+            || matches_any(self.application, &granted.roles)
+    }
+}
+
+/// The delegated permissions, the scp claim, and application permissions, the roles claim,
+/// of an access token.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GrantedPermissions {
+    pub scopes: Vec<String>,
+    pub roles: Vec<String>,
+}
+
+impl GrantedPermissions {
+    /// Read the permissions from the claims of the access token without validating the
+    /// token. Returns None if the token is not a JWT.
+    pub fn from_access_token(access_token: &str) -> Option<GrantedPermissions> {
+        #[derive(Deserialize)]
+        struct PermissionClaims {
+            #[serde(default)]
+            scp: Option<String>,
+            #[serde(default)]
+            roles: Vec<String>,
+        }
+
+        let payload = access_token.split('.').nth(1)?;
+        let bytes = URL_SAFE_NO_PAD.decode(payload.trim_end_matches('=')).ok()?;
+        let claims: PermissionClaims = serde_json::from_slice(&bytes).ok()?;
+        Some(GrantedPermissions {
+            scopes: claims
+                .scp
+                .unwrap_or_default()
+                .split_whitespace()
+                .map(|scope| scope.to_owned())
+                .collect(),
+            roles: claims.roles,
+        })
+    }
+}
+
+/// Check the permissions of the access token for the request. Requests to unknown
+/// endpoints and tokens that are not a JWT are not checked.
+pub(crate) fn check_permissions(method: &Method, url: &Url, access_token: &str) -> GraphResult<()> {
+    let required = match RequiredPermissions::find(method, url) {
+        Some(required) => required,
+        None => return Ok(()),
+    };
+    let granted = match GrantedPermissions::from_access_token(access_token) {
+        Some(granted) => granted,
+        None => return Ok(()),
+    };
+
+    if required.is_satisfied_by(&granted) {
+        return Ok(());
+    }
+
+    let required_permissions = if granted.scopes.is_empty() {
+        required.application
+    } else {
+        required.delegated
+    };
+    if required_permissions.is_empty() {
+        return Err(GraphFailure::DelegatedOnlyEndpoint {
+            url: Some(url.clone()),
+        });
+    }
+    Err(GraphFailure::MissingPermission {
+        url: Some(url.clone()),
+        required: required_permissions.iter().map(|p| p.to_string()).collect(),
+        granted: granted.scopes.into_iter().chain(granted.roles).collect(),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn access_token(claims: serde_json::Value) -> String {
+        format!(
+            "eyJhbGciOiJSUzI1NiJ9.{}.signature",
+            URL_SAFE_NO_PAD.encode(claims.to_string())
+        )
+    }
+
+    #[test]
+    fn find_required_permissions() {
+        let url =
+            Url::parse("https://graph.microsoft.com/v1.0/users/user@contoso.com/messages").unwrap();
+        let required = RequiredPermissions::find(&Method::GET, &url).unwrap();
+        assert_eq!("/users/{id}/messages", required.path);
+        assert_eq!("Mail.ReadBasic", required.delegated[0]);
+
+        let url = Url::parse("https://graph.microsoft.com/beta/me/unknownEndpoint").unwrap();
+        assert!(RequiredPermissions::find(&Method::GET, &url).is_none());
+    }
+
+    #[test]
+    fn find_required_permissions_with_base_url_path() {
+        let url = Url::parse("https://proxy.contoso.com/graph/v1.0/me/messages").unwrap();
+        let required = RequiredPermissions::find(&Method::GET, &url).unwrap();
+        assert_eq!("/me/messages", required.path);
+
+        let url = Url::parse("https://proxy.contoso.com/graph/me/messages").unwrap();
+        assert!(RequiredPermissions::find(&Method::GET, &url).is_none());
+    }
+
+    #[test]
+    fn check_token_permissions() {
+        let url = Url::parse("https://graph.microsoft.com/v1.0/me/messages").unwrap();
+        let token = access_token(serde_json::json!({ "scp": "User.Read Mail.Read" }));
+        assert!(check_permissions(&Method::GET, &url, &token).is_ok());
+
+        let token = access_token(serde_json::json!({ "scp": "User.Read" }));
+        let err = check_permissions(&Method::GET, &url, &token).unwrap_err();
+        assert!(matches!(err, GraphFailure::MissingPermission { .. }));
+
+        // Application tokens can't use /me.
+        let token = access_token(serde_json::json!({ "roles": ["Mail.Read"] }));
+        let err = check_permissions(&Method::GET, &url, &token).unwrap_err();
+        assert!(matches!(err, GraphFailure::DelegatedOnlyEndpoint { .. }));
+
+        assert!(check_permissions(&Method::GET, &url, "opaque").is_ok());
+    }
+
+    #[test]
+    fn higher_privileged_permissions_are_accepted() {
+        let url = Url::parse("https://graph.microsoft.com/v1.0/organization").unwrap();
+        let token = access_token(serde_json::json!({ "scp": "User.Read" }));
+        assert!(check_permissions(&Method::GET, &url, &token).is_ok());
+
+        let url = Url::parse("https://graph.microsoft.com/v1.0/users/user-id").unwrap();
+        let token = access_token(serde_json::json!({ "scp": "Directory.AccessAsUser.All" }));
+        assert!(check_permissions(&Method::PATCH, &url, &token).is_ok());
+
+        let url = Url::parse("https://graph.microsoft.com/v1.0/teams/team-id/channels").unwrap();
+        let token = access_token(serde_json::json!({ "roles": ["Group.Read.All"] }));
+        assert!(check_permissions(&Method::GET, &url, &token).is_ok());
+        let token = access_token(serde_json::json!({ "scp": "Directory.Read.All" }));
+        assert!(check_permissions(&Method::GET, &url, &token).is_ok());
+    }
+}
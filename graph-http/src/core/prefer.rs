@@ -0,0 +1,203 @@
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, InvalidHeaderValue};
+
+pub const PREFER: HeaderName = HeaderName::from_static("prefer");
+pub const PREFERENCE_APPLIED: HeaderName = HeaderName::from_static("preference-applied");
+pub const CONSISTENCY_LEVEL: HeaderName = HeaderName::from_static("consistencylevel");
+
+/// The format of the body of Outlook messages and events returned by the API.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum BodyContentType {
+    Text,
+    Html,
+}
+
+impl BodyContentType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BodyContentType::Text => "text",
+            BodyContentType::Html => "html",
+        }
+    }
+}
+
+/// Builder for the `Prefer` request header.
+///
+/// The header can be set for a single request using `RequestHandler::prefer` or for
+/// every request of a client using `GraphClientConfiguration::prefer`. The preferences
+/// honored by the API are returned in the `Preference-Applied` response header which
+/// can be read using [preference_applied].
+///
+/// # Example
+/// ```rust
+/// use graph_http::api_impl::{BodyContentType, Prefer};
+///
+/// let prefer = Prefer::new()
+///     .outlook_timezone("Pacific Standard Time")
+///     .outlook_body_content_type(BodyContentType::Text)
+///     .max_page_size(50);
+///
+/// assert_eq!(
+///     "outlook.timezone=\"Pacific Standard Time\", outlook.body-content-type=\"text\", odata.maxpagesize=50",
+///     prefer.to_string()
+/// );
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Prefer {
+    preferences: Vec<String>,
+}
+
+impl Prefer {
+    pub fn new() -> Prefer {
+        Prefer::default()
+    }
+
+    /// Return dates and times of Outlook resources in the given time zone.
+    ///
+    /// Backslashes and quotes in the time zone are escaped.
+    pub fn outlook_timezone(self, time_zone: impl AsRef<str>) -> Prefer {
+        let time_zone = time_zone
+            .as_ref()
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"");
+        self.preference(format!("outlook.timezone=\"{time_zone}\""))
+    }
+
+    /// Return the body of Outlook messages and events as text or html.
+    pub fn outlook_body_content_type(self, content_type: BodyContentType) -> Prefer {
+        self.preference(format!(
+            "outlook.body-content-type=\"{}\"",
+            content_type.as_str()
+        ))
+    }
+
+    /// Return the created or updated resource in the response.
+    pub fn return_representation(self) -> Prefer {
+        self.preference("return=representation")
+    }
+
+    /// Return an empty response with a 204 No Content status for create and update
+    /// requests.
+    pub fn return_minimal(self) -> Prefer {
+        self.preference("return=minimal")
+    }
+
+    /// The maximum number of items returned in each page of a collection.
+    pub fn max_page_size(self, max_page_size: u32) -> Prefer {
+        self.preference(format!("odata.maxpagesize={max_page_size}"))
+    }
+
+    /// Any other preference such as `respond-async` or `IdType="ImmutableId"`.
+    pub fn preference(mut self, preference: impl Into<String>) -> Prefer {
+        self.preferences.push(preference.into());
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.preferences.is_empty()
+    }
+
+    pub fn header_value(&self) -> Result<HeaderValue, InvalidHeaderValue> {
+        HeaderValue::from_str(self.to_string().as_str())
+    }
+}
+
+impl std::fmt::Display for Prefer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.preferences.join(", ").as_str())
+    }
+}
+
+/// Add a preference to the `Prefer` header keeping the preferences already in the header,
+/// such as the preferences set for the client.
+pub(crate) fn add_preference(
+    headers: &mut HeaderMap,
+    preference: &str,
+) -> Result<(), InvalidHeaderValue> {
+    let value = match headers.get(PREFER).and_then(|value| value.to_str().ok()) {
+        Some(preferences) if !preferences.trim().is_empty() => {
+            HeaderValue::from_str(format!("{preferences}, {preference}").as_str())?
+        }
+        _ => HeaderValue::from_str(preference)?,
+    };
+    headers.insert(PREFER, value);
+    Ok(())
+}
+
+/// The preferences of the `Prefer` request header that were honored by the API
+/// as returned in the `Preference-Applied` response header.
+pub fn preference_applied(headers: &HeaderMap) -> Vec<String> {
+    let mut preferences = Vec::new();
+    for value in headers.get_all(PREFERENCE_APPLIED) {
+        let Ok(value) = value.to_str() else {
+            continue;
+        };
+
+        let mut preference = String::new();
+        let mut quoted = false;
+        for c in value.chars() {
+            match c {
+                '"' => {
+                    quoted = !quoted;
+                    preference.push(c);
+                }
+                ',' if !quoted => {
+                    preferences.push(preference.trim().to_owned());
+                    preference.clear();
+                }
+                _ => preference.push(c),
+            }
+        }
+        preferences.push(preference.trim().to_owned());
+    }
+    preferences.retain(|preference| !preference.is_empty());
+    preferences
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn preference_applied_header() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            PREFERENCE_APPLIED,
+            HeaderValue::from_static("outlook.timezone=\"Tokyo, Osaka\", odata.maxpagesize=10"),
+        );
+
+        assert_eq!(
+            vec![
+                "outlook.timezone=\"Tokyo, Osaka\"".to_string(),
+                "odata.maxpagesize=10".to_string()
+            ],
+            preference_applied(&headers)
+        );
+        assert!(preference_applied(&HeaderMap::new()).is_empty());
+    }
+
+    #[test]
+    fn outlook_timezone_is_escaped() {
+        let prefer = Prefer::new().outlook_timezone("Custom \"Zone\" \\ 1");
+        assert_eq!(
+            "outlook.timezone=\"Custom \\\"Zone\\\" \\\\ 1\"",
+            prefer.to_string()
+        );
+    }
+
+    #[test]
+    fn add_preference_keeps_existing_preferences() {
+        let mut headers = HeaderMap::new();
+        add_preference(&mut headers, "odata.maxpagesize=10").unwrap();
+        assert_eq!("odata.maxpagesize=10", headers[PREFER].to_str().unwrap());
+
+        headers.insert(
+            PREFER,
+            HeaderValue::from_static("outlook.timezone=\"Pacific Standard Time\""),
+        );
+        add_preference(&mut headers, "odata.maxpagesize=10").unwrap();
+        assert_eq!(
+            "outlook.timezone=\"Pacific Standard Time\", odata.maxpagesize=10",
+            headers[PREFER].to_str().unwrap()
+        );
+    }
+}
@@ -0,0 +1,247 @@
+use std::fmt::{Display, Formatter};
+
+use http::header::{AUTHORIZATION, CONTENT_TYPE};
+use http::{HeaderMap, HeaderName, HeaderValue, Method};
+use url::Url;
+
+use crate::internal::BodyRead;
+
+const REDACTED: &str = "[REDACTED]";
+
+/// Headers whose values are never shown.
+const SECRET_HEADERS: [&str; 5] = [
+    "authorization",
+    "proxy-authorization",
+    "cookie",
+    "set-cookie",
+    "x-api-key",
+];
+
+/// Query parameters whose values are replaced, such as the signature of
+/// pre-authenticated download urls.
+const SECRET_QUERY_PARAMETERS: [&str; 6] = [
+    "access_token",
+    "client_secret",
+    "code",
+    "sig",
+    "tempauth",
+    "token",
+];
+
+/// Json properties whose values are replaced in request and response bodies.
+const SECRET_PROPERTIES: [&str; 9] = [
+    "access_token",
+    "refresh_token",
+    "id_token",
+    "client_secret",
+    "secrettext",
+    "password",
+    "passwordprofile",
+    "@microsoft.graph.downloadurl",
+    "@content.downloadurl",
+];
+
+/// The value of a header, or `[REDACTED]` for authorization and cookie headers.
+pub(crate) fn redact_header(name: &HeaderName, value: &HeaderValue) -> String {
+    if SECRET_HEADERS.contains(&name.as_str()) {
+        REDACTED.to_owned()
+    } else {
+        String::from_utf8_lossy(value.as_bytes()).into_owned()
+    }
+}
+
+/// The url with the values of secret query parameters replaced.
+pub(crate) fn redact_url(url: &Url) -> Url {
+    let mut url = url.clone();
+    let has_secret = url
+        .query_pairs()
+        .any(|(key, _)| SECRET_QUERY_PARAMETERS.contains(&key.to_lowercase().as_str()));
+    if !has_secret {
+        return url;
+    }
+
+    let query_pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(key, value)| {
+            if SECRET_QUERY_PARAMETERS.contains(&key.to_lowercase().as_str()) {
+                (key.into_owned(), REDACTED.to_owned())
+            } else {
+                (key.into_owned(), value.into_owned())
+            }
+        })
+        .collect();
+    url.query_pairs_mut().clear().extend_pairs(query_pairs);
+    url
+}
+
+/// A request or response body with the values of secret json properties replaced.
+/// Bodies that are not json are returned unchanged.
+pub(crate) fn redact_text(text: &str) -> String {
+    match serde_json::from_str::<serde_json::Value>(text) {
+        Ok(mut value) => {
+            redact_json(&mut value);
+            value.to_string()
+        }
+        Err(_) => text.to_owned(),
+    }
+}
+
+fn redact_json(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if SECRET_PROPERTIES.contains(&key.to_lowercase().as_str()) {
+                    *value = serde_json::Value::String(REDACTED.to_owned());
+                } else {
+                    redact_json(value);
+                }
+            }
+        }
+        serde_json::Value::Array(values) => values.iter_mut().for_each(redact_json),
+        _ => {}
+    }
+}
+
+/// A redacted representation of a request used for debugging, such as to paste the
+/// request into Graph Explorer or to share it in a bug report.
+///
+/// The access token is never included because it is only added when the request is
+/// sent. The values of secret headers, query parameters, and json properties such as
+/// passwords are replaced with `[REDACTED]`. Binary and streamed bodies are not read.
+///
+/// The [Display] implementation writes the request as HTTP text and
+/// [RequestDump::to_curl] returns a curl command.
+///
+/// # Example
+/// ```rust,ignore
+/// let request_dump = client
+///     .users()
+///     .create_user(&serde_json::json!({ "displayName": "Adele Vance" }))
+///     .dump_request();
+///
+/// println!("{request_dump}");
+/// println!("{}", request_dump.to_curl());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RequestDump {
+    pub method: Method,
+    pub url: Url,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<String>,
+}
+
+impl RequestDump {
+    pub(crate) fn new(
+        method: &Method,
+        url: &Url,
+        headers: &HeaderMap,
+        body: Option<&BodyRead>,
+    ) -> RequestDump {
+        let mut dump_headers = vec![(
+            AUTHORIZATION.as_str().to_owned(),
+            format!("Bearer {REDACTED}"),
+        )];
+        for (name, value) in headers.iter() {
+            if name == AUTHORIZATION {
+                continue;
+            }
+            dump_headers.push((name.as_str().to_owned(), redact_header(name, value)));
+        }
+
+        if let Some(body) = body {
+            // The same content type that is set when the request is sent.
+            let content_type = match body.content_type() {
+                Some(content_type) => Some(String::from_utf8_lossy(content_type.as_bytes())),
+                None if body.has_byte_buf() => Some("application/octet-stream".into()),
+                None if body.has_string_buf() => Some("application/json".into()),
+                None => None,
+            };
+            if let Some(content_type) = content_type {
+                if !headers.contains_key(CONTENT_TYPE) {
+                    dump_headers
+                        .push((CONTENT_TYPE.as_str().to_owned(), content_type.into_owned()));
+                }
+            }
+        }
+
+        RequestDump {
+            method: method.clone(),
+            url: redact_url(url),
+            headers: dump_headers,
+            body: body.map(|body| match body.text() {
+                Some(text) => redact_text(text),
+                None => "<binary body not shown>".to_owned(),
+            }),
+        }
+    }
+
+    /// The request as a curl command.
+    pub fn to_curl(&self) -> String {
+        let quote = |value: &str| format!("'{}'", value.replace('\'', r"'\''"));
+
+        let mut command = format!("curl -X {} {}", self.method, quote(self.url.as_str()));
+        for (name, value) in self.headers.iter() {
+            command.push_str(" -H ");
+            command.push_str(&quote(&format!("{name}: {value}")));
+        }
+        if let Some(body) = self.body.as_ref() {
+            command.push_str(" --data-raw ");
+            command.push_str(&quote(body));
+        }
+        command
+    }
+}
+
+impl Display for RequestDump {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{} {} HTTP/1.1", self.method, self.url)?;
+        for (name, value) in self.headers.iter() {
+            writeln!(f, "{name}: {value}")?;
+        }
+        if let Some(body) = self.body.as_ref() {
+            writeln!(f)?;
+            writeln!(f, "{body}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn request_dump_is_redacted() {
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, HeaderValue::from_static("Bearer token"));
+        headers.insert("consistencylevel", HeaderValue::from_static("eventual"));
+        let url = Url::parse(
+            "https://graph.microsoft.com/v1.0/users?$filter=displayName eq 'Adele'&tempauth=secret",
+        )
+        .unwrap();
+        let body = BodyRead::new(
+            serde_json::json!({
+                "displayName": "Adele Vance",
+                "passwordProfile": { "password": "password" }
+            })
+            .to_string(),
+        );
+
+        let request_dump = RequestDump::new(&Method::POST, &url, &headers, Some(&body));
+        let dump = request_dump.to_string();
+        assert!(dump.starts_with("POST https://graph.microsoft.com/v1.0/users?"));
+        assert!(dump.contains("authorization: Bearer [REDACTED]"));
+        assert!(dump.contains("consistencylevel: eventual"));
+        assert!(dump.contains("content-type: application/json"));
+        assert!(dump.contains("tempauth=%5BREDACTED%5D"));
+        assert!(dump.contains("Adele Vance"));
+        assert!(!dump.contains("secret"));
+        assert!(!dump.contains("\"password\""));
+        assert!(!dump.contains("token"));
+
+        let curl = request_dump.to_curl();
+        assert!(curl.starts_with("curl -X POST 'https://graph.microsoft.com/v1.0/users?"));
+        assert!(curl.contains("%27Adele%27"));
+        assert!(curl.contains(" --data-raw '{"));
+    }
+}
@@ -0,0 +1,195 @@
+use futures_util::future::BoxFuture;
+use reqwest::{Request, Response};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use tokio::sync::Semaphore;
+
+#[derive(Debug)]
+struct HostLimit {
+    semaphore: Arc<Semaphore>,
+    queued: AtomicUsize,
+}
+
+/// Decrements the queue depth when the request leaves the queue, including when the
+/// request future is dropped while waiting.
+struct Queued<'a>(&'a AtomicUsize);
+
+impl Drop for Queued<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Limits the number of in-flight requests per host for all clones of a client.
+/// Requests over the limit wait in a first in, first out queue.
+///
+/// Use [RequestLimiter::queue_depth] to monitor how many requests are waiting.
+///
+/// # Example
+/// ```rust,ignore
+/// let client = GraphClient::from(
+///     GraphClientConfiguration::new()
+///         .access_token("ACCESS_TOKEN")
+///         .concurrency_limit_per_host(Some(4)),
+/// );
+///
+/// if let Some(limiter) = client.request_limiter() {
+///     println!("queued requests: {}", limiter.queue_depth());
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct RequestLimiter {
+    limit: usize,
+    hosts: Arc<Mutex<HashMap<String, Arc<HostLimit>>>>,
+}
+
+impl RequestLimiter {
+    pub fn new(limit: usize) -> RequestLimiter {
+        RequestLimiter {
+            limit: limit.max(1),
+            hosts: Default::default(),
+        }
+    }
+
+    /// The maximum number of in-flight requests per host.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    fn host_limit(&self, host: &str) -> Arc<HostLimit> {
+        let mut hosts = self.hosts.lock().unwrap_or_else(|err| err.into_inner());
+        hosts
+            .entry(host.to_owned())
+            .or_insert_with(|| {
+                Arc::new(HostLimit {
+                    semaphore: Arc::new(Semaphore::new(self.limit)),
+                    queued: AtomicUsize::new(0),
+                })
+            })
+            .clone()
+    }
+
+    /// The number of requests waiting for all hosts.
+    pub fn queue_depth(&self) -> usize {
+        let hosts = self.hosts.lock().unwrap_or_else(|err| err.into_inner());
+        hosts
+            .values()
+            .map(|host| host.queued.load(Ordering::SeqCst))
+            .sum()
+    }
+
+    /// The number of requests waiting for the host.
+    pub fn queue_depth_for_host(&self, host: &str) -> usize {
+        let hosts = self.hosts.lock().unwrap_or_else(|err| err.into_inner());
+        hosts
+            .get(host)
+            .map(|host| host.queued.load(Ordering::SeqCst))
+            .unwrap_or_default()
+    }
+
+    /// The number of requests in-flight for the host.
+    pub fn in_flight_for_host(&self, host: &str) -> usize {
+        let hosts = self.hosts.lock().unwrap_or_else(|err| err.into_inner());
+        hosts
+            .get(host)
+            .map(|host| self.limit - host.semaphore.available_permits())
+            .unwrap_or_default()
+    }
+}
+
+/// Waits for a permit of the [RequestLimiter] for the host of the request before
+/// sending it. The permit is held until the response headers are received.
+#[derive(Clone)]
+pub(crate) struct RequestLimiterService<S> {
+    inner: S,
+    limiter: RequestLimiter,
+}
+
+impl<S> RequestLimiterService<S> {
+    pub(crate) fn new(inner: S, limiter: RequestLimiter) -> Self {
+        RequestLimiterService { inner, limiter }
+    }
+}
+
+impl<S> tower::Service<Request> for RequestLimiterService<S>
+where
+    S: tower::Service<Request, Response = Response, Error = tower::BoxError>
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = tower::BoxError;
+    type Future = BoxFuture<'static, Result<Response, tower::BoxError>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request) -> Self::Future {
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let host_limit = self
+            .limiter
+            .host_limit(req.url().host_str().unwrap_or_default());
+
+        Box::pin(async move {
+            let permit = {
+                host_limit.queued.fetch_add(1, Ordering::SeqCst);
+                let _queued = Queued(&host_limit.queued);
+                host_limit.semaphore.clone().acquire_owned().await?
+            };
+            let response = inner.call(req).await;
+            drop(permit);
+            response
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use tower::{Service, ServiceExt};
+
+    #[tokio::test]
+    async fn requests_wait_for_permit() {
+        let limiter = RequestLimiter::new(1);
+        let (release, released) = tokio::sync::oneshot::channel::<()>();
+        let released = Arc::new(tokio::sync::Mutex::new(Some(released)));
+
+        let transport = tower::service_fn(move |_req: Request| {
+            let released = released.clone();
+            async move {
+                if let Some(released) = released.lock().await.take() {
+                    let _ = released.await;
+                }
+                Ok::<_, tower::BoxError>(Response::from(http::Response::new("")))
+            }
+        });
+        let mut service = RequestLimiterService::new(transport, limiter.clone());
+
+        let request = || {
+            Request::new(
+                reqwest::Method::GET,
+                url::Url::parse("https://graph.microsoft.com/v1.0/me").unwrap(),
+            )
+        };
+        let first = service.ready().await.unwrap().call(request());
+        let second = service.ready().await.unwrap().call(request());
+        let first = tokio::spawn(first);
+        let second = tokio::spawn(second);
+
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        assert_eq!(1, limiter.in_flight_for_host("graph.microsoft.com"));
+        assert_eq!(1, limiter.queue_depth_for_host("graph.microsoft.com"));
+
+        release.send(()).unwrap();
+        first.await.unwrap().unwrap();
+        second.await.unwrap().unwrap();
+        assert_eq!(0, limiter.queue_depth());
+        assert_eq!(0, limiter.in_flight_for_host("graph.microsoft.com"));
+    }
+}
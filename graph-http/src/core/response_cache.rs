@@ -0,0 +1,622 @@
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use base64::Engine;
+use futures_util::future::BoxFuture;
+use reqwest::header::{
+    HeaderMap, HeaderName, HeaderValue, AUTHORIZATION, ETAG, IF_MATCH, IF_MODIFIED_SINCE,
+    IF_NONE_MATCH, IF_UNMODIFIED_SINCE, LAST_MODIFIED,
+};
+use reqwest::{Method, Request, Response, ResponseBuilderExt, StatusCode};
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Debug;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+/// The default maximum number of responses kept by a [MemoryCacheStorage].
+const DEFAULT_MEMORY_CAPACITY: usize = 1000;
+
+/// FNV-1a is used for hashes that are stored because the hash must not change
+/// between versions of Rust.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// A successful response stored by a [ResponseCache] with the validators used to
+/// revalidate it.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CachedResponse {
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl CachedResponse {
+    fn from_parts(status: StatusCode, headers: &HeaderMap, body: &[u8]) -> Option<CachedResponse> {
+        let header = |name: HeaderName| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(String::from)
+        };
+
+        let etag = header(ETAG);
+        let last_modified = header(LAST_MODIFIED);
+        if etag.is_none() && last_modified.is_none() {
+            return None;
+        }
+
+        Some(CachedResponse {
+            etag,
+            last_modified,
+            status: status.as_u16(),
+            headers: headers
+                .iter()
+                .filter_map(|(name, value)| {
+                    Some((name.to_string(), value.to_str().ok()?.to_owned()))
+                })
+                .collect(),
+            body: body.to_vec(),
+        })
+    }
+
+    fn into_response(self, url: url::Url) -> Result<Response, tower::BoxError> {
+        let mut builder = http::Response::builder().status(self.status).url(url);
+        for (name, value) in self.headers.iter() {
+            builder = builder.header(name.as_str(), value.as_str());
+        }
+        Ok(Response::from(builder.body(self.body)?))
+    }
+}
+
+/// Where a [ResponseCache] stores responses. Implement this trait to store responses
+/// somewhere other than memory or disk such as a shared cache.
+///
+/// Storage failures must not fail requests. A response that cannot be read is
+/// requested again and a response that cannot be written is not cached.
+pub trait CacheStorage: Debug + Send + Sync {
+    fn get(&self, key: &str) -> Option<CachedResponse>;
+
+    fn put(&self, key: &str, response: CachedResponse);
+
+    fn remove(&self, key: &str);
+}
+
+#[derive(Debug, Default)]
+struct MemoryResponses {
+    responses: HashMap<String, CachedResponse>,
+    /// Keys from the least to the most recently used.
+    order: VecDeque<String>,
+}
+
+impl MemoryResponses {
+    fn touch(&mut self, key: &str) {
+        self.order.retain(|used| used.ne(key));
+        self.order.push_back(key.to_owned());
+    }
+}
+
+/// Stores responses in memory. The most recently used responses are kept, evicting
+/// the least recently used response when the capacity is reached.
+#[derive(Debug)]
+pub struct MemoryCacheStorage {
+    capacity: usize,
+    responses: Mutex<MemoryResponses>,
+}
+
+impl MemoryCacheStorage {
+    pub fn new() -> MemoryCacheStorage {
+        MemoryCacheStorage::default()
+    }
+
+    /// The maximum number of responses that are kept. Default is 1000.
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, MemoryResponses> {
+        self.responses.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().responses.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for MemoryCacheStorage {
+    fn default() -> Self {
+        MemoryCacheStorage {
+            capacity: DEFAULT_MEMORY_CAPACITY,
+            responses: Default::default(),
+        }
+    }
+}
+
+impl CacheStorage for MemoryCacheStorage {
+    fn get(&self, key: &str) -> Option<CachedResponse> {
+        let mut responses = self.lock();
+        let response = responses.responses.get(key).cloned()?;
+        responses.touch(key);
+        Some(response)
+    }
+
+    fn put(&self, key: &str, response: CachedResponse) {
+        let mut responses = self.lock();
+        if !responses.responses.contains_key(key) {
+            while responses.order.len() >= self.capacity {
+                if let Some(evicted) = responses.order.pop_front() {
+                    responses.responses.remove(&evicted);
+                }
+            }
+        }
+        responses.responses.insert(key.to_owned(), response);
+        responses.touch(key);
+    }
+
+    fn remove(&self, key: &str) {
+        let mut responses = self.lock();
+        responses.responses.remove(key);
+        responses.order.retain(|used| used.ne(key));
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DiskEntry {
+    key: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+/// Stores each response as a json file in a directory so that responses are kept
+/// between runs of the application.
+///
+/// The directory is created when the first response is stored. Response bodies may
+/// contain personal data so on Unix the directory is created readable only by the
+/// current user and each file is written with the same permissions.
+#[derive(Clone, Debug)]
+pub struct DiskCacheStorage {
+    directory: PathBuf,
+}
+
+impl DiskCacheStorage {
+    pub fn new(directory: impl Into<PathBuf>) -> DiskCacheStorage {
+        DiskCacheStorage {
+            directory: directory.into(),
+        }
+    }
+
+    /// The file name is a hash of the key because urls can be longer than file names.
+    fn path(&self, key: &str) -> PathBuf {
+        self.directory
+            .join(format!("{:016x}.json", fnv1a(key.as_bytes())))
+    }
+
+    /// Write to a temporary file first so that a response is never partially written.
+    fn write(&self, key: &str, json: &[u8]) -> std::io::Result<()> {
+        let mut builder = std::fs::DirBuilder::new();
+        builder.recursive(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::DirBuilderExt;
+            builder.mode(0o700);
+        }
+        builder.create(&self.directory)?;
+
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+
+        use std::io::Write;
+        let path = self.path(key);
+        let temp_path = path.with_extension("tmp");
+        let mut file = options.open(&temp_path)?;
+        file.write_all(json)?;
+        std::fs::rename(&temp_path, &path)
+    }
+}
+
+impl CacheStorage for DiskCacheStorage {
+    fn get(&self, key: &str) -> Option<CachedResponse> {
+        let json = std::fs::read(self.path(key)).ok()?;
+        let entry: DiskEntry = serde_json::from_slice(&json).ok()?;
+        if entry.key != key {
+            return None;
+        }
+
+        Some(CachedResponse {
+            etag: entry.etag,
+            last_modified: entry.last_modified,
+            status: entry.status,
+            headers: entry.headers,
+            body: STANDARD.decode(entry.body).ok()?,
+        })
+    }
+
+    fn put(&self, key: &str, response: CachedResponse) {
+        let entry = DiskEntry {
+            key: key.to_owned(),
+            etag: response.etag,
+            last_modified: response.last_modified,
+            status: response.status,
+            headers: response.headers,
+            body: STANDARD.encode(response.body),
+        };
+
+        if let Ok(json) = serde_json::to_vec(&entry) {
+            let _ = self.write(key, &json);
+        }
+    }
+
+    fn remove(&self, key: &str) {
+        let _ = std::fs::remove_file(self.path(key));
+    }
+}
+
+/// Caches successful GET responses that have an `ETag` or `Last-Modified` header and
+/// revalidates them with `If-None-Match` and `If-Modified-Since`. When Microsoft Graph
+/// returns 304 Not Modified the cached response is returned in its place.
+///
+/// Use a cache for resources that are read often but rarely change such as the
+/// directory schema or the root of a drive. Responses are keyed by url and by the
+/// user of the access token so that a response is never returned to another user.
+/// The body of a cached response is read into memory before it is returned so a
+/// cache should not be used with large downloads. Requests that already have a conditional header
+/// are not cached, and a successful request with any other method removes the cached
+/// response of the url.
+///
+/// Clones share the same storage.
+///
+/// # Example
+/// ```rust,ignore
+/// let client = GraphClient::from(
+///     GraphClientConfiguration::new()
+///         .access_token("ACCESS_TOKEN")
+///         .response_cache(ResponseCache::disk("./graph-cache")),
+/// );
+///
+/// // The second request is sent with If-None-Match and returns the cached body
+/// // if the drive has not changed.
+/// let drive = client.me().drive().get_drive().send().await?;
+/// let drive = client.me().drive().get_drive().send().await?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct ResponseCache {
+    storage: Arc<dyn CacheStorage>,
+}
+
+impl ResponseCache {
+    pub fn new<S: CacheStorage + 'static>(storage: S) -> ResponseCache {
+        ResponseCache {
+            storage: Arc::new(storage),
+        }
+    }
+
+    pub fn memory() -> ResponseCache {
+        ResponseCache::new(MemoryCacheStorage::new())
+    }
+
+    pub fn disk(directory: impl Into<PathBuf>) -> ResponseCache {
+        ResponseCache::new(DiskCacheStorage::new(directory))
+    }
+
+    pub fn storage(&self) -> &dyn CacheStorage {
+        self.storage.as_ref()
+    }
+
+    /// The url of the request prefixed by the identity of its access token. The tenant
+    /// and object id claims identify the user of a JWT so that the key does not change
+    /// when the token is refreshed. Other tokens are identified by a hash of the token.
+    fn key(req: &Request) -> String {
+        let url = req.url().as_str();
+        let Some(authorization) = req.headers().get(AUTHORIZATION) else {
+            return url.to_owned();
+        };
+
+        let claims = authorization
+            .to_str()
+            .ok()
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .and_then(|token| token.split('.').nth(1))
+            .and_then(|payload| URL_SAFE_NO_PAD.decode(payload).ok())
+            .and_then(|payload| serde_json::from_slice::<serde_json::Value>(&payload).ok());
+        let claim = |name: &str| {
+            claims
+                .as_ref()
+                .and_then(|claims| claims.get(name))
+                .and_then(|value| value.as_str())
+        };
+
+        match (claim("tid"), claim("oid")) {
+            (Some(tenant_id), Some(object_id)) => format!("{tenant_id}.{object_id} {url}"),
+            _ => format!("{:016x} {url}", fnv1a(authorization.as_bytes())),
+        }
+    }
+
+    fn is_conditional(headers: &HeaderMap) -> bool {
+        [
+            IF_MATCH,
+            IF_NONE_MATCH,
+            IF_MODIFIED_SINCE,
+            IF_UNMODIFIED_SINCE,
+        ]
+        .iter()
+        .any(|name| headers.contains_key(name))
+    }
+}
+
+impl Default for ResponseCache {
+    fn default() -> Self {
+        ResponseCache::memory()
+    }
+}
+
+/// Revalidates the responses of GET requests stored in a [ResponseCache].
+#[derive(Clone)]
+pub(crate) struct ResponseCacheService<S> {
+    inner: S,
+    cache: ResponseCache,
+}
+
+impl<S> ResponseCacheService<S> {
+    pub(crate) fn new(inner: S, cache: ResponseCache) -> Self {
+        ResponseCacheService { inner, cache }
+    }
+}
+
+impl<S> tower::Service<Request> for ResponseCacheService<S>
+where
+    S: tower::Service<Request, Response = Response, Error = tower::BoxError>
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = tower::BoxError;
+    type Future = BoxFuture<'static, Result<Response, tower::BoxError>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: Request) -> Self::Future {
+        let cache = self.cache.clone();
+        let key = ResponseCache::key(&req);
+
+        if req.method() != Method::GET {
+            let future = self.inner.call(req);
+            return Box::pin(async move {
+                let response = future.await?;
+                if response.status().is_success() {
+                    cache.storage.remove(key.as_str());
+                }
+                Ok(response)
+            });
+        }
+
+        if ResponseCache::is_conditional(req.headers()) {
+            return Box::pin(self.inner.call(req));
+        }
+
+        let cached = cache.storage.get(key.as_str());
+        if let Some(cached) = cached.as_ref() {
+            let headers = req.headers_mut();
+            if let Some(etag) = cached.etag.as_deref() {
+                if let Ok(value) = HeaderValue::from_str(etag) {
+                    headers.insert(IF_NONE_MATCH, value);
+                }
+            } else if let Some(last_modified) = cached.last_modified.as_deref() {
+                if let Ok(value) = HeaderValue::from_str(last_modified) {
+                    headers.insert(IF_MODIFIED_SINCE, value);
+                }
+            }
+        }
+
+        let future = self.inner.call(req);
+        Box::pin(async move {
+            let response = future.await?;
+            let status = response.status();
+
+            if status == StatusCode::NOT_MODIFIED {
+                if let Some(cached) = cached {
+                    return cached.into_response(response.url().clone());
+                }
+                return Ok(response);
+            }
+
+            if status != StatusCode::OK {
+                return Ok(response);
+            }
+
+            if !response.headers().contains_key(ETAG)
+                && !response.headers().contains_key(LAST_MODIFIED)
+            {
+                cache.storage.remove(key.as_str());
+                return Ok(response);
+            }
+
+            let version = response.version();
+            let url = response.url().clone();
+            let headers = response.headers().clone();
+            let body = response.bytes().await?;
+
+            if let Some(cached) = CachedResponse::from_parts(status, &headers, &body) {
+                cache.storage.put(key.as_str(), cached);
+            }
+
+            let mut builder = http::Response::builder()
+                .status(status)
+                .version(version)
+                .url(url);
+            if let Some(builder_headers) = builder.headers_mut() {
+                builder_headers.extend(headers);
+            }
+            Ok(Response::from(builder.body(body)?))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tower::{Service, ServiceExt};
+
+    #[tokio::test]
+    async fn not_modified_returns_cached_body() {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+        let transport = tower::service_fn(move |req: Request| {
+            let count = counter.fetch_add(1, Ordering::SeqCst);
+            async move {
+                let response = if req.method() == Method::DELETE {
+                    http::Response::builder().status(204).body("")
+                } else if count == 0 {
+                    assert!(req.headers().get(IF_NONE_MATCH).is_none());
+                    http::Response::builder()
+                        .status(200)
+                        .header(ETAG, "\"1\"")
+                        .body(r#"{"id":"root"}"#)
+                } else {
+                    assert_eq!("\"1\"", req.headers()[IF_NONE_MATCH]);
+                    http::Response::builder().status(304).body("")
+                };
+                Ok::<_, tower::BoxError>(Response::from(response.unwrap()))
+            }
+        });
+
+        let cache = ResponseCache::memory();
+        let mut service = ResponseCacheService::new(transport, cache.clone());
+        let url = url::Url::parse("https://graph.microsoft.com/v1.0/me/drive/root").unwrap();
+
+        for _ in 0..2 {
+            let request = Request::new(Method::GET, url.clone());
+            let response = service.ready().await.unwrap().call(request).await.unwrap();
+            assert_eq!(200, response.status().as_u16());
+            assert_eq!("\"1\"", response.headers()[ETAG]);
+            assert_eq!(r#"{"id":"root"}"#, response.text().await.unwrap());
+        }
+        assert_eq!(2, requests.load(Ordering::SeqCst));
+        assert!(cache.storage().get(url.as_str()).is_some());
+
+        let request = Request::new(Method::DELETE, url.clone());
+        service.ready().await.unwrap().call(request).await.unwrap();
+        assert!(cache.storage().get(url.as_str()).is_none());
+    }
+
+    #[tokio::test]
+    async fn responses_are_not_shared_between_users() {
+        let transport = tower::service_fn(|_req: Request| async {
+            let response = http::Response::builder()
+                .status(200)
+                .header(ETAG, "\"1\"")
+                .body(r#"{"id":"root"}"#);
+            Ok::<_, tower::BoxError>(Response::from(response.unwrap()))
+        });
+
+        let token = |oid: &str| {
+            let claims = serde_json::json!({ "tid": "tenant", "oid": oid });
+            let payload = URL_SAFE_NO_PAD.encode(claims.to_string());
+            format!("Bearer header.{payload}.signature")
+        };
+
+        let cache = ResponseCache::memory();
+        let mut service = ResponseCacheService::new(transport, cache.clone());
+        let url = url::Url::parse("https://graph.microsoft.com/v1.0/me/drive/root").unwrap();
+
+        let mut keys = Vec::new();
+        for authorization in [token("user-1"), token("user-2"), "Bearer opaque".to_owned()] {
+            let mut request = Request::new(Method::GET, url.clone());
+            request.headers_mut().insert(
+                AUTHORIZATION,
+                HeaderValue::from_str(authorization.as_str()).unwrap(),
+            );
+            keys.push(ResponseCache::key(&request));
+            service.ready().await.unwrap().call(request).await.unwrap();
+        }
+
+        assert_eq!(format!("tenant.user-1 {url}"), keys[0]);
+        assert_eq!(
+            3,
+            keys.iter().collect::<std::collections::HashSet<_>>().len()
+        );
+        assert!(keys.iter().all(|key| cache.storage().get(key).is_some()));
+        assert!(cache.storage().get(url.as_str()).is_none());
+    }
+
+    #[test]
+    fn memory_storage_evicts_least_recently_used() {
+        let storage = MemoryCacheStorage::new().with_capacity(2);
+        storage.put("a", CachedResponse::default());
+        storage.put("b", CachedResponse::default());
+        assert!(storage.get("a").is_some());
+
+        storage.put("c", CachedResponse::default());
+        assert_eq!(2, storage.len());
+        assert!(storage.get("a").is_some());
+        assert!(storage.get("b").is_none());
+        assert!(storage.get("c").is_some());
+    }
+
+    #[test]
+    fn disk_storage_round_trip() {
+        let directory = std::env::temp_dir().join(format!(
+            "graph-rs-sdk-response-cache-{}",
+            std::process::id()
+        ));
+        let storage = DiskCacheStorage::new(&directory);
+        let response = CachedResponse {
+            etag: Some("\"1\"".to_owned()),
+            last_modified: None,
+            status: 200,
+            headers: vec![("etag".to_owned(), "\"1\"".to_owned())],
+            body: vec![0, 1, 2, 255],
+        };
+
+        storage.put(
+            "https://graph.microsoft.com/v1.0/schemaExtensions",
+            response.clone(),
+        );
+        assert_eq!(
+            Some(response),
+            storage.get("https://graph.microsoft.com/v1.0/schemaExtensions")
+        );
+        assert_eq!(None, storage.get("https://graph.microsoft.com/v1.0/me"));
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = |path: &std::path::Path| {
+                std::fs::metadata(path).unwrap().permissions().mode() & 0o777
+            };
+            assert_eq!(0o700, mode(&directory));
+            assert_eq!(
+                0o600,
+                mode(&storage.path("https://graph.microsoft.com/v1.0/schemaExtensions"))
+            );
+        }
+
+        storage.remove("https://graph.microsoft.com/v1.0/schemaExtensions");
+        assert_eq!(
+            None,
+            storage.get("https://graph.microsoft.com/v1.0/schemaExtensions")
+        );
+        let _ = std::fs::remove_dir_all(directory);
+    }
+}
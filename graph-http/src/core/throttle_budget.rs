@@ -0,0 +1,205 @@
+use crate::internal::{retry_after, RateLimit};
+use futures_util::future::BoxFuture;
+use reqwest::header::{HeaderMap, HeaderName};
+use reqwest::{Request, Response, StatusCode};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+const THROTTLE_LIMIT_PERCENTAGE: HeaderName =
+    HeaderName::from_static("x-ms-throttle-limit-percentage");
+const THROTTLE_SCOPE: HeaderName = HeaderName::from_static("x-ms-throttle-scope");
+
+/// The throttling state of the last response that included throttling headers.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ThrottleBudgetState {
+    /// The number of resource units allowed within the time window (`RateLimit-Limit`).
+    pub limit: Option<u64>,
+    /// The number of resource units remaining within the time window (`RateLimit-Remaining`).
+    pub remaining: Option<u64>,
+    /// When the resource units are replenished (`RateLimit-Reset`).
+    pub reset_at: Option<Instant>,
+    /// How close the application is to the limit when above 80% of it, such as `1.2`
+    /// for 120% (`x-ms-throttle-limit-percentage`).
+    pub throttle_limit_percentage: Option<f64>,
+    /// The scope of the limit that was reached (`x-ms-throttle-scope`).
+    pub throttle_scope: Option<String>,
+    /// When requests can be sent again after a 429 Too Many Requests response (`Retry-After`).
+    pub retry_after_at: Option<Instant>,
+}
+
+/// Throttling budget of the client updated from the `RateLimit` and Microsoft Graph
+/// throttling headers of every response. The budget is shared by all clones of the
+/// client and by the requests of the client.
+///
+/// Only requests of the async client update the budget.
+///
+/// See [Throttling guidance](https://learn.microsoft.com/en-us/graph/throttling)
+///
+/// # Example
+/// ```rust,ignore
+/// let client = GraphClient::new("ACCESS_TOKEN");
+/// let response = client.me().messages().list_messages().send().await?;
+///
+/// let state = client.throttle_budget().state();
+/// println!("{:#?}", state.remaining);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ThrottleBudget {
+    state: Arc<Mutex<ThrottleBudgetState>>,
+}
+
+impl ThrottleBudget {
+    /// A copy of the current throttling state.
+    pub fn state(&self) -> ThrottleBudgetState {
+        self.state
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .clone()
+    }
+
+    pub(crate) fn update(&self, status: StatusCode, headers: &HeaderMap) {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap_or_else(|err| err.into_inner());
+
+        if let Some(rate_limit) = RateLimit::from_headers(headers) {
+            state.limit = rate_limit.limit.or(state.limit);
+            state.remaining = rate_limit.remaining;
+            state.reset_at = rate_limit.reset.map(|reset| now + reset);
+        }
+
+        state.throttle_limit_percentage = headers
+            .get(THROTTLE_LIMIT_PERCENTAGE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse::<f64>().ok());
+        state.throttle_scope = headers
+            .get(THROTTLE_SCOPE)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.to_owned());
+
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            state.retry_after_at = retry_after(headers).map(|retry_after| now + retry_after);
+        }
+    }
+
+    /// How long to wait before sending a request when a threshold is set. Requests wait
+    /// for the `Retry-After` of a throttled response and, when the remaining resource units
+    /// are at or below the threshold, until the resource units are replenished.
+    pub(crate) fn delay(&self, threshold: Option<u64>) -> Option<Duration> {
+        let threshold = threshold?;
+        let now = Instant::now();
+        let state = self.state.lock().unwrap_or_else(|err| err.into_inner());
+
+        let retry_after = state
+            .retry_after_at
+            .filter(|retry_after_at| *retry_after_at > now)
+            .map(|retry_after_at| retry_after_at - now);
+
+        let reset = match (state.remaining, state.reset_at) {
+            (Some(remaining), Some(reset_at)) if remaining <= threshold && reset_at > now => {
+                Some(reset_at - now)
+            }
+            _ => None,
+        };
+
+        retry_after.max(reset)
+    }
+}
+
+/// Updates the [ThrottleBudget] from the response headers and delays requests
+/// when the budget is exhausted.
+#[derive(Clone)]
+pub(crate) struct ThrottleBudgetService<S> {
+    inner: S,
+    budget: ThrottleBudget,
+    threshold: Option<u64>,
+}
+
+impl<S> ThrottleBudgetService<S> {
+    pub(crate) fn new(inner: S, budget: ThrottleBudget, threshold: Option<u64>) -> Self {
+        ThrottleBudgetService {
+            inner,
+            budget,
+            threshold,
+        }
+    }
+}
+
+impl<S> tower::Service<Request> for ThrottleBudgetService<S>
+where
+    S: tower::Service<Request, Response = Response, Error = tower::BoxError>
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = tower::BoxError;
+    type Future = BoxFuture<'static, Result<Response, tower::BoxError>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request) -> Self::Future {
+        // The ready service is used for the request and the clone is used for the next.
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let budget = self.budget.clone();
+        let delay = budget.delay(self.threshold);
+
+        Box::pin(async move {
+            if let Some(delay) = delay {
+                tokio::time::sleep(delay).await;
+            }
+            let response = inner.call(req).await?;
+            budget.update(response.status(), response.headers());
+            Ok(response)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    fn headers(remaining: &'static str, reset: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert("ratelimit-limit", HeaderValue::from_static("1000"));
+        headers.insert("ratelimit-remaining", HeaderValue::from_static(remaining));
+        headers.insert("ratelimit-reset", HeaderValue::from_static(reset));
+        headers
+    }
+
+    #[test]
+    fn update_budget_from_headers() {
+        let budget = ThrottleBudget::default();
+        let mut response_headers = headers("20", "30");
+        response_headers.insert(THROTTLE_LIMIT_PERCENTAGE, HeaderValue::from_static("1.2"));
+        budget.update(StatusCode::OK, &response_headers);
+
+        let state = budget.state();
+        assert_eq!(Some(1000), state.limit);
+        assert_eq!(Some(20), state.remaining);
+        assert_eq!(Some(1.2), state.throttle_limit_percentage);
+        assert!(state.retry_after_at.is_none());
+
+        assert!(budget.delay(None).is_none());
+        assert!(budget.delay(Some(10)).is_none());
+        let delay = budget.delay(Some(20)).unwrap();
+        assert!(delay > Duration::from_secs(25) && delay <= Duration::from_secs(30));
+    }
+
+    #[test]
+    fn retry_after_delays_requests() {
+        let budget = ThrottleBudget::default();
+        let mut response_headers = HeaderMap::new();
+        response_headers.insert("retry-after", HeaderValue::from_static("5"));
+        budget.update(StatusCode::TOO_MANY_REQUESTS, &response_headers);
+
+        assert!(budget.delay(None).is_none());
+        let delay = budget.delay(Some(0)).unwrap();
+        assert!(delay > Duration::from_secs(3) && delay <= Duration::from_secs(5));
+    }
+}
@@ -0,0 +1,353 @@
+//! Fault injection for testing the retry and resume logic of applications that use
+//! the Graph client without sending requests that fail to Microsoft Graph.
+//!
+//! Requires feature `fault-injection`
+//!
+//! # Example
+//! ```rust,ignore
+//! use graph_rs_sdk::fault_injection::FaultInjection;
+//! use graph_rs_sdk::{Graph, GraphClientConfiguration};
+//! use std::time::Duration;
+//!
+//! let fault_injection = FaultInjection::new()
+//!     .too_many_requests(0.1)
+//!     .service_unavailable(0.05)
+//!     .disconnect(0.01)
+//!     .retry_after(Duration::from_secs(1))
+//!     .seed(42);
+//!
+//! let client = Graph::from(
+//!     GraphClientConfiguration::new()
+//!         .access_token("ACCESS_TOKEN")
+//!         .fault_injection(fault_injection.clone()),
+//! );
+//!
+//! // ... run the migration
+//! println!("injected faults: {}", fault_injection.injected_faults());
+//! ```
+
+use std::fmt::{Debug, Formatter};
+use std::io;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use futures_util::future::{self, BoxFuture};
+use http::header::{CONTENT_TYPE, RETRY_AFTER};
+use http::{HeaderValue, StatusCode};
+use reqwest::{Request, Response};
+
+/// A fault that was injected in place of sending the request.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Fault {
+    /// A 429 Too Many Requests response with a `Retry-After` header.
+    TooManyRequests,
+    /// A 503 Service Unavailable response with a `Retry-After` header.
+    ServiceUnavailable,
+    /// The request fails with a timed out io error after the timeout delay.
+    Timeout,
+    /// The request fails with a connection reset io error.
+    Disconnect,
+}
+
+/// Pseudo random number generator (SplitMix64). Faults only need to be spread
+/// across requests and reproducible with a seed.
+#[derive(Debug)]
+struct Rng(u64);
+
+impl Rng {
+    fn next_f64(&mut self) -> f64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        (z >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Configuration of the faults that are injected into the requests of the client.
+/// Rates are the probability, between 0.0 and 1.0, that a request fails with the fault.
+///
+/// Clones share the random number generator and the count of injected faults.
+#[derive(Clone)]
+pub struct FaultInjection {
+    too_many_requests: f64,
+    service_unavailable: f64,
+    timeout: f64,
+    disconnect: f64,
+    retry_after: Duration,
+    timeout_delay: Duration,
+    rng: Arc<Mutex<Rng>>,
+    injected: Arc<AtomicUsize>,
+}
+
+impl FaultInjection {
+    pub fn new() -> FaultInjection {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_nanos() as u64)
+            .unwrap_or_default();
+
+        FaultInjection {
+            too_many_requests: 0.0,
+            service_unavailable: 0.0,
+            timeout: 0.0,
+            disconnect: 0.0,
+            retry_after: Duration::from_secs(1),
+            timeout_delay: Duration::ZERO,
+            rng: Arc::new(Mutex::new(Rng(seed))),
+            injected: Default::default(),
+        }
+    }
+
+    fn rate(rate: f64) -> f64 {
+        rate.clamp(0.0, 1.0)
+    }
+
+    pub fn too_many_requests(mut self, rate: f64) -> Self {
+        self.too_many_requests = FaultInjection::rate(rate);
+        self
+    }
+
+    pub fn service_unavailable(mut self, rate: f64) -> Self {
+        self.service_unavailable = FaultInjection::rate(rate);
+        self
+    }
+
+    pub fn timeout(mut self, rate: f64) -> Self {
+        self.timeout = FaultInjection::rate(rate);
+        self
+    }
+
+    pub fn disconnect(mut self, rate: f64) -> Self {
+        self.disconnect = FaultInjection::rate(rate);
+        self
+    }
+
+    /// The `Retry-After` header of injected 429 and 503 responses. Default is 1 second.
+    pub fn retry_after(mut self, retry_after: Duration) -> Self {
+        self.retry_after = retry_after;
+        self
+    }
+
+    /// How long an injected timeout waits before failing. Default is no delay.
+    pub fn timeout_delay(mut self, timeout_delay: Duration) -> Self {
+        self.timeout_delay = timeout_delay;
+        self
+    }
+
+    /// Seed the random number generator to inject the same faults on every run.
+    pub fn seed(self, seed: u64) -> Self {
+        *self.rng.lock().unwrap_or_else(|err| err.into_inner()) = Rng(seed);
+        self
+    }
+
+    /// The number of faults that have been injected.
+    pub fn injected_faults(&self) -> usize {
+        self.injected.load(Ordering::SeqCst)
+    }
+
+    fn next_fault(&self) -> Option<Fault> {
+        let roll = self
+            .rng
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .next_f64();
+
+        let mut threshold = 0.0;
+        for (rate, fault) in [
+            (self.too_many_requests, Fault::TooManyRequests),
+            (self.service_unavailable, Fault::ServiceUnavailable),
+            (self.timeout, Fault::Timeout),
+            (self.disconnect, Fault::Disconnect),
+        ] {
+            threshold += rate;
+            if roll < threshold {
+                self.injected.fetch_add(1, Ordering::SeqCst);
+                return Some(fault);
+            }
+        }
+        None
+    }
+
+    fn error_response(&self, status: StatusCode, code: &str) -> Response {
+        let body = serde_json::json!({
+            "error": {
+                "code": code,
+                "message": "Fault injected by the graph client for testing.",
+            }
+        });
+
+        let mut response = http::Response::new(body.to_string());
+        *response.status_mut() = status;
+        response
+            .headers_mut()
+            .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        response
+            .headers_mut()
+            .insert(RETRY_AFTER, HeaderValue::from(self.retry_after.as_secs()));
+        Response::from(response)
+    }
+}
+
+impl Default for FaultInjection {
+    fn default() -> Self {
+        FaultInjection::new()
+    }
+}
+
+impl Debug for FaultInjection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FaultInjection")
+            .field("too_many_requests", &self.too_many_requests)
+            .field("service_unavailable", &self.service_unavailable)
+            .field("timeout", &self.timeout)
+            .field("disconnect", &self.disconnect)
+            .field("retry_after", &self.retry_after)
+            .field("timeout_delay", &self.timeout_delay)
+            .finish()
+    }
+}
+
+/// Injects faults in place of sending requests to the inner service.
+#[derive(Clone)]
+pub(crate) struct FaultInjectionService<S> {
+    inner: S,
+    fault_injection: FaultInjection,
+}
+
+impl<S> FaultInjectionService<S> {
+    pub(crate) fn new(inner: S, fault_injection: FaultInjection) -> Self {
+        FaultInjectionService {
+            inner,
+            fault_injection,
+        }
+    }
+}
+
+impl<S> tower::Service<Request> for FaultInjectionService<S>
+where
+    S: tower::Service<Request, Response = Response, Error = tower::BoxError>
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = tower::BoxError;
+    type Future = BoxFuture<'static, Result<Response, tower::BoxError>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request) -> Self::Future {
+        let fault_injection = self.fault_injection.clone();
+        match fault_injection.next_fault() {
+            Some(Fault::TooManyRequests) => {
+                let response = fault_injection
+                    .error_response(StatusCode::TOO_MANY_REQUESTS, "TooManyRequests");
+                Box::pin(future::ready(Ok(response)))
+            }
+            Some(Fault::ServiceUnavailable) => {
+                let response = fault_injection
+                    .error_response(StatusCode::SERVICE_UNAVAILABLE, "serviceNotAvailable");
+                Box::pin(future::ready(Ok(response)))
+            }
+            Some(Fault::Timeout) => Box::pin(async move {
+                tokio::time::sleep(fault_injection.timeout_delay).await;
+                let err: tower::BoxError =
+                    Box::new(io::Error::new(io::ErrorKind::TimedOut, "injected timeout"));
+                Err(err)
+            }),
+            Some(Fault::Disconnect) => {
+                let err: tower::BoxError = Box::new(io::Error::new(
+                    io::ErrorKind::ConnectionReset,
+                    "injected disconnect",
+                ));
+                Box::pin(future::ready(Err(err)))
+            }
+            None => Box::pin(self.inner.call(req)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use tower::util::BoxCloneService;
+    use tower::{Service, ServiceExt};
+
+    fn request() -> Request {
+        Request::new(
+            reqwest::Method::GET,
+            url::Url::parse("https://graph.microsoft.com/v1.0/me").unwrap(),
+        )
+    }
+
+    fn transport() -> BoxCloneService<Request, Response, tower::BoxError> {
+        tower::service_fn(|_req: Request| async {
+            Ok::<_, tower::BoxError>(Response::from(http::Response::new("{}")))
+        })
+        .boxed_clone()
+    }
+
+    #[tokio::test]
+    async fn inject_faults() {
+        let fault_injection = FaultInjection::new().too_many_requests(1.0).seed(7);
+        let mut service = FaultInjectionService::new(transport(), fault_injection.clone());
+
+        let response = service
+            .ready()
+            .await
+            .unwrap()
+            .call(request())
+            .await
+            .unwrap();
+        assert_eq!(StatusCode::TOO_MANY_REQUESTS, response.status());
+        assert_eq!("1", response.headers().get(RETRY_AFTER).unwrap());
+        assert_eq!(1, fault_injection.injected_faults());
+
+        let fault_injection = FaultInjection::new().disconnect(1.0);
+        let mut service = FaultInjectionService::new(transport(), fault_injection);
+        assert!(service
+            .ready()
+            .await
+            .unwrap()
+            .call(request())
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn no_faults() {
+        let fault_injection = FaultInjection::new();
+        let mut service = FaultInjectionService::new(transport(), fault_injection.clone());
+
+        let response = service
+            .ready()
+            .await
+            .unwrap()
+            .call(request())
+            .await
+            .unwrap();
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!(0, fault_injection.injected_faults());
+    }
+
+    #[test]
+    fn seeded_faults_are_reproducible() {
+        let faults = |seed| {
+            let fault_injection = FaultInjection::new()
+                .too_many_requests(0.3)
+                .timeout(0.3)
+                .seed(seed);
+            (0..20)
+                .map(|_| fault_injection.next_fault())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(faults(42), faults(42));
+    }
+}
@@ -0,0 +1,400 @@
+//! Capture the requests and responses of a client session in an HTTP Archive (HAR)
+//! file to troubleshoot throttling and latency issues, such as with Microsoft support.
+//!
+//! Every attempt sent by the client is captured, including retries, with the time
+//! the request was sent and how long it took. The values of authorization and cookie
+//! headers, secret query parameters, and secret json properties such as tokens and
+//! passwords are replaced with `[REDACTED]`.
+//!
+//! Response bodies are read into memory before they are returned so capture
+//! should not be enabled for large downloads.
+//!
+//! Requires feature `har-capture`
+//!
+//! # Example
+//! ```rust,ignore
+//! use graph_rs_sdk::har::HarRecorder;
+//! use graph_rs_sdk::{Graph, GraphClientConfiguration};
+//!
+//! let har_recorder = HarRecorder::new();
+//! let client = Graph::from(
+//!     GraphClientConfiguration::new()
+//!         .access_token("ACCESS_TOKEN")
+//!         .har_capture(har_recorder.clone()),
+//! );
+//!
+//! // ... run the requests
+//! har_recorder.save("graph-session.har")?;
+//! ```
+
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::Instant;
+
+use futures_util::future::BoxFuture;
+use graph_error::GraphResult;
+use http::header::CONTENT_TYPE;
+use http::{HeaderMap, Version};
+use reqwest::{Request, Response, ResponseBuilderExt};
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+use crate::internal::{redact_header, redact_text, redact_url};
+
+const HAR_VERSION: &str = "1.2";
+
+/// An HTTP Archive. See [HAR 1.2](http://www.softwareishard.com/blog/har-12-spec/).
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Har {
+    pub log: HarLog,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HarLog {
+    pub version: String,
+    pub creator: HarCreator,
+    pub entries: Vec<HarEntry>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HarCreator {
+    pub name: String,
+    pub version: String,
+}
+
+/// A request and its response.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HarEntry {
+    /// The time the request was sent in RFC 3339 format.
+    pub started_date_time: String,
+    /// The total time of the request in milliseconds.
+    pub time: f64,
+    pub request: HarRequest,
+    pub response: HarResponse,
+    pub cache: HarCache,
+    pub timings: HarTimings,
+    /// The error of requests that failed without a response such as timeouts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HarRequest {
+    pub method: String,
+    pub url: String,
+    pub http_version: String,
+    pub cookies: Vec<HarNameValue>,
+    pub headers: Vec<HarNameValue>,
+    pub query_string: Vec<HarNameValue>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub post_data: Option<HarPostData>,
+    pub headers_size: i64,
+    pub body_size: i64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HarResponse {
+    pub status: u16,
+    pub status_text: String,
+    pub http_version: String,
+    pub cookies: Vec<HarNameValue>,
+    pub headers: Vec<HarNameValue>,
+    pub content: HarContent,
+    #[serde(rename = "redirectURL")]
+    pub redirect_url: String,
+    pub headers_size: i64,
+    pub body_size: i64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HarNameValue {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HarPostData {
+    pub mime_type: String,
+    pub text: String,
+}
+
+/// The response body. The text is not set for binary bodies.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HarContent {
+    pub size: i64,
+    pub mime_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HarCache {}
+
+/// Timings in milliseconds. Only the time waiting for the response is known to the
+/// client so the other timings are 0.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HarTimings {
+    pub send: f64,
+    pub wait: f64,
+    pub receive: f64,
+}
+
+/// Collects the [HarEntry] of every request sent by the clients it is set on
+/// using `GraphClientConfiguration::har_capture`. Clones share the entries.
+#[derive(Clone, Debug, Default)]
+pub struct HarRecorder {
+    entries: Arc<Mutex<Vec<HarEntry>>>,
+}
+
+impl HarRecorder {
+    pub fn new() -> HarRecorder {
+        HarRecorder::default()
+    }
+
+    pub fn entries(&self) -> Vec<HarEntry> {
+        self.entries
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .clone()
+    }
+
+    /// Remove the captured entries such as to start a new session.
+    pub fn clear(&self) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .clear();
+    }
+
+    pub fn har(&self) -> Har {
+        Har {
+            log: HarLog {
+                version: HAR_VERSION.to_owned(),
+                creator: HarCreator {
+                    name: "graph-rs-sdk".to_owned(),
+                    version: env!("CARGO_PKG_VERSION").to_owned(),
+                },
+                entries: self.entries(),
+            },
+        }
+    }
+
+    /// Write the captured entries to a .har file.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> GraphResult<()> {
+        let json = serde_json::to_string_pretty(&self.har())?;
+        std::fs::write(path, json)?;
+        Ok(())
+    }
+
+    fn push(&self, entry: HarEntry) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .push(entry);
+    }
+}
+
+fn http_version(version: Version) -> String {
+    format!("{version:?}")
+}
+
+fn name_values(headers: &HeaderMap) -> Vec<HarNameValue> {
+    headers
+        .iter()
+        .map(|(name, value)| HarNameValue {
+            name: name.to_string(),
+            value: redact_header(name, value),
+        })
+        .collect()
+}
+
+fn mime_type(headers: &HeaderMap) -> String {
+    headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default()
+        .to_owned()
+}
+
+fn har_request(request: &Request) -> HarRequest {
+    let url = redact_url(request.url());
+    let body = request.body().and_then(|body| body.as_bytes());
+    HarRequest {
+        method: request.method().to_string(),
+        url: url.to_string(),
+        http_version: http_version(request.version()),
+        cookies: Vec::new(),
+        headers: name_values(request.headers()),
+        query_string: url
+            .query_pairs()
+            .map(|(name, value)| HarNameValue {
+                name: name.into_owned(),
+                value: value.into_owned(),
+            })
+            .collect(),
+        post_data: body.map(|body| HarPostData {
+            mime_type: mime_type(request.headers()),
+            text: match std::str::from_utf8(body) {
+                Ok(text) => redact_text(text),
+                Err(_) => String::new(),
+            },
+        }),
+        headers_size: -1,
+        body_size: body.map(|body| body.len() as i64).unwrap_or(-1),
+    }
+}
+
+fn har_entry(started: OffsetDateTime, elapsed: f64, request: HarRequest) -> HarEntry {
+    HarEntry {
+        started_date_time: started.format(&Rfc3339).unwrap_or_default(),
+        time: elapsed,
+        request,
+        timings: HarTimings {
+            send: 0.0,
+            wait: elapsed,
+            receive: 0.0,
+        },
+        ..Default::default()
+    }
+}
+
+/// Captures the requests sent to the inner service in a [HarRecorder].
+#[derive(Clone)]
+pub(crate) struct HarService<S> {
+    inner: S,
+    recorder: HarRecorder,
+}
+
+impl<S> HarService<S> {
+    pub(crate) fn new(inner: S, recorder: HarRecorder) -> Self {
+        HarService { inner, recorder }
+    }
+}
+
+impl<S> tower::Service<Request> for HarService<S>
+where
+    S: tower::Service<Request, Response = Response, Error = tower::BoxError>
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = tower::BoxError;
+    type Future = BoxFuture<'static, Result<Response, tower::BoxError>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request) -> Self::Future {
+        let recorder = self.recorder.clone();
+        let har_request = har_request(&req);
+        let started = OffsetDateTime::now_utc();
+        let instant = Instant::now();
+        let future = self.inner.call(req);
+
+        Box::pin(async move {
+            let response = match future.await {
+                Ok(response) => response,
+                Err(err) => {
+                    let elapsed = instant.elapsed().as_secs_f64() * 1000.0;
+                    let mut entry = har_entry(started, elapsed, har_request);
+                    entry.comment = Some(err.to_string());
+                    recorder.push(entry);
+                    return Err(err);
+                }
+            };
+
+            let status = response.status();
+            let version = response.version();
+            let url = response.url().clone();
+            let headers = response.headers().clone();
+            let body = response.bytes().await?;
+            let elapsed = instant.elapsed().as_secs_f64() * 1000.0;
+
+            let mut entry = har_entry(started, elapsed, har_request);
+            entry.response = HarResponse {
+                status: status.as_u16(),
+                status_text: status.canonical_reason().unwrap_or_default().to_owned(),
+                http_version: http_version(version),
+                cookies: Vec::new(),
+                headers: name_values(&headers),
+                content: HarContent {
+                    size: body.len() as i64,
+                    mime_type: mime_type(&headers),
+                    text: std::str::from_utf8(&body).ok().map(redact_text),
+                },
+                redirect_url: String::new(),
+                headers_size: -1,
+                body_size: body.len() as i64,
+            };
+            recorder.push(entry);
+
+            let mut builder = http::Response::builder()
+                .status(status)
+                .version(version)
+                .url(url);
+            if let Some(builder_headers) = builder.headers_mut() {
+                builder_headers.extend(headers);
+            }
+            Ok(Response::from(builder.body(body)?))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use tower::{Service, ServiceExt};
+
+    #[tokio::test]
+    async fn capture_redacted_entries() {
+        let recorder = HarRecorder::new();
+        let transport = tower::service_fn(|_req: Request| async {
+            let response = http::Response::builder()
+                .status(429)
+                .header(CONTENT_TYPE, "application/json")
+                .header("Retry-After", "10")
+                .body(r#"{"error":{"code":"TooManyRequests"},"access_token":"token"}"#)
+                .unwrap();
+            Ok::<_, tower::BoxError>(Response::from(response))
+        });
+        let mut service = HarService::new(transport, recorder.clone());
+
+        let mut request = Request::new(
+            reqwest::Method::GET,
+            url::Url::parse("https://graph.microsoft.com/v1.0/me/drive/root?tempauth=secret")
+                .unwrap(),
+        );
+        request.headers_mut().insert(
+            http::header::AUTHORIZATION,
+            http::HeaderValue::from_static("Bearer token"),
+        );
+        let response = service.ready().await.unwrap().call(request).await.unwrap();
+        assert_eq!(429, response.status().as_u16());
+        assert!(response.text().await.unwrap().contains("TooManyRequests"));
+
+        let har = recorder.har();
+        assert_eq!("1.2", har.log.version);
+        let entry = &har.log.entries[0];
+        assert_eq!(429, entry.response.status);
+        assert_eq!("Too Many Requests", entry.response.status_text);
+        assert_eq!(
+            "[REDACTED]", entry.request.headers[0].value,
+            "authorization header is redacted"
+        );
+
+        let json = serde_json::to_string(&har).unwrap();
+        assert!(json.contains("startedDateTime"));
+        assert!(json.contains("redirectURL"));
+        assert!(!json.contains("secret"));
+        assert!(!json.contains("\"token\""));
+    }
+}
@@ -6,7 +6,7 @@ use std::{
     sync::mpsc,
     thread,
 };
-use tokio::io::AsyncWriteExt;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
 
 pub fn create_dir<P: AsRef<Path>>(directory: P) -> Result<(), std::io::Error> {
     if !directory.as_ref().exists() {
@@ -58,3 +58,20 @@ pub async fn copy_async(
     }
     Ok(path)
 }
+
+/// Write the body of the response to the writer as it is received and return the
+/// number of bytes written. The writer is flushed but not shut down.
+pub async fn copy_async_writer<W: AsyncWrite + Unpin + ?Sized>(
+    writer: &mut W,
+    response: reqwest::Response,
+) -> Result<u64, AsyncIoError> {
+    let mut written = 0;
+    let mut stream = response.bytes_stream();
+    while let Some(item) = stream.next().await {
+        let bytes = item?;
+        writer.write_all(&bytes).await?;
+        written += bytes.len() as u64;
+    }
+    writer.flush().await?;
+    Ok(written)
+}
@@ -1,6 +1,7 @@
 #[macro_use]
 extern crate serde;
 
+mod api_version;
 mod blocking;
 mod client;
 mod core;
@@ -18,9 +19,19 @@ pub mod traits;
 /// Io utilities for creating directories and files.
 pub mod io_tools;
 
+#[cfg(feature = "fault-injection")]
+pub mod fault_injection;
+
+#[cfg(feature = "har-capture")]
+pub mod har;
+
+#[cfg(feature = "test-util")]
+pub mod test_util;
+
 #[allow(unused_imports)]
 pub(crate) mod internal {
 
+    pub use crate::api_version::*;
     pub use crate::client::*;
     pub use crate::core::*;
     pub use crate::io_tools::*;
@@ -35,6 +46,7 @@ pub(crate) mod internal {
 }
 
 pub mod api_impl {
+    pub use crate::api_version::ApiVersion;
     pub use crate::blocking::{BlockingClient, BlockingRequestHandler, UploadSessionBlocking};
     pub use crate::client::*;
     pub use crate::core::*;
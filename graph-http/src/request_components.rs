@@ -1,5 +1,6 @@
 use std::io::ErrorKind;
 
+use crate::api_version::ApiVersion;
 use graph_core::resource::ResourceIdentity;
 use graph_error::{GraphFailure, GraphResult};
 use http::{HeaderMap, Method};
@@ -55,6 +56,28 @@ impl RequestComponents {
 
         Ok(())
     }
+
+    /// Replace the api version segment of the url path. Urls without an api version
+    /// segment are not changed.
+    pub fn set_api_version(&mut self, api_version: ApiVersion) {
+        let segments: Vec<String> = self
+            .url
+            .path_segments()
+            .map(|segments| segments.map(|segment| segment.to_owned()).collect())
+            .unwrap_or_default();
+
+        if let Some(index) = segments
+            .iter()
+            .position(|segment| segment.parse::<ApiVersion>().is_ok())
+        {
+            if let Ok(mut path) = self.url.path_segments_mut() {
+                path.clear()
+                    .extend(&segments[..index])
+                    .push(api_version.as_str())
+                    .extend(&segments[index + 1..]);
+            }
+        }
+    }
 }
 
 impl TryFrom<(ResourceIdentity, reqwest::Method, GraphResult<Url>)> for RequestComponents {
@@ -65,6 +88,34 @@ impl TryFrom<(ResourceIdentity, reqwest::Method, GraphResult<Url>)> for RequestC
     }
 }
 
+/// A request builder with the method, url, and headers of the request components
+/// for use with http clients and tower services that use the http crate.
+impl From<&RequestComponents> for http::request::Builder {
+    fn from(value: &RequestComponents) -> Self {
+        let mut builder = http::Request::builder()
+            .method(value.method.clone())
+            .uri(value.url.as_str());
+        if let Some(headers) = builder.headers_mut() {
+            headers.extend(value.headers.clone());
+        }
+        builder
+    }
+}
+
+impl<B> TryFrom<&http::Request<B>> for RequestComponents {
+    type Error = GraphFailure;
+
+    fn try_from(value: &http::Request<B>) -> Result<Self, Self::Error> {
+        let mut request_components = RequestComponents::new(
+            ResourceIdentity::default(),
+            Url::parse(value.uri().to_string().as_str())?,
+            value.method().clone(),
+        );
+        request_components.headers = value.headers().clone();
+        Ok(request_components)
+    }
+}
+
 impl Default for RequestComponents {
     fn default() -> Self {
         RequestComponents {
@@ -75,3 +126,37 @@ impl Default for RequestComponents {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use http::header::CONTENT_TYPE;
+    use http::HeaderValue;
+
+    #[test]
+    fn http_request_conversion() {
+        let mut request_components = RequestComponents::new(
+            ResourceIdentity::Users,
+            Url::parse("https://graph.microsoft.com/v1.0/users?$top=5").unwrap(),
+            Method::POST,
+        );
+        request_components
+            .headers
+            .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+        let request = http::request::Builder::from(&request_components)
+            .body(())
+            .unwrap();
+        assert_eq!(Method::POST, request.method());
+        assert_eq!(
+            "https://graph.microsoft.com/v1.0/users?$top=5",
+            request.uri().to_string()
+        );
+        assert_eq!("application/json", request.headers()[CONTENT_TYPE]);
+
+        let from_request = RequestComponents::try_from(&request).unwrap();
+        assert_eq!(request_components.url, from_request.url);
+        assert_eq!(request_components.method, from_request.method);
+        assert_eq!(request_components.headers, from_request.headers);
+    }
+}
@@ -1,29 +1,30 @@
 use crate::blocking::BlockingRequestHandler;
 use crate::internal::{
-    BodyRead, Client, GraphClientConfiguration, HttpResponseBuilderExt, ODataNextLink, ODataQuery,
-    RequestComponents,
+    add_preference, check_permissions, retry_after, ApiVersion, BodyRead, Client, GraphResponse,
+    HttpResponseBuilderExt, HttpService, ODataNextLink, ODataQuery, PageDecoder, Prefer,
+    RequestComponents, RequestDump, RequiredPermissions, ResponseExt, CONSISTENCY_LEVEL, PREFER,
 };
 use async_stream::try_stream;
-use futures::Stream;
+use futures::{Stream, StreamExt};
 use graph_error::{AuthExecutionResult, ErrorMessage, GraphFailure, GraphResult};
-use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE};
-use reqwest::{Request, Response};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE, IF_MATCH, IF_NONE_MATCH};
+use reqwest::StatusCode;
 use serde::de::DeserializeOwned;
 use std::collections::VecDeque;
 use std::fmt::Debug;
 use std::time::Duration;
-use tower::util::BoxCloneService;
 use tower::{Service, ServiceExt};
 use url::Url;
 
+const HTTP_PIPELINE: &str = "graph_rs_sdk::http_pipeline";
+
 pub struct RequestHandler {
     pub(crate) inner: Client,
     pub(crate) request_components: RequestComponents,
     pub(crate) error: Option<GraphFailure>,
     pub(crate) body: Option<BodyRead>,
-    pub(crate) client_builder: GraphClientConfiguration,
-    pub(crate) service:
-        BoxCloneService<Request, Response, Box<dyn std::error::Error + Send + Sync>>,
+    pub(crate) service: HttpService,
+    pub(crate) permission_check: bool,
 }
 
 impl RequestHandler {
@@ -34,7 +35,6 @@ impl RequestHandler {
         body: Option<BodyRead>,
     ) -> RequestHandler {
         let service = inner.builder.build_tower_service(&inner.inner);
-        let client_builder = inner.builder.clone();
         let mut original_headers = inner.headers.clone();
         original_headers.extend(request_components.headers.clone());
         request_components.headers = original_headers;
@@ -55,18 +55,20 @@ impl RequestHandler {
             request_components,
             error,
             body,
-            client_builder,
             service,
+            permission_check: false,
         }
     }
 
     pub fn into_blocking(self) -> BlockingRequestHandler {
-        BlockingRequestHandler::new(
-            self.client_builder.build_blocking(),
+        let mut blocking_handler = BlockingRequestHandler::new(
+            self.inner.blocking_client(),
             self.request_components,
             self.error,
             self.body,
-        )
+        );
+        blocking_handler.permission_check = self.permission_check;
+        blocking_handler
     }
 
     /// Returns true if any errors occurred prior to sending the request.
@@ -129,6 +131,29 @@ impl RequestHandler {
         self
     }
 
+    /// Use a different version of the Microsoft Graph API for this request than
+    /// the version used by the client.
+    pub fn api_version(mut self, api_version: ApiVersion) -> Self {
+        self.request_components.set_api_version(api_version);
+        self
+    }
+
+    /// Use the beta endpoint of the Microsoft Graph API for this request.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let client = Graph::new("ACCESS_TOKEN");
+    /// let response = client.me().get_user().beta().send().await?;
+    /// ```
+    pub fn beta(self) -> Self {
+        self.api_version(ApiVersion::Beta)
+    }
+
+    /// Use the v1.0 endpoint of the Microsoft Graph API for this request.
+    pub fn v1(self) -> Self {
+        self.api_version(ApiVersion::V1)
+    }
+
     /// Insert a header for the request.
     #[inline]
     pub fn header<K: Into<HeaderName>, V: Into<HeaderValue>>(
@@ -155,8 +180,130 @@ impl RequestHandler {
         self.request_components.as_mut()
     }
 
+    /// Set the `ConsistencyLevel: eventual` header. Advanced queries on directory objects,
+    /// such as `$count`, `$search`, and `$filter` with `ne`, `not`, or `endsWith`,
+    /// are only supported with this header.
+    pub fn consistency_level_eventual(self) -> Self {
+        self.header(CONSISTENCY_LEVEL, HeaderValue::from_static("eventual"))
+    }
+
+    /// Only apply the request if the ETag of the resource matches the given ETag, such as
+    /// the `@odata.etag` of a previous response. Use this to avoid overwriting changes
+    /// made by another client. The request fails with [GraphFailure::PreconditionFailed]
+    /// if the resource has changed.
+    pub fn if_match(self, etag: impl AsRef<str>) -> Self {
+        self.etag_header(IF_MATCH, etag.as_ref())
+    }
+
+    /// Only apply the request if the ETag of the resource does not match the given ETag.
+    /// Use `*` to only create a resource if it does not already exist.
+    pub fn if_none_match(self, etag: impl AsRef<str>) -> Self {
+        self.etag_header(IF_NONE_MATCH, etag.as_ref())
+    }
+
+    fn etag_header(mut self, header_name: HeaderName, etag: &str) -> Self {
+        match HeaderValue::from_str(etag) {
+            Ok(value) => {
+                self.request_components.headers.insert(header_name, value);
+            }
+            Err(err) => {
+                if self.error.is_none() {
+                    self.error = Some(GraphFailure::from(http::Error::from(err)));
+                }
+            }
+        }
+        self
+    }
+
+    /// Set the `Prefer` header for the request. Replaces any `Prefer` header set
+    /// for the client.
+    pub fn prefer(mut self, prefer: &Prefer) -> Self {
+        match prefer.header_value() {
+            Ok(value) => {
+                self.request_components.headers.insert(PREFER, value);
+            }
+            Err(err) => {
+                if self.error.is_none() {
+                    self.error = Some(GraphFailure::from(http::Error::from(err)));
+                }
+            }
+        }
+        self
+    }
+
+    /// Set the number of items returned in each page of a collection.
+    ///
+    /// Delta queries do not support `$top`, so the page size of a delta query is set
+    /// using the `Prefer: odata.maxpagesize` header, keeping any other preferences of the
+    /// request. The page size of any other request is set using `$top`. The api may
+    /// return fewer items in a page than requested.
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        if !is_delta_request(&self.request_components.url) {
+            return self.top(page_size.to_string());
+        }
+
+        let preference = format!("odata.maxpagesize={page_size}");
+        if let Err(err) = add_preference(&mut self.request_components.headers, &preference) {
+            if self.error.is_none() {
+                self.error = Some(GraphFailure::from(http::Error::from(err)));
+            }
+        }
+        self
+    }
+
+    /// The permissions of which at least one is required by the request or None if the
+    /// permissions of the endpoint are not known.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let client = Graph::new("ACCESS_TOKEN");
+    /// let required = client.me().messages().list_messages().required_permissions();
+    /// println!("{:#?}", required.map(|required| required.delegated));
+    /// ```
+    pub fn required_permissions(&self) -> Option<&'static RequiredPermissions> {
+        RequiredPermissions::find(
+            &self.request_components.method,
+            &self.request_components.url,
+        )
+    }
+
+    /// Check the scp and roles claims of the access token against the permissions required
+    /// by the request before sending it. The request fails with
+    /// [GraphFailure::MissingPermission] instead of a 403 Forbidden response if the token
+    /// has none of the required permissions, or [GraphFailure::DelegatedOnlyEndpoint] if
+    /// an application token is used for an endpoint such as `/me`.
+    ///
+    /// Requests to endpoints with unknown permissions and access tokens that are not a
+    /// JWT, such as tokens for personal Microsoft accounts, are not checked.
+    pub fn check_permissions(mut self) -> Self {
+        self.permission_check = true;
+        self
+    }
+
+    /// A redacted representation of the request, without the access token, that
+    /// can be printed as HTTP text or a curl command for debugging. See [RequestDump].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let request_handler = client.me().get_user().select(&["displayName"]);
+    /// println!("{}", request_handler.dump_request());
+    /// ```
+    pub fn dump_request(&self) -> RequestDump {
+        RequestDump::new(
+            &self.request_components.method,
+            &self.request_components.url,
+            &self.request_components.headers,
+            self.body.as_ref(),
+        )
+    }
+
     pub fn paging(self) -> Paging {
-        Paging(self)
+        Paging {
+            handler: self,
+            throttle_retries: 0,
+            next_link: None,
+            started: false,
+        }
     }
 
     pub(crate) async fn default_request_builder_with_token(
@@ -179,7 +326,12 @@ impl RequestHandler {
             .headers(self.request_components.headers.clone());
 
         if let Some(body) = self.body.take() {
-            if body.has_byte_buf() {
+            if let Some(content_type) = body.content_type() {
+                self.request_components
+                    .headers
+                    .entry(CONTENT_TYPE)
+                    .or_insert(content_type.clone());
+            } else if body.has_byte_buf() {
                 self.request_components
                     .headers
                     .entry(CONTENT_TYPE)
@@ -206,6 +358,13 @@ impl RequestHandler {
             .client_application
             .get_token_silent_async()
             .await?;
+        if self.permission_check {
+            check_permissions(
+                &self.request_components.method,
+                &self.request_components.url,
+                access_token.as_str(),
+            )?;
+        }
 
         let request_builder = self
             .inner
@@ -218,7 +377,12 @@ impl RequestHandler {
             .headers(self.request_components.headers.clone());
 
         if let Some(body) = self.body.take() {
-            if body.has_byte_buf() {
+            if let Some(content_type) = body.content_type() {
+                self.request_components
+                    .headers
+                    .entry(CONTENT_TYPE)
+                    .or_insert(content_type.clone());
+            } else if body.has_byte_buf() {
                 self.request_components
                     .headers
                     .entry(CONTENT_TYPE)
@@ -246,21 +410,145 @@ impl RequestHandler {
         self.default_request_builder().await
     }
 
+    /// Builds the request, including the access token, and converts it into an
+    /// [http::Request] that can be sent using http clients and tower services other
+    /// than the client of this crate.
+    pub async fn into_http_request(self) -> GraphResult<http::Request<reqwest::Body>> {
+        let request = self.build().await?.build()?;
+        Ok(http::Request::try_from(request)?)
+    }
+
     #[inline]
     pub async fn send(self) -> GraphResult<reqwest::Response> {
         let mut service = self.service.clone();
+        let log_policy = self.inner.log_policy();
+        let resource_identity = self.request_components.resource_identity;
         let request_builder = self.build().await?;
         let request = request_builder.build()?;
-        service
+
+        let method = request.method().clone();
+        let url = request.url().to_string();
+        tracing::debug!(
+            target: HTTP_PIPELINE,
+            resource = ?resource_identity,
+            %method,
+            url = log_policy.redact(url.as_str()),
+            "sending request"
+        );
+
+        let response = service
             .ready()
             .await
             .map_err(GraphFailure::from)?
             .call(request)
             .await
-            .map_err(GraphFailure::from)
+            .map_err(GraphFailure::from)?;
+
+        tracing::debug!(
+            target: HTTP_PIPELINE,
+            resource = ?resource_identity,
+            %method,
+            status = response.status().as_u16(),
+            request_id = response
+                .headers()
+                .get("request-id")
+                .and_then(|value| value.to_str().ok())
+                .unwrap_or_default(),
+            "response received"
+        );
+        Ok(response)
+    }
+
+    /// Send the request and deserialize the response body into a [GraphResponse]
+    /// which keeps the status, headers, and url of the response.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let response: GraphResponse<serde_json::Value> = client
+    ///     .me()
+    ///     .get_user()
+    ///     .send_json()
+    ///     .await?;
+    ///
+    /// println!("{:#?}", response.request_id());
+    /// ```
+    pub async fn send_json<T: DeserializeOwned>(self) -> GraphResult<GraphResponse<T>> {
+        let response = self.send().await?;
+        response.into_graph_response().await
+    }
+
+    /// Send a `$count` request, such as `get_users_count`, and parse the plain text
+    /// integer returned in the response body.
+    ///
+    /// The `ConsistencyLevel: eventual` header that is required to count directory
+    /// objects is added if the request does not already have it.
+    pub async fn send_count(mut self) -> GraphResult<u64> {
+        self.request_components
+            .headers
+            .entry(CONSISTENCY_LEVEL)
+            .or_insert(HeaderValue::from_static("eventual"));
+
+        let response = self.send().await?.error_for_graph_status().await?;
+
+        parse_count(response.text().await?.as_str())
+    }
+
+    /// Get the number of items in a collection without fetching a page of the items.
+    ///
+    /// The `$count` segment is added to the url of the collection, such as
+    /// `/users/$count`, and the request is sent using [RequestHandler::send_count].
+    /// Query parameters such as `$filter` and `$search` are kept so that only the
+    /// matching items are counted.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let count = client
+    ///     .users()
+    ///     .list_user()
+    ///     .filter(&["accountEnabled eq false"])
+    ///     .count_only()
+    ///     .await?;
+    /// ```
+    pub async fn count_only(mut self) -> GraphResult<u64> {
+        count_segment(&mut self.request_components.url);
+        self.send_count().await
     }
 }
 
+/// Add the `$count` segment to the url of a collection unless it is already the last
+/// segment of the path.
+pub(crate) fn count_segment(url: &mut Url) {
+    if url
+        .path_segments()
+        .and_then(|mut segments| segments.next_back())
+        == Some("$count")
+    {
+        return;
+    }
+
+    if let Ok(mut segments) = url.path_segments_mut() {
+        segments.pop_if_empty().push("$count");
+    }
+}
+
+/// Delta queries such as `/users/delta` or `/me/mailFolders/{id}/messages/delta()`.
+pub(crate) fn is_delta_request(url: &Url) -> bool {
+    url.path_segments()
+        .and_then(|mut segments| segments.next_back())
+        .is_some_and(|segment| {
+            segment == "delta" || segment.starts_with("delta(") || segment.contains(".delta")
+        })
+}
+
+/// Parse the body of a `$count` response. The body is a plain text integer that may
+/// start with a byte order mark.
+pub(crate) fn parse_count(body: &str) -> GraphResult<u64> {
+    body.trim_start_matches('\u{feff}')
+        .trim()
+        .parse::<u64>()
+        .map_err(|_| GraphFailure::invalid("integer in the body of the $count response"))
+}
+
 impl ODataQuery for RequestHandler {
     fn append_query_pair<KV: AsRef<str>>(self, key: KV, value: KV) -> Self {
         self.append_query_pair(key.as_ref(), value.as_ref())
@@ -282,9 +570,153 @@ impl AsMut<Url> for RequestHandler {
 pub type PagingResponse<T> = http::Response<Result<T, ErrorMessage>>;
 pub type PagingResult<T> = GraphResult<PagingResponse<T>>;
 
-pub struct Paging(RequestHandler);
+pub struct Paging {
+    handler: RequestHandler,
+    throttle_retries: usize,
+    next_link: Option<String>,
+    started: bool,
+}
 
 impl Paging {
+    /// Retry next link requests that are throttled with a 429 Too Many Requests or
+    /// 503 Service Unavailable response up to `max_retries` times, waiting for the
+    /// duration of the Retry-After header before each retry.
+    ///
+    /// The [`GraphClientConfiguration`](crate::api_impl::GraphClientConfiguration) retry
+    /// settings only apply to the first request and not to the next link requests made
+    /// when paging. Some resources, such as Teams channel and chat messages, have
+    /// stricter throttling limits than the rest of the api which makes throttling
+    /// while paging more likely.
+    ///
+    /// When the response does not include a Retry-After header an exponential back-off
+    /// is used starting at one second.
+    ///
+    /// Default is no retry.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let deque = client
+    ///     .team("TEAM_ID")
+    ///     .channel("CHANNEL_ID")
+    ///     .messages()
+    ///     .list_messages()
+    ///     .paging()
+    ///     .throttle_retries(3)
+    ///     .json::<serde_json::Value>()
+    ///     .await?;
+    /// ```
+    pub fn throttle_retries(mut self, max_retries: usize) -> Paging {
+        self.throttle_retries = max_retries;
+        self
+    }
+
+    /// Start paging at a next link returned by a previous paging session, such as a
+    /// next link saved as a checkpoint before the process was restarted. The next link
+    /// replaces the url and query of the request.
+    ///
+    /// The next link is sent the access token of the client so it must be on the same
+    /// host as the request.
+    pub fn resume_from(mut self, next_link: impl AsRef<str>) -> GraphResult<Paging> {
+        let url = Url::parse(next_link.as_ref())?;
+        if url.origin() != self.handler.request_components.url.origin() {
+            return Err(GraphFailure::invalid(
+                "next link on the host of the request",
+            ));
+        }
+
+        self.handler.request_components.url = url;
+        Ok(self)
+    }
+
+    /// The next link of the last page returned by [Paging::next_page], or None if
+    /// there are no more pages or no page has been requested yet.
+    pub fn next_link(&self) -> Option<&str> {
+        self.next_link.as_deref()
+    }
+
+    /// Get one page at a time. Returns None when there are no more pages.
+    ///
+    /// Save the [Paging::next_link] after processing each page to resume paging from
+    /// that page using [Paging::resume_from] if the process is restarted. A page that
+    /// fails to send, such as due to a network error, can be retried by calling
+    /// `next_page` again.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let mut paging = client.users().list_user().page_size(100).paging();
+    /// if let Some(next_link) = checkpoint.load()? {
+    ///     paging = paging.resume_from(next_link)?;
+    /// }
+    ///
+    /// while let Some(response) = paging.next_page::<Collection<User>>().await? {
+    ///     for user in response.into_body()? {
+    ///         println!("{:#?}", user.display_name);
+    ///     }
+    ///     checkpoint.save(paging.next_link())?;
+    /// }
+    /// ```
+    pub async fn next_page<T: DeserializeOwned>(
+        &mut self,
+    ) -> GraphResult<Option<PagingResponse<T>>> {
+        let response = match self.next_link.clone() {
+            Some(next_link) => {
+                let access_token = self
+                    .handler
+                    .inner
+                    .client_application
+                    .get_token_silent_async()
+                    .await?;
+                Paging::send_next_link(
+                    &self.handler.inner.inner,
+                    next_link.as_str(),
+                    access_token.as_str(),
+                    self.throttle_retries,
+                )
+                .await?
+            }
+            None if self.started => return Ok(None),
+            None => {
+                if let Some(err) = self.handler.error.take() {
+                    return Err(err);
+                }
+                let (_, request) = self.handler.default_request_builder_with_token().await?;
+                request.send().await?
+            }
+        };
+
+        let (next_link, http_response) = Paging::http_response(response).await?;
+        self.next_link = next_link;
+        self.started = true;
+        Ok(Some(http_response))
+    }
+
+    fn retry_after(headers: &HeaderMap, attempt: u32) -> Duration {
+        retry_after(headers).unwrap_or_else(|| Duration::from_secs(2u64.pow(attempt.min(6))))
+    }
+
+    async fn send_next_link(
+        client: &reqwest::Client,
+        url: &str,
+        access_token: &str,
+        max_retries: usize,
+    ) -> GraphResult<reqwest::Response> {
+        let mut attempt = 0;
+        loop {
+            let response = client.get(url).bearer_auth(access_token).send().await?;
+            let throttled = matches!(
+                response.status(),
+                StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE
+            );
+
+            if !throttled || attempt >= max_retries {
+                return Ok(response);
+            }
+
+            tokio::time::sleep(Paging::retry_after(response.headers(), attempt as u32)).await;
+            attempt += 1;
+        }
+    }
+
     async fn http_response<T: DeserializeOwned>(
         response: reqwest::Response,
     ) -> GraphResult<(Option<String>, PagingResponse<T>)> {
@@ -356,11 +788,11 @@ impl Paging {
     ///
     /// ```
     pub async fn json<T: DeserializeOwned>(mut self) -> GraphResult<VecDeque<PagingResponse<T>>> {
-        if let Some(err) = self.0.error {
+        if let Some(err) = self.handler.error {
             return Err(err);
         }
 
-        let (access_token, request) = self.0.default_request_builder_with_token().await?;
+        let (access_token, request) = self.handler.default_request_builder_with_token().await?;
         let response = request.send().await?;
 
         let (next, http_response) = Paging::http_response(response).await?;
@@ -368,13 +800,15 @@ impl Paging {
         let mut vec = VecDeque::new();
         vec.push_back(http_response);
 
-        let client = self.0.inner.inner.clone();
+        let client = self.handler.inner.inner.clone();
         while let Some(next) = next_link {
-            let response = client
-                .get(next)
-                .bearer_auth(access_token.as_str())
-                .send()
-                .await?;
+            let response = Paging::send_next_link(
+                &client,
+                next.as_str(),
+                access_token.as_str(),
+                self.throttle_retries,
+            )
+            .await?;
 
             let (next, http_response) = Paging::http_response(response).await?;
 
@@ -389,20 +823,20 @@ impl Paging {
         mut self,
     ) -> impl Stream<Item = PagingResult<T>> + 'a {
         try_stream! {
-            let (access_token, request) = self.0.default_request_builder_with_token().await?;
+            let (access_token, request) = self.handler.default_request_builder_with_token().await?;
             let response = request.send().await?;
             let (next, http_response) = Paging::http_response(response).await?;
             let mut next_link = next;
             yield http_response;
 
             while let Some(url) = next_link {
-                let response = self.0
-                    .inner
-                    .inner
-                    .get(url)
-                    .bearer_auth(access_token.as_str())
-                    .send()
-                    .await?;
+                let response = Paging::send_next_link(
+                    &self.handler.inner.inner,
+                    url.as_str(),
+                    access_token.as_str(),
+                    self.throttle_retries,
+                )
+                .await?;
                 let (next, http_response) = Paging::http_response(response).await?;
                 next_link = next;
                 yield http_response;
@@ -429,13 +863,169 @@ impl Paging {
     pub fn stream<'a, T: DeserializeOwned + 'a>(
         mut self,
     ) -> GraphResult<impl Stream<Item = PagingResult<T>> + 'a> {
-        if let Some(err) = self.0.error.take() {
+        if let Some(err) = self.handler.error.take() {
             return Err(err);
         }
 
         Ok(Box::pin(self.try_stream()))
     }
 
+    fn try_item_stream<'a, T: DeserializeOwned + 'a>(
+        mut self,
+    ) -> impl Stream<Item = GraphResult<T>> + 'a {
+        try_stream! {
+            let (access_token, request) = self.handler.default_request_builder_with_token().await?;
+            let mut response = request.send().await?.error_for_graph_status().await?;
+
+            loop {
+                let mut decoder = PageDecoder::new();
+                let mut body = response.bytes_stream();
+                while let Some(chunk) = body.next().await {
+                    for item in decoder.decode::<T>(chunk?.as_ref())? {
+                        yield item;
+                    }
+                }
+
+                match decoder.finish()? {
+                    Some(next_link) => {
+                        let next = Paging::send_next_link(
+                            &self.handler.inner.inner,
+                            next_link.as_str(),
+                            access_token.as_str(),
+                            self.throttle_retries,
+                        )
+                        .await?;
+                        response = next.error_for_graph_status().await?;
+                    }
+                    None => break,
+                }
+            }
+        }
+    }
+
+    /// Stream the items of the current request and any next link requests, deserializing
+    /// each item of the `value` array as the bytes of the response body are received.
+    ///
+    /// Unlike [Paging::stream], which deserializes a whole page at once, only the item
+    /// that is being received is kept in memory. Use this for large pages such as
+    /// `driveItems` or audit logs with a page size of 999. Returns an error if a page
+    /// is not successful or a page ends before the whole body is received.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let mut stream = client
+    ///     .audit_logs()
+    ///     .list_directory_audits()
+    ///     .page_size(999)
+    ///     .paging()
+    ///     .stream_items::<serde_json::Value>()?;
+    ///
+    /// while let Some(item) = stream.next().await {
+    ///     export(item?).await?;
+    /// }
+    /// ```
+    pub fn stream_items<'a, T: DeserializeOwned + 'a>(
+        mut self,
+    ) -> GraphResult<impl Stream<Item = GraphResult<T>> + 'a> {
+        if let Some(err) = self.handler.error.take() {
+            return Err(err);
+        }
+
+        Ok(Box::pin(self.try_item_stream()))
+    }
+
+    /// Stream the current request along with any next link requests while the next pages
+    /// are requested in the background, up to `lookahead` pages ahead of the caller.
+    ///
+    /// Next links are only known once the previous page is received so pages are still
+    /// requested one at a time, but the request for the next page is sent while the caller
+    /// processes the current page. Requests pause when `lookahead` pages are waiting to be
+    /// taken from the stream, and stop when the stream is dropped.
+    ///
+    /// Prefetching is throttling-aware. Before each next link request it waits for the
+    /// Retry-After of any throttled response of the client, or until the resource units of
+    /// the [ThrottleBudget](crate::api_impl::ThrottleBudget) are replenished when none
+    /// remain, and the responses update the budget of the client. Use
+    /// [Paging::throttle_retries] to retry throttled next link requests.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let mut stream = client
+    ///     .users()
+    ///     .list_user()
+    ///     .page_size(999)
+    ///     .paging()
+    ///     .throttle_retries(3)
+    ///     .prefetch::<Collection<User>>(2)
+    ///     .await?;
+    ///
+    /// while let Some(result) = stream.next().await {
+    ///     for user in result?.into_body()? {
+    ///         export(user).await?;
+    ///     }
+    /// }
+    /// ```
+    pub async fn prefetch<T: DeserializeOwned + Send + 'static>(
+        mut self,
+        lookahead: usize,
+    ) -> GraphResult<impl Stream<Item = PagingResult<T>> + Send + 'static> {
+        if let Some(err) = self.handler.error.take() {
+            return Err(err);
+        }
+
+        let (sender, receiver) = tokio::sync::mpsc::channel(lookahead.max(1));
+
+        let (access_token, request) = self.handler.default_request_builder_with_token().await?;
+        let response = request.send().await?;
+        let (next, http_response) = Paging::http_response(response).await?;
+        let mut next_link = next;
+        let _ = sender.send(Ok(http_response)).await;
+
+        let client = self.handler.inner.inner.clone();
+        let budget = self.handler.inner.throttle_budget();
+        let throttle_retries = self.throttle_retries;
+        tokio::spawn(async move {
+            while let Some(next) = next_link.take() {
+                if let Some(delay) = budget.delay(Some(0)) {
+                    tokio::time::sleep(delay).await;
+                }
+
+                let result = match Paging::send_next_link(
+                    &client,
+                    next.as_str(),
+                    access_token.as_str(),
+                    throttle_retries,
+                )
+                .await
+                {
+                    Ok(response) => {
+                        budget.update(response.status(), response.headers());
+                        Paging::http_response(response).await
+                    }
+                    Err(err) => Err(err),
+                };
+
+                match result {
+                    Ok((next, response)) => {
+                        if sender.send(Ok(response)).await.is_err() {
+                            break;
+                        }
+                        next_link = next;
+                    }
+                    Err(err) => {
+                        let _ = sender.send(Err(err)).await;
+                        break;
+                    }
+                }
+            }
+        });
+
+        Ok(Box::pin(futures::stream::unfold(
+            receiver,
+            |mut receiver| async move { receiver.recv().await.map(|result| (result, receiver)) },
+        )))
+    }
+
     /// Get next link responses using a channel Receiver [`tokio::sync::mpsc::Receiver<Option<GraphResult<http::Response<T>>>>`].
     ///
     /// By default channels use [`tokio::sync::mpsc::Sender::send_timeout`] with a buffer of 100
@@ -504,8 +1094,9 @@ impl Paging {
         client: &reqwest::Client,
         url: &str,
         access_token: &str,
+        max_retries: usize,
     ) -> GraphResult<(Option<String>, PagingResponse<T>)> {
-        let response = client.get(url).bearer_auth(access_token).send().await?;
+        let response = Paging::send_next_link(client, url, access_token, max_retries).await?;
 
         Paging::http_response(response).await
     }
@@ -544,7 +1135,7 @@ impl Paging {
     ) -> GraphResult<tokio::sync::mpsc::Receiver<PagingResult<T>>> {
         let (sender, receiver) = tokio::sync::mpsc::channel(buffer);
 
-        let (access_token, request) = self.0.default_request_builder_with_token().await?;
+        let (access_token, request) = self.handler.default_request_builder_with_token().await?;
         let response = request.send().await?;
         let (next, http_response) = Paging::http_response(response).await?;
         let mut next_link = next;
@@ -553,12 +1144,17 @@ impl Paging {
             .await
             .unwrap();
 
-        let client = self.0.inner.inner.clone();
+        let client = self.handler.inner.inner.clone();
+        let throttle_retries = self.throttle_retries;
         tokio::spawn(async move {
             while let Some(next) = next_link {
-                let result =
-                    Paging::send_channel_request(&client, next.as_str(), access_token.as_str())
-                        .await;
+                let result = Paging::send_channel_request(
+                    &client,
+                    next.as_str(),
+                    access_token.as_str(),
+                    throttle_retries,
+                )
+                .await;
 
                 match result {
                     Ok((next, response)) => {
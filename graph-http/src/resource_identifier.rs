@@ -11,6 +11,9 @@ pub struct ResourceConfig {
     pub resource_identity: ResourceIdentity,
     pub url: Url,
     pub resource_identity_id: Option<String>,
+    /// The id is a path, such as the `{hostname}:/{path}:` id of a site, whose
+    /// separators are kept when it is added to the url.
+    pub path_id: bool,
 }
 
 impl ResourceConfig {
@@ -23,6 +26,7 @@ impl ResourceConfig {
             resource_identity,
             url,
             resource_identity_id,
+            path_id: false,
         }
     }
 }
@@ -37,6 +41,28 @@ impl ResourceConfig {
             p.extend(path);
         }
     }
+
+    /// Use a path, such as the `{hostname}:/{path}:` id of a site, as the id of the
+    /// resource. Unlike other ids the separators of the path are not percent encoded.
+    pub fn set_path_id(&mut self, id: impl Into<String>) {
+        self.resource_identity_id = Some(id.into());
+        self.path_id = true;
+    }
+
+    /// Append the path of the resource and its id, if any, to the url. The id is
+    /// removed because it is now part of the url.
+    pub fn extend_path_with_id<S: AsRef<str>>(&mut self, resource_path: S) {
+        self.extend_path(&[resource_path]);
+        let path_id = std::mem::take(&mut self.path_id);
+        if let Some(id) = self.resource_identity_id.take() {
+            if path_id {
+                let segments: Vec<&str> = id.split('/').filter(|s| !s.is_empty()).collect();
+                self.extend_path(&segments);
+            } else {
+                self.extend_path(&[id]);
+            }
+        }
+    }
 }
 
 impl AsRef<Url> for ResourceConfig {
@@ -50,3 +76,44 @@ impl AsMut<Url> for ResourceConfig {
         &mut self.url
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn resource_config() -> ResourceConfig {
+        ResourceConfig::new(
+            ResourceIdentity::Sites,
+            Url::parse("https://graph.microsoft.com/v1.0").unwrap(),
+            None,
+        )
+    }
+
+    #[test]
+    fn id_with_separator_is_percent_encoded() {
+        let mut resource_config = resource_config();
+        resource_config.resource_identity_id = Some("contoso.sharepoint.com:/sites/a:".into());
+        resource_config.extend_path_with_id("sites");
+        assert_eq!(
+            "/v1.0/sites/contoso.sharepoint.com:%2Fsites%2Fa:",
+            resource_config.url.path()
+        );
+        assert_eq!(None, resource_config.resource_identity_id);
+
+        let mut resource_config = resource_config();
+        resource_config.extend_path(&["users", "a/b"]);
+        assert_eq!("/v1.0/users/a%2Fb", resource_config.url.path());
+    }
+
+    #[test]
+    fn path_id_keeps_separators() {
+        let mut resource_config = resource_config();
+        resource_config.set_path_id("contoso.sharepoint.com:/sites/a:");
+        resource_config.extend_path_with_id("sites");
+        assert_eq!(
+            "/v1.0/sites/contoso.sharepoint.com:/sites/a:",
+            resource_config.url.path()
+        );
+        assert!(!resource_config.path_id);
+    }
+}
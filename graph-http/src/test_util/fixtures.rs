@@ -0,0 +1,143 @@
+//! Canned json bodies of common Microsoft Graph resources for use with
+//! [MockResponse::json](crate::test_util::MockResponse::json).
+
+use serde_json::{json, Value};
+
+pub const USER_ID: &str = "87d349ed-44d7-43e1-9a83-5f2406dee5bd";
+pub const GROUP_ID: &str = "02bd9fd6-8f93-4758-87c3-1fb73740a315";
+pub const DRIVE_ID: &str = "b!-RIj2DuyvEyV1T4NlOaMHk8XkS_I8MdFlUCq1BlcjgmhRfAj3-Z8RY2VpuvV_tpd";
+pub const DRIVE_ITEM_ID: &str = "01BYE5RZ6QN3ZWBTUFOFD3GSPGOHDJD36K";
+pub const MESSAGE_ID: &str = "AAMkAGVmMDEzMTM4LTZmYWUtNDdkNC1hMDZiLTU1OGY5OTZhYmY4OABGAAAAAAAiQ8W967B7TKBjgx9rVEURBwAiIsqMbYjsT5e-T7KzowPTAAAAAAEMAAAiIsqMbYjsT5e-T7KzowPTAAAYbvZFAAA=";
+pub const EVENT_ID: &str = "AAMkAGIAAAoZDOFAAA=";
+pub const EVENT_ETAG: &str = "W/\"ZlnW4RIAV06KYYwlrfNZvQAAKGWwbw==\"";
+
+pub fn user() -> Value {
+    json!({
+        "@odata.context": "https://graph.microsoft.com/v1.0/$metadata#users/$entity",
+        "id": USER_ID,
+        "businessPhones": ["+1 425 555 0109"],
+        "displayName": "Adele Vance",
+        "givenName": "Adele",
+        "jobTitle": "Retail Manager",
+        "mail": "AdeleV@contoso.com",
+        "mobilePhone": null,
+        "officeLocation": "18/2111",
+        "preferredLanguage": "en-US",
+        "surname": "Vance",
+        "userPrincipalName": "AdeleV@contoso.com"
+    })
+}
+
+pub fn group() -> Value {
+    json!({
+        "@odata.context": "https://graph.microsoft.com/v1.0/$metadata#groups/$entity",
+        "id": GROUP_ID,
+        "description": "Self help community for library",
+        "displayName": "Library Assist",
+        "groupTypes": ["Unified"],
+        "mail": "library2@contoso.com",
+        "mailEnabled": true,
+        "mailNickname": "library",
+        "securityEnabled": false,
+        "visibility": "Public"
+    })
+}
+
+pub fn message() -> Value {
+    json!({
+        "@odata.context": "https://graph.microsoft.com/v1.0/$metadata#users('AdeleV%40contoso.com')/messages/$entity",
+        "id": MESSAGE_ID,
+        "createdDateTime": "2024-04-08T18:21:11Z",
+        "receivedDateTime": "2024-04-08T18:21:12Z",
+        "hasAttachments": false,
+        "subject": "Meet for lunch?",
+        "bodyPreview": "The new cafeteria is open.",
+        "importance": "normal",
+        "isRead": false,
+        "body": {
+            "contentType": "text",
+            "content": "The new cafeteria is open."
+        },
+        "from": {
+            "emailAddress": {
+                "name": "Megan Bowen",
+                "address": "MeganB@contoso.com"
+            }
+        },
+        "toRecipients": [{
+            "emailAddress": {
+                "name": "Adele Vance",
+                "address": "AdeleV@contoso.com"
+            }
+        }]
+    })
+}
+
+pub fn event() -> Value {
+    json!({
+        "@odata.context": "https://graph.microsoft.com/v1.0/$metadata#users('AdeleV%40contoso.com')/events/$entity",
+        "@odata.etag": EVENT_ETAG,
+        "id": EVENT_ID,
+        "subject": "Let's go for lunch",
+        "body": {
+            "contentType": "html",
+            "content": "Does noon work for you?"
+        },
+        "start": {
+            "dateTime": "2024-04-15T12:00:00.0000000",
+            "timeZone": "Pacific Standard Time"
+        },
+        "end": {
+            "dateTime": "2024-04-15T14:00:00.0000000",
+            "timeZone": "Pacific Standard Time"
+        },
+        "location": {
+            "displayName": "Harry's Bar"
+        },
+        "isOnlineMeeting": false
+    })
+}
+
+pub fn drive_item() -> Value {
+    json!({
+        "@odata.context": "https://graph.microsoft.com/v1.0/$metadata#drives('drive-id')/items/$entity",
+        "id": DRIVE_ITEM_ID,
+        "name": "Document.docx",
+        "size": 10240,
+        "createdDateTime": "2024-04-08T18:21:11Z",
+        "lastModifiedDateTime": "2024-04-08T18:21:11Z",
+        "webUrl": "https://contoso.sharepoint.com/Documents/Document.docx",
+        "file": {
+            "mimeType": "application/vnd.openxmlformats-officedocument.wordprocessingml.document"
+        },
+        "parentReference": {
+            "driveId": DRIVE_ID,
+            "driveType": "business",
+            "path": "/drive/root:"
+        }
+    })
+}
+
+/// A collection response with the values and an optional `@odata.nextLink`.
+pub fn collection(values: Vec<Value>, next_link: Option<&str>) -> Value {
+    let mut body = json!({ "value": values });
+    if let Some(next_link) = next_link {
+        body["@odata.nextLink"] = Value::String(next_link.to_owned());
+    }
+    body
+}
+
+/// A Microsoft Graph error response body.
+pub fn error(code: &str, message: &str) -> Value {
+    json!({
+        "error": {
+            "code": code,
+            "message": message,
+            "innerError": {
+                "date": "2024-04-08T18:21:11",
+                "request-id": "b6f1d8a0-5b3f-4c1e-9e5c-2d1a8c7b9f00",
+                "client-request-id": "b6f1d8a0-5b3f-4c1e-9e5c-2d1a8c7b9f00"
+            }
+        }
+    })
+}
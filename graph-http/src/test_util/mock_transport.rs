@@ -0,0 +1,250 @@
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+use futures_util::future;
+use http::{HeaderMap, HeaderName, HeaderValue, Method, StatusCode};
+use reqwest::{Request, Response};
+use url::Url;
+
+use crate::test_util::{fixtures, Recording, RequestMatcher};
+
+/// A canned response returned by a [MockTransport].
+#[derive(Clone, Debug)]
+pub struct MockResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Vec<u8>,
+}
+
+impl MockResponse {
+    pub fn new(status: u16) -> MockResponse {
+        MockResponse {
+            status: StatusCode::from_u16(status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+            headers: HeaderMap::new(),
+            body: Vec::new(),
+        }
+    }
+
+    /// A response with a json body and a `Content-Type: application/json` header.
+    pub fn json(status: u16, body: &serde_json::Value) -> MockResponse {
+        MockResponse::new(status)
+            .header(
+                http::header::CONTENT_TYPE,
+                HeaderValue::from_static("application/json"),
+            )
+            .body(body.to_string())
+    }
+
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.append(name, value);
+        self
+    }
+
+    pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    fn to_response(&self) -> Response {
+        let mut builder = http::Response::builder().status(self.status);
+        if let Some(headers) = builder.headers_mut() {
+            headers.extend(self.headers.clone());
+        }
+        Response::from(
+            builder
+                .body(self.body.clone())
+                .unwrap_or_else(|_| http::Response::new(Vec::new())),
+        )
+    }
+}
+
+/// A request that was sent to a [MockTransport].
+#[derive(Clone, Debug)]
+pub struct ReceivedRequest {
+    pub method: Method,
+    pub url: Url,
+    pub headers: HeaderMap,
+    pub body: Option<Vec<u8>>,
+}
+
+impl ReceivedRequest {
+    pub fn body_json(&self) -> Option<serde_json::Value> {
+        serde_json::from_slice(self.body.as_ref()?).ok()
+    }
+}
+
+#[derive(Debug)]
+struct Mock {
+    matcher: RequestMatcher,
+    response: MockResponse,
+    remaining: Option<usize>,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    mocks: Vec<Mock>,
+    received_requests: Vec<ReceivedRequest>,
+}
+
+/// Sends requests of the Graph client to mocks instead of the network.
+///
+/// Mocks are checked in the order they were added and the response of the first mock
+/// that matches the request is returned. Requests that do not match a mock receive a
+/// `404 Not Found` response with a Graph error body.
+///
+/// Clones of the transport share the mocks and received requests so that a clone
+/// can be given to the client and the original used for assertions.
+/// See [GraphClientConfiguration::transport](crate::api_impl::GraphClientConfiguration::transport)
+#[derive(Clone, Debug, Default)]
+pub struct MockTransport {
+    state: Arc<Mutex<MockState>>,
+}
+
+impl MockTransport {
+    pub fn new() -> MockTransport {
+        MockTransport::default()
+    }
+
+    /// Create a transport that replays responses captured by a
+    /// [RecordingTransport](crate::test_util::RecordingTransport).
+    pub fn replay(recordings: &[Recording]) -> MockTransport {
+        let transport = MockTransport::new();
+        for recording in recordings {
+            transport.mock_times(recording.matcher(), recording.response(), 1);
+        }
+        transport
+    }
+
+    /// Respond to every request that matches.
+    pub fn mock(&self, matcher: RequestMatcher, response: MockResponse) {
+        self.push(matcher, response, None);
+    }
+
+    /// Respond to the first n requests that match.
+    pub fn mock_times(&self, matcher: RequestMatcher, response: MockResponse, n: usize) {
+        self.push(matcher, response, Some(n));
+    }
+
+    fn push(&self, matcher: RequestMatcher, response: MockResponse, remaining: Option<usize>) {
+        self.lock().mocks.push(Mock {
+            matcher,
+            response,
+            remaining,
+        });
+    }
+
+    /// The requests received by the transport in the order they were sent.
+    pub fn received_requests(&self) -> Vec<ReceivedRequest> {
+        self.lock().received_requests.clone()
+    }
+
+    /// Remove all mocks and received requests.
+    pub fn reset(&self) {
+        let mut state = self.lock();
+        state.mocks.clear();
+        state.received_requests.clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, MockState> {
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    fn respond(&self, request: Request) -> Response {
+        let mut state = self.lock();
+        state.received_requests.push(ReceivedRequest {
+            method: request.method().clone(),
+            url: request.url().clone(),
+            headers: request.headers().clone(),
+            body: request
+                .body()
+                .and_then(|body| body.as_bytes())
+                .map(|bytes| bytes.to_vec()),
+        });
+
+        let mock = state.mocks.iter_mut().find(|mock| {
+            mock.remaining.map(|n| n > 0).unwrap_or(true) && mock.matcher.matches(&request)
+        });
+
+        match mock {
+            Some(mock) => {
+                if let Some(remaining) = mock.remaining.as_mut() {
+                    *remaining -= 1;
+                }
+                mock.response.to_response()
+            }
+            None => MockResponse::json(
+                404,
+                &fixtures::error(
+                    "MockNotFound",
+                    &format!("No mock matched {} {}", request.method(), request.url()),
+                ),
+            )
+            .to_response(),
+        }
+    }
+}
+
+impl tower::Service<Request> for MockTransport {
+    type Response = Response;
+    type Error = tower::BoxError;
+    type Future = future::Ready<Result<Response, tower::BoxError>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request) -> Self::Future {
+        future::ready(Ok(self.respond(request)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use tower::ServiceExt;
+
+    fn request(method: Method, url: &str) -> Request {
+        Request::new(method, url.parse().unwrap())
+    }
+
+    #[tokio::test]
+    async fn respond_with_first_matching_mock() {
+        let transport = MockTransport::new();
+        transport.mock_times(
+            RequestMatcher::new().path("/v1.0/me"),
+            MockResponse::json(200, &fixtures::user()),
+            1,
+        );
+        transport.mock(
+            RequestMatcher::new().path("/v1.0/me"),
+            MockResponse::new(429),
+        );
+
+        let response = transport
+            .clone()
+            .oneshot(request(Method::GET, "https://graph.microsoft.com/v1.0/me"))
+            .await
+            .unwrap();
+        assert_eq!(StatusCode::OK, response.status());
+        let body: serde_json::Value = response.json().await.unwrap();
+        assert_eq!(fixtures::user(), body);
+
+        let response = transport
+            .clone()
+            .oneshot(request(Method::GET, "https://graph.microsoft.com/v1.0/me"))
+            .await
+            .unwrap();
+        assert_eq!(StatusCode::TOO_MANY_REQUESTS, response.status());
+
+        let response = transport
+            .clone()
+            .oneshot(request(
+                Method::GET,
+                "https://graph.microsoft.com/v1.0/users",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(StatusCode::NOT_FOUND, response.status());
+        assert_eq!(3, transport.received_requests().len());
+    }
+}
@@ -0,0 +1,34 @@
+//! Utilities for unit testing code that uses the Graph client without sending
+//! requests to Microsoft Graph.
+//!
+//! Requires feature `test-util`
+//!
+//! # Example
+//! ```rust,ignore
+//! use graph_rs_sdk::test_util::{fixtures, MockResponse, MockTransport, RequestMatcher};
+//! use graph_rs_sdk::{http::Method, Graph, GraphClientConfiguration};
+//!
+//! let transport = MockTransport::new();
+//! transport.mock(
+//!     RequestMatcher::new().method(Method::GET).path("/v1.0/me"),
+//!     MockResponse::json(200, &fixtures::user()),
+//! );
+//!
+//! let client = Graph::from(
+//!     GraphClientConfiguration::new()
+//!         .access_token("ACCESS_TOKEN")
+//!         .transport(transport.clone()),
+//! );
+//!
+//! let response = client.me().get_user().send().await?;
+//! assert_eq!(1, transport.received_requests().len());
+//! ```
+
+pub mod fixtures;
+mod mock_transport;
+mod recorder;
+mod request_matcher;
+
+pub use mock_transport::*;
+pub use recorder::*;
+pub use request_matcher::*;
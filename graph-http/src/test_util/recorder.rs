@@ -0,0 +1,257 @@
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+use graph_error::GraphResult;
+use http::{HeaderName, HeaderValue, Method};
+use reqwest::{Request, Response};
+use url::Url;
+
+use crate::test_util::{MockResponse, RequestMatcher};
+
+const REDACTED: &str = "REDACTED";
+
+/// Headers that are never written to recordings.
+const SECRET_HEADERS: [&str; 3] = ["authorization", "cookie", "set-cookie"];
+
+/// Json properties whose values are replaced in recorded response bodies.
+const SECRET_PROPERTIES: [&str; 8] = [
+    "access_token",
+    "refresh_token",
+    "id_token",
+    "client_secret",
+    "secrettext",
+    "password",
+    "@microsoft.graph.downloadurl",
+    "@content.downloadurl",
+];
+
+/// A response captured by a [RecordingTransport].
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct Recording {
+    pub method: String,
+    pub url: String,
+    pub status: u16,
+    pub headers: BTreeMap<String, String>,
+    pub body: String,
+}
+
+impl Recording {
+    /// Matches requests with the same method, path, and query.
+    pub(crate) fn matcher(&self) -> RequestMatcher {
+        let mut matcher = RequestMatcher::new();
+        if let Ok(method) = self.method.parse::<Method>() {
+            matcher = matcher.method(method);
+        }
+        if let Ok(url) = Url::parse(self.url.as_str()) {
+            matcher = matcher.path(url.path());
+            for (key, value) in url.query_pairs() {
+                matcher = matcher.query_param(key, value);
+            }
+        }
+        matcher
+    }
+
+    pub(crate) fn response(&self) -> MockResponse {
+        let mut response = MockResponse::new(self.status).body(self.body.as_str());
+        for (name, value) in self.headers.iter() {
+            if let (Ok(name), Ok(value)) = (
+                HeaderName::from_bytes(name.as_bytes()),
+                HeaderValue::from_str(value),
+            ) {
+                response = response.header(name, value);
+            }
+        }
+        response
+    }
+}
+
+/// Replace the values of secret properties anywhere in a json value.
+fn scrub_json(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if SECRET_PROPERTIES.contains(&key.to_lowercase().as_str()) {
+                    *value = serde_json::Value::String(REDACTED.to_owned());
+                } else {
+                    scrub_json(value);
+                }
+            }
+        }
+        serde_json::Value::Array(values) => values.iter_mut().for_each(scrub_json),
+        _ => {}
+    }
+}
+
+fn scrub_body(body: &[u8]) -> String {
+    match serde_json::from_slice::<serde_json::Value>(body) {
+        Ok(mut value) => {
+            scrub_json(&mut value);
+            value.to_string()
+        }
+        Err(_) => String::from_utf8_lossy(body).into_owned(),
+    }
+}
+
+/// Sends requests using a reqwest client and records the responses with secrets
+/// removed so that they can be saved and replayed in tests using [MockTransport::replay].
+///
+/// Authorization and cookie headers are not recorded and the values of token, secret,
+/// password, and pre-authenticated download url properties in json bodies are replaced.
+/// Bodies are stored as text so binary responses are not replayed exactly.
+///
+/// [MockTransport::replay]: crate::test_util::MockTransport::replay
+///
+/// # Example
+/// ```rust,ignore
+/// let recorder = RecordingTransport::new(reqwest::Client::new());
+/// let client = Graph::from(
+///     GraphClientConfiguration::new()
+///         .access_token("ACCESS_TOKEN")
+///         .transport(recorder.clone()),
+/// );
+///
+/// client.me().get_user().send().await?;
+/// recorder.save("tests/recordings/me.json")?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct RecordingTransport {
+    client: reqwest::Client,
+    recordings: Arc<Mutex<Vec<Recording>>>,
+}
+
+impl RecordingTransport {
+    pub fn new(client: reqwest::Client) -> RecordingTransport {
+        RecordingTransport {
+            client,
+            recordings: Default::default(),
+        }
+    }
+
+    pub fn recordings(&self) -> Vec<Recording> {
+        self.recordings
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .clone()
+    }
+
+    /// Write the recordings to a json file.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> GraphResult<()> {
+        let json = serde_json::to_string_pretty(&self.recordings())?;
+        std::fs::write(path, json)?;
+        Ok(())
+    }
+
+    /// Read recordings written by [RecordingTransport::save].
+    pub fn load<P: AsRef<Path>>(path: P) -> GraphResult<Vec<Recording>> {
+        let json = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(json.as_str())?)
+    }
+}
+
+impl tower::Service<Request> for RecordingTransport {
+    type Response = Response;
+    type Error = tower::BoxError;
+    type Future = Pin<Box<dyn Future<Output = Result<Response, tower::BoxError>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request) -> Self::Future {
+        let client = self.client.clone();
+        let recordings = self.recordings.clone();
+
+        Box::pin(async move {
+            let method = request.method().to_string();
+            let url = request.url().to_string();
+            let response = client.execute(request).await?;
+
+            let status = response.status();
+            let version = response.version();
+            let headers = response.headers().clone();
+            let body = response.bytes().await?;
+
+            let recording = Recording {
+                method,
+                url,
+                status: status.as_u16(),
+                headers: headers
+                    .iter()
+                    .filter(|(name, _)| !SECRET_HEADERS.contains(&name.as_str()))
+                    .filter_map(|(name, value)| {
+                        Some((name.to_string(), value.to_str().ok()?.to_owned()))
+                    })
+                    .collect(),
+                body: scrub_body(&body),
+            };
+            recordings
+                .lock()
+                .unwrap_or_else(|err| err.into_inner())
+                .push(recording);
+
+            let mut builder = http::Response::builder().status(status).version(version);
+            if let Some(builder_headers) = builder.headers_mut() {
+                builder_headers.extend(headers);
+            }
+            Ok(Response::from(builder.body(body)?))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn scrub_secrets() {
+        let body = serde_json::json!({
+            "access_token": "token",
+            "value": [{
+                "id": "1",
+                "@microsoft.graph.downloadUrl": "https://contoso.sharepoint.com/download?tempauth=secret",
+                "passwordCredentials": [{ "secretText": "secret" }]
+            }]
+        });
+
+        let scrubbed: serde_json::Value =
+            serde_json::from_str(scrub_body(body.to_string().as_bytes()).as_str()).unwrap();
+        assert_eq!(
+            serde_json::json!({
+                "access_token": "REDACTED",
+                "value": [{
+                    "id": "1",
+                    "@microsoft.graph.downloadUrl": "REDACTED",
+                    "passwordCredentials": [{ "secretText": "REDACTED" }]
+                }]
+            }),
+            scrubbed
+        );
+    }
+
+    #[test]
+    fn recording_matches_request() {
+        let recording = Recording {
+            method: "GET".into(),
+            url: "https://graph.microsoft.com/v1.0/users?$top=5".into(),
+            status: 200,
+            headers: Default::default(),
+            body: "{}".into(),
+        };
+
+        let matcher = recording.matcher();
+        assert!(matcher.matches(&Request::new(
+            Method::GET,
+            "https://graph.microsoft.com/v1.0/users?$top=5"
+                .parse()
+                .unwrap()
+        )));
+        assert!(!matcher.matches(&Request::new(
+            Method::GET,
+            "https://graph.microsoft.com/v1.0/users".parse().unwrap()
+        )));
+    }
+}
@@ -0,0 +1,138 @@
+use http::{HeaderName, HeaderValue, Method};
+use reqwest::Request;
+
+/// Matches requests sent to a [MockTransport](crate::test_util::MockTransport).
+/// A matcher without any conditions matches every request.
+#[derive(Clone, Debug, Default)]
+pub struct RequestMatcher {
+    method: Option<Method>,
+    path: Option<String>,
+    query: Vec<(String, String)>,
+    headers: Vec<(HeaderName, HeaderValue)>,
+    body_json: Option<serde_json::Value>,
+}
+
+impl RequestMatcher {
+    pub fn new() -> RequestMatcher {
+        RequestMatcher::default()
+    }
+
+    pub fn method(mut self, method: Method) -> Self {
+        self.method = Some(method);
+        self
+    }
+
+    /// The path of the url including the api version such as `/v1.0/me/messages`.
+    pub fn path(mut self, path: impl AsRef<str>) -> Self {
+        self.path = Some(path.as_ref().to_owned());
+        self
+    }
+
+    /// The query of the url must contain the key and value.
+    pub fn query_param(mut self, key: impl AsRef<str>, value: impl AsRef<str>) -> Self {
+        self.query
+            .push((key.as_ref().to_owned(), value.as_ref().to_owned()));
+        self
+    }
+
+    /// The request must have a header with the name and value.
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.push((name, value));
+        self
+    }
+
+    /// The request body must be json equal to the value.
+    pub fn body_json(mut self, body: serde_json::Value) -> Self {
+        self.body_json = Some(body);
+        self
+    }
+
+    pub fn matches(&self, request: &Request) -> bool {
+        if let Some(method) = self.method.as_ref() {
+            if request.method().ne(method) {
+                return false;
+            }
+        }
+
+        if let Some(path) = self.path.as_ref() {
+            if request.url().path().ne(path.as_str()) {
+                return false;
+            }
+        }
+
+        let query: Vec<(String, String)> = request
+            .url()
+            .query_pairs()
+            .map(|(key, value)| (key.into_owned(), value.into_owned()))
+            .collect();
+        if !self.query.iter().all(|pair| query.contains(pair)) {
+            return false;
+        }
+
+        if !self
+            .headers
+            .iter()
+            .all(|(name, value)| request.headers().get_all(name).iter().any(|v| v.eq(value)))
+        {
+            return false;
+        }
+
+        if let Some(body_json) = self.body_json.as_ref() {
+            let body = request
+                .body()
+                .and_then(|body| body.as_bytes())
+                .and_then(|bytes| serde_json::from_slice::<serde_json::Value>(bytes).ok());
+            if body.as_ref().ne(&Some(body_json)) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn request(method: Method, url: &str) -> Request {
+        Request::new(method, url.parse().unwrap())
+    }
+
+    #[test]
+    fn match_method_path_and_query() {
+        let matcher = RequestMatcher::new()
+            .method(Method::GET)
+            .path("/v1.0/users")
+            .query_param("$top", "5");
+
+        assert!(matcher.matches(&request(
+            Method::GET,
+            "https://graph.microsoft.com/v1.0/users?$top=5&$select=id"
+        )));
+        assert!(!matcher.matches(&request(
+            Method::POST,
+            "https://graph.microsoft.com/v1.0/users?$top=5"
+        )));
+        assert!(!matcher.matches(&request(
+            Method::GET,
+            "https://graph.microsoft.com/beta/users?$top=5"
+        )));
+        assert!(!matcher.matches(&request(
+            Method::GET,
+            "https://graph.microsoft.com/v1.0/users"
+        )));
+    }
+
+    #[test]
+    fn match_body_json() {
+        let matcher = RequestMatcher::new().body_json(serde_json::json!({ "displayName": "name" }));
+
+        let mut req = request(Method::POST, "https://graph.microsoft.com/v1.0/groups");
+        *req.body_mut() = Some(r#"{"displayName":"name"}"#.into());
+        assert!(matcher.matches(&req));
+
+        *req.body_mut() = Some(r#"{"displayName":"other"}"#.into());
+        assert!(!matcher.matches(&req));
+    }
+}
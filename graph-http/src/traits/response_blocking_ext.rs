@@ -1,10 +1,11 @@
 use crate::blocking::UploadSessionBlocking;
 use crate::internal::{
-    copy, create_dir, parse_content_disposition, FileConfig, HttpResponseBuilderExt, RangeIter,
-    UploadSessionLink, MAX_FILE_NAME_LEN,
+    copy, create_dir, parse_content_disposition, preference_applied, FileConfig, GraphResponse,
+    HttpResponseBuilderExt, RangeIter, UploadSessionLink, MAX_FILE_NAME_LEN,
 };
 use graph_error::download::BlockingDownloadError;
 use graph_error::{ErrorMessage, ErrorType, GraphFailure, GraphResult};
+use serde::de::DeserializeOwned;
 use std::io::Read;
 use std::path::PathBuf;
 
@@ -225,6 +226,23 @@ pub trait ResponseBlockingExt {
     /// println!("{:#?}", error_type.as_str());
     /// ```
     fn graph_error_type(&self) -> Option<ErrorType>;
+
+    /// The preferences of the `Prefer` request header that were applied as returned
+    /// in the `Preference-Applied` response header.
+    /// ```rust,ignore
+    /// let preferences = response.preference_applied();
+    /// println!("{preferences:#?}");
+    /// ```
+    fn preference_applied(&self) -> Vec<String>;
+
+    /// Deserialize the body of the response and keep the status, headers, and url
+    /// of the response. Error responses are returned as an error. An empty body,
+    /// such as the body of a `204 No Content` response, is deserialized as `null`.
+    /// ```rust,ignore
+    /// let response: GraphResponse<serde_json::Value> = response.into_graph_response()?;
+    /// println!("{:#?}", response.request_id());
+    /// ```
+    fn into_graph_response<T: DeserializeOwned>(self) -> GraphResult<GraphResponse<T>>;
 }
 
 impl ResponseBlockingExt for reqwest::blocking::Response {
@@ -515,4 +533,31 @@ impl ResponseBlockingExt for reqwest::blocking::Response {
         let status = self.status();
         ErrorType::from_u16(status.as_u16())
     }
+
+    fn preference_applied(&self) -> Vec<String> {
+        preference_applied(self.headers())
+    }
+
+    fn into_graph_response<T: DeserializeOwned>(self) -> GraphResult<GraphResponse<T>> {
+        let status = self.status();
+        let headers = self.headers().clone();
+        let url = self.url().clone();
+
+        if !status.is_success() {
+            return Err(GraphFailure::from_error_message(
+                status,
+                self.into_graph_error_message()?,
+            ));
+        }
+
+        // Responses such as 204 No Content have an empty body which is deserialized
+        // as null so that T can be (), Option or serde_json::Value.
+        let bytes = self.bytes()?;
+        let body: T = if bytes.iter().all(u8::is_ascii_whitespace) {
+            serde_json::from_value(serde_json::Value::Null)?
+        } else {
+            serde_json::from_slice(&bytes)?
+        };
+        Ok(GraphResponse::new(status, headers, url, body))
+    }
 }
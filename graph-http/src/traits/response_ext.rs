@@ -1,5 +1,6 @@
 use crate::internal::{
-    copy_async, create_dir_async, FileConfig, HttpResponseBuilderExt, RangeIter, UploadSession,
+    copy_async, copy_async_writer, create_dir_async, preference_applied, FileConfig, GraphResponse,
+    HttpResponseBuilderExt, RangeIter, UploadSession,
 };
 use crate::traits::UploadSessionLink;
 use async_trait::async_trait;
@@ -7,9 +8,10 @@ use graph_error::download::AsyncDownloadError;
 use graph_error::{ErrorMessage, ErrorType, GraphFailure, GraphResult};
 use reqwest::header::HeaderMap;
 use reqwest::Response;
+use serde::de::DeserializeOwned;
 use std::ffi::OsString;
 use std::path::PathBuf;
-use tokio::io::AsyncReadExt;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite};
 
 pub(crate) const MAX_FILE_NAME_LEN: usize = 255;
 
@@ -187,6 +189,44 @@ pub trait ResponseExt {
         reader: impl AsyncReadExt + Send + Unpin,
     ) -> GraphResult<UploadSession>;
 
+    /// # Begin an upload session that reads the content from any [tokio::io::AsyncRead] while uploading.<br>
+    ///
+    /// Unlike [ResponseExt::into_upload_session_async_read], which reads the whole content
+    /// into memory before the upload starts, the content is read one chunk at a time as
+    /// each chunk is uploaded. Use this to upload generated content, in-memory buffers, or
+    /// downloads from another service without writing a temporary file.<br>
+    ///
+    /// The `size` of the content must be known because it is sent with every chunk.
+    /// See [UploadSession::from_async_read].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let response = client
+    ///     .me()
+    ///     .drive()
+    ///     .item_by_path(":/export.csv:")
+    ///     .create_upload_session(&upload)
+    ///     .send()
+    ///     .await?;
+    ///
+    /// let object = s3_client.get_object().bucket("exports").key("export.csv").send().await?;
+    /// let size = object.content_length().unwrap_or_default() as u64;
+    ///
+    /// let mut upload_session = response
+    ///     .into_upload_session_streamed(object.body.into_async_read(), size)
+    ///     .await?;
+    ///
+    /// while let Some(result) = upload_session.next().await {
+    ///     let response = result?;
+    ///     println!("{response:#?}");
+    /// }
+    /// ```
+    async fn into_upload_session_streamed(
+        self,
+        reader: impl AsyncRead + Send + Unpin + 'static,
+        size: u64,
+    ) -> GraphResult<UploadSession>;
+
     /// # Downloads the content of the HTTP response and saves it to a file.<br>
     ///
     /// This method takes a `file_config` object containing various parameters that control how the
@@ -305,6 +345,37 @@ pub trait ResponseExt {
         file_config: &FileConfig,
     ) -> Result<http::Response<PathBuf>, AsyncDownloadError>;
 
+    /// # Downloads the content of the HTTP response into any [tokio::io::AsyncWrite].<br>
+    ///
+    /// The body is written as it is received so the content is never held in memory, which
+    /// allows streaming a download into a socket, an encryption wrapper, or a hash calculator
+    /// instead of a file. The writer is flushed but not shut down so it can still be used
+    /// after the download, such as to get the digest of a hasher.<br><br>
+    ///
+    /// Returns the number of bytes written with the status, url, and headers of the
+    /// response. The body is written regardless of the status of the response so check
+    /// the status before downloading.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let response = client
+    ///     .me()
+    ///     .drive()
+    ///     .item(ITEM_ID)
+    ///     .get_items_content()
+    ///     .send()
+    ///     .await?;
+    ///
+    /// let mut stream = tokio::net::TcpStream::connect("127.0.0.1:8080").await?;
+    /// let response = response.download_to_writer(&mut stream).await?;
+    ///
+    /// println!("{} bytes {:#?}", response.body(), response.headers().get("content-type"));
+    /// ```
+    async fn download_to_writer<W: AsyncWrite + Send + Unpin + ?Sized>(
+        self,
+        writer: &mut W,
+    ) -> Result<http::Response<u64>, AsyncDownloadError>;
+
     /// If the response is a server error then Microsoft Graph will return
     /// an error in the response body. The [`ErrorMessage`] type maps to these
     /// errors and this method deserializes to this type.
@@ -324,6 +395,15 @@ pub trait ResponseExt {
     /// ```
     async fn into_graph_error_message(self) -> Result<ErrorMessage, reqwest::Error>;
 
+    /// Returns the response if the status is successful, otherwise the error in the
+    /// body of the response. A 412 Precondition Failed response is returned as
+    /// [GraphFailure::PreconditionFailed].
+    /// ```rust,ignore
+    /// let response = client.me().get_user().send().await?.error_for_graph_status().await?;
+    /// let user: serde_json::Value = response.json().await?;
+    /// ```
+    async fn error_for_graph_status(self) -> GraphResult<reqwest::Response>;
+
     /// Microsoft Graph specific status code errors mapped from the response [StatusCode].
     /// Not all status codes map to a Microsoft Graph error.
     ///
@@ -333,6 +413,23 @@ pub trait ResponseExt {
     /// println!("{:#?}", error_type.as_str());
     /// ```
     fn graph_error_type(&self) -> Option<ErrorType>;
+
+    /// The preferences of the `Prefer` request header that were applied as returned
+    /// in the `Preference-Applied` response header.
+    /// ```rust,ignore
+    /// let preferences = response.preference_applied();
+    /// println!("{preferences:#?}");
+    /// ```
+    fn preference_applied(&self) -> Vec<String>;
+
+    /// Deserialize the body of the response and keep the status, headers, and url
+    /// of the response. Error responses are returned as an error. An empty body,
+    /// such as the body of a `204 No Content` response, is deserialized as `null`.
+    /// ```rust,ignore
+    /// let response: GraphResponse<serde_json::Value> = response.into_graph_response().await?;
+    /// println!("{:#?}", response.request_id());
+    /// ```
+    async fn into_graph_response<T: DeserializeOwned>(self) -> GraphResult<GraphResponse<T>>;
 }
 
 #[async_trait]
@@ -513,6 +610,19 @@ impl ResponseExt for reqwest::Response {
         ))
     }
 
+    async fn into_upload_session_streamed(
+        self,
+        reader: impl AsyncRead + Send + Unpin + 'static,
+        size: u64,
+    ) -> GraphResult<UploadSession> {
+        let body: serde_json::Value = self.json().await?;
+        let url = body
+            .upload_session_link()
+            .ok_or_else(|| GraphFailure::not_found("No uploadUrl found in response body"))?;
+
+        UploadSession::from_async_read(url, reader, size)
+    }
+
     /// # Downloads the content of the HTTP response and saves it to a file.<br>
     ///
     /// This method takes a `file_config` object containing various parameters that control how the
@@ -677,6 +787,26 @@ impl ResponseExt for reqwest::Response {
             .body(copy_async(path, self).await?)?)
     }
 
+    async fn download_to_writer<W: AsyncWrite + Send + Unpin + ?Sized>(
+        self,
+        writer: &mut W,
+    ) -> Result<http::Response<u64>, AsyncDownloadError> {
+        let status = self.status();
+        let url = self.url().clone();
+        let headers = self.headers().clone();
+        let version = self.version();
+
+        let mut builder = http::Response::builder()
+            .url(url)
+            .status(http::StatusCode::from(&status))
+            .version(version);
+        if let Some(builder_headers) = builder.headers_mut() {
+            builder_headers.extend(headers);
+        }
+
+        Ok(builder.body(copy_async_writer(writer, self).await?)?)
+    }
+
     /// If the response is a server error then Microsoft Graph will return
     /// an error in the response body. The [`ErrorMessage`] type maps to these
     /// errors and this method deserializes to this type.
@@ -698,6 +828,17 @@ impl ResponseExt for reqwest::Response {
         self.json().await
     }
 
+    async fn error_for_graph_status(self) -> GraphResult<reqwest::Response> {
+        let status = self.status();
+        if !status.is_success() {
+            return Err(GraphFailure::from_error_message(
+                status,
+                self.into_graph_error_message().await?,
+            ));
+        }
+        Ok(self)
+    }
+
     /// Microsoft Graph specific status code errors mapped from the response [StatusCode].
     /// Not all status codes map to a Microsoft Graph error.
     ///
@@ -710,4 +851,26 @@ impl ResponseExt for reqwest::Response {
         let status = self.status();
         ErrorType::from_u16(status.as_u16())
     }
+
+    fn preference_applied(&self) -> Vec<String> {
+        preference_applied(self.headers())
+    }
+
+    async fn into_graph_response<T: DeserializeOwned>(self) -> GraphResult<GraphResponse<T>> {
+        let status = self.status();
+        let headers = self.headers().clone();
+        let url = self.url().clone();
+
+        let response = self.error_for_graph_status().await?;
+
+        // Responses such as 204 No Content have an empty body which is deserialized
+        // as null so that T can be (), Option or serde_json::Value.
+        let bytes = response.bytes().await?;
+        let body: T = if bytes.iter().all(u8::is_ascii_whitespace) {
+            serde_json::from_value(serde_json::Value::Null)?
+        } else {
+            serde_json::from_slice(&bytes)?
+        };
+        Ok(GraphResponse::new(status, headers, url, body))
+    }
 }
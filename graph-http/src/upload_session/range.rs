@@ -4,7 +4,16 @@ use graph_error::{GraphFailure, GraphResult};
 use reqwest::header::{HeaderMap, HeaderValue, CONTENT_LENGTH, CONTENT_RANGE, CONTENT_TYPE};
 use std::collections::VecDeque;
 use std::io::{BufReader, Read};
-use tokio::io::AsyncReadExt;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// Chunks of an upload session must be a multiple of 320 KiB.
+const UPLOAD_CHUNK_MULTIPLE: u64 = 320 * 1024;
+
+/// The default chunk size of 10 MiB is within the recommended 5-10 MiB.
+const DEFAULT_UPLOAD_CHUNK_SIZE: u64 = 32 * UPLOAD_CHUNK_MULTIPLE;
+
+/// The maximum size of a chunk is 60 MiB.
+const MAX_UPLOAD_CHUNK_SIZE: u64 = 192 * UPLOAD_CHUNK_MULTIPLE;
 
 #[derive(Clone, Debug, Default)]
 pub(crate) struct Range {
@@ -122,6 +131,60 @@ impl RangeIter {
     }
 }
 
+/// Reads the chunks of an upload session from a reader one at a time so that only
+/// one chunk is in memory.
+pub(crate) struct ReaderRanges {
+    reader: Box<dyn AsyncRead + Send + Unpin>,
+    size: u64,
+    position: u64,
+    chunk_size: u64,
+}
+
+impl ReaderRanges {
+    pub(crate) fn new<R: AsyncRead + Send + Unpin + 'static>(reader: R, size: u64) -> ReaderRanges {
+        ReaderRanges {
+            reader: Box::new(reader),
+            size,
+            position: 0,
+            chunk_size: DEFAULT_UPLOAD_CHUNK_SIZE,
+        }
+    }
+
+    /// Round the chunk size down to a multiple of 320 KiB between 320 KiB and 60 MiB.
+    pub(crate) fn set_chunk_size(&mut self, chunk_size: u64) {
+        self.chunk_size = (chunk_size - chunk_size % UPLOAD_CHUNK_MULTIPLE)
+            .clamp(UPLOAD_CHUNK_MULTIPLE, MAX_UPLOAD_CHUNK_SIZE);
+    }
+
+    /// Read the next chunk. Returns an error if the reader ends before `size` bytes
+    /// have been read.
+    pub(crate) async fn next_range(&mut self) -> GraphResult<Option<(HeaderMap, reqwest::Body)>> {
+        if self.position >= self.size {
+            return Ok(None);
+        }
+
+        let length = self.chunk_size.min(self.size - self.position);
+        let mut buf = vec![0u8; length as usize];
+        self.reader.read_exact(&mut buf).await?;
+
+        let range = Range {
+            start_pos: self.position,
+            end_pos: self.position + length - 1,
+            bytes: buf,
+        };
+        self.position += length;
+
+        let mut header_map = HeaderMap::new();
+        header_map.insert(CONTENT_LENGTH, HeaderValue::from(range.content_length()));
+        header_map.insert(
+            CONTENT_RANGE,
+            HeaderValue::from_str(range.content_range(self.size).as_str())
+                .map_err(|_| GraphFailure::invalid("content range of the upload chunk"))?,
+        );
+        Ok(Some((header_map, reqwest::Body::from(range.body()))))
+    }
+}
+
 impl Iterator for RangeIter {
     type Item = Range;
 
@@ -178,3 +241,37 @@ impl TryFrom<BytesMut> for RangeIter {
         Ok(range_iter)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[tokio::test]
+    async fn reader_ranges_are_read_one_chunk_at_a_time() {
+        let size = 2 * UPLOAD_CHUNK_MULTIPLE + 100;
+        let reader = std::io::Cursor::new(vec![7u8; size as usize]);
+        let mut reader_ranges = ReaderRanges::new(reader, size);
+        reader_ranges.set_chunk_size(UPLOAD_CHUNK_MULTIPLE + 1);
+
+        let mut content_ranges = Vec::new();
+        while let Some((header_map, _body)) = reader_ranges.next_range().await.unwrap() {
+            content_ranges.push(header_map[CONTENT_RANGE].to_str().unwrap().to_owned());
+        }
+
+        assert_eq!(
+            vec![
+                format!("bytes 0-327679/{size}"),
+                format!("bytes 327680-655359/{size}"),
+                format!("bytes 655360-655459/{size}"),
+            ],
+            content_ranges
+        );
+    }
+
+    #[tokio::test]
+    async fn reader_shorter_than_size_is_an_error() {
+        let reader = std::io::Cursor::new(vec![0u8; 10]);
+        let mut reader_ranges = ReaderRanges::new(reader, 20);
+        assert!(reader_ranges.next_range().await.is_err());
+    }
+}
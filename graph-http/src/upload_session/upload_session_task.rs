@@ -1,5 +1,5 @@
 use crate::traits::AsyncIterator;
-use crate::upload_session::RangeIter;
+use crate::upload_session::{RangeIter, ReaderRanges};
 use async_stream::try_stream;
 use async_trait::async_trait;
 use futures::Stream;
@@ -8,10 +8,12 @@ use reqwest::header::HeaderMap;
 use reqwest::RequestBuilder;
 use std::io::Read;
 use std::time::Duration;
+use tokio::io::AsyncRead;
 
 pub struct UploadSession {
     url: reqwest::Url,
     range_iter: RangeIter,
+    reader_ranges: Option<ReaderRanges>,
     client: reqwest::Client,
 }
 
@@ -20,6 +22,7 @@ impl UploadSession {
         UploadSession {
             url,
             range_iter: Default::default(),
+            reader_ranges: None,
             client: Default::default(),
         }
     }
@@ -28,6 +31,7 @@ impl UploadSession {
         UploadSession {
             url,
             range_iter,
+            reader_ranges: None,
             client: Default::default(),
         }
     }
@@ -80,12 +84,65 @@ impl UploadSession {
         Ok(UploadSession {
             url: reqwest::Url::parse(upload_url.as_ref())?,
             range_iter: RangeIter::from_reader(reader)?,
+            reader_ranges: None,
             client: Default::default(),
         })
     }
 
+    /// Create an upload session that reads `size` bytes from the reader one chunk at a
+    /// time while uploading, instead of reading the whole file into memory first. Use
+    /// this to upload generated content or a download from another service without
+    /// writing a temporary file.
+    ///
+    /// The size must be known because it is sent with every chunk. A stream of bytes can
+    /// be read using an adapter such as `tokio_util::io::StreamReader`.
+    ///
+    /// Upload sessions created from a reader can be sent using [UploadSession::stream]
+    /// or [AsyncIterator::next] but not [UploadSession::channel].
+    pub fn from_async_read<U: AsRef<str>, R: AsyncRead + Send + Unpin + 'static>(
+        upload_url: U,
+        reader: R,
+        size: u64,
+    ) -> GraphResult<UploadSession> {
+        Ok(UploadSession {
+            url: reqwest::Url::parse(upload_url.as_ref())?,
+            range_iter: Default::default(),
+            reader_ranges: Some(ReaderRanges::new(reader, size)),
+            client: Default::default(),
+        })
+    }
+
+    /// Set the size of the chunks read from the reader of an upload session created
+    /// using [UploadSession::from_async_read]. The size is rounded down to a multiple of
+    /// 320 KiB, as required by Microsoft Graph, between 320 KiB and 60 MiB.
+    ///
+    /// Default is 10 MiB.
+    pub fn chunk_size(mut self, chunk_size: u64) -> UploadSession {
+        if let Some(reader_ranges) = self.reader_ranges.as_mut() {
+            reader_ranges.set_chunk_size(chunk_size);
+        }
+        self
+    }
+
+    async fn next_reader_range(&mut self) -> GraphResult<Option<(HeaderMap, reqwest::Body)>> {
+        let Some(reader_ranges) = self.reader_ranges.as_mut() else {
+            return Ok(None);
+        };
+
+        let result = reader_ranges.next_range().await;
+        if result.is_err() {
+            // The position of the reader is unknown after a failed read.
+            self.reader_ranges = None;
+        }
+        result
+    }
+
     fn try_stream(&mut self) -> impl Stream<Item = GraphResult<reqwest::Response>> + '_ {
         try_stream! {
+            while let Some((header_map, body)) = self.next_reader_range().await? {
+                yield self.send(header_map, body).await?;
+            }
+
             let components = self.range_iter.map_all().ok_or(GraphFailure::invalid(
                 "Invalid Headers (internal error, please report)",
             ))?;
@@ -174,6 +231,12 @@ impl UploadSession {
         buffer: usize,
         timeout: Duration,
     ) -> GraphResult<tokio::sync::mpsc::Receiver<reqwest::Result<reqwest::Response>>> {
+        if self.reader_ranges.is_some() {
+            return Err(GraphFailure::invalid(
+                "upload session that is not read from an AsyncRead to use a channel",
+            ));
+        }
+
         let (sender, receiver) = tokio::sync::mpsc::channel(buffer);
 
         let components = self.range_iter.map_all().ok_or(GraphFailure::invalid(
@@ -197,6 +260,12 @@ impl AsyncIterator for UploadSession {
     type Item = GraphResult<reqwest::Response>;
 
     async fn next(&mut self) -> Option<Self::Item> {
+        match self.next_reader_range().await {
+            Ok(Some((header_map, body))) => return Some(self.send(header_map, body).await),
+            Ok(None) => {}
+            Err(err) => return Some(Err(err)),
+        }
+
         let (header_map, body) = self.range_iter.pop_front()?;
         Some(self.send(header_map, body).await)
     }
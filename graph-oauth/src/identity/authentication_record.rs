@@ -0,0 +1,210 @@
+use base64::Engine;
+use serde_json::Value;
+use url::Url;
+
+use graph_error::{IdentityResult, AF};
+
+use crate::identity::{AzureCloudInstance, Token};
+
+const AUTHENTICATION_RECORD_VERSION: &str = "1.0";
+
+fn default_version() -> String {
+    AUTHENTICATION_RECORD_VERSION.to_owned()
+}
+
+/// Decode a base64url encoded json value such as the client_info of a token response
+/// or the payload of a jwt. Padding is optional.
+pub(crate) fn decode_base64_json(value: &str) -> Option<Value> {
+    let decoded = base64::engine::general_purpose::URL_SAFE_NO_PAD
+        .decode(value.trim_end_matches('='))
+        .ok()?;
+    serde_json::from_slice(&decoded).ok()
+}
+
+/// The account information of a signed in user that can be shared with other processes
+/// such as CLI tools so that the user does not have to sign in again.
+///
+/// The record does not contain any tokens. Tokens can be shared using the
+/// [MsalTokenCache] export format.
+///
+/// The serialized record uses the same format as the `AuthenticationRecord` of the
+/// Azure Identity libraries.
+///
+/// # Example
+/// ```rust
+/// use graph_oauth::AuthenticationRecord;
+///
+/// let record = AuthenticationRecord::new(
+///     "uid.utid",
+///     "utid",
+///     "00000000-0000-0000-0000-000000000000",
+///     "login.microsoftonline.com",
+///     Some("user@contoso.com"),
+/// );
+///
+/// let json = record.serialize().unwrap();
+/// assert_eq!(record, AuthenticationRecord::deserialize(&json).unwrap());
+/// ```
+///
+/// [MsalTokenCache]: crate::identity::MsalTokenCache
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthenticationRecord {
+    /// The unique identifier of the account in the form `{uid}.{utid}` where uid is the
+    /// object id of the user and utid is the id of the home tenant of the user.
+    pub home_account_id: String,
+    /// The tenant the account signed in to.
+    pub tenant_id: String,
+    pub client_id: String,
+    /// The host of the authority such as `login.microsoftonline.com`.
+    pub authority: String,
+    /// The user principal name or email of the account.
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default = "default_version")]
+    pub version: String,
+}
+
+impl AuthenticationRecord {
+    pub fn new(
+        home_account_id: impl AsRef<str>,
+        tenant_id: impl AsRef<str>,
+        client_id: impl AsRef<str>,
+        authority: impl AsRef<str>,
+        username: Option<&str>,
+    ) -> AuthenticationRecord {
+        AuthenticationRecord {
+            home_account_id: home_account_id.as_ref().to_owned(),
+            tenant_id: tenant_id.as_ref().to_owned(),
+            client_id: client_id.as_ref().to_owned(),
+            authority: authority.as_ref().to_owned(),
+            username: username.map(|username| username.to_owned()),
+            version: default_version(),
+        }
+    }
+
+    /// Create the record of the account that a token was issued to.
+    ///
+    /// The home account id is taken from the client_info of the token response, which is
+    /// returned when the request includes `client_info=1`, or the oid and tid claims of the
+    /// id token otherwise. The id token is not validated.
+    pub fn try_from_token(
+        token: &Token,
+        client_id: impl AsRef<str>,
+        azure_cloud_instance: &AzureCloudInstance,
+    ) -> IdentityResult<AuthenticationRecord> {
+        let claims = token
+            .id_token
+            .as_ref()
+            .and_then(|id_token| id_token.id_token.split('.').nth(1))
+            .and_then(decode_base64_json);
+        let claim = |name: &str| {
+            claims
+                .as_ref()
+                .and_then(|claims| claims.get(name))
+                .and_then(Value::as_str)
+                .map(|value| value.to_owned())
+        };
+
+        let client_info = token.client_info.as_deref().and_then(decode_base64_json);
+        let client_info_field = |name: &str| {
+            client_info
+                .as_ref()
+                .and_then(|client_info| client_info.get(name))
+                .and_then(Value::as_str)
+                .map(|value| value.to_owned())
+        };
+
+        let uid = client_info_field("uid")
+            .or_else(|| claim("oid"))
+            .ok_or_else(|| AF::msg_err("token", "token is missing client_info and id_token"))?;
+        let utid = client_info_field("utid")
+            .or_else(|| claim("tid"))
+            .ok_or_else(|| AF::msg_err("token", "token is missing client_info and id_token"))?;
+        let tenant_id = claim("tid").unwrap_or_else(|| utid.clone());
+        let username = claim("preferred_username").or_else(|| claim("upn"));
+
+        let authority = Url::from(azure_cloud_instance)
+            .host_str()
+            .unwrap_or_default()
+            .to_owned();
+
+        Ok(AuthenticationRecord {
+            home_account_id: format!("{uid}.{utid}"),
+            tenant_id,
+            client_id: client_id.as_ref().to_owned(),
+            authority,
+            username,
+            version: default_version(),
+        })
+    }
+
+    /// Serialize the record to json.
+    pub fn serialize(&self) -> IdentityResult<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Deserialize a record from json.
+    pub fn deserialize(json: &str) -> IdentityResult<AuthenticationRecord> {
+        Ok(serde_json::from_str(json)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::identity::IdToken;
+
+    fn encode(value: Value) -> String {
+        base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(value.to_string())
+    }
+
+    #[test]
+    fn deserialize_azure_identity_record() {
+        let record = AuthenticationRecord::deserialize(
+            r#"{
+                "username": "user@contoso.com",
+                "authority": "login.microsoftonline.com",
+                "homeAccountId": "uid.utid",
+                "tenantId": "utid",
+                "clientId": "04b07795-8ddb-461a-bbee-02f9e1bf7b46",
+                "version": "1.0"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!("uid.utid", record.home_account_id);
+        assert_eq!(Some("user@contoso.com".to_string()), record.username);
+    }
+
+    #[test]
+    fn record_from_token() {
+        let mut token = Token::default();
+        token.client_info = Some(encode(serde_json::json!({ "uid": "uid", "utid": "utid" })));
+        token.with_id_token(IdToken::new(
+            &format!(
+                "{}.{}.signature",
+                encode(serde_json::json!({ "alg": "RS256" })),
+                encode(serde_json::json!({
+                    "tid": "tid",
+                    "oid": "oid",
+                    "preferred_username": "user@contoso.com"
+                }))
+            ),
+            None,
+            None,
+            None,
+        ));
+
+        let record = AuthenticationRecord::try_from_token(
+            &token,
+            "client",
+            &AzureCloudInstance::AzurePublic,
+        )
+        .unwrap();
+        assert_eq!("uid.utid", record.home_account_id);
+        assert_eq!("tid", record.tenant_id);
+        assert_eq!("login.microsoftonline.com", record.authority);
+        assert_eq!(Some("user@contoso.com".to_string()), record.username);
+    }
+}
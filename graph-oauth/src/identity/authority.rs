@@ -1,6 +1,8 @@
 use std::fmt::Display;
 use url::{ParseError, Url};
 
+use graph_error::{IdentityResult, AF};
+
 lazy_static! {
     pub static ref AZURE_PUBLIC_CLOUD_INSTANCE: Url = {
         Url::parse(AzureCloudInstance::AzurePublic.as_ref())
@@ -29,18 +31,67 @@ pub enum AzureCloudInstance {
     /// Microsoft Azure public cloud. Maps to https://login.microsoftonline.com
     #[default]
     AzurePublic,
-    /// Microsoft Chinese national cloud. Maps to https://login.chinacloudapi.cn
+    /// Microsoft Azure operated by 21Vianet in China. Maps to https://login.chinacloudapi.cn
+    /// and Microsoft Graph at https://microsoftgraph.chinacloudapi.cn
+    ///
+    /// https://login.partner.microsoftonline.cn is a login host of the same cloud and
+    /// can be set as the instance of the credential.
     AzureChina,
     /// Microsoft German national cloud ("Black Forest"). Maps to https://login.microsoftonline.de
+    /// and Microsoft Graph at https://graph.microsoft.de
     AzureGermany,
     /// US Government cloud. Maps to https://login.microsoftonline.us
+    ///
+    /// Tokens can be requested for the Microsoft Graph resources of both GCC High
+    /// and DoD. Use [AzureCloudInstance::AzureUsGovernmentGccHigh] or
+    /// [AzureCloudInstance::AzureUsGovernmentDod] to use the Microsoft Graph
+    /// endpoint of the environment.
     AzureUsGovernment,
+    /// US Government GCC High cloud. Maps to https://login.microsoftonline.us
+    /// and Microsoft Graph at https://graph.microsoft.us
+    AzureUsGovernmentGccHigh,
+    /// US Government Department of Defense (DoD) cloud. Maps to
+    /// https://login.microsoftonline.us and Microsoft Graph at https://dod-graph.microsoft.us
+    AzureUsGovernmentDod,
 }
 
 impl AzureCloudInstance {
     pub fn get_open_id_configuration_url(&self, authority: Authority) -> String {
         format!("{}/v2.0/{}", self.as_ref(), authority.as_ref())
     }
+
+    /// The cloud of a login host such as `login.partner.microsoftonline.cn`. Returns
+    /// None for hosts that are not a login host of a cloud such as ADFS servers.
+    ///
+    /// The login host of the US Government cloud is shared by GCC High and DoD and
+    /// maps to [AzureCloudInstance::AzureUsGovernment].
+    ///
+    /// # Example
+    /// ```rust
+    /// use graph_oauth::AzureCloudInstance;
+    ///
+    /// assert_eq!(
+    ///     Some(AzureCloudInstance::AzureChina),
+    ///     AzureCloudInstance::from_host("login.partner.microsoftonline.cn")
+    /// );
+    /// assert_eq!(None, AzureCloudInstance::from_host("fs.contoso.com"));
+    /// ```
+    pub fn from_host(host: &str) -> Option<AzureCloudInstance> {
+        match host.to_ascii_lowercase().as_str() {
+            "login.microsoftonline.com"
+            | "login.windows.net"
+            | "login.microsoft.com"
+            | "sts.windows.net" => Some(AzureCloudInstance::AzurePublic),
+            "login.chinacloudapi.cn" | "login.partner.microsoftonline.cn" => {
+                Some(AzureCloudInstance::AzureChina)
+            }
+            "login.microsoftonline.de" => Some(AzureCloudInstance::AzureGermany),
+            "login.microsoftonline.us" | "login.usgovcloudapi.net" => {
+                Some(AzureCloudInstance::AzureUsGovernment)
+            }
+            _ => None,
+        }
+    }
 }
 
 impl AsRef<str> for AzureCloudInstance {
@@ -49,7 +100,9 @@ impl AsRef<str> for AzureCloudInstance {
             AzureCloudInstance::AzurePublic => "https://login.microsoftonline.com",
             AzureCloudInstance::AzureChina => "https://login.chinacloudapi.cn",
             AzureCloudInstance::AzureGermany => "https://login.microsoftonline.de",
-            AzureCloudInstance::AzureUsGovernment => "https://login.microsoftonline.us",
+            AzureCloudInstance::AzureUsGovernment
+            | AzureCloudInstance::AzureUsGovernmentGccHigh
+            | AzureCloudInstance::AzureUsGovernmentDod => "https://login.microsoftonline.us",
         }
     }
 }
@@ -60,7 +113,9 @@ impl From<&AzureCloudInstance> for Url {
             AzureCloudInstance::AzurePublic => AZURE_PUBLIC_CLOUD_INSTANCE.clone(),
             AzureCloudInstance::AzureChina => AZURE_CHINA_CLOUD_INSTANCE.clone(),
             AzureCloudInstance::AzureGermany => AZURE_GERMANY_CLOUD_INSTANCE.clone(),
-            AzureCloudInstance::AzureUsGovernment => AZURE_US_GOVERNMENT.clone(),
+            AzureCloudInstance::AzureUsGovernment
+            | AzureCloudInstance::AzureUsGovernmentGccHigh
+            | AzureCloudInstance::AzureUsGovernmentDod => AZURE_US_GOVERNMENT.clone(),
         }
     }
 }
@@ -71,54 +126,143 @@ impl From<AzureCloudInstance> for Url {
             AzureCloudInstance::AzurePublic => AZURE_PUBLIC_CLOUD_INSTANCE.clone(),
             AzureCloudInstance::AzureChina => AZURE_CHINA_CLOUD_INSTANCE.clone(),
             AzureCloudInstance::AzureGermany => AZURE_GERMANY_CLOUD_INSTANCE.clone(),
-            AzureCloudInstance::AzureUsGovernment => AZURE_US_GOVERNMENT.clone(),
+            AzureCloudInstance::AzureUsGovernment
+            | AzureCloudInstance::AzureUsGovernmentGccHigh
+            | AzureCloudInstance::AzureUsGovernmentDod => AZURE_US_GOVERNMENT.clone(),
         }
     }
 }
 
 impl AzureCloudInstance {
+    /// The endpoints of the authority on this cloud instance.
+    pub fn authority_endpoints(&self, authority: &Authority) -> AuthorityEndpoints {
+        AuthorityEndpoints::new(self.as_ref(), authority.clone())
+    }
+
+    /// The uri of the authority without the endpoint path such as
+    /// `https://login.microsoftonline.com/common`.
+    pub fn authority_uri(&self, authority: &Authority) -> String {
+        self.authority_endpoints(authority).authority_uri()
+    }
+
     pub fn auth_uri(&self, authority: &Authority) -> Result<Url, ParseError> {
-        Url::parse(&format!(
-            "{}/{}/oauth2/v2.0/authorize",
-            self.as_ref(),
-            authority.as_ref()
-        ))
+        self.authority_endpoints(authority).auth_uri()
     }
 
     pub fn token_uri(&self, authority: &Authority) -> Result<Url, ParseError> {
-        Url::parse(&format!(
-            "{}/{}/oauth2/v2.0/token",
-            self.as_ref(),
-            authority.as_ref()
-        ))
+        self.authority_endpoints(authority).token_uri()
     }
 
     pub fn admin_consent_uri(&self, authority: &Authority) -> Result<Url, ParseError> {
-        Url::parse(&format!(
-            "{}/{}/adminconsent",
-            self.as_ref(),
-            authority.as_ref()
-        ))
+        self.authority_endpoints(authority).admin_consent_uri()
     }
 
     pub fn device_code_uri(&self, authority: &Authority) -> Result<Url, ParseError> {
-        Url::parse(&format!(
-            "{}/{}/oauth2/v2.0/devicecode",
-            self.as_ref(),
-            authority.as_ref()
-        ))
+        self.authority_endpoints(authority).device_code_uri()
     }
 
     pub fn openid_configuration_uri(&self, authority: &Authority) -> Result<Url, ParseError> {
-        Url::parse(&format!(
-            "{}/{}/v2.0/.well-known/openid-configuration",
-            self.as_ref(),
-            authority.as_ref()
-        ))
+        self.authority_endpoints(authority)
+            .openid_configuration_uri()
     }
 
     pub fn issuer(&self, authority: &Authority) -> Result<Url, ParseError> {
-        Url::parse(&format!("{}/{}/v2.0", self.as_ref(), authority.as_ref()))
+        self.authority_endpoints(authority).issuer()
+    }
+
+    /// The Microsoft Graph resource of the cloud such as `https://graph.microsoft.us`.
+    /// This is also the base url of the Microsoft Graph endpoint of the cloud.
+    pub fn graph_resource(&self) -> &'static str {
+        match self {
+            AzureCloudInstance::AzurePublic => "https://graph.microsoft.com",
+            AzureCloudInstance::AzureChina => "https://microsoftgraph.chinacloudapi.cn",
+            AzureCloudInstance::AzureGermany => "https://graph.microsoft.de",
+            AzureCloudInstance::AzureUsGovernment
+            | AzureCloudInstance::AzureUsGovernmentGccHigh => "https://graph.microsoft.us",
+            AzureCloudInstance::AzureUsGovernmentDod => "https://dod-graph.microsoft.us",
+        }
+    }
+
+    /// The Microsoft Graph resources that tokens of the cloud can be requested for.
+    pub(crate) fn graph_resources(&self) -> &'static [&'static str] {
+        match self {
+            AzureCloudInstance::AzurePublic => &["https://graph.microsoft.com"],
+            AzureCloudInstance::AzureChina => &["https://microsoftgraph.chinacloudapi.cn"],
+            AzureCloudInstance::AzureGermany => &["https://graph.microsoft.de"],
+            AzureCloudInstance::AzureUsGovernment => &[
+                "https://graph.microsoft.us",
+                "https://dod-graph.microsoft.us",
+            ],
+            AzureCloudInstance::AzureUsGovernmentGccHigh => &["https://graph.microsoft.us"],
+            AzureCloudInstance::AzureUsGovernmentDod => &["https://dod-graph.microsoft.us"],
+        }
+    }
+
+    /// Rewrites a Microsoft Graph scope of the Azure public cloud such as
+    /// `https://graph.microsoft.com/.default` to the Microsoft Graph resource of this
+    /// cloud such as `https://graph.microsoft.us/.default`.
+    ///
+    /// Returns an error if the scope is a Microsoft Graph scope of a different national
+    /// cloud because the token request would fail with AADSTS500011.
+    /// Scopes of other resources are returned unchanged.
+    ///
+    /// # Example
+    /// ```rust
+    /// use graph_oauth::AzureCloudInstance;
+    ///
+    /// let scope = AzureCloudInstance::AzureUsGovernment
+    ///     .graph_scope("https://graph.microsoft.com/.default")
+    ///     .unwrap();
+    /// assert_eq!("https://graph.microsoft.us/.default", scope);
+    ///
+    /// let scope = AzureCloudInstance::AzureUsGovernmentDod
+    ///     .graph_scope("https://graph.microsoft.com/User.Read")
+    ///     .unwrap();
+    /// assert_eq!("https://dod-graph.microsoft.us/User.Read", scope);
+    ///
+    /// assert!(AzureCloudInstance::AzurePublic
+    ///     .graph_scope("https://graph.microsoft.us/.default")
+    ///     .is_err());
+    /// ```
+    pub fn graph_scope(&self, scope: &str) -> IdentityResult<String> {
+        let resource_of = |resources: &[&'static str]| {
+            resources.iter().copied().find(|resource| {
+                scope
+                    .get(..resource.len())
+                    .is_some_and(|prefix| prefix.eq_ignore_ascii_case(resource))
+                    && scope[resource.len()..].starts_with('/')
+            })
+        };
+
+        if resource_of(self.graph_resources()).is_some() {
+            return Ok(scope.to_owned());
+        }
+
+        if let Some(resource) = resource_of(AzureCloudInstance::AzurePublic.graph_resources()) {
+            return Ok(format!(
+                "{}{}",
+                self.graph_resource(),
+                &scope[resource.len()..]
+            ));
+        }
+
+        for cloud in [
+            AzureCloudInstance::AzureChina,
+            AzureCloudInstance::AzureGermany,
+            AzureCloudInstance::AzureUsGovernmentGccHigh,
+            AzureCloudInstance::AzureUsGovernmentDod,
+        ] {
+            if resource_of(cloud.graph_resources()).is_some() {
+                return AF::msg_result(
+                    "scope",
+                    format!(
+                        "{scope} is a Microsoft Graph scope of {cloud:?} but the cloud instance is {self:?}"
+                    ),
+                );
+            }
+        }
+
+        Ok(scope.to_owned())
     }
 
     /*
@@ -139,6 +283,127 @@ impl AzureCloudInstance {
     */
 }
 
+/// The endpoints of an [Authority] on an authority host. The host is the instance
+/// url of an [AzureCloudInstance] or a custom instance such as an ADFS server.
+///
+/// The endpoints use the layout of the authority:
+/// - Microsoft Entra ID: `https://login.microsoftonline.com/{tenant}/oauth2/v2.0/token`
+/// - Azure AD B2C: `https://{tenant}.b2clogin.com/{tenant}.onmicrosoft.com/{policy}/oauth2/v2.0/token`
+/// - ADFS: `https://{adfs_host}/adfs/oauth2/token`
+///
+/// # Example
+/// ```rust
+/// use graph_oauth::{Authority, AuthorityEndpoints};
+///
+/// let endpoints = AuthorityEndpoints::new(
+///     "https://fs.contoso.com",
+///     Authority::AzureDirectoryFederatedServices,
+/// );
+/// assert_eq!(
+///     "https://fs.contoso.com/adfs/oauth2/authorize",
+///     endpoints.auth_uri().unwrap().as_str()
+/// );
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct AuthorityEndpoints {
+    instance: String,
+    authority: Authority,
+}
+
+impl AuthorityEndpoints {
+    pub fn new(instance: impl AsRef<str>, authority: Authority) -> AuthorityEndpoints {
+        AuthorityEndpoints {
+            instance: instance.as_ref().trim_end_matches('/').to_owned(),
+            authority,
+        }
+    }
+
+    pub fn authority(&self) -> &Authority {
+        &self.authority
+    }
+
+    /// The uri of the authority without the endpoint path such as
+    /// `https://login.microsoftonline.com/common`.
+    ///
+    /// Azure AD B2C authorities use the B2C host, tenant, and policy instead
+    /// of the instance such as
+    /// `https://contoso.b2clogin.com/contoso.onmicrosoft.com/B2C_1_signupsignin`.
+    pub fn authority_uri(&self) -> String {
+        match &self.authority {
+            Authority::AzureActiveDirectoryB2C {
+                host,
+                tenant,
+                policy,
+            } => format!("https://{host}/{tenant}/{policy}"),
+            _ => format!("{}/{}", self.instance, self.authority.as_ref()),
+        }
+    }
+
+    /// ADFS does not use the v2.0 endpoints.
+    fn oauth2_uri(&self) -> String {
+        if self.authority.is_adfs() {
+            format!("{}/oauth2", self.authority_uri())
+        } else {
+            format!("{}/oauth2/v2.0", self.authority_uri())
+        }
+    }
+
+    pub fn auth_uri(&self) -> Result<Url, ParseError> {
+        Url::parse(&format!("{}/authorize", self.oauth2_uri()))
+    }
+
+    pub fn token_uri(&self) -> Result<Url, ParseError> {
+        Url::parse(&format!("{}/token", self.oauth2_uri()))
+    }
+
+    /// The admin consent endpoint. Not supported by ADFS.
+    pub fn admin_consent_uri(&self) -> Result<Url, ParseError> {
+        Url::parse(&format!("{}/adminconsent", self.authority_uri()))
+    }
+
+    pub fn device_code_uri(&self) -> Result<Url, ParseError> {
+        Url::parse(&format!("{}/devicecode", self.oauth2_uri()))
+    }
+
+    pub fn openid_configuration_uri(&self) -> Result<Url, ParseError> {
+        if self.authority.is_adfs() {
+            Url::parse(&format!(
+                "{}/.well-known/openid-configuration",
+                self.authority_uri()
+            ))
+        } else {
+            Url::parse(&format!(
+                "{}/v2.0/.well-known/openid-configuration",
+                self.authority_uri()
+            ))
+        }
+    }
+
+    /// The uri of the keys used to sign tokens issued by the authority.
+    pub fn jwks_uri(&self) -> Result<Url, ParseError> {
+        if self.authority.is_adfs() {
+            Url::parse(&format!("{}/discovery/keys", self.authority_uri()))
+        } else {
+            Url::parse(&format!("{}/discovery/v2.0/keys", self.authority_uri()))
+        }
+    }
+
+    /// The issuer of tokens for the authority.
+    ///
+    /// The issuer of Azure AD B2C tokens uses the tenant id instead of the tenant
+    /// domain name. Create the [Authority::AzureActiveDirectoryB2C] with the tenant
+    /// id when validating the issuer of B2C tokens.
+    pub fn issuer(&self) -> Result<Url, ParseError> {
+        match &self.authority {
+            Authority::AzureActiveDirectoryB2C { host, tenant, .. } => {
+                Url::parse(&format!("https://{host}/{tenant}/v2.0/"))
+            }
+            Authority::AzureDirectoryFederatedServices => Url::parse(&self.authority_uri()),
+            _ => Url::parse(&format!("{}/v2.0", self.authority_uri())),
+        }
+    }
+}
+
 /// Specifies which Microsoft accounts can be used for sign-in with a given application.
 /// See https://aka.ms/msal-net-application-configuration
 ///
@@ -218,6 +483,12 @@ pub enum Authority {
     /// familiar with it from the Microsoft Identity Platform documentation.
     #[default]
     AzureActiveDirectory,
+    /// Active Directory Federation Services (ADFS) on-premises.
+    /// Maps to https://[instance]/adfs/ where the instance is the ADFS server such as
+    /// https://fs.contoso.com. Set the instance using `with_instance` on the credential
+    /// or application builder.
+    ///
+    /// ADFS uses the `/adfs/oauth2/` endpoints instead of `/oauth2/v2.0/`.
     AzureDirectoryFederatedServices,
     /// Users with both a personal Microsoft account and a work or school account
     /// from Azure AD can sign in to the application.
@@ -240,9 +511,81 @@ pub enum Authority {
     /// school account or directory guests with a personal Microsoft account) can sign in
     /// to the application.
     TenantId(String),
+    /// Azure AD B2C authority for a user flow or custom policy such as `B2C_1_signupsignin`.
+    /// Maps to https://[host]/[tenant]/[policy] such as
+    /// https://contoso.b2clogin.com/contoso.onmicrosoft.com/B2C_1_signupsignin
+    ///
+    /// Tokens for B2C authorities are requested from the B2C host instead of the
+    /// [AzureCloudInstance]. Use [Authority::b2c] or [Authority::b2c_with_host] to
+    /// create the authority.
+    AzureActiveDirectoryB2C {
+        /// The B2C host such as `contoso.b2clogin.com` or a custom domain.
+        host: String,
+        /// The tenant domain name such as `contoso.onmicrosoft.com` or the tenant id.
+        tenant: String,
+        /// The user flow or custom policy such as `B2C_1_signupsignin`.
+        policy: String,
+    },
 }
 
 impl Authority {
+    /// Azure AD B2C authority using the b2clogin.com host of the tenant.
+    ///
+    /// The tenant is the tenant name such as `contoso` or the tenant domain
+    /// name such as `contoso.onmicrosoft.com`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use graph_oauth::{Authority, AzureCloudInstance};
+    ///
+    /// let authority = Authority::b2c("contoso", "B2C_1_signupsignin");
+    /// let uri = AzureCloudInstance::default().token_uri(&authority).unwrap();
+    /// assert_eq!(
+    ///     "https://contoso.b2clogin.com/contoso.onmicrosoft.com/B2C_1_signupsignin/oauth2/v2.0/token",
+    ///     uri.as_str()
+    /// );
+    /// ```
+    pub fn b2c(tenant: impl AsRef<str>, policy: impl AsRef<str>) -> Authority {
+        let tenant = tenant.as_ref();
+        let tenant_name = tenant.trim_end_matches(".onmicrosoft.com");
+        Authority::AzureActiveDirectoryB2C {
+            host: format!("{tenant_name}.b2clogin.com"),
+            tenant: format!("{tenant_name}.onmicrosoft.com"),
+            policy: policy.as_ref().to_owned(),
+        }
+    }
+
+    /// Azure AD B2C authority using a custom domain such as `login.contoso.com`.
+    pub fn b2c_with_host(
+        host: impl AsRef<str>,
+        tenant: impl AsRef<str>,
+        policy: impl AsRef<str>,
+    ) -> Authority {
+        Authority::AzureActiveDirectoryB2C {
+            host: host.as_ref().to_owned(),
+            tenant: tenant.as_ref().to_owned(),
+            policy: policy.as_ref().to_owned(),
+        }
+    }
+
+    /// Returns true for Active Directory Federation Services (ADFS) authorities.
+    pub fn is_adfs(&self) -> bool {
+        matches!(self, Authority::AzureDirectoryFederatedServices)
+    }
+
+    /// Returns true for Azure AD B2C authorities.
+    pub fn is_b2c(&self) -> bool {
+        matches!(self, Authority::AzureActiveDirectoryB2C { .. })
+    }
+
+    /// The user flow or custom policy of an Azure AD B2C authority.
+    pub fn policy(&self) -> Option<&str> {
+        match self {
+            Authority::AzureActiveDirectoryB2C { policy, .. } => Some(policy.as_str()),
+            _ => None,
+        }
+    }
+
     pub fn tenant_id(&self) -> Option<&String> {
         match self {
             Authority::TenantId(tenant_id) => Some(tenant_id),
@@ -257,6 +600,7 @@ impl Authority {
             Authority::Organizations => "organizations",
             Authority::Consumers => "consumers",
             Authority::TenantId(tenant_id) => tenant_id.as_str(),
+            Authority::AzureActiveDirectoryB2C { tenant, .. } => tenant.as_str(),
         }
     }
 }
@@ -307,3 +651,83 @@ impl From<&str> for Authority {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn national_cloud_urls() {
+        let authority = Authority::TenantId("tenant".into());
+        for (cloud, token_uri, graph_scope) in [
+            (
+                AzureCloudInstance::AzurePublic,
+                "https://login.microsoftonline.com/tenant/oauth2/v2.0/token",
+                "https://graph.microsoft.com/.default",
+            ),
+            (
+                AzureCloudInstance::AzureChina,
+                "https://login.chinacloudapi.cn/tenant/oauth2/v2.0/token",
+                "https://microsoftgraph.chinacloudapi.cn/.default",
+            ),
+            (
+                AzureCloudInstance::AzureGermany,
+                "https://login.microsoftonline.de/tenant/oauth2/v2.0/token",
+                "https://graph.microsoft.de/.default",
+            ),
+            (
+                AzureCloudInstance::AzureUsGovernment,
+                "https://login.microsoftonline.us/tenant/oauth2/v2.0/token",
+                "https://graph.microsoft.us/.default",
+            ),
+            (
+                AzureCloudInstance::AzureUsGovernmentGccHigh,
+                "https://login.microsoftonline.us/tenant/oauth2/v2.0/token",
+                "https://graph.microsoft.us/.default",
+            ),
+            (
+                AzureCloudInstance::AzureUsGovernmentDod,
+                "https://login.microsoftonline.us/tenant/oauth2/v2.0/token",
+                "https://dod-graph.microsoft.us/.default",
+            ),
+        ] {
+            assert_eq!(token_uri, cloud.token_uri(&authority).unwrap().as_str());
+            assert_eq!(
+                graph_scope,
+                cloud
+                    .graph_scope("https://graph.microsoft.com/.default")
+                    .unwrap()
+            );
+            assert_eq!(
+                Url::parse(cloud.as_ref()).unwrap().host_str(),
+                Url::from(cloud).host_str()
+            );
+        }
+    }
+
+    #[test]
+    fn china_cloud_login_hosts() {
+        let authority = Authority::Organizations;
+        assert_eq!(
+            "https://login.chinacloudapi.cn/organizations/oauth2/v2.0/authorize",
+            AzureCloudInstance::AzureChina
+                .auth_uri(&authority)
+                .unwrap()
+                .as_str()
+        );
+        assert_eq!(
+            "https://login.partner.microsoftonline.cn/organizations/oauth2/v2.0/devicecode",
+            AuthorityEndpoints::new("https://login.partner.microsoftonline.cn", authority)
+                .device_code_uri()
+                .unwrap()
+                .as_str()
+        );
+        assert_eq!(
+            Some(AzureCloudInstance::AzureChina),
+            AzureCloudInstance::from_host("LOGIN.CHINACLOUDAPI.CN")
+        );
+        assert!(AzureCloudInstance::AzureChina
+            .graph_scope("https://graph.microsoft.de/.default")
+            .is_err());
+    }
+}
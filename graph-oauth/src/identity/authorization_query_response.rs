@@ -1,9 +1,13 @@
 use serde::Deserializer;
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::{Debug, Display, Formatter};
 use url::Url;
 
+use graph_core::crypto::ct_eq;
+use graph_core::identity::LogPolicy;
+use graph_error::{AuthorizationFailure, IdentityResult, AF};
+
 /// The specification defines errors here:
 /// https://datatracker.ietf.org/doc/html/draft-ietf-oauth-v2-31#section-4.2.2.1
 ///
@@ -99,6 +103,33 @@ where
     Ok(None)
 }
 
+/// Returns an error if the state received in the authorization response does not
+/// match the state sent in the authorization request.
+///
+/// The state is used to prevent cross-site request forgery attacks and should be
+/// validated before the code is redeemed for a token. The authorization url builders
+/// generate a secure random state using `with_generated_state` and return the state
+/// of the request using `state()` so that it can be stored until the response is
+/// received. The states are compared in constant time.
+///
+/// # Example
+/// ```rust
+/// use graph_oauth::validate_state;
+///
+/// assert!(validate_state("12345", "12345").is_ok());
+/// assert!(validate_state("12345", "54321").is_err());
+/// ```
+pub fn validate_state(expected: &str, received: &str) -> IdentityResult<()> {
+    if ct_eq(expected, received) {
+        Ok(())
+    } else {
+        AF::msg_result(
+            "state",
+            "state of the authorization response does not match the state of the request",
+        )
+    }
+}
+
 #[derive(Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub(crate) struct PhantomAuthorizationResponse {
     pub code: Option<String>,
@@ -116,10 +147,10 @@ pub(crate) struct PhantomAuthorizationResponse {
     #[serde(flatten)]
     pub additional_fields: HashMap<String, Value>,
     #[serde(skip)]
-    log_pii: bool,
+    log_policy: LogPolicy,
 }
 
-#[derive(Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct AuthorizationError {
     pub error: Option<AuthorizationResponseError>,
     pub error_description: Option<String>,
@@ -142,47 +173,132 @@ pub struct AuthorizationResponse {
     pub error_uri: Option<Url>,
     #[serde(flatten)]
     pub additional_fields: HashMap<String, Value>,
-    /// When true debug logging will log personally identifiable information such
-    /// as the id_token. This is disabled by default. When log_pii is enabled
-    /// passing [AuthorizationResponse] to logging or print functions will log the access token
+    /// How the access token and id token are written to debug output.
+    /// Defaults to [LogPolicy::Redacted]. With [LogPolicy::Full] passing
+    /// [AuthorizationResponse] to logging or print functions will log the access token
     /// and id token value.
     #[serde(skip)]
-    pub log_pii: bool,
+    pub log_policy: LogPolicy,
 }
 
 impl AuthorizationResponse {
+    /// Parse the response of [ResponseMode::Query](crate::identity::ResponseMode::Query)
+    /// from the query of the redirect uri.
+    ///
+    /// # Example
+    /// ```rust
+    /// use graph_oauth::AuthorizationResponse;
+    ///
+    /// let response = AuthorizationResponse::from_query("code=AwABAAAAvPM1KaPl&state=12345").unwrap();
+    /// assert_eq!(Some("AwABAAAAvPM1KaPl".to_string()), response.code);
+    /// assert!(response.validate_state("12345").is_ok());
+    /// ```
+    pub fn from_query(query: &str) -> IdentityResult<AuthorizationResponse> {
+        serde_urlencoded::from_str(query.trim_start_matches('?'))
+            .map_err(|err| AF::msg_err("authorization_response", err.to_string().as_str()))
+    }
+
+    /// Parse the response of [ResponseMode::Fragment](crate::identity::ResponseMode::Fragment)
+    /// from the fragment of the redirect uri. Hybrid flows such as `code id_token` return
+    /// both the code and id token in the fragment.
+    pub fn from_fragment(fragment: &str) -> IdentityResult<AuthorizationResponse> {
+        AuthorizationResponse::from_query(fragment.trim_start_matches('#'))
+    }
+
+    /// Parse the response of [ResponseMode::FormPost](crate::identity::ResponseMode::FormPost)
+    /// from the `application/x-www-form-urlencoded` body of the POST to the redirect uri.
+    pub fn from_form_post(body: impl AsRef<[u8]>) -> IdentityResult<AuthorizationResponse> {
+        serde_urlencoded::from_bytes(body.as_ref())
+            .map_err(|err| AF::msg_err("authorization_response", err.to_string().as_str()))
+    }
+
+    /// Parse the response from the query and fragment of the redirect uri.
+    ///
+    /// Parameters in the fragment take precedence over parameters in the query because
+    /// a redirect uri may have its own query while the response is in the fragment.
+    pub fn from_redirect_uri(uri: &Url) -> IdentityResult<AuthorizationResponse> {
+        if uri.query().is_none() && uri.fragment().is_none() {
+            return AF::msg_result(
+                "redirect_uri",
+                format!("uri missing query or fragment: {uri}"),
+            );
+        }
+
+        let mut parameters: BTreeMap<String, String> = uri.query_pairs().into_owned().collect();
+        if let Some(fragment) = uri.fragment() {
+            parameters.extend(url::form_urlencoded::parse(fragment.as_bytes()).into_owned());
+        }
+
+        let query = url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(parameters)
+            .finish();
+        AuthorizationResponse::from_query(&query)
+    }
+
     pub fn is_err(&self) -> bool {
         self.error.is_some()
     }
+
+    /// The error of the authorization response if one was returned.
+    pub fn authorization_error(&self) -> Option<AuthorizationError> {
+        if self.is_err() {
+            Some(AuthorizationError {
+                error: self.error.clone(),
+                error_description: self.error_description.clone(),
+                error_uri: self.error_uri.clone(),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns an error if the state of the response does not match the state
+    /// sent in the authorization request. See [validate_state].
+    pub fn validate_state(&self, expected_state: impl AsRef<str>) -> IdentityResult<()> {
+        match self.state.as_ref() {
+            Some(state) => validate_state(expected_state.as_ref(), state),
+            None => AF::msg_result("state", "authorization response is missing the state"),
+        }
+    }
+}
+
+/// Parse the response from the redirect request received by a redirect handler such as
+/// a tower service or axum handler. The response is read from the form urlencoded body
+/// of POST requests and from the query of other requests.
+impl<B: AsRef<[u8]>> TryFrom<&http::Request<B>> for AuthorizationResponse {
+    type Error = AuthorizationFailure;
+
+    fn try_from(value: &http::Request<B>) -> Result<Self, Self::Error> {
+        if value.method() == http::Method::POST {
+            return AuthorizationResponse::from_form_post(value.body());
+        }
+
+        match value.uri().query() {
+            Some(query) => AuthorizationResponse::from_query(query),
+            None => AF::msg_result(
+                "redirect_uri",
+                format!("uri missing query: {}", value.uri()),
+            ),
+        }
+    }
 }
 
 impl Debug for AuthorizationResponse {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        if self.log_pii {
-            f.debug_struct("AuthQueryResponse")
-                .field("code", &self.code)
-                .field("id_token", &self.id_token)
-                .field("access_token", &self.access_token)
-                .field("state", &self.state)
-                .field("nonce", &self.nonce)
-                .field("error", &self.error)
-                .field("error_description", &self.error_description)
-                .field("error_uri", &self.error_uri)
-                .field("additional_fields", &self.additional_fields)
-                .finish()
-        } else {
-            f.debug_struct("AuthQueryResponse")
-                .field("code", &self.code)
-                .field("id_token", &"[REDACTED]")
-                .field("access_token", &"[REDACTED]")
-                .field("state", &self.state)
-                .field("nonce", &self.nonce)
-                .field("error", &self.error)
-                .field("error_description", &self.error_description)
-                .field("error_uri", &self.error_uri)
-                .field("additional_fields", &self.additional_fields)
-                .finish()
-        }
+        let mut debug = f.debug_struct("AuthQueryResponse");
+        debug.field("code", &self.code);
+        self.log_policy
+            .debug_field(&mut debug, "id_token", &self.id_token);
+        self.log_policy
+            .debug_field(&mut debug, "access_token", &self.access_token);
+        debug
+            .field("state", &self.state)
+            .field("nonce", &self.nonce)
+            .field("error", &self.error)
+            .field("error_description", &self.error_description)
+            .field("error_uri", &self.error_uri)
+            .field("additional_fields", &self.additional_fields)
+            .finish()
     }
 }
 
@@ -227,4 +343,58 @@ mod test {
         let response: AuthorizationResponse = serde_urlencoded::from_str(query).unwrap();
         assert_eq!(Some(String::from("token")), response.access_token);
     }
+
+    #[test]
+    pub fn authorization_response_from_form_post() {
+        let body =
+            "code=AwABAAAAvPM1KaPl&id_token=eyJ0eXAiOiJKV1Qi&state=12345&session_state=fe1540c3";
+        let response = AuthorizationResponse::from_form_post(body).unwrap();
+        assert_eq!(Some(String::from("AwABAAAAvPM1KaPl")), response.code);
+        assert_eq!(Some(String::from("eyJ0eXAiOiJKV1Qi")), response.id_token);
+        assert_eq!(Some(String::from("fe1540c3")), response.session_state);
+        assert!(response.validate_state("12345").is_ok());
+        assert!(response.validate_state("54321").is_err());
+    }
+
+    #[test]
+    pub fn authorization_response_from_hybrid_fragment() {
+        let uri = Url::parse(
+            "https://localhost:8080/redirect?tenant=common#code=AwABAAAAvPM1KaPl&id_token=eyJ0eXAiOiJKV1Qi&state=12345",
+        )
+        .unwrap();
+        let response = AuthorizationResponse::from_redirect_uri(&uri).unwrap();
+        assert_eq!(Some(String::from("AwABAAAAvPM1KaPl")), response.code);
+        assert_eq!(Some(String::from("eyJ0eXAiOiJKV1Qi")), response.id_token);
+        assert_eq!(Some(String::from("12345")), response.state);
+    }
+
+    #[test]
+    pub fn authorization_response_error_from_query() {
+        let response = AuthorizationResponse::from_query(
+            "error=access_denied&error_description=the+user+canceled+the+authentication",
+        )
+        .unwrap();
+        let error = response.authorization_error().unwrap();
+        assert_eq!(Some(AuthorizationResponseError::AccessDenied), error.error);
+        assert_eq!(
+            Some(String::from("the user canceled the authentication")),
+            error.error_description
+        );
+        assert!(response.validate_state("12345").is_err());
+    }
+
+    #[test]
+    pub fn authorization_response_from_http_request() {
+        let request = http::Request::get("http://localhost:8000/redirect?code=code&state=12345")
+            .body(Vec::new())
+            .unwrap();
+        let response = AuthorizationResponse::try_from(&request).unwrap();
+        assert_eq!(Some(String::from("code")), response.code);
+
+        let request = http::Request::post("http://localhost:8000/redirect")
+            .body("code=code&state=12345")
+            .unwrap();
+        let response = AuthorizationResponse::try_from(&request).unwrap();
+        assert!(response.validate_state("12345").is_ok());
+    }
 }
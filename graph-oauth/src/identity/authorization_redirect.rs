@@ -0,0 +1,259 @@
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::sync::{Arc, Mutex};
+
+use graph_core::crypto::ProofKeyCodeExchange;
+use graph_error::{IdentityResult, AF};
+
+use crate::identity::{
+    AuthCodeAuthorizationUrlParameters, AuthorizationCodeCredentialBuilder, AuthorizationResponse,
+    IntoCredentialBuilder,
+};
+
+/// An authorization request that is waiting for the redirect from the authorization server.
+#[derive(Clone)]
+pub struct PendingAuthorization {
+    pub parameters: AuthCodeAuthorizationUrlParameters,
+    pub pkce: Option<ProofKeyCodeExchange>,
+}
+
+impl PendingAuthorization {
+    pub fn new(parameters: AuthCodeAuthorizationUrlParameters) -> PendingAuthorization {
+        PendingAuthorization {
+            parameters,
+            pkce: None,
+        }
+    }
+
+    pub fn with_pkce(mut self, pkce: &ProofKeyCodeExchange) -> Self {
+        self.pkce = Some(pkce.clone());
+        self
+    }
+}
+
+impl Debug for PendingAuthorization {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PendingAuthorization")
+            .field("parameters", &self.parameters)
+            .finish()
+    }
+}
+
+/// Storage for authorization requests keyed by the state of the request, such as a
+/// user session store, so that the redirect handler can validate the state of the
+/// authorization response and build the credential for the authorization code.
+pub trait AuthorizationStateStore: Send + Sync {
+    fn insert(&self, state: &str, pending: PendingAuthorization);
+
+    /// Remove and return the authorization request for the state. A state can only
+    /// be used once.
+    fn take(&self, state: &str) -> Option<PendingAuthorization>;
+
+    /// Store the authorization request using the state of the authorization url
+    /// parameters. The state is required.
+    fn insert_pending(&self, pending: PendingAuthorization) -> IdentityResult<()> {
+        let state = pending.parameters.state().cloned().ok_or_else(|| {
+            AF::msg_err(
+                "state",
+                "authorization url parameters are missing the state",
+            )
+        })?;
+        self.insert(state.as_str(), pending);
+        Ok(())
+    }
+}
+
+/// In memory [AuthorizationStateStore] for applications running a single instance.
+#[derive(Clone, Default)]
+pub struct InMemoryAuthorizationStateStore {
+    store: Arc<Mutex<HashMap<String, PendingAuthorization>>>,
+}
+
+impl InMemoryAuthorizationStateStore {
+    pub fn new() -> InMemoryAuthorizationStateStore {
+        InMemoryAuthorizationStateStore::default()
+    }
+}
+
+impl AuthorizationStateStore for InMemoryAuthorizationStateStore {
+    fn insert(&self, state: &str, pending: PendingAuthorization) {
+        let mut store = self.store.lock().unwrap_or_else(|err| err.into_inner());
+        store.insert(state.to_owned(), pending);
+    }
+
+    fn take(&self, state: &str) -> Option<PendingAuthorization> {
+        let mut store = self.store.lock().unwrap_or_else(|err| err.into_inner());
+        store.remove(state)
+    }
+}
+
+impl Debug for InMemoryAuthorizationStateStore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InMemoryAuthorizationStateStore").finish()
+    }
+}
+
+/// Validate the authorization response received by the redirect endpoint and return the
+/// credential builder for the authorization code. The client secret still needs to be set
+/// on the builder for confidential clients.
+///
+/// The state of the response must match the state of a pending authorization request in
+/// the store. The pending request is removed from the store.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::identity::{handle_authorization_redirect, AuthorizationResponse};
+///
+/// let response = AuthorizationResponse::from_query(query)?;
+/// let credential = handle_authorization_redirect(response, &state_store)?
+///     .with_client_secret("client-secret")
+///     .build();
+/// ```
+pub fn handle_authorization_redirect(
+    response: AuthorizationResponse,
+    state_store: &(impl AuthorizationStateStore + ?Sized),
+) -> IdentityResult<AuthorizationCodeCredentialBuilder> {
+    if let Some(error) = response.authorization_error() {
+        let message = error
+            .error_description
+            .or_else(|| error.error.map(|error| error.to_string()))
+            .unwrap_or_default();
+        return AF::msg_result("authorization_response", message);
+    }
+
+    let state = response
+        .state
+        .as_ref()
+        .ok_or_else(|| AF::msg_err("state", "authorization response is missing the state"))?;
+    let pending = state_store
+        .take(state.as_str())
+        .ok_or_else(|| AF::msg_err("state", "no pending authorization request for the state"))?;
+    let (_, credential_builder) = (response, pending).into_credential_builder()?;
+    Ok(credential_builder)
+}
+
+#[cfg(feature = "axum")]
+mod axum_extract {
+    use std::sync::Arc;
+
+    use axum::async_trait;
+    use axum::body::Bytes;
+    use axum::extract::{FromRef, FromRequest, Request};
+    use axum::http::StatusCode;
+
+    use crate::identity::{
+        handle_authorization_redirect, AuthorizationCodeCredentialBuilder, AuthorizationResponse,
+        AuthorizationStateStore,
+    };
+
+    /// Form post bodies of authorization responses are small. Larger bodies are rejected.
+    const MAX_BODY_SIZE: usize = 64 * 1024;
+
+    /// Axum extractor for the [AuthorizationResponse] sent to the redirect endpoint.
+    ///
+    /// The response is read from the query of GET requests and from the form
+    /// urlencoded body of POST requests when using `ResponseMode::FormPost`.
+    ///
+    /// Requires feature `axum`
+    #[derive(Debug)]
+    pub struct AuthorizationRedirect(pub AuthorizationResponse);
+
+    #[async_trait]
+    impl<S: Send + Sync> FromRequest<S> for AuthorizationRedirect {
+        type Rejection = (StatusCode, String);
+
+        async fn from_request(req: Request, _state: &S) -> Result<Self, Self::Rejection> {
+            let (parts, body) = req.into_parts();
+            let body: Bytes = axum::body::to_bytes(body, MAX_BODY_SIZE)
+                .await
+                .map_err(|err| (StatusCode::BAD_REQUEST, err.to_string()))?;
+            let request = axum::http::Request::from_parts(parts, body);
+
+            AuthorizationResponse::try_from(&request)
+                .map(AuthorizationRedirect)
+                .map_err(|err| (StatusCode::BAD_REQUEST, err.to_string()))
+        }
+    }
+
+    /// Axum extractor that validates the state of the authorization response using the
+    /// [AuthorizationStateStore] of the application state and returns the credential
+    /// builder for the authorization code.
+    ///
+    /// Requires feature `axum`
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use graph_rs_sdk::identity::{AuthorizationCodeRedirect, AuthorizationStateStore};
+    ///
+    /// #[derive(Clone, FromRef)]
+    /// struct AppState {
+    ///     state_store: Arc<dyn AuthorizationStateStore>,
+    /// }
+    ///
+    /// async fn redirect(AuthorizationCodeRedirect(mut builder): AuthorizationCodeRedirect) {
+    ///     let confidential_client = builder.with_client_secret("client-secret").build();
+    /// }
+    /// ```
+    #[derive(Debug)]
+    pub struct AuthorizationCodeRedirect(pub AuthorizationCodeCredentialBuilder);
+
+    #[async_trait]
+    impl<S> FromRequest<S> for AuthorizationCodeRedirect
+    where
+        S: Send + Sync,
+        Arc<dyn AuthorizationStateStore>: FromRef<S>,
+    {
+        type Rejection = (StatusCode, String);
+
+        async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+            let AuthorizationRedirect(response) =
+                AuthorizationRedirect::from_request(req, state).await?;
+            let state_store: Arc<dyn AuthorizationStateStore> = FromRef::from_ref(state);
+
+            handle_authorization_redirect(response, state_store.as_ref())
+                .map(AuthorizationCodeRedirect)
+                .map_err(|err| (StatusCode::BAD_REQUEST, err.to_string()))
+        }
+    }
+}
+
+#[cfg(feature = "axum")]
+pub use axum_extract::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parameters(state: &str) -> AuthCodeAuthorizationUrlParameters {
+        AuthCodeAuthorizationUrlParameters::builder(uuid::Uuid::new_v4())
+            .with_state(state)
+            .build()
+    }
+
+    #[test]
+    fn redirect_validates_state_once() {
+        let state_store = InMemoryAuthorizationStateStore::new();
+        state_store
+            .insert_pending(PendingAuthorization::new(parameters("12345")))
+            .unwrap();
+
+        let response = AuthorizationResponse::from_query("code=code&state=12345").unwrap();
+        assert!(handle_authorization_redirect(response.clone(), &state_store).is_ok());
+        assert!(handle_authorization_redirect(response, &state_store).is_err());
+    }
+
+    #[test]
+    fn redirect_unknown_state() {
+        let state_store = InMemoryAuthorizationStateStore::new();
+        state_store
+            .insert_pending(PendingAuthorization::new(parameters("12345")))
+            .unwrap();
+
+        let response = AuthorizationResponse::from_query("code=code&state=54321").unwrap();
+        assert!(handle_authorization_redirect(response, &state_store).is_err());
+
+        let response =
+            AuthorizationResponse::from_query("error=access_denied&state=12345").unwrap();
+        assert!(handle_authorization_redirect(response, &state_store).is_err());
+    }
+}
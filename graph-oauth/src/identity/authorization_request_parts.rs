@@ -1,8 +1,21 @@
-use http::header::CONTENT_TYPE;
-use http::{HeaderMap, HeaderValue};
+use base64::Engine;
+use http::header::{AUTHORIZATION, CONTENT_TYPE};
+use http::{HeaderMap, HeaderValue, Method};
 use std::collections::HashMap;
 use url::Url;
 
+/// The parts of a token request: the token uri, the form urlencoded body, the basic
+/// auth credentials, and the headers.
+///
+/// The request parts can be converted for use with http clients other than reqwest.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::identity::{ClientSecretCredential, TokenCredentialExecutor};
+///
+/// let mut credential = ClientSecretCredential::new("client-id", "client-secret");
+/// let request: http::Request<String> = credential.request_parts()?.into_http_request()?;
+/// ```
 pub struct AuthorizationRequestParts {
     pub(crate) uri: Url,
     pub(crate) form_urlencoded: HashMap<String, String>,
@@ -42,4 +55,102 @@ impl AuthorizationRequestParts {
                 .append_pair(key.as_ref(), value.as_ref());
         }
     }
+
+    /// The form urlencoded parameters of the request body sorted by key.
+    pub fn into_query_pairs(self) -> Vec<(String, String)> {
+        let mut query_pairs: Vec<(String, String)> = self.form_urlencoded.into_iter().collect();
+        query_pairs.sort();
+        query_pairs
+    }
+
+    /// The application/x-www-form-urlencoded request body.
+    pub fn into_form_body(self) -> String {
+        AuthorizationRequestParts::form_body(&self.form_urlencoded)
+    }
+
+    /// Convert into a POST [http::Request] with the form urlencoded body. The basic auth
+    /// credentials, if any, are set in the Authorization header.
+    pub fn into_http_request(self) -> Result<http::Request<String>, http::Error> {
+        let body = AuthorizationRequestParts::form_body(&self.form_urlencoded);
+        let mut builder = http::Request::builder()
+            .method(Method::POST)
+            .uri(self.uri.as_str());
+
+        if let Some((client_identifier, secret)) = self.basic_auth.as_ref() {
+            let credentials = base64::engine::general_purpose::STANDARD
+                .encode(format!("{client_identifier}:{secret}"));
+            builder = builder.header(AUTHORIZATION, format!("Basic {credentials}"));
+        }
+
+        if let Some(headers) = builder.headers_mut() {
+            headers.extend(self.headers);
+        }
+
+        builder.body(body)
+    }
+
+    fn form_body(form_urlencoded: &HashMap<String, String>) -> String {
+        let mut query_pairs: Vec<(&String, &String)> = form_urlencoded.iter().collect();
+        query_pairs.sort();
+        url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(query_pairs)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn request_parts() -> AuthorizationRequestParts {
+        let form = HashMap::from([
+            ("grant_type".to_owned(), "client_credentials".to_owned()),
+            (
+                "scope".to_owned(),
+                "https://graph.microsoft.com/.default".to_owned(),
+            ),
+        ]);
+        AuthorizationRequestParts::new(
+            Url::parse("https://login.microsoftonline.com/common/oauth2/v2.0/token").unwrap(),
+            form,
+            Some(("client_id".to_owned(), "secret".to_owned())),
+        )
+    }
+
+    #[test]
+    fn into_form_body() {
+        assert_eq!(
+            "grant_type=client_credentials&scope=https%3A%2F%2Fgraph.microsoft.com%2F.default",
+            request_parts().into_form_body()
+        );
+        assert_eq!(
+            vec![
+                ("grant_type".to_owned(), "client_credentials".to_owned()),
+                (
+                    "scope".to_owned(),
+                    "https://graph.microsoft.com/.default".to_owned()
+                ),
+            ],
+            request_parts().into_query_pairs()
+        );
+    }
+
+    #[test]
+    fn into_http_request() {
+        let request = request_parts().into_http_request().unwrap();
+        assert_eq!(Method::POST, request.method());
+        assert_eq!(
+            "https://login.microsoftonline.com/common/oauth2/v2.0/token",
+            request.uri().to_string()
+        );
+        assert_eq!(
+            "application/x-www-form-urlencoded",
+            request.headers().get(CONTENT_TYPE).unwrap()
+        );
+        assert_eq!(
+            "Basic Y2xpZW50X2lkOnNlY3JldA==",
+            request.headers().get(AUTHORIZATION).unwrap()
+        );
+        assert!(request.body().starts_with("grant_type=client_credentials"));
+    }
 }
@@ -0,0 +1,206 @@
+use std::fmt::Display;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use url::Url;
+
+use graph_error::{IdentityResult, AF};
+
+/// Environment variable checked for the region before the instance metadata service
+/// is queried when using [AzureRegion::AutoDetect].
+const REGION_NAME: &str = "REGION_NAME";
+
+const IMDS_LOCATION_ENDPOINT: &str =
+    "http://169.254.169.254/metadata/instance/compute/location?format=text&api-version=2021-01-01";
+
+const IMDS_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Hosts of the Azure public cloud whose regional endpoints are under login.microsoft.com
+const PUBLIC_CLOUD_HOSTS: [&str; 4] = [
+    "login.microsoftonline.com",
+    "login.microsoft.com",
+    "login.windows.net",
+    "sts.windows.net",
+];
+
+static DETECTED_REGION: OnceLock<Option<String>> = OnceLock::new();
+
+/// The Azure region used for regional token endpoints (ESTS-R) such as
+/// `https://westus2.login.microsoft.com`.
+///
+/// Regional endpoints are only used by the client credentials flow. If a request
+/// to the regional endpoint fails, the token is requested from the global endpoint.
+///
+/// # Example
+/// ```rust
+/// use graph_oauth::AzureRegion;
+///
+/// assert_eq!(AzureRegion::AutoDetect, AzureRegion::from("auto"));
+/// assert_eq!(AzureRegion::Region("westus2".into()), AzureRegion::from("westus2"));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum AzureRegion {
+    /// Detect the region using the `REGION_NAME` environment variable or the
+    /// Azure instance metadata service. The global endpoint is used if the region
+    /// cannot be detected.
+    AutoDetect,
+    /// A region name such as `westus2`.
+    Region(String),
+}
+
+impl AzureRegion {
+    /// Detect the region of the current Azure compute instance. The region is
+    /// detected once and cached for the lifetime of the process.
+    pub fn detect() -> Option<String> {
+        if let Some(region) = DETECTED_REGION.get() {
+            return region.clone();
+        }
+
+        let region = region_from_env().or_else(imds_region);
+        DETECTED_REGION.get_or_init(|| region).clone()
+    }
+
+    /// Detect the region of the current Azure compute instance. The region is
+    /// detected once and cached for the lifetime of the process.
+    pub async fn detect_async() -> Option<String> {
+        if let Some(region) = DETECTED_REGION.get() {
+            return region.clone();
+        }
+
+        let region = match region_from_env() {
+            Some(region) => Some(region),
+            None => imds_region_async().await,
+        };
+        DETECTED_REGION.get_or_init(|| region).clone()
+    }
+
+    pub(crate) fn resolve(&self) -> Option<String> {
+        match self {
+            AzureRegion::AutoDetect => AzureRegion::detect(),
+            AzureRegion::Region(region) => Some(region.clone()),
+        }
+    }
+
+    pub(crate) async fn resolve_async(&self) -> Option<String> {
+        match self {
+            AzureRegion::AutoDetect => AzureRegion::detect_async().await,
+            AzureRegion::Region(region) => Some(region.clone()),
+        }
+    }
+
+    /// The regional endpoint of a token uri.
+    ///
+    /// Token uris of the Azure public cloud use `{region}.login.microsoft.com` and
+    /// token uris of other clouds use `{region}.{host}`.
+    pub fn regional_uri(region: &str, uri: &Url) -> IdentityResult<Url> {
+        let host = uri
+            .host_str()
+            .ok_or_else(|| AF::msg_err("authority", "authority uri is missing a host"))?;
+
+        let regional_host = if PUBLIC_CLOUD_HOSTS.contains(&host) {
+            format!("{region}.login.microsoft.com")
+        } else {
+            format!("{region}.{host}")
+        };
+
+        let mut regional_uri = uri.clone();
+        regional_uri.set_host(Some(regional_host.as_str()))?;
+        Ok(regional_uri)
+    }
+}
+
+fn normalize_region(region: impl AsRef<str>) -> Option<String> {
+    let region = region.as_ref().trim().to_lowercase().replace(' ', "");
+    if region.is_empty() {
+        None
+    } else {
+        Some(region)
+    }
+}
+
+fn region_from_env() -> Option<String> {
+    std::env::var(REGION_NAME).ok().and_then(normalize_region)
+}
+
+fn imds_region() -> Option<String> {
+    let http_client = reqwest::blocking::ClientBuilder::new()
+        .timeout(IMDS_TIMEOUT)
+        .build()
+        .ok()?;
+    let response = http_client
+        .get(IMDS_LOCATION_ENDPOINT)
+        .header("Metadata", "true")
+        .send()
+        .ok()?;
+    normalize_region(response.error_for_status().ok()?.text().ok()?)
+}
+
+async fn imds_region_async() -> Option<String> {
+    let http_client = reqwest::ClientBuilder::new()
+        .timeout(IMDS_TIMEOUT)
+        .build()
+        .ok()?;
+    let response = http_client
+        .get(IMDS_LOCATION_ENDPOINT)
+        .header("Metadata", "true")
+        .send()
+        .await
+        .ok()?;
+    normalize_region(response.error_for_status().ok()?.text().await.ok()?)
+}
+
+impl From<&str> for AzureRegion {
+    fn from(value: &str) -> Self {
+        if value.trim().eq_ignore_ascii_case("auto") {
+            AzureRegion::AutoDetect
+        } else {
+            AzureRegion::Region(value.trim().to_lowercase())
+        }
+    }
+}
+
+impl From<String> for AzureRegion {
+    fn from(value: String) -> Self {
+        AzureRegion::from(value.as_str())
+    }
+}
+
+impl Display for AzureRegion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AzureRegion::AutoDetect => write!(f, "auto"),
+            AzureRegion::Region(region) => write!(f, "{region}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn public_cloud_regional_uri() {
+        let uri = Url::parse("https://login.microsoftonline.com/tenant/oauth2/v2.0/token").unwrap();
+        assert_eq!(
+            "https://westus2.login.microsoft.com/tenant/oauth2/v2.0/token",
+            AzureRegion::regional_uri("westus2", &uri).unwrap().as_str()
+        );
+    }
+
+    #[test]
+    fn sovereign_cloud_regional_uri() {
+        let uri = Url::parse("https://login.microsoftonline.us/tenant/oauth2/v2.0/token").unwrap();
+        assert_eq!(
+            "https://usgovvirginia.login.microsoftonline.us/tenant/oauth2/v2.0/token",
+            AzureRegion::regional_uri("usgovvirginia", &uri)
+                .unwrap()
+                .as_str()
+        );
+    }
+
+    #[test]
+    fn normalize_region_name() {
+        assert_eq!(Some("westus2".to_string()), normalize_region(" West US2\n"));
+        assert_eq!(None, normalize_region(""));
+    }
+}
@@ -0,0 +1,258 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use graph_error::{AuthExecutionError, ErrorMessage};
+use http::{HeaderMap, HeaderValue, StatusCode};
+use uuid::Uuid;
+
+use crate::identity::AppConfig;
+
+const SCHEMA_VERSION: &str = "2";
+const CLIENT_SKU: &str = "graph-rs-sdk";
+const CLIENT_VER: &str = env!("CARGO_PKG_VERSION");
+
+/// Failed requests are dropped from the last request telemetry when the header would
+/// otherwise grow too large.
+const MAX_FAILED_REQUESTS: usize = 50;
+
+const X_CLIENT_SKU: &str = "x-client-sku";
+const X_CLIENT_VER: &str = "x-client-ver";
+const X_CLIENT_OS: &str = "x-client-os";
+const X_CLIENT_CPU: &str = "x-client-cpu";
+const X_CLIENT_CURRENT_TELEMETRY: &str = "x-client-current-telemetry";
+const X_CLIENT_LAST_TELEMETRY: &str = "x-client-last-telemetry";
+const CLIENT_REQUEST_ID: &str = "client-request-id";
+const RETURN_CLIENT_REQUEST_ID: &str = "return-client-request-id";
+
+struct FailedRequest {
+    api_id: String,
+    correlation_id: String,
+    error: String,
+}
+
+#[derive(Default)]
+struct RequestTelemetry {
+    /// The api id and correlation id of the last token request that was sent.
+    pending_request: Option<(String, String)>,
+    /// Failed token requests that are reported in the x-client-last-telemetry
+    /// header of the next token request.
+    failed_requests: Vec<FailedRequest>,
+}
+
+/// Telemetry of the token requests of a client application. Clones of the
+/// application share the telemetry.
+#[derive(Clone, Default)]
+pub(crate) struct ClientTelemetry(Arc<Mutex<RequestTelemetry>>);
+
+impl ClientTelemetry {
+    fn lock(&self) -> MutexGuard<'_, RequestTelemetry> {
+        self.0.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// The x-client-last-telemetry header value in the MSAL schema:
+    /// `2|{silent_successful_count}|{api_id,correlation_id,...}|{error,...}|`
+    fn last_telemetry(&self) -> String {
+        let telemetry = self.lock();
+        let requests = telemetry
+            .failed_requests
+            .iter()
+            .map(|failed| format!("{},{}", failed.api_id, failed.correlation_id))
+            .collect::<Vec<String>>()
+            .join(",");
+        let errors = telemetry
+            .failed_requests
+            .iter()
+            .map(|failed| failed.error.as_str())
+            .collect::<Vec<&str>>()
+            .join(",");
+        format!("{SCHEMA_VERSION}|0|{requests}|{errors}|")
+    }
+
+    /// Client identification and telemetry headers sent with token requests so that
+    /// requests can be correlated in Microsoft Entra sign-in logs.
+    ///
+    /// The api id is the grant type of the request.
+    pub(crate) fn headers(&self, api_id: &str, force_refresh: bool) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(X_CLIENT_SKU, HeaderValue::from_static(CLIENT_SKU));
+        headers.insert(X_CLIENT_VER, HeaderValue::from_static(CLIENT_VER));
+        headers.insert(X_CLIENT_OS, HeaderValue::from_static(std::env::consts::OS));
+        headers.insert(
+            X_CLIENT_CPU,
+            HeaderValue::from_static(std::env::consts::ARCH),
+        );
+        headers.insert(RETURN_CLIENT_REQUEST_ID, HeaderValue::from_static("true"));
+
+        let api_id = api_id.replace([',', '|'], "");
+        let correlation_id = Uuid::new_v4().to_string();
+        if let Ok(correlation_id) = HeaderValue::from_str(&correlation_id) {
+            headers.insert(CLIENT_REQUEST_ID, correlation_id);
+        }
+
+        let current = format!("{SCHEMA_VERSION}|{api_id},{}|", u8::from(force_refresh));
+        if let Ok(current) = HeaderValue::from_str(&current) {
+            headers.insert(X_CLIENT_CURRENT_TELEMETRY, current);
+        }
+        if let Ok(last) = HeaderValue::from_str(&self.last_telemetry()) {
+            headers.insert(X_CLIENT_LAST_TELEMETRY, last);
+        }
+
+        self.lock().pending_request = Some((api_id, correlation_id));
+        headers
+    }
+
+    /// The last token request succeeded. Failed requests are cleared because they
+    /// have been reported.
+    pub(crate) fn request_succeeded(&self) {
+        let mut telemetry = self.lock();
+        telemetry.pending_request = None;
+        telemetry.failed_requests.clear();
+    }
+
+    /// The last token request failed with the given error which is reported by the
+    /// next token request.
+    pub(crate) fn request_failed(&self, error: &str) {
+        let mut telemetry = self.lock();
+        let Some((api_id, correlation_id)) = telemetry.pending_request.take() else {
+            return;
+        };
+
+        if telemetry.failed_requests.len() >= MAX_FAILED_REQUESTS {
+            telemetry.failed_requests.remove(0);
+        }
+        telemetry.failed_requests.push(FailedRequest {
+            api_id,
+            correlation_id,
+            error: error.replace([',', '|'], ""),
+        });
+    }
+}
+
+impl Debug for ClientTelemetry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClientTelemetry").finish()
+    }
+}
+
+impl PartialEq for ClientTelemetry {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// The correlation id sent in the client-request-id header of a token request.
+pub(crate) fn client_request_id(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(CLIENT_REQUEST_ID)
+        .and_then(|value| value.to_str().ok())
+}
+
+/// Record the status of a token request, or the transport error if no response was
+/// received. Error responses are recorded by [token_request_error] which has the
+/// error of the response body.
+pub(crate) fn record_response(app_config: &AppConfig, status: Result<StatusCode, &reqwest::Error>) {
+    if !app_config.token_credential_options.telemetry {
+        return;
+    }
+
+    match status {
+        Ok(status) if status.is_success() => app_config.client_telemetry.request_succeeded(),
+        Ok(_) => {}
+        Err(_) => app_config.client_telemetry.request_failed("network_error"),
+    }
+}
+
+/// The error of a failed token response. The `error` of the response, or its
+/// first AADSTS error code, is reported by the next token request when telemetry
+/// is enabled.
+pub(crate) fn token_request_error(
+    app_config: &AppConfig,
+    response: http::Response<Result<serde_json::Value, ErrorMessage>>,
+) -> AuthExecutionError {
+    let error = AuthExecutionError::silent_token_auth(response);
+    if app_config.token_credential_options.telemetry {
+        let error_code = error
+            .msal_error_response()
+            .and_then(|error_response| {
+                if error_response.error.is_empty() {
+                    error_response.aadsts_code().map(|code| code.to_string())
+                } else {
+                    Some(error_response.error)
+                }
+            })
+            .unwrap_or_else(|| "unknown_error".to_owned());
+        app_config
+            .client_telemetry
+            .request_failed(error_code.as_str());
+    }
+    error
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn error_response(
+        body: serde_json::Value,
+    ) -> http::Response<Result<serde_json::Value, ErrorMessage>> {
+        http::Response::builder()
+            .status(400)
+            .body(Ok(body))
+            .unwrap()
+    }
+
+    #[test]
+    fn last_telemetry_reports_failed_requests() {
+        let mut app_config = AppConfig::new(Uuid::new_v4());
+        app_config.token_credential_options.with_telemetry(true);
+
+        let telemetry = app_config.client_telemetry.clone();
+        let headers = telemetry.headers("client_credentials", false);
+        assert_eq!(
+            "2|client_credentials,0|",
+            headers.get(X_CLIENT_CURRENT_TELEMETRY).unwrap()
+        );
+        assert_eq!("2|0|||", headers.get(X_CLIENT_LAST_TELEMETRY).unwrap());
+
+        token_request_error(
+            &app_config,
+            error_response(serde_json::json!({
+                "error": "invalid_client",
+                "error_codes": [7000215]
+            })),
+        );
+        let correlation_id = client_request_id(&headers).unwrap();
+        assert_eq!(
+            format!("2|0|client_credentials,{correlation_id}|invalid_client|"),
+            telemetry.last_telemetry()
+        );
+
+        telemetry.request_succeeded();
+        assert_eq!("2|0|||", telemetry.last_telemetry());
+    }
+
+    #[test]
+    fn telemetry_is_scoped_to_the_client() {
+        let mut app_config = AppConfig::new(Uuid::new_v4());
+        app_config.token_credential_options.with_telemetry(true);
+        let other_app_config = AppConfig::new(app_config.client_id);
+
+        app_config
+            .client_telemetry
+            .headers("client_credentials", false);
+        token_request_error(
+            &app_config,
+            error_response(serde_json::json!({ "error": "", "error_codes": [7000215] })),
+        );
+
+        assert!(app_config
+            .client_telemetry
+            .last_telemetry()
+            .ends_with("|7000215|"));
+        assert_eq!("2|0|||", other_app_config.client_telemetry.last_telemetry());
+        assert_eq!(
+            app_config.client_telemetry,
+            app_config.clone().client_telemetry
+        );
+    }
+}
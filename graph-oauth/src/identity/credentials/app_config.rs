@@ -3,13 +3,17 @@ use http::{HeaderName, HeaderValue};
 use std::collections::{BTreeSet, HashMap};
 use std::fmt::{Debug, Formatter};
 
-use graph_core::identity::ForceTokenRefresh;
-use graph_error::AF;
+use graph_core::identity::{ForceTokenRefresh, LogPolicy};
+use graph_error::{IdentityResult, AF};
 use reqwest::header::HeaderMap;
 use url::Url;
 use uuid::Uuid;
 
-use crate::identity::{Authority, AzureCloudInstance, IdToken};
+use crate::identity::{
+    AuthEventSink, Authority, AuthorityEndpoints, AzureCloudInstance, AzureRegion, ClientTelemetry,
+    IdToken, RefreshTokenRotationHook, SharedAuthEventSink, TokenCredentialOptions,
+    TokenHttpClient,
+};
 use crate::ApplicationOptions;
 
 #[derive(Clone, Default, PartialEq)]
@@ -29,6 +33,11 @@ pub struct AppConfig {
     /// STS instance (for instance https://login.microsoftonline.com for the Azure public cloud).
     /// Maps to the instance url string.
     pub(crate) azure_cloud_instance: AzureCloudInstance,
+    /// Custom authority host that overrides the host of the [AzureCloudInstance] such as
+    /// the host of an on-premises ADFS server.
+    pub(crate) instance: Option<Url>,
+    /// Region of the regional token endpoint (ESTS-R) used by the client credentials flow.
+    pub(crate) azure_region: Option<AzureRegion>,
     pub(crate) extra_query_parameters: HashMap<String, String>,
     pub(crate) extra_header_parameters: HeaderMap,
     /// Required -
@@ -65,7 +74,18 @@ pub struct AppConfig {
     pub(crate) cache_id: String,
     pub(crate) force_token_refresh: ForceTokenRefresh,
     pub(crate) id_token: Option<IdToken>,
-    pub(crate) log_pii: bool,
+    /// How personally identifiable information and secrets are written to debug output
+    /// and token lifecycle log events.
+    pub(crate) log_policy: LogPolicy,
+    pub(crate) token_credential_options: TokenCredentialOptions,
+    /// Called when the refresh token of a credential is rotated.
+    pub(crate) refresh_token_rotation_hook: Option<RefreshTokenRotationHook>,
+    /// Http client used for async token requests instead of the shared default client.
+    pub(crate) http_client: TokenHttpClient,
+    /// Receives authentication events of the credential.
+    pub(crate) auth_event_sink: Option<SharedAuthEventSink>,
+    /// Failed token requests reported by the telemetry headers of the next token request.
+    pub(crate) client_telemetry: ClientTelemetry,
 }
 
 impl TryFrom<ApplicationOptions> for AppConfig {
@@ -82,6 +102,8 @@ impl TryFrom<ApplicationOptions> for AppConfig {
                 .map(Authority::from)
                 .unwrap_or_default(),
             azure_cloud_instance: value.azure_cloud_instance.unwrap_or_default(),
+            instance: value.instance,
+            azure_region: None,
             extra_query_parameters: Default::default(),
             extra_header_parameters: Default::default(),
             scope: Default::default(),
@@ -93,45 +115,46 @@ impl TryFrom<ApplicationOptions> for AppConfig {
             cache_id,
             force_token_refresh: Default::default(),
             id_token: Default::default(),
-            log_pii: false,
+            log_policy: LogPolicy::default(),
+            token_credential_options: Default::default(),
+            refresh_token_rotation_hook: None,
+            http_client: Default::default(),
+            auth_event_sink: None,
+            client_telemetry: Default::default(),
         })
     }
 }
 
 impl Debug for AppConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        if self.log_pii {
-            f.debug_struct("AppConfig")
-                .field("tenant_id", &self.tenant_id)
-                .field("client_id", &self.client_id)
-                .field("authority", &self.authority)
-                .field("azure_cloud_instance", &self.azure_cloud_instance)
-                .field("extra_query_parameters", &self.extra_query_parameters)
-                .field("extra_header_parameters", &self.extra_header_parameters)
-                .field("scope", &self.scope)
-                .field("force_token_refresh", &self.force_token_refresh)
-                .finish()
-        } else {
-            f.debug_struct("AppConfig")
-                .field(
-                    "tenant_id",
-                    &"[REDACTED] - call enable_pii_logging(true) to log value",
-                )
-                .field(
-                    "client_id",
-                    &"[REDACTED] - call enable_pii_logging(true) to log value",
-                )
-                .field("authority", &self.authority)
-                .field("azure_cloud_instance", &self.azure_cloud_instance)
-                .field("extra_query_parameters", &self.extra_query_parameters)
-                .field(
-                    "extra_header_parameters",
-                    &"[REDACTED] - call enable_pii_logging(true) to log value",
-                )
-                .field("scope", &self.scope)
-                .field("force_token_refresh", &self.force_token_refresh)
-                .finish()
-        }
+        let mut debug = f.debug_struct("AppConfig");
+        self.log_policy
+            .debug_field(&mut debug, "tenant_id", &self.tenant_id);
+        self.log_policy
+            .debug_field(&mut debug, "client_id", &self.client_id);
+        debug
+            .field("authority", &self.authority)
+            .field("azure_cloud_instance", &self.azure_cloud_instance)
+            .field("instance", &self.instance)
+            .field("azure_region", &self.azure_region)
+            .field("extra_query_parameters", &self.extra_query_parameters);
+        self.log_policy.debug_field(
+            &mut debug,
+            "extra_header_parameters",
+            &self.extra_header_parameters,
+        );
+        debug
+            .field("scope", &self.scope)
+            .field("force_token_refresh", &self.force_token_refresh)
+            .field("token_credential_options", &self.token_credential_options)
+            .field(
+                "refresh_token_rotation_hook",
+                &self.refresh_token_rotation_hook,
+            )
+            .field("http_client", &self.http_client)
+            .field("auth_event_sink", &self.auth_event_sink)
+            .field("log_policy", &self.log_policy)
+            .finish()
     }
 }
 
@@ -158,6 +181,8 @@ impl AppConfig {
             client_id,
             authority: Default::default(),
             azure_cloud_instance: Default::default(),
+            instance: None,
+            azure_region: None,
             extra_query_parameters: Default::default(),
             extra_header_parameters: Default::default(),
             scope: Default::default(),
@@ -169,12 +194,21 @@ impl AppConfig {
             cache_id,
             force_token_refresh: Default::default(),
             id_token: Default::default(),
-            log_pii: Default::default(),
+            log_policy: LogPolicy::default(),
+            token_credential_options: Default::default(),
+            refresh_token_rotation_hook: None,
+            http_client: Default::default(),
+            auth_event_sink: None,
+            client_telemetry: Default::default(),
         }
     }
 
     pub fn enable_pii_logging(&mut self, log_pii: bool) {
-        self.log_pii = log_pii;
+        self.log_policy = LogPolicy::from_pii_logging(log_pii);
+    }
+
+    pub(crate) fn with_log_policy(&mut self, log_policy: LogPolicy) {
+        self.log_policy = log_policy;
     }
 
     pub(crate) fn with_client_id(&mut self, client_id: impl TryInto<Uuid>) {
@@ -192,6 +226,80 @@ impl AppConfig {
         self.azure_cloud_instance = azure_cloud_instance;
     }
 
+    pub(crate) fn with_instance(&mut self, instance: Url) {
+        self.instance = Some(instance);
+    }
+
+    /// The endpoints of the authority using the custom instance if one is set
+    /// or the [AzureCloudInstance] of the app config otherwise.
+    pub(crate) fn authority_endpoints(&self) -> AuthorityEndpoints {
+        self.authority_endpoints_with_host(&self.azure_cloud_instance)
+    }
+
+    /// The endpoints of the authority using the custom instance if one is set
+    /// or the given [AzureCloudInstance] otherwise.
+    pub(crate) fn authority_endpoints_with_host(
+        &self,
+        azure_cloud_instance: &AzureCloudInstance,
+    ) -> AuthorityEndpoints {
+        match self.instance.as_ref() {
+            Some(instance) => AuthorityEndpoints::new(instance.as_str(), self.authority.clone()),
+            None => azure_cloud_instance.authority_endpoints(&self.authority),
+        }
+    }
+
+    /// The scope of the request with Microsoft Graph scopes rewritten to the
+    /// Microsoft Graph resource of the [AzureCloudInstance] of the app config.
+    pub(crate) fn cloud_scope(&self) -> IdentityResult<BTreeSet<String>> {
+        self.cloud_scope_with_host(&self.azure_cloud_instance)
+    }
+
+    /// The scope of the request with Microsoft Graph scopes rewritten to the
+    /// Microsoft Graph resource of the given [AzureCloudInstance].
+    ///
+    /// Scopes are not rewritten when a custom instance is used that is not a
+    /// login host of a cloud.
+    pub(crate) fn cloud_scope_with_host(
+        &self,
+        azure_cloud_instance: &AzureCloudInstance,
+    ) -> IdentityResult<BTreeSet<String>> {
+        match self.cloud_of_host(azure_cloud_instance) {
+            Some(cloud) => self
+                .scope
+                .iter()
+                .map(|scope| cloud.graph_scope(scope))
+                .collect(),
+            None => Ok(self.scope.clone()),
+        }
+    }
+
+    /// The cloud that tokens are requested from. This is the given [AzureCloudInstance]
+    /// unless a custom instance is set, in which case it is the cloud of the instance
+    /// host such as [AzureCloudInstance::AzureChina] for `login.partner.microsoftonline.cn`
+    /// or None for hosts that are not a login host of a cloud such as ADFS servers.
+    pub(crate) fn cloud_of_host(
+        &self,
+        azure_cloud_instance: &AzureCloudInstance,
+    ) -> Option<AzureCloudInstance> {
+        let Some(instance) = self.instance.as_ref() else {
+            return Some(*azure_cloud_instance);
+        };
+
+        let cloud = instance
+            .host_str()
+            .and_then(AzureCloudInstance::from_host)?;
+        if cloud.as_ref() == azure_cloud_instance.as_ref() {
+            // Keeps GCC High and DoD which share the login host of the US Government cloud.
+            Some(*azure_cloud_instance)
+        } else {
+            Some(cloud)
+        }
+    }
+
+    pub(crate) fn with_azure_region(&mut self, azure_region: AzureRegion) {
+        self.azure_region = Some(azure_region);
+    }
+
     pub(crate) fn with_tenant(&mut self, tenant_id: impl AsRef<str>) {
         let tenant = tenant_id.as_ref().to_string();
         self.tenant_id = Some(tenant.clone());
@@ -235,6 +343,25 @@ impl AppConfig {
         self.scope = scope.into_iter().map(|s| s.to_string()).collect();
     }
 
+    pub(crate) fn with_token_credential_options(
+        &mut self,
+        token_credential_options: TokenCredentialOptions,
+    ) {
+        self.token_credential_options = token_credential_options;
+    }
+
+    pub(crate) fn with_http_client(&mut self, http_client: reqwest::Client) {
+        self.http_client.with_http_client(http_client);
+    }
+
+    pub(crate) fn with_blocking_http_client(&mut self, http_client: reqwest::blocking::Client) {
+        self.http_client.with_blocking_http_client(http_client);
+    }
+
+    pub(crate) fn with_auth_event_sink(&mut self, auth_event_sink: impl AuthEventSink + 'static) {
+        self.auth_event_sink = Some(SharedAuthEventSink::new(auth_event_sink));
+    }
+
     pub(crate) fn with_id_token(&mut self, id_token: IdToken) {
         self.id_token = Some(id_token);
     }
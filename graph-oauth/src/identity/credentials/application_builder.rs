@@ -2,16 +2,19 @@ use crate::identity::{
     application_options::ApplicationOptions, credentials::app_config::AppConfig,
     AuthCodeAuthorizationUrlParameterBuilder, Authority,
     AuthorizationCodeAssertionCredentialBuilder, AuthorizationCodeCredentialBuilder,
-    AzureCloudInstance, ClientAssertionCredentialBuilder,
+    AzureCloudInstance, AzureRegion, ClientAssertionCredentialBuilder,
     ClientCredentialsAuthorizationUrlParameterBuilder, ClientSecretCredentialBuilder,
     DeviceCodeCredentialBuilder, DeviceCodePollingExecutor, EnvironmentCredential,
     OpenIdAuthorizationUrlParameterBuilder, OpenIdCredentialBuilder, PublicClientApplication,
     ResourceOwnerPasswordCredential, ResourceOwnerPasswordCredentialBuilder,
+    TokenCredentialOptions,
 };
+use graph_core::crypto::ProofKeyCodeExchange;
 use graph_error::{IdentityResult, AF};
 use http::{HeaderMap, HeaderName, HeaderValue};
 use std::collections::HashMap;
 use std::env::VarError;
+use url::Url;
 use uuid::Uuid;
 
 #[cfg(feature = "openssl")]
@@ -56,6 +59,33 @@ impl ConfidentialClientApplicationBuilder {
         self
     }
 
+    /// Use a custom authority host instead of the host of the [AzureCloudInstance]
+    /// such as the host of an on-premises ADFS server.
+    pub fn with_instance(&mut self, instance: Url) -> &mut Self {
+        self.app_config.with_instance(instance);
+        self
+    }
+
+    /// Request tokens from the regional endpoint (ESTS-R) of an Azure region such as
+    /// `westus2` for lower latency and higher availability. Use `auto` to detect the
+    /// region of the current Azure compute instance. Tokens are requested from the
+    /// global endpoint if the regional endpoint fails.
+    pub fn with_azure_region(&mut self, azure_region: impl Into<AzureRegion>) -> &mut Self {
+        self.app_config.with_azure_region(azure_region.into());
+        self
+    }
+
+    /// Options that apply to token requests such as whether the authority
+    /// host is validated using instance discovery.
+    pub fn with_token_credential_options(
+        &mut self,
+        token_credential_options: TokenCredentialOptions,
+    ) -> &mut Self {
+        self.app_config
+            .with_token_credential_options(token_credential_options);
+        self
+    }
+
     /// Extends the query parameters of both the default query params and user defined params.
     /// Does not overwrite default params.
     pub fn with_extra_query_param(&mut self, query_param: (String, String)) -> &mut Self {
@@ -277,6 +307,24 @@ impl PublicClientApplicationBuilder {
         self
     }
 
+    /// Use a custom authority host instead of the host of the [AzureCloudInstance]
+    /// such as the host of an on-premises ADFS server.
+    pub fn with_instance(&mut self, instance: Url) -> &mut Self {
+        self.app_config.with_instance(instance);
+        self
+    }
+
+    /// Options that apply to token requests such as whether the authority
+    /// host is validated using instance discovery.
+    pub fn with_token_credential_options(
+        &mut self,
+        token_credential_options: TokenCredentialOptions,
+    ) -> &mut Self {
+        self.app_config
+            .with_token_credential_options(token_credential_options);
+        self
+    }
+
     /// Extends the query parameters of both the default query params and user defined params.
     /// Does not overwrite default params.
     pub fn with_extra_query_param(&mut self, query_param: (String, String)) -> &mut Self {
@@ -334,6 +382,24 @@ impl PublicClientApplicationBuilder {
         )
     }
 
+    /// Auth code flow for desktop and mobile apps which can't store a client secret.
+    /// The authorization code is exchanged using the code verifier of PKCE.
+    ///
+    /// Use [AuthorizationCodeCredentialBuilder::build_public_client] to build the
+    /// [PublicClientApplication].
+    pub fn with_auth_code_pkce(
+        &mut self,
+        authorization_code: impl AsRef<str>,
+        proof_key_for_code_exchange: &ProofKeyCodeExchange,
+    ) -> AuthorizationCodeCredentialBuilder {
+        let mut credential_builder = AuthorizationCodeCredentialBuilder::new_with_auth_code(
+            authorization_code,
+            self.app_config.clone(),
+        );
+        credential_builder.with_pkce(proof_key_for_code_exchange);
+        credential_builder
+    }
+
     pub fn with_username_password(
         &mut self,
         username: impl AsRef<str>,
@@ -23,8 +23,8 @@ use crate::identity::X509Certificate;
 #[cfg(feature = "interactive-auth")]
 use {
     crate::identity::{
-        tracing_targets::INTERACTIVE_AUTH, AuthorizationCodeCertificateCredentialBuilder,
-        AuthorizationResponse, Token,
+        interactive_prompt_shown, tracing_targets::INTERACTIVE_AUTH,
+        AuthorizationCodeCertificateCredentialBuilder, AuthorizationResponse, Token,
     },
     crate::interactive::{
         HostOptions, InteractiveAuthEvent, UserEvents, WebViewAuth, WebViewAuthorizationEvent,
@@ -136,6 +136,8 @@ pub struct AuthCodeAuthorizationUrlParameters {
     pub(crate) login_hint: Option<String>,
     pub(crate) code_challenge: Option<String>,
     pub(crate) code_challenge_method: Option<String>,
+    /// Set with `with_pkce` so the code verifier is carried over to the credential builder.
+    pub(crate) pkce: Option<ProofKeyCodeExchange>,
 }
 
 impl Debug for AuthCodeAuthorizationUrlParameters {
@@ -171,6 +173,7 @@ impl AuthCodeAuthorizationUrlParameters {
             login_hint: None,
             code_challenge: None,
             code_challenge_method: None,
+            pkce: None,
         })
     }
 
@@ -190,7 +193,14 @@ impl AuthCodeAuthorizationUrlParameters {
         self,
         authorization_code: impl AsRef<str>,
     ) -> AuthorizationCodeCredentialBuilder {
-        AuthorizationCodeCredentialBuilder::new_with_auth_code(authorization_code, self.app_config)
+        let mut credential_builder = AuthorizationCodeCredentialBuilder::new_with_auth_code(
+            authorization_code,
+            self.app_config,
+        );
+        if let Some(pkce) = self.pkce.as_ref() {
+            credential_builder.with_pkce(pkce);
+        }
+        credential_builder
     }
 
     pub fn into_assertion_credential(
@@ -216,6 +226,11 @@ impl AuthCodeAuthorizationUrlParameters {
         )
     }
 
+    /// Get the state. See [validate_state](crate::identity::validate_state).
+    pub fn state(&self) -> Option<&String> {
+        self.state.as_ref()
+    }
+
     /// Get the nonce.
     ///
     /// This value may be generated automatically by the client and may be useful for users
@@ -231,11 +246,18 @@ impl AuthCodeAuthorizationUrlParameters {
         &self,
         options: WebViewOptions,
     ) -> WebViewResult<AuthorizationResponse> {
-        let uri = self
+        let mut parameters = self.clone();
+        if options.validate_state && parameters.state.is_none() {
+            parameters.state = Some(secure_random_32());
+        }
+        let expected_state = parameters.state.clone().filter(|_| options.validate_state);
+
+        let uri = parameters
             .url()
             .map_err(|err| Box::new(AuthExecutionError::from(err)))?;
         let redirect_uri = self.redirect_uri().cloned().unwrap();
         let (sender, receiver) = std::sync::mpsc::channel();
+        interactive_prompt_shown(&parameters.app_config);
 
         std::thread::spawn(move || {
             AuthCodeAuthorizationUrlParameters::run(uri, vec![redirect_uri], options, sender)
@@ -255,17 +277,8 @@ impl AuthCodeAuthorizationUrlParameters {
                     Err(WebViewError::InvalidUri(reason))
                 }
                 InteractiveAuthEvent::ReachedRedirectUri(uri) => {
-                    let query = uri
-                        .query()
-                        .or(uri.fragment())
-                        .ok_or(WebViewError::InvalidUri(format!(
-                            "uri missing query or fragment: {}",
-                            uri
-                        )))?;
-
-                    let response_query: AuthorizationResponse =
-                        serde_urlencoded::from_str(query)
-                            .map_err(|err| WebViewError::InvalidUri(err.to_string()))?;
+                    let response_query = AuthorizationResponse::from_redirect_uri(&uri)
+                        .map_err(|err| WebViewError::InvalidUri(err.to_string()))?;
 
                     if response_query.is_err() {
                         tracing::debug!(target: INTERACTIVE_AUTH, "error in authorization query or fragment from redirect uri");
@@ -279,6 +292,10 @@ impl AuthCodeAuthorizationUrlParameters {
                         });
                     }
 
+                    if let Some(expected_state) = expected_state.as_ref() {
+                        response_query.validate_state(expected_state)?;
+                    }
+
                     tracing::debug!(target: INTERACTIVE_AUTH, "parsed authorization query or fragment from redirect uri");
 
                     Ok(response_query)
@@ -320,17 +337,8 @@ impl AuthCodeAuthorizationUrlParameters {
                     Err(WebViewError::InvalidUri(reason))
                 }
                 InteractiveAuthEvent::ReachedRedirectUri(uri) => {
-                    let query = uri
-                        .query()
-                        .or(uri.fragment())
-                        .ok_or(WebViewError::InvalidUri(format!(
-                            "uri missing query or fragment: {}",
-                            uri
-                        )))?;
-
-                    let response_query: AuthorizationResponse =
-                        serde_urlencoded::from_str(query)
-                            .map_err(|err| WebViewError::InvalidUri(err.to_string()))?;
+                    let response_query = AuthorizationResponse::from_redirect_uri(&uri)
+                        .map_err(|err| WebViewError::InvalidUri(err.to_string()))?;
 
                     Ok(response_query)
                 }
@@ -415,9 +423,10 @@ impl AuthorizationUrl for AuthCodeAuthorizationUrlParameters {
             return AF::result("scope");
         }
 
-        serializer
-            .client_id(client_id.as_str())
-            .set_scope(self.app_config.scope.clone());
+        serializer.client_id(client_id.as_str()).set_scope(
+            self.app_config
+                .cloud_scope_with_host(azure_cloud_instance)?,
+        );
 
         let response_types: Vec<String> =
             self.response_type.iter().map(|s| s.to_string()).collect();
@@ -454,11 +463,20 @@ impl AuthorizationUrl for AuthCodeAuthorizationUrlParameters {
             serializer.state(state.as_str());
         }
 
-        if !self.prompt.is_empty() {
-            serializer.prompt(&self.prompt.as_query());
+        // ADFS does not support domain_hint and only supports prompt=login and prompt=none.
+        let is_adfs = self.app_config.authority.is_adfs();
+
+        let prompt: BTreeSet<Prompt> = self
+            .prompt
+            .iter()
+            .filter(|prompt| !is_adfs || prompt.is_supported_by_adfs())
+            .cloned()
+            .collect();
+        if !prompt.is_empty() {
+            serializer.prompt(&prompt.as_query());
         }
 
-        if let Some(domain_hint) = self.domain_hint.as_ref() {
+        if let Some(domain_hint) = self.domain_hint.as_ref().filter(|_| !is_adfs) {
             serializer.domain_hint(domain_hint.as_str());
         }
 
@@ -497,8 +515,17 @@ impl AuthorizationUrl for AuthCodeAuthorizationUrlParameters {
             ],
         )?;
 
-        let mut uri = azure_cloud_instance.auth_uri(&self.app_config.authority)?;
+        let mut uri = self
+            .app_config
+            .authority_endpoints_with_host(azure_cloud_instance)
+            .auth_uri()?;
         uri.set_query(Some(query.as_str()));
+
+        // The user flow of an Azure AD B2C authority is sent in the p query parameter
+        // as well as in the path.
+        if let Some(policy) = self.app_config.authority.policy() {
+            uri.query_pairs_mut().append_pair("p", policy);
+        }
         Ok(uri)
     }
 }
@@ -524,6 +551,7 @@ impl AuthCodeAuthorizationUrlParameterBuilder {
                 login_hint: None,
                 code_challenge: None,
                 code_challenge_method: None,
+                pkce: None,
             },
         }
     }
@@ -545,6 +573,7 @@ impl AuthCodeAuthorizationUrlParameterBuilder {
                 login_hint: None,
                 code_challenge: None,
                 code_challenge_method: None,
+                pkce: None,
             },
         }
     }
@@ -604,6 +633,16 @@ impl AuthCodeAuthorizationUrlParameterBuilder {
         self
     }
 
+    /// Generates a secure random state. See [validate_state](crate::identity::validate_state).
+    pub fn with_generated_state(&mut self) -> &mut Self {
+        self.credential.state = Some(secure_random_32());
+        self
+    }
+
+    pub fn state(&self) -> Option<&String> {
+        self.credential.state.as_ref()
+    }
+
     /// Indicates the type of user interaction that is required. Valid values are login, none,
     /// consent, and select_account.
     ///
@@ -655,6 +694,7 @@ impl AuthCodeAuthorizationUrlParameterBuilder {
     pub fn with_pkce(&mut self, proof_key_for_code_exchange: &ProofKeyCodeExchange) -> &mut Self {
         self.with_code_challenge(proof_key_for_code_exchange.code_challenge.as_str());
         self.with_code_challenge_method(proof_key_for_code_exchange.code_challenge_method.as_str());
+        self.credential.pkce = Some(proof_key_for_code_exchange.clone());
         self
     }
 
@@ -674,10 +714,7 @@ impl AuthCodeAuthorizationUrlParameterBuilder {
         self,
         authorization_code: impl AsRef<str>,
     ) -> AuthorizationCodeCredentialBuilder {
-        AuthorizationCodeCredentialBuilder::new_with_auth_code(
-            authorization_code,
-            self.credential.app_config,
-        )
+        self.credential.into_credential(authorization_code)
     }
 
     pub fn with_auth_code_assertion(
@@ -840,6 +877,7 @@ impl WithInteractiveAuth<&X509Certificate> for AuthCodeAuthorizationUrlParameter
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::identity::Authority;
 
     #[test]
     fn serialize_uri() {
@@ -862,6 +900,45 @@ mod test {
         assert!(url_result.is_ok());
     }
 
+    #[test]
+    fn url_with_b2c_authority() {
+        let url = AuthCodeAuthorizationUrlParameters::builder(Uuid::new_v4())
+            .with_redirect_uri(Url::parse("https://localhost:8080").unwrap())
+            .with_scope(["openid", "offline_access"])
+            .with_authority(Authority::b2c("contoso", "B2C_1_signupsignin"))
+            .url()
+            .unwrap();
+
+        assert_eq!(Some("contoso.b2clogin.com"), url.host_str());
+        assert_eq!(
+            "/contoso.onmicrosoft.com/B2C_1_signupsignin/oauth2/v2.0/authorize",
+            url.path()
+        );
+        assert!(url
+            .query_pairs()
+            .any(|(key, value)| key == "p" && value == "B2C_1_signupsignin"));
+    }
+
+    #[test]
+    fn url_with_adfs_authority() {
+        let url = AuthCodeAuthorizationUrlParameters::builder(Uuid::new_v4())
+            .with_redirect_uri(Url::parse("https://localhost:8080").unwrap())
+            .with_scope(["openid"])
+            .with_authority(Authority::AzureDirectoryFederatedServices)
+            .with_instance(Url::parse("https://fs.contoso.com").unwrap())
+            .with_domain_hint("contoso.com")
+            .with_prompt([Prompt::Login, Prompt::SelectAccount])
+            .url()
+            .unwrap();
+
+        assert_eq!(Some("fs.contoso.com"), url.host_str());
+        assert_eq!("/adfs/oauth2/authorize", url.path());
+
+        let query: HashMap<String, String> = url.query_pairs().into_owned().collect();
+        assert!(!query.contains_key("domain_hint"));
+        assert_eq!(Some(&"login".to_string()), query.get("prompt"));
+    }
+
     #[test]
     #[should_panic]
     fn response_type_id_token_panics_when_response_mode_query() {
@@ -916,4 +993,18 @@ mod test {
         let query = url.query().unwrap();
         assert!(query.contains("nonce"));
     }
+
+    #[test]
+    fn generate_state() {
+        let mut builder = AuthCodeAuthorizationUrlParameters::builder(Uuid::new_v4());
+        builder
+            .with_redirect_uri(Url::parse("https://localhost:8080").unwrap())
+            .with_scope(["read", "write"])
+            .with_generated_state();
+
+        let state = builder.state().cloned().unwrap();
+        let url = builder.url().unwrap();
+        let query: HashMap<String, String> = url.query_pairs().into_owned().collect();
+        assert_eq!(Some(&state), query.get("state"));
+    }
 }
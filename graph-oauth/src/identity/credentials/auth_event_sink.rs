@@ -0,0 +1,156 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::identity::AppConfig;
+
+/// The kind of an [AuthEvent].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AuthEventKind {
+    /// A token request was sent to the token endpoint. The grant type is the
+    /// `grant_type` of the request such as `authorization_code` or `refresh_token`.
+    TokenRequested { grant_type: String },
+    /// The token endpoint returned a new token.
+    TokenAcquired,
+    /// The token endpoint returned a new token for a refresh token.
+    TokenRefreshed,
+    /// A token that has not expired was returned from the token cache.
+    CacheHit,
+    /// The sign in page was shown to the user in a webview.
+    InteractivePromptShown,
+    /// The token endpoint rejected the request because the user or an administrator
+    /// has to consent to the requested permissions first.
+    ConsentRequired { error: String },
+}
+
+/// A structured authentication event of a credential.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuthEvent {
+    pub kind: AuthEventKind,
+    pub timestamp: OffsetDateTime,
+    /// The correlation id of the token request, which is the `client-request-id` header
+    /// sent with the request, used to find the request in Microsoft Entra sign-in logs.
+    /// None for events that are not tied to a single token request such as cache hits
+    /// or when token request telemetry is disabled.
+    pub correlation_id: Option<String>,
+    pub client_id: Uuid,
+    pub tenant_id: Option<String>,
+}
+
+/// Receives the [AuthEvent]s of a credential such as to feed authentication
+/// telemetry into a SIEM.
+///
+/// Events are recorded on the thread that requests the token and implementations
+/// should not block. Closures taking an [AuthEvent] implement the trait.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::identity::{AuthEvent, ConfidentialClientApplication};
+///
+/// let confidential_client = ConfidentialClientApplication::builder("client-id")
+///     .with_client_secret("client-secret")
+///     .with_tenant("tenant-id")
+///     .with_auth_event_sink(|event: &AuthEvent| {
+///         println!("{:?} {:?} {:?}", event.timestamp, event.kind, event.correlation_id);
+///     })
+///     .build();
+/// ```
+pub trait AuthEventSink: Send + Sync {
+    fn record(&self, event: &AuthEvent);
+}
+
+impl<F> AuthEventSink for F
+where
+    F: Fn(&AuthEvent) + Send + Sync,
+{
+    fn record(&self, event: &AuthEvent) {
+        self(event)
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct SharedAuthEventSink(Arc<dyn AuthEventSink>);
+
+impl SharedAuthEventSink {
+    pub(crate) fn new(auth_event_sink: impl AuthEventSink + 'static) -> SharedAuthEventSink {
+        SharedAuthEventSink(Arc::new(auth_event_sink))
+    }
+}
+
+impl Debug for SharedAuthEventSink {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuthEventSink").finish()
+    }
+}
+
+impl PartialEq for SharedAuthEventSink {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Send an event to the [AuthEventSink] of the app config if one is set.
+pub(crate) fn record_auth_event(
+    app_config: &AppConfig,
+    kind: AuthEventKind,
+    correlation_id: Option<String>,
+) {
+    if let Some(auth_event_sink) = app_config.auth_event_sink.as_ref() {
+        auth_event_sink.0.record(&AuthEvent {
+            kind,
+            timestamp: OffsetDateTime::now_utc(),
+            correlation_id,
+            client_id: app_config.client_id,
+            tenant_id: app_config.tenant_id.clone(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::identity::{cache_hit, token_acquired, token_request_failed, Token};
+    use graph_error::AuthExecutionError;
+    use std::sync::Mutex;
+
+    #[test]
+    fn events_are_recorded() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink_events = events.clone();
+        let mut app_config = AppConfig::new(Uuid::new_v4());
+        app_config.with_auth_event_sink(move |event: &AuthEvent| {
+            sink_events.lock().unwrap().push(event.clone());
+        });
+
+        let token = Token::new("Bearer", 3600, "access_token", vec!["User.Read"]);
+        token_acquired(&app_config, &token, false);
+        token_acquired(&app_config, &token, true);
+        cache_hit(&app_config, &token);
+
+        let error =
+            AuthExecutionError::silent_token_auth(http::Response::new(Ok(serde_json::json!({
+                "error": "invalid_grant",
+                "error_codes": [65001],
+                "correlation_id": "correlation-id",
+            }))));
+        token_request_failed(&app_config, error);
+
+        let events = events.lock().unwrap();
+        let kinds: Vec<AuthEventKind> = events.iter().map(|event| event.kind.clone()).collect();
+        assert_eq!(
+            vec![
+                AuthEventKind::TokenAcquired,
+                AuthEventKind::TokenRefreshed,
+                AuthEventKind::CacheHit,
+                AuthEventKind::ConsentRequired {
+                    error: "invalid_grant".into()
+                },
+            ],
+            kinds
+        );
+        assert_eq!(Some("correlation-id"), events[3].correlation_id.as_deref());
+        assert_eq!(app_config.client_id, events[0].client_id);
+    }
+}
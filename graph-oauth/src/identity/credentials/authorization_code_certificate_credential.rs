@@ -9,16 +9,16 @@ use url::Url;
 use uuid::Uuid;
 
 use graph_core::cache::{CacheStore, InMemoryCacheStore, TokenCache};
-use graph_core::http::{AsyncResponseConverterExt, ResponseConverterExt};
 use graph_core::identity::ForceTokenRefresh;
-use graph_error::{AuthExecutionError, AuthExecutionResult, IdentityResult, AF};
+use graph_error::{AuthExecutionError, IdentityResult, AF};
 
 #[cfg(feature = "openssl")]
 use crate::identity::{AuthorizationResponse, X509Certificate};
 
 use crate::identity::{
-    AppConfig, AuthCodeAuthorizationUrlParameterBuilder, Authority, AzureCloudInstance,
-    ConfidentialClientApplication, Token, TokenCredentialExecutor, CLIENT_ASSERTION_TYPE,
+    cache_hit, cache_miss, token_expired, AppConfig, AuthCodeAuthorizationUrlParameterBuilder,
+    Authority, AzureCloudInstance, ConfidentialClientApplication, RefreshTokenCredential,
+    RefreshTokenRotationHook, SecretString, Token, TokenCredentialExecutor, CLIENT_ASSERTION_TYPE,
 };
 use crate::oauth_serializer::{AuthParameter, AuthSerializer};
 
@@ -86,11 +86,11 @@ pub struct AuthorizationCodeCertificateCredential {
     pub(crate) authorization_code: Option<String>,
     /// The refresh token needed to make an access token request using a refresh token.
     /// Do not include an authorization code when using a refresh token.
-    pub(crate) refresh_token: Option<String>,
+    pub(crate) refresh_token: Option<SecretString>,
     /// The same code_verifier that was used to obtain the authorization_code.
     /// Required if PKCE was used in the authorization code grant request. For more information,
     /// see the PKCE RFC https://datatracker.ietf.org/doc/html/rfc7636.
-    pub(crate) code_verifier: Option<String>,
+    pub(crate) code_verifier: Option<SecretString>,
     /// The value must be set to urn:ietf:params:oauth:client-assertion-type:jwt-bearer.
     pub(crate) client_assertion_type: String,
     /// An assertion (a JSON web token) that you need to create and sign with the certificate
@@ -153,46 +153,19 @@ impl AuthorizationCodeCertificateCredential {
     ) -> AuthCodeAuthorizationUrlParameterBuilder {
         AuthCodeAuthorizationUrlParameterBuilder::new(client_id)
     }
+}
 
-    fn execute_cached_token_refresh(&mut self, cache_id: String) -> AuthExecutionResult<Token> {
-        let response = self.execute()?;
-
-        if !response.status().is_success() {
-            return Err(AuthExecutionError::silent_token_auth(
-                response.into_http_response()?,
-            ));
-        }
-
-        let new_token: Token = response.json()?;
-        self.token_cache.store(cache_id, new_token.clone());
-
-        if new_token.refresh_token.is_some() {
-            self.refresh_token = new_token.refresh_token.clone();
-        }
+impl RefreshTokenCredential for AuthorizationCodeCertificateCredential {
+    type TokenCache = InMemoryCacheStore<Token>;
 
-        Ok(new_token)
-    }
-
-    async fn execute_cached_token_refresh_async(
+    fn refresh_token_parts(
         &mut self,
-        cache_id: String,
-    ) -> AuthExecutionResult<Token> {
-        let response = self.execute_async().await?;
-
-        if !response.status().is_success() {
-            return Err(AuthExecutionError::silent_token_auth(
-                response.into_http_response_async().await?,
-            ));
-        }
-
-        let new_token: Token = response.json().await?;
-
-        if new_token.refresh_token.is_some() {
-            self.refresh_token = new_token.refresh_token.clone();
-        }
-
-        self.token_cache.store(cache_id, new_token.clone());
-        Ok(new_token)
+    ) -> (&mut Self::TokenCache, &AppConfig, &mut Option<SecretString>) {
+        (
+            &mut self.token_cache,
+            &self.app_config,
+            &mut self.refresh_token,
+        )
     }
 }
 
@@ -205,25 +178,20 @@ impl TokenCache for AuthorizationCodeCertificateCredential {
 
         match self.app_config.force_token_refresh {
             ForceTokenRefresh::Never => {
-                // Attempt to bypass a read on the token store by using previous
-                // refresh token stored outside of RwLock
-                if self.refresh_token.is_some() {
-                    if let Ok(token) = self.execute_cached_token_refresh(cache_id.clone()) {
-                        return Ok(token);
-                    }
-                }
-
                 if let Some(token) = self.token_cache.get(cache_id.as_str()) {
                     if token.is_expired_sub(time::Duration::minutes(5)) {
+                        token_expired(&self.app_config, &token);
                         if let Some(refresh_token) = token.refresh_token.as_ref() {
-                            self.refresh_token = Some(refresh_token.to_owned());
+                            self.refresh_token = Some(refresh_token.clone());
                         }
 
                         self.execute_cached_token_refresh(cache_id)
                     } else {
+                        cache_hit(&self.app_config, &token);
                         Ok(token)
                     }
                 } else {
+                    cache_miss(&self.app_config);
                     self.execute_cached_token_refresh(cache_id)
                 }
             }
@@ -242,28 +210,20 @@ impl TokenCache for AuthorizationCodeCertificateCredential {
 
         match self.app_config.force_token_refresh {
             ForceTokenRefresh::Never => {
-                // Attempt to bypass a read on the token store by using previous
-                // refresh token stored outside of RwLock
-                if self.refresh_token.is_some() {
-                    if let Ok(token) = self
-                        .execute_cached_token_refresh_async(cache_id.clone())
-                        .await
-                    {
-                        return Ok(token);
-                    }
-                }
-
                 if let Some(old_token) = self.token_cache.get(cache_id.as_str()) {
                     if old_token.is_expired_sub(time::Duration::minutes(5)) {
+                        token_expired(&self.app_config, &old_token);
                         if let Some(refresh_token) = old_token.refresh_token.as_ref() {
-                            self.refresh_token = Some(refresh_token.to_owned());
+                            self.refresh_token = Some(refresh_token.clone());
                         }
 
                         self.execute_cached_token_refresh_async(cache_id).await
                     } else {
+                        cache_hit(&self.app_config, &old_token);
                         Ok(old_token.clone())
                     }
                 } else {
+                    cache_miss(&self.app_config);
                     self.execute_cached_token_refresh_async(cache_id).await
                 }
             }
@@ -303,18 +263,18 @@ impl TokenCredentialExecutor for AuthorizationCodeCertificateCredential {
             .client_id(client_id.as_str())
             .client_assertion(self.client_assertion.as_str())
             .client_assertion_type(self.client_assertion_type.as_str())
-            .set_scope(self.app_config.scope.clone());
+            .set_scope(self.app_config.cloud_scope()?);
 
         if let Some(redirect_uri) = self.app_config.redirect_uri.as_ref() {
             serializer.redirect_uri(redirect_uri.as_str());
         }
 
         if let Some(code_verifier) = self.code_verifier.as_ref() {
-            serializer.code_verifier(code_verifier.as_ref());
+            serializer.code_verifier(code_verifier.expose_secret());
         }
 
         if let Some(refresh_token) = self.refresh_token.as_ref() {
-            if refresh_token.trim().is_empty() {
+            if refresh_token.expose_secret().trim().is_empty() {
                 return AF::msg_result(
                     AuthParameter::RefreshToken.alias(),
                     "refresh_token is empty - cannot be an empty string",
@@ -322,7 +282,7 @@ impl TokenCredentialExecutor for AuthorizationCodeCertificateCredential {
             }
 
             serializer
-                .refresh_token(refresh_token.as_ref())
+                .refresh_token(refresh_token.expose_secret())
                 .grant_type("refresh_token");
 
             return serializer.as_credential_map(
@@ -418,12 +378,13 @@ impl AuthorizationCodeCertificateCredentialBuilder {
     #[cfg(feature = "interactive-auth")]
     #[cfg(feature = "openssl")]
     pub(crate) fn new_with_token(
-        token: Token,
+        mut token: Token,
         x509: &X509Certificate,
         app_config: AppConfig,
     ) -> IdentityResult<AuthorizationCodeCertificateCredentialBuilder> {
         let cache_id = app_config.cache_id.clone();
         let mut token_cache = InMemoryCacheStore::new();
+        token.with_log_policy(app_config.log_policy);
         token_cache.store(cache_id, token);
 
         let mut builder = Self {
@@ -470,7 +431,7 @@ impl AuthorizationCodeCertificateCredentialBuilder {
 
     pub fn with_refresh_token<T: AsRef<str>>(&mut self, refresh_token: T) -> &mut Self {
         self.credential.authorization_code = None;
-        self.credential.refresh_token = Some(refresh_token.as_ref().to_owned());
+        self.credential.refresh_token = Some(SecretString::new(refresh_token.as_ref()));
         self
     }
 
@@ -480,7 +441,7 @@ impl AuthorizationCodeCertificateCredentialBuilder {
     }
 
     pub fn with_code_verifier<T: AsRef<str>>(&mut self, code_verifier: T) -> &mut Self {
-        self.credential.code_verifier = Some(code_verifier.as_ref().to_owned());
+        self.credential.code_verifier = Some(SecretString::new(code_verifier.as_ref()));
         self
     }
 
@@ -515,6 +476,15 @@ impl AuthorizationCodeCertificateCredentialBuilder {
     pub fn credential(self) -> AuthorizationCodeCertificateCredential {
         self.credential
     }
+
+    /// Set a callback that is called when the refresh token is rotated.
+    pub fn with_refresh_token_rotation_hook(
+        &mut self,
+        refresh_token_rotation_hook: RefreshTokenRotationHook,
+    ) -> &mut Self {
+        self.credential.app_config.refresh_token_rotation_hook = Some(refresh_token_rotation_hook);
+        self
+    }
 }
 
 impl From<AuthorizationCodeCertificateCredential>
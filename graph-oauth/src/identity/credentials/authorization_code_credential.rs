@@ -7,16 +7,16 @@ use http::{HeaderMap, HeaderName, HeaderValue};
 use url::Url;
 use uuid::Uuid;
 
-use graph_core::cache::{CacheStore, InMemoryCacheStore, TokenCache};
+use graph_core::cache::{CacheStore, TokenCache};
 use graph_core::crypto::ProofKeyCodeExchange;
-use graph_core::http::{AsyncResponseConverterExt, ResponseConverterExt};
 use graph_core::identity::ForceTokenRefresh;
-use graph_error::{AuthExecutionError, AuthExecutionResult, IdentityResult, AF};
+use graph_error::{AuthExecutionError, IdentityResult, AF};
 
 use crate::identity::credentials::app_config::{AppConfig, AppConfigBuilder};
 use crate::identity::{
-    tracing_targets::CREDENTIAL_EXECUTOR, Authority, AuthorizationResponse, AzureCloudInstance,
-    ConfidentialClientApplication, Token, TokenCredentialExecutor,
+    cache_hit, cache_miss, token_expired, Authority, AuthorizationResponse, AzureCloudInstance,
+    ConfidentialClientApplication, PublicClientApplication, RefreshTokenCredential,
+    RefreshTokenRotationHook, SecretString, Token, TokenCredentialExecutor, TokenStore,
 };
 use crate::oauth_serializer::{AuthParameter, AuthSerializer};
 use crate::AuthCodeAuthorizationUrlParameterBuilder;
@@ -25,6 +25,7 @@ credential_builder!(
     AuthorizationCodeCredentialBuilder,
     ConfidentialClientApplication<AuthorizationCodeCredential>
 );
+token_cache_builder!(AuthorizationCodeCredentialBuilder);
 
 /// The OAuth 2.0 authorization code grant type, or auth code flow, enables a client application
 /// to obtain authorized access to protected resources like web APIs. The auth code flow requires
@@ -42,8 +43,8 @@ pub struct AuthorizationCodeCredential {
     /// Required when requesting a new access token using a refresh token
     /// The refresh token needed to make an access token request using a refresh token.
     /// Do not include an authorization code when using a refresh token.
-    pub(crate) refresh_token: Option<String>,
-    /// Required
+    pub(crate) refresh_token: Option<SecretString>,
+    /// Required unless the code verifier of PKCE is set for a public client
     /// The application secret that you created in the app registration portal for your app.
     /// Don't use the application secret in a native app or single page app because a
     /// client_secret can't be reliably stored on devices or web pages. It's required for web
@@ -52,12 +53,12 @@ pub struct AuthorizationCodeCredential {
     /// is done by the SDK. For more information on URI encoding, see the URI Generic Syntax
     /// specification. The Basic auth pattern of instead providing credentials in the Authorization
     /// header, per RFC 6749 is also supported.
-    pub(crate) client_secret: String,
+    pub(crate) client_secret: SecretString,
     /// The same code_verifier that was used to obtain the authorization_code.
     /// Required if PKCE was used in the authorization code grant request. For more information,
     /// see the PKCE RFC https://datatracker.ietf.org/doc/html/rfc7636.
-    pub(crate) code_verifier: Option<String>,
-    token_cache: InMemoryCacheStore<Token>,
+    pub(crate) code_verifier: Option<SecretString>,
+    token_cache: TokenStore,
 }
 
 impl Debug for AuthorizationCodeCredential {
@@ -81,7 +82,7 @@ impl AuthorizationCodeCredential {
                 .build(),
             authorization_code: Some(authorization_code.as_ref().to_owned()),
             refresh_token: None,
-            client_secret: client_secret.as_ref().to_owned(),
+            client_secret: SecretString::new(client_secret.as_ref()),
             code_verifier: None,
             token_cache: Default::default(),
         })
@@ -101,14 +102,14 @@ impl AuthorizationCodeCredential {
                 .build(),
             authorization_code: Some(authorization_code.as_ref().to_owned()),
             refresh_token: None,
-            client_secret: client_secret.as_ref().to_owned(),
+            client_secret: SecretString::new(client_secret.as_ref()),
             code_verifier: None,
             token_cache: Default::default(),
         })
     }
 
     pub fn with_refresh_token<T: AsRef<str>>(&mut self, refresh_token: T) {
-        self.refresh_token = Some(refresh_token.as_ref().to_owned());
+        self.refresh_token = Some(SecretString::new(refresh_token.as_ref()));
     }
 
     pub fn builder(
@@ -124,45 +125,19 @@ impl AuthorizationCodeCredential {
     ) -> AuthCodeAuthorizationUrlParameterBuilder {
         AuthCodeAuthorizationUrlParameterBuilder::new(client_id)
     }
+}
 
-    fn execute_cached_token_refresh(&mut self, cache_id: String) -> AuthExecutionResult<Token> {
-        let response = self.execute()?;
-
-        if !response.status().is_success() {
-            return Err(AuthExecutionError::silent_token_auth(
-                response.into_http_response()?,
-            ));
-        }
-
-        let new_token: Token = response.json()?;
-        self.token_cache.store(cache_id, new_token.clone());
-
-        if new_token.refresh_token.is_some() {
-            self.refresh_token = new_token.refresh_token.clone();
-        }
-
-        Ok(new_token)
-    }
+impl RefreshTokenCredential for AuthorizationCodeCredential {
+    type TokenCache = TokenStore;
 
-    async fn execute_cached_token_refresh_async(
+    fn refresh_token_parts(
         &mut self,
-        cache_id: String,
-    ) -> AuthExecutionResult<Token> {
-        let response = self.execute_async().await?;
-
-        if !response.status().is_success() {
-            return Err(AuthExecutionError::silent_token_auth(
-                response.into_http_response_async().await?,
-            ));
-        }
-
-        let new_token: Token = response.json().await?;
-        self.token_cache.store(cache_id, new_token.clone());
-
-        if new_token.refresh_token.is_some() {
-            self.refresh_token = new_token.refresh_token.clone();
-        }
-        Ok(new_token)
+    ) -> (&mut Self::TokenCache, &AppConfig, &mut Option<SecretString>) {
+        (
+            &mut self.token_cache,
+            &self.app_config,
+            &mut self.refresh_token,
+        )
     }
 }
 
@@ -176,34 +151,24 @@ impl TokenCache for AuthorizationCodeCredential {
 
         match self.app_config.force_token_refresh {
             ForceTokenRefresh::Never => {
-                // Attempt to bypass a read on the token store by using previous
-                // refresh token stored outside of RwLock
-                if self.refresh_token.is_some() {
-                    tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=Some");
-                    if let Ok(token) = self.execute_cached_token_refresh(cache_id.clone()) {
-                        return Ok(token);
-                    }
-                }
-
                 if let Some(token) = self.token_cache.get(cache_id.as_str()) {
                     if token.is_expired_sub(time::Duration::minutes(5)) {
-                        tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=Some");
+                        token_expired(&self.app_config, &token);
                         if let Some(refresh_token) = token.refresh_token.as_ref() {
-                            self.refresh_token = Some(refresh_token.to_owned());
+                            self.refresh_token = Some(refresh_token.clone());
                         }
 
                         self.execute_cached_token_refresh(cache_id)
                     } else {
-                        tracing::debug!(target: CREDENTIAL_EXECUTOR, "using token from cache");
+                        cache_hit(&self.app_config, &token);
                         Ok(token)
                     }
                 } else {
-                    tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
+                    cache_miss(&self.app_config);
                     self.execute_cached_token_refresh(cache_id)
                 }
             }
             ForceTokenRefresh::Once | ForceTokenRefresh::Always => {
-                tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
                 let token_result = self.execute_cached_token_refresh(cache_id);
                 if self.app_config.force_token_refresh == ForceTokenRefresh::Once {
                     self.app_config.force_token_refresh = ForceTokenRefresh::Never;
@@ -219,31 +184,19 @@ impl TokenCache for AuthorizationCodeCredential {
 
         match self.app_config.force_token_refresh {
             ForceTokenRefresh::Never => {
-                // Attempt to bypass a read on the token store by using previous
-                // refresh token stored outside of RwLock
-                if self.refresh_token.is_some() {
-                    tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=Some");
-                    if let Ok(token) = self
-                        .execute_cached_token_refresh_async(cache_id.clone())
-                        .await
-                    {
-                        return Ok(token);
-                    }
-                }
-
                 if let Some(old_token) = self.token_cache.get(cache_id.as_str()) {
                     if old_token.is_expired_sub(time::Duration::minutes(5)) {
+                        token_expired(&self.app_config, &old_token);
                         if let Some(refresh_token) = old_token.refresh_token.as_ref() {
-                            self.refresh_token = Some(refresh_token.to_owned());
+                            self.refresh_token = Some(refresh_token.clone());
                         }
-                        tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=Some");
                         self.execute_cached_token_refresh_async(cache_id).await
                     } else {
-                        tracing::debug!(target: CREDENTIAL_EXECUTOR, "using token from cache");
+                        cache_hit(&self.app_config, &old_token);
                         Ok(old_token.clone())
                     }
                 } else {
-                    tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
+                    cache_miss(&self.app_config);
                     self.execute_cached_token_refresh_async(cache_id).await
                 }
             }
@@ -278,7 +231,7 @@ impl AuthorizationCodeCredentialBuilder {
                 app_config: AppConfig::new(client_id.as_ref()),
                 authorization_code: Some(authorization_code.as_ref().to_owned()),
                 refresh_token: None,
-                client_secret: client_secret.as_ref().to_owned(),
+                client_secret: SecretString::new(client_secret.as_ref()),
                 code_verifier: None,
                 token_cache: Default::default(),
             },
@@ -287,10 +240,11 @@ impl AuthorizationCodeCredentialBuilder {
 
     pub(crate) fn new_with_token(
         app_config: AppConfig,
-        token: Token,
+        mut token: Token,
     ) -> AuthorizationCodeCredentialBuilder {
         let cache_id = app_config.cache_id.clone();
-        let mut token_cache = InMemoryCacheStore::new();
+        let mut token_cache = TokenStore::default();
+        token.with_log_policy(app_config.log_policy);
         token_cache.store(cache_id, token);
 
         Self {
@@ -298,7 +252,7 @@ impl AuthorizationCodeCredentialBuilder {
                 app_config,
                 authorization_code: None,
                 refresh_token: None,
-                client_secret: String::new(),
+                client_secret: Default::default(),
                 code_verifier: None,
                 token_cache,
             },
@@ -314,7 +268,7 @@ impl AuthorizationCodeCredentialBuilder {
                 app_config,
                 authorization_code: Some(authorization_code.as_ref().to_owned()),
                 refresh_token: None,
-                client_secret: String::new(),
+                client_secret: Default::default(),
                 code_verifier: None,
                 token_cache: Default::default(),
             },
@@ -332,7 +286,7 @@ impl AuthorizationCodeCredentialBuilder {
                 app_config,
                 authorization_code: Some(authorization_code),
                 refresh_token: None,
-                client_secret: secret,
+                client_secret: SecretString::from(secret),
                 code_verifier: None,
                 token_cache: Default::default(),
             },
@@ -346,7 +300,7 @@ impl AuthorizationCodeCredentialBuilder {
     }
 
     pub fn with_refresh_token<T: AsRef<str>>(&mut self, refresh_token: T) -> &mut Self {
-        self.credential.refresh_token = Some(refresh_token.as_ref().to_owned());
+        self.credential.refresh_token = Some(SecretString::new(refresh_token.as_ref()));
         self
     }
 
@@ -357,17 +311,33 @@ impl AuthorizationCodeCredentialBuilder {
     }
 
     pub fn with_client_secret<T: AsRef<str>>(&mut self, client_secret: T) -> &mut Self {
-        self.credential.client_secret = client_secret.as_ref().to_owned();
+        self.credential.client_secret = SecretString::new(client_secret.as_ref());
         self
     }
 
     fn with_code_verifier<T: AsRef<str>>(&mut self, code_verifier: T) -> &mut Self {
-        self.credential.code_verifier = Some(code_verifier.as_ref().to_owned());
+        self.credential.code_verifier = Some(SecretString::new(code_verifier.as_ref()));
         self
     }
 
     pub fn with_pkce(&mut self, proof_key_for_code_exchange: &ProofKeyCodeExchange) -> &mut Self {
-        self.with_code_verifier(proof_key_for_code_exchange.code_verifier.as_str());
+        self.with_code_verifier(proof_key_for_code_exchange.code_verifier.expose_secret());
+        self
+    }
+
+    /// Build a public client application for desktop and mobile apps which can't store
+    /// a client secret. The authorization code is exchanged using only the code verifier
+    /// set with [with_pkce](AuthorizationCodeCredentialBuilder::with_pkce).
+    pub fn build_public_client(&self) -> PublicClientApplication<AuthorizationCodeCredential> {
+        PublicClientApplication::credential(self.credential.clone())
+    }
+
+    /// Set a callback that is called when the refresh token is rotated.
+    pub fn with_refresh_token_rotation_hook(
+        &mut self,
+        refresh_token_rotation_hook: RefreshTokenRotationHook,
+    ) -> &mut Self {
+        self.credential.app_config.refresh_token_rotation_hook = Some(refresh_token_rotation_hook);
         self
     }
 }
@@ -387,27 +357,30 @@ impl TokenCredentialExecutor for AuthorizationCodeCredential {
             return AF::result(AuthParameter::ClientId.alias());
         }
 
-        if self.client_secret.trim().is_empty() {
+        // Public clients such as desktop and mobile apps can't store a client secret
+        // and instead use only the code verifier of PKCE.
+        if self.client_secret.expose_secret().trim().is_empty() && self.code_verifier.is_none() {
             return AF::result(AuthParameter::ClientSecret.alias());
         }
 
         serializer
             .client_id(client_id.as_str())
-            .client_secret(self.client_secret.as_str())
-            .set_scope(self.app_config.scope.clone());
+            .set_scope(self.app_config.cloud_scope()?);
+        if !self.client_secret.expose_secret().trim().is_empty() {
+            serializer.client_secret(self.client_secret.expose_secret());
+        }
 
         let cache_id = self.app_config.cache_id.to_string();
         if let Some(token) = self.token_cache.get(cache_id.as_str()) {
             if let Some(refresh_token) = token.refresh_token.as_ref() {
                 serializer
                     .grant_type("refresh_token")
-                    .refresh_token(refresh_token.as_ref());
+                    .refresh_token(refresh_token.expose_secret());
 
                 return serializer.as_credential_map(
-                    vec![AuthParameter::Scope],
+                    vec![AuthParameter::Scope, AuthParameter::ClientSecret],
                     vec![
                         AuthParameter::ClientId,
-                        AuthParameter::ClientSecret,
                         AuthParameter::RefreshToken,
                         AuthParameter::GrantType,
                     ],
@@ -421,19 +394,18 @@ impl TokenCredentialExecutor for AuthorizationCodeCredential {
 
         if should_attempt_refresh {
             let refresh_token = self.refresh_token.clone().unwrap_or_default();
-            if refresh_token.trim().is_empty() {
+            if refresh_token.expose_secret().trim().is_empty() {
                 return AF::msg_result(AuthParameter::RefreshToken, "Refresh token is empty");
             }
 
             serializer
                 .grant_type("refresh_token")
-                .refresh_token(refresh_token.as_ref());
+                .refresh_token(refresh_token.expose_secret());
 
             return serializer.as_credential_map(
-                vec![AuthParameter::Scope],
+                vec![AuthParameter::Scope, AuthParameter::ClientSecret],
                 vec![
                     AuthParameter::ClientId,
-                    AuthParameter::ClientSecret,
                     AuthParameter::RefreshToken,
                     AuthParameter::GrantType,
                 ],
@@ -455,14 +427,17 @@ impl TokenCredentialExecutor for AuthorizationCodeCredential {
                 .grant_type("authorization_code");
 
             if let Some(code_verifier) = self.code_verifier.as_ref() {
-                serializer.code_verifier(code_verifier.as_str());
+                serializer.code_verifier(code_verifier.expose_secret());
             }
 
             return serializer.as_credential_map(
-                vec![AuthParameter::Scope, AuthParameter::CodeVerifier],
                 vec![
-                    AuthParameter::ClientId,
+                    AuthParameter::Scope,
                     AuthParameter::ClientSecret,
+                    AuthParameter::CodeVerifier,
+                ],
+                vec![
+                    AuthParameter::ClientId,
                     AuthParameter::RedirectUri,
                     AuthParameter::AuthorizationCode,
                     AuthParameter::GrantType,
@@ -493,9 +468,13 @@ impl TokenCredentialExecutor for AuthorizationCodeCredential {
     }
 
     fn basic_auth(&self) -> Option<(String, String)> {
+        if self.client_secret.expose_secret().trim().is_empty() {
+            return None;
+        }
+
         Some((
             self.app_config.client_id.to_string(),
-            self.client_secret.clone(),
+            self.client_secret.expose_secret().to_owned(),
         ))
     }
 
@@ -597,4 +576,27 @@ mod test {
             .with_tenant("tenant_id")
             .build();
     }
+
+    #[test]
+    fn public_client_pkce_serialization() {
+        use graph_core::crypto::GenPkce;
+
+        let pkce = ProofKeyCodeExchange::oneshot().unwrap();
+        let uuid_value = Uuid::new_v4().to_string();
+        let mut credential_builder =
+            AuthorizationCodeCredential::builder("auth_code", uuid_value, "");
+        credential_builder.with_scope(vec!["User.Read"]);
+
+        let mut credential = credential_builder.build_public_client();
+        assert!(credential.form_urlencode().is_err());
+
+        let mut credential = credential_builder.with_pkce(&pkce).build_public_client();
+        let map = credential.form_urlencode().unwrap();
+        assert_eq!(
+            map.get("code_verifier").map(|v| v.as_str()),
+            Some(pkce.code_verifier.expose_secret())
+        );
+        assert!(!map.contains_key("client_secret"));
+        assert!(credential_builder.credential.basic_auth().is_none());
+    }
 }
@@ -0,0 +1,276 @@
+use std::fmt::{Debug, Formatter};
+use std::time::Duration;
+
+use graph_core::cache::TokenCache;
+use graph_core::identity::ForceTokenRefresh;
+use time::OffsetDateTime;
+use tokio::task::JoinHandle;
+
+use crate::identity::{tracing_targets::CREDENTIAL_EXECUTOR, Token};
+
+const DEFAULT_REFRESH_FRACTION: f64 = 0.8;
+const DEFAULT_MAX_JITTER: Duration = Duration::from_secs(60);
+const DEFAULT_RETRY_INTERVAL: Duration = Duration::from_secs(30);
+const MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A random value in the range [0, 1) used for jitter.
+fn random_fraction() -> f64 {
+    (uuid::Uuid::new_v4().as_u64_pair().0 >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Time until a token should be renewed which is the refresh fraction of the lifetime
+/// of the token minus a random jitter of at most max_jitter.
+fn refresh_delay(token: &Token, refresh_fraction: f64, max_jitter: Duration) -> Duration {
+    let now = OffsetDateTime::now_utc();
+    let issued = token.timestamp.unwrap_or(now);
+    let expires_on = token
+        .expires_on
+        .unwrap_or(issued + time::Duration::seconds(token.expires_in));
+    let lifetime = (expires_on - issued).max(time::Duration::ZERO);
+
+    let jitter = max_jitter.mul_f64(random_fraction());
+    let refresh_at = issued + lifetime * refresh_fraction - jitter;
+
+    Duration::try_from(refresh_at - now)
+        .unwrap_or(Duration::ZERO)
+        .max(MIN_REFRESH_INTERVAL)
+}
+
+/// Renews the cached token of a credential on a background task before the token expires
+/// so that requests do not have to wait for a token request.
+///
+/// Clones of a credential share the token cache. Start the refresher with a clone of the
+/// credential or client application that is used for requests and the renewed token is
+/// used by every clone.
+///
+/// Tokens are renewed when they reach a fraction of their lifetime, 80% by default. A random
+/// jitter is subtracted from the renewal time so that many instances of an application
+/// started at the same time do not renew their tokens at the same time.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::{identity::{BackgroundTokenRefresher, ConfidentialClientApplication}, GraphClient};
+///
+/// let confidential_client = ConfidentialClientApplication::builder("client-id")
+///     .with_client_secret("secret")
+///     .with_tenant("tenant-id")
+///     .build();
+///
+/// let mut refresher = BackgroundTokenRefresher::new().with_refresh_fraction(0.75);
+/// refresher.start(confidential_client.clone());
+///
+/// let client = GraphClient::from(&confidential_client);
+/// ```
+pub struct BackgroundTokenRefresher {
+    refresh_fraction: f64,
+    max_jitter: Duration,
+    retry_interval: Duration,
+    task: Option<JoinHandle<()>>,
+}
+
+impl BackgroundTokenRefresher {
+    pub fn new() -> BackgroundTokenRefresher {
+        BackgroundTokenRefresher {
+            refresh_fraction: DEFAULT_REFRESH_FRACTION,
+            max_jitter: DEFAULT_MAX_JITTER,
+            retry_interval: DEFAULT_RETRY_INTERVAL,
+            task: None,
+        }
+    }
+
+    /// The fraction of the lifetime of a token after which the token is renewed.
+    /// The value is clamped to the range 0.1 to 1.0.
+    pub fn with_refresh_fraction(mut self, refresh_fraction: f64) -> Self {
+        self.refresh_fraction = refresh_fraction.clamp(0.1, 1.0);
+        self
+    }
+
+    /// The maximum random time subtracted from the time a token is renewed.
+    pub fn with_max_jitter(mut self, max_jitter: Duration) -> Self {
+        self.max_jitter = max_jitter;
+        self
+    }
+
+    /// The time to wait before trying again when renewing a token fails.
+    pub fn with_retry_interval(mut self, retry_interval: Duration) -> Self {
+        self.retry_interval = retry_interval.max(MIN_REFRESH_INTERVAL);
+        self
+    }
+
+    /// Start renewing tokens on a background task. Does nothing if the refresher
+    /// has already been started.
+    ///
+    /// Must be called from within a tokio runtime.
+    pub fn start<Credential>(&mut self, mut credential: Credential)
+    where
+        Credential: TokenCache<Token = Token> + Send + 'static,
+    {
+        if self.is_running() {
+            return;
+        }
+
+        let refresh_fraction = self.refresh_fraction;
+        let max_jitter = self.max_jitter;
+        let retry_interval = self.retry_interval;
+
+        self.task = Some(tokio::spawn(async move {
+            let mut force_token_refresh = false;
+            loop {
+                if force_token_refresh {
+                    credential.with_force_token_refresh(ForceTokenRefresh::Once);
+                }
+
+                let delay = match credential.get_token_silent_async().await {
+                    Ok(token) => {
+                        force_token_refresh = true;
+                        refresh_delay(&token, refresh_fraction, max_jitter)
+                    }
+                    Err(err) => {
+                        tracing::warn!(target: CREDENTIAL_EXECUTOR, "background token refresh failed: {err}");
+                        retry_interval
+                    }
+                };
+
+                tokio::time::sleep(delay).await;
+            }
+        }));
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.task
+            .as_ref()
+            .map(|task| !task.is_finished())
+            .unwrap_or_default()
+    }
+
+    /// Stop renewing tokens. Tokens that are already cached are not removed.
+    pub fn stop(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
+    }
+}
+
+impl Default for BackgroundTokenRefresher {
+    fn default() -> Self {
+        BackgroundTokenRefresher::new()
+    }
+}
+
+impl Debug for BackgroundTokenRefresher {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BackgroundTokenRefresher")
+            .field("refresh_fraction", &self.refresh_fraction)
+            .field("max_jitter", &self.max_jitter)
+            .field("retry_interval", &self.retry_interval)
+            .field("running", &self.is_running())
+            .finish()
+    }
+}
+
+impl Drop for BackgroundTokenRefresher {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::identity::{AppConfig, ConfidentialClientApplication, TokenCredentialExecutor};
+    use async_trait::async_trait;
+    use graph_core::cache::AsBearer;
+    use graph_error::{AuthExecutionError, IdentityResult};
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    /// Issues a new token with a two second lifetime when forced to refresh.
+    #[derive(Clone, Debug)]
+    struct RefreshingCredential {
+        app_config: AppConfig,
+        tokens: Arc<Mutex<Vec<Token>>>,
+        force_token_refresh: ForceTokenRefresh,
+    }
+
+    #[async_trait]
+    impl TokenCache for RefreshingCredential {
+        type Token = Token;
+
+        fn get_token_silent(&mut self) -> Result<Self::Token, AuthExecutionError> {
+            let mut tokens = self.tokens.lock().unwrap();
+            if tokens.is_empty() || self.force_token_refresh != ForceTokenRefresh::Never {
+                let access_token = format!("access_token_{}", tokens.len() + 1);
+                tokens.push(Token::new(
+                    "Bearer",
+                    2,
+                    access_token.as_str(),
+                    vec!["User.Read"],
+                ));
+                self.force_token_refresh = ForceTokenRefresh::Never;
+            }
+            Ok(tokens.last().cloned().unwrap())
+        }
+
+        async fn get_token_silent_async(&mut self) -> Result<Self::Token, AuthExecutionError> {
+            self.get_token_silent()
+        }
+
+        fn with_force_token_refresh(&mut self, force_token_refresh: ForceTokenRefresh) {
+            self.force_token_refresh = force_token_refresh;
+        }
+    }
+
+    impl TokenCredentialExecutor for RefreshingCredential {
+        fn form_urlencode(&mut self) -> IdentityResult<HashMap<String, String>> {
+            Ok(HashMap::new())
+        }
+
+        fn app_config(&self) -> &AppConfig {
+            &self.app_config
+        }
+    }
+
+    #[test]
+    fn refresh_at_fraction_of_lifetime() {
+        let token = Token::new("Bearer", 3600, "access_token", vec!["User.Read"]);
+        let delay = refresh_delay(&token, 0.8, Duration::ZERO);
+        assert!(delay <= Duration::from_secs(2880));
+        assert!(delay > Duration::from_secs(2870));
+    }
+
+    #[test]
+    fn refresh_delay_includes_jitter() {
+        let token = Token::new("Bearer", 3600, "access_token", vec!["User.Read"]);
+        let delay = refresh_delay(&token, 0.8, Duration::from_secs(60));
+        assert!(delay <= Duration::from_secs(2880));
+        assert!(delay > Duration::from_secs(2810));
+    }
+
+    #[test]
+    fn expired_token_refreshes_after_min_interval() {
+        let delay = refresh_delay(&Token::default(), 0.8, Duration::from_secs(60));
+        assert_eq!(MIN_REFRESH_INTERVAL, delay);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn refreshed_token_is_served() {
+        let mut confidential_client = ConfidentialClientApplication::new(RefreshingCredential {
+            app_config: AppConfig::new(uuid::Uuid::new_v4()),
+            tokens: Default::default(),
+            force_token_refresh: ForceTokenRefresh::Never,
+        });
+
+        let mut refresher = BackgroundTokenRefresher::new()
+            .with_refresh_fraction(0.5)
+            .with_max_jitter(Duration::ZERO);
+        refresher.start(confidential_client.clone());
+        assert!(refresher.is_running());
+
+        tokio::time::sleep(Duration::from_millis(1500)).await;
+        let token = TokenCache::get_token_silent(&mut confidential_client).unwrap();
+        assert_eq!("access_token_2", token.as_bearer());
+
+        refresher.stop();
+        assert!(!refresher.is_running());
+    }
+}
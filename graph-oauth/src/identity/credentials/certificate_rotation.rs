@@ -0,0 +1,162 @@
+use std::fmt::{Debug, Formatter};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use graph_error::IdentityResult;
+
+use crate::identity::X509Certificate;
+
+type CertificateLoader = dyn Fn(&str) -> IdentityResult<X509Certificate> + Send + Sync;
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+/// Source of the certificate of a [ClientCertificateCredential](crate::identity::ClientCertificateCredential)
+/// that is re-read when the certificate is rotated.
+///
+/// The certificate is loaded again when any of the certificate files have been modified
+/// since the certificate was last loaded or when a token request fails with a
+/// 401 Unauthorized response such as when the certificate has expired. This allows
+/// replacing the certificate of a long-running service without a restart.
+///
+/// # Example
+/// ```rust,ignore
+/// let credential = ClientCertificateCredential::builder("CLIENT_ID")
+///     .with_certificate_rotation(CertificateRotation::from_pem_files(
+///         "/etc/secrets/cert.pem",
+///         "/etc/secrets/key.pem",
+///     ))?
+///     .credential();
+/// ```
+#[derive(Clone)]
+pub struct CertificateRotation {
+    loader: Arc<CertificateLoader>,
+    paths: Vec<PathBuf>,
+    modified: Arc<Mutex<Vec<Option<SystemTime>>>>,
+    certificate: Arc<Mutex<Option<X509Certificate>>>,
+}
+
+impl CertificateRotation {
+    /// Load the certificate using a callback that is given the client id.
+    ///
+    /// The callback is only called again when a token request fails with a
+    /// 401 Unauthorized response.
+    pub fn from_fn<F>(loader: F) -> CertificateRotation
+    where
+        F: Fn(&str) -> IdentityResult<X509Certificate> + Send + Sync + 'static,
+    {
+        CertificateRotation {
+            loader: Arc::new(loader),
+            paths: Vec::new(),
+            modified: Default::default(),
+            certificate: Default::default(),
+        }
+    }
+
+    /// Load the certificate and private key from PEM encoded files.
+    pub fn from_pem_files(
+        certificate_path: impl AsRef<Path>,
+        private_key_path: impl AsRef<Path>,
+    ) -> CertificateRotation {
+        let certificate_path = certificate_path.as_ref().to_path_buf();
+        let private_key_path = private_key_path.as_ref().to_path_buf();
+        let paths = vec![certificate_path.clone(), private_key_path.clone()];
+
+        let mut rotation = CertificateRotation::from_fn(move |client_id| {
+            X509Certificate::from_pem_files(client_id, &certificate_path, &private_key_path)
+        });
+        rotation.paths = paths;
+        rotation
+    }
+
+    /// Load the certificate and private key from a PKCS#12 (.pfx or .p12) file.
+    pub fn from_pkcs12_file(
+        path: impl AsRef<Path>,
+        password: impl AsRef<str>,
+    ) -> CertificateRotation {
+        let path = path.as_ref().to_path_buf();
+        let password = password.as_ref().to_owned();
+        let paths = vec![path.clone()];
+
+        let mut rotation = CertificateRotation::from_fn(move |client_id| {
+            X509Certificate::from_pkcs12_file(client_id, &path, password.as_str())
+        });
+        rotation.paths = paths;
+        rotation
+    }
+
+    fn modified_times(&self) -> Vec<Option<SystemTime>> {
+        self.paths.iter().map(|path| modified(path)).collect()
+    }
+
+    fn has_changed(&self) -> bool {
+        *self.modified.lock().unwrap() != self.modified_times()
+    }
+
+    fn load(&self, client_id: &str) -> IdentityResult<()> {
+        let modified = self.modified_times();
+        let certificate = (self.loader)(client_id)?;
+        *self.certificate.lock().unwrap() = Some(certificate);
+        *self.modified.lock().unwrap() = modified;
+        Ok(())
+    }
+
+    /// Sign a new client assertion, loading the certificate first if it has not been
+    /// loaded, the certificate files have changed, or `reload` is true.
+    pub(crate) fn sign(
+        &self,
+        client_id: &str,
+        tenant_id: Option<String>,
+        reload: bool,
+    ) -> IdentityResult<String> {
+        let loaded = self.certificate.lock().unwrap().is_some();
+        if reload || !loaded || self.has_changed() {
+            self.load(client_id)?;
+        }
+
+        let certificate = self.certificate.lock().unwrap();
+        match certificate.as_ref() {
+            Some(certificate) => certificate.sign_with_tenant(tenant_id),
+            None => Err(graph_error::AF::x509("No certificate loaded")),
+        }
+    }
+}
+
+impl Debug for CertificateRotation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CertificateRotation")
+            .field("paths", &self.paths)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use openssl::pkey::PKey;
+    use openssl::x509::X509;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn reload_certificate() {
+        let loads = Arc::new(AtomicUsize::new(0));
+        let counter = loads.clone();
+        let rotation = CertificateRotation::from_fn(move |client_id| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            let cert = X509::from_pem(include_bytes!("test/cert.pem")).unwrap();
+            let private_key = PKey::private_key_from_pem(include_bytes!("test/key.pem")).unwrap();
+            Ok(X509Certificate::new(client_id, cert, private_key))
+        });
+
+        assert!(rotation.sign("client_id", None, false).is_ok());
+        assert!(rotation.sign("client_id", None, false).is_ok());
+        assert_eq!(1, loads.load(Ordering::SeqCst));
+
+        assert!(rotation.sign("client_id", None, true).is_ok());
+        assert_eq!(2, loads.load(Ordering::SeqCst));
+    }
+}
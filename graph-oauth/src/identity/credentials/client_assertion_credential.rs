@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::fmt::{Debug, Formatter};
+use std::path::{Path, PathBuf};
 
 use async_trait::async_trait;
 use http::{HeaderMap, HeaderName, HeaderValue};
@@ -14,8 +15,9 @@ use graph_error::{AuthExecutionError, AuthExecutionResult, IdentityResult, AF};
 
 use crate::identity::credentials::app_config::AppConfig;
 use crate::identity::{
-    tracing_targets::CREDENTIAL_EXECUTOR, Authority, AzureCloudInstance,
-    ConfidentialClientApplication, Token, TokenCredentialExecutor, CLIENT_ASSERTION_TYPE,
+    cache_hit, cache_miss, token_acquired, token_expired, token_request_error, Authority,
+    AzureCloudInstance, AzureRegion, ConfidentialClientApplication, Token, TokenCredentialExecutor,
+    CLIENT_ASSERTION_TYPE,
 };
 
 credential_builder!(
@@ -50,6 +52,9 @@ pub struct ClientAssertionCredential {
     /// workload identity federation to learn how to setup and use assertions generated from
     /// other identity providers.
     pub(crate) client_assertion: String,
+    /// File the client assertion is read from before each token request, such as the
+    /// federated token file of Kubernetes workload identity which is refreshed periodically.
+    pub(crate) client_assertion_file: Option<PathBuf>,
     token_cache: InMemoryCacheStore<Token>,
 }
 
@@ -74,21 +79,53 @@ impl ClientAssertionCredential {
                 .build(),
             client_assertion_type: CLIENT_ASSERTION_TYPE.to_owned(),
             client_assertion: assertion.as_ref().to_string(),
+            client_assertion_file: None,
             token_cache: Default::default(),
         }
     }
 
+    /// Read the client assertion from a file. The file is read again before each
+    /// token request so that an assertion that is replaced by another process, such
+    /// as the federated token file of Kubernetes workload identity, is picked up.
+    pub fn new_with_assertion_file(
+        tenant_id: impl AsRef<str>,
+        client_id: impl AsRef<str>,
+        assertion_file: impl AsRef<Path>,
+    ) -> IdentityResult<ClientAssertionCredential> {
+        let mut credential = ClientAssertionCredential::new(tenant_id, client_id, "");
+        credential.client_assertion_file = Some(assertion_file.as_ref().to_path_buf());
+        credential.read_client_assertion_file()?;
+        Ok(credential)
+    }
+
+    fn read_client_assertion_file(&mut self) -> IdentityResult<()> {
+        if let Some(path) = self.client_assertion_file.as_ref() {
+            let assertion = std::fs::read_to_string(path).map_err(|err| {
+                AF::msg_err(
+                    AuthParameter::ClientAssertion.alias(),
+                    format!("{}: {err}", path.display()).as_str(),
+                )
+            })?;
+            self.client_assertion = assertion.trim().to_owned();
+        }
+        Ok(())
+    }
+
     fn execute_cached_token_refresh(&mut self, cache_id: String) -> AuthExecutionResult<Token> {
+        self.read_client_assertion_file()?;
         let response = self.execute()?;
 
         if !response.status().is_success() {
-            return Err(AuthExecutionError::silent_token_auth(
+            return Err(token_request_error(
+                self.app_config(),
                 response.into_http_response()?,
             ));
         }
 
-        let new_token: Token = response.json()?;
+        let mut new_token: Token = response.json()?;
+        new_token.with_log_policy(self.app_config.log_policy);
         self.token_cache.store(cache_id, new_token.clone());
+        token_acquired(&self.app_config, &new_token, false);
         Ok(new_token)
     }
 
@@ -96,16 +133,20 @@ impl ClientAssertionCredential {
         &mut self,
         cache_id: String,
     ) -> AuthExecutionResult<Token> {
+        self.read_client_assertion_file()?;
         let response = self.execute_async().await?;
 
         if !response.status().is_success() {
-            return Err(AuthExecutionError::silent_token_auth(
+            return Err(token_request_error(
+                self.app_config(),
                 response.into_http_response_async().await?,
             ));
         }
 
-        let new_token: Token = response.json().await?;
+        let mut new_token: Token = response.json().await?;
+        new_token.with_log_policy(self.app_config.log_policy);
         self.token_cache.store(cache_id, new_token.clone());
+        token_acquired(&self.app_config, &new_token, false);
         Ok(new_token)
     }
 }
@@ -119,14 +160,14 @@ impl TokenCache for ClientAssertionCredential {
         let cache_id = self.app_config.cache_id.to_string();
         if let Some(token) = self.token_cache.get(cache_id.as_str()) {
             if token.is_expired_sub(time::Duration::minutes(5)) {
-                tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
+                token_expired(&self.app_config, &token);
                 self.execute_cached_token_refresh(cache_id)
             } else {
-                tracing::debug!(target: CREDENTIAL_EXECUTOR, "using token from cache");
+                cache_hit(&self.app_config, &token);
                 Ok(token)
             }
         } else {
-            tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
+            cache_miss(&self.app_config);
             self.execute_cached_token_refresh(cache_id)
         }
     }
@@ -136,14 +177,14 @@ impl TokenCache for ClientAssertionCredential {
         let cache_id = self.app_config.cache_id.to_string();
         if let Some(token) = self.token_cache.get(cache_id.as_str()) {
             if token.is_expired_sub(time::Duration::minutes(5)) {
-                tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
+                token_expired(&self.app_config, &token);
                 self.execute_cached_token_refresh_async(cache_id).await
             } else {
-                tracing::debug!(target: CREDENTIAL_EXECUTOR, "using token from cache");
+                cache_hit(&self.app_config, &token);
                 Ok(token.clone())
             }
         } else {
-            tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
+            cache_miss(&self.app_config);
             self.execute_cached_token_refresh_async(cache_id).await
         }
     }
@@ -174,7 +215,7 @@ impl TokenCredentialExecutor for ClientAssertionCredential {
             .client_id(client_id.as_str())
             .client_assertion(self.client_assertion.as_str())
             .client_assertion_type(self.client_assertion_type.as_str())
-            .set_scope(self.app_config.scope.clone())
+            .set_scope(self.app_config.cloud_scope()?)
             .grant_type("client_credentials");
 
         serializer.as_credential_map(
@@ -222,6 +263,7 @@ impl ClientAssertionCredentialBuilder {
                     .build(),
                 client_assertion_type: CLIENT_ASSERTION_TYPE.to_string(),
                 client_assertion: signed_assertion.as_ref().to_owned(),
+                client_assertion_file: None,
                 token_cache: Default::default(),
             },
         }
@@ -239,11 +281,23 @@ impl ClientAssertionCredentialBuilder {
                 app_config,
                 client_assertion_type: CLIENT_ASSERTION_TYPE.to_string(),
                 client_assertion: signed_assertion.as_ref().to_owned(),
+                client_assertion_file: None,
                 token_cache: Default::default(),
             },
         }
     }
 
+    /// Request tokens from the regional endpoint (ESTS-R) of an Azure region such as
+    /// `westus2` for lower latency and higher availability. Use `auto` to detect the
+    /// region of the current Azure compute instance. Tokens are requested from the
+    /// global endpoint if the regional endpoint fails.
+    pub fn with_azure_region(&mut self, azure_region: impl Into<AzureRegion>) -> &mut Self {
+        self.credential
+            .app_config
+            .with_azure_region(azure_region.into());
+        self
+    }
+
     pub fn with_client_assertion<T: AsRef<str>>(&mut self, client_assertion: T) -> &mut Self {
         self.credential.client_assertion = client_assertion.as_ref().to_owned();
         self
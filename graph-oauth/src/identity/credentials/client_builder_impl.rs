@@ -30,6 +30,13 @@ macro_rules! credential_builder_base {
                 self
             }
 
+            /// Use a custom authority host instead of the host of the [AzureCloudInstance](crate::identity::AzureCloudInstance)
+            /// such as the host of an on-premises ADFS server.
+            pub fn with_instance(&mut self, instance: url::Url) -> &mut Self {
+                self.credential.app_config.with_instance(instance);
+                self
+            }
+
             /// Extends the query parameters of both the default query params and user defined params.
             /// Does not overwrite default params.
             pub fn with_extra_query_param(&mut self, query_param: (String, String)) -> &mut Self {
@@ -83,6 +90,60 @@ macro_rules! credential_builder_base {
                 self.credential.app_config.with_scope(scope);
                 self
             }
+
+            /// Options that apply to token requests such as whether the authority
+            /// host is validated using instance discovery.
+            pub fn with_token_credential_options(
+                &mut self,
+                token_credential_options: crate::identity::TokenCredentialOptions,
+            ) -> &mut Self {
+                self.credential
+                    .app_config
+                    .with_token_credential_options(token_credential_options);
+                self
+            }
+
+            /// How client ids, tenant ids, and tokens are written to debug output and
+            /// token lifecycle log events. Defaults to [LogPolicy::Redacted](crate::LogPolicy::Redacted).
+            pub fn with_log_policy(&mut self, log_policy: crate::LogPolicy) -> &mut Self {
+                self.credential.app_config.with_log_policy(log_policy);
+                self
+            }
+
+            /// Use the given http client for async token requests. Connections of the client
+            /// are reused by every clone of the credential.
+            ///
+            /// By default a client that only allows https and TLS 1.2 or later is used.
+            pub fn with_http_client(&mut self, http_client: reqwest::Client) -> &mut Self {
+                self.credential.app_config.with_http_client(http_client);
+                self
+            }
+
+            /// Use the given http client for blocking token requests. Connections of the
+            /// client are reused by every clone of the credential.
+            ///
+            /// By default a client that only allows https and TLS 1.2 or later is used.
+            pub fn with_blocking_http_client(
+                &mut self,
+                http_client: reqwest::blocking::Client,
+            ) -> &mut Self {
+                self.credential
+                    .app_config
+                    .with_blocking_http_client(http_client);
+                self
+            }
+
+            /// Send authentication events of the credential, such as token requests
+            /// and cache hits, to the sink.
+            pub fn with_auth_event_sink(
+                &mut self,
+                auth_event_sink: impl crate::identity::AuthEventSink + 'static,
+            ) -> &mut Self {
+                self.credential
+                    .app_config
+                    .with_auth_event_sink(auth_event_sink);
+                self
+            }
         }
     };
 }
@@ -98,3 +159,38 @@ macro_rules! credential_builder {
         }
     };
 }
+
+macro_rules! token_cache_builder {
+    ($name:ident) => {
+        impl $name {
+            /// Store refresh tokens in a file encrypted with AES-256-GCM instead of only
+            /// in memory.
+            pub fn with_encrypted_file_token_cache(
+                &mut self,
+                token_cache: crate::identity::EncryptedFileTokenCache,
+            ) -> &mut Self {
+                let cache_id = self.credential.app_config.cache_id.clone();
+                self.credential
+                    .token_cache
+                    .replace(cache_id.as_str(), token_cache);
+                self
+            }
+
+            /// Store refresh tokens in the credential store of the operating system
+            /// instead of only in memory.
+            ///
+            /// Requires feature `platform-keyring`
+            #[cfg(feature = "platform-keyring")]
+            pub fn with_keyring_token_cache(
+                &mut self,
+                token_cache: crate::identity::KeyringTokenCache,
+            ) -> &mut Self {
+                let cache_id = self.credential.app_config.cache_id.clone();
+                self.credential
+                    .token_cache
+                    .replace(cache_id.as_str(), token_cache);
+                self
+            }
+        }
+    };
+}
@@ -12,13 +12,14 @@ use graph_core::identity::ForceTokenRefresh;
 use graph_error::{AuthExecutionError, AuthExecutionResult, AuthorizationFailure, IdentityResult};
 
 use crate::identity::credentials::app_config::AppConfig;
-#[cfg(feature = "openssl")]
-use crate::identity::X509Certificate;
 use crate::identity::{
-    tracing_targets::CREDENTIAL_EXECUTOR, Authority, AzureCloudInstance,
+    cache_hit, cache_miss, token_acquired, token_expired, token_request_error,
+    tracing_targets::CREDENTIAL_EXECUTOR, Authority, AzureCloudInstance, AzureRegion,
     ClientCredentialsAuthorizationUrlParameterBuilder, ConfidentialClientApplication, Token,
     TokenCredentialExecutor,
 };
+#[cfg(feature = "openssl")]
+use crate::identity::{CertificateRotation, X509Certificate};
 use crate::oauth_serializer::{AuthParameter, AuthSerializer};
 
 pub(crate) static CLIENT_ASSERTION_TYPE: &str =
@@ -60,6 +61,8 @@ pub struct ClientCertificateCredential {
     /// the certificate yourself.
     pub(crate) client_assertion: String,
     token_cache: InMemoryCacheStore<Token>,
+    #[cfg(feature = "openssl")]
+    certificate_rotation: Option<CertificateRotation>,
 }
 
 impl ClientCertificateCredential {
@@ -83,17 +86,56 @@ impl ClientCertificateCredential {
         ClientCredentialsAuthorizationUrlParameterBuilder::new(client_id)
     }
 
+    /// Sign a new client assertion when the certificate is loaded from a
+    /// [CertificateRotation].
+    #[cfg(feature = "openssl")]
+    fn rotate_client_assertion(&mut self, reload: bool) -> IdentityResult<()> {
+        if let Some(certificate_rotation) = self.certificate_rotation.as_ref() {
+            self.client_assertion = certificate_rotation.sign(
+                self.app_config.client_id.to_string().as_str(),
+                self.app_config.authority.tenant_id().cloned(),
+                reload,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Returns true if the token request should be retried using a certificate
+    /// that has been loaded again.
+    #[cfg(feature = "openssl")]
+    fn retry_with_rotated_certificate(&mut self, status: http::StatusCode) -> IdentityResult<bool> {
+        if self.certificate_rotation.is_none() || status != http::StatusCode::UNAUTHORIZED {
+            return Ok(false);
+        }
+
+        tracing::debug!(target: CREDENTIAL_EXECUTOR, "reloading certificate after unauthorized token response");
+        self.rotate_client_assertion(true)?;
+        Ok(true)
+    }
+
     fn execute_cached_token_refresh(&mut self, cache_id: String) -> AuthExecutionResult<Token> {
-        let response = self.execute()?;
+        #[cfg(feature = "openssl")]
+        self.rotate_client_assertion(false)?;
+
+        #[allow(unused_mut)]
+        let mut response = self.execute()?;
+
+        #[cfg(feature = "openssl")]
+        if self.retry_with_rotated_certificate(response.status())? {
+            response = self.execute()?;
+        }
 
         if !response.status().is_success() {
-            return Err(AuthExecutionError::silent_token_auth(
+            return Err(token_request_error(
+                self.app_config(),
                 response.into_http_response()?,
             ));
         }
 
-        let new_token: Token = response.json()?;
+        let mut new_token: Token = response.json()?;
+        new_token.with_log_policy(self.app_config.log_policy);
         self.token_cache.store(cache_id, new_token.clone());
+        token_acquired(&self.app_config, &new_token, false);
         Ok(new_token)
     }
 
@@ -101,16 +143,28 @@ impl ClientCertificateCredential {
         &mut self,
         cache_id: String,
     ) -> AuthExecutionResult<Token> {
-        let response = self.execute_async().await?;
+        #[cfg(feature = "openssl")]
+        self.rotate_client_assertion(false)?;
+
+        #[allow(unused_mut)]
+        let mut response = self.execute_async().await?;
+
+        #[cfg(feature = "openssl")]
+        if self.retry_with_rotated_certificate(response.status())? {
+            response = self.execute_async().await?;
+        }
 
         if !response.status().is_success() {
-            return Err(AuthExecutionError::silent_token_auth(
+            return Err(token_request_error(
+                self.app_config(),
                 response.into_http_response_async().await?,
             ));
         }
 
-        let new_token: Token = response.json().await?;
+        let mut new_token: Token = response.json().await?;
+        new_token.with_log_policy(self.app_config.log_policy);
         self.token_cache.store(cache_id, new_token.clone());
+        token_acquired(&self.app_config, &new_token, false);
         Ok(new_token)
     }
 }
@@ -132,14 +186,14 @@ impl TokenCache for ClientCertificateCredential {
         let cache_id = self.app_config.cache_id.to_string();
         if let Some(token) = self.token_cache.get(cache_id.as_str()) {
             if token.is_expired_sub(time::Duration::minutes(5)) {
-                tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
+                token_expired(&self.app_config, &token);
                 self.execute_cached_token_refresh(cache_id)
             } else {
-                tracing::debug!(target: CREDENTIAL_EXECUTOR, "using token from cache");
+                cache_hit(&self.app_config, &token);
                 Ok(token)
             }
         } else {
-            tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
+            cache_miss(&self.app_config);
             self.execute_cached_token_refresh(cache_id)
         }
     }
@@ -149,14 +203,14 @@ impl TokenCache for ClientCertificateCredential {
         let cache_id = self.app_config.cache_id.to_string();
         if let Some(token) = self.token_cache.get(cache_id.as_str()) {
             if token.is_expired_sub(time::Duration::minutes(5)) {
-                tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token refresh");
+                token_expired(&self.app_config, &token);
                 self.execute_cached_token_refresh_async(cache_id).await
             } else {
-                tracing::debug!(target: CREDENTIAL_EXECUTOR, "using token from cache");
+                cache_hit(&self.app_config, &token);
                 Ok(token.clone())
             }
         } else {
-            tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request");
+            cache_miss(&self.app_config);
             self.execute_cached_token_refresh_async(cache_id).await
         }
     }
@@ -188,7 +242,7 @@ impl TokenCredentialExecutor for ClientCertificateCredential {
             .client_assertion(self.client_assertion.as_str())
             .client_assertion_type(self.client_assertion_type.as_str())
             .grant_type("client_credentials")
-            .set_scope(self.app_config.scope.clone());
+            .set_scope(self.app_config.cloud_scope()?);
 
         serializer.as_credential_map(
             vec![AuthParameter::Scope],
@@ -233,6 +287,8 @@ impl ClientCertificateCredentialBuilder {
                 client_assertion_type: CLIENT_ASSERTION_TYPE.to_owned(),
                 client_assertion: Default::default(),
                 token_cache: Default::default(),
+                #[cfg(feature = "openssl")]
+                certificate_rotation: None,
             },
         }
     }
@@ -251,6 +307,8 @@ impl ClientCertificateCredentialBuilder {
                 client_assertion_type: CLIENT_ASSERTION_TYPE.to_owned(),
                 client_assertion: Default::default(),
                 token_cache: Default::default(),
+                #[cfg(feature = "openssl")]
+                certificate_rotation: None,
             },
         };
         credential_builder.with_certificate(x509)?;
@@ -267,12 +325,35 @@ impl ClientCertificateCredentialBuilder {
         Ok(self)
     }
 
+    /// Load the certificate from a [CertificateRotation] so that the certificate is
+    /// re-read when it is replaced or when a token request is unauthorized.
+    #[cfg(feature = "openssl")]
+    pub fn with_certificate_rotation(
+        &mut self,
+        certificate_rotation: CertificateRotation,
+    ) -> IdentityResult<&mut Self> {
+        self.credential.certificate_rotation = Some(certificate_rotation);
+        self.credential.rotate_client_assertion(true)?;
+        Ok(self)
+    }
+
     #[allow(dead_code)]
     fn with_client_assertion<T: AsRef<str>>(&mut self, client_assertion: T) -> &mut Self {
         self.credential.client_assertion = client_assertion.as_ref().to_owned();
         self
     }
 
+    /// Request tokens from the regional endpoint (ESTS-R) of an Azure region such as
+    /// `westus2` for lower latency and higher availability. Use `auto` to detect the
+    /// region of the current Azure compute instance. Tokens are requested from the
+    /// global endpoint if the regional endpoint fails.
+    pub fn with_azure_region(&mut self, azure_region: impl Into<AzureRegion>) -> &mut Self {
+        self.credential
+            .app_config
+            .with_azure_region(azure_region.into());
+        self
+    }
+
     pub fn credential(self) -> ClientCertificateCredential {
         self.credential
     }
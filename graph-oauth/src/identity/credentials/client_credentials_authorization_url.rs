@@ -3,7 +3,8 @@ use reqwest::IntoUrl;
 use url::Url;
 use uuid::Uuid;
 
-use graph_error::{AuthorizationFailure, IdentityResult};
+use graph_core::crypto::secure_random_32;
+use graph_error::{AuthorizationFailure, IdentityResult, AF};
 
 use crate::identity::{credentials::app_config::AppConfig, Authority, AzureCloudInstance};
 use crate::oauth_serializer::{AuthParameter, AuthSerializer};
@@ -73,6 +74,11 @@ impl ClientCredentialsAuthorizationUrlParameters {
         ClientCertificateCredentialBuilder::new_with_certificate(x509, self.app_config)
     }
 
+    /// Get the state. See [validate_state](crate::identity::validate_state).
+    pub fn state(&self) -> Option<&String> {
+        self.state.as_ref()
+    }
+
     pub fn url(&self) -> IdentityResult<Url> {
         self.url_with_host(&self.app_config.azure_cloud_instance)
     }
@@ -98,7 +104,17 @@ impl ClientCredentialsAuthorizationUrlParameters {
             serializer.state(state.as_ref());
         }
 
-        let mut uri = azure_cloud_instance.admin_consent_uri(&self.app_config.authority)?;
+        if self.app_config.authority.is_adfs() {
+            return AF::msg_result(
+                "authority",
+                "ADFS does not support the admin consent endpoint",
+            );
+        }
+
+        let mut uri = self
+            .app_config
+            .authority_endpoints_with_host(azure_cloud_instance)
+            .admin_consent_uri()?;
         let query = serializer.encode_query(
             vec![AuthParameter::State],
             vec![AuthParameter::ClientId, AuthParameter::RedirectUri],
@@ -158,6 +174,16 @@ impl ClientCredentialsAuthorizationUrlParameterBuilder {
         self
     }
 
+    /// Generates a secure random state. See [validate_state](crate::identity::validate_state).
+    pub fn with_generated_state(&mut self) -> &mut Self {
+        self.credential.state = Some(secure_random_32());
+        self
+    }
+
+    pub fn state(&self) -> Option<&String> {
+        self.credential.state.as_ref()
+    }
+
     pub fn build(&self) -> ClientCredentialsAuthorizationUrlParameters {
         self.credential.clone()
     }
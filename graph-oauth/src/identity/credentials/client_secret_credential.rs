@@ -12,9 +12,10 @@ use graph_core::identity::ForceTokenRefresh;
 use graph_error::{AuthExecutionError, AuthExecutionResult, AuthorizationFailure, IdentityResult};
 
 use crate::identity::{
-    credentials::app_config::AppConfig, tracing_targets::CREDENTIAL_EXECUTOR, Authority,
-    AzureCloudInstance, ClientCredentialsAuthorizationUrlParameterBuilder,
-    ConfidentialClientApplication, Token, TokenCredentialExecutor,
+    cache_hit, cache_miss, credentials::app_config::AppConfig, token_acquired, token_expired,
+    token_request_error, Authority, AzureCloudInstance, AzureRegion,
+    ClientCredentialsAuthorizationUrlParameterBuilder, ConfidentialClientApplication, SecretString,
+    Token, TokenCredentialExecutor,
 };
 use crate::oauth_serializer::{AuthParameter, AuthSerializer};
 
@@ -45,7 +46,7 @@ pub struct ClientSecretCredential {
     /// is done by the SDK. For more information on URI encoding, see the URI Generic Syntax
     /// specification. The Basic auth pattern of instead providing credentials in the Authorization
     /// header, per RFC 6749 is also supported.
-    pub(crate) client_secret: String,
+    pub(crate) client_secret: SecretString,
     token_cache: InMemoryCacheStore<Token>,
 }
 
@@ -66,7 +67,7 @@ impl ClientSecretCredential {
             app_config: AppConfig::builder(client_id.as_ref())
                 .scope(vec!["https://graph.microsoft.com/.default"])
                 .build(),
-            client_secret: client_secret.as_ref().to_owned(),
+            client_secret: SecretString::new(client_secret.as_ref()),
             token_cache: InMemoryCacheStore::new(),
         }
     }
@@ -81,7 +82,7 @@ impl ClientSecretCredential {
                 .tenant(tenant_id.as_ref())
                 .scope(vec!["https://graph.microsoft.com/.default"])
                 .build(),
-            client_secret: client_secret.as_ref().to_owned(),
+            client_secret: SecretString::new(client_secret.as_ref()),
             token_cache: InMemoryCacheStore::new(),
         }
     }
@@ -96,13 +97,16 @@ impl ClientSecretCredential {
         let response = self.execute()?;
 
         if !response.status().is_success() {
-            return Err(AuthExecutionError::silent_token_auth(
+            return Err(token_request_error(
+                self.app_config(),
                 response.into_http_response()?,
             ));
         }
 
-        let new_token: Token = response.json()?;
+        let mut new_token: Token = response.json()?;
+        new_token.with_log_policy(self.app_config.log_policy);
         self.token_cache.store(cache_id, new_token.clone());
+        token_acquired(&self.app_config, &new_token, false);
         Ok(new_token)
     }
 
@@ -113,13 +117,16 @@ impl ClientSecretCredential {
         let response = self.execute_async().await?;
 
         if !response.status().is_success() {
-            return Err(AuthExecutionError::silent_token_auth(
+            return Err(token_request_error(
+                self.app_config(),
                 response.into_http_response_async().await?,
             ));
         }
 
-        let new_token: Token = response.json().await?;
+        let mut new_token: Token = response.json().await?;
+        new_token.with_log_policy(self.app_config.log_policy);
         self.token_cache.store(cache_id, new_token.clone());
+        token_acquired(&self.app_config, &new_token, false);
         Ok(new_token)
     }
 }
@@ -132,14 +139,14 @@ impl TokenCache for ClientSecretCredential {
         let cache_id = self.app_config.cache_id.to_string();
         if let Some(token) = self.token_cache.get(cache_id.as_str()) {
             if token.is_expired_sub(time::Duration::minutes(5)) {
-                tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
+                token_expired(&self.app_config, &token);
                 self.execute_cached_token_refresh(cache_id)
             } else {
-                tracing::debug!(target: CREDENTIAL_EXECUTOR, "using token from cache");
+                cache_hit(&self.app_config, &token);
                 Ok(token)
             }
         } else {
-            tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
+            cache_miss(&self.app_config);
             self.execute_cached_token_refresh(cache_id)
         }
     }
@@ -148,14 +155,14 @@ impl TokenCache for ClientSecretCredential {
         let cache_id = self.app_config.cache_id.to_string();
         if let Some(token) = self.token_cache.get(cache_id.as_str()) {
             if token.is_expired_sub(time::Duration::minutes(5)) {
-                tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
+                token_expired(&self.app_config, &token);
                 self.execute_cached_token_refresh_async(cache_id).await
             } else {
-                tracing::debug!(target: CREDENTIAL_EXECUTOR, "using token from cache");
+                cache_hit(&self.app_config, &token);
                 Ok(token.clone())
             }
         } else {
-            tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
+            cache_miss(&self.app_config);
             self.execute_cached_token_refresh_async(cache_id).await
         }
     }
@@ -174,15 +181,15 @@ impl TokenCredentialExecutor for ClientSecretCredential {
             return AuthorizationFailure::result(AuthParameter::ClientId);
         }
 
-        if self.client_secret.trim().is_empty() {
+        if self.client_secret.expose_secret().trim().is_empty() {
             return AuthorizationFailure::result(AuthParameter::ClientSecret);
         }
 
         serializer
             .client_id(client_id.as_str())
-            .client_secret(self.client_secret.as_str())
+            .client_secret(self.client_secret.expose_secret())
             .grant_type("client_credentials")
-            .set_scope(self.app_config.scope.clone());
+            .set_scope(self.app_config.cloud_scope()?);
 
         // Don't include ClientId and Client Secret in the fields for form url encode because
         // Client Id and Client Secret are already included as basic auth.
@@ -204,7 +211,7 @@ impl TokenCredentialExecutor for ClientSecretCredential {
     fn basic_auth(&self) -> Option<(String, String)> {
         Some((
             self.app_config.client_id.to_string(),
-            self.client_secret.clone(),
+            self.client_secret.expose_secret().to_owned(),
         ))
     }
 
@@ -235,14 +242,25 @@ impl ClientSecretCredentialBuilder {
         Self {
             credential: ClientSecretCredential {
                 app_config,
-                client_secret: client_secret.as_ref().to_string(),
+                client_secret: SecretString::new(client_secret.as_ref()),
                 token_cache: InMemoryCacheStore::new(),
             },
         }
     }
 
     pub fn with_client_secret<T: AsRef<str>>(&mut self, client_secret: T) -> &mut Self {
-        self.credential.client_secret = client_secret.as_ref().to_owned();
+        self.credential.client_secret = SecretString::new(client_secret.as_ref());
+        self
+    }
+
+    /// Request tokens from the regional endpoint (ESTS-R) of an Azure region such as
+    /// `westus2` for lower latency and higher availability. Use `auto` to detect the
+    /// region of the current Azure compute instance. Tokens are requested from the
+    /// global endpoint if the regional endpoint fails.
+    pub fn with_azure_region(&mut self, azure_region: impl Into<AzureRegion>) -> &mut Self {
+        self.credential
+            .app_config
+            .with_azure_region(azure_region.into());
         self
     }
 
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::fmt::Debug;
+use std::sync::Arc;
 
 use async_trait::async_trait;
 
@@ -9,13 +10,14 @@ use uuid::Uuid;
 
 use graph_core::cache::{AsBearer, TokenCache};
 use graph_core::identity::{ClientApplication, ForceTokenRefresh};
-use graph_error::{AuthExecutionResult, IdentityResult};
+use graph_error::{AuthExecutionError, AuthExecutionResult, IdentityResult};
 
 use crate::identity::{
-    AppConfig, Authority, AuthorizationCodeAssertionCredential,
+    token_request_failed, AppConfig, Authority, AuthorizationCodeAssertionCredential,
     AuthorizationCodeCertificateCredential, AuthorizationCodeCredential, AzureCloudInstance,
     ClientAssertionCredential, ClientCertificateCredential, ClientSecretCredential,
     ConfidentialClientApplicationBuilder, OpenIdCredential, TokenCredentialExecutor,
+    TokenRequestLock,
 };
 
 /// Clients capable of maintaining the confidentiality of their credentials
@@ -33,6 +35,9 @@ use crate::identity::{
 #[derive(Clone, Debug)]
 pub struct ConfidentialClientApplication<Credential> {
     credential: Credential,
+    /// Shared by applications with the same client id, tenant, and scopes so that
+    /// concurrent token requests are coalesced into a single request to the token endpoint.
+    token_request_lock: Arc<TokenRequestLock>,
 }
 
 impl ConfidentialClientApplication<()> {
@@ -45,11 +50,17 @@ impl<Credential: Clone + Debug + Send + Sync + TokenCredentialExecutor>
     ConfidentialClientApplication<Credential>
 {
     pub(crate) fn new(credential: Credential) -> ConfidentialClientApplication<Credential> {
-        ConfidentialClientApplication { credential }
+        ConfidentialClientApplication {
+            token_request_lock: TokenRequestLock::for_app_config(credential.app_config()),
+            credential,
+        }
     }
 
     pub(crate) fn credential(credential: Credential) -> ConfidentialClientApplication<Credential> {
-        ConfidentialClientApplication { credential }
+        ConfidentialClientApplication {
+            token_request_lock: TokenRequestLock::for_app_config(credential.app_config()),
+            credential,
+        }
     }
 
     pub fn into_inner(self) -> Credential {
@@ -62,12 +73,24 @@ impl<Credential: Clone + Debug + Send + Sync + TokenCache + TokenCredentialExecu
     ClientApplication for ConfidentialClientApplication<Credential>
 {
     fn get_token_silent(&mut self) -> AuthExecutionResult<String> {
-        let token = self.credential.get_token_silent()?;
+        let token_request_lock = self.token_request_lock.clone();
+        let _guard = token_request_lock.lock();
+        let token = self
+            .credential
+            .get_token_silent()
+            .map_err(|err| token_request_failed(self.credential.app_config(), err))?;
         Ok(token.as_bearer())
     }
 
     async fn get_token_silent_async(&mut self) -> AuthExecutionResult<String> {
-        let token = self.credential.get_token_silent_async().await?;
+        // Tasks waiting on the lock use the token cached by the task holding the lock.
+        let token_request_lock = self.token_request_lock.clone();
+        let _guard = token_request_lock.lock_async().await;
+        let token = self
+            .credential
+            .get_token_silent_async()
+            .await
+            .map_err(|err| token_request_failed(self.credential.app_config(), err))?;
         Ok(token.as_bearer())
     }
 
@@ -75,6 +98,53 @@ impl<Credential: Clone + Debug + Send + Sync + TokenCache + TokenCredentialExecu
         self.credential
             .with_force_token_refresh(force_token_refresh);
     }
+
+    fn scopes(&self) -> Vec<String> {
+        self.credential.app_config().scope.iter().cloned().collect()
+    }
+
+    fn authority_uri(&self) -> Option<String> {
+        Some(
+            self.credential
+                .app_config()
+                .authority_endpoints()
+                .authority_uri(),
+        )
+    }
+
+    /// The Microsoft Graph endpoint of the [AzureCloudInstance](crate::identity::AzureCloudInstance)
+    /// of the application. None when a custom authority host is used that is not
+    /// a login host of a cloud.
+    fn graph_base_url(&self) -> Option<String> {
+        let app_config = self.credential.app_config();
+        app_config
+            .cloud_of_host(&app_config.azure_cloud_instance)
+            .map(|cloud| cloud.graph_resource().to_owned())
+    }
+}
+
+#[async_trait]
+impl<Credential: Clone + Debug + Send + Sync + TokenCache + TokenCredentialExecutor> TokenCache
+    for ConfidentialClientApplication<Credential>
+{
+    type Token = Credential::Token;
+
+    fn get_token_silent(&mut self) -> Result<Self::Token, AuthExecutionError> {
+        let token_request_lock = self.token_request_lock.clone();
+        let _guard = token_request_lock.lock();
+        self.credential.get_token_silent()
+    }
+
+    async fn get_token_silent_async(&mut self) -> Result<Self::Token, AuthExecutionError> {
+        let token_request_lock = self.token_request_lock.clone();
+        let _guard = token_request_lock.lock_async().await;
+        self.credential.get_token_silent_async().await
+    }
+
+    fn with_force_token_refresh(&mut self, force_token_refresh: ForceTokenRefresh) {
+        self.credential
+            .with_force_token_refresh(force_token_refresh);
+    }
 }
 
 #[async_trait]
@@ -170,7 +240,7 @@ impl From<OpenIdCredential> for ConfidentialClientApplication<OpenIdCredential>
 
 #[cfg(test)]
 mod test {
-    use crate::identity::Authority;
+    use crate::identity::{Authority, Token};
 
     use super::*;
 
@@ -235,4 +305,190 @@ mod test {
             credential_uri.as_str()
         );
     }
+
+    #[test]
+    fn confidential_client_sovereign_cloud_scope() {
+        let mut confidential_client =
+            ConfidentialClientApplication::builder(Uuid::new_v4().to_string().as_str())
+                .with_client_secret("ALDSKFJLKERLKJALSDKJF2209LAKJGFL")
+                .with_tenant("tenant")
+                .with_scope(vec!["https://graph.microsoft.com/.default"])
+                .with_azure_cloud_instance(AzureCloudInstance::AzureUsGovernment)
+                .build();
+
+        let form = confidential_client.credential.form_urlencode().unwrap();
+        assert_eq!(
+            Some(&"https://graph.microsoft.us/.default".to_string()),
+            form.get("scope")
+        );
+    }
+
+    #[test]
+    fn confidential_client_dod_cloud_scope_and_graph_base_url() {
+        let mut confidential_client =
+            ConfidentialClientApplication::builder(Uuid::new_v4().to_string().as_str())
+                .with_client_secret("ALDSKFJLKERLKJALSDKJF2209LAKJGFL")
+                .with_tenant("tenant")
+                .with_scope(vec!["https://graph.microsoft.com/.default"])
+                .with_azure_cloud_instance(AzureCloudInstance::AzureUsGovernmentDod)
+                .build();
+
+        assert_eq!(
+            Some("https://dod-graph.microsoft.us".to_string()),
+            confidential_client.graph_base_url()
+        );
+        let form = confidential_client.credential.form_urlencode().unwrap();
+        assert_eq!(
+            Some(&"https://dod-graph.microsoft.us/.default".to_string()),
+            form.get("scope")
+        );
+    }
+
+    #[test]
+    fn confidential_client_china_partner_instance() {
+        let mut confidential_client =
+            ConfidentialClientApplication::builder(Uuid::new_v4().to_string().as_str())
+                .with_client_secret("ALDSKFJLKERLKJALSDKJF2209LAKJGFL")
+                .with_tenant("tenant")
+                .with_scope(vec!["https://graph.microsoft.com/.default"])
+                .with_instance(Url::parse("https://login.partner.microsoftonline.cn").unwrap())
+                .build();
+
+        assert_eq!(
+            Some("https://microsoftgraph.chinacloudapi.cn".to_string()),
+            confidential_client.graph_base_url()
+        );
+        assert_eq!(
+            "https://login.partner.microsoftonline.cn/tenant/oauth2/v2.0/token",
+            confidential_client
+                .credential
+                .app_config()
+                .authority_endpoints()
+                .token_uri()
+                .unwrap()
+                .as_str()
+        );
+        let form = confidential_client.credential.form_urlencode().unwrap();
+        assert_eq!(
+            Some(&"https://microsoftgraph.chinacloudapi.cn/.default".to_string()),
+            form.get("scope")
+        );
+    }
+
+    #[test]
+    fn confidential_client_mismatched_cloud_scope() {
+        let mut confidential_client =
+            ConfidentialClientApplication::builder(Uuid::new_v4().to_string().as_str())
+                .with_client_secret("ALDSKFJLKERLKJALSDKJF2209LAKJGFL")
+                .with_tenant("tenant")
+                .with_scope(vec!["https://microsoftgraph.chinacloudapi.cn/.default"])
+                .build();
+
+        assert!(confidential_client.credential.form_urlencode().is_err());
+    }
+
+    #[test]
+    fn client_application_accessors() {
+        let confidential_client = ConfidentialClientApplication::builder(Uuid::new_v4())
+            .with_client_secret("secret")
+            .with_tenant("tenant-id")
+            .with_scope(vec!["User.Read"])
+            .build();
+
+        fn describe(client_application: &dyn ClientApplication) -> (Vec<String>, Option<String>) {
+            (
+                client_application.scopes(),
+                client_application.authority_uri(),
+            )
+        }
+
+        let (scopes, authority_uri) = describe(&confidential_client);
+        assert_eq!(vec!["User.Read".to_string()], scopes);
+        assert_eq!(
+            Some("https://login.microsoftonline.com/tenant-id".to_string()),
+            authority_uri
+        );
+
+        let mut confidential_client = confidential_client;
+        assert!(ClientApplication::get_token_interactive(&mut confidential_client).is_err());
+    }
+
+    #[derive(Clone, Debug)]
+    struct CountingCredential {
+        app_config: AppConfig,
+        token: Arc<std::sync::Mutex<Option<Token>>>,
+        requests: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    impl CountingCredential {
+        fn new() -> CountingCredential {
+            CountingCredential {
+                app_config: AppConfig::new(Uuid::new_v4()),
+                token: Default::default(),
+                requests: Default::default(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl TokenCache for CountingCredential {
+        type Token = Token;
+
+        fn get_token_silent(&mut self) -> Result<Self::Token, AuthExecutionError> {
+            if let Some(token) = self.token.lock().unwrap().clone() {
+                return Ok(token);
+            }
+
+            std::thread::sleep(std::time::Duration::from_millis(50));
+            self.requests
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            let token = Token::new("Bearer", 3600, "access_token", vec!["User.Read"]);
+            *self.token.lock().unwrap() = Some(token.clone());
+            Ok(token)
+        }
+
+        async fn get_token_silent_async(&mut self) -> Result<Self::Token, AuthExecutionError> {
+            self.get_token_silent()
+        }
+
+        fn with_force_token_refresh(&mut self, _force_token_refresh: ForceTokenRefresh) {}
+    }
+
+    impl TokenCredentialExecutor for CountingCredential {
+        fn form_urlencode(&mut self) -> IdentityResult<HashMap<String, String>> {
+            Ok(HashMap::new())
+        }
+
+        fn app_config(&self) -> &AppConfig {
+            &self.app_config
+        }
+    }
+
+    #[test]
+    fn concurrent_token_requests_are_coalesced() {
+        let credential = CountingCredential::new();
+        let requests = credential.requests.clone();
+        let confidential_client = ConfidentialClientApplication::new(credential);
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let mut confidential_client = confidential_client.clone();
+                std::thread::spawn(move || TokenCache::get_token_silent(&mut confidential_client))
+            })
+            .collect();
+
+        for handle in handles {
+            assert!(handle.join().unwrap().is_ok());
+        }
+        assert_eq!(1, requests.load(std::sync::atomic::Ordering::SeqCst));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn get_token_silent_in_runtime() {
+        let mut confidential_client = ConfidentialClientApplication::new(CountingCredential::new());
+        assert_eq!(
+            "access_token",
+            ClientApplication::get_token_silent(&mut confidential_client).unwrap()
+        );
+    }
 }
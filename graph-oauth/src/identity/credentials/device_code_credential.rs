@@ -5,7 +5,7 @@ use std::ops::Add;
 use std::str::FromStr;
 use std::time::Duration;
 
-use graph_core::cache::{CacheStore, InMemoryCacheStore, TokenCache};
+use graph_core::cache::{CacheStore, TokenCache};
 use graph_core::identity::ForceTokenRefresh;
 use http::{HeaderMap, HeaderName, HeaderValue};
 use tracing::error;
@@ -13,8 +13,10 @@ use url::Url;
 use uuid::Uuid;
 
 use crate::identity::{
-    AppConfig, Authority, AzureCloudInstance, DeviceAuthorizationResponse, PollDeviceCodeEvent,
-    PublicClientApplication, Token, TokenCredentialExecutor,
+    cache_hit, cache_miss, token_expired, AppConfig, Authority, AzureCloudInstance,
+    DeviceAuthorizationResponse, DeviceCodePollEvent, PollDeviceCodeEvent, PublicClientApplication,
+    RefreshTokenCredential, RefreshTokenRotationHook, SecretString, Token, TokenCredentialExecutor,
+    TokenStore,
 };
 use crate::oauth_serializer::{AuthParameter, AuthSerializer};
 use graph_core::http::{
@@ -27,6 +29,7 @@ use graph_error::{
 
 #[cfg(feature = "interactive-auth")]
 use {
+    crate::identity::interactive_prompt_shown,
     crate::interactive::{HostOptions, UserEvents, WebViewAuth, WebViewOptions},
     crate::tracing_targets::INTERACTIVE_AUTH,
     graph_error::WebViewDeviceCodeError,
@@ -40,6 +43,7 @@ credential_builder!(
     DeviceCodeCredentialBuilder,
     PublicClientApplication<DeviceCodeCredential>
 );
+token_cache_builder!(DeviceCodeCredentialBuilder);
 
 /// The device authorization grant: allows users to sign in to input-constrained devices
 /// such as a smart TV, IoT device, or a printer. To enable this flow, the device has the
@@ -54,13 +58,13 @@ pub struct DeviceCodeCredential {
     /// Required when requesting a new access token using a refresh token
     /// The refresh token needed to make an access token request using a refresh token.
     /// Do not include an authorization code when using a refresh token.
-    pub(crate) refresh_token: Option<String>,
+    pub(crate) refresh_token: Option<SecretString>,
     /// Required.
     /// The device_code returned in the device authorization request.
     /// A device_code is a long string used to verify the session between the client and the authorization server.
     /// The client uses this parameter to request the access token from the authorization server.
     pub(crate) device_code: Option<String>,
-    token_cache: InMemoryCacheStore<Token>,
+    pub(crate) token_cache: TokenStore,
 }
 
 impl DeviceCodeCredential {
@@ -78,7 +82,7 @@ impl DeviceCodeCredential {
     }
 
     pub fn with_refresh_token<T: AsRef<str>>(&mut self, refresh_token: T) -> &mut Self {
-        self.refresh_token = Some(refresh_token.as_ref().to_owned());
+        self.refresh_token = Some(SecretString::new(refresh_token.as_ref()));
         self
     }
 
@@ -90,47 +94,6 @@ impl DeviceCodeCredential {
     pub fn builder(client_id: impl AsRef<str>) -> DeviceCodeCredentialBuilder {
         DeviceCodeCredentialBuilder::new(client_id.as_ref())
     }
-
-    fn execute_cached_token_refresh(&mut self, cache_id: String) -> AuthExecutionResult<Token> {
-        let response = self.execute()?;
-
-        if !response.status().is_success() {
-            return Err(AuthExecutionError::silent_token_auth(
-                response.into_http_response()?,
-            ));
-        }
-
-        let new_token: Token = response.json()?;
-        self.token_cache.store(cache_id, new_token.clone());
-
-        if new_token.refresh_token.is_some() {
-            self.refresh_token = new_token.refresh_token.clone();
-        }
-
-        Ok(new_token)
-    }
-
-    async fn execute_cached_token_refresh_async(
-        &mut self,
-        cache_id: String,
-    ) -> AuthExecutionResult<Token> {
-        let response = self.execute_async().await?;
-
-        if !response.status().is_success() {
-            return Err(AuthExecutionError::silent_token_auth(
-                response.into_http_response_async().await?,
-            ));
-        }
-
-        let new_token: Token = response.json().await?;
-
-        if new_token.refresh_token.is_some() {
-            self.refresh_token = new_token.refresh_token.clone();
-        }
-
-        self.token_cache.store(cache_id, new_token.clone());
-        Ok(new_token)
-    }
 }
 
 impl Debug for DeviceCodeCredential {
@@ -141,6 +104,20 @@ impl Debug for DeviceCodeCredential {
     }
 }
 
+impl RefreshTokenCredential for DeviceCodeCredential {
+    type TokenCache = TokenStore;
+
+    fn refresh_token_parts(
+        &mut self,
+    ) -> (&mut Self::TokenCache, &AppConfig, &mut Option<SecretString>) {
+        (
+            &mut self.token_cache,
+            &self.app_config,
+            &mut self.refresh_token,
+        )
+    }
+}
+
 #[async_trait]
 impl TokenCache for DeviceCodeCredential {
     type Token = Token;
@@ -150,25 +127,20 @@ impl TokenCache for DeviceCodeCredential {
 
         match self.app_config.force_token_refresh {
             ForceTokenRefresh::Never => {
-                // Attempt to bypass a read on the token store by using previous
-                // refresh token stored outside of RwLock
-                if self.refresh_token.is_some() {
-                    if let Ok(token) = self.execute_cached_token_refresh(cache_id.clone()) {
-                        return Ok(token);
-                    }
-                }
-
                 if let Some(token) = self.token_cache.get(cache_id.as_str()) {
                     if token.is_expired_sub(time::Duration::minutes(5)) {
+                        token_expired(&self.app_config, &token);
                         if let Some(refresh_token) = token.refresh_token.as_ref() {
-                            self.refresh_token = Some(refresh_token.to_owned());
+                            self.refresh_token = Some(refresh_token.clone());
                         }
 
                         self.execute_cached_token_refresh(cache_id)
                     } else {
+                        cache_hit(&self.app_config, &token);
                         Ok(token)
                     }
                 } else {
+                    cache_miss(&self.app_config);
                     self.execute_cached_token_refresh(cache_id)
                 }
             }
@@ -187,28 +159,20 @@ impl TokenCache for DeviceCodeCredential {
 
         match self.app_config.force_token_refresh {
             ForceTokenRefresh::Never => {
-                // Attempt to bypass a read on the token store by using previous
-                // refresh token stored outside of RwLock
-                if self.refresh_token.is_some() {
-                    if let Ok(token) = self
-                        .execute_cached_token_refresh_async(cache_id.clone())
-                        .await
-                    {
-                        return Ok(token);
-                    }
-                }
-
                 if let Some(old_token) = self.token_cache.get(cache_id.as_str()) {
                     if old_token.is_expired_sub(time::Duration::minutes(5)) {
+                        token_expired(&self.app_config, &old_token);
                         if let Some(refresh_token) = old_token.refresh_token.as_ref() {
-                            self.refresh_token = Some(refresh_token.to_owned());
+                            self.refresh_token = Some(refresh_token.clone());
                         }
 
                         self.execute_cached_token_refresh_async(cache_id).await
                     } else {
+                        cache_hit(&self.app_config, &old_token);
                         Ok(old_token.clone())
                     }
                 } else {
+                    cache_miss(&self.app_config);
                     self.execute_cached_token_refresh_async(cache_id).await
                 }
             }
@@ -230,11 +194,9 @@ impl TokenCache for DeviceCodeCredential {
 impl TokenCredentialExecutor for DeviceCodeCredential {
     fn uri(&mut self) -> IdentityResult<Url> {
         if self.device_code.is_none() && self.refresh_token.is_none() {
-            Ok(self
-                .azure_cloud_instance()
-                .device_code_uri(&self.authority())?)
+            Ok(self.app_config.authority_endpoints().device_code_uri()?)
         } else {
-            Ok(self.azure_cloud_instance().token_uri(&self.authority())?)
+            Ok(self.app_config.authority_endpoints().token_uri()?)
         }
     }
 
@@ -247,10 +209,10 @@ impl TokenCredentialExecutor for DeviceCodeCredential {
 
         serializer
             .client_id(client_id.as_str())
-            .set_scope(self.app_config.scope.clone());
+            .set_scope(self.app_config.cloud_scope()?);
 
         if let Some(refresh_token) = self.refresh_token.as_ref() {
-            if refresh_token.trim().is_empty() {
+            if refresh_token.expose_secret().trim().is_empty() {
                 return AuthorizationFailure::msg_result(
                     AuthParameter::RefreshToken.alias(),
                     "Found empty string for refresh token",
@@ -259,7 +221,7 @@ impl TokenCredentialExecutor for DeviceCodeCredential {
 
             serializer
                 .grant_type("refresh_token")
-                .device_code(refresh_token.as_ref());
+                .device_code(refresh_token.expose_secret());
 
             return serializer.as_credential_map(
                 vec![],
@@ -352,7 +314,16 @@ impl DeviceCodeCredentialBuilder {
 
     pub fn with_refresh_token<T: AsRef<str>>(&mut self, refresh_token: T) -> &mut Self {
         self.credential.device_code = None;
-        self.credential.refresh_token = Some(refresh_token.as_ref().to_owned());
+        self.credential.refresh_token = Some(SecretString::new(refresh_token.as_ref()));
+        self
+    }
+
+    /// Set a callback that is called when the refresh token is rotated.
+    pub fn with_refresh_token_rotation_hook(
+        &mut self,
+        refresh_token_rotation_hook: RefreshTokenRotationHook,
+    ) -> &mut Self {
+        self.credential.app_config.refresh_token_rotation_hook = Some(refresh_token_rotation_hook);
         self
     }
 }
@@ -405,7 +376,7 @@ impl DeviceCodePollingExecutor {
                 // Wait the amount of seconds that interval is.
                 std::thread::sleep(interval);
 
-                let response = credential.execute().unwrap();
+                let response = credential.execute()?;
                 let http_response = response.into_http_response()?;
                 let status = http_response.status();
 
@@ -420,9 +391,9 @@ impl DeviceCodePollingExecutor {
                     if let Some(error) = option_error {
                         match PollDeviceCodeEvent::from_str(error.as_str()) {
                             Ok(poll_device_code_type) => match poll_device_code_type {
-                                PollDeviceCodeEvent::AuthorizationPending
-                                | PollDeviceCodeEvent::BadVerificationCode => continue,
+                                PollDeviceCodeEvent::AuthorizationPending => continue,
                                 PollDeviceCodeEvent::AuthorizationDeclined
+                                | PollDeviceCodeEvent::BadVerificationCode
                                 | PollDeviceCodeEvent::ExpiredToken
                                 | PollDeviceCodeEvent::AccessDenied => break,
                                 PollDeviceCodeEvent::SlowDown => {
@@ -505,7 +476,7 @@ impl DeviceCodePollingExecutor {
                             Ok(poll_device_code_type) => match poll_device_code_type {
                                 PollDeviceCodeEvent::AuthorizationPending => continue,
                                 PollDeviceCodeEvent::AuthorizationDeclined => break,
-                                PollDeviceCodeEvent::BadVerificationCode => continue,
+                                PollDeviceCodeEvent::BadVerificationCode => break,
                                 PollDeviceCodeEvent::ExpiredToken => break,
                                 PollDeviceCodeEvent::AccessDenied => break,
                                 PollDeviceCodeEvent::SlowDown => {
@@ -530,6 +501,146 @@ impl DeviceCodePollingExecutor {
         Ok(receiver)
     }
 
+    /// Poll the token endpoint for the device code and receive a typed [DeviceCodePollEvent]
+    /// for each poll.
+    ///
+    /// The [DeviceAuthorizationResponse], which has the user code and verification uri
+    /// to show the user, is returned before polling starts. Polling stops after the first
+    /// terminal event such as [DeviceCodePollEvent::Success] or [DeviceCodePollEvent::Declined].
+    /// [DeviceCodePollEvent::Expired] is sent if the device code expires while polling.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let (device_authorization_response, receiver) = PublicClientApplication::builder(CLIENT_ID)
+    ///     .with_device_code_executor()
+    ///     .with_scope(vec!["User.Read"])
+    ///     .poll_events()?;
+    ///
+    /// println!("{}", device_authorization_response.message);
+    ///
+    /// while let Ok(event) = receiver.recv() {
+    ///     match event {
+    ///         DeviceCodePollEvent::Success(token) => println!("{token:#?}"),
+    ///         DeviceCodePollEvent::Declined => println!("Sign in was declined"),
+    ///         DeviceCodePollEvent::Expired => println!("The code expired"),
+    ///         _ => {}
+    ///     }
+    /// }
+    /// ```
+    pub fn poll_events(
+        &mut self,
+    ) -> AuthExecutionResult<(
+        DeviceAuthorizationResponse,
+        std::sync::mpsc::Receiver<DeviceCodePollEvent>,
+    )> {
+        let (sender, receiver) = std::sync::mpsc::channel();
+
+        let mut credential = self.credential.clone();
+        let device_authorization_response: DeviceAuthorizationResponse =
+            credential.execute()?.json()?;
+
+        let expires_at = std::time::Instant::now().add(Duration::from_secs(
+            device_authorization_response.expires_in,
+        ));
+        let mut interval = Duration::from_secs(device_authorization_response.interval);
+        credential.with_device_code(device_authorization_response.device_code.clone());
+        let log_policy = credential.app_config.log_policy;
+
+        let _ = std::thread::spawn(move || {
+            loop {
+                std::thread::sleep(interval);
+
+                if std::time::Instant::now() >= expires_at {
+                    sender.send(DeviceCodePollEvent::Expired)?;
+                    break;
+                }
+
+                let event = match credential
+                    .execute()
+                    .and_then(|response| response.into_http_response::<serde_json::Value>())
+                {
+                    Ok(http_response) => DeviceCodePollEvent::from_response(
+                        http_response.status().is_success(),
+                        http_response.json(),
+                        &mut interval,
+                        log_policy,
+                    ),
+                    Err(err) => DeviceCodePollEvent::RequestFailed(err.to_string()),
+                };
+                let is_terminal = event.is_terminal();
+                sender.send(event)?;
+
+                if is_terminal {
+                    break;
+                }
+            }
+            Ok::<(), anyhow::Error>(())
+        });
+
+        Ok((device_authorization_response, receiver))
+    }
+
+    /// Poll the token endpoint for the device code and receive a typed [DeviceCodePollEvent]
+    /// for each poll. See [DeviceCodePollingExecutor::poll_events].
+    pub async fn poll_events_async(
+        &mut self,
+        buffer: Option<usize>,
+    ) -> AuthExecutionResult<(
+        DeviceAuthorizationResponse,
+        tokio::sync::mpsc::Receiver<DeviceCodePollEvent>,
+    )> {
+        let (sender, receiver) = tokio::sync::mpsc::channel(buffer.unwrap_or(100));
+
+        let mut credential = self.credential.clone();
+        let device_authorization_response: DeviceAuthorizationResponse =
+            credential.execute_async().await?.json().await?;
+
+        let expires_at = tokio::time::Instant::now().add(Duration::from_secs(
+            device_authorization_response.expires_in,
+        ));
+        let mut interval = Duration::from_secs(device_authorization_response.interval);
+        credential.with_device_code(device_authorization_response.device_code.clone());
+        let log_policy = credential.app_config.log_policy;
+
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(interval).await;
+
+                if tokio::time::Instant::now() >= expires_at {
+                    sender.send(DeviceCodePollEvent::Expired).await?;
+                    break;
+                }
+
+                let http_response = match credential.execute_async().await {
+                    Ok(response) => {
+                        response
+                            .into_http_response_async::<serde_json::Value>()
+                            .await
+                    }
+                    Err(err) => Err(err),
+                };
+                let event = match http_response {
+                    Ok(http_response) => DeviceCodePollEvent::from_response(
+                        http_response.status().is_success(),
+                        http_response.json(),
+                        &mut interval,
+                        log_policy,
+                    ),
+                    Err(err) => DeviceCodePollEvent::RequestFailed(err.to_string()),
+                };
+                let is_terminal = event.is_terminal();
+                sender.send(event).await?;
+
+                if is_terminal {
+                    break;
+                }
+            }
+            Ok::<(), anyhow::Error>(())
+        });
+
+        Ok((device_authorization_response, receiver))
+    }
+
     #[cfg(feature = "interactive-auth")]
     pub fn with_interactive_auth(
         &mut self,
@@ -615,6 +726,7 @@ impl DeviceCodeInteractiveAuth {
         };
 
         let (sender, _receiver) = std::sync::mpsc::channel();
+        interactive_prompt_shown(&self.credential.app_config);
 
         let options = self.options.clone();
         std::thread::spawn(move || {
@@ -634,14 +746,15 @@ impl DeviceCodeInteractiveAuth {
             // Wait the amount of seconds that interval is.
             std::thread::sleep(interval);
 
-            let response = credential.execute().unwrap();
+            let response = credential.execute().map_err(Box::new)?;
             let http_response = response.into_http_response().map_err(Box::new)?;
             let status = http_response.status();
 
             if status.is_success() {
                 return if let Some(json) = http_response.json() {
-                    let token: Token = serde_json::from_value(json)
+                    let mut token: Token = serde_json::from_value(json)
                         .map_err(|err| Box::new(AuthExecutionError::from(err)))?;
+                    token.with_log_policy(credential.app_config.log_policy);
                     let cache_id = credential.app_config.cache_id.clone();
                     credential.token_cache.store(cache_id, token);
                     Ok(PublicClientApplication::from(credential))
@@ -657,13 +770,13 @@ impl DeviceCodeInteractiveAuth {
                 if let Some(error) = option_error {
                     match PollDeviceCodeEvent::from_str(error.as_str()) {
                         Ok(poll_device_code_type) => match poll_device_code_type {
-                            PollDeviceCodeEvent::AuthorizationPending
-                            | PollDeviceCodeEvent::BadVerificationCode => continue,
+                            PollDeviceCodeEvent::AuthorizationPending => continue,
                             PollDeviceCodeEvent::SlowDown => {
                                 interval = interval.add(Duration::from_secs(5));
                                 continue;
                             }
                             PollDeviceCodeEvent::AuthorizationDeclined
+                            | PollDeviceCodeEvent::BadVerificationCode
                             | PollDeviceCodeEvent::ExpiredToken
                             | PollDeviceCodeEvent::AccessDenied => {
                                 return Err(WebViewDeviceCodeError::DeviceCodePollingError(
@@ -691,6 +804,7 @@ impl DeviceCodeInteractiveAuth {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::identity::EncryptedFileTokenCache;
 
     #[test]
     #[should_panic]
@@ -699,4 +813,38 @@ mod test {
 
         let _ = credential.form_urlencode().unwrap();
     }
+    #[test]
+    fn encrypted_file_token_cache_round_trip() {
+        let path = std::env::temp_dir().join(format!("token_cache_{}.bin", Uuid::new_v4()));
+        let key = crate::identity::TokenCacheKey::generate(1).unwrap();
+        let client_id = Uuid::new_v4().to_string();
+        let mut token = Token::new("Bearer", 3600, "access_token", vec!["User.Read"]);
+        token.with_refresh_token("refresh-token");
+
+        // A token cached before the encrypted cache is set is written to the file.
+        let mut builder = DeviceCodeCredential::builder(client_id.as_str());
+        let cache_id = builder.credential.app_config.cache_id.clone();
+        builder
+            .credential
+            .token_cache
+            .store(cache_id.as_str(), token);
+        builder.with_encrypted_file_token_cache(
+            EncryptedFileTokenCache::new(&path, key.clone()).unwrap(),
+        );
+        assert!(!String::from_utf8_lossy(&std::fs::read(&path).unwrap()).contains("refresh-token"));
+
+        let mut builder = DeviceCodeCredential::builder(client_id.as_str());
+        builder.with_encrypted_file_token_cache(EncryptedFileTokenCache::new(&path, key).unwrap());
+        let token = builder
+            .credential
+            .token_cache
+            .get(cache_id.as_str())
+            .unwrap();
+        assert_eq!(
+            Some(SecretString::from("refresh-token")),
+            token.refresh_token
+        );
+        assert!(token.is_expired());
+        std::fs::remove_file(&path).unwrap();
+    }
 }
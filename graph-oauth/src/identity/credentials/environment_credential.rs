@@ -1,9 +1,13 @@
 use std::env::VarError;
 use std::fmt::{Debug, Formatter};
 
+use graph_error::{IdentityResult, AF};
+
+#[cfg(feature = "openssl")]
+use crate::identity::{CertificateRotation, ClientCertificateCredential};
 use crate::identity::{
-    ClientSecretCredential, ConfidentialClientApplication, PublicClientApplication,
-    ResourceOwnerPasswordCredential,
+    ClientAssertionCredential, ClientSecretCredential, ConfidentialClientApplication,
+    PublicClientApplication, ResourceOwnerPasswordCredential,
 };
 
 const AZURE_TENANT_ID: &str = "AZURE_TENANT_ID";
@@ -11,6 +15,15 @@ const AZURE_CLIENT_ID: &str = "AZURE_CLIENT_ID";
 const AZURE_CLIENT_SECRET: &str = "AZURE_CLIENT_SECRET";
 const AZURE_USERNAME: &str = "AZURE_USERNAME";
 const AZURE_PASSWORD: &str = "AZURE_PASSWORD";
+#[cfg(feature = "openssl")]
+const AZURE_CLIENT_CERTIFICATE_PATH: &str = "AZURE_CLIENT_CERTIFICATE_PATH";
+#[cfg(feature = "openssl")]
+const AZURE_CLIENT_CERTIFICATE_PASSWORD: &str = "AZURE_CLIENT_CERTIFICATE_PASSWORD";
+const AZURE_FEDERATED_TOKEN_FILE: &str = "AZURE_FEDERATED_TOKEN_FILE";
+
+fn required_var(name: &str) -> IdentityResult<String> {
+    std::env::var(name).map_err(|_| AF::required(name))
+}
 
 #[derive(Clone)]
 pub struct EnvironmentCredential;
@@ -38,6 +51,69 @@ impl EnvironmentCredential {
         }
     }
 
+    /// Create a [ClientCertificateCredential] from the `AZURE_CLIENT_ID`,
+    /// `AZURE_CLIENT_CERTIFICATE_PATH`, and optional `AZURE_TENANT_ID` and
+    /// `AZURE_CLIENT_CERTIFICATE_PASSWORD` environment variables.
+    ///
+    /// The certificate path is either a PKCS#12 file or a PEM file that contains both
+    /// the certificate and private key. A PKCS#12 file is expected when the file has a
+    /// `.pfx` or `.p12` extension or when a password is set. The certificate is read
+    /// again when the file changes.
+    #[cfg(feature = "openssl")]
+    pub fn client_certificate_credential(
+    ) -> IdentityResult<ConfidentialClientApplication<ClientCertificateCredential>> {
+        let tenant_id = std::env::var(AZURE_TENANT_ID).ok();
+        let azure_client_id = required_var(AZURE_CLIENT_ID)?;
+        let certificate_path =
+            std::path::PathBuf::from(required_var(AZURE_CLIENT_CERTIFICATE_PATH)?);
+        let certificate_password = std::env::var(AZURE_CLIENT_CERTIFICATE_PASSWORD).ok();
+
+        let is_pkcs12 = certificate_path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(|extension| {
+                extension.eq_ignore_ascii_case("pfx") || extension.eq_ignore_ascii_case("p12")
+            })
+            .unwrap_or_default();
+
+        let certificate_rotation = if is_pkcs12 || certificate_password.is_some() {
+            CertificateRotation::from_pkcs12_file(
+                &certificate_path,
+                certificate_password.unwrap_or_default(),
+            )
+        } else {
+            CertificateRotation::from_pem_files(&certificate_path, &certificate_path)
+        };
+
+        let mut builder = ClientCertificateCredential::builder(azure_client_id);
+        if let Some(tenant_id) = tenant_id {
+            builder.with_tenant(tenant_id);
+        }
+        builder.with_certificate_rotation(certificate_rotation)?;
+        Ok(ConfidentialClientApplication::credential(
+            builder.credential(),
+        ))
+    }
+
+    /// Create a [ClientAssertionCredential] for workload identity federation from the
+    /// `AZURE_TENANT_ID`, `AZURE_CLIENT_ID`, and `AZURE_FEDERATED_TOKEN_FILE` environment
+    /// variables such as those set by Azure Kubernetes Service workload identity.
+    ///
+    /// The federated token file is read again before each token request.
+    pub fn client_assertion_credential(
+    ) -> IdentityResult<ConfidentialClientApplication<ClientAssertionCredential>> {
+        let tenant_id = required_var(AZURE_TENANT_ID)?;
+        let azure_client_id = required_var(AZURE_CLIENT_ID)?;
+        let federated_token_file = required_var(AZURE_FEDERATED_TOKEN_FILE)?;
+        Ok(ConfidentialClientApplication::credential(
+            ClientAssertionCredential::new_with_assertion_file(
+                tenant_id,
+                azure_client_id,
+                federated_token_file,
+            )?,
+        ))
+    }
+
     fn try_azure_client_secret_compile_time_env(
     ) -> Result<ConfidentialClientApplication<ClientSecretCredential>, VarError> {
         let tenant_id = option_env!("AZURE_TENANT_ID");
@@ -128,3 +204,137 @@ impl EnvironmentCredential {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::identity::{Authority, TokenCredentialExecutor};
+    use std::path::PathBuf;
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    // Environment variables are shared by all tests of the process.
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    const CLIENT_ID: &str = "6731de76-14a6-49ae-97bc-6eba6914391e";
+
+    fn temp_file(name: &str, contents: &[u8]) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("{}-{name}", Uuid::new_v4()));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    fn clear_env() {
+        for name in [
+            AZURE_TENANT_ID,
+            AZURE_CLIENT_ID,
+            AZURE_CLIENT_SECRET,
+            AZURE_USERNAME,
+            AZURE_PASSWORD,
+            AZURE_FEDERATED_TOKEN_FILE,
+            "AZURE_CLIENT_CERTIFICATE_PATH",
+            "AZURE_CLIENT_CERTIFICATE_PASSWORD",
+        ] {
+            std::env::remove_var(name);
+        }
+    }
+
+    #[test]
+    fn client_assertion_credential_from_env() {
+        let _lock = ENV_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        clear_env();
+
+        let token_file = temp_file("federated-token", b"federated.token.value\n");
+        std::env::set_var(AZURE_TENANT_ID, "tenant-id");
+        std::env::set_var(AZURE_CLIENT_ID, CLIENT_ID);
+        std::env::set_var(AZURE_FEDERATED_TOKEN_FILE, &token_file);
+
+        let mut app = EnvironmentCredential::client_assertion_credential().unwrap();
+        assert_eq!(CLIENT_ID, app.client_id().to_string());
+        assert_eq!(Authority::TenantId("tenant-id".to_owned()), app.authority());
+        let form = app.form_urlencode().unwrap();
+        assert_eq!(
+            Some("federated.token.value"),
+            form.get("client_assertion").map(|value| value.as_str())
+        );
+
+        std::env::remove_var(AZURE_FEDERATED_TOKEN_FILE);
+        assert!(EnvironmentCredential::client_assertion_credential().is_err());
+
+        std::env::set_var(
+            AZURE_FEDERATED_TOKEN_FILE,
+            token_file.with_extension("missing"),
+        );
+        assert!(EnvironmentCredential::client_assertion_credential().is_err());
+        clear_env();
+    }
+
+    #[cfg(feature = "openssl")]
+    fn self_signed_certificate() -> (
+        openssl::x509::X509,
+        openssl::pkey::PKey<openssl::pkey::Private>,
+    ) {
+        use openssl::asn1::Asn1Time;
+        use openssl::hash::MessageDigest;
+        use openssl::pkey::PKey;
+        use openssl::x509::{X509NameBuilder, X509};
+
+        let private_key = PKey::from_rsa(openssl::rsa::Rsa::generate(2048).unwrap()).unwrap();
+        let mut name = X509NameBuilder::new().unwrap();
+        name.append_entry_by_text("CN", "graph-rs-sdk").unwrap();
+        let name = name.build();
+
+        let mut builder = X509::builder().unwrap();
+        builder.set_version(2).unwrap();
+        builder.set_subject_name(&name).unwrap();
+        builder.set_issuer_name(&name).unwrap();
+        builder.set_pubkey(&private_key).unwrap();
+        builder
+            .set_not_before(&Asn1Time::days_from_now(0).unwrap())
+            .unwrap();
+        builder
+            .set_not_after(&Asn1Time::days_from_now(30).unwrap())
+            .unwrap();
+        builder.sign(&private_key, MessageDigest::sha256()).unwrap();
+        (builder.build(), private_key)
+    }
+
+    #[cfg(feature = "openssl")]
+    #[test]
+    fn client_certificate_credential_from_env() {
+        let _lock = ENV_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        clear_env();
+
+        let (cert, private_key) = self_signed_certificate();
+        let mut pem = cert.to_pem().unwrap();
+        pem.extend(private_key.private_key_to_pem_pkcs8().unwrap());
+        let pem_path = temp_file("cert.pem", &pem);
+
+        std::env::set_var(AZURE_CLIENT_ID, CLIENT_ID);
+        std::env::set_var(AZURE_CLIENT_CERTIFICATE_PATH, &pem_path);
+        let app = EnvironmentCredential::client_certificate_credential().unwrap();
+        assert_eq!(CLIENT_ID, app.client_id().to_string());
+        assert_eq!(Authority::AzureActiveDirectory, app.authority());
+
+        let pkcs12 = openssl::pkcs12::Pkcs12::builder()
+            .name("graph-rs-sdk")
+            .pkey(&private_key)
+            .cert(&cert)
+            .build2("password")
+            .unwrap();
+        let pfx_path = temp_file("cert.pfx", &pkcs12.to_der().unwrap());
+
+        std::env::set_var(AZURE_TENANT_ID, "tenant-id");
+        std::env::set_var(AZURE_CLIENT_CERTIFICATE_PATH, &pfx_path);
+        std::env::set_var(AZURE_CLIENT_CERTIFICATE_PASSWORD, "password");
+        let app = EnvironmentCredential::client_certificate_credential().unwrap();
+        assert_eq!(Authority::TenantId("tenant-id".to_owned()), app.authority());
+
+        std::env::set_var(AZURE_CLIENT_CERTIFICATE_PASSWORD, "wrong");
+        assert!(EnvironmentCredential::client_certificate_credential().is_err());
+
+        std::env::remove_var(AZURE_CLIENT_CERTIFICATE_PATH);
+        assert!(EnvironmentCredential::client_certificate_credential().is_err());
+        clear_env();
+    }
+}
@@ -0,0 +1,324 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use graph_core::cache::{CacheStore, TokenCache};
+use graph_error::{AuthExecutionError, AuthExecutionResult, AF};
+
+#[cfg(feature = "platform-keyring")]
+use crate::identity::KeyringTokenCache;
+use crate::identity::{
+    store_token, tracing_targets::CREDENTIAL_EXECUTOR, AppConfig, Authority,
+    DeviceAuthorizationResponse, DeviceCodeCredential, DeviceCodePollEvent,
+    DeviceCodePollingExecutor, EncryptedFileTokenCache, PublicClientApplication, SecretString,
+    Token, TokenStore,
+};
+
+type DisplayCallback = dyn Fn(&DeviceAuthorizationResponse) + Send + Sync;
+type PersistCallback = dyn Fn(&Token) + Send + Sync;
+
+/// Returns true if the process is most likely running without a desktop session,
+/// such as in an SSH session or on a Linux server without a display, where a browser
+/// or webview cannot be opened for interactive sign in.
+pub fn is_headless_environment() -> bool {
+    let is_ssh = ["SSH_CONNECTION", "SSH_CLIENT", "SSH_TTY"]
+        .iter()
+        .any(|var| std::env::var_os(var).is_some());
+    if is_ssh {
+        return true;
+    }
+
+    if cfg!(target_os = "linux") {
+        return std::env::var_os("DISPLAY").is_none()
+            && std::env::var_os("WAYLAND_DISPLAY").is_none();
+    }
+
+    false
+}
+
+/// Sign in for command line tools and headless servers.
+///
+/// A cached token or refresh token is used first. If there is no token or the refresh
+/// token was rejected, the device code flow is started and the display callback is called
+/// with the [DeviceAuthorizationResponse] so that the user code and verification uri can
+/// be shown to the user, such as by printing the message in an SSH session or showing a
+/// QR code in a GUI. By default the message is only emitted as a `tracing` event at
+/// info level, so set a display callback with [HeadlessAuth::with_device_code_display]
+/// unless the application shows `tracing` output to the user.
+///
+/// The token is passed to the persistence callback after every sign in so that the refresh
+/// token can be stored and given to [HeadlessAuth::with_refresh_token] the next time the
+/// application starts. With feature `platform-keyring` the refresh token can instead be
+/// stored in the credential store of the operating system using
+/// [HeadlessAuth::with_keyring_token_cache].
+///
+/// Include the `offline_access` scope to receive a refresh token.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::{identity::HeadlessAuth, GraphClient};
+///
+/// let public_client = HeadlessAuth::new("client-id", vec!["offline_access", "User.Read"])
+///     .with_tenant("tenant-id")
+///     .with_refresh_token(std::fs::read_to_string("refresh_token").unwrap_or_default())
+///     .with_token_persistence(|token| {
+///         if let Some(refresh_token) = token.refresh_token.as_ref() {
+///             let _ = std::fs::write("refresh_token", refresh_token.expose_secret());
+///         }
+///     })
+///     .authenticate_async()
+///     .await?;
+///
+/// let client = GraphClient::from(&public_client);
+/// ```
+#[derive(Clone)]
+pub struct HeadlessAuth {
+    app_config: AppConfig,
+    refresh_token: Option<SecretString>,
+    token_cache: TokenStore,
+    display: Arc<DisplayCallback>,
+    persist: Option<Arc<PersistCallback>>,
+}
+
+impl HeadlessAuth {
+    pub fn new<T: ToString, I: IntoIterator<Item = T>>(
+        client_id: impl AsRef<str>,
+        scope: I,
+    ) -> HeadlessAuth {
+        HeadlessAuth {
+            app_config: AppConfig::builder(client_id.as_ref()).scope(scope).build(),
+            refresh_token: None,
+            token_cache: Default::default(),
+            display: Arc::new(|response: &DeviceAuthorizationResponse| {
+                tracing::info!(target: CREDENTIAL_EXECUTOR, "{}", response.message);
+            }),
+            persist: None,
+        }
+    }
+
+    pub fn with_tenant(mut self, tenant_id: impl AsRef<str>) -> Self {
+        self.app_config.tenant_id = Some(tenant_id.as_ref().to_owned());
+        self.app_config.authority = Authority::TenantId(tenant_id.as_ref().to_owned());
+        self
+    }
+
+    /// A previously persisted refresh token which is used before falling back to
+    /// the device code flow. An empty refresh token is ignored.
+    pub fn with_refresh_token(mut self, refresh_token: impl AsRef<str>) -> Self {
+        let refresh_token = refresh_token.as_ref().trim();
+        if !refresh_token.is_empty() {
+            self.refresh_token = Some(SecretString::from(refresh_token));
+        }
+        self
+    }
+
+    /// Called with the user code and verification uri when the device code flow starts.
+    /// Replaces the default callback which emits the message as a `tracing` event.
+    pub fn with_device_code_display<F>(mut self, display: F) -> Self
+    where
+        F: Fn(&DeviceAuthorizationResponse) + Send + Sync + 'static,
+    {
+        self.display = Arc::new(display);
+        self
+    }
+
+    /// Called with the token after every sign in.
+    pub fn with_token_persistence<F>(mut self, persist: F) -> Self
+    where
+        F: Fn(&Token) + Send + Sync + 'static,
+    {
+        self.persist = Some(Arc::new(persist));
+        self
+    }
+
+    /// Store refresh tokens in the credential store of the operating system.
+    ///
+    /// Requires feature `platform-keyring`
+    #[cfg(feature = "platform-keyring")]
+    pub fn with_keyring_token_cache(mut self, token_cache: KeyringTokenCache) -> Self {
+        self.token_cache = TokenStore::from(token_cache);
+        self
+    }
+
+    /// Store refresh tokens in a file encrypted with AES-256-GCM.
+    pub fn with_encrypted_file_token_cache(mut self, token_cache: EncryptedFileTokenCache) -> Self {
+        self.token_cache = TokenStore::from(token_cache);
+        self
+    }
+
+    fn credential(&self) -> DeviceCodeCredential {
+        DeviceCodeCredential {
+            app_config: self.app_config.clone(),
+            refresh_token: self.refresh_token.clone(),
+            device_code: None,
+            token_cache: self.token_cache.clone(),
+        }
+    }
+
+    fn has_cached_token(&self) -> bool {
+        self.refresh_token.is_some()
+            || self
+                .token_cache
+                .get(self.app_config.cache_id.as_str())
+                .is_some()
+    }
+
+    fn signed_in(
+        &self,
+        mut credential: DeviceCodeCredential,
+        token: Token,
+        from_device_code: bool,
+    ) -> PublicClientApplication<DeviceCodeCredential> {
+        if from_device_code {
+            store_token(
+                &mut credential.token_cache,
+                &self.app_config,
+                self.app_config.cache_id.clone(),
+                None,
+                &token,
+            );
+        }
+        credential.device_code = None;
+        if token.refresh_token.is_some() {
+            credential.refresh_token = token.refresh_token.clone();
+        }
+
+        if let Some(persist) = self.persist.as_ref() {
+            persist(&token);
+        }
+        PublicClientApplication::credential(credential)
+    }
+
+    fn poll_result(event: DeviceCodePollEvent) -> Option<AuthExecutionResult<Token>> {
+        match event {
+            DeviceCodePollEvent::Success(token) => Some(Ok(*token)),
+            DeviceCodePollEvent::Declined => Some(Err(AF::msg_err(
+                "device_code",
+                "the user declined the sign in",
+            )
+            .into())),
+            DeviceCodePollEvent::Expired => Some(Err(AF::msg_err(
+                "device_code",
+                "the device code expired before the user signed in",
+            )
+            .into())),
+            DeviceCodePollEvent::BadVerificationCode => Some(Err(AF::msg_err(
+                "device_code",
+                "the device code was not recognized by the token endpoint",
+            )
+            .into())),
+            DeviceCodePollEvent::Failed(error) => Some(Err(AF::msg_err(
+                error.error.as_str(),
+                error.error_description.as_deref().unwrap_or_default(),
+            )
+            .into())),
+            DeviceCodePollEvent::RequestFailed(error) => {
+                Some(Err(AF::msg_err("device_code", error.as_str()).into()))
+            }
+            DeviceCodePollEvent::AuthorizationPending | DeviceCodePollEvent::SlowDown { .. } => {
+                None
+            }
+        }
+    }
+
+    fn polling_stopped() -> AuthExecutionError {
+        AF::msg_err("device_code", "device code polling stopped unexpectedly").into()
+    }
+
+    /// Sign in using a cached token or refresh token, falling back to the device
+    /// code flow. The returned client application refreshes the token as needed.
+    pub fn authenticate(
+        &self,
+    ) -> AuthExecutionResult<PublicClientApplication<DeviceCodeCredential>> {
+        let mut credential = self.credential();
+        if self.has_cached_token() {
+            match credential.get_token_silent() {
+                Ok(token) => return Ok(self.signed_in(credential, token, false)),
+                Err(err) => {
+                    tracing::debug!(target: CREDENTIAL_EXECUTOR, "cached sign in failed, starting device code flow: {err}");
+                }
+            }
+        }
+
+        let (response, receiver) =
+            DeviceCodePollingExecutor::new_with_app_config(self.app_config.clone())
+                .poll_events()?;
+        (self.display)(&response);
+
+        while let Ok(event) = receiver.recv() {
+            if let Some(result) = HeadlessAuth::poll_result(event) {
+                return Ok(self.signed_in(credential, result?, true));
+            }
+        }
+        Err(HeadlessAuth::polling_stopped())
+    }
+
+    /// Sign in using a cached token or refresh token, falling back to the device
+    /// code flow. The returned client application refreshes the token as needed.
+    pub async fn authenticate_async(
+        &self,
+    ) -> AuthExecutionResult<PublicClientApplication<DeviceCodeCredential>> {
+        let mut credential = self.credential();
+        if self.has_cached_token() {
+            match credential.get_token_silent_async().await {
+                Ok(token) => return Ok(self.signed_in(credential, token, false)),
+                Err(err) => {
+                    tracing::debug!(target: CREDENTIAL_EXECUTOR, "cached sign in failed, starting device code flow: {err}");
+                }
+            }
+        }
+
+        let (response, mut receiver) =
+            DeviceCodePollingExecutor::new_with_app_config(self.app_config.clone())
+                .poll_events_async(None)
+                .await?;
+        (self.display)(&response);
+
+        while let Some(event) = receiver.recv().await {
+            if let Some(result) = HeadlessAuth::poll_result(event) {
+                return Ok(self.signed_in(credential, result?, true));
+            }
+        }
+        Err(HeadlessAuth::polling_stopped())
+    }
+}
+
+impl Debug for HeadlessAuth {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HeadlessAuth")
+            .field("app_config", &self.app_config)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn cached_token_skips_device_code() {
+        let count = Arc::new(AtomicUsize::new(0));
+        let persist_count = count.clone();
+        let mut headless_auth = HeadlessAuth::new(uuid::Uuid::new_v4().to_string(), ["User.Read"])
+            .with_device_code_display(|_| panic!("device code flow should not start"))
+            .with_token_persistence(move |_| {
+                persist_count.fetch_add(1, Ordering::SeqCst);
+            });
+
+        let mut token = Token::new("Bearer", 3600, "access_token", vec!["User.Read"]);
+        token.with_refresh_token("refresh_token");
+        let cache_id = headless_auth.app_config.cache_id.clone();
+        headless_auth.token_cache.store(cache_id, token);
+
+        let mut public_client = headless_auth.authenticate().unwrap();
+        assert_eq!(1, count.load(Ordering::SeqCst));
+        assert_eq!(
+            "access_token",
+            public_client
+                .get_token_silent()
+                .unwrap()
+                .access_token
+                .expose_secret()
+        );
+    }
+}
@@ -121,7 +121,10 @@ impl ImplicitCredential {
         serializer
             .client_id(client_id.as_str())
             .nonce(self.nonce.as_str())
-            .set_scope(self.app_config.scope.clone());
+            .set_scope(
+                self.app_config
+                    .cloud_scope_with_host(azure_cloud_instance)?,
+            );
 
         let response_types: Vec<String> =
             self.response_type.iter().map(|s| s.to_string()).collect();
@@ -164,11 +167,18 @@ impl ImplicitCredential {
             serializer.state(state.as_str());
         }
 
-        if let Some(prompt) = self.prompt.as_ref() {
+        // ADFS does not support domain_hint and only supports prompt=login and prompt=none.
+        let is_adfs = self.app_config.authority.is_adfs();
+
+        if let Some(prompt) = self
+            .prompt
+            .as_ref()
+            .filter(|prompt| !is_adfs || prompt.is_supported_by_adfs())
+        {
             serializer.prompt(prompt.as_ref());
         }
 
-        if let Some(domain_hint) = self.domain_hint.as_ref() {
+        if let Some(domain_hint) = self.domain_hint.as_ref().filter(|_| !is_adfs) {
             serializer.domain_hint(domain_hint.as_str());
         }
 
@@ -193,7 +203,10 @@ impl ImplicitCredential {
             ],
         )?;
 
-        let mut uri = azure_cloud_instance.auth_uri(&self.app_config.authority)?;
+        let mut uri = self
+            .app_config
+            .authority_endpoints_with_host(azure_cloud_instance)
+            .auth_uri()?;
         uri.set_query(Some(query.as_str()));
         Ok(uri)
     }
@@ -2,10 +2,14 @@ pub use app_config::*;
 pub use application_builder::*;
 pub(crate) use as_query::*;
 pub use auth_code_authorization_url::*;
+pub use auth_event_sink::*;
 pub use authorization_code_assertion_credential::*;
 pub use authorization_code_certificate_credential::*;
 pub use authorization_code_credential::*;
+pub use background_token_refresher::*;
 pub use bearer_token_credential::*;
+#[cfg(feature = "openssl")]
+pub use certificate_rotation::*;
 pub use client_assertion_credential::*;
 
 pub use client_certificate_credential::*;
@@ -14,14 +18,19 @@ pub use client_secret_credential::*;
 pub use confidential_client_application::*;
 pub use device_code_credential::*;
 pub use environment_credential::*;
+pub use headless_auth::*;
+pub use multi_tenant_client_factory::*;
 pub use open_id_authorization_url::*;
 pub use open_id_credential::*;
 pub use prompt::*;
 pub use public_client_application::*;
+pub use refresh_token_rotation::*;
 pub use resource_owner_password_credential::*;
 pub use response_mode::*;
 pub use response_type::*;
 pub use token_credential_executor::*;
+pub(crate) use token_events::*;
+pub(crate) use token_request_lock::*;
 #[cfg(feature = "openssl")]
 pub use x509_certificate::*;
 
@@ -34,10 +43,14 @@ mod app_config;
 mod application_builder;
 mod as_query;
 mod auth_code_authorization_url;
+mod auth_event_sink;
 mod authorization_code_assertion_credential;
 mod authorization_code_certificate_credential;
 mod authorization_code_credential;
+mod background_token_refresher;
 mod bearer_token_credential;
+#[cfg(feature = "openssl")]
+mod certificate_rotation;
 mod client_assertion_credential;
 mod client_certificate_credential;
 mod client_credentials_authorization_url;
@@ -45,14 +58,19 @@ mod client_secret_credential;
 mod confidential_client_application;
 mod device_code_credential;
 mod environment_credential;
+mod headless_auth;
+mod multi_tenant_client_factory;
 mod open_id_authorization_url;
 mod open_id_credential;
 mod prompt;
 mod public_client_application;
+mod refresh_token_rotation;
 mod resource_owner_password_credential;
 mod response_mode;
 mod response_type;
 mod token_credential_executor;
+mod token_events;
+mod token_request_lock;
 
 #[cfg(feature = "openssl")]
 mod x509_certificate;
@@ -60,6 +78,8 @@ mod x509_certificate;
 pub(crate) mod tracing_targets {
     pub const CREDENTIAL_EXECUTOR: &str = "graph_rs_sdk::credential_executor";
 
+    pub const TOKEN_LIFECYCLE: &str = "graph_rs_sdk::token_lifecycle";
+
     #[allow(dead_code)]
     pub const INTERACTIVE_AUTH: &str = "graph_rs_sdk::interactive_auth";
 }
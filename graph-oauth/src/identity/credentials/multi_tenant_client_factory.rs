@@ -0,0 +1,211 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{Debug, Formatter};
+use std::sync::{Arc, Mutex};
+
+use graph_error::IdentityResult;
+
+#[cfg(feature = "openssl")]
+use crate::identity::{ClientCertificateCredential, X509Certificate};
+use crate::identity::{ClientSecretCredential, ConfidentialClientApplication};
+
+const DEFAULT_CAPACITY: usize = 256;
+
+type ClientFactory<Credential> =
+    dyn Fn(&str) -> IdentityResult<ConfidentialClientApplication<Credential>> + Send + Sync;
+
+struct TenantClients<Credential> {
+    clients: HashMap<String, ConfidentialClientApplication<Credential>>,
+    /// Tenant ids from least to most recently used.
+    order: VecDeque<String>,
+}
+
+impl<Credential> TenantClients<Credential> {
+    fn touch(&mut self, tenant_id: &str) {
+        if let Some(position) = self.order.iter().position(|id| id.eq(tenant_id)) {
+            if let Some(id) = self.order.remove(position) {
+                self.order.push_back(id);
+            }
+        }
+    }
+}
+
+/// Creates a [ConfidentialClientApplication] per customer tenant for multi-tenant
+/// applications using the credentials of the application registration.
+///
+/// Each tenant has its own token cache. The clients of the most recently used tenants are
+/// kept and returned for later calls, evicting the least recently used tenant when the
+/// capacity is reached. Clones of the factory share the clients.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::{identity::MultiTenantClientFactory, GraphClient};
+///
+/// let factory = MultiTenantClientFactory::with_client_secret("client-id", "client-secret")
+///     .with_capacity(500);
+///
+/// let confidential_client = factory.for_tenant("customer-tenant-id")?;
+/// let client = GraphClient::from(&confidential_client);
+/// ```
+#[derive(Clone)]
+pub struct MultiTenantClientFactory<Credential> {
+    factory: Arc<ClientFactory<Credential>>,
+    capacity: usize,
+    clients: Arc<Mutex<TenantClients<Credential>>>,
+}
+
+impl MultiTenantClientFactory<ClientSecretCredential> {
+    pub fn with_client_secret(
+        client_id: impl AsRef<str>,
+        client_secret: impl AsRef<str>,
+    ) -> MultiTenantClientFactory<ClientSecretCredential> {
+        let client_id = client_id.as_ref().to_owned();
+        let client_secret = client_secret.as_ref().to_owned();
+        MultiTenantClientFactory::new(move |tenant_id| {
+            Ok(ConfidentialClientApplication::builder(client_id.as_str())
+                .with_tenant(tenant_id)
+                .with_client_secret(client_secret.as_str())
+                .build())
+        })
+    }
+}
+
+#[cfg(feature = "openssl")]
+impl MultiTenantClientFactory<ClientCertificateCredential> {
+    pub fn with_client_x509_certificate(
+        client_id: impl AsRef<str>,
+        certificate: X509Certificate,
+    ) -> MultiTenantClientFactory<ClientCertificateCredential> {
+        let client_id = client_id.as_ref().to_owned();
+        let certificate = Arc::new(certificate);
+        MultiTenantClientFactory::new(move |tenant_id| {
+            Ok(ConfidentialClientApplication::builder(client_id.as_str())
+                .with_tenant(tenant_id)
+                .with_client_x509_certificate(certificate.as_ref())?
+                .build())
+        })
+    }
+}
+
+impl<Credential: Clone> MultiTenantClientFactory<Credential> {
+    /// Create a factory using a function that builds the client for a tenant id.
+    pub fn new<F>(factory: F) -> MultiTenantClientFactory<Credential>
+    where
+        F: Fn(&str) -> IdentityResult<ConfidentialClientApplication<Credential>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        MultiTenantClientFactory {
+            factory: Arc::new(factory),
+            capacity: DEFAULT_CAPACITY,
+            clients: Arc::new(Mutex::new(TenantClients {
+                clients: HashMap::new(),
+                order: VecDeque::new(),
+            })),
+        }
+    }
+
+    /// The maximum number of tenant clients that are kept. Default is 256.
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    /// The client for the tenant. The client, and its cached tokens, is created
+    /// the first time and reused until it is evicted.
+    pub fn for_tenant(
+        &self,
+        tenant_id: impl AsRef<str>,
+    ) -> IdentityResult<ConfidentialClientApplication<Credential>> {
+        let tenant_id = tenant_id.as_ref();
+        let mut tenant_clients = self.clients.lock().unwrap_or_else(|err| err.into_inner());
+
+        if let Some(client) = tenant_clients.clients.get(tenant_id).cloned() {
+            tenant_clients.touch(tenant_id);
+            return Ok(client);
+        }
+
+        let client = (self.factory)(tenant_id)?;
+        while tenant_clients.order.len() >= self.capacity {
+            if let Some(evicted) = tenant_clients.order.pop_front() {
+                tenant_clients.clients.remove(&evicted);
+            }
+        }
+        tenant_clients
+            .clients
+            .insert(tenant_id.to_owned(), client.clone());
+        tenant_clients.order.push_back(tenant_id.to_owned());
+        Ok(client)
+    }
+
+    /// Remove the client and cached tokens of the tenant, such as when a customer
+    /// removes the application from their tenant.
+    pub fn evict(&self, tenant_id: impl AsRef<str>) {
+        let tenant_id = tenant_id.as_ref();
+        let mut tenant_clients = self.clients.lock().unwrap_or_else(|err| err.into_inner());
+        tenant_clients.clients.remove(tenant_id);
+        tenant_clients.order.retain(|id| id.ne(tenant_id));
+    }
+
+    /// The number of tenant clients that are kept.
+    pub fn len(&self) -> usize {
+        self.clients
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .clients
+            .len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<Credential> Debug for MultiTenantClientFactory<Credential> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MultiTenantClientFactory")
+            .field("capacity", &self.capacity)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::identity::TokenCredentialExecutor;
+
+    #[test]
+    fn least_recently_used_tenant_is_evicted() {
+        let factory = MultiTenantClientFactory::with_client_secret(
+            uuid::Uuid::new_v4().to_string(),
+            "secret",
+        )
+        .with_capacity(2);
+
+        let tenant_a = factory.for_tenant("tenant-a").unwrap();
+        assert_eq!(
+            Some("tenant-a"),
+            tenant_a.authority().tenant_id().map(|id| id.as_str())
+        );
+        factory.for_tenant("tenant-b").unwrap();
+        factory.for_tenant("tenant-a").unwrap();
+        factory.for_tenant("tenant-c").unwrap();
+
+        assert_eq!(2, factory.len());
+        let tenant_clients = factory.clients.lock().unwrap();
+        assert!(tenant_clients.clients.contains_key("tenant-a"));
+        assert!(!tenant_clients.clients.contains_key("tenant-b"));
+        assert!(tenant_clients.clients.contains_key("tenant-c"));
+    }
+
+    #[test]
+    fn evict_tenant() {
+        let factory = MultiTenantClientFactory::with_client_secret(
+            uuid::Uuid::new_v4().to_string(),
+            "secret",
+        );
+        factory.for_tenant("tenant-a").unwrap();
+        factory.evict("tenant-a");
+        assert!(factory.is_empty());
+    }
+}
@@ -7,12 +7,13 @@ use url::Url;
 use uuid::Uuid;
 
 use graph_core::crypto::secure_random_32;
-use graph_error::{AuthorizationFailure, IdentityResult, AF};
+use graph_core::identity::JwksKeySet;
+use graph_error::{AuthExecutionResult, AuthorizationFailure, IdentityResult, AF};
 
 use crate::identity::credentials::app_config::AppConfig;
 use crate::identity::{
-    AsQuery, Authority, AuthorizationUrl, AzureCloudInstance, OpenIdCredentialBuilder, Prompt,
-    ResponseMode, ResponseType,
+    AsQuery, Authority, AuthorizationResponse, AuthorizationUrl, AzureCloudInstance, IdToken,
+    OpenIdCredentialBuilder, Prompt, ResponseMode, ResponseType,
 };
 use crate::oauth_serializer::{AuthParameter, AuthSerializer};
 
@@ -20,7 +21,7 @@ use crate::identity::tracing_targets::CREDENTIAL_EXECUTOR;
 
 #[cfg(feature = "interactive-auth")]
 use {
-    crate::identity::AuthorizationResponse,
+    crate::identity::interactive_prompt_shown,
     crate::interactive::{
         HostOptions, InteractiveAuthEvent, UserEvents, WebViewAuth, WebViewAuthorizationEvent,
         WebViewHostValidator, WebViewOptions,
@@ -63,6 +64,8 @@ pub struct OpenIdAuthorizationUrlParameters {
     /// Also supported if requesting only a code.
     /// - form_post: Executes a POST containing the code to your redirect URI.
     /// Supported when requesting a code.
+    ///
+    /// Defaults to form_post when an id token is requested.
     pub(crate) response_mode: Option<ResponseMode>,
     /// Required
     /// A value generated and sent by your app in its request for an ID token. The same nonce
@@ -186,6 +189,11 @@ impl OpenIdAuthorizationUrlParameters {
         self.authorization_url_with_host(azure_cloud_instance)
     }
 
+    /// Get the state. See [validate_state](crate::identity::validate_state).
+    pub fn state(&self) -> Option<&String> {
+        self.state.as_ref()
+    }
+
     /// Get the nonce.
     ///
     /// This value may be generated automatically by the client and may be useful for users
@@ -196,6 +204,104 @@ impl OpenIdAuthorizationUrlParameters {
         &self.nonce
     }
 
+    /// Get the keys used to sign id tokens issued by the authority.
+    pub fn jwks(&self) -> AuthExecutionResult<JwksKeySet> {
+        let jwks_uri = self.app_config.authority_endpoints().jwks_uri()?;
+        Ok(self
+            .app_config
+            .http_client
+            .blocking_http_client()?
+            .get(jwks_uri)
+            .send()?
+            .error_for_status()?
+            .json()?)
+    }
+
+    /// Get the keys used to sign id tokens issued by the authority.
+    pub async fn jwks_async(&self) -> AuthExecutionResult<JwksKeySet> {
+        let jwks_uri = self.app_config.authority_endpoints().jwks_uri()?;
+        Ok(self
+            .app_config
+            .http_client
+            .http_client()?
+            .get(jwks_uri)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?)
+    }
+
+    /// Validate the response of the hybrid flow, response type `code id_token`, and return
+    /// the credential builder that redeems the authorization code for an access token.
+    ///
+    /// The state of the response is compared to the state of the request, the signature of
+    /// the id token is verified using the signing keys of the authority, and the nonce and
+    /// c_hash claims of the id token are validated. The issuer is validated when the authority
+    /// is a tenant id.
+    ///
+    /// The response may be parsed from the fragment of the redirect uri using
+    /// [AuthorizationResponse::from_fragment] or from the body of a form post using
+    /// [AuthorizationResponse::from_form_post].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let jwks = url_parameters.jwks_async().await?;
+    /// let response = AuthorizationResponse::from_form_post(body)?;
+    /// let mut confidential_client = url_parameters
+    ///     .validate_hybrid_response(&response, &jwks)?
+    ///     .with_client_secret("client-secret")
+    ///     .build();
+    /// ```
+    pub fn validate_hybrid_response(
+        &self,
+        response: &AuthorizationResponse,
+        jwks: &JwksKeySet,
+    ) -> IdentityResult<OpenIdCredentialBuilder> {
+        if let Some(error) = response.authorization_error() {
+            let message = error
+                .error_description
+                .or_else(|| error.error.map(|error| error.to_string()))
+                .unwrap_or_default();
+            return AF::msg_result("authorization_response", message);
+        }
+
+        if let Some(state) = self.state.as_ref() {
+            response.validate_state(state)?;
+        }
+
+        let code = response
+            .code
+            .as_ref()
+            .ok_or_else(|| AF::msg_err("code", "authorization response is missing the code"))?;
+        let mut id_token = IdToken::try_from(response.clone())?;
+        id_token.with_log_policy(self.app_config.log_policy);
+
+        let issuer = match &self.app_config.authority {
+            Authority::TenantId(tenant_id) if Uuid::parse_str(tenant_id).is_ok() => Some(
+                self.app_config
+                    .authority_endpoints()
+                    .issuer()?
+                    .as_str()
+                    .trim_end_matches('/')
+                    .to_owned(),
+            ),
+            _ => None,
+        };
+        id_token.validate(
+            jwks,
+            self.app_config.client_id.to_string().as_str(),
+            issuer.as_deref(),
+            self.nonce.as_str(),
+        )?;
+
+        let mut app_config = self.app_config.clone();
+        app_config.with_id_token(id_token);
+        Ok(OpenIdCredentialBuilder::new_with_auth_code(
+            app_config, code,
+        ))
+    }
+
     #[cfg(feature = "interactive-auth")]
     pub fn interactive_webview_authentication(
         &self,
@@ -208,9 +314,23 @@ impl OpenIdAuthorizationUrlParameters {
                 "interactive auth does not support ResponseMode::FormPost at this time",
             ))?;
         }
-        let uri = self.url()?;
+        let mut parameters = self.clone();
+        // The webview reads the response from the redirect uri and not from a form post.
+        if parameters.response_mode.is_none() {
+            parameters.response_mode = Some(ResponseMode::Fragment);
+        }
+        if web_view_options.validate_state && parameters.state.is_none() {
+            parameters.state = Some(secure_random_32());
+        }
+        let expected_state = parameters
+            .state
+            .clone()
+            .filter(|_| web_view_options.validate_state);
+
+        let uri = parameters.url()?;
         let redirect_uri = self.redirect_uri().cloned().unwrap();
         let (sender, receiver) = std::sync::mpsc::channel();
+        interactive_prompt_shown(&parameters.app_config);
 
         std::thread::spawn(move || {
             OpenIdAuthorizationUrlParameters::run(
@@ -235,16 +355,8 @@ impl OpenIdAuthorizationUrlParameters {
                     Err(WebViewError::InvalidUri(reason))
                 }
                 InteractiveAuthEvent::ReachedRedirectUri(uri) => {
-                    let query = uri
-                        .query()
-                        .or(uri.fragment())
-                        .ok_or(WebViewError::InvalidUri(format!(
-                            "uri missing query or fragment: {}",
-                            uri
-                        )))?;
-
-                    let authorization_response: AuthorizationResponse =
-                        serde_urlencoded::from_str(query).map_err(|_| {
+                    let authorization_response = AuthorizationResponse::from_redirect_uri(&uri)
+                        .map_err(|_| {
                             WebViewError::InvalidUri(format!(
                                 "unable to deserialize query or fragment: {}",
                                 uri
@@ -258,6 +370,10 @@ impl OpenIdAuthorizationUrlParameters {
                         ));
                     }
 
+                    if let Some(expected_state) = expected_state.as_ref() {
+                        authorization_response.validate_state(expected_state)?;
+                    }
+
                     tracing::debug!(target: "graph_rs_sdk::interactive_auth", "parsed authorization query or fragment from redirect uri");
 
                     let mut credential_builder = OpenIdCredentialBuilder::from((
@@ -300,12 +416,14 @@ impl AuthorizationUrl for OpenIdAuthorizationUrlParameters {
             return AuthorizationFailure::result("client_id");
         }
 
-        if self.app_config.scope.is_empty() || !self.app_config.scope.contains("openid") {
-            let mut scope = self.app_config.scope.clone();
-            scope.insert("openid".into());
-            serializer.set_scope(scope);
-        } else {
-            serializer.set_scope(self.app_config.scope.clone());
+        let mut scope = self
+            .app_config
+            .cloud_scope_with_host(azure_cloud_instance)?;
+        scope.insert("openid".into());
+        serializer.set_scope(scope);
+
+        if self.nonce.trim().is_empty() {
+            return AF::msg_result("nonce", "openid requires a nonce");
         }
 
         serializer
@@ -335,7 +453,13 @@ impl AuthorizationUrl for OpenIdAuthorizationUrlParameters {
             serializer.response_types(self.response_type.iter());
         }
 
-        if let Some(response_mode) = self.response_mode.as_ref() {
+        // Id tokens are posted to the redirect uri unless another response mode is set.
+        let response_mode = self.response_mode.clone().or_else(|| {
+            self.response_type
+                .contains(&ResponseType::IdToken)
+                .then_some(ResponseMode::FormPost)
+        });
+        if let Some(response_mode) = response_mode.as_ref() {
             if response_mode.eq(&ResponseMode::Query) {
                 return Err(AF::msg_err(
                     "response_mode",
@@ -354,11 +478,20 @@ impl AuthorizationUrl for OpenIdAuthorizationUrlParameters {
             serializer.state(state.as_str());
         }
 
-        if !self.prompt.is_empty() {
-            serializer.prompt(&self.prompt.as_query());
+        // ADFS does not support domain_hint and only supports prompt=login and prompt=none.
+        let is_adfs = self.app_config.authority.is_adfs();
+
+        let prompt: BTreeSet<Prompt> = self
+            .prompt
+            .iter()
+            .filter(|prompt| !is_adfs || prompt.is_supported_by_adfs())
+            .cloned()
+            .collect();
+        if !prompt.is_empty() {
+            serializer.prompt(&prompt.as_query());
         }
 
-        if let Some(domain_hint) = self.domain_hint.as_ref() {
+        if let Some(domain_hint) = self.domain_hint.as_ref().filter(|_| !is_adfs) {
             serializer.domain_hint(domain_hint.as_str());
         }
 
@@ -383,8 +516,17 @@ impl AuthorizationUrl for OpenIdAuthorizationUrlParameters {
             ],
         )?;
 
-        let mut uri = azure_cloud_instance.auth_uri(&self.app_config.authority)?;
+        let mut uri = self
+            .app_config
+            .authority_endpoints_with_host(azure_cloud_instance)
+            .auth_uri()?;
         uri.set_query(Some(query.as_str()));
+
+        // The user flow of an Azure AD B2C authority is sent in the p query parameter
+        // as well as in the path.
+        if let Some(policy) = self.app_config.authority.policy() {
+            uri.query_pairs_mut().append_pair("p", policy);
+        }
         Ok(uri)
     }
 }
@@ -469,6 +611,32 @@ impl OpenIdAuthorizationUrlParameterBuilder {
         self
     }
 
+    /// Use a custom authority host instead of the host of the [AzureCloudInstance]
+    /// such as the host of an on-premises ADFS server.
+    pub fn with_instance(&mut self, instance: Url) -> &mut Self {
+        self.credential.app_config.with_instance(instance);
+        self
+    }
+
+    /// Use the given http client to get the signing keys of the authority and for the
+    /// async token requests of the credential.
+    pub fn with_http_client(&mut self, http_client: reqwest::Client) -> &mut Self {
+        self.credential.app_config.with_http_client(http_client);
+        self
+    }
+
+    /// Use the given http client to get the signing keys of the authority and for the
+    /// blocking token requests of the credential.
+    pub fn with_blocking_http_client(
+        &mut self,
+        http_client: reqwest::blocking::Client,
+    ) -> &mut Self {
+        self.credential
+            .app_config
+            .with_blocking_http_client(http_client);
+        self
+    }
+
     /// Default is code.
     /// Must include code for the open id connect flow.
     /// Can also include id_token or token if using the hybrid flow.
@@ -494,7 +662,7 @@ impl OpenIdAuthorizationUrlParameterBuilder {
     /// - **fragment**: Default when requesting an ID token by using the implicit flow.
     ///     Also supported if requesting only a code.
     /// - **form_post**: Executes a POST containing the code to your redirect URI.
-    ///     Supported when requesting a code.
+    ///     Supported when requesting a code. Default when an id token is requested.
     pub fn with_response_mode(&mut self, response_mode: ResponseMode) -> &mut Self {
         self.credential.response_mode = Some(response_mode);
         self
@@ -513,11 +681,36 @@ impl OpenIdAuthorizationUrlParameterBuilder {
         self
     }
 
+    /// Use the hybrid flow which returns an authorization code and id token in a
+    /// single response posted to the redirect uri. Sets the response type to
+    /// `code id_token`, the response mode to form_post, and generates a state.
+    ///
+    /// Validate the response using
+    /// [validate_hybrid_response](OpenIdAuthorizationUrlParameters::validate_hybrid_response).
+    pub fn with_hybrid_flow(&mut self) -> &mut Self {
+        self.credential.response_type = BTreeSet::from([ResponseType::Code, ResponseType::IdToken]);
+        self.credential.response_mode = Some(ResponseMode::FormPost);
+        if self.credential.state.is_none() {
+            self.credential.state = Some(secure_random_32());
+        }
+        self
+    }
+
     pub fn with_state<T: AsRef<str>>(&mut self, state: T) -> &mut Self {
         self.credential.state = Some(state.as_ref().to_owned());
         self
     }
 
+    /// Generates a secure random state. See [validate_state](crate::identity::validate_state).
+    pub fn with_generated_state(&mut self) -> &mut Self {
+        self.credential.state = Some(secure_random_32());
+        self
+    }
+
+    pub fn state(&self) -> Option<&String> {
+        self.credential.state.as_ref()
+    }
+
     /// Takes an iterator of scopes to use in the request.
     /// Replaces current scopes if any were added previously.
     pub fn with_scope<T: ToString, I: IntoIterator<Item = T>>(&mut self, scope: I) -> &mut Self {
@@ -596,23 +789,107 @@ mod test {
     use crate::identity::TokenCredentialExecutor;
 
     #[test]
-    #[should_panic]
-    fn panics_on_invalid_response_type_code_token() {
-        let _ = OpenIdAuthorizationUrlParameters::builder(Uuid::new_v4())
+    fn invalid_response_type_code_token() {
+        let result = OpenIdAuthorizationUrlParameters::builder(Uuid::new_v4())
             .with_response_type([ResponseType::Code, ResponseType::Token])
             .with_scope(["scope"])
-            .url()
-            .unwrap();
+            .url();
+        assert!(result.is_err());
     }
 
     #[test]
-    #[should_panic]
-    fn panics_on_invalid_client_id() {
-        let _ = OpenIdAuthorizationUrlParameters::builder("client_id")
+    fn invalid_client_id() {
+        let result = OpenIdAuthorizationUrlParameters::builder("client_id")
             .with_response_type([ResponseType::Token])
             .with_scope(["scope"])
+            .url();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn empty_nonce() {
+        let result = OpenIdAuthorizationUrlParameters::builder(Uuid::new_v4())
+            .with_nonce("")
+            .url();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn query_response_mode() {
+        let result = OpenIdAuthorizationUrlParameters::builder(Uuid::new_v4())
+            .with_response_mode(ResponseMode::Query)
+            .url();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn form_post_default_for_id_token() {
+        let url = OpenIdAuthorizationUrlParameters::builder(Uuid::new_v4())
+            .with_response_type([ResponseType::Code, ResponseType::IdToken])
             .url()
             .unwrap();
+        assert!(url.query().unwrap().contains("response_mode=form_post"));
+
+        let url = OpenIdAuthorizationUrlParameters::builder(Uuid::new_v4())
+            .with_response_type([ResponseType::Code])
+            .url()
+            .unwrap();
+        assert!(!url.query().unwrap().contains("response_mode"));
+    }
+
+    #[test]
+    fn url_with_b2c_policy() {
+        let url = OpenIdAuthorizationUrlParameters::builder(Uuid::new_v4())
+            .with_authority(Authority::b2c("contoso", "B2C_1_signupsignin"))
+            .url()
+            .unwrap();
+        assert_eq!(
+            "/contoso.onmicrosoft.com/B2C_1_signupsignin/oauth2/v2.0/authorize",
+            url.path()
+        );
+        assert!(url
+            .query_pairs()
+            .any(|(key, value)| key == "p" && value == "B2C_1_signupsignin"));
+    }
+
+    #[test]
+    fn msal_authorization_url_round_trip() {
+        // Authorization url in the format produced by MSAL for the same parameters.
+        let msal_url = Url::parse(
+            "https://login.microsoftonline.com/common/oauth2/v2.0/authorize?\
+            client_id=6731de76-14a6-49ae-97bc-6eba6914391e&response_type=code+id_token&\
+            redirect_uri=http%3A%2F%2Flocalhost%3A8000%2Fredirect&scope=openid+profile+User.Read&\
+            state=12345&nonce=678910&response_mode=form_post&prompt=select_account&\
+            login_hint=user%40contoso.com",
+        )
+        .unwrap();
+
+        let url = OpenIdAuthorizationUrlParameters::builder("6731de76-14a6-49ae-97bc-6eba6914391e")
+            .with_response_type([ResponseType::Code, ResponseType::IdToken])
+            .with_redirect_uri(Url::parse("http://localhost:8000/redirect").unwrap())
+            .with_scope(["profile", "User.Read"])
+            .with_state("12345")
+            .with_nonce("678910")
+            .with_prompt([Prompt::SelectAccount])
+            .with_login_hint("user@contoso.com")
+            .url()
+            .unwrap();
+
+        // Scopes and response types are space separated sets and may be in any order.
+        let query = |url: &Url| {
+            url.query_pairs()
+                .map(|(key, value)| {
+                    let values: BTreeSet<&str> = value.split(' ').collect();
+                    (key.to_string(), Vec::from_iter(values).join(" "))
+                })
+                .collect::<BTreeSet<_>>()
+        };
+        assert_eq!(msal_url.origin(), url.origin());
+        assert_eq!(msal_url.path(), url.path());
+        assert_eq!(query(&msal_url), query(&url));
+
+        let round_trip = Url::parse(url.as_str()).unwrap();
+        assert_eq!(query(&url), query(&round_trip));
     }
 
     #[test]
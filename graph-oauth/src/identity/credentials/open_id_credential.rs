@@ -2,7 +2,7 @@ use std::collections::HashMap;
 use std::fmt::{Debug, Formatter};
 
 use async_trait::async_trait;
-use graph_core::cache::{CacheStore, InMemoryCacheStore, TokenCache};
+use graph_core::cache::{CacheStore, TokenCache};
 use http::{HeaderMap, HeaderName, HeaderValue};
 
 use reqwest::IntoUrl;
@@ -11,17 +11,17 @@ use uuid::Uuid;
 
 use graph_core::{
     crypto::{GenPkce, ProofKeyCodeExchange},
-    http::{AsyncResponseConverterExt, ResponseConverterExt},
     identity::ForceTokenRefresh,
 };
 
-use graph_error::{AuthExecutionError, AuthExecutionResult, IdentityResult, AF};
+use graph_error::{AuthExecutionError, IdentityResult, AF};
 
 use crate::identity::credentials::app_config::{AppConfig, AppConfigBuilder};
 use crate::identity::{
-    Authority, AuthorizationResponse, AzureCloudInstance, ConfidentialClientApplication, IdToken,
-    OpenIdAuthorizationUrlParameterBuilder, OpenIdAuthorizationUrlParameters, Token,
-    TokenCredentialExecutor,
+    cache_hit, cache_miss, token_expired, Authority, AuthorizationResponse, AzureCloudInstance,
+    ConfidentialClientApplication, IdToken, OpenIdAuthorizationUrlParameterBuilder,
+    OpenIdAuthorizationUrlParameters, RefreshTokenCredential, RefreshTokenRotationHook,
+    SecretString, Token, TokenCredentialExecutor, TokenStore,
 };
 use crate::internal::{AuthParameter, AuthSerializer};
 
@@ -29,6 +29,7 @@ credential_builder!(
     OpenIdCredentialBuilder,
     ConfidentialClientApplication<OpenIdCredential>
 );
+token_cache_builder!(OpenIdCredentialBuilder);
 
 /// OpenID Connect (OIDC) extends the OAuth 2.0 authorization protocol for use as an additional
 /// authentication protocol. You can use OIDC to enable single sign-on (SSO) between your
@@ -45,7 +46,7 @@ pub struct OpenIdCredential {
     /// Required when requesting a new access token using a refresh token
     /// The refresh token needed to make an access token request using a refresh token.
     /// Do not include an authorization code when using a refresh token.
-    pub(crate) refresh_token: Option<String>,
+    pub(crate) refresh_token: Option<SecretString>,
     /// Required
     /// The application secret that you created in the app registration portal for your app.
     /// Don't use the application secret in a native app or single page app because a
@@ -55,16 +56,16 @@ pub struct OpenIdCredential {
     /// is done by the SDK. For more information on URI encoding, see the URI Generic Syntax
     /// specification. The Basic auth pattern of instead providing credentials in the Authorization
     /// header, per RFC 6749 is also supported.
-    pub(crate) client_secret: String,
+    pub(crate) client_secret: SecretString,
     /// The same code_verifier that was used to obtain the authorization_code.
     /// Required if PKCE was used in the authorization code grant request. For more information,
     /// see the PKCE RFC https://datatracker.ietf.org/doc/html/rfc7636.
-    pub(crate) code_verifier: Option<String>,
+    pub(crate) code_verifier: Option<SecretString>,
     /// Used only when the client generates the pkce itself when the generate method
     /// is called.
     pub(crate) pkce: Option<ProofKeyCodeExchange>,
     serializer: AuthSerializer,
-    token_cache: InMemoryCacheStore<Token>,
+    token_cache: TokenStore,
 }
 
 impl Debug for OpenIdCredential {
@@ -90,7 +91,7 @@ impl OpenIdCredential {
                 .build(),
             authorization_code: Some(authorization_code.as_ref().to_owned()),
             refresh_token: None,
-            client_secret: client_secret.as_ref().to_owned(),
+            client_secret: SecretString::new(client_secret.as_ref()),
             code_verifier: None,
             pkce: None,
             serializer: Default::default(),
@@ -100,7 +101,7 @@ impl OpenIdCredential {
 
     pub fn with_refresh_token<T: AsRef<str>>(&mut self, refresh_token: T) {
         self.authorization_code = None;
-        self.refresh_token = Some(refresh_token.as_ref().to_owned());
+        self.refresh_token = Some(SecretString::new(refresh_token.as_ref()));
     }
 
     pub fn builder(client_id: impl TryInto<Uuid>) -> OpenIdCredentialBuilder {
@@ -118,46 +119,19 @@ impl OpenIdCredential {
     pub fn pkce(&self) -> Option<&ProofKeyCodeExchange> {
         self.pkce.as_ref()
     }
+}
 
-    fn execute_cached_token_refresh(&mut self, cache_id: String) -> AuthExecutionResult<Token> {
-        let response = self.execute()?;
-
-        if !response.status().is_success() {
-            return Err(AuthExecutionError::silent_token_auth(
-                response.into_http_response()?,
-            ));
-        }
-
-        let new_token: Token = response.json()?;
-        self.token_cache.store(cache_id, new_token.clone());
-
-        if new_token.refresh_token.is_some() {
-            self.refresh_token = new_token.refresh_token.clone();
-        }
-
-        Ok(new_token)
-    }
+impl RefreshTokenCredential for OpenIdCredential {
+    type TokenCache = TokenStore;
 
-    async fn execute_cached_token_refresh_async(
+    fn refresh_token_parts(
         &mut self,
-        cache_id: String,
-    ) -> AuthExecutionResult<Token> {
-        let response = self.execute_async().await?;
-
-        if !response.status().is_success() {
-            return Err(AuthExecutionError::silent_token_auth(
-                response.into_http_response_async().await?,
-            ));
-        }
-
-        let new_token: Token = response.json().await?;
-
-        if new_token.refresh_token.is_some() {
-            self.refresh_token = new_token.refresh_token.clone();
-        }
-
-        self.token_cache.store(cache_id, new_token.clone());
-        Ok(new_token)
+    ) -> (&mut Self::TokenCache, &AppConfig, &mut Option<SecretString>) {
+        (
+            &mut self.token_cache,
+            &self.app_config,
+            &mut self.refresh_token,
+        )
     }
 }
 
@@ -170,25 +144,20 @@ impl TokenCache for OpenIdCredential {
 
         match self.app_config.force_token_refresh {
             ForceTokenRefresh::Never => {
-                // Attempt to bypass a read on the token store by using previous
-                // refresh token stored outside of RwLock
-                if self.refresh_token.is_some() {
-                    if let Ok(token) = self.execute_cached_token_refresh(cache_id.clone()) {
-                        return Ok(token);
-                    }
-                }
-
                 if let Some(token) = self.token_cache.get(cache_id.as_str()) {
                     if token.is_expired_sub(time::Duration::minutes(5)) {
+                        token_expired(&self.app_config, &token);
                         if let Some(refresh_token) = token.refresh_token.as_ref() {
-                            self.refresh_token = Some(refresh_token.to_owned());
+                            self.refresh_token = Some(refresh_token.clone());
                         }
 
                         self.execute_cached_token_refresh(cache_id)
                     } else {
+                        cache_hit(&self.app_config, &token);
                         Ok(token)
                     }
                 } else {
+                    cache_miss(&self.app_config);
                     self.execute_cached_token_refresh(cache_id)
                 }
             }
@@ -207,28 +176,20 @@ impl TokenCache for OpenIdCredential {
 
         match self.app_config.force_token_refresh {
             ForceTokenRefresh::Never => {
-                // Attempt to bypass a read on the token store by using previous
-                // refresh token stored outside of RwLock
-                if self.refresh_token.is_some() {
-                    if let Ok(token) = self
-                        .execute_cached_token_refresh_async(cache_id.clone())
-                        .await
-                    {
-                        return Ok(token);
-                    }
-                }
-
                 if let Some(old_token) = self.token_cache.get(cache_id.as_str()) {
                     if old_token.is_expired_sub(time::Duration::minutes(5)) {
+                        token_expired(&self.app_config, &old_token);
                         if let Some(refresh_token) = old_token.refresh_token.as_ref() {
-                            self.refresh_token = Some(refresh_token.to_owned());
+                            self.refresh_token = Some(refresh_token.clone());
                         }
 
                         self.execute_cached_token_refresh_async(cache_id).await
                     } else {
+                        cache_hit(&self.app_config, &old_token);
                         Ok(old_token.clone())
                     }
                 } else {
+                    cache_miss(&self.app_config);
                     self.execute_cached_token_refresh_async(cache_id).await
                 }
             }
@@ -255,23 +216,23 @@ impl TokenCredentialExecutor for OpenIdCredential {
             return AF::result(AuthParameter::ClientId.alias());
         }
 
-        if self.client_secret.trim().is_empty() {
+        if self.client_secret.expose_secret().trim().is_empty() {
             return AF::result(AuthParameter::ClientSecret.alias());
         }
 
         self.serializer
             .client_id(client_id.as_str())
-            .client_secret(self.client_secret.as_str())
-            .set_scope(self.app_config.scope.clone());
+            .client_secret(self.client_secret.expose_secret())
+            .set_scope(self.app_config.cloud_scope()?);
 
         if let Some(refresh_token) = self.refresh_token.as_ref() {
-            if refresh_token.trim().is_empty() {
+            if refresh_token.expose_secret().trim().is_empty() {
                 return AF::msg_result(AuthParameter::RefreshToken, "Refresh token is empty");
             }
 
             self.serializer
                 .grant_type("refresh_token")
-                .refresh_token(refresh_token.as_ref());
+                .refresh_token(refresh_token.expose_secret());
 
             return self.serializer.as_credential_map(
                 vec![AuthParameter::Scope],
@@ -302,7 +263,7 @@ impl TokenCredentialExecutor for OpenIdCredential {
             self.authorization_code = None;
 
             if let Some(code_verifier) = self.code_verifier.as_ref() {
-                self.serializer.code_verifier(code_verifier.as_str());
+                self.serializer.code_verifier(code_verifier.expose_secret());
             }
 
             return self.serializer.as_credential_map(
@@ -342,7 +303,7 @@ impl TokenCredentialExecutor for OpenIdCredential {
     fn basic_auth(&self) -> Option<(String, String)> {
         Some((
             self.app_config.client_id.to_string(),
-            self.client_secret.clone(),
+            self.client_secret.expose_secret().to_owned(),
         ))
     }
 
@@ -374,7 +335,7 @@ impl OpenIdCredentialBuilder {
                     .build(),
                 authorization_code: None,
                 refresh_token: None,
-                client_secret: String::new(),
+                client_secret: Default::default(),
                 code_verifier: None,
                 pkce: None,
                 serializer: Default::default(),
@@ -390,7 +351,7 @@ impl OpenIdCredentialBuilder {
                 app_config,
                 authorization_code: None,
                 refresh_token: None,
-                client_secret: String::new(),
+                client_secret: Default::default(),
                 code_verifier: None,
                 pkce: None,
                 serializer: Default::default(),
@@ -429,7 +390,7 @@ impl OpenIdCredentialBuilder {
                 app_config,
                 authorization_code: Some(authorization_code.as_ref().to_owned()),
                 refresh_token: None,
-                client_secret: client_secret.as_ref().to_owned(),
+                client_secret: SecretString::new(client_secret.as_ref()),
                 code_verifier: None,
                 pkce: None,
                 serializer: Default::default(),
@@ -438,9 +399,13 @@ impl OpenIdCredentialBuilder {
         }
     }
 
-    pub(crate) fn new_with_token(app_config: AppConfig, token: Token) -> OpenIdCredentialBuilder {
+    pub(crate) fn new_with_token(
+        app_config: AppConfig,
+        mut token: Token,
+    ) -> OpenIdCredentialBuilder {
         let cache_id = app_config.cache_id.clone();
-        let mut token_cache = InMemoryCacheStore::new();
+        let mut token_cache = TokenStore::default();
+        token.with_log_policy(app_config.log_policy);
         token_cache.store(cache_id, token);
 
         Self {
@@ -465,7 +430,7 @@ impl OpenIdCredentialBuilder {
 
     pub fn with_refresh_token<T: AsRef<str>>(&mut self, refresh_token: T) -> &mut Self {
         self.credential.authorization_code = None;
-        self.credential.refresh_token = Some(refresh_token.as_ref().to_owned());
+        self.credential.refresh_token = Some(SecretString::new(refresh_token.as_ref()));
         self
     }
 
@@ -475,24 +440,24 @@ impl OpenIdCredentialBuilder {
     }
 
     pub fn with_client_secret<T: AsRef<str>>(&mut self, client_secret: T) -> &mut Self {
-        self.credential.client_secret = client_secret.as_ref().to_owned();
+        self.credential.client_secret = SecretString::new(client_secret.as_ref());
         self
     }
 
     fn with_code_verifier<T: AsRef<str>>(&mut self, code_verifier: T) -> &mut Self {
-        self.credential.code_verifier = Some(code_verifier.as_ref().to_owned());
+        self.credential.code_verifier = Some(SecretString::new(code_verifier.as_ref()));
         self
     }
 
     pub fn with_pkce(&mut self, pkce: ProofKeyCodeExchange) -> &mut Self {
-        self.with_code_verifier(pkce.code_verifier.as_str());
+        self.with_code_verifier(pkce.code_verifier.expose_secret());
         self.credential.pkce = Some(pkce);
         self
     }
 
     pub fn with_pkce_oneshot(&mut self) -> IdentityResult<&mut Self> {
         let pkce = ProofKeyCodeExchange::oneshot()?;
-        self.with_code_verifier(pkce.code_verifier.as_str());
+        self.with_code_verifier(pkce.code_verifier.expose_secret());
         self.credential.pkce = Some(pkce);
         Ok(self)
     }
@@ -500,6 +465,15 @@ impl OpenIdCredentialBuilder {
     pub fn credential(&self) -> &OpenIdCredential {
         &self.credential
     }
+
+    /// Set a callback that is called when the refresh token is rotated.
+    pub fn with_refresh_token_rotation_hook(
+        &mut self,
+        refresh_token_rotation_hook: RefreshTokenRotationHook,
+    ) -> &mut Self {
+        self.credential.app_config.refresh_token_rotation_hook = Some(refresh_token_rotation_hook);
+        self
+    }
 }
 
 impl From<OpenIdAuthorizationUrlParameters> for OpenIdCredentialBuilder {
@@ -521,9 +495,9 @@ impl From<(AppConfig, AuthorizationResponse)> for OpenIdCredentialBuilder {
             if let Some(id_token) = authorization_response.id_token.as_ref() {
                 app_config.with_id_token(IdToken::new(
                     id_token.as_ref(),
-                    None,
                     Some(authorization_code.as_ref()),
-                    None,
+                    authorization_response.state.as_deref(),
+                    authorization_response.session_state.as_deref(),
                 ));
                 OpenIdCredentialBuilder::new_with_auth_code(app_config, authorization_code)
             } else {
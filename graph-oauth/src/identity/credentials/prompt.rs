@@ -28,6 +28,13 @@ pub enum Prompt {
     AttemptNone,
 }
 
+impl Prompt {
+    /// ADFS only supports prompt=login and prompt=none.
+    pub(crate) fn is_supported_by_adfs(&self) -> bool {
+        matches!(self, Prompt::Login | Prompt::None)
+    }
+}
+
 impl AsRef<str> for Prompt {
     fn as_ref(&self) -> &'static str {
         match self {
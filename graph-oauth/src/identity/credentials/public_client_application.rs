@@ -1,16 +1,18 @@
 use crate::identity::credentials::app_config::AppConfig;
 use crate::identity::credentials::application_builder::PublicClientApplicationBuilder;
 use crate::identity::{
-    Authority, AzureCloudInstance, DeviceCodeCredential, ResourceOwnerPasswordCredential,
-    TokenCredentialExecutor,
+    token_request_failed, Authority, AuthorizationCodeCredential, AzureCloudInstance,
+    DeviceCodeCredential, ResourceOwnerPasswordCredential, TokenCredentialExecutor,
+    TokenRequestLock,
 };
 use async_trait::async_trait;
 use graph_core::cache::{AsBearer, TokenCache};
 use graph_core::identity::{ClientApplication, ForceTokenRefresh};
-use graph_error::{AuthExecutionResult, IdentityResult};
+use graph_error::{AuthExecutionError, AuthExecutionResult, IdentityResult};
 use reqwest::Response;
 use std::collections::HashMap;
 use std::fmt::Debug;
+use std::sync::Arc;
 use url::Url;
 use uuid::Uuid;
 
@@ -20,9 +22,17 @@ use uuid::Uuid;
 /// secure client authentication via any other means.
 ///
 /// See [Client Types](https://datatracker.ietf.org/doc/html/rfc6749#section-2.1) in the specification.
+///
+/// [ClientApplication::get_token_interactive] is not supported and returns an error. The
+/// sign in flows of public client credentials, such as the device code flow, need the
+/// user to act on a prompt, and an authorization code or device code can only be
+/// redeemed once.
 #[derive(Clone, Debug)]
 pub struct PublicClientApplication<Credential> {
     credential: Credential,
+    /// Shared by applications with the same client id, tenant, and scopes so that
+    /// concurrent token requests are coalesced into a single request to the token endpoint.
+    token_request_lock: Arc<TokenRequestLock>,
 }
 
 impl PublicClientApplication<()> {
@@ -35,25 +45,43 @@ impl<Credential: Clone + Debug + Send + Sync + TokenCredentialExecutor>
     PublicClientApplication<Credential>
 {
     pub(crate) fn new(credential: Credential) -> PublicClientApplication<Credential> {
-        PublicClientApplication { credential }
+        PublicClientApplication {
+            token_request_lock: TokenRequestLock::for_app_config(credential.app_config()),
+            credential,
+        }
     }
 
     pub(crate) fn credential(credential: Credential) -> PublicClientApplication<Credential> {
-        PublicClientApplication { credential }
+        PublicClientApplication {
+            token_request_lock: TokenRequestLock::for_app_config(credential.app_config()),
+            credential,
+        }
     }
 }
 
 #[async_trait]
-impl<Credential: Clone + Debug + Send + Sync + TokenCache> ClientApplication
-    for PublicClientApplication<Credential>
+impl<Credential: Clone + Debug + Send + Sync + TokenCache + TokenCredentialExecutor>
+    ClientApplication for PublicClientApplication<Credential>
 {
     fn get_token_silent(&mut self) -> AuthExecutionResult<String> {
-        let token = self.credential.get_token_silent()?;
+        let token_request_lock = self.token_request_lock.clone();
+        let _guard = token_request_lock.lock();
+        let token = self
+            .credential
+            .get_token_silent()
+            .map_err(|err| token_request_failed(self.credential.app_config(), err))?;
         Ok(token.as_bearer())
     }
 
     async fn get_token_silent_async(&mut self) -> AuthExecutionResult<String> {
-        let token = self.credential.get_token_silent_async().await?;
+        // Tasks waiting on the lock use the token cached by the task holding the lock.
+        let token_request_lock = self.token_request_lock.clone();
+        let _guard = token_request_lock.lock_async().await;
+        let token = self
+            .credential
+            .get_token_silent_async()
+            .await
+            .map_err(|err| token_request_failed(self.credential.app_config(), err))?;
         Ok(token.as_bearer())
     }
 
@@ -61,6 +89,53 @@ impl<Credential: Clone + Debug + Send + Sync + TokenCache> ClientApplication
         self.credential
             .with_force_token_refresh(force_token_refresh);
     }
+
+    fn scopes(&self) -> Vec<String> {
+        self.credential.app_config().scope.iter().cloned().collect()
+    }
+
+    fn authority_uri(&self) -> Option<String> {
+        Some(
+            self.credential
+                .app_config()
+                .authority_endpoints()
+                .authority_uri(),
+        )
+    }
+
+    /// The Microsoft Graph endpoint of the [AzureCloudInstance](crate::identity::AzureCloudInstance)
+    /// of the application. None when a custom authority host is used that is not
+    /// a login host of a cloud.
+    fn graph_base_url(&self) -> Option<String> {
+        let app_config = self.credential.app_config();
+        app_config
+            .cloud_of_host(&app_config.azure_cloud_instance)
+            .map(|cloud| cloud.graph_resource().to_owned())
+    }
+}
+
+#[async_trait]
+impl<Credential: Clone + Debug + Send + Sync + TokenCache> TokenCache
+    for PublicClientApplication<Credential>
+{
+    type Token = Credential::Token;
+
+    fn get_token_silent(&mut self) -> Result<Self::Token, AuthExecutionError> {
+        let token_request_lock = self.token_request_lock.clone();
+        let _guard = token_request_lock.lock();
+        self.credential.get_token_silent()
+    }
+
+    async fn get_token_silent_async(&mut self) -> Result<Self::Token, AuthExecutionError> {
+        let token_request_lock = self.token_request_lock.clone();
+        let _guard = token_request_lock.lock_async().await;
+        self.credential.get_token_silent_async().await
+    }
+
+    fn with_force_token_refresh(&mut self, force_token_refresh: ForceTokenRefresh) {
+        self.credential
+            .with_force_token_refresh(force_token_refresh);
+    }
 }
 
 #[async_trait]
@@ -108,8 +183,27 @@ impl From<ResourceOwnerPasswordCredential>
     }
 }
 
+impl From<AuthorizationCodeCredential> for PublicClientApplication<AuthorizationCodeCredential> {
+    fn from(value: AuthorizationCodeCredential) -> Self {
+        PublicClientApplication::credential(value)
+    }
+}
+
 impl From<DeviceCodeCredential> for PublicClientApplication<DeviceCodeCredential> {
     fn from(value: DeviceCodeCredential) -> Self {
         PublicClientApplication::credential(value)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn get_token_interactive_is_not_supported() {
+        let credential =
+            DeviceCodeCredential::new(Uuid::new_v4().to_string(), "device_code", vec!["User.Read"]);
+        let mut public_client = PublicClientApplication::from(credential);
+        assert!(ClientApplication::get_token_interactive(&mut public_client).is_err());
+    }
+}
@@ -0,0 +1,368 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use graph_core::cache::CacheStore;
+use graph_core::http::{AsyncResponseConverterExt, ResponseConverterExt};
+use graph_error::{AuthExecutionError, AuthExecutionResult};
+
+use crate::identity::{
+    token_acquired, token_request_error, tracing_targets::CREDENTIAL_EXECUTOR, AppConfig,
+    SecretString, Token, TokenCredentialExecutor,
+};
+
+type RotationCallback = dyn Fn(&RefreshTokenRotationEvent) + Send + Sync;
+
+/// Events emitted when the refresh token of a credential changes.
+#[derive(Clone, Debug)]
+pub enum RefreshTokenRotationEvent {
+    /// The token endpoint returned a new refresh token which replaced the previous
+    /// refresh token in the token cache.
+    Rotated { cache_id: String, token: Token },
+    /// A refresh token was rejected with `invalid_grant` because another clone of the
+    /// credential had already rotated it. The newer token in the token cache was used instead.
+    ReuseDetected { cache_id: String },
+}
+
+/// Callback that is called with a [RefreshTokenRotationEvent] when the refresh token
+/// of a credential is rotated, such as to persist the new refresh token.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_oauth::{RefreshTokenRotationEvent, RefreshTokenRotationHook};
+///
+/// let credential = ConfidentialClientApplication::builder("client-id")
+///     .with_auth_code("authorization-code")
+///     .with_client_secret("client-secret")
+///     .with_refresh_token_rotation_hook(RefreshTokenRotationHook::new(|event| {
+///         if let RefreshTokenRotationEvent::Rotated { token, .. } = event {
+///             save_refresh_token(token.refresh_token.as_ref().map(|t| t.expose_secret()));
+///         }
+///     }))
+///     .build();
+/// ```
+#[derive(Clone)]
+pub struct RefreshTokenRotationHook(Arc<RotationCallback>);
+
+impl RefreshTokenRotationHook {
+    pub fn new<F>(callback: F) -> RefreshTokenRotationHook
+    where
+        F: Fn(&RefreshTokenRotationEvent) + Send + Sync + 'static,
+    {
+        RefreshTokenRotationHook(Arc::new(callback))
+    }
+
+    pub(crate) fn notify(&self, event: &RefreshTokenRotationEvent) {
+        (self.0)(event)
+    }
+}
+
+impl Debug for RefreshTokenRotationHook {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RefreshTokenRotationHook").finish()
+    }
+}
+
+impl PartialEq for RefreshTokenRotationHook {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+fn notify(app_config: &AppConfig, event: RefreshTokenRotationEvent) {
+    if let Some(hook) = app_config.refresh_token_rotation_hook.as_ref() {
+        hook.notify(&event);
+    }
+}
+
+/// The refresh token that is sent in the next token request. The token cache is checked
+/// first because clones of a credential share the token cache and another clone may have
+/// already rotated the refresh token.
+pub(crate) fn current_refresh_token(
+    token_cache: &impl CacheStore<Token>,
+    cache_id: &str,
+    refresh_token: Option<&SecretString>,
+) -> Option<SecretString> {
+    token_cache
+        .get(cache_id)
+        .and_then(|token| token.refresh_token)
+        .or_else(|| refresh_token.cloned())
+}
+
+/// Replace the cached token with a new token. The token is replaced in a single write
+/// so that the access token and refresh token in the cache are always from the same response.
+pub(crate) fn store_token(
+    token_cache: &mut impl CacheStore<Token>,
+    app_config: &AppConfig,
+    cache_id: String,
+    previous_refresh_token: Option<&SecretString>,
+    token: &Token,
+) {
+    token_cache.store(cache_id.clone(), token.clone());
+    token_acquired(app_config, token, previous_refresh_token.is_some());
+
+    if let (Some(previous), Some(refresh_token)) =
+        (previous_refresh_token, token.refresh_token.as_ref())
+    {
+        if previous.ne(refresh_token) {
+            tracing::debug!(target: CREDENTIAL_EXECUTOR, "refresh token rotated");
+            notify(
+                app_config,
+                RefreshTokenRotationEvent::Rotated {
+                    cache_id,
+                    token: token.clone(),
+                },
+            );
+        }
+    }
+}
+
+/// Get the token in the cache when a refresh token request failed with `invalid_grant`
+/// because the refresh token that was sent had already been rotated by another clone
+/// of the credential.
+pub(crate) fn rotated_token(
+    token_cache: &impl CacheStore<Token>,
+    app_config: &AppConfig,
+    cache_id: &str,
+    used_refresh_token: Option<&SecretString>,
+    error: &AuthExecutionError,
+) -> Option<Token> {
+    let used_refresh_token = used_refresh_token?;
+    if error.msal_error_response()?.error.ne("invalid_grant") {
+        return None;
+    }
+
+    let token = token_cache.get(cache_id)?;
+    if token.refresh_token.as_ref()?.eq(used_refresh_token) {
+        return None;
+    }
+
+    tracing::debug!(target: CREDENTIAL_EXECUTOR, "refresh token reuse detected; using rotated refresh token");
+    notify(
+        app_config,
+        RefreshTokenRotationEvent::ReuseDetected {
+            cache_id: cache_id.to_owned(),
+        },
+    );
+    Some(token)
+}
+
+/// Token refresh shared by the credentials that are refreshed with a refresh token.
+#[async_trait]
+pub(crate) trait RefreshTokenCredential: TokenCredentialExecutor + Send {
+    type TokenCache: CacheStore<Token> + Send;
+
+    /// The token cache, app config and current refresh token of the credential.
+    fn refresh_token_parts(
+        &mut self,
+    ) -> (&mut Self::TokenCache, &AppConfig, &mut Option<SecretString>);
+
+    fn execute_token_refresh(
+        &mut self,
+        cache_id: String,
+        refresh_token: Option<&SecretString>,
+    ) -> AuthExecutionResult<Token> {
+        let response = self.execute()?;
+
+        if !response.status().is_success() {
+            return Err(token_request_error(
+                self.app_config(),
+                response.into_http_response()?,
+            ));
+        }
+
+        let mut new_token: Token = response.json()?;
+        let (token_cache, app_config, current_refresh_token) = self.refresh_token_parts();
+        new_token.with_log_policy(app_config.log_policy);
+        store_token(token_cache, app_config, cache_id, refresh_token, &new_token);
+
+        if new_token.refresh_token.is_some() {
+            *current_refresh_token = new_token.refresh_token.clone();
+        }
+
+        Ok(new_token)
+    }
+
+    async fn execute_token_refresh_async(
+        &mut self,
+        cache_id: String,
+        refresh_token: Option<&SecretString>,
+    ) -> AuthExecutionResult<Token> {
+        let response = self.execute_async().await?;
+
+        if !response.status().is_success() {
+            return Err(token_request_error(
+                self.app_config(),
+                response.into_http_response_async().await?,
+            ));
+        }
+
+        let mut new_token: Token = response.json().await?;
+        let (token_cache, app_config, current_refresh_token) = self.refresh_token_parts();
+        new_token.with_log_policy(app_config.log_policy);
+        store_token(token_cache, app_config, cache_id, refresh_token, &new_token);
+
+        if new_token.refresh_token.is_some() {
+            *current_refresh_token = new_token.refresh_token.clone();
+        }
+
+        Ok(new_token)
+    }
+
+    /// Refresh the token and, if the refresh token was already rotated by another
+    /// clone of the credential, use the rotated token from the cache or retry once.
+    fn execute_cached_token_refresh(&mut self, cache_id: String) -> AuthExecutionResult<Token> {
+        let (token_cache, _, current) = self.refresh_token_parts();
+        let refresh_token =
+            current_refresh_token(&*token_cache, cache_id.as_str(), current.as_ref());
+
+        match self.execute_token_refresh(cache_id.clone(), refresh_token.as_ref()) {
+            Err(error) => {
+                let token = self
+                    .rotated_refresh_token(cache_id.as_str(), refresh_token.as_ref(), &error)
+                    .ok_or(error)?;
+
+                if !token.is_expired_sub(time::Duration::minutes(5)) {
+                    return Ok(token);
+                }
+
+                self.execute_token_refresh(cache_id, token.refresh_token.as_ref())
+            }
+            result => result,
+        }
+    }
+
+    async fn execute_cached_token_refresh_async(
+        &mut self,
+        cache_id: String,
+    ) -> AuthExecutionResult<Token> {
+        let (token_cache, _, current) = self.refresh_token_parts();
+        let refresh_token =
+            current_refresh_token(&*token_cache, cache_id.as_str(), current.as_ref());
+
+        match self
+            .execute_token_refresh_async(cache_id.clone(), refresh_token.as_ref())
+            .await
+        {
+            Err(error) => {
+                let token = self
+                    .rotated_refresh_token(cache_id.as_str(), refresh_token.as_ref(), &error)
+                    .ok_or(error)?;
+
+                if !token.is_expired_sub(time::Duration::minutes(5)) {
+                    return Ok(token);
+                }
+
+                self.execute_token_refresh_async(cache_id, token.refresh_token.as_ref())
+                    .await
+            }
+            result => result,
+        }
+    }
+
+    /// The rotated token in the cache after a refresh failed because of refresh token
+    /// reuse. The refresh token of the credential is updated to the rotated refresh token.
+    fn rotated_refresh_token(
+        &mut self,
+        cache_id: &str,
+        used_refresh_token: Option<&SecretString>,
+        error: &AuthExecutionError,
+    ) -> Option<Token> {
+        let (token_cache, app_config, current) = self.refresh_token_parts();
+        let token = rotated_token(
+            &*token_cache,
+            app_config,
+            cache_id,
+            used_refresh_token,
+            error,
+        )?;
+        *current = token.refresh_token.clone();
+        Some(token)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use graph_core::cache::InMemoryCacheStore;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn token(refresh_token: &str) -> Token {
+        let mut token = Token::new("Bearer", 3600, "access_token", vec!["User.Read"]);
+        token.with_refresh_token(refresh_token);
+        token
+    }
+
+    fn invalid_grant() -> AuthExecutionError {
+        let response = http::Response::builder()
+            .status(400)
+            .body(Ok(serde_json::json!({ "error": "invalid_grant" })))
+            .unwrap();
+        AuthExecutionError::silent_token_auth(response)
+    }
+
+    #[test]
+    fn store_token_notifies_rotation() {
+        let count = Arc::new(AtomicUsize::new(0));
+        let hook_count = count.clone();
+        let mut app_config = AppConfig::new(uuid::Uuid::new_v4());
+        app_config.refresh_token_rotation_hook = Some(RefreshTokenRotationHook::new(move |_| {
+            hook_count.fetch_add(1, Ordering::SeqCst);
+        }));
+
+        let mut token_cache = InMemoryCacheStore::new();
+        let previous = SecretString::from("refresh_token");
+        store_token(
+            &mut token_cache,
+            &app_config,
+            "cache_id".into(),
+            Some(&previous),
+            &token("refresh_token"),
+        );
+        assert_eq!(0, count.load(Ordering::SeqCst));
+
+        store_token(
+            &mut token_cache,
+            &app_config,
+            "cache_id".into(),
+            Some(&previous),
+            &token("rotated_refresh_token"),
+        );
+        assert_eq!(1, count.load(Ordering::SeqCst));
+        assert_eq!(
+            Some(SecretString::from("rotated_refresh_token")),
+            current_refresh_token(&token_cache, "cache_id", Some(&previous))
+        );
+    }
+
+    #[test]
+    fn rotated_token_after_reuse() {
+        let app_config = AppConfig::new(uuid::Uuid::new_v4());
+        let mut token_cache = InMemoryCacheStore::new();
+        token_cache.store("cache_id", token("rotated_refresh_token"));
+
+        let used = SecretString::from("refresh_token");
+        let token = rotated_token(
+            &token_cache,
+            &app_config,
+            "cache_id",
+            Some(&used),
+            &invalid_grant(),
+        )
+        .unwrap();
+        assert_eq!(
+            Some(SecretString::from("rotated_refresh_token")),
+            token.refresh_token
+        );
+
+        let used = SecretString::from("rotated_refresh_token");
+        assert!(rotated_token(
+            &token_cache,
+            &app_config,
+            "cache_id",
+            Some(&used),
+            &invalid_grant()
+        )
+        .is_none());
+    }
+}
@@ -1,7 +1,7 @@
 use crate::identity::credentials::app_config::AppConfig;
 use crate::identity::{
-    tracing_targets::CREDENTIAL_EXECUTOR, Authority, AzureCloudInstance, Token,
-    TokenCredentialExecutor,
+    cache_hit, cache_miss, token_acquired, token_expired, token_request_error, Authority,
+    AzureCloudInstance, SecretString, Token, TokenCredentialExecutor,
 };
 use crate::oauth_serializer::{AuthParameter, AuthSerializer};
 use async_trait::async_trait;
@@ -30,7 +30,7 @@ pub struct ResourceOwnerPasswordCredential {
     pub(crate) username: String,
     /// Required
     /// The user's password.
-    pub(crate) password: String,
+    pub(crate) password: SecretString,
     token_cache: InMemoryCacheStore<Token>,
 }
 
@@ -53,7 +53,7 @@ impl ResourceOwnerPasswordCredential {
                 .authority(Authority::Organizations)
                 .build(),
             username: username.as_ref().to_owned(),
-            password: password.as_ref().to_owned(),
+            password: SecretString::new(password.as_ref()),
             token_cache: Default::default(),
         }
     }
@@ -69,7 +69,7 @@ impl ResourceOwnerPasswordCredential {
                 .tenant(tenant_id.as_ref())
                 .build(),
             username: username.as_ref().to_owned(),
-            password: password.as_ref().to_owned(),
+            password: SecretString::new(password.as_ref()),
             token_cache: Default::default(),
         }
     }
@@ -82,13 +82,16 @@ impl ResourceOwnerPasswordCredential {
         let response = self.execute()?;
 
         if !response.status().is_success() {
-            return Err(AuthExecutionError::silent_token_auth(
+            return Err(token_request_error(
+                self.app_config(),
                 response.into_http_response()?,
             ));
         }
 
-        let new_token: Token = response.json()?;
+        let mut new_token: Token = response.json()?;
+        new_token.with_log_policy(self.app_config.log_policy);
         self.token_cache.store(cache_id, new_token.clone());
+        token_acquired(&self.app_config, &new_token, false);
         Ok(new_token)
     }
 
@@ -99,13 +102,16 @@ impl ResourceOwnerPasswordCredential {
         let response = self.execute_async().await?;
 
         if !response.status().is_success() {
-            return Err(AuthExecutionError::silent_token_auth(
+            return Err(token_request_error(
+                self.app_config(),
                 response.into_http_response_async().await?,
             ));
         }
 
-        let new_token: Token = response.json().await?;
+        let mut new_token: Token = response.json().await?;
+        new_token.with_log_policy(self.app_config.log_policy);
         self.token_cache.store(cache_id, new_token.clone());
+        token_acquired(&self.app_config, &new_token, false);
         Ok(new_token)
     }
 }
@@ -118,14 +124,14 @@ impl TokenCache for ResourceOwnerPasswordCredential {
         let cache_id = self.app_config.cache_id.to_string();
         if let Some(token) = self.token_cache.get(cache_id.as_str()) {
             if token.is_expired_sub(time::Duration::minutes(5)) {
-                tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
+                token_expired(&self.app_config, &token);
                 self.execute_cached_token_refresh(cache_id)
             } else {
-                tracing::debug!(target: CREDENTIAL_EXECUTOR, "using token from cache");
+                cache_hit(&self.app_config, &token);
                 Ok(token)
             }
         } else {
-            tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
+            cache_miss(&self.app_config);
             self.execute_cached_token_refresh(cache_id)
         }
     }
@@ -134,14 +140,14 @@ impl TokenCache for ResourceOwnerPasswordCredential {
         let cache_id = self.app_config.cache_id.to_string();
         if let Some(token) = self.token_cache.get(cache_id.as_str()) {
             if token.is_expired_sub(time::Duration::minutes(5)) {
-                tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
+                token_expired(&self.app_config, &token);
                 self.execute_cached_token_refresh_async(cache_id).await
             } else {
-                tracing::debug!(target: CREDENTIAL_EXECUTOR, "using token from cache");
+                cache_hit(&self.app_config, &token);
                 Ok(token.clone())
             }
         } else {
-            tracing::debug!(target: CREDENTIAL_EXECUTOR, "executing silent token request; refresh_token=None");
+            cache_miss(&self.app_config);
             self.execute_cached_token_refresh_async(cache_id).await
         }
     }
@@ -164,14 +170,14 @@ impl TokenCredentialExecutor for ResourceOwnerPasswordCredential {
             return AF::result(AuthParameter::Username.alias());
         }
 
-        if self.password.trim().is_empty() {
+        if self.password.expose_secret().trim().is_empty() {
             return AF::result(AuthParameter::Password.alias());
         }
 
         serializer
             .client_id(client_id.as_str())
             .grant_type("password")
-            .set_scope(self.app_config.scope.clone());
+            .set_scope(self.app_config.cloud_scope()?);
 
         serializer.as_credential_map(
             vec![AuthParameter::Scope],
@@ -192,7 +198,10 @@ impl TokenCredentialExecutor for ResourceOwnerPasswordCredential {
     }
 
     fn basic_auth(&self) -> Option<(String, String)> {
-        Some((self.username.clone(), self.password.clone()))
+        Some((
+            self.username.clone(),
+            self.password.expose_secret().to_owned(),
+        ))
     }
 
     fn app_config(&self) -> &AppConfig {
@@ -226,7 +235,7 @@ impl ResourceOwnerPasswordCredentialBuilder {
             credential: ResourceOwnerPasswordCredential {
                 app_config,
                 username: username.as_ref().to_owned(),
-                password: password.as_ref().to_owned(),
+                password: SecretString::new(password.as_ref()),
                 token_cache: Default::default(),
             },
         }
@@ -244,7 +253,7 @@ impl ResourceOwnerPasswordCredentialBuilder {
     }
 
     pub fn with_password<T: AsRef<str>>(&mut self, password: T) -> &mut Self {
-        self.credential.password = password.as_ref().to_owned();
+        self.credential.password = SecretString::new(password.as_ref());
         self
     }
 
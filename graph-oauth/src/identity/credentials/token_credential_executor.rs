@@ -1,5 +1,6 @@
 use std::collections::HashMap;
-use std::fmt::Debug;
+use std::fmt::{Debug, Formatter};
+use std::sync::{Arc, OnceLock};
 
 use async_trait::async_trait;
 use dyn_clone::DynClone;
@@ -9,19 +10,120 @@ use reqwest::tls::Version;
 use url::{ParseError, Url};
 use uuid::Uuid;
 
+use graph_core::http::{AsyncResponseConverterExt, ResponseConverterExt};
+use graph_core::identity::ForceTokenRefresh;
 use graph_error::{AuthExecutionResult, IdentityResult};
 
 use crate::identity::credentials::app_config::AppConfig;
 use crate::identity::{
-    tracing_targets::CREDENTIAL_EXECUTOR, Authority, AuthorizationRequestParts, AzureCloudInstance,
+    record_response, token_request_error, token_requested, tracing_targets::CREDENTIAL_EXECUTOR,
+    Authority, AuthorizationRequestParts, AzureCloudInstance, AzureRegion, InstanceDiscovery,
+    Token,
 };
 
 dyn_clone::clone_trait_object!(TokenCredentialExecutor);
 
+/// The http clients used for the token requests of a credential.
+///
+/// A client that was not set with `with_http_client` or `with_blocking_http_client`
+/// on the credential builder is created on first use with a configuration that only
+/// allows https and TLS 1.2 or later. Clones of the credential share the clients so
+/// that connections to the token endpoint are pooled and reused between requests.
+#[derive(Clone, Default)]
+pub(crate) struct TokenHttpClient {
+    http_client: Arc<OnceLock<reqwest::Client>>,
+    blocking_http_client: Arc<OnceLock<reqwest::blocking::Client>>,
+}
+
+impl TokenHttpClient {
+    pub(crate) fn with_http_client(&mut self, http_client: reqwest::Client) {
+        self.http_client = Arc::new(OnceLock::from(http_client));
+    }
+
+    pub(crate) fn with_blocking_http_client(&mut self, http_client: reqwest::blocking::Client) {
+        self.blocking_http_client = Arc::new(OnceLock::from(http_client));
+    }
+
+    pub(crate) fn http_client(&self) -> reqwest::Result<reqwest::Client> {
+        if let Some(http_client) = self.http_client.get() {
+            return Ok(http_client.clone());
+        }
+
+        let http_client = reqwest::ClientBuilder::new()
+            .min_tls_version(Version::TLS_1_2)
+            .https_only(true)
+            .build()?;
+        Ok(self.http_client.get_or_init(|| http_client).clone())
+    }
+
+    pub(crate) fn blocking_http_client(&self) -> reqwest::Result<reqwest::blocking::Client> {
+        if let Some(http_client) = self.blocking_http_client.get() {
+            return Ok(http_client.clone());
+        }
+
+        let http_client = reqwest::blocking::ClientBuilder::new()
+            .min_tls_version(Version::TLS_1_2)
+            .https_only(true)
+            .build()?;
+        Ok(self
+            .blocking_http_client
+            .get_or_init(|| http_client)
+            .clone())
+    }
+}
+
+impl Debug for TokenHttpClient {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TokenHttpClient").finish()
+    }
+}
+
+impl PartialEq for TokenHttpClient {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.http_client, &other.http_client)
+            && Arc::ptr_eq(&self.blocking_http_client, &other.blocking_http_client)
+    }
+}
+
+/// Instance discovery is skipped for an instance that was set explicitly, such as a
+/// private cloud, and for Azure AD B2C and ADFS hosts which are not part of instance
+/// discovery.
+fn uses_instance_discovery(app_config: &AppConfig) -> bool {
+    app_config.token_credential_options.instance_discovery
+        && app_config.instance.is_none()
+        && !app_config.authority.is_b2c()
+        && !app_config.authority.is_adfs()
+}
+
+/// Regional endpoints are only used for the client credentials grant.
+fn is_client_credentials_grant(form: &HashMap<String, String>) -> bool {
+    form.get("grant_type")
+        .map(|grant_type| grant_type.eq("client_credentials"))
+        .unwrap_or_default()
+}
+
+/// Builds and sends the token request of a credential.
+///
+/// The trait is object safe so that credentials of different types can be stored
+/// in the same field and swapped at runtime.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_oauth::{ClientSecretCredential, ResourceOwnerPasswordCredential, TokenCredentialExecutor};
+///
+/// let mut credential: Box<dyn TokenCredentialExecutor + Send + Sync> =
+///     Box::new(ClientSecretCredential::new("client-id", "secret"));
+///
+/// if use_password_credential {
+///     credential = Box::new(ResourceOwnerPasswordCredential::new("client-id", "username", "password"));
+/// }
+///
+/// let token = credential.execute_token_async().await?;
+/// ```
 #[async_trait]
 pub trait TokenCredentialExecutor: DynClone + Debug {
     fn uri(&mut self) -> IdentityResult<Url> {
-        Ok(self.azure_cloud_instance().token_uri(&self.authority())?)
+        Ok(self.app_config().authority_endpoints().token_uri()?)
     }
 
     fn form_urlencode(&mut self) -> IdentityResult<HashMap<String, String>>;
@@ -33,18 +135,41 @@ pub trait TokenCredentialExecutor: DynClone + Debug {
         let extra_headers = self.extra_header_parameters();
         let extra_query_params = self.extra_query_parameters();
 
+        let grant_type = form.get("grant_type").cloned().unwrap_or_default();
+        let telemetry_headers = self
+            .app_config()
+            .token_credential_options
+            .telemetry
+            .then(|| {
+                let force_refresh =
+                    self.app_config().force_token_refresh != ForceTokenRefresh::Never;
+                self.app_config()
+                    .client_telemetry
+                    .headers(grant_type.as_str(), force_refresh)
+            });
+        token_requested(
+            self.app_config(),
+            grant_type.as_str(),
+            telemetry_headers.as_ref(),
+        );
+
         let mut auth_request = AuthorizationRequestParts::new(uri, form, basic_auth);
+        if let Some(telemetry_headers) = telemetry_headers.as_ref() {
+            auth_request.with_extra_headers(telemetry_headers);
+        }
         auth_request.with_extra_headers(extra_headers);
         auth_request.with_extra_query_parameters(extra_query_params);
 
         Ok(auth_request)
     }
 
+    /// Build the blocking token request. Uses the http client set with
+    /// `with_blocking_http_client` on the credential builder if one was given.
+    ///
+    /// The returned request builder can be changed before it is sent for requests
+    /// that the credential does not support.
     fn build_request(&mut self) -> AuthExecutionResult<reqwest::blocking::RequestBuilder> {
-        let http_client = reqwest::blocking::ClientBuilder::new()
-            .min_tls_version(Version::TLS_1_2)
-            .https_only(true)
-            .build()?;
+        let http_client = self.app_config().http_client.blocking_http_client()?;
 
         let auth_request = self.request_parts()?;
         let basic_auth = auth_request.basic_auth;
@@ -75,11 +200,13 @@ pub trait TokenCredentialExecutor: DynClone + Debug {
         }
     }
 
+    /// Build the async token request. Uses the http client set with `with_http_client`
+    /// on the credential builder if one was given.
+    ///
+    /// The returned request builder can be changed before it is sent for requests
+    /// that the credential does not support.
     fn build_request_async(&mut self) -> AuthExecutionResult<reqwest::RequestBuilder> {
-        let http_client = reqwest::ClientBuilder::new()
-            .min_tls_version(Version::TLS_1_2)
-            .https_only(true)
-            .build()?;
+        let http_client = self.app_config().http_client.http_client()?;
 
         let auth_request = self.request_parts()?;
         let basic_auth = auth_request.basic_auth;
@@ -133,7 +260,7 @@ pub trait TokenCredentialExecutor: DynClone + Debug {
     }
 
     fn issuer(&self) -> Result<Url, ParseError> {
-        self.azure_cloud_instance().issuer(&self.authority())
+        self.app_config().authority_endpoints().issuer()
     }
 
     fn extra_query_parameters(&self) -> &HashMap<String, String> {
@@ -141,18 +268,315 @@ pub trait TokenCredentialExecutor: DynClone + Debug {
     }
 
     fn execute(&mut self) -> AuthExecutionResult<reqwest::blocking::Response> {
-        let request_builder = self.build_request()?;
-        let response = request_builder.send()?;
+        // Azure AD B2C and ADFS hosts have no regional endpoints.
+        let is_b2c_or_adfs = self.authority().is_b2c() || self.authority().is_adfs();
+
+        if uses_instance_discovery(self.app_config()) {
+            let http_client = self.app_config().http_client.blocking_http_client()?;
+            InstanceDiscovery::validate_authority(&self.uri()?, &http_client)?;
+        }
+
+        if let Some(azure_region) = self
+            .app_config()
+            .azure_region
+            .clone()
+            .filter(|_| !is_b2c_or_adfs)
+        {
+            if is_client_credentials_grant(&self.form_urlencode()?) {
+                if let Some(region) = azure_region.resolve() {
+                    let regional_uri = AzureRegion::regional_uri(&region, &self.uri()?)?;
+                    let mut request = self.build_request()?.build()?;
+                    *request.url_mut() = regional_uri;
+
+                    let response = self
+                        .app_config()
+                        .http_client
+                        .blocking_http_client()?
+                        .execute(request);
+                    record_response(
+                        self.app_config(),
+                        response.as_ref().map(|response| response.status()),
+                    );
+
+                    match response {
+                        Ok(response) if !response.status().is_server_error() => {
+                            let status = response.status();
+                            tracing::debug!(target: CREDENTIAL_EXECUTOR, "regional authorization response received; region={region} status={status:#?}");
+                            return Ok(response);
+                        }
+                        _ => {
+                            tracing::debug!(target: CREDENTIAL_EXECUTOR, "regional token endpoint failed, falling back to the global token endpoint; region={region}");
+                        }
+                    }
+                }
+            }
+        }
+
+        let response = self.build_request()?.send();
+        record_response(
+            self.app_config(),
+            response.as_ref().map(|response| response.status()),
+        );
+
+        let response = response?;
         let status = response.status();
         tracing::debug!(target: CREDENTIAL_EXECUTOR, "authorization response received; status={status:#?}");
         Ok(response)
     }
 
     async fn execute_async(&mut self) -> AuthExecutionResult<reqwest::Response> {
-        let request_builder = self.build_request_async()?;
-        let response = request_builder.send().await?;
+        // Azure AD B2C and ADFS hosts have no regional endpoints.
+        let is_b2c_or_adfs = self.authority().is_b2c() || self.authority().is_adfs();
+
+        if uses_instance_discovery(self.app_config()) {
+            let uri = self.uri()?;
+            let http_client = self.app_config().http_client.http_client()?;
+            InstanceDiscovery::validate_authority_async(&uri, &http_client).await?;
+        }
+
+        if let Some(azure_region) = self
+            .app_config()
+            .azure_region
+            .clone()
+            .filter(|_| !is_b2c_or_adfs)
+        {
+            if is_client_credentials_grant(&self.form_urlencode()?) {
+                if let Some(region) = azure_region.resolve_async().await {
+                    let regional_uri = AzureRegion::regional_uri(&region, &self.uri()?)?;
+                    let mut request = self.build_request_async()?.build()?;
+                    *request.url_mut() = regional_uri;
+
+                    let response = self
+                        .app_config()
+                        .http_client
+                        .http_client()?
+                        .execute(request)
+                        .await;
+                    record_response(
+                        self.app_config(),
+                        response.as_ref().map(|response| response.status()),
+                    );
+
+                    match response {
+                        Ok(response) if !response.status().is_server_error() => {
+                            let status = response.status();
+                            tracing::debug!(target: CREDENTIAL_EXECUTOR, "regional authorization response received; region={region} status={status:#?}");
+                            return Ok(response);
+                        }
+                        _ => {
+                            tracing::debug!(target: CREDENTIAL_EXECUTOR, "regional token endpoint failed, falling back to the global token endpoint; region={region}");
+                        }
+                    }
+                }
+            }
+        }
+
+        let response = self.build_request_async()?.send().await;
+        record_response(
+            self.app_config(),
+            response.as_ref().map(|response| response.status()),
+        );
+
+        let response = response?;
         let status = response.status();
         tracing::debug!(target: CREDENTIAL_EXECUTOR, "authorization response received; status={status:#?}");
         Ok(response)
     }
+
+    /// Send the token request and deserialize the response into a [Token].
+    ///
+    /// Returns an error with the response of the token endpoint if the request failed.
+    /// The token is not stored in the token cache of the credential.
+    fn execute_token(&mut self) -> AuthExecutionResult<Token> {
+        let response = self.execute()?;
+
+        if !response.status().is_success() {
+            return Err(token_request_error(
+                self.app_config(),
+                response.into_http_response()?,
+            ));
+        }
+
+        let mut token: Token = response.json()?;
+        token.with_log_policy(self.app_config().log_policy);
+        Ok(token)
+    }
+
+    /// Send the token request and deserialize the response into a [Token].
+    ///
+    /// Returns an error with the response of the token endpoint if the request failed.
+    /// The token is not stored in the token cache of the credential.
+    async fn execute_token_async(&mut self) -> AuthExecutionResult<Token> {
+        let response = self.execute_async().await?;
+
+        if !response.status().is_success() {
+            return Err(token_request_error(
+                self.app_config(),
+                response.into_http_response_async().await?,
+            ));
+        }
+
+        let mut token: Token = response.json().await?;
+        token.with_log_policy(self.app_config().log_policy);
+        Ok(token)
+    }
+}
+
+#[async_trait]
+impl TokenCredentialExecutor for Box<dyn TokenCredentialExecutor + Send + Sync> {
+    fn uri(&mut self) -> IdentityResult<Url> {
+        self.as_mut().uri()
+    }
+
+    fn form_urlencode(&mut self) -> IdentityResult<HashMap<String, String>> {
+        self.as_mut().form_urlencode()
+    }
+
+    fn request_parts(&mut self) -> IdentityResult<AuthorizationRequestParts> {
+        self.as_mut().request_parts()
+    }
+
+    fn build_request(&mut self) -> AuthExecutionResult<reqwest::blocking::RequestBuilder> {
+        self.as_mut().build_request()
+    }
+
+    fn build_request_async(&mut self) -> AuthExecutionResult<reqwest::RequestBuilder> {
+        self.as_mut().build_request_async()
+    }
+
+    fn client_id(&self) -> &Uuid {
+        self.as_ref().client_id()
+    }
+
+    fn authority(&self) -> Authority {
+        self.as_ref().authority()
+    }
+
+    fn azure_cloud_instance(&self) -> AzureCloudInstance {
+        self.as_ref().azure_cloud_instance()
+    }
+
+    fn basic_auth(&self) -> Option<(String, String)> {
+        self.as_ref().basic_auth()
+    }
+
+    fn app_config(&self) -> &AppConfig {
+        self.as_ref().app_config()
+    }
+
+    fn extra_header_parameters(&self) -> &HeaderMap {
+        self.as_ref().extra_header_parameters()
+    }
+
+    fn issuer(&self) -> Result<Url, ParseError> {
+        self.as_ref().issuer()
+    }
+
+    fn extra_query_parameters(&self) -> &HashMap<String, String> {
+        self.as_ref().extra_query_parameters()
+    }
+
+    fn execute(&mut self) -> AuthExecutionResult<reqwest::blocking::Response> {
+        self.as_mut().execute()
+    }
+
+    async fn execute_async(&mut self) -> AuthExecutionResult<reqwest::Response> {
+        self.as_mut().execute_async().await
+    }
+
+    fn execute_token(&mut self) -> AuthExecutionResult<Token> {
+        self.as_mut().execute_token()
+    }
+
+    async fn execute_token_async(&mut self) -> AuthExecutionResult<Token> {
+        self.as_mut().execute_token_async().await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::identity::{
+        ClientSecretCredential, ClientSecretCredentialBuilder, ResourceOwnerPasswordCredential,
+    };
+
+    fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+    #[test]
+    fn heterogeneous_credentials() {
+        let client_id = Uuid::new_v4().to_string();
+        let mut credentials: Vec<Box<dyn TokenCredentialExecutor + Send + Sync>> = vec![
+            Box::new(ClientSecretCredential::new(client_id.as_str(), "secret")),
+            Box::new(ResourceOwnerPasswordCredential::new(
+                client_id.as_str(),
+                "username",
+                "password",
+            )),
+        ];
+        assert_send_sync(&credentials);
+
+        let grant_types: Vec<String> = credentials
+            .iter_mut()
+            .map(|credential| credential.form_urlencode().unwrap()["grant_type"].clone())
+            .collect();
+        assert_eq!(vec!["client_credentials", "password"], grant_types);
+
+        let mut credential = credentials.remove(0);
+        assert_eq!(client_id, credential.client_id().to_string());
+        credential = credentials.remove(0);
+        assert_eq!(
+            "password",
+            credential.form_urlencode().unwrap()["grant_type"]
+        );
+
+        let cloned = credential.clone();
+        assert_eq!(credential.client_id(), cloned.client_id());
+    }
+
+    #[test]
+    fn http_client_is_shared() {
+        let http_client = reqwest::Client::new();
+        let mut credential =
+            ClientSecretCredentialBuilder::new(Uuid::new_v4().to_string(), "secret")
+                .with_http_client(http_client)
+                .credential();
+        let cloned = credential.clone();
+        assert_eq!(
+            credential.app_config().http_client,
+            cloned.app_config().http_client
+        );
+        assert!(credential.build_request_async().is_ok());
+    }
+
+    #[test]
+    fn http_client_is_scoped_to_the_credential() {
+        let credential =
+            ClientSecretCredentialBuilder::new(Uuid::new_v4().to_string(), "secret").credential();
+        let other_credential =
+            ClientSecretCredentialBuilder::new(Uuid::new_v4().to_string(), "secret")
+                .with_blocking_http_client(reqwest::blocking::Client::new())
+                .credential();
+        assert_ne!(
+            credential.app_config().http_client,
+            other_credential.app_config().http_client
+        );
+        assert_eq!(
+            credential.app_config().http_client,
+            credential.clone().app_config().http_client
+        );
+    }
+
+    #[test]
+    fn instance_discovery_is_skipped_for_explicit_instance() {
+        let credential = ClientSecretCredentialBuilder::new(Uuid::new_v4().to_string(), "secret")
+            .with_tenant("tenant-id")
+            .credential();
+        assert!(uses_instance_discovery(credential.app_config()));
+
+        let credential = ClientSecretCredentialBuilder::new(Uuid::new_v4().to_string(), "secret")
+            .with_tenant("tenant-id")
+            .with_instance(Url::parse("https://login.private.cloud.example").unwrap())
+            .credential();
+        assert!(!uses_instance_discovery(credential.app_config()));
+    }
 }
@@ -0,0 +1,121 @@
+use http::HeaderMap;
+use time::OffsetDateTime;
+
+use graph_error::AuthExecutionError;
+
+use crate::identity::{
+    client_request_id, record_auth_event, tracing_targets::TOKEN_LIFECYCLE, AppConfig,
+    AuthEventKind, Token,
+};
+
+/// AADSTS code returned when the user or an administrator has not consented
+/// to the requested permissions.
+const CONSENT_REQUIRED_CODE: i64 = 65001;
+
+/// Seconds until the token expires. Negative when the token has expired.
+fn expires_in(token: &Token) -> i64 {
+    token
+        .expires_on
+        .map(|expires_on| (expires_on - OffsetDateTime::now_utc()).whole_seconds())
+        .unwrap_or(token.expires_in)
+}
+
+/// A new token was returned by the token endpoint. `refreshed` is true when the
+/// token was acquired using a refresh token.
+pub(crate) fn token_acquired(app_config: &AppConfig, token: &Token, refreshed: bool) {
+    let client_id = app_config.client_id.to_string();
+    tracing::debug!(
+        target: TOKEN_LIFECYCLE,
+        client_id = app_config.log_policy.redact(client_id.as_str()),
+        expires_in = token.expires_in,
+        scope = %token.scope.join(" "),
+        refresh_token = token.refresh_token.is_some(),
+        "{}",
+        if refreshed { "token refreshed" } else { "token acquired" }
+    );
+    let kind = if refreshed {
+        AuthEventKind::TokenRefreshed
+    } else {
+        AuthEventKind::TokenAcquired
+    };
+    record_auth_event(app_config, kind, None);
+}
+
+/// A token request with the given grant type is about to be sent. The headers are
+/// the telemetry headers of the request, if any, which carry the correlation id.
+pub(crate) fn token_requested(
+    app_config: &AppConfig,
+    grant_type: &str,
+    headers: Option<&HeaderMap>,
+) {
+    record_auth_event(
+        app_config,
+        AuthEventKind::TokenRequested {
+            grant_type: grant_type.to_owned(),
+        },
+        headers
+            .and_then(client_request_id)
+            .map(|correlation_id| correlation_id.to_owned()),
+    );
+}
+
+/// A token request of an application failed. Returns the error so that it can be
+/// used in `map_err`.
+pub(crate) fn token_request_failed(
+    app_config: &AppConfig,
+    error: AuthExecutionError,
+) -> AuthExecutionError {
+    if let Some(error_response) = error.msal_error_response() {
+        let consent_required = error_response.error.eq("consent_required")
+            || error_response.suberror.as_deref() == Some("consent_required")
+            || error_response.aadsts_code() == Some(CONSENT_REQUIRED_CODE);
+        if consent_required {
+            record_auth_event(
+                app_config,
+                AuthEventKind::ConsentRequired {
+                    error: error_response.error.clone(),
+                },
+                error_response.correlation_id.clone(),
+            );
+        }
+    }
+    error
+}
+
+/// The sign in page was shown to the user.
+#[cfg(feature = "interactive-auth")]
+pub(crate) fn interactive_prompt_shown(app_config: &AppConfig) {
+    record_auth_event(app_config, AuthEventKind::InteractivePromptShown, None);
+}
+
+pub(crate) fn cache_hit(app_config: &AppConfig, token: &Token) {
+    let client_id = app_config.client_id.to_string();
+    tracing::debug!(
+        target: TOKEN_LIFECYCLE,
+        client_id = app_config.log_policy.redact(client_id.as_str()),
+        expires_in = expires_in(token),
+        "token cache hit"
+    );
+    record_auth_event(app_config, AuthEventKind::CacheHit, None);
+}
+
+pub(crate) fn cache_miss(app_config: &AppConfig) {
+    let client_id = app_config.client_id.to_string();
+    tracing::debug!(
+        target: TOKEN_LIFECYCLE,
+        client_id = app_config.log_policy.redact(client_id.as_str()),
+        "token cache miss"
+    );
+}
+
+/// The cached token expired or expires within the refresh window.
+pub(crate) fn token_expired(app_config: &AppConfig, token: &Token) {
+    let client_id = app_config.client_id.to_string();
+    tracing::debug!(
+        target: TOKEN_LIFECYCLE,
+        client_id = app_config.log_policy.redact(client_id.as_str()),
+        expires_in = expires_in(token),
+        refresh_token = token.refresh_token.is_some(),
+        "cached token expired"
+    );
+}
@@ -0,0 +1,138 @@
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Arc, Condvar, Mutex, OnceLock, PoisonError, Weak};
+
+use tokio::sync::Notify;
+use uuid::Uuid;
+
+use crate::identity::AppConfig;
+
+/// Applications with the same client id, tenant, and scopes share a lock.
+type TokenRequestKey = (Uuid, String, BTreeSet<String>);
+
+static TOKEN_REQUEST_LOCKS: OnceLock<Mutex<HashMap<TokenRequestKey, Weak<TokenRequestLock>>>> =
+    OnceLock::new();
+
+/// Coalesces concurrent token requests for the same client id, tenant, and scopes
+/// into a single request to the token endpoint.
+///
+/// Blocking and async callers wait on the same lock. Blocking callers wait on a
+/// [Condvar] so that they can be made from within a tokio runtime, where
+/// [tokio::sync::Mutex::blocking_lock] panics, and async callers wait on a [Notify].
+#[derive(Debug, Default)]
+pub(crate) struct TokenRequestLock {
+    locked: Mutex<bool>,
+    blocking_waiters: Condvar,
+    non_blocking_waiters: Notify,
+}
+
+/// Releases the [TokenRequestLock] when dropped.
+#[derive(Debug)]
+pub(crate) struct TokenRequestGuard<'a> {
+    lock: &'a TokenRequestLock,
+}
+
+impl TokenRequestLock {
+    /// The lock shared by all applications with the client id, tenant, and scopes
+    /// of the app config.
+    pub(crate) fn for_app_config(app_config: &AppConfig) -> Arc<TokenRequestLock> {
+        let key = (
+            app_config.client_id,
+            app_config.authority.to_string(),
+            app_config.scope.clone(),
+        );
+        let mut locks = TOKEN_REQUEST_LOCKS
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        if let Some(lock) = locks.get(&key).and_then(Weak::upgrade) {
+            return lock;
+        }
+
+        locks.retain(|_, lock| lock.strong_count() > 0);
+        let lock = Arc::new(TokenRequestLock::default());
+        locks.insert(key, Arc::downgrade(&lock));
+        lock
+    }
+
+    fn try_lock(&self) -> Option<TokenRequestGuard<'_>> {
+        let mut locked = self.locked.lock().unwrap_or_else(PoisonError::into_inner);
+        if *locked {
+            None
+        } else {
+            *locked = true;
+            Some(TokenRequestGuard { lock: self })
+        }
+    }
+
+    pub(crate) fn lock(&self) -> TokenRequestGuard<'_> {
+        let mut locked = self.locked.lock().unwrap_or_else(PoisonError::into_inner);
+        while *locked {
+            locked = self
+                .blocking_waiters
+                .wait(locked)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+        *locked = true;
+        TokenRequestGuard { lock: self }
+    }
+
+    pub(crate) async fn lock_async(&self) -> TokenRequestGuard<'_> {
+        loop {
+            // Created before trying the lock so that a release in between is not missed.
+            let notified = self.non_blocking_waiters.notified();
+            if let Some(guard) = self.try_lock() {
+                return guard;
+            }
+            notified.await;
+        }
+    }
+}
+
+impl Drop for TokenRequestGuard<'_> {
+    fn drop(&mut self) {
+        *self
+            .lock
+            .locked
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = false;
+        self.lock.blocking_waiters.notify_one();
+        self.lock.non_blocking_waiters.notify_waiters();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn apps_with_same_client_tenant_and_scopes_share_lock() {
+        let mut app_config = AppConfig::new(Uuid::new_v4());
+        app_config.with_tenant("tenant");
+        app_config.with_scope(["User.Read"]);
+        let lock = TokenRequestLock::for_app_config(&app_config);
+        assert!(Arc::ptr_eq(
+            &lock,
+            &TokenRequestLock::for_app_config(&app_config)
+        ));
+
+        app_config.with_scope(["Mail.Read"]);
+        assert!(!Arc::ptr_eq(
+            &lock,
+            &TokenRequestLock::for_app_config(&app_config)
+        ));
+    }
+
+    #[tokio::test]
+    async fn blocking_and_async_callers_share_lock() {
+        let lock = TokenRequestLock::default();
+        let guard = lock.lock();
+        assert!(lock.try_lock().is_none());
+        drop(guard);
+
+        let guard = lock.lock_async().await;
+        assert!(lock.try_lock().is_none());
+        drop(guard);
+        assert!(lock.try_lock().is_some());
+    }
+}
@@ -1,8 +1,10 @@
 use std::collections::HashMap;
+use std::path::Path;
 
 use base64::engine::general_purpose::URL_SAFE_NO_PAD;
 use base64::Engine;
 use graph_error::{IdentityResult, AF};
+use openssl::asn1::{Asn1Time, Asn1TimeRef};
 use openssl::error::ErrorStack;
 use openssl::hash::MessageDigest;
 use openssl::pkcs12::{ParsedPkcs12_2, Pkcs12};
@@ -35,6 +37,37 @@ fn thumbprint(cert: &X509) -> IdentityResult<String> {
     Ok(URL_SAFE_NO_PAD.encode(digest_bytes))
 }
 
+fn read_file(path: &Path) -> IdentityResult<Vec<u8>> {
+    std::fs::read(path).map_err(|err| AF::x509(format!("{}: {err}", path.display())))
+}
+
+fn asn1_time_to_offset_date_time(time: &Asn1TimeRef) -> IdentityResult<OffsetDateTime> {
+    let now = Asn1Time::days_from_now(0).map_err(|err| AF::x509(err.to_string()))?;
+    let diff = now.diff(time).map_err(|err| AF::x509(err.to_string()))?;
+    Ok(OffsetDateTime::now_utc()
+        + time::Duration::days(diff.days as i64)
+        + time::Duration::seconds(diff.secs as i64))
+}
+
+fn validate_expiry(cert: &X509Ref) -> IdentityResult<()> {
+    let now = Asn1Time::days_from_now(0).map_err(|err| AF::x509(err.to_string()))?;
+    if cert.not_before() > now {
+        return Err(AF::x509(format!(
+            "certificate is not valid before {}",
+            cert.not_before()
+        )));
+    }
+
+    if cert.not_after() < now {
+        return Err(AF::x509(format!(
+            "certificate expired on {}",
+            cert.not_after()
+        )));
+    }
+
+    Ok(())
+}
+
 /// Computes the client assertion used in certificate credential authorization flows.
 /// The client assertion is computed from the DER encoding of an X509 certificate and it's private key.
 ///
@@ -160,6 +193,137 @@ impl X509Certificate {
         })
     }
 
+    /// Load the certificate and private key from a PKCS#12 (.pfx or .p12) file.
+    ///
+    /// Returns an error if the certificate has expired or is not yet valid.
+    pub fn from_pkcs12_file(
+        client_id: impl AsRef<str>,
+        path: impl AsRef<Path>,
+        password: impl AsRef<str>,
+    ) -> IdentityResult<Self> {
+        X509Certificate::from_pkcs12(client_id, None, path.as_ref(), password.as_ref())
+    }
+
+    /// Load the certificate and private key from a PKCS#12 (.pfx or .p12) file.
+    ///
+    /// Returns an error if the certificate has expired or is not yet valid.
+    pub fn from_pkcs12_file_with_tenant(
+        client_id: impl AsRef<str>,
+        tenant_id: impl AsRef<str>,
+        path: impl AsRef<Path>,
+        password: impl AsRef<str>,
+    ) -> IdentityResult<Self> {
+        X509Certificate::from_pkcs12(
+            client_id,
+            Some(tenant_id.as_ref().to_owned()),
+            path.as_ref(),
+            password.as_ref(),
+        )
+    }
+
+    fn from_pkcs12(
+        client_id: impl AsRef<str>,
+        tenant_id: Option<String>,
+        path: &Path,
+        password: &str,
+    ) -> IdentityResult<Self> {
+        let parsed_pkcs12 = Pkcs12::from_der(&read_file(path)?)
+            .map_err(|err| AF::x509(err.to_string()))?
+            .parse2(password)
+            .map_err(|err| AF::x509(err.to_string()))?;
+
+        let certificate = parsed_pkcs12.cert.clone().ok_or(AF::x509(
+            "No certificate found after parsing Pkcs12 using pass",
+        ))?;
+        let private_key = parsed_pkcs12.pkey.clone().ok_or(AF::x509(
+            "No private key found after parsing Pkcs12 using pass",
+        ))?;
+        validate_expiry(&certificate)?;
+
+        Ok(Self {
+            client_id: client_id.as_ref().to_owned(),
+            tenant_id,
+            claims: None,
+            extend_claims: true,
+            certificate,
+            certificate_chain: true,
+            pkey: private_key,
+            parsed_pkcs12: Some(parsed_pkcs12),
+            uuid: Uuid::new_v4(),
+        })
+    }
+
+    /// Load the certificate and private key from PEM encoded files.
+    ///
+    /// The certificate file may contain the certificate chain in which case the first
+    /// certificate must be the certificate of the application. Returns an error if the
+    /// private key does not belong to the certificate or if the certificate has expired
+    /// or is not yet valid.
+    pub fn from_pem_files(
+        client_id: impl AsRef<str>,
+        certificate_path: impl AsRef<Path>,
+        private_key_path: impl AsRef<Path>,
+    ) -> IdentityResult<Self> {
+        X509Certificate::from_pem(
+            client_id,
+            None,
+            certificate_path.as_ref(),
+            private_key_path.as_ref(),
+        )
+    }
+
+    /// Load the certificate and private key from PEM encoded files.
+    ///
+    /// See [X509Certificate::from_pem_files]
+    pub fn from_pem_files_with_tenant(
+        client_id: impl AsRef<str>,
+        tenant_id: impl AsRef<str>,
+        certificate_path: impl AsRef<Path>,
+        private_key_path: impl AsRef<Path>,
+    ) -> IdentityResult<Self> {
+        X509Certificate::from_pem(
+            client_id,
+            Some(tenant_id.as_ref().to_owned()),
+            certificate_path.as_ref(),
+            private_key_path.as_ref(),
+        )
+    }
+
+    fn from_pem(
+        client_id: impl AsRef<str>,
+        tenant_id: Option<String>,
+        certificate_path: &Path,
+        private_key_path: &Path,
+    ) -> IdentityResult<Self> {
+        let certificate = X509::stack_from_pem(&read_file(certificate_path)?)
+            .map_err(|err| AF::x509(err.to_string()))?
+            .into_iter()
+            .next()
+            .ok_or(AF::x509("No certificate found in PEM file"))?;
+        let private_key = PKey::private_key_from_pem(&read_file(private_key_path)?)
+            .map_err(|err| AF::x509(err.to_string()))?;
+
+        let public_key = certificate
+            .public_key()
+            .map_err(|err| AF::x509(err.to_string()))?;
+        if !public_key.public_eq(&private_key) {
+            return Err(AF::x509("Private key does not match the certificate"));
+        }
+        validate_expiry(&certificate)?;
+
+        Ok(Self {
+            client_id: client_id.as_ref().to_owned(),
+            tenant_id,
+            claims: None,
+            extend_claims: true,
+            certificate,
+            certificate_chain: false,
+            pkey: private_key,
+            parsed_pkcs12: None,
+            uuid: Uuid::new_v4(),
+        })
+    }
+
     /// Provide your own set of claims in the payload of the JWT.
     ///
     /// Replace the claims that would be generated for the client assertion.
@@ -209,6 +373,23 @@ impl X509Certificate {
         Ok(URL_SAFE_NO_PAD.encode(digest_bytes))
     }
 
+    /// The date and time after which the certificate is no longer valid.
+    pub fn expires_on(&self) -> IdentityResult<OffsetDateTime> {
+        asn1_time_to_offset_date_time(self.certificate.not_after())
+    }
+
+    /// Returns true if the certificate has expired.
+    pub fn is_expired(&self) -> bool {
+        Asn1Time::days_from_now(0)
+            .map(|now| self.certificate.not_after() < now)
+            .unwrap_or(true)
+    }
+
+    /// Returns an error if the certificate has expired or is not yet valid.
+    pub fn validate_expiry(&self) -> IdentityResult<()> {
+        validate_expiry(&self.certificate)
+    }
+
     /// Get the value used for the jti field in the payload. This field is computed
     /// when constructing the [X509Certificate] and will be different from any
     /// custom claims provided.
@@ -425,4 +606,85 @@ mod test {
         let certificate = X509Certificate::new("client_id", cert, private_key);
         assert!(certificate.sign_with_tenant(None).is_ok());
     }
+
+    fn self_signed_certificate() -> (X509, PKey<Private>) {
+        let private_key = PKey::from_rsa(openssl::rsa::Rsa::generate(2048).unwrap()).unwrap();
+        let mut name = openssl::x509::X509NameBuilder::new().unwrap();
+        name.append_entry_by_text("CN", "graph-rs-sdk").unwrap();
+        let name = name.build();
+
+        let mut builder = X509::builder().unwrap();
+        builder.set_version(2).unwrap();
+        builder.set_subject_name(&name).unwrap();
+        builder.set_issuer_name(&name).unwrap();
+        builder.set_pubkey(&private_key).unwrap();
+        builder
+            .set_not_before(&Asn1Time::days_from_now(0).unwrap())
+            .unwrap();
+        builder
+            .set_not_after(&Asn1Time::days_from_now(30).unwrap())
+            .unwrap();
+        builder.sign(&private_key, MessageDigest::sha256()).unwrap();
+        (builder.build(), private_key)
+    }
+
+    fn temp_file(name: &str, contents: &[u8]) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("{}-{name}", Uuid::new_v4()));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    pub fn from_pem_files() {
+        let (cert, private_key) = self_signed_certificate();
+        let cert_path = temp_file("cert.pem", &cert.to_pem().unwrap());
+        let key_path = temp_file("key.pem", &private_key.private_key_to_pem_pkcs8().unwrap());
+
+        let certificate =
+            X509Certificate::from_pem_files("client_id", &cert_path, &key_path).unwrap();
+        assert!(!certificate.is_expired());
+        assert!(certificate.expires_on().unwrap() > OffsetDateTime::now_utc());
+        assert_eq!(
+            hex::encode(cert.digest(MessageDigest::sha1()).unwrap()).to_uppercase(),
+            certificate.get_hex_thumbprint().unwrap()
+        );
+        assert!(certificate.sign().is_ok());
+
+        let (_, other_key) = self_signed_certificate();
+        let other_key_path = temp_file(
+            "other-key.pem",
+            &other_key.private_key_to_pem_pkcs8().unwrap(),
+        );
+        assert!(X509Certificate::from_pem_files("client_id", &cert_path, &other_key_path).is_err());
+    }
+
+    #[test]
+    pub fn from_pem_files_expired() {
+        let cert_path = temp_file("expired-cert.pem", include_bytes!("test/cert.pem"));
+        let key_path = temp_file("expired-key.pem", include_bytes!("test/key.pem"));
+
+        let cert = X509::from_pem(include_bytes!("test/cert.pem")).unwrap();
+        let private_key = PKey::private_key_from_pem(include_bytes!("test/key.pem")).unwrap();
+        assert!(X509Certificate::new("client_id", cert, private_key).is_expired());
+        assert!(X509Certificate::from_pem_files("client_id", &cert_path, &key_path).is_err());
+    }
+
+    #[test]
+    pub fn from_pkcs12_file() {
+        let (cert, private_key) = self_signed_certificate();
+        let pkcs12 = Pkcs12::builder()
+            .name("graph-rs-sdk")
+            .pkey(&private_key)
+            .cert(&cert)
+            .build2("password")
+            .unwrap();
+        let path = temp_file("cert.pfx", &pkcs12.to_der().unwrap());
+
+        let certificate =
+            X509Certificate::from_pkcs12_file_with_tenant("client_id", "tenant", &path, "password")
+                .unwrap();
+        assert!(certificate.validate_expiry().is_ok());
+        assert!(certificate.sign().is_ok());
+        assert!(X509Certificate::from_pkcs12_file("client_id", &path, "wrong").is_err());
+    }
 }
@@ -1,12 +1,24 @@
 use std::collections::{BTreeSet, HashMap};
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
+use std::time::Duration;
 
 use serde_json::Value;
 
+use graph_core::identity::LogPolicy;
+use graph_error::MsalErrorResponse;
+
+use crate::identity::Token;
+
 #[cfg(feature = "interactive-auth")]
 use graph_core::http::JsonHttpResponse;
 
+#[cfg(feature = "qr")]
+use graph_error::{AuthorizationFailure, AF};
+
+#[cfg(feature = "qr")]
+use qrcode::{render::svg, render::unicode, QrCode};
+
 #[cfg(feature = "interactive-auth")]
 use crate::interactive::WindowCloseReason;
 
@@ -85,6 +97,48 @@ impl Display for DeviceAuthorizationResponse {
     }
 }
 
+impl DeviceAuthorizationResponse {
+    /// The uri that the user should open to sign in. This is the verification_uri_complete
+    /// when it is included in the response and otherwise the verification_uri.
+    pub fn sign_in_uri(&self) -> &str {
+        self.verification_uri_complete
+            .as_deref()
+            .unwrap_or(self.verification_uri.as_str())
+    }
+
+    #[cfg(feature = "qr")]
+    fn qr_code(&self) -> Result<QrCode, AuthorizationFailure> {
+        QrCode::new(self.sign_in_uri().as_bytes())
+            .map_err(|err| AF::msg_err("verification_uri", err.to_string().as_str()))
+    }
+
+    /// Render the sign in uri as a QR code using unicode half blocks that can be
+    /// printed to a terminal.
+    ///
+    /// Requires feature `qr`
+    #[cfg(feature = "qr")]
+    pub fn to_qr_terminal(&self) -> Result<String, AuthorizationFailure> {
+        Ok(self
+            .qr_code()?
+            .render::<unicode::Dense1x2>()
+            .dark_color(unicode::Dense1x2::Light)
+            .light_color(unicode::Dense1x2::Dark)
+            .build())
+    }
+
+    /// Render the sign in uri as an SVG image of a QR code.
+    ///
+    /// Requires feature `qr`
+    #[cfg(feature = "qr")]
+    pub fn to_qr_svg(&self, min_size: u32) -> Result<String, AuthorizationFailure> {
+        Ok(self
+            .qr_code()?
+            .render::<svg::Color>()
+            .min_dimensions(min_size, min_size)
+            .build())
+    }
+}
+
 /// Response types used when polling for a device code
 /// https://datatracker.ietf.org/doc/html/rfc8628#section-3.5
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
@@ -157,6 +211,88 @@ impl Display for PollDeviceCodeEvent {
     }
 }
 
+/// The state of the device code flow sent for each poll of the token endpoint by
+/// [DeviceCodePollingExecutor::poll_events](crate::identity::DeviceCodePollingExecutor::poll_events)
+/// so that apps can show progress and tell a user that declined apart from a device
+/// code that expired.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DeviceCodePollEvent {
+    /// The user hasn't finished authenticating. Polling continues.
+    AuthorizationPending,
+    /// The interval between polls was increased. Polling continues.
+    SlowDown { interval: Duration },
+    /// The user declined the authorization request. Polling stops.
+    Declined,
+    /// The device code expired before the user authenticated. Polling stops.
+    Expired,
+    /// The device code wasn't recognized by the token endpoint. Polling stops.
+    BadVerificationCode,
+    /// The user authenticated and the token was issued. Polling stops.
+    Success(Box<Token>),
+    /// The token endpoint returned an error that is not part of the device code flow.
+    /// Polling stops.
+    Failed(Box<MsalErrorResponse>),
+    /// The token request could not be sent or its response could not be read, such as
+    /// when the network is unavailable. Polling stops.
+    RequestFailed(String),
+}
+
+impl DeviceCodePollEvent {
+    /// Returns true if polling stops after this event. Only `authorization_pending`
+    /// and `slow_down` continue polling.
+    pub fn is_terminal(&self) -> bool {
+        !matches!(
+            self,
+            DeviceCodePollEvent::AuthorizationPending | DeviceCodePollEvent::SlowDown { .. }
+        )
+    }
+
+    /// Create the event from the status and json body of a token request.
+    ///
+    /// The interval is increased by 5 seconds when the token endpoint asks to slow down.
+    pub(crate) fn from_response(
+        is_success: bool,
+        json: Option<Value>,
+        interval: &mut Duration,
+        log_policy: LogPolicy,
+    ) -> DeviceCodePollEvent {
+        let json = json.unwrap_or_default();
+        if is_success {
+            return match serde_json::from_value::<Token>(json) {
+                Ok(mut token) => {
+                    token.with_log_policy(log_policy);
+                    DeviceCodePollEvent::Success(Box::new(token))
+                }
+                Err(err) => DeviceCodePollEvent::Failed(Box::new(MsalErrorResponse {
+                    error: "invalid_token_response".into(),
+                    error_description: Some(err.to_string()),
+                    ..Default::default()
+                })),
+            };
+        }
+
+        let error_response = MsalErrorResponse::from_json(&json).unwrap_or_default();
+        match PollDeviceCodeEvent::from_str(error_response.error.as_str()) {
+            Ok(PollDeviceCodeEvent::AuthorizationPending) => {
+                DeviceCodePollEvent::AuthorizationPending
+            }
+            Ok(PollDeviceCodeEvent::SlowDown) => {
+                *interval += Duration::from_secs(5);
+                DeviceCodePollEvent::SlowDown {
+                    interval: *interval,
+                }
+            }
+            Ok(PollDeviceCodeEvent::AuthorizationDeclined)
+            | Ok(PollDeviceCodeEvent::AccessDenied) => DeviceCodePollEvent::Declined,
+            Ok(PollDeviceCodeEvent::ExpiredToken) => DeviceCodePollEvent::Expired,
+            Ok(PollDeviceCodeEvent::BadVerificationCode) => {
+                DeviceCodePollEvent::BadVerificationCode
+            }
+            Err(_) => DeviceCodePollEvent::Failed(Box::new(error_response)),
+        }
+    }
+}
+
 #[cfg(feature = "interactive-auth")]
 #[derive(Debug)]
 pub enum InteractiveDeviceCodeEvent {
@@ -174,3 +310,119 @@ pub enum InteractiveDeviceCodeEvent {
         public_application: PublicClientApplication<DeviceCodeCredential>,
     },
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn poll_event_from_error_response() {
+        let mut interval = Duration::from_secs(5);
+
+        let event = DeviceCodePollEvent::from_response(
+            false,
+            Some(serde_json::json!({ "error": "authorization_pending" })),
+            &mut interval,
+            LogPolicy::default(),
+        );
+        assert_eq!(DeviceCodePollEvent::AuthorizationPending, event);
+        assert!(!event.is_terminal());
+
+        let event = DeviceCodePollEvent::from_response(
+            false,
+            Some(serde_json::json!({ "error": "slow_down" })),
+            &mut interval,
+            LogPolicy::default(),
+        );
+        assert_eq!(
+            DeviceCodePollEvent::SlowDown {
+                interval: Duration::from_secs(10)
+            },
+            event
+        );
+
+        let event = DeviceCodePollEvent::from_response(
+            false,
+            Some(serde_json::json!({ "error": "authorization_declined" })),
+            &mut interval,
+            LogPolicy::default(),
+        );
+        assert_eq!(DeviceCodePollEvent::Declined, event);
+        assert!(event.is_terminal());
+
+        let event = DeviceCodePollEvent::from_response(
+            false,
+            Some(serde_json::json!({ "error": "invalid_client", "error_codes": [7000215] })),
+            &mut interval,
+            LogPolicy::default(),
+        );
+        assert!(matches!(event, DeviceCodePollEvent::Failed(_)));
+    }
+
+    #[test]
+    fn poll_event_stops_polling() {
+        let mut interval = Duration::from_secs(5);
+
+        for (error, expected) in [
+            ("expired_token", DeviceCodePollEvent::Expired),
+            ("access_denied", DeviceCodePollEvent::Declined),
+            (
+                "bad_verification_code",
+                DeviceCodePollEvent::BadVerificationCode,
+            ),
+        ] {
+            let event = DeviceCodePollEvent::from_response(
+                false,
+                Some(serde_json::json!({ "error": error })),
+                &mut interval,
+            );
+            assert_eq!(expected, event);
+            assert!(event.is_terminal());
+        }
+
+        assert!(DeviceCodePollEvent::RequestFailed("connection refused".into()).is_terminal());
+        assert!(!DeviceCodePollEvent::SlowDown { interval }.is_terminal());
+    }
+
+    #[test]
+    fn poll_event_from_token_response() {
+        let mut interval = Duration::from_secs(5);
+        let event = DeviceCodePollEvent::from_response(
+            true,
+            Some(serde_json::json!({
+                "token_type": "Bearer",
+                "scope": "User.Read",
+                "expires_in": 3600,
+                "access_token": "ACCESS_TOKEN"
+            })),
+            &mut interval,
+            LogPolicy::None,
+        );
+
+        match event {
+            DeviceCodePollEvent::Success(token) => {
+                assert_eq!("ACCESS_TOKEN", token.access_token.expose_secret());
+                assert_eq!(LogPolicy::None, token.log_policy);
+            }
+            _ => panic!("expected a token"),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "qr")]
+    fn sign_in_uri_qr_code() {
+        let response: DeviceAuthorizationResponse = serde_json::from_value(serde_json::json!({
+            "device_code": "DEVICE_CODE",
+            "expires_in": 900,
+            "message": "To sign in, use a web browser to open the page https://microsoft.com/devicelogin and enter the code FQK5HW3UF to authenticate.",
+            "user_code": "FQK5HW3UF",
+            "verification_uri": "https://microsoft.com/devicelogin",
+        }))
+        .unwrap();
+        assert_eq!("https://microsoft.com/devicelogin", response.sign_in_uri());
+
+        let svg = response.to_qr_svg(200).unwrap();
+        assert!(svg.contains("<svg"));
+        assert!(!response.to_qr_terminal().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,442 @@
+use std::collections::BTreeMap;
+use std::fmt::{Debug, Formatter};
+use std::num::NonZeroU32;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN};
+use ring::rand::{SecureRandom, SystemRandom};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use graph_core::cache::{CacheStore, InMemoryCacheStore};
+use graph_error::{IdentityResult, AF};
+
+use crate::identity::{tracing_targets::CREDENTIAL_EXECUTOR, SecretString, Token};
+
+const MAGIC: &[u8; 4] = b"GRTC";
+const FORMAT_VERSION: u8 = 1;
+/// Magic, format version, and key version.
+const HEADER_LEN: usize = 9;
+const KEY_LEN: usize = 32;
+const PBKDF2_ITERATIONS: u32 = 600_000;
+
+/// A 256-bit key used to encrypt an [EncryptedFileTokenCache]. The version of the key is
+/// written to the cache file so that the key used to encrypt the file can be found
+/// when keys are rotated. The key is zeroized when it is dropped.
+#[derive(Eq, PartialEq, Zeroize, ZeroizeOnDrop)]
+pub struct TokenCacheKey {
+    version: u32,
+    key: [u8; KEY_LEN],
+}
+
+impl TokenCacheKey {
+    pub fn from_bytes(version: u32, key: [u8; KEY_LEN]) -> TokenCacheKey {
+        TokenCacheKey { version, key }
+    }
+
+    /// Generate a random key.
+    pub fn generate(version: u32) -> IdentityResult<TokenCacheKey> {
+        let mut key = TokenCacheKey {
+            version,
+            key: [0u8; KEY_LEN],
+        };
+        SystemRandom::new()
+            .fill(&mut key.key)
+            .map_err(|_| AF::msg_err("token_cache", "ring::error::Unspecified"))?;
+        Ok(key)
+    }
+
+    /// Derive the key from a user supplied secret, such as a password, and a salt
+    /// using PBKDF2-HMAC-SHA256.
+    pub fn from_secret(
+        version: u32,
+        secret: impl AsRef<[u8]>,
+        salt: impl AsRef<[u8]>,
+    ) -> TokenCacheKey {
+        let mut key = TokenCacheKey {
+            version,
+            key: [0u8; KEY_LEN],
+        };
+        ring::pbkdf2::derive(
+            ring::pbkdf2::PBKDF2_HMAC_SHA256,
+            NonZeroU32::new(PBKDF2_ITERATIONS).unwrap(),
+            salt.as_ref(),
+            secret.as_ref(),
+            &mut key.key,
+        );
+        key
+    }
+
+    /// Get the key from the credential store of the operating system (DPAPI, Keychain, or
+    /// the Secret Service) or generate and store a new random key if there is none.
+    /// The key never leaves the device.
+    ///
+    /// Requires feature `platform-keyring`
+    #[cfg(feature = "platform-keyring")]
+    pub fn from_keyring(service: impl AsRef<str>, version: u32) -> IdentityResult<TokenCacheKey> {
+        use base64::Engine;
+
+        let engine = base64::engine::general_purpose::STANDARD;
+        let entry = keyring::Entry::new(
+            service.as_ref(),
+            format!("token-cache-key-v{version}").as_str(),
+        )
+        .map_err(|err| AF::msg_err("keyring", err.to_string().as_str()))?;
+
+        match entry.get_password() {
+            Ok(encoded) => {
+                let key: [u8; KEY_LEN] = engine
+                    .decode(encoded)
+                    .ok()
+                    .and_then(|key| key.try_into().ok())
+                    .ok_or_else(|| AF::msg_err("keyring", "invalid token cache key"))?;
+                Ok(TokenCacheKey { version, key })
+            }
+            Err(keyring::Error::NoEntry) => {
+                let key = TokenCacheKey::generate(version)?;
+                entry
+                    .set_password(engine.encode(key.key).as_str())
+                    .map_err(|err| AF::msg_err("keyring", err.to_string().as_str()))?;
+                Ok(key)
+            }
+            Err(err) => AF::msg_result("keyring", err),
+        }
+    }
+
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    fn aead_key(&self) -> IdentityResult<LessSafeKey> {
+        UnboundKey::new(&AES_256_GCM, &self.key)
+            .map(LessSafeKey::new)
+            .map_err(|_| AF::msg_err("token_cache", "ring::error::Unspecified"))
+    }
+
+    /// Header, nonce, and ciphertext with the tag. The header is authenticated.
+    fn seal(&self, plaintext: &[u8]) -> IdentityResult<Vec<u8>> {
+        let mut nonce = [0u8; NONCE_LEN];
+        SystemRandom::new()
+            .fill(&mut nonce)
+            .map_err(|_| AF::msg_err("token_cache", "ring::error::Unspecified"))?;
+
+        let mut header = Vec::with_capacity(HEADER_LEN);
+        header.extend_from_slice(MAGIC);
+        header.push(FORMAT_VERSION);
+        header.extend_from_slice(&self.version.to_be_bytes());
+
+        let mut in_out = plaintext.to_vec();
+        self.aead_key()?
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(header.as_slice()),
+                &mut in_out,
+            )
+            .map_err(|_| AF::msg_err("token_cache", "unable to encrypt token cache"))?;
+
+        let mut sealed = header;
+        sealed.extend_from_slice(&nonce);
+        sealed.extend_from_slice(&in_out);
+        Ok(sealed)
+    }
+
+    fn open(&self, sealed: &[u8]) -> IdentityResult<Vec<u8>> {
+        let (header, rest) = sealed.split_at(HEADER_LEN);
+        let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(nonce)
+            .map_err(|_| AF::msg_err("token_cache", "invalid token cache nonce"))?;
+
+        let mut in_out = ciphertext.to_vec();
+        let plaintext = self
+            .aead_key()?
+            .open_in_place(nonce, Aad::from(header), &mut in_out)
+            .map_err(|_| {
+                AF::msg_err(
+                    "token_cache",
+                    "unable to decrypt token cache - the key is wrong or the file was modified",
+                )
+            })?;
+        Ok(plaintext.to_vec())
+    }
+}
+
+impl Debug for TokenCacheKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TokenCacheKey")
+            .field("version", &self.version)
+            .field("key", &"[REDACTED]")
+            .finish()
+    }
+}
+
+/// The key version of an encrypted token cache file.
+fn key_version(sealed: &[u8]) -> IdentityResult<u32> {
+    if sealed.len() < HEADER_LEN + NONCE_LEN || !sealed.starts_with(MAGIC) {
+        return AF::msg_result("token_cache", "file is not an encrypted token cache");
+    }
+    if sealed[4] != FORMAT_VERSION {
+        return AF::msg_result(
+            "token_cache",
+            format!("unsupported token cache format version {}", sealed[4]),
+        );
+    }
+    let mut version = [0u8; 4];
+    version.copy_from_slice(&sealed[5..HEADER_LEN]);
+    Ok(u32::from_be_bytes(version))
+}
+
+struct EncryptedFileState {
+    key: TokenCacheKey,
+    refresh_tokens: BTreeMap<String, SecretString>,
+}
+
+/// A token cache that stores refresh tokens in a file encrypted with AES-256-GCM so that
+/// desktop and command line apps do not write refresh tokens to plain text files.
+///
+/// The key can be derived from a user supplied secret using [TokenCacheKey::from_secret]
+/// or, with feature `platform-keyring`, kept in the credential store of the operating
+/// system using [TokenCacheKey::from_keyring]. Access tokens are only cached in memory.
+///
+/// Keys are rotated by opening the cache with the new key and the previous keys using
+/// [EncryptedFileTokenCache::with_previous_keys], which re-encrypts a file that was
+/// encrypted with a previous key, or by calling [EncryptedFileTokenCache::rotate_key].
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_oauth::{EncryptedFileTokenCache, PublicClientApplication, TokenCacheKey};
+///
+/// let key = TokenCacheKey::from_secret(1, "secret", "my-app");
+/// let token_cache = EncryptedFileTokenCache::new("token_cache.bin", key)?;
+///
+/// let public_client = PublicClientApplication::builder("client-id")
+///     .with_device_code_executor()
+///     .with_encrypted_file_token_cache(token_cache)
+///     .build();
+/// ```
+#[derive(Clone)]
+pub struct EncryptedFileTokenCache {
+    path: PathBuf,
+    state: Arc<Mutex<EncryptedFileState>>,
+    in_memory: InMemoryCacheStore<Token>,
+}
+
+impl EncryptedFileTokenCache {
+    /// Open the cache file encrypted with the key or create it on the first store.
+    pub fn new(
+        path: impl AsRef<Path>,
+        key: TokenCacheKey,
+    ) -> IdentityResult<EncryptedFileTokenCache> {
+        EncryptedFileTokenCache::with_previous_keys(path, key, vec![])
+    }
+
+    /// Open the cache file encrypted with the key or one of the previous keys. A file
+    /// encrypted with a previous key is re-encrypted with the key.
+    pub fn with_previous_keys(
+        path: impl AsRef<Path>,
+        key: TokenCacheKey,
+        previous_keys: Vec<TokenCacheKey>,
+    ) -> IdentityResult<EncryptedFileTokenCache> {
+        let path = path.as_ref().to_path_buf();
+        let mut refresh_tokens = BTreeMap::new();
+        let mut rotated = false;
+
+        match std::fs::read(&path) {
+            Ok(sealed) => {
+                let version = key_version(&sealed)?;
+                let file_key = std::iter::once(&key)
+                    .chain(previous_keys.iter())
+                    .find(|key| key.version == version)
+                    .ok_or_else(|| {
+                        AF::msg_err(
+                            "token_cache",
+                            format!("no key for token cache key version {version}").as_str(),
+                        )
+                    })?;
+                rotated = file_key.version != key.version;
+                refresh_tokens = serde_json::from_slice(&file_key.open(&sealed)?)?;
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => return AF::msg_result("token_cache", err),
+        }
+
+        let cache = EncryptedFileTokenCache {
+            path,
+            state: Arc::new(Mutex::new(EncryptedFileState {
+                key,
+                refresh_tokens,
+            })),
+            in_memory: InMemoryCacheStore::new(),
+        };
+        if rotated {
+            cache.persist(&cache.lock())?;
+        }
+        Ok(cache)
+    }
+
+    pub fn path(&self) -> &Path {
+        self.path.as_path()
+    }
+
+    /// Re-encrypt the cache file with a new key.
+    pub fn rotate_key(&self, key: TokenCacheKey) -> IdentityResult<()> {
+        let mut state = self.lock();
+        state.key = key;
+        self.persist(&state)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, EncryptedFileState> {
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// Write to a temporary file first so that the cache file is never partially written.
+    fn persist(&self, state: &EncryptedFileState) -> IdentityResult<()> {
+        let plaintext = serde_json::to_vec(&state.refresh_tokens)?;
+        let sealed = state.key.seal(&plaintext)?;
+
+        let temp_path = self.path.with_extension("tmp");
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+
+        let write = || -> std::io::Result<()> {
+            use std::io::Write;
+            let mut file = options.open(&temp_path)?;
+            file.write_all(&sealed)?;
+            file.sync_all()?;
+            std::fs::rename(&temp_path, &self.path)
+        };
+        write().map_err(|err| AF::msg_err("token_cache", err.to_string().as_str()))
+    }
+
+    fn refresh_token(&self, cache_id: &str) -> Option<SecretString> {
+        self.lock().refresh_tokens.get(cache_id).cloned()
+    }
+}
+
+impl Debug for EncryptedFileTokenCache {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EncryptedFileTokenCache")
+            .field("path", &self.path)
+            .finish()
+    }
+}
+
+impl CacheStore<Token> for EncryptedFileTokenCache {
+    fn store<T: Into<String>>(&mut self, cache_id: T, token: Token) {
+        let cache_id = cache_id.into();
+        if let Some(refresh_token) = token.refresh_token.as_ref() {
+            let mut state = self.lock();
+            if state.refresh_tokens.get(&cache_id) != Some(refresh_token) {
+                state
+                    .refresh_tokens
+                    .insert(cache_id.clone(), refresh_token.clone());
+                if let Err(err) = self.persist(&state) {
+                    tracing::warn!(target: CREDENTIAL_EXECUTOR, "unable to store refresh token in encrypted token cache: {err}");
+                }
+            }
+        }
+
+        self.in_memory.store(cache_id, token);
+    }
+
+    fn get(&self, cache_id: &str) -> Option<Token> {
+        if let Some(token) = self.in_memory.get(cache_id) {
+            return Some(token);
+        }
+
+        self.refresh_token(cache_id).map(Token::from_refresh_token)
+    }
+
+    fn evict(&self, cache_id: &str) -> Option<Token> {
+        let refresh_token = {
+            let mut state = self.lock();
+            let refresh_token = state.refresh_tokens.remove(cache_id);
+            if refresh_token.is_some() {
+                if let Err(err) = self.persist(&state) {
+                    tracing::warn!(target: CREDENTIAL_EXECUTOR, "unable to remove refresh token from encrypted token cache: {err}");
+                }
+            }
+            refresh_token
+        };
+
+        self.in_memory
+            .evict(cache_id)
+            .or_else(|| refresh_token.map(Token::from_refresh_token))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn temp_path() -> PathBuf {
+        std::env::temp_dir().join(format!("token_cache_{}.bin", uuid::Uuid::new_v4()))
+    }
+
+    fn token(refresh_token: &str) -> Token {
+        let mut token = Token::new("Bearer", 3600, "access_token", vec!["User.Read"]);
+        token.with_refresh_token(refresh_token);
+        token
+    }
+
+    #[test]
+    fn refresh_token_is_encrypted_at_rest() {
+        let path = temp_path();
+        let key = || TokenCacheKey::from_bytes(1, [3u8; KEY_LEN]);
+        let mut cache = EncryptedFileTokenCache::new(&path, key()).unwrap();
+        cache.store("cache-id", token("refresh-token"));
+
+        let sealed = std::fs::read(&path).unwrap();
+        assert!(sealed.starts_with(MAGIC));
+        assert!(!String::from_utf8_lossy(&sealed).contains("refresh-token"));
+
+        let cache = EncryptedFileTokenCache::new(&path, key()).unwrap();
+        let token = cache.get("cache-id").unwrap();
+        assert_eq!(
+            Some(SecretString::from("refresh-token")),
+            token.refresh_token
+        );
+        assert!(token.is_expired());
+
+        let wrong_key = TokenCacheKey::from_bytes(1, [7u8; KEY_LEN]);
+        assert!(EncryptedFileTokenCache::new(&path, wrong_key).is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn modified_file_is_rejected() {
+        let path = temp_path();
+        let key = || TokenCacheKey::from_bytes(1, [3u8; KEY_LEN]);
+        let mut cache = EncryptedFileTokenCache::new(&path, key()).unwrap();
+        cache.store("cache-id", token("refresh-token"));
+
+        let mut sealed = std::fs::read(&path).unwrap();
+        let last = sealed.len() - 1;
+        sealed[last] ^= 1;
+        std::fs::write(&path, &sealed).unwrap();
+        assert!(EncryptedFileTokenCache::new(&path, key()).is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn rotate_key() {
+        let path = temp_path();
+        let old_key = || TokenCacheKey::from_bytes(1, [1u8; KEY_LEN]);
+        let new_key = || TokenCacheKey::from_bytes(2, [2u8; KEY_LEN]);
+        let mut cache = EncryptedFileTokenCache::new(&path, old_key()).unwrap();
+        cache.store("cache-id", token("refresh-token"));
+
+        let cache =
+            EncryptedFileTokenCache::with_previous_keys(&path, new_key(), vec![old_key()]).unwrap();
+        assert!(cache.get("cache-id").is_some());
+        assert_eq!(2, key_version(&std::fs::read(&path).unwrap()).unwrap());
+
+        let cache = EncryptedFileTokenCache::new(&path, new_key()).unwrap();
+        assert!(cache.get("cache-id").is_some());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -7,8 +7,9 @@ use std::fmt::{Debug, Display, Formatter};
 
 use crate::identity::AuthorizationResponse;
 use base64::Engine;
-use graph_core::identity::{Claims, DecodedJwt};
-use graph_error::{AuthorizationFailure, AF};
+use graph_core::crypto::{ct_eq, left_half_sha256};
+use graph_core::identity::{Claims, DecodedJwt, JwksKeySet, LogPolicy};
+use graph_error::{AuthorizationFailure, IdentityResult, AF};
 use jsonwebtoken::errors as JwtErrors;
 use jsonwebtoken::{Algorithm, DecodingKey, Validation};
 use std::str::FromStr;
@@ -27,7 +28,7 @@ pub struct IdToken {
     #[serde(flatten)]
     pub additional_fields: HashMap<String, Value>,
     #[serde(skip)]
-    log_pii: bool,
+    log_policy: LogPolicy,
     #[serde(skip)]
     pub(crate) verified: bool,
 }
@@ -44,7 +45,7 @@ impl TryFrom<AuthorizationResponse> for IdToken {
             state: value.state,
             session_state: value.session_state,
             additional_fields: Default::default(),
-            log_pii: false,
+            log_policy: LogPolicy::default(),
             verified: false,
         })
     }
@@ -63,7 +64,7 @@ impl IdToken {
             state: state.map(|value| value.into()),
             session_state: session_state.map(|value| value.into()),
             additional_fields: Default::default(),
-            log_pii: false,
+            log_policy: LogPolicy::default(),
             verified: false,
         }
     }
@@ -87,15 +88,13 @@ impl IdToken {
         jsonwebtoken::decode_header(self.id_token.as_str())
     }
 
-    /// Slated Post 2.0 Release
     /// Decode and verify the id token using the following parameters:
     /// modulus (n): product of two prime numbers used to generate key pair.
     /// Exponent (e): exponent used to decode the data.
     /// client_id: tenant client id in Azure.
     /// issuer: issuer for tenant in Azure.
-    #[allow(dead_code)]
     fn decode(
-        &mut self,
+        &self,
         modulus: &str,
         exponent: &str,
         client_id: &str,
@@ -107,22 +106,105 @@ impl IdToken {
             validation.set_issuer(&[issuer]);
         }
 
-        let token_data = jsonwebtoken::decode::<Claims>(
+        jsonwebtoken::decode::<Claims>(
             &self.id_token,
-            &DecodingKey::from_rsa_components(modulus, exponent).unwrap(),
+            &DecodingKey::from_rsa_components(modulus, exponent)?,
             &validation,
-        )?;
+        )
+    }
+
+    /// Verify the signature of the id token using the signing keys of the authority and
+    /// validate the audience, issuer, and nonce claims. When the id token was returned with
+    /// an authorization code, such as in the hybrid flow, the c_hash claim is validated
+    /// against the code.
+    ///
+    /// The issuer is not validated if None. Pass None for the multi-tenant authorities
+    /// `common`, `organizations`, and `consumers`.
+    pub fn validate(
+        &mut self,
+        jwks: &JwksKeySet,
+        client_id: &str,
+        issuer: Option<&str>,
+        nonce: &str,
+    ) -> IdentityResult<DecodedJwt> {
+        let header = self
+            .decode_header()
+            .map_err(|err| AF::msg_err("id_token", err.to_string().as_str()))?;
+        let kid = header
+            .kid
+            .ok_or_else(|| AF::msg_err("id_token", "id token header is missing the kid"))?;
+        let key = jwks
+            .keys
+            .iter()
+            .find(|key| key.kid.eq(&kid))
+            .ok_or_else(|| AF::msg_err("id_token", "no signing key found for the id token"))?;
+
+        let token_data = self
+            .decode(
+                key.modulus.as_str(),
+                key.exponent.as_str(),
+                client_id,
+                issuer,
+            )
+            .map_err(|err| AF::msg_err("id_token", err.to_string().as_str()))?;
+        validate_claims(&token_data.claims, nonce, self.code.as_deref())?;
         self.verified = true;
         Ok(token_data)
     }
 
+    /// Returns true if the id token was verified using [IdToken::validate].
+    pub fn is_verified(&self) -> bool {
+        self.verified
+    }
+
     /// Enable or disable logging of personally identifiable information such
-    /// as logging the id_token. This is disabled by default. When log_pii is enabled
+    /// as logging the id_token. This is disabled by default. When enabled
     /// passing an [IdToken] to logging or print functions will log id_token field.
     /// By default this does not get logged.
     pub fn enable_pii_logging(&mut self, log_pii: bool) {
-        self.log_pii = log_pii;
+        self.log_policy = LogPolicy::from_pii_logging(log_pii);
+    }
+
+    /// Set how the id_token field is written to debug output.
+    pub fn with_log_policy(&mut self, log_policy: LogPolicy) -> &mut Self {
+        self.log_policy = log_policy;
+        self
+    }
+}
+
+/// Validate the nonce claim and, if an authorization code was returned with the id token,
+/// the c_hash claim.
+pub(crate) fn validate_claims(
+    claims: &Claims,
+    nonce: &str,
+    code: Option<&str>,
+) -> IdentityResult<()> {
+    let nonce_matches = claims
+        .nonce
+        .as_ref()
+        .map(|claim| ct_eq(claim, nonce))
+        .unwrap_or_default();
+    if !nonce_matches {
+        return AF::msg_result(
+            "nonce",
+            "nonce of the id token does not match the nonce of the request",
+        );
     }
+
+    if let Some(code) = code {
+        let c_hash = claims
+            .c_hash
+            .as_ref()
+            .ok_or_else(|| AF::msg_err("c_hash", "id token is missing the c_hash claim"))?;
+        if !ct_eq(c_hash, left_half_sha256(code)) {
+            return AF::msg_result(
+                "c_hash",
+                "c_hash of the id token does not match the authorization code",
+            );
+        }
+    }
+
+    Ok(())
 }
 
 impl Display for IdToken {
@@ -161,21 +243,14 @@ impl TryFrom<&str> for IdToken {
 
 impl Debug for IdToken {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        if self.log_pii {
-            f.debug_struct("IdToken")
-                .field("code", &self.code)
-                .field("id_token", &self.id_token)
-                .field("session_state", &self.session_state)
-                .field("additional_fields", &self.additional_fields)
-                .finish()
-        } else {
-            f.debug_struct("IdToken")
-                .field("code", &self.code)
-                .field("id_token", &"[REDACTED]")
-                .field("session_state", &self.session_state)
-                .field("additional_fields", &self.additional_fields)
-                .finish()
-        }
+        let mut debug = f.debug_struct("IdToken");
+        debug.field("code", &self.code);
+        self.log_policy
+            .debug_field(&mut debug, "id_token", &self.id_token);
+        debug
+            .field("session_state", &self.session_state)
+            .field("additional_fields", &self.additional_fields)
+            .finish()
     }
 }
 
@@ -248,3 +323,31 @@ impl FromStr for IdToken {
         deserialize_result
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn claims(nonce: &str, c_hash: &str) -> Claims {
+        serde_json::from_value(serde_json::json!({
+            "aud": "client-id",
+            "iss": "https://login.microsoftonline.com/tenant-id/v2.0",
+            "iat": 0,
+            "nbf": 0,
+            "exp": 0,
+            "nonce": nonce,
+            "c_hash": c_hash
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn validate_nonce_and_c_hash() {
+        let code = "jHkWEdUXMU1BwAsC4vtUsZwnNvTIxEl0z9K3vx5KF0Y";
+        let claims = claims("nonce", "77QmUPtjPfzWtF2AnpK9RQ");
+        assert!(validate_claims(&claims, "nonce", Some(code)).is_ok());
+        assert!(validate_claims(&claims, "nonce", None).is_ok());
+        assert!(validate_claims(&claims, "other-nonce", Some(code)).is_err());
+        assert!(validate_claims(&claims, "nonce", Some("other-code")).is_err());
+    }
+}
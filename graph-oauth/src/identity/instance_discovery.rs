@@ -0,0 +1,303 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use url::Url;
+
+use graph_error::{AuthExecutionResult, IdentityResult, AF};
+
+use crate::identity::{AllowedHostValidator, HostIs};
+
+/// The endpoint used to discover the metadata of an authority host.
+pub const INSTANCE_DISCOVERY_ENDPOINT: &str =
+    "https://login.microsoftonline.com/common/discovery/instance";
+
+const INSTANCE_DISCOVERY_API_VERSION: &str = "1.1";
+
+/// The hosts that are known to the Microsoft identity platform. These hosts are
+/// validated without sending a request to the instance discovery endpoint.
+const WELL_KNOWN_INSTANCES: [(&str, &str, &[&str]); 4] = [
+    (
+        "login.microsoftonline.com",
+        "login.windows.net",
+        &[
+            "login.microsoftonline.com",
+            "login.windows.net",
+            "login.microsoft.com",
+            "sts.windows.net",
+        ],
+    ),
+    (
+        "login.partner.microsoftonline.cn",
+        "login.partner.microsoftonline.cn",
+        &["login.partner.microsoftonline.cn", "login.chinacloudapi.cn"],
+    ),
+    (
+        "login.microsoftonline.de",
+        "login.microsoftonline.de",
+        &["login.microsoftonline.de"],
+    ),
+    (
+        "login.microsoftonline.us",
+        "login.microsoftonline.us",
+        &["login.microsoftonline.us", "login.usgovcloudapi.net"],
+    ),
+];
+
+lazy_static! {
+    static ref INSTANCE_DISCOVERY_CACHE: RwLock<HashMap<String, InstanceDiscoveryMetadata>> = {
+        let mut cache = HashMap::new();
+        for (preferred_network, preferred_cache, aliases) in WELL_KNOWN_INSTANCES.iter() {
+            let metadata = InstanceDiscoveryMetadata {
+                preferred_network: preferred_network.to_string(),
+                preferred_cache: preferred_cache.to_string(),
+                aliases: aliases.iter().map(|alias| alias.to_string()).collect(),
+            };
+            for alias in aliases.iter() {
+                cache.insert(alias.to_string(), metadata.clone());
+            }
+        }
+        RwLock::new(cache)
+    };
+}
+
+/// The metadata of a cloud returned by instance discovery. Each alias is a host
+/// of the same cloud that tokens can be requested from.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct InstanceDiscoveryMetadata {
+    pub preferred_network: String,
+    pub preferred_cache: String,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+impl InstanceDiscoveryMetadata {
+    /// An [AllowedHostValidator] for the aliases of the cloud.
+    pub fn allowed_host_validator(&self) -> AllowedHostValidator {
+        let urls: Vec<Url> = self
+            .aliases
+            .iter()
+            .flat_map(|alias| Url::parse(&format!("https://{alias}")))
+            .collect();
+        AllowedHostValidator::from(urls.as_slice())
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+struct InstanceDiscoveryResponse {
+    #[serde(default)]
+    metadata: Vec<InstanceDiscoveryMetadata>,
+    error: Option<String>,
+    error_description: Option<String>,
+}
+
+/// Validates authority hosts using the instance discovery endpoint of the
+/// Microsoft identity platform.
+///
+/// Hosts of the well known clouds are validated without a request to the endpoint.
+/// The metadata of any other host is requested once and cached for the lifetime of
+/// the process.
+pub struct InstanceDiscovery;
+
+impl InstanceDiscovery {
+    /// The instance discovery uri for an authority host.
+    pub fn discovery_uri(authority_host: &str) -> IdentityResult<Url> {
+        let mut uri = Url::parse(INSTANCE_DISCOVERY_ENDPOINT)?;
+        uri.query_pairs_mut()
+            .append_pair("api-version", INSTANCE_DISCOVERY_API_VERSION)
+            .append_pair(
+                "authorization_endpoint",
+                &format!("https://{authority_host}/common/oauth2/v2.0/authorize"),
+            );
+        Ok(uri)
+    }
+
+    /// Get the cached metadata of an authority host.
+    pub fn cached_metadata(authority_host: &str) -> Option<InstanceDiscoveryMetadata> {
+        INSTANCE_DISCOVERY_CACHE
+            .read()
+            .ok()?
+            .get(&authority_host.to_lowercase())
+            .cloned()
+    }
+
+    /// Get the metadata of an authority host, requesting it from the instance
+    /// discovery endpoint using the given http client if it is not cached.
+    pub fn metadata(
+        authority_host: &str,
+        http_client: &reqwest::blocking::Client,
+    ) -> AuthExecutionResult<InstanceDiscoveryMetadata> {
+        if let Some(metadata) = InstanceDiscovery::cached_metadata(authority_host) {
+            return Ok(metadata);
+        }
+
+        let response: InstanceDiscoveryResponse = http_client
+            .get(InstanceDiscovery::discovery_uri(authority_host)?)
+            .send()?
+            .json()?;
+        Ok(InstanceDiscovery::cache_response(authority_host, response)?)
+    }
+
+    /// Get the metadata of an authority host, requesting it from the instance
+    /// discovery endpoint using the given http client if it is not cached.
+    pub async fn metadata_async(
+        authority_host: &str,
+        http_client: &reqwest::Client,
+    ) -> AuthExecutionResult<InstanceDiscoveryMetadata> {
+        if let Some(metadata) = InstanceDiscovery::cached_metadata(authority_host) {
+            return Ok(metadata);
+        }
+
+        let response: InstanceDiscoveryResponse = http_client
+            .get(InstanceDiscovery::discovery_uri(authority_host)?)
+            .send()
+            .await?
+            .json()
+            .await?;
+        Ok(InstanceDiscovery::cache_response(authority_host, response)?)
+    }
+
+    /// Returns an error if the host of the uri is not a validated authority host.
+    pub fn validate_authority(
+        uri: &Url,
+        http_client: &reqwest::blocking::Client,
+    ) -> AuthExecutionResult<()> {
+        let metadata = InstanceDiscovery::metadata(authority_host(uri)?, http_client)?;
+        Ok(validate_host(&metadata, uri)?)
+    }
+
+    /// Returns an error if the host of the uri is not a validated authority host.
+    pub async fn validate_authority_async(
+        uri: &Url,
+        http_client: &reqwest::Client,
+    ) -> AuthExecutionResult<()> {
+        let metadata = InstanceDiscovery::metadata_async(authority_host(uri)?, http_client).await?;
+        Ok(validate_host(&metadata, uri)?)
+    }
+
+    fn cache_response(
+        authority_host: &str,
+        response: InstanceDiscoveryResponse,
+    ) -> IdentityResult<InstanceDiscoveryMetadata> {
+        if let Some(error) = response.error {
+            return AF::msg_result(
+                "authority",
+                format!(
+                    "{authority_host} failed instance discovery: {error} {}",
+                    response.error_description.unwrap_or_default()
+                ),
+            );
+        }
+
+        let authority_host = authority_host.to_lowercase();
+        let metadata = response
+            .metadata
+            .into_iter()
+            .find(|metadata| {
+                metadata
+                    .aliases
+                    .iter()
+                    .any(|alias| alias.eq_ignore_ascii_case(&authority_host))
+            })
+            // A host that is not part of a known cloud is returned without metadata
+            // and is its own and only alias.
+            .unwrap_or_else(|| InstanceDiscoveryMetadata {
+                preferred_network: authority_host.clone(),
+                preferred_cache: authority_host.clone(),
+                aliases: vec![authority_host.clone()],
+            });
+
+        if let Ok(mut cache) = INSTANCE_DISCOVERY_CACHE.write() {
+            for alias in metadata.aliases.iter() {
+                cache.insert(alias.to_lowercase(), metadata.clone());
+            }
+            cache.insert(authority_host, metadata.clone());
+        }
+
+        Ok(metadata)
+    }
+}
+
+fn authority_host(uri: &Url) -> IdentityResult<&str> {
+    uri.host_str()
+        .ok_or_else(|| AF::msg_err("authority", "authority uri is missing a host"))
+}
+
+fn validate_host(metadata: &InstanceDiscoveryMetadata, uri: &Url) -> IdentityResult<()> {
+    match metadata.allowed_host_validator().validate_url(uri) {
+        HostIs::Valid => Ok(()),
+        HostIs::Invalid => AF::msg_result(
+            "authority",
+            format!(
+                "{} is not a validated authority host",
+                uri.host_str().unwrap_or_default()
+            ),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn well_known_hosts_are_cached() {
+        let http_client = reqwest::blocking::Client::new();
+        for host in [
+            "login.microsoftonline.com",
+            "login.windows.net",
+            "login.chinacloudapi.cn",
+            "login.microsoftonline.de",
+            "login.microsoftonline.us",
+        ] {
+            let uri = Url::parse(&format!("https://{host}/common/oauth2/v2.0/token")).unwrap();
+            assert!(InstanceDiscovery::validate_authority(&uri, &http_client).is_ok());
+        }
+    }
+
+    #[test]
+    fn discovery_uri() {
+        let uri = InstanceDiscovery::discovery_uri("login.contoso.com").unwrap();
+        assert_eq!(
+            "https://login.microsoftonline.com/common/discovery/instance?api-version=1.1&authorization_endpoint=https%3A%2F%2Flogin.contoso.com%2Fcommon%2Foauth2%2Fv2.0%2Fauthorize",
+            uri.as_str()
+        );
+    }
+
+    #[test]
+    fn invalid_instance_response() {
+        let response: InstanceDiscoveryResponse = serde_json::from_value(serde_json::json!({
+            "error": "invalid_instance",
+            "error_description": "AADSTS50049: Unknown or invalid instance."
+        }))
+        .unwrap();
+
+        assert!(InstanceDiscovery::cache_response("login.invalid.example", response).is_err());
+        assert!(InstanceDiscovery::cached_metadata("login.invalid.example").is_none());
+    }
+
+    #[test]
+    fn discovered_metadata_validates_aliases() {
+        let response: InstanceDiscoveryResponse = serde_json::from_value(serde_json::json!({
+            "tenant_discovery_endpoint": "https://login.cloud.example/common/v2.0/.well-known/openid-configuration",
+            "api-version": "1.1",
+            "metadata": [
+                {
+                    "preferred_network": "login.cloud.example",
+                    "preferred_cache": "login.cloud.example",
+                    "aliases": ["login.cloud.example", "sts.cloud.example"]
+                }
+            ]
+        }))
+        .unwrap();
+
+        let metadata = InstanceDiscovery::cache_response("sts.cloud.example", response).unwrap();
+        assert_eq!("login.cloud.example", metadata.preferred_network);
+
+        let uri = Url::parse("https://login.cloud.example/common/oauth2/v2.0/token").unwrap();
+        let http_client = reqwest::blocking::Client::new();
+        assert!(InstanceDiscovery::validate_authority(&uri, &http_client).is_ok());
+
+        let uri = Url::parse("https://login.other.example/common/oauth2/v2.0/token").unwrap();
+        assert!(validate_host(&metadata, &uri).is_err());
+    }
+}
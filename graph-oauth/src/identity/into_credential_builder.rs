@@ -1,8 +1,142 @@
 use std::fmt::Debug;
 
+use graph_error::{AuthorizationFailure, IdentityResult, AF};
+
+use crate::identity::{
+    AuthCodeAuthorizationUrlParameterBuilder, AuthCodeAuthorizationUrlParameters,
+    AuthorizationCodeCredentialBuilder, AuthorizationResponse, PendingAuthorization,
+};
+
 pub trait IntoCredentialBuilder<CredentialBuilder: Clone + Debug> {
     type Response;
     type Error: std::error::Error;
 
     fn into_credential_builder(self) -> Result<(Self::Response, CredentialBuilder), Self::Error>;
 }
+
+/// The authorization code of the response after checking that the response is not an
+/// error and that the state matches the state of the authorization request.
+fn authorization_code(
+    response: &AuthorizationResponse,
+    expected_state: Option<&String>,
+) -> IdentityResult<String> {
+    if let Some(error) = response.authorization_error() {
+        let message = error
+            .error_description
+            .or_else(|| error.error.map(|error| error.to_string()))
+            .unwrap_or_default();
+        return AF::msg_result("authorization_response", message);
+    }
+
+    if let Some(expected_state) = expected_state {
+        response.validate_state(expected_state)?;
+    }
+
+    response
+        .code
+        .clone()
+        .ok_or_else(|| AF::msg_err("code", "authorization response is missing the code"))
+}
+
+/// Build the credential for the code of the authorization response using the parameters
+/// of the authorization url. The state of the response is validated when the parameters
+/// have a state and the PKCE code verifier, set with `with_pkce`, is carried over.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::identity::{AuthorizationResponse, IntoCredentialBuilder};
+///
+/// let response = AuthorizationResponse::from_query(query)?;
+/// let (response, mut credential_builder) = (response, parameters).into_credential_builder()?;
+/// let confidential_client = credential_builder.with_client_secret("client-secret").build();
+/// ```
+impl IntoCredentialBuilder<AuthorizationCodeCredentialBuilder>
+    for (AuthorizationResponse, AuthCodeAuthorizationUrlParameters)
+{
+    type Response = AuthorizationResponse;
+    type Error = AuthorizationFailure;
+
+    fn into_credential_builder(
+        self,
+    ) -> Result<(Self::Response, AuthorizationCodeCredentialBuilder), Self::Error> {
+        let (response, parameters) = self;
+        let code = authorization_code(&response, parameters.state())?;
+        Ok((response, parameters.into_credential(code)))
+    }
+}
+
+impl IntoCredentialBuilder<AuthorizationCodeCredentialBuilder>
+    for (
+        AuthorizationResponse,
+        AuthCodeAuthorizationUrlParameterBuilder,
+    )
+{
+    type Response = AuthorizationResponse;
+    type Error = AuthorizationFailure;
+
+    fn into_credential_builder(
+        self,
+    ) -> Result<(Self::Response, AuthorizationCodeCredentialBuilder), Self::Error> {
+        let (response, builder) = self;
+        (response, builder.build()).into_credential_builder()
+    }
+}
+
+impl IntoCredentialBuilder<AuthorizationCodeCredentialBuilder>
+    for (AuthorizationResponse, PendingAuthorization)
+{
+    type Response = AuthorizationResponse;
+    type Error = AuthorizationFailure;
+
+    fn into_credential_builder(
+        self,
+    ) -> Result<(Self::Response, AuthorizationCodeCredentialBuilder), Self::Error> {
+        let (response, pending) = self;
+        let (response, mut credential_builder) =
+            (response, pending.parameters).into_credential_builder()?;
+        if let Some(pkce) = pending.pkce.as_ref() {
+            credential_builder.with_pkce(pkce);
+        }
+        Ok((response, credential_builder))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::identity::TokenCredentialExecutor;
+    use graph_core::crypto::{GenPkce, ProofKeyCodeExchange};
+
+    #[test]
+    fn into_credential_builder_from_authorization_response() {
+        let pkce = ProofKeyCodeExchange::oneshot().unwrap();
+        let mut builder = AuthCodeAuthorizationUrlParameters::builder(uuid::Uuid::new_v4());
+        builder
+            .with_redirect_uri(url::Url::parse("http://localhost:8000/redirect").unwrap())
+            .with_state("12345")
+            .with_pkce(&pkce);
+
+        let response =
+            AuthorizationResponse::from_query("code=AwABAAAAvPM1KaPl&state=12345").unwrap();
+        let (_, credential_builder) = (response, builder.clone())
+            .into_credential_builder()
+            .unwrap();
+        let mut credential = credential_builder.build_public_client();
+        let form = credential.form_urlencode().unwrap();
+        assert_eq!(Some(&"AwABAAAAvPM1KaPl".to_string()), form.get("code"));
+        assert_eq!(
+            Some(pkce.code_verifier.expose_secret()),
+            form.get("code_verifier").map(|v| v.as_str())
+        );
+
+        let response =
+            AuthorizationResponse::from_query("code=AwABAAAAvPM1KaPl&state=54321").unwrap();
+        assert!((response, builder.clone())
+            .into_credential_builder()
+            .is_err());
+
+        let response =
+            AuthorizationResponse::from_query("error=access_denied&state=12345").unwrap();
+        assert!((response, builder).into_credential_builder().is_err());
+    }
+}
@@ -0,0 +1,97 @@
+use graph_core::cache::{CacheStore, InMemoryCacheStore};
+use keyring::Entry;
+
+use crate::identity::{tracing_targets::CREDENTIAL_EXECUTOR, Token};
+
+/// A token cache that stores refresh tokens in the credential store of the operating system
+/// so that desktop apps do not have to write refresh tokens to plain text files.
+///
+/// Refresh tokens are stored in the Windows Credential Manager (DPAPI), the macOS Keychain,
+/// or the Secret Service on Linux. Access tokens are only cached in memory because they are
+/// short-lived and can exceed the size limits of some credential stores.
+///
+/// When the process restarts, the cache returns an expired token holding only the stored
+/// refresh token so that the credential requests a new access token on first use.
+///
+/// Requires feature `platform-keyring`
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_oauth::{ConfidentialClientApplication, KeyringTokenCache};
+///
+/// let credential = ConfidentialClientApplication::builder("client-id")
+///     .with_auth_code("authorization-code")
+///     .with_client_secret("client-secret")
+///     .with_keyring_token_cache(KeyringTokenCache::new("my-app"))
+///     .build();
+/// ```
+#[derive(Clone)]
+pub struct KeyringTokenCache {
+    service: String,
+    in_memory: InMemoryCacheStore<Token>,
+}
+
+impl KeyringTokenCache {
+    /// Create a cache that stores refresh tokens under the given service name.
+    /// The cache id of the credential is used as the account name of the entry.
+    pub fn new(service: impl AsRef<str>) -> KeyringTokenCache {
+        KeyringTokenCache {
+            service: service.as_ref().to_owned(),
+            in_memory: InMemoryCacheStore::new(),
+        }
+    }
+
+    pub fn service(&self) -> &str {
+        self.service.as_str()
+    }
+
+    fn entry(&self, cache_id: &str) -> keyring::Result<Entry> {
+        Entry::new(self.service.as_str(), cache_id)
+    }
+
+    fn refresh_token(&self, cache_id: &str) -> Option<String> {
+        self.entry(cache_id)
+            .and_then(|entry| entry.get_password())
+            .ok()
+    }
+}
+
+impl CacheStore<Token> for KeyringTokenCache {
+    fn store<T: Into<String>>(&mut self, cache_id: T, token: Token) {
+        let cache_id = cache_id.into();
+        if let Some(refresh_token) = token.refresh_token.as_ref() {
+            if let Err(err) = self
+                .entry(cache_id.as_str())
+                .and_then(|entry| entry.set_password(refresh_token.expose_secret()))
+            {
+                tracing::warn!(target: CREDENTIAL_EXECUTOR, "unable to store refresh token in platform keyring: {err}");
+            }
+        }
+
+        self.in_memory.store(cache_id, token);
+    }
+
+    fn get(&self, cache_id: &str) -> Option<Token> {
+        if let Some(token) = self.in_memory.get(cache_id) {
+            return Some(token);
+        }
+
+        self.refresh_token(cache_id).map(Token::from_refresh_token)
+    }
+
+    fn evict(&self, cache_id: &str) -> Option<Token> {
+        let refresh_token = self.refresh_token(cache_id);
+        if let Err(err) = self
+            .entry(cache_id)
+            .and_then(|entry| entry.delete_password())
+        {
+            if !matches!(err, keyring::Error::NoEntry) {
+                tracing::warn!(target: CREDENTIAL_EXECUTOR, "unable to remove refresh token from platform keyring: {err}");
+            }
+        }
+
+        self.in_memory
+            .evict(cache_id)
+            .or_else(|| refresh_token.map(Token::from_refresh_token))
+    }
+}
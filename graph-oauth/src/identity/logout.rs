@@ -0,0 +1,247 @@
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use serde_json::Value;
+use url::Url;
+
+use graph_core::crypto::ct_eq;
+use graph_core::identity::JwksKeySet;
+use graph_error::{IdentityResult, AF};
+
+const BACK_CHANNEL_LOGOUT_EVENT: &str = "http://schemas.openid.net/event/backchannel-logout";
+
+fn matches(expected: &str, received: &str) -> bool {
+    ct_eq(expected, received)
+}
+
+/// A front-channel logout request sent by the identity provider to the front-channel
+/// logout uri of the application when the user signs out of another application.
+///
+/// The `iss` and `sid` query parameters are included when the application registration
+/// requires them and identify the session of the user that should be ended.
+///
+/// See [OpenID Connect Front-Channel Logout](https://openid.net/specs/openid-connect-frontchannel-1_0.html)
+#[derive(Clone, Default, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FrontChannelLogoutRequest {
+    pub iss: Option<String>,
+    pub sid: Option<String>,
+}
+
+impl FrontChannelLogoutRequest {
+    /// Parse the logout request from the query of the front-channel logout uri.
+    pub fn from_query(query: &str) -> IdentityResult<FrontChannelLogoutRequest> {
+        serde_urlencoded::from_str(query.trim_start_matches('?'))
+            .map_err(|err| AF::msg_err("logout_request", err.to_string().as_str()))
+    }
+
+    pub fn from_uri(uri: &Url) -> IdentityResult<FrontChannelLogoutRequest> {
+        FrontChannelLogoutRequest::from_query(uri.query().unwrap_or_default())
+    }
+
+    /// Validate that the logout request was sent by the issuer of the id token
+    /// for the session id, the `sid` claim of the id token, of the user.
+    ///
+    /// Both parameters are required when validating. Requests without them can't
+    /// be matched to a session and should be ignored.
+    pub fn validate(&self, issuer: &str, session_id: &str) -> IdentityResult<()> {
+        let (iss, sid) = match (self.iss.as_ref(), self.sid.as_ref()) {
+            (Some(iss), Some(sid)) => (iss, sid),
+            _ => {
+                return AF::msg_result(
+                    "logout_request",
+                    "logout request is missing the iss or sid query parameter",
+                )
+            }
+        };
+
+        if !matches(issuer.trim_end_matches('/'), iss.trim_end_matches('/')) {
+            return AF::msg_result("iss", "issuer of the logout request does not match");
+        }
+        if !matches(session_id, sid) {
+            return AF::msg_result("sid", "session id of the logout request does not match");
+        }
+        Ok(())
+    }
+}
+
+/// The claims of a back-channel logout token.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LogoutTokenClaims {
+    pub iss: String,
+    pub aud: Value,
+    pub iat: u64,
+    #[serde(default)]
+    pub exp: Option<u64>,
+    pub jti: String,
+    #[serde(default)]
+    pub sub: Option<String>,
+    #[serde(default)]
+    pub sid: Option<String>,
+    pub events: HashMap<String, Value>,
+    #[serde(default)]
+    pub nonce: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+/// The claims checks of the logout token specification which are not covered by
+/// the signature, audience, and issuer validation.
+pub(crate) fn validate_logout_claims(claims: &LogoutTokenClaims) -> IdentityResult<()> {
+    if !claims
+        .events
+        .get(BACK_CHANNEL_LOGOUT_EVENT)
+        .map(|event| event.is_object())
+        .unwrap_or_default()
+    {
+        return AF::msg_result(
+            "events",
+            "logout token is missing the back-channel logout event",
+        );
+    }
+
+    if claims.sub.is_none() && claims.sid.is_none() {
+        return AF::msg_result("logout_token", "logout token is missing the sub and sid");
+    }
+
+    if claims.nonce.is_some() {
+        return AF::msg_result("nonce", "logout token must not contain a nonce");
+    }
+
+    Ok(())
+}
+
+/// A logout token sent by the identity provider in a POST to the back-channel logout uri
+/// of the application. The claims identify the user, `sub`, or session, `sid`, that
+/// should be signed out.
+///
+/// See [OpenID Connect Back-Channel Logout](https://openid.net/specs/openid-connect-backchannel-1_0.html)
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::identity::LogoutToken;
+///
+/// let logout_token = LogoutToken::from_form_post(body)?;
+/// let claims = logout_token.validate(&jwks, "client-id", "https://login.microsoftonline.com/tenant-id/v2.0")?;
+/// if let Some(sid) = claims.sid.as_ref() {
+///     sessions.remove(sid);
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq)]
+pub struct LogoutToken(String);
+
+impl LogoutToken {
+    pub fn new(logout_token: impl AsRef<str>) -> LogoutToken {
+        LogoutToken(logout_token.as_ref().to_owned())
+    }
+
+    /// Parse the `logout_token` parameter of the form urlencoded body of the
+    /// back-channel logout request.
+    pub fn from_form_post(body: impl AsRef<[u8]>) -> IdentityResult<LogoutToken> {
+        url::form_urlencoded::parse(body.as_ref())
+            .find(|(key, _)| key.eq("logout_token"))
+            .map(|(_, value)| LogoutToken(value.into_owned()))
+            .ok_or_else(|| AF::msg_err("logout_token", "request body is missing the logout_token"))
+    }
+
+    /// Verify the signature of the logout token using the signing keys of the issuer and
+    /// validate the audience, issuer, and the logout claims. Returns the claims of the
+    /// token if the token is valid.
+    pub fn validate(
+        &self,
+        jwks: &JwksKeySet,
+        client_id: &str,
+        issuer: &str,
+    ) -> IdentityResult<LogoutTokenClaims> {
+        let header = jsonwebtoken::decode_header(self.0.as_str())
+            .map_err(|err| AF::msg_err("logout_token", err.to_string().as_str()))?;
+        let kid = header
+            .kid
+            .ok_or_else(|| AF::msg_err("logout_token", "logout token header is missing the kid"))?;
+        let key = jwks
+            .keys
+            .iter()
+            .find(|key| key.kid.eq(&kid))
+            .ok_or_else(|| {
+                AF::msg_err("logout_token", "no signing key found for the logout token")
+            })?;
+
+        let mut validation = Validation::new(Algorithm::RS256);
+        validation.set_audience(&[client_id]);
+        validation.set_issuer(&[issuer]);
+        validation.set_required_spec_claims(&["iss", "aud"]);
+
+        let decoding_key =
+            DecodingKey::from_rsa_components(key.modulus.as_str(), key.exponent.as_str())
+                .map_err(|err| AF::msg_err("logout_token", err.to_string().as_str()))?;
+        let token_data =
+            jsonwebtoken::decode::<LogoutTokenClaims>(self.0.as_str(), &decoding_key, &validation)
+                .map_err(|err| AF::msg_err("logout_token", err.to_string().as_str()))?;
+
+        validate_logout_claims(&token_data.claims)?;
+        Ok(token_data.claims)
+    }
+}
+
+impl AsRef<str> for LogoutToken {
+    fn as_ref(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl Debug for LogoutToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("LogoutToken").field(&"[REDACTED]").finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn front_channel_logout_request() {
+        let request = FrontChannelLogoutRequest::from_query(
+            "?iss=https%3A%2F%2Flogin.microsoftonline.com%2Ftenant-id%2Fv2.0&sid=session-id",
+        )
+        .unwrap();
+        let issuer = "https://login.microsoftonline.com/tenant-id/v2.0";
+        assert!(request.validate(issuer, "session-id").is_ok());
+        assert!(request.validate(issuer, "other-session-id").is_err());
+        assert!(request
+            .validate("https://login.microsoftonline.com/other/v2.0", "session-id")
+            .is_err());
+
+        let request = FrontChannelLogoutRequest::from_query("").unwrap();
+        assert!(request.validate(issuer, "session-id").is_err());
+    }
+
+    #[test]
+    fn logout_token_claims() {
+        let mut claims: LogoutTokenClaims = serde_json::from_value(serde_json::json!({
+            "iss": "https://login.microsoftonline.com/tenant-id/v2.0",
+            "aud": "client-id",
+            "iat": 1704067200,
+            "jti": "bWJq",
+            "sid": "session-id",
+            "events": { "http://schemas.openid.net/event/backchannel-logout": {} }
+        }))
+        .unwrap();
+        assert!(validate_logout_claims(&claims).is_ok());
+
+        claims.nonce = Some("nonce".into());
+        assert!(validate_logout_claims(&claims).is_err());
+
+        claims.nonce = None;
+        claims.events.clear();
+        assert!(validate_logout_claims(&claims).is_err());
+    }
+
+    #[test]
+    fn logout_token_from_form_post() {
+        let logout_token =
+            LogoutToken::from_form_post("logout_token=eyJhbGci.eyJpc3Mi.c2ln").unwrap();
+        assert_eq!("eyJhbGci.eyJpc3Mi.c2ln", logout_token.as_ref());
+        assert!(LogoutToken::from_form_post("state=12345").is_err());
+    }
+}
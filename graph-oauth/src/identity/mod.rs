@@ -1,14 +1,28 @@
 mod allowed_host_validator;
 mod application_options;
+mod authentication_record;
 mod authority;
 mod authorization_query_response;
+mod authorization_redirect;
 mod authorization_request_parts;
 mod authorization_url;
+mod azure_region;
+mod client_telemetry;
 mod credentials;
 mod device_authorization_response;
+mod encrypted_file_token_cache;
 mod id_token;
+mod instance_discovery;
 mod into_credential_builder;
+#[cfg(feature = "platform-keyring")]
+mod keyring_token_cache;
+mod logout;
+mod msal_token_cache;
+mod realm_discovery;
 mod token;
+mod token_credential_options;
+mod token_diagnostics;
+mod token_store;
 
 #[cfg(feature = "openssl")]
 pub use openssl::{
@@ -18,12 +32,27 @@ pub use openssl::{
 
 pub use allowed_host_validator::*;
 pub use application_options::*;
+pub use authentication_record::*;
 pub use authority::*;
 pub use authorization_query_response::*;
+pub use authorization_redirect::*;
 pub use authorization_request_parts::*;
 pub use authorization_url::*;
+pub use azure_region::*;
+pub(crate) use client_telemetry::*;
 pub use credentials::*;
 pub use device_authorization_response::*;
+pub use encrypted_file_token_cache::*;
+pub use graph_core::crypto::SecretString;
 pub use id_token::*;
+pub use instance_discovery::*;
 pub use into_credential_builder::*;
+#[cfg(feature = "platform-keyring")]
+pub use keyring_token_cache::*;
+pub use logout::*;
+pub use msal_token_cache::*;
+pub use realm_discovery::*;
 pub use token::*;
+pub use token_credential_options::*;
+pub use token_diagnostics::*;
+pub(crate) use token_store::*;
@@ -0,0 +1,365 @@
+use std::collections::BTreeMap;
+
+use time::OffsetDateTime;
+
+use graph_error::IdentityResult;
+
+use crate::identity::{AuthenticationRecord, IdToken, SecretString, Token};
+
+/// An account in the MSAL unified token cache schema.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MsalAccountEntity {
+    pub home_account_id: String,
+    pub environment: String,
+    pub realm: String,
+    pub local_account_id: String,
+    #[serde(default)]
+    pub username: String,
+    pub authority_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_info: Option<String>,
+}
+
+/// An access token, refresh token, or id token in the MSAL unified token cache schema.
+/// Timestamps are unix timestamps in seconds stored as strings.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MsalCredentialEntity {
+    pub credential_type: String,
+    pub secret: String,
+    pub home_account_id: String,
+    pub environment: String,
+    pub client_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub realm: Option<String>,
+    /// The scopes of an access token separated by a space.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cached_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_on: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extended_expires_on: Option<String>,
+}
+
+/// App metadata in the MSAL unified token cache schema.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MsalAppMetadataEntity {
+    pub client_id: String,
+    pub environment: String,
+}
+
+/// A token cache export that uses the MSAL unified token cache schema so that sign in
+/// state can be shared with other MSAL based tools.
+///
+/// The cache stores secrets such as refresh tokens in plain text. Protect the
+/// serialized cache the same way as any other credential.
+///
+/// # Example
+/// ```rust
+/// use graph_oauth::{AuthenticationRecord, MsalTokenCache, Token};
+///
+/// let record = AuthenticationRecord::new(
+///     "uid.utid",
+///     "utid",
+///     "00000000-0000-0000-0000-000000000000",
+///     "login.microsoftonline.com",
+///     Some("user@contoso.com"),
+/// );
+///
+/// let mut token = Token::new("Bearer", 3600, "access_token", vec!["User.Read"]);
+/// token.with_refresh_token("refresh_token");
+///
+/// let mut cache = MsalTokenCache::new();
+/// cache.insert_token(&record, &token);
+///
+/// let json = cache.serialize().unwrap();
+/// let cache = MsalTokenCache::deserialize(&json).unwrap();
+/// let token = cache.token(&record).unwrap();
+/// assert_eq!("access_token", token.access_token.expose_secret());
+/// assert_eq!(
+///     Some("refresh_token"),
+///     token.refresh_token.as_ref().map(|t| t.expose_secret())
+/// );
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MsalTokenCache {
+    #[serde(rename = "Account", default)]
+    pub accounts: BTreeMap<String, MsalAccountEntity>,
+    #[serde(rename = "AccessToken", default)]
+    pub access_tokens: BTreeMap<String, MsalCredentialEntity>,
+    #[serde(rename = "RefreshToken", default)]
+    pub refresh_tokens: BTreeMap<String, MsalCredentialEntity>,
+    #[serde(rename = "IdToken", default)]
+    pub id_tokens: BTreeMap<String, MsalCredentialEntity>,
+    #[serde(rename = "AppMetadata", default)]
+    pub app_metadata: BTreeMap<String, MsalAppMetadataEntity>,
+}
+
+impl MsalTokenCache {
+    pub fn new() -> MsalTokenCache {
+        MsalTokenCache::default()
+    }
+
+    /// Add the tokens of an account to the cache replacing any tokens of the account
+    /// with the same key.
+    pub fn insert_token(&mut self, record: &AuthenticationRecord, token: &Token) {
+        let home_account_id = record.home_account_id.as_str();
+        let environment = record.authority.as_str();
+        let realm = record.tenant_id.as_str();
+        let client_id = record.client_id.as_str();
+        let local_account_id = home_account_id.split('.').next().unwrap_or(home_account_id);
+
+        self.accounts.insert(
+            cache_key(&[home_account_id, environment, realm]),
+            MsalAccountEntity {
+                home_account_id: home_account_id.to_owned(),
+                environment: environment.to_owned(),
+                realm: realm.to_owned(),
+                local_account_id: local_account_id.to_owned(),
+                username: record.username.clone().unwrap_or_default(),
+                authority_type: "MSSTS".to_owned(),
+                client_info: token.client_info.clone(),
+            },
+        );
+
+        let credential = |credential_type: &str, secret: &str| MsalCredentialEntity {
+            credential_type: credential_type.to_owned(),
+            secret: secret.to_owned(),
+            home_account_id: home_account_id.to_owned(),
+            environment: environment.to_owned(),
+            client_id: client_id.to_owned(),
+            ..Default::default()
+        };
+
+        if !token.access_token.expose_secret().is_empty() {
+            let cached_at = token.timestamp.unwrap_or_else(OffsetDateTime::now_utc);
+            let expires_on = token
+                .expires_on
+                .unwrap_or(cached_at + time::Duration::seconds(token.expires_in));
+            let extended_expires_on = token
+                .ext_expires_in
+                .map(|ext_expires_in| cached_at + time::Duration::seconds(ext_expires_in))
+                .unwrap_or(expires_on);
+            let target = token.scope.join(" ");
+
+            self.access_tokens.insert(
+                cache_key(&[
+                    home_account_id,
+                    environment,
+                    "accesstoken",
+                    client_id,
+                    realm,
+                    target.as_str(),
+                ]),
+                MsalCredentialEntity {
+                    realm: Some(realm.to_owned()),
+                    target: Some(target),
+                    token_type: Some(token.token_type.clone()),
+                    cached_at: Some(cached_at.unix_timestamp().to_string()),
+                    expires_on: Some(expires_on.unix_timestamp().to_string()),
+                    extended_expires_on: Some(extended_expires_on.unix_timestamp().to_string()),
+                    ..credential("AccessToken", token.access_token.expose_secret())
+                },
+            );
+        }
+
+        if let Some(refresh_token) = token.refresh_token.as_ref() {
+            self.refresh_tokens.insert(
+                cache_key(&[
+                    home_account_id,
+                    environment,
+                    "refreshtoken",
+                    client_id,
+                    "",
+                    "",
+                ]),
+                credential("RefreshToken", refresh_token.expose_secret()),
+            );
+        }
+
+        if let Some(id_token) = token.id_token.as_ref() {
+            self.id_tokens.insert(
+                cache_key(&[
+                    home_account_id,
+                    environment,
+                    "idtoken",
+                    client_id,
+                    realm,
+                    "",
+                ]),
+                MsalCredentialEntity {
+                    realm: Some(realm.to_owned()),
+                    ..credential("IdToken", id_token.id_token.as_str())
+                },
+            );
+        }
+
+        self.app_metadata.insert(
+            cache_key(&["appmetadata", environment, client_id]),
+            MsalAppMetadataEntity {
+                client_id: client_id.to_owned(),
+                environment: environment.to_owned(),
+            },
+        );
+    }
+
+    /// Get the tokens of an account. The access token that expires last is returned
+    /// along with the refresh token and id token of the account.
+    ///
+    /// If the cache only has a refresh token for the account, the returned token has an
+    /// empty access token that is expired so that it is refreshed on first use.
+    pub fn token(&self, record: &AuthenticationRecord) -> Option<Token> {
+        let matches = |entity: &MsalCredentialEntity| {
+            entity.home_account_id.eq(&record.home_account_id)
+                && entity.environment.eq_ignore_ascii_case(&record.authority)
+                && entity.client_id.eq_ignore_ascii_case(&record.client_id)
+        };
+        let in_realm = |entity: &MsalCredentialEntity| {
+            entity
+                .realm
+                .as_ref()
+                .map(|realm| realm.eq_ignore_ascii_case(&record.tenant_id))
+                .unwrap_or(true)
+        };
+
+        let access_token = self
+            .access_tokens
+            .values()
+            .filter(|entity| matches(entity) && in_realm(entity))
+            .max_by_key(|entity| unix_timestamp(entity.expires_on.as_ref()));
+        let refresh_token = self.refresh_tokens.values().find(|entity| matches(entity));
+        let id_token = self
+            .id_tokens
+            .values()
+            .find(|entity| matches(entity) && in_realm(entity));
+
+        if access_token.is_none() && refresh_token.is_none() {
+            return None;
+        }
+
+        let now = OffsetDateTime::now_utc();
+        let mut token = Token::default();
+        match access_token {
+            Some(entity) => {
+                let expires_on = unix_timestamp(entity.expires_on.as_ref()).unwrap_or(now);
+                token.access_token = SecretString::from(&entity.secret);
+                token.token_type = entity.token_type.clone().unwrap_or_else(|| "Bearer".into());
+                token.scope = entity
+                    .target
+                    .as_deref()
+                    .unwrap_or_default()
+                    .split(' ')
+                    .filter(|scope| !scope.is_empty())
+                    .map(|scope| scope.to_owned())
+                    .collect();
+                token.expires_in = (expires_on - now).whole_seconds().max(0);
+                token.timestamp = unix_timestamp(entity.cached_at.as_ref()).or(Some(now));
+                token.expires_on = Some(expires_on);
+            }
+            None => {
+                token.token_type = "Bearer".into();
+                token.timestamp = Some(now);
+                token.expires_on = Some(now - time::Duration::seconds(1));
+            }
+        }
+
+        token.refresh_token = refresh_token.map(|entity| SecretString::from(&entity.secret));
+        if let Some(entity) = id_token {
+            token.with_id_token(IdToken::new(entity.secret.as_str(), None, None, None));
+        }
+        token.client_info = self
+            .accounts
+            .values()
+            .find(|account| account.home_account_id.eq(&record.home_account_id))
+            .and_then(|account| account.client_info.clone());
+
+        Some(token)
+    }
+
+    /// Serialize the cache to json.
+    pub fn serialize(&self) -> IdentityResult<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Deserialize a cache from json.
+    pub fn deserialize(json: &str) -> IdentityResult<MsalTokenCache> {
+        Ok(serde_json::from_str(json)?)
+    }
+}
+
+/// Cache keys of the MSAL unified token cache schema are lowercase and separated by a dash.
+fn cache_key(parts: &[&str]) -> String {
+    parts.join("-").to_lowercase()
+}
+
+fn unix_timestamp(value: Option<&String>) -> Option<OffsetDateTime> {
+    OffsetDateTime::from_unix_timestamp(value?.parse().ok()?).ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn record() -> AuthenticationRecord {
+        AuthenticationRecord::new(
+            "uid.utid",
+            "utid",
+            "client",
+            "login.microsoftonline.com",
+            Some("user@contoso.com"),
+        )
+    }
+
+    #[test]
+    fn unified_cache_schema_keys() {
+        let mut token = Token::new(
+            "Bearer",
+            3600,
+            "access_token",
+            vec!["User.Read", "Mail.Read"],
+        );
+        token.with_refresh_token("refresh_token");
+
+        let mut cache = MsalTokenCache::new();
+        cache.insert_token(&record(), &token);
+
+        assert!(cache.access_tokens.contains_key(
+            "uid.utid-login.microsoftonline.com-accesstoken-client-utid-user.read mail.read"
+        ));
+        assert!(cache
+            .refresh_tokens
+            .contains_key("uid.utid-login.microsoftonline.com-refreshtoken-client--"));
+        assert!(cache
+            .accounts
+            .contains_key("uid.utid-login.microsoftonline.com-utid"));
+        assert!(cache
+            .app_metadata
+            .contains_key("appmetadata-login.microsoftonline.com-client"));
+    }
+
+    #[test]
+    fn refresh_token_only() {
+        let json = r#"{
+            "RefreshToken": {
+                "uid.utid-login.microsoftonline.com-refreshtoken-client--": {
+                    "credential_type": "RefreshToken",
+                    "secret": "refresh_token",
+                    "home_account_id": "uid.utid",
+                    "environment": "login.microsoftonline.com",
+                    "client_id": "client"
+                }
+            }
+        }"#;
+
+        let cache = MsalTokenCache::deserialize(json).unwrap();
+        let token = cache.token(&record()).unwrap();
+        assert_eq!(
+            Some(SecretString::from("refresh_token")),
+            token.refresh_token
+        );
+        assert!(token.is_expired());
+    }
+}
@@ -0,0 +1,230 @@
+use url::Url;
+
+use graph_error::{AuthExecutionResult, IdentityResult, AF};
+
+/// The authority host of the user realm endpoint.
+pub const REALM_DISCOVERY_HOST: &str = "https://login.microsoftonline.com";
+
+const USER_REALM_API_VERSION: &str = "1.0";
+
+/// Whether the domain of a user is managed by Microsoft Entra ID, federated with
+/// another identity provider, or not known which is the case for personal Microsoft
+/// accounts and domains that are not registered with any tenant.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum AccountType {
+    Managed,
+    Federated,
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+/// The realm of a user principal name returned by the user realm endpoint.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct UserRealm {
+    #[serde(default)]
+    pub account_type: AccountType,
+    #[serde(default)]
+    pub domain_name: Option<String>,
+    #[serde(default)]
+    pub cloud_instance_name: Option<String>,
+    #[serde(default)]
+    pub cloud_audience_urn: Option<String>,
+    #[serde(default)]
+    pub federation_protocol: Option<String>,
+    #[serde(default)]
+    pub federation_metadata_url: Option<String>,
+    #[serde(default)]
+    pub federation_active_auth_url: Option<String>,
+    /// The tenant id of the domain. Only set for managed and federated domains.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant_id: Option<String>,
+}
+
+impl UserRealm {
+    pub fn is_managed(&self) -> bool {
+        self.account_type == AccountType::Managed
+    }
+
+    pub fn is_federated(&self) -> bool {
+        self.account_type == AccountType::Federated
+    }
+
+    /// The `domain_hint` for the authorization request. Users of federated domains are
+    /// sent directly to the sign in page of their identity provider and users of managed
+    /// domains skip the account type selection. Returns None for unknown accounts.
+    pub fn domain_hint(&self) -> Option<&str> {
+        match self.account_type {
+            AccountType::Federated => self.domain_name.as_deref(),
+            AccountType::Managed => Some("organizations"),
+            AccountType::Unknown => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+struct OpenIdConfiguration {
+    token_endpoint: Option<String>,
+    error: Option<String>,
+}
+
+/// Discovers the realm of a user principal name, such as `user@contoso.com`, using the
+/// user realm endpoint of the Microsoft identity platform to decide on the `login_hint`
+/// and `domain_hint` of a sign in request before the user is sent to sign in.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::identity::RealmDiscovery;
+///
+/// let http_client = reqwest::Client::new();
+/// let user_realm = RealmDiscovery::user_realm_async("user@contoso.com", &http_client).await?;
+/// if let Some(domain_hint) = user_realm.domain_hint() {
+///     url_builder.with_domain_hint(domain_hint);
+/// }
+/// println!("{:#?}", user_realm.tenant_id);
+/// ```
+pub struct RealmDiscovery;
+
+impl RealmDiscovery {
+    /// The user realm uri for a user principal name.
+    pub fn user_realm_uri(user_principal_name: &str) -> IdentityResult<Url> {
+        let mut uri = Url::parse(REALM_DISCOVERY_HOST)?;
+        uri.path_segments_mut()
+            .map_err(|_| AF::msg_err("user_realm", "invalid realm discovery host"))?
+            .pop_if_empty()
+            .extend(["common", "userrealm", user_principal_name]);
+        uri.query_pairs_mut()
+            .append_pair("api-version", USER_REALM_API_VERSION);
+        Ok(uri)
+    }
+
+    /// The OpenID configuration uri of the tenant of a domain.
+    pub fn openid_configuration_uri(domain: &str) -> IdentityResult<Url> {
+        let mut uri = Url::parse(REALM_DISCOVERY_HOST)?;
+        uri.path_segments_mut()
+            .map_err(|_| AF::msg_err("user_realm", "invalid realm discovery host"))?
+            .pop_if_empty()
+            .extend([domain, "v2.0", ".well-known", "openid-configuration"]);
+        Ok(uri)
+    }
+
+    fn domain(user_principal_name: &str) -> IdentityResult<&str> {
+        match user_principal_name.rsplit_once('@') {
+            Some((user, domain)) if !user.is_empty() && !domain.is_empty() => Ok(domain),
+            _ => AF::msg_result(
+                "user_principal_name",
+                "user principal name must be in the format user@domain",
+            ),
+        }
+    }
+
+    fn tenant_id(configuration: OpenIdConfiguration) -> Option<String> {
+        if configuration.error.is_some() {
+            return None;
+        }
+        let token_endpoint = Url::parse(configuration.token_endpoint?.as_str()).ok()?;
+        let tenant_id = token_endpoint.path_segments()?.next()?;
+        Some(tenant_id.to_owned())
+    }
+
+    /// Get the realm and tenant id of a user principal name using the given http client.
+    pub fn user_realm(
+        user_principal_name: &str,
+        http_client: &reqwest::blocking::Client,
+    ) -> AuthExecutionResult<UserRealm> {
+        let domain = RealmDiscovery::domain(user_principal_name)?;
+
+        let mut user_realm: UserRealm = http_client
+            .get(RealmDiscovery::user_realm_uri(user_principal_name)?)
+            .send()?
+            .json()?;
+        if user_realm.account_type != AccountType::Unknown {
+            let configuration: OpenIdConfiguration = http_client
+                .get(RealmDiscovery::openid_configuration_uri(domain)?)
+                .send()?
+                .json()?;
+            user_realm.tenant_id = RealmDiscovery::tenant_id(configuration);
+        }
+        Ok(user_realm)
+    }
+
+    /// Get the realm and tenant id of a user principal name using the given http client.
+    pub async fn user_realm_async(
+        user_principal_name: &str,
+        http_client: &reqwest::Client,
+    ) -> AuthExecutionResult<UserRealm> {
+        let domain = RealmDiscovery::domain(user_principal_name)?;
+
+        let mut user_realm: UserRealm = http_client
+            .get(RealmDiscovery::user_realm_uri(user_principal_name)?)
+            .send()
+            .await?
+            .json()
+            .await?;
+        if user_realm.account_type != AccountType::Unknown {
+            let configuration: OpenIdConfiguration = http_client
+                .get(RealmDiscovery::openid_configuration_uri(domain)?)
+                .send()
+                .await?
+                .json()
+                .await?;
+            user_realm.tenant_id = RealmDiscovery::tenant_id(configuration);
+        }
+        Ok(user_realm)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn user_realm_uri() {
+        let uri = RealmDiscovery::user_realm_uri("user@contoso.com").unwrap();
+        assert_eq!(
+            "https://login.microsoftonline.com/common/userrealm/user@contoso.com?api-version=1.0",
+            uri.as_str()
+        );
+        assert!(RealmDiscovery::domain("contoso.com").is_err());
+        assert_eq!(
+            "contoso.com",
+            RealmDiscovery::domain("user@contoso.com").unwrap()
+        );
+    }
+
+    #[test]
+    fn federated_user_realm() {
+        let user_realm: UserRealm = serde_json::from_value(serde_json::json!({
+            "ver": "1.0",
+            "account_type": "Federated",
+            "domain_name": "contoso.com",
+            "federation_protocol": "WSTrust",
+            "federation_metadata_url": "https://sts.contoso.com/adfs/services/trust/mex",
+            "federation_active_auth_url": "https://sts.contoso.com/adfs/services/trust/2005/usernamemixed",
+            "cloud_instance_name": "microsoftonline.com",
+            "cloud_audience_urn": "urn:federation:MicrosoftOnline"
+        }))
+        .unwrap();
+        assert!(user_realm.is_federated());
+        assert_eq!(Some("contoso.com"), user_realm.domain_hint());
+
+        let user_realm: UserRealm = serde_json::from_value(serde_json::json!({
+            "ver": "1.0",
+            "account_type": "Unknown",
+        }))
+        .unwrap();
+        assert_eq!(None, user_realm.domain_hint());
+    }
+
+    #[test]
+    fn tenant_id_from_openid_configuration() {
+        let configuration: OpenIdConfiguration = serde_json::from_value(serde_json::json!({
+            "token_endpoint": "https://login.microsoftonline.com/72f988bf-86f1-41af-91ab-2d7cd011db47/oauth2/v2.0/token",
+        }))
+        .unwrap();
+        assert_eq!(
+            Some("72f988bf-86f1-41af-91ab-2d7cd011db47".to_string()),
+            RealmDiscovery::tenant_id(configuration)
+        );
+    }
+}
@@ -7,8 +7,11 @@ use std::fmt;
 use std::fmt::Display;
 use std::ops::{Add, Sub};
 
-use crate::identity::{AuthorizationResponse, IdToken};
-use graph_core::{cache::AsBearer, identity::Claims};
+use crate::identity::{AuthorizationResponse, IdToken, SecretString};
+use graph_core::{
+    cache::AsBearer,
+    identity::{Claims, LogPolicy},
+};
 use jsonwebtoken::{Algorithm, DecodingKey, TokenData, Validation};
 use time::OffsetDateTime;
 
@@ -49,6 +52,14 @@ struct PhantomToken {
     additional_fields: HashMap<String, Value>,
 }
 
+/// Previous name of [Token] in graph-oauth. Kept for one release to ease migration.
+#[deprecated(note = "use Token")]
+pub type MsalTokenResponse = Token;
+
+/// Previous name of [Token] in graph-extensions. Kept for one release to ease migration.
+#[deprecated(note = "use Token")]
+pub type MsalToken = Token;
+
 /// An access token is a security token issued by an authorization server as part of an OAuth 2.0 flow.
 /// It contains information about the user and the resource for which the token is intended.
 /// The information can be used to access web APIs and other protected resources.
@@ -79,7 +90,7 @@ pub struct Token {
     ///
     /// See [Access Token](https://www.rfc-editor.org/rfc/rfc6749.html#section-1.4) in
     /// the specification
-    pub access_token: String,
+    pub access_token: SecretString,
     pub token_type: String,
     #[serde(deserialize_with = "deserialize_number_from_string")]
     pub expires_in: i64,
@@ -106,7 +117,7 @@ pub struct Token {
     /// exchange this refresh token for a new access token when needed.
     /// For more information, see
     /// [Refresh tokens in the Microsoft identity platform.](https://learn.microsoft.com/en-us/azure/active-directory/develop/refresh-tokens)
-    pub refresh_token: Option<String>,
+    pub refresh_token: Option<SecretString>,
     pub user_id: Option<String>,
     pub id_token: Option<IdToken>,
     pub state: Option<String>,
@@ -119,8 +130,10 @@ pub struct Token {
     /// Any extra returned fields for AccessToken.
     #[serde(flatten)]
     pub additional_fields: HashMap<String, Value>,
+    /// How the access token, refresh token, and id token are written to debug output.
+    /// Defaults to [LogPolicy::Redacted].
     #[serde(skip)]
-    pub log_pii: bool,
+    pub log_policy: LogPolicy,
 }
 
 impl Token {
@@ -138,7 +151,7 @@ impl Token {
             ext_expires_in: None,
             expires_in,
             scope: scope.into_iter().map(|s| s.to_string()).collect(),
-            access_token: access_token.into(),
+            access_token: SecretString::new(access_token),
             refresh_token: None,
             user_id: None,
             id_token: None,
@@ -150,7 +163,26 @@ impl Token {
             timestamp: Some(timestamp),
             expires_on: Some(expires_on),
             additional_fields: Default::default(),
-            log_pii: false,
+            log_policy: LogPolicy::default(),
+        }
+    }
+
+    /// An expired token holding only a refresh token, such as a refresh token read from
+    /// persistent storage. The expired token causes the credential to use the refresh token
+    /// to get a new access token.
+    ///
+    /// # Example
+    /// ```
+    /// # use graph_oauth::Token;
+    ///
+    /// let token = Token::from_refresh_token("refresh_token");
+    /// assert!(token.is_expired());
+    /// ```
+    pub fn from_refresh_token(refresh_token: impl Into<SecretString>) -> Token {
+        Token {
+            token_type: "Bearer".into(),
+            refresh_token: Some(refresh_token.into()),
+            ..Default::default()
         }
     }
 
@@ -209,7 +241,7 @@ impl Token {
     /// access_token.with_access_token("ASODFIUJ34KJ;LADSK");
     /// ```
     pub fn with_access_token(&mut self, s: &str) -> &mut Self {
-        self.access_token = s.into();
+        self.access_token = SecretString::new(s);
         self
     }
 
@@ -223,7 +255,7 @@ impl Token {
     /// access_token.with_refresh_token("#ASOD323U5342");
     /// ```
     pub fn with_refresh_token(&mut self, s: &str) -> &mut Self {
-        self.refresh_token = Some(s.to_string());
+        self.refresh_token = Some(SecretString::new(s));
         self
     }
 
@@ -284,12 +316,24 @@ impl Token {
     }
 
     /// Enable or disable logging of personally identifiable information such
-    /// as logging the id_token. This is disabled by default. When log_pii is enabled
+    /// as logging the id_token. This is disabled by default. When enabled
     /// passing [Token] to logging or print functions will log both the bearer
     /// access token value, the refresh token value if any, and the id token value.
     /// By default these do not get logged.
+    ///
+    /// Same as calling [Token::with_log_policy] with [LogPolicy::Full] or [LogPolicy::Redacted].
     pub fn enable_pii_logging(&mut self, log_pii: bool) {
-        self.log_pii = log_pii;
+        self.with_log_policy(LogPolicy::from_pii_logging(log_pii));
+    }
+
+    /// Set how the access token, refresh token, and id token are written to debug output.
+    /// The policy is also applied to the id token.
+    pub fn with_log_policy(&mut self, log_policy: LogPolicy) -> &mut Self {
+        self.log_policy = log_policy;
+        if let Some(id_token) = self.id_token.as_mut() {
+            id_token.with_log_policy(log_policy);
+        }
+        self
     }
 
     /// Timestamp field is used to tell whether the access token is expired.
@@ -422,7 +466,7 @@ impl Default for Token {
             expires_in: 0,
             ext_expires_in: None,
             scope: vec![],
-            access_token: String::new(),
+            access_token: SecretString::default(),
             refresh_token: None,
             user_id: None,
             id_token: None,
@@ -436,7 +480,7 @@ impl Default for Token {
                 OffsetDateTime::from_unix_timestamp(0).unwrap_or(time::OffsetDateTime::UNIX_EPOCH),
             ),
             additional_fields: Default::default(),
-            log_pii: false,
+            log_policy: LogPolicy::default(),
         }
     }
 }
@@ -450,6 +494,7 @@ impl TryFrom<AuthorizationResponse> for Token {
         Ok(Token {
             access_token: value
                 .access_token
+                .map(SecretString::new)
                 .ok_or_else(|| AF::msg_err("access_token", "access_token is None"))?,
             token_type: "Bearer".to_string(),
             expires_in: value.expires_in.unwrap_or_default(),
@@ -466,20 +511,20 @@ impl TryFrom<AuthorizationResponse> for Token {
             timestamp: None,
             expires_on: None,
             additional_fields: Default::default(),
-            log_pii: false,
+            log_policy: LogPolicy::default(),
         })
     }
 }
 
 impl Display for Token {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.access_token)
+        write!(f, "{}", self.access_token.expose_secret())
     }
 }
 
 impl AsBearer for Token {
     fn as_bearer(&self) -> String {
-        self.access_token.to_string()
+        self.access_token.expose_secret().to_owned()
     }
 }
 
@@ -521,50 +566,36 @@ impl TryFrom<reqwest::blocking::Response> for Token {
 
 impl fmt::Debug for Token {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.log_pii {
-            f.debug_struct("MsalAccessToken")
-                .field("bearer_token", &self.access_token)
-                .field("refresh_token", &self.refresh_token)
-                .field("token_type", &self.token_type)
-                .field("expires_in", &self.expires_in)
-                .field("scope", &self.scope)
-                .field("user_id", &self.user_id)
-                .field("id_token", &self.id_token)
-                .field("state", &self.state)
-                .field("timestamp", &self.timestamp)
-                .field("expires_on", &self.expires_on)
-                .field("additional_fields", &self.additional_fields)
-                .finish()
-        } else {
-            f.debug_struct("MsalAccessToken")
-                .field(
-                    "bearer_token",
-                    &"[REDACTED]  - call enable_pii_logging(true) to log value",
-                )
-                .field(
-                    "refresh_token",
-                    &"[REDACTED] - call enable_pii_logging(true) to log value",
-                )
-                .field("token_type", &self.token_type)
-                .field("expires_in", &self.expires_in)
-                .field("scope", &self.scope)
-                .field("user_id", &self.user_id)
-                .field(
-                    "id_token",
-                    &"[REDACTED] - call enable_pii_logging(true) to log value",
-                )
-                .field("state", &self.state)
-                .field("timestamp", &self.timestamp)
-                .field("expires_on", &self.expires_on)
-                .field("additional_fields", &self.additional_fields)
-                .finish()
-        }
+        let mut debug = f.debug_struct("MsalAccessToken");
+        self.log_policy.debug_field(
+            &mut debug,
+            "bearer_token",
+            &self.access_token.expose_secret(),
+        );
+        self.log_policy.debug_field(
+            &mut debug,
+            "refresh_token",
+            &self.refresh_token.as_ref().map(|t| t.expose_secret()),
+        );
+        debug
+            .field("token_type", &self.token_type)
+            .field("expires_in", &self.expires_in)
+            .field("scope", &self.scope)
+            .field("user_id", &self.user_id);
+        self.log_policy
+            .debug_field(&mut debug, "id_token", &self.id_token);
+        debug
+            .field("state", &self.state)
+            .field("timestamp", &self.timestamp)
+            .field("expires_on", &self.expires_on)
+            .field("additional_fields", &self.additional_fields)
+            .finish()
     }
 }
 
 impl AsRef<str> for Token {
     fn as_ref(&self) -> &str {
-        self.access_token.as_str()
+        self.access_token.expose_secret()
     }
 }
 
@@ -580,13 +611,13 @@ impl<'de> Deserialize<'de> for Token {
             .id_token
             .map(|id_token_string| IdToken::new(id_token_string.as_ref(), None, None, None));
 
-        let token = Token {
-            access_token: phantom_access_token.access_token,
+        Ok(Token {
+            access_token: SecretString::new(phantom_access_token.access_token),
             token_type: phantom_access_token.token_type,
             expires_in: phantom_access_token.expires_in,
             ext_expires_in: phantom_access_token.ext_expires_in,
             scope: phantom_access_token.scope,
-            refresh_token: phantom_access_token.refresh_token,
+            refresh_token: phantom_access_token.refresh_token.map(SecretString::new),
             user_id: phantom_access_token.user_id,
             id_token,
             state: phantom_access_token.state,
@@ -597,12 +628,8 @@ impl<'de> Deserialize<'de> for Token {
             timestamp: Some(timestamp),
             expires_on: Some(expires_on),
             additional_fields: phantom_access_token.additional_fields,
-            log_pii: false,
-        };
-
-        // tracing::debug!(target: "phantom", token.as_value());
-
-        Ok(token)
+            log_policy: LogPolicy::default(),
+        })
     }
 }
 
@@ -667,7 +694,7 @@ mod test {
             error_description: None,
             error_uri: None,
             additional_fields: Default::default(),
-            log_pii: false,
+            log_policy: LogPolicy::default(),
         };
 
         let token = Token::try_from(authorization_response).unwrap();
@@ -680,9 +707,30 @@ mod test {
                 Some("session_state")
             ))
         );
-        assert_eq!(token.access_token, "token".to_string());
+        assert_eq!(token.access_token.expose_secret(), "token");
         assert_eq!(token.state, Some("state".to_string()));
         assert_eq!(token.session_state, Some("session_state".to_string()));
         assert_eq!(token.expires_in, 3600);
     }
+
+    #[test]
+    fn debug_output_follows_log_policy() {
+        let mut token = Token::new("Bearer", 3600, "secret_access_token", vec!["User.Read"]);
+        token.with_refresh_token("secret_refresh_token");
+
+        let debug = format!("{token:?}");
+        assert!(debug.contains("bearer_token: \"[REDACTED]\""));
+        assert!(!debug.contains("secret_access_token"));
+        assert!(!debug.contains("secret_refresh_token"));
+
+        token.with_log_policy(LogPolicy::None);
+        let debug = format!("{token:?}");
+        assert!(!debug.contains("bearer_token"));
+        assert!(!debug.contains("[REDACTED]"));
+
+        token.enable_pii_logging(true);
+        let debug = format!("{token:?}");
+        assert!(debug.contains("secret_access_token"));
+        assert!(debug.contains("secret_refresh_token"));
+    }
 }
@@ -0,0 +1,64 @@
+/// Options that apply to token requests of every credential.
+///
+/// # Example
+/// ```rust
+/// use graph_oauth::TokenCredentialOptions;
+///
+/// let mut options = TokenCredentialOptions::new();
+/// options.with_instance_discovery(false);
+/// options.with_telemetry(true);
+/// assert!(!options.instance_discovery());
+/// assert!(options.telemetry());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct TokenCredentialOptions {
+    pub(crate) instance_discovery: bool,
+    pub(crate) telemetry: bool,
+}
+
+impl TokenCredentialOptions {
+    pub fn new() -> TokenCredentialOptions {
+        TokenCredentialOptions::default()
+    }
+
+    /// Validate the authority host using instance discovery before a token is requested.
+    /// Enabled by default.
+    ///
+    /// Instance discovery is skipped when the instance is set explicitly using
+    /// `with_instance` and for Azure AD B2C and ADFS authorities. Disable instance
+    /// discovery for other authorities that are not known to the Microsoft identity
+    /// platform such as Azure Stack.
+    pub fn with_instance_discovery(&mut self, instance_discovery: bool) -> &mut Self {
+        self.instance_discovery = instance_discovery;
+        self
+    }
+
+    pub fn instance_discovery(&self) -> bool {
+        self.instance_discovery
+    }
+
+    /// Send the client identification headers (x-client-SKU, x-client-VER, x-client-OS)
+    /// and the telemetry headers of previous failed requests (x-client-last-telemetry)
+    /// with token requests. Disabled by default.
+    ///
+    /// The headers are used by Microsoft to diagnose sign-in and conditional access
+    /// issues and do not contain any personal data. Failed requests are only reported
+    /// by later token requests of the same client application and its clones.
+    pub fn with_telemetry(&mut self, telemetry: bool) -> &mut Self {
+        self.telemetry = telemetry;
+        self
+    }
+
+    pub fn telemetry(&self) -> bool {
+        self.telemetry
+    }
+}
+
+impl Default for TokenCredentialOptions {
+    fn default() -> Self {
+        TokenCredentialOptions {
+            instance_discovery: true,
+            telemetry: false,
+        }
+    }
+}
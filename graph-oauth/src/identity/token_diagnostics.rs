@@ -0,0 +1,402 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use http::StatusCode;
+use serde_json::Value;
+use time::OffsetDateTime;
+
+use crate::identity::Token;
+
+/// The audiences of access tokens for Microsoft Graph in the public and national clouds.
+/// Tokens for the national clouds use the Microsoft Graph resource of the cloud.
+const GRAPH_AUDIENCES: [&str; 11] = [
+    "https://graph.microsoft.com",
+    "https://graph.microsoft.com/",
+    "https://microsoftgraph.chinacloudapi.cn",
+    "https://microsoftgraph.chinacloudapi.cn/",
+    "https://graph.microsoft.de",
+    "https://graph.microsoft.de/",
+    "https://graph.microsoft.us",
+    "https://graph.microsoft.us/",
+    "https://dod-graph.microsoft.us",
+    "https://dod-graph.microsoft.us/",
+    "00000003-0000-0000-c000-000000000000",
+];
+
+/// The claims of an access token that are useful when diagnosing failed requests.
+///
+/// See [Access token claims reference](https://learn.microsoft.com/en-us/entra/identity-platform/access-token-claims-reference)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AccessTokenClaims {
+    #[serde(default)]
+    pub aud: Option<String>,
+    #[serde(default)]
+    pub iss: Option<String>,
+    #[serde(default)]
+    pub tid: Option<String>,
+    /// The client id of the application. Version 2.0 tokens use `azp`.
+    #[serde(default, alias = "azp")]
+    pub appid: Option<String>,
+    #[serde(default)]
+    pub app_displayname: Option<String>,
+    /// Space separated delegated permissions.
+    #[serde(default)]
+    pub scp: Option<String>,
+    /// Application permissions.
+    #[serde(default)]
+    pub roles: Vec<String>,
+    #[serde(default)]
+    pub exp: Option<i64>,
+    #[serde(default)]
+    pub nbf: Option<i64>,
+    /// Client capabilities, such as `cp1` for continuous access evaluation.
+    #[serde(default)]
+    pub xms_cc: Vec<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+/// A likely reason that a request using the access token failed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TokenIssue {
+    /// The access token is not a JWT so the claims can't be read. Access tokens for
+    /// personal Microsoft accounts are opaque to the client.
+    NotJwt,
+    Expired {
+        exp: i64,
+    },
+    NotYetValid {
+        nbf: i64,
+    },
+    WrongAudience {
+        aud: String,
+    },
+    WrongTenant {
+        expected: String,
+        actual: String,
+    },
+    MissingPermissions {
+        missing: Vec<String>,
+    },
+    NoPermissions,
+}
+
+impl Display for TokenIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TokenIssue::NotJwt => write!(
+                f,
+                "the access token is not a JWT and its claims can't be inspected"
+            ),
+            TokenIssue::Expired { exp } => write!(f, "the access token expired at {exp}"),
+            TokenIssue::NotYetValid { nbf } => {
+                write!(f, "the access token is not valid before {nbf}")
+            }
+            TokenIssue::WrongAudience { aud } => write!(
+                f,
+                "the access token audience is {aud} and not Microsoft Graph, request the token using scopes such as https://graph.microsoft.com/.default"
+            ),
+            TokenIssue::WrongTenant { expected, actual } => write!(
+                f,
+                "the access token was issued by tenant {actual} but tenant {expected} was expected"
+            ),
+            TokenIssue::MissingPermissions { missing } => write!(
+                f,
+                "the access token does not contain the permissions: {}",
+                missing.join(", ")
+            ),
+            TokenIssue::NoPermissions => write!(
+                f,
+                "the access token contains no delegated permissions (scp) or application permissions (roles), grant admin consent or request the required scopes"
+            ),
+        }
+    }
+}
+
+/// Decode the claims of an access token without validating the token to explain why a
+/// request to Microsoft Graph most likely failed with a 401 Unauthorized or 403 Forbidden.
+///
+/// The token is not validated and must not be used to make authorization decisions.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::identity::TokenDiagnostics;
+///
+/// let diagnostics = TokenDiagnostics::new(token.access_token.expose_secret())
+///     .with_tenant("tenant-id")
+///     .with_required_permissions(["Mail.Read"]);
+/// println!("{}", diagnostics.report(response.status()));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenDiagnostics {
+    claims: Option<AccessTokenClaims>,
+    tenant_id: Option<String>,
+    audiences: Vec<String>,
+    required_permissions: Vec<String>,
+}
+
+impl TokenDiagnostics {
+    pub fn new(access_token: impl AsRef<str>) -> TokenDiagnostics {
+        TokenDiagnostics {
+            claims: TokenDiagnostics::decode_claims(access_token.as_ref()),
+            tenant_id: None,
+            audiences: GRAPH_AUDIENCES.iter().map(|aud| aud.to_string()).collect(),
+            required_permissions: Vec::new(),
+        }
+    }
+
+    fn decode_claims(access_token: &str) -> Option<AccessTokenClaims> {
+        let mut parts = access_token.split('.');
+        let payload = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(_), Some(payload), Some(_), None) => payload,
+            _ => return None,
+        };
+        let bytes = URL_SAFE_NO_PAD.decode(payload.trim_end_matches('=')).ok()?;
+        serde_json::from_slice(&bytes).ok()
+    }
+
+    /// The tenant the token is expected to be issued by.
+    pub fn with_tenant(mut self, tenant_id: impl AsRef<str>) -> Self {
+        self.tenant_id = Some(tenant_id.as_ref().to_owned());
+        self
+    }
+
+    /// The expected audience of the token when the token is not for Microsoft Graph.
+    pub fn with_audience(mut self, audience: impl AsRef<str>) -> Self {
+        self.audiences = vec![audience.as_ref().to_owned()];
+        self
+    }
+
+    /// The delegated or application permissions required by the request such as
+    /// `User.Read` or `Mail.Read`. Any one of the permissions in the Microsoft Graph
+    /// documentation for the request is enough, so pass only the least privileged
+    /// permission that the application requested.
+    pub fn with_required_permissions<T: ToString, I: IntoIterator<Item = T>>(
+        mut self,
+        permissions: I,
+    ) -> Self {
+        self.required_permissions = permissions
+            .into_iter()
+            .map(|permission| permission.to_string())
+            .collect();
+        self
+    }
+
+    /// The decoded claims or None if the access token is not a JWT.
+    pub fn claims(&self) -> Option<&AccessTokenClaims> {
+        self.claims.as_ref()
+    }
+
+    /// The delegated permissions and application permissions of the token.
+    pub fn permissions(&self) -> Vec<&str> {
+        self.claims
+            .as_ref()
+            .map(|claims| {
+                claims
+                    .scp
+                    .iter()
+                    .flat_map(|scp| scp.split_whitespace())
+                    .chain(claims.roles.iter().map(|role| role.as_str()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn issues(&self) -> Vec<TokenIssue> {
+        let claims = match self.claims.as_ref() {
+            Some(claims) => claims,
+            None => return vec![TokenIssue::NotJwt],
+        };
+        let mut issues = Vec::new();
+
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+        if let Some(exp) = claims.exp.filter(|exp| *exp <= now) {
+            issues.push(TokenIssue::Expired { exp });
+        }
+        if let Some(nbf) = claims.nbf.filter(|nbf| *nbf > now) {
+            issues.push(TokenIssue::NotYetValid { nbf });
+        }
+
+        if let Some(aud) = claims.aud.as_ref() {
+            if !self.audiences.iter().any(|audience| audience.eq(aud)) {
+                issues.push(TokenIssue::WrongAudience { aud: aud.clone() });
+            }
+        }
+
+        if let (Some(expected), Some(actual)) = (self.tenant_id.as_ref(), claims.tid.as_ref()) {
+            if !expected.eq_ignore_ascii_case(actual) {
+                issues.push(TokenIssue::WrongTenant {
+                    expected: expected.clone(),
+                    actual: actual.clone(),
+                });
+            }
+        }
+
+        let permissions = self.permissions();
+        if permissions.is_empty() {
+            issues.push(TokenIssue::NoPermissions);
+        } else {
+            let missing: Vec<String> = self
+                .required_permissions
+                .iter()
+                .filter(|required| {
+                    !permissions
+                        .iter()
+                        .any(|permission| permission.eq_ignore_ascii_case(required))
+                })
+                .cloned()
+                .collect();
+            if !missing.is_empty() {
+                issues.push(TokenIssue::MissingPermissions { missing });
+            }
+        }
+
+        issues
+    }
+
+    /// A human readable report of the token and the likely reasons that the request
+    /// failed with the given status code.
+    pub fn report(&self, status: StatusCode) -> String {
+        let mut report = format!("Request failed with status {status}.\n");
+        if let Some(claims) = self.claims.as_ref() {
+            let app = claims
+                .app_displayname
+                .as_deref()
+                .or(claims.appid.as_deref())
+                .unwrap_or("unknown");
+            report.push_str(&format!(
+                "Token: app {app}, tenant {}, audience {}, permissions [{}]",
+                claims.tid.as_deref().unwrap_or("unknown"),
+                claims.aud.as_deref().unwrap_or("unknown"),
+                self.permissions().join(", ")
+            ));
+            if !claims.xms_cc.is_empty() {
+                report.push_str(&format!(
+                    ", client capabilities [{}]",
+                    claims.xms_cc.join(", ")
+                ));
+            }
+            report.push('\n');
+        }
+
+        let issues = self.issues();
+        if issues.is_empty() {
+            match status {
+                StatusCode::UNAUTHORIZED => report.push_str(
+                    "No problem found with the token claims. The token may have been revoked or, for continuous access evaluation, a claims challenge must be satisfied.\n",
+                ),
+                StatusCode::FORBIDDEN => report.push_str(
+                    "No problem found with the token claims. The signed in user or application may not have access to the resource.\n",
+                ),
+                _ => report.push_str("No problem found with the token claims.\n"),
+            }
+        } else {
+            for issue in issues {
+                report.push_str(&format!("- {issue}\n"));
+            }
+        }
+        report
+    }
+}
+
+impl From<&Token> for TokenDiagnostics {
+    fn from(value: &Token) -> Self {
+        TokenDiagnostics::new(value.access_token.expose_secret())
+    }
+}
+
+impl Display for TokenDiagnostics {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let issues = self.issues();
+        if issues.is_empty() {
+            return write!(f, "no problem found with the access token");
+        }
+        let issues: Vec<String> = issues.iter().map(|issue| issue.to_string()).collect();
+        write!(f, "{}", issues.join("; "))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn access_token(claims: Value) -> String {
+        format!(
+            "{}.{}.signature",
+            URL_SAFE_NO_PAD.encode(r#"{"alg":"RS256","typ":"JWT"}"#),
+            URL_SAFE_NO_PAD.encode(claims.to_string())
+        )
+    }
+
+    #[test]
+    fn diagnose_claims() {
+        let exp = OffsetDateTime::now_utc().unix_timestamp() + 3600;
+        let diagnostics = TokenDiagnostics::new(access_token(serde_json::json!({
+            "aud": "https://graph.microsoft.com",
+            "tid": "tenant-id",
+            "appid": "client-id",
+            "scp": "User.Read Mail.Read",
+            "exp": exp
+        })));
+        assert!(diagnostics.issues().is_empty());
+        assert_eq!(vec!["User.Read", "Mail.Read"], diagnostics.permissions());
+
+        let diagnostics = diagnostics
+            .with_tenant("other-tenant-id")
+            .with_required_permissions(["Mail.Send"]);
+        assert_eq!(
+            vec![
+                TokenIssue::WrongTenant {
+                    expected: "other-tenant-id".into(),
+                    actual: "tenant-id".into()
+                },
+                TokenIssue::MissingPermissions {
+                    missing: vec!["Mail.Send".into()]
+                }
+            ],
+            diagnostics.issues()
+        );
+    }
+
+    #[test]
+    fn diagnose_expired_app_token() {
+        let diagnostics = TokenDiagnostics::new(access_token(serde_json::json!({
+            "aud": "api://other",
+            "exp": 1000
+        })));
+        assert_eq!(
+            vec![
+                TokenIssue::Expired { exp: 1000 },
+                TokenIssue::WrongAudience {
+                    aud: "api://other".into()
+                },
+                TokenIssue::NoPermissions
+            ],
+            diagnostics.issues()
+        );
+        assert!(diagnostics
+            .report(StatusCode::UNAUTHORIZED)
+            .contains("expired"));
+    }
+
+    #[test]
+    fn national_cloud_audience() {
+        let exp = OffsetDateTime::now_utc().unix_timestamp() + 3600;
+        let diagnostics = TokenDiagnostics::new(access_token(serde_json::json!({
+            "aud": "https://microsoftgraph.chinacloudapi.cn",
+            "iss": "https://sts.chinacloudapi.cn/tenant-id/",
+            "roles": ["User.Read.All"],
+            "exp": exp
+        })));
+        assert!(diagnostics.issues().is_empty());
+    }
+
+    #[test]
+    fn opaque_token() {
+        let diagnostics = TokenDiagnostics::new("EwBwA8l6BAAU");
+        assert!(diagnostics.claims().is_none());
+        assert_eq!(vec![TokenIssue::NotJwt], diagnostics.issues());
+    }
+}
@@ -0,0 +1,99 @@
+use graph_core::cache::{CacheStore, InMemoryCacheStore};
+
+#[cfg(feature = "platform-keyring")]
+use crate::identity::KeyringTokenCache;
+use crate::identity::{EncryptedFileTokenCache, Token};
+
+/// The token cache of credentials for flows that return refresh tokens.
+#[derive(Clone)]
+pub(crate) enum TokenStore {
+    InMemory(InMemoryCacheStore<Token>),
+    EncryptedFile(EncryptedFileTokenCache),
+    #[cfg(feature = "platform-keyring")]
+    Keyring(KeyringTokenCache),
+}
+
+impl Default for TokenStore {
+    fn default() -> Self {
+        TokenStore::InMemory(InMemoryCacheStore::new())
+    }
+}
+
+impl TokenStore {
+    /// Replace the token store keeping the token cached for the cache id so that
+    /// a token cached before the token store was set is not lost.
+    pub(crate) fn replace(&mut self, cache_id: &str, token_store: impl Into<TokenStore>) {
+        let mut token_store = token_store.into();
+        if let Some(token) = self.get(cache_id) {
+            token_store.store(cache_id, token);
+        }
+        *self = token_store;
+    }
+}
+
+impl CacheStore<Token> for TokenStore {
+    fn store<T: Into<String>>(&mut self, cache_id: T, token: Token) {
+        match self {
+            TokenStore::InMemory(store) => store.store(cache_id, token),
+            TokenStore::EncryptedFile(store) => store.store(cache_id, token),
+            #[cfg(feature = "platform-keyring")]
+            TokenStore::Keyring(store) => store.store(cache_id, token),
+        }
+    }
+
+    fn get(&self, cache_id: &str) -> Option<Token> {
+        match self {
+            TokenStore::InMemory(store) => store.get(cache_id),
+            TokenStore::EncryptedFile(store) => store.get(cache_id),
+            #[cfg(feature = "platform-keyring")]
+            TokenStore::Keyring(store) => store.get(cache_id),
+        }
+    }
+
+    fn evict(&self, cache_id: &str) -> Option<Token> {
+        match self {
+            TokenStore::InMemory(store) => store.evict(cache_id),
+            TokenStore::EncryptedFile(store) => store.evict(cache_id),
+            #[cfg(feature = "platform-keyring")]
+            TokenStore::Keyring(store) => store.evict(cache_id),
+        }
+    }
+}
+
+impl From<EncryptedFileTokenCache> for TokenStore {
+    fn from(value: EncryptedFileTokenCache) -> Self {
+        TokenStore::EncryptedFile(value)
+    }
+}
+
+#[cfg(feature = "platform-keyring")]
+impl From<KeyringTokenCache> for TokenStore {
+    fn from(value: KeyringTokenCache) -> Self {
+        TokenStore::Keyring(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn replace_keeps_cached_token() {
+        let mut token_store = TokenStore::default();
+        token_store.store(
+            "cache-id",
+            Token::new("Bearer", 3600, "ACCESS_TOKEN", vec!["User.Read"]),
+        );
+
+        let new_store = TokenStore::default();
+        token_store.replace("cache-id", new_store.clone());
+        assert_eq!(
+            Some("ACCESS_TOKEN".to_string()),
+            new_store
+                .get("cache-id")
+                .map(|token| token.access_token.expose_secret().to_owned())
+        );
+        assert!(token_store.get("cache-id").is_some());
+        assert!(token_store.get("other-cache-id").is_none());
+    }
+}
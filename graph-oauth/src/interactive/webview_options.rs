@@ -57,6 +57,12 @@ pub struct WebViewOptions {
     ///
     /// Default is false
     pub clear_browsing_data: bool,
+    /// Generate a secure random state for the authorization request if one is not set
+    /// and validate that the state of the authorization response matches the state
+    /// of the request to prevent cross-site request forgery attacks.
+    ///
+    /// Default is true
+    pub validate_state: bool,
 }
 
 impl WebViewOptions {
@@ -99,6 +105,14 @@ impl WebViewOptions {
         self.clear_browsing_data = clear_browsing_data;
         self
     }
+
+    /// Generate a secure random state for the authorization request if one is not set
+    /// and validate that the state of the authorization response matches the state
+    /// of the request. Enabled by default.
+    pub fn validate_state(mut self, validate_state: bool) -> Self {
+        self.validate_state = validate_state;
+        self
+    }
 }
 
 #[cfg(windows)]
@@ -110,6 +124,7 @@ impl Default for WebViewOptions {
             ports: Default::default(),
             timeout: None,
             clear_browsing_data: Default::default(),
+            validate_state: true,
         }
     }
 }
@@ -122,6 +137,7 @@ impl Default for WebViewOptions {
             ports: Default::default(),
             timeout: None,
             clear_browsing_data: Default::default(),
+            validate_state: true,
         }
     }
 }
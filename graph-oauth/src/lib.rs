@@ -62,5 +62,6 @@ pub mod extensions {
 }
 
 pub use crate::identity::*;
-pub use graph_core::{crypto::GenPkce, crypto::ProofKeyCodeExchange};
+pub use graph_core::identity::LogPolicy;
+pub use graph_core::{crypto::ct_eq, crypto::GenPkce, crypto::ProofKeyCodeExchange};
 pub use jsonwebtoken::{Header, TokenData};
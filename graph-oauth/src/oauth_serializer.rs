@@ -6,6 +6,7 @@ use std::fmt::Display;
 
 use url::form_urlencoded::Serializer;
 
+use graph_core::identity::LogPolicy;
 use graph_error::{AuthorizationFailure, IdentityResult};
 
 use crate::identity::{AsQuery, Prompt, ResponseType};
@@ -119,7 +120,7 @@ impl AsRef<str> for AuthParameter {
 pub struct AuthSerializer {
     scopes: BTreeSet<String>,
     parameters: BTreeMap<String, String>,
-    log_pii: bool,
+    log_policy: LogPolicy,
 }
 
 impl AuthSerializer {
@@ -135,10 +136,17 @@ impl AuthSerializer {
         AuthSerializer {
             scopes: BTreeSet::new(),
             parameters: BTreeMap::new(),
-            log_pii: false,
+            log_policy: LogPolicy::default(),
         }
     }
 
+    /// Set how secret parameters such as the client secret and refresh token are
+    /// written to debug output. Defaults to [LogPolicy::Redacted].
+    pub fn with_log_policy(&mut self, log_policy: LogPolicy) -> &mut Self {
+        self.log_policy = log_policy;
+        self
+    }
+
     /// Insert oauth credentials using the OAuthParameter enum.
     /// This method is used internally for each of the setter methods.
     /// Callers can optionally use this method to set credentials instead
@@ -728,12 +736,14 @@ impl fmt::Debug for AuthSerializer {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut map_debug: BTreeMap<&str, &str> = BTreeMap::new();
         for (key, value) in self.parameters.iter() {
-            if self.log_pii {
+            if self.log_policy.is_full() {
                 map_debug.insert(key.as_str(), value.as_str());
             } else if let Some(oac) = AuthParameter::iter()
                 .find(|oac| oac.alias().eq(key.as_str()) && oac.is_debug_redacted())
             {
-                map_debug.insert(oac.alias(), "[REDACTED]");
+                if self.log_policy.ne(&LogPolicy::None) {
+                    map_debug.insert(oac.alias(), self.log_policy.redact(value.as_str()));
+                }
             } else {
                 map_debug.insert(key.as_str(), value.as_str());
             }
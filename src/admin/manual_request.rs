@@ -0,0 +1,248 @@
+use crate::admin::*;
+use crate::api_default_imports::*;
+use crate::client::{odata_date_time, odata_string};
+use crate::types::{
+    ServiceHealthClassificationType, ServiceHealthStatus, ServiceUpdateCategory,
+    ServiceUpdateSeverity,
+};
+use std::fmt::{Display, Formatter};
+use time::OffsetDateTime;
+
+/// Builds the `$filter` of a service health issues query. Every condition must match.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::admin::ServiceHealthIssueFilter;
+/// use graph_rs_sdk::types::{Collection, ServiceHealthClassificationType, ServiceHealthIssue};
+///
+/// let filter = ServiceHealthIssueFilter::new()
+///     .service("Exchange Online")
+///     .classification(ServiceHealthClassificationType::Incident)
+///     .unresolved();
+///
+/// let response: GraphResponse<Collection<ServiceHealthIssue>> = client
+///     .admin()
+///     .list_issues_filtered(&filter)
+///     .send_json()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ServiceHealthIssueFilter {
+    conditions: Vec<String>,
+}
+
+impl ServiceHealthIssueFilter {
+    pub fn new() -> ServiceHealthIssueFilter {
+        ServiceHealthIssueFilter::default()
+    }
+
+    /// Issues of a service such as `Exchange Online` or `Microsoft Teams`.
+    pub fn service(mut self, service: impl AsRef<str>) -> ServiceHealthIssueFilter {
+        self.conditions
+            .push(format!("service eq {}", odata_string(service.as_ref())));
+        self
+    }
+
+    pub fn classification(
+        mut self,
+        classification: ServiceHealthClassificationType,
+    ) -> ServiceHealthIssueFilter {
+        self.conditions
+            .push(format!("classification eq '{}'", classification.as_str()));
+        self
+    }
+
+    pub fn status(mut self, status: ServiceHealthStatus) -> ServiceHealthIssueFilter {
+        self.conditions
+            .push(format!("status eq '{}'", status.as_str()));
+        self
+    }
+
+    /// Issues that have not been resolved.
+    pub fn unresolved(mut self) -> ServiceHealthIssueFilter {
+        self.conditions.push("isResolved eq false".to_owned());
+        self
+    }
+
+    /// Issues that started at or after the given date and time.
+    pub fn started_after(mut self, date_time: OffsetDateTime) -> ServiceHealthIssueFilter {
+        self.conditions
+            .push(format!("startDateTime ge {}", odata_date_time(date_time)));
+        self
+    }
+
+    /// Issues that started before the given date and time.
+    pub fn started_before(mut self, date_time: OffsetDateTime) -> ServiceHealthIssueFilter {
+        self.conditions
+            .push(format!("startDateTime lt {}", odata_date_time(date_time)));
+        self
+    }
+
+    /// Issues that were updated at or after the given date and time such as the time
+    /// of the last poll of a monitor.
+    pub fn modified_after(mut self, date_time: OffsetDateTime) -> ServiceHealthIssueFilter {
+        self.conditions.push(format!(
+            "lastModifiedDateTime ge {}",
+            odata_date_time(date_time)
+        ));
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.conditions.is_empty()
+    }
+}
+
+impl Display for ServiceHealthIssueFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.conditions.join(" and "))
+    }
+}
+
+/// Builds the `$filter` of a message center messages query. Every condition must match.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::admin::ServiceUpdateMessageFilter;
+/// use graph_rs_sdk::types::{Collection, ServiceUpdateMessage, ServiceUpdateSeverity};
+///
+/// let filter = ServiceUpdateMessageFilter::new()
+///     .severity(ServiceUpdateSeverity::High)
+///     .modified_after(OffsetDateTime::now_utc() - Duration::days(7));
+///
+/// let response: GraphResponse<Collection<ServiceUpdateMessage>> = client
+///     .admin()
+///     .list_messages_filtered(&filter)
+///     .send_json()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ServiceUpdateMessageFilter {
+    conditions: Vec<String>,
+}
+
+impl ServiceUpdateMessageFilter {
+    pub fn new() -> ServiceUpdateMessageFilter {
+        ServiceUpdateMessageFilter::default()
+    }
+
+    /// Messages about a service such as `Exchange Online` or `Microsoft Teams`.
+    pub fn service(mut self, service: impl AsRef<str>) -> ServiceUpdateMessageFilter {
+        self.conditions.push(format!(
+            "services/any(s:s eq {})",
+            odata_string(service.as_ref())
+        ));
+        self
+    }
+
+    pub fn severity(mut self, severity: ServiceUpdateSeverity) -> ServiceUpdateMessageFilter {
+        self.conditions
+            .push(format!("severity eq '{}'", severity.as_str()));
+        self
+    }
+
+    pub fn category(mut self, category: ServiceUpdateCategory) -> ServiceUpdateMessageFilter {
+        self.conditions
+            .push(format!("category eq '{}'", category.as_str()));
+        self
+    }
+
+    /// Messages announcing a major change.
+    pub fn major_change(mut self) -> ServiceUpdateMessageFilter {
+        self.conditions.push("isMajorChange eq true".to_owned());
+        self
+    }
+
+    /// Messages that were published or updated at or after the given date and time.
+    pub fn modified_after(mut self, date_time: OffsetDateTime) -> ServiceUpdateMessageFilter {
+        self.conditions.push(format!(
+            "lastModifiedDateTime ge {}",
+            odata_date_time(date_time)
+        ));
+        self
+    }
+
+    /// Messages with an action required by date at or before the given date and time.
+    pub fn action_required_before(
+        mut self,
+        date_time: OffsetDateTime,
+    ) -> ServiceUpdateMessageFilter {
+        self.conditions.push(format!(
+            "actionRequiredByDateTime le {}",
+            odata_date_time(date_time)
+        ));
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.conditions.is_empty()
+    }
+}
+
+impl Display for ServiceUpdateMessageFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.conditions.join(" and "))
+    }
+}
+
+impl AdminApiClient {
+    /// List the health of every Microsoft 365 service in the tenant with the
+    /// issues of each service expanded.
+    pub fn list_health_overviews_with_issues(&self) -> RequestHandler {
+        self.list_health_overviews().expand(&["issues"])
+    }
+
+    /// List service health issues that match the filter. Use [RequestHandler::paging]
+    /// to stream every page of the results.
+    pub fn list_issues_filtered(&self, filter: &ServiceHealthIssueFilter) -> RequestHandler {
+        let request = self.list_issues();
+        if filter.is_empty() {
+            return request;
+        }
+        request.filter(&[filter.to_string().as_str()])
+    }
+
+    /// List message center messages that match the filter. Use [RequestHandler::paging]
+    /// to stream every page of the results.
+    pub fn list_messages_filtered(&self, filter: &ServiceUpdateMessageFilter) -> RequestHandler {
+        let request = self.list_messages();
+        if filter.is_empty() {
+            return request;
+        }
+        request.filter(&[filter.to_string().as_str()])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn service_health_issue_filter() {
+        let filter = ServiceHealthIssueFilter::new()
+            .service("Exchange Online")
+            .classification(ServiceHealthClassificationType::Incident)
+            .unresolved()
+            .started_after(OffsetDateTime::from_unix_timestamp(1704067200).unwrap());
+
+        assert_eq!(
+            "service eq 'Exchange Online' and classification eq 'incident' \
+             and isResolved eq false and startDateTime ge 2024-01-01T00:00:00Z",
+            filter.to_string()
+        );
+    }
+
+    #[test]
+    fn service_update_message_filter() {
+        let filter = ServiceUpdateMessageFilter::new()
+            .service("Microsoft Teams")
+            .severity(ServiceUpdateSeverity::Critical)
+            .category(ServiceUpdateCategory::PlanForChange);
+
+        assert_eq!(
+            "services/any(s:s eq 'Microsoft Teams') and severity eq 'critical' \
+             and category eq 'planForChange'",
+            filter.to_string()
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use crate::api_default_imports::*;
+use crate::applications::*;
+use crate::types::{KeyCredential, PasswordCredential, RedirectUris};
+
+impl ApplicationsIdApiClient {
+    /// Add a client secret to the application.
+    ///
+    /// The returned credential contains the `secret_text` which is only returned
+    /// once and cannot be retrieved later.
+    pub async fn add_client_secret(
+        &self,
+        password_credential: &PasswordCredential,
+    ) -> GraphResult<PasswordCredential> {
+        let response: GraphResponse<PasswordCredential> = self
+            .add_password(&serde_json::json!({ "passwordCredential": password_credential }))
+            .send_json()
+            .await?;
+        Ok(response.into_body())
+    }
+
+    /// Add a certificate to the application.
+    ///
+    /// The proof is a JWT signed with the private key of an existing valid certificate
+    /// of the application. Applications that do not have a valid certificate must
+    /// add the first certificate by updating the `keyCredentials` of the application.
+    pub fn add_key_credential(
+        &self,
+        key_credential: &KeyCredential,
+        proof: impl AsRef<str>,
+    ) -> RequestHandler {
+        self.add_key(&serde_json::json!({
+            "keyCredential": key_credential,
+            "passwordCredential": null,
+            "proof": proof.as_ref()
+        }))
+    }
+
+    /// Replace the redirect uris of the web platform of the application.
+    pub fn set_web_redirect_uris<T: ToString, I: IntoIterator<Item = T>>(
+        &self,
+        redirect_uris: I,
+    ) -> RequestHandler {
+        self.update_application(&serde_json::json!({ "web": RedirectUris::new(redirect_uris) }))
+    }
+
+    /// Replace the redirect uris of the single page application platform of the application.
+    pub fn set_spa_redirect_uris<T: ToString, I: IntoIterator<Item = T>>(
+        &self,
+        redirect_uris: I,
+    ) -> RequestHandler {
+        self.update_application(&serde_json::json!({ "spa": RedirectUris::new(redirect_uris) }))
+    }
+
+    /// Replace the redirect uris of the mobile and desktop platform of the application.
+    pub fn set_public_client_redirect_uris<T: ToString, I: IntoIterator<Item = T>>(
+        &self,
+        redirect_uris: I,
+    ) -> RequestHandler {
+        self.update_application(
+            &serde_json::json!({ "publicClient": RedirectUris::new(redirect_uris) }),
+        )
+    }
+}
@@ -0,0 +1,148 @@
+use crate::api_default_imports::*;
+use crate::audit_logs::*;
+use crate::client::{odata_date_time, odata_string};
+use std::fmt::{Display, Formatter};
+use time::OffsetDateTime;
+
+/// Builds the `$filter` of a sign-in log query from the commonly filtered properties.
+/// Every condition must match.
+///
+/// # Example
+/// ```rust,ignore
+/// use futures::StreamExt;
+/// use graph_rs_sdk::audit_logs::SignInFilter;
+/// use graph_rs_sdk::types::{Collection, SignIn};
+///
+/// let filter = SignInFilter::new()
+///     .created_after(OffsetDateTime::now_utc() - Duration::days(1))
+///     .app_id("00000003-0000-0000-c000-000000000000")
+///     .failed();
+///
+/// let mut stream = client
+///     .audit_logs()
+///     .list_sign_ins_filtered(&filter)
+///     .paging()
+///     .stream::<Collection<SignIn>>()?;
+///
+/// while let Some(result) = stream.next().await {
+///     for sign_in in result?.into_body()? {
+///         println!("{:#?}", sign_in.user_principal_name);
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SignInFilter {
+    conditions: Vec<String>,
+    advanced_query: bool,
+}
+
+impl SignInFilter {
+    pub fn new() -> SignInFilter {
+        SignInFilter::default()
+    }
+
+    /// Sign-ins at or after the given date and time.
+    pub fn created_after(mut self, date_time: OffsetDateTime) -> SignInFilter {
+        self.conditions
+            .push(format!("createdDateTime ge {}", odata_date_time(date_time)));
+        self
+    }
+
+    /// Sign-ins before the given date and time.
+    pub fn created_before(mut self, date_time: OffsetDateTime) -> SignInFilter {
+        self.conditions
+            .push(format!("createdDateTime lt {}", odata_date_time(date_time)));
+        self
+    }
+
+    pub fn app_id(mut self, app_id: impl AsRef<str>) -> SignInFilter {
+        self.conditions
+            .push(format!("appId eq {}", odata_string(app_id.as_ref())));
+        self
+    }
+
+    pub fn user_id(mut self, user_id: impl AsRef<str>) -> SignInFilter {
+        self.conditions
+            .push(format!("userId eq {}", odata_string(user_id.as_ref())));
+        self
+    }
+
+    pub fn user_principal_name(mut self, user_principal_name: impl AsRef<str>) -> SignInFilter {
+        self.conditions.push(format!(
+            "userPrincipalName eq {}",
+            odata_string(user_principal_name.as_ref())
+        ));
+        self
+    }
+
+    /// Sign-ins that failed with the given error code.
+    pub fn error_code(mut self, error_code: i64) -> SignInFilter {
+        self.conditions
+            .push(format!("status/errorCode eq {error_code}"));
+        self
+    }
+
+    /// Successful sign-ins.
+    pub fn successful(self) -> SignInFilter {
+        self.error_code(0)
+    }
+
+    /// Sign-ins that failed with any error code.
+    pub fn failed(mut self) -> SignInFilter {
+        self.conditions.push("status/errorCode ne 0".to_owned());
+        self.advanced_query = true;
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.conditions.is_empty()
+    }
+}
+
+impl Display for SignInFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.conditions.join(" and "))
+    }
+}
+
+impl AuditLogsApiClient {
+    /// List sign-ins that match the filter. Use [RequestHandler::paging] to stream
+    /// every page of the results.
+    ///
+    /// The `ConsistencyLevel: eventual` header is set when the filter uses the `ne` operator.
+    pub fn list_sign_ins_filtered(&self, filter: &SignInFilter) -> RequestHandler {
+        let mut request = self.list_sign_ins();
+        if !filter.is_empty() {
+            request = request.filter(&[filter.to_string().as_str()]);
+        }
+        if filter.advanced_query {
+            request = request.consistency_level_eventual();
+        }
+        request
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sign_in_filter() {
+        let filter = SignInFilter::new()
+            .created_after(OffsetDateTime::from_unix_timestamp(1704067200).unwrap())
+            .created_before(
+                OffsetDateTime::from_unix_timestamp(1704153600)
+                    .unwrap()
+                    .to_offset(time::UtcOffset::from_hms(2, 0, 0).unwrap()),
+            )
+            .user_principal_name("o'neil@contoso.com")
+            .failed();
+
+        assert_eq!(
+            "createdDateTime ge 2024-01-01T00:00:00Z and createdDateTime lt 2024-01-02T00:00:00Z \
+             and userPrincipalName eq 'o''neil@contoso.com' and status/errorCode ne 0",
+            filter.to_string()
+        );
+        assert!(filter.advanced_query);
+    }
+}
@@ -1,3 +1,5 @@
+mod manual_request;
 mod request;
 
+pub use manual_request::*;
 pub use request::*;
@@ -0,0 +1,594 @@
+use crate::api_default_imports::*;
+use crate::client::Graph;
+use crate::header::{HeaderName, HeaderValue};
+use futures::StreamExt;
+use graph_http::traits::ResponseExt;
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+/// The maximum number of requests in a JSON batch.
+const MAX_BATCH_SIZE: usize = 20;
+
+/// A request of a JSON batch executed by a [BulkExecutor].
+///
+/// The url is relative to the version of the api such as `/users/{id}`. Use
+/// [BatchOperation::depends_on] to run the operation only after another operation
+/// has completed successfully. Operations that depend on each other are always sent
+/// in the same batch.
+///
+/// See [Combine multiple requests in one HTTP call using JSON batching](https://learn.microsoft.com/en-us/graph/json-batching)
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchOperation {
+    id: String,
+    method: String,
+    url: String,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    headers: BTreeMap<String, String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<Value>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    depends_on: Vec<String>,
+}
+
+impl BatchOperation {
+    pub fn new(id: impl AsRef<str>, method: Method, url: impl AsRef<str>) -> BatchOperation {
+        let url = url.as_ref();
+        BatchOperation {
+            id: id.as_ref().to_owned(),
+            method: method.as_str().to_owned(),
+            url: if url.starts_with('/') {
+                url.to_owned()
+            } else {
+                format!("/{url}")
+            },
+            headers: BTreeMap::new(),
+            body: None,
+            depends_on: Vec::new(),
+        }
+    }
+
+    pub fn get(id: impl AsRef<str>, url: impl AsRef<str>) -> BatchOperation {
+        BatchOperation::new(id, Method::GET, url)
+    }
+
+    pub fn post(id: impl AsRef<str>, url: impl AsRef<str>, body: Value) -> BatchOperation {
+        BatchOperation::new(id, Method::POST, url).body(body)
+    }
+
+    pub fn patch(id: impl AsRef<str>, url: impl AsRef<str>, body: Value) -> BatchOperation {
+        BatchOperation::new(id, Method::PATCH, url).body(body)
+    }
+
+    pub fn put(id: impl AsRef<str>, url: impl AsRef<str>, body: Value) -> BatchOperation {
+        BatchOperation::new(id, Method::PUT, url).body(body)
+    }
+
+    pub fn delete(id: impl AsRef<str>, url: impl AsRef<str>) -> BatchOperation {
+        BatchOperation::new(id, Method::DELETE, url)
+    }
+
+    /// Create the resource addressed by an alternate key, such as
+    /// `/applications(uniqueName='app-name')`, or update it if it already exists.
+    pub fn upsert(id: impl AsRef<str>, url: impl AsRef<str>, body: Value) -> BatchOperation {
+        BatchOperation::patch(id, url, body).header("Prefer", "create-if-missing")
+    }
+
+    /// The JSON body of the request. Sets the `Content-Type` of the request to
+    /// `application/json` as required for requests with a body.
+    pub fn body(mut self, body: Value) -> BatchOperation {
+        self.body = Some(body);
+        self.headers
+            .entry("Content-Type".to_owned())
+            .or_insert_with(|| "application/json".to_owned());
+        self
+    }
+
+    pub fn header(mut self, name: impl AsRef<str>, value: impl AsRef<str>) -> BatchOperation {
+        self.headers
+            .insert(name.as_ref().to_owned(), value.as_ref().to_owned());
+        self
+    }
+
+    /// Run this operation only after the operation with the given id has completed
+    /// successfully. The operation fails with 424 Failed Dependency otherwise.
+    pub fn depends_on(mut self, id: impl AsRef<str>) -> BatchOperation {
+        self.depends_on.push(id.as_ref().to_owned());
+        self
+    }
+
+    pub fn id(&self) -> &str {
+        self.id.as_str()
+    }
+}
+
+/// The response of a single operation executed by a [BulkExecutor].
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+pub struct BulkResult {
+    pub id: String,
+    pub status: u16,
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    #[serde(default)]
+    pub body: Option<Value>,
+    /// The number of times the operation was sent, including retries.
+    #[serde(skip)]
+    pub attempts: usize,
+}
+
+impl BulkResult {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// The error of a failed operation such as the `error` of a 400 Bad Request response.
+    pub fn error_message(&self) -> Option<ErrorMessage> {
+        if self.is_success() {
+            return None;
+        }
+        self.body
+            .as_ref()
+            .and_then(|body| serde_json::from_value(body.clone()).ok())
+    }
+
+    /// The result of an operation of a batch that failed as a whole. The status is 0
+    /// because the operation has no response of its own and the error of the batch is
+    /// the body so that it is returned by [BulkResult::error_message].
+    fn batch_failed(operation: &BatchOperation, error: &GraphFailure) -> BulkResult {
+        let error_message = match error {
+            GraphFailure::ErrorMessage(error_message)
+            | GraphFailure::PreconditionFailed(error_message) => error_message.clone(),
+            _ => ErrorMessage {
+                error: ErrorStatus {
+                    message: Some(error.to_string()),
+                    ..Default::default()
+                },
+            },
+        };
+
+        BulkResult {
+            id: operation.id.clone(),
+            body: serde_json::to_value(error_message).ok(),
+            attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Operations that failed with 424 Failed Dependency, were throttled, or failed
+    /// with a transient server error. A POST that failed with 500, 502, or 504 may have
+    /// been applied and is not retried so that resources are not created twice.
+    fn is_retryable(&self, operation: &BatchOperation) -> bool {
+        match self.status {
+            424 | 429 | 503 => true,
+            500 | 502 | 504 => operation.method != Method::POST.as_str(),
+            _ => false,
+        }
+    }
+
+    fn retry_after(&self, attempt: usize) -> Duration {
+        self.headers
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case("retry-after"))
+            .and_then(|(_, value)| value.trim().parse::<u64>().ok())
+            .map(Duration::from_secs)
+            .unwrap_or_else(|| Duration::from_secs(2u64.pow(attempt.min(6) as u32)))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct BatchResponse {
+    #[serde(default)]
+    responses: Vec<BulkResult>,
+}
+
+/// Executes any number of operations using JSON batching.
+///
+/// The operations are split into batches of at most 20 requests with operations that
+/// depend on each other in the same batch, and the batches are sent concurrently.
+/// Batches wait for the `Retry-After` of any throttled response of the client before
+/// they are sent and are retried when the batch itself is throttled. Use
+/// [GraphClientConfiguration::throttle_threshold] to also delay requests when the
+/// throttling budget of the client is low.
+///
+/// Operations that fail with 424 Failed Dependency, 429 Too Many Requests, or a transient
+/// server error are retried individually, after the operations they depend on have
+/// succeeded, up to [BulkExecutor::max_retries] times. POST operations are not retried
+/// after 500, 502, or 504 because the operation may have been applied.
+///
+/// A batch that fails as a whole fails each of its operations with the error of the
+/// batch in [BulkResult::error_message]. The other batches are still executed.
+///
+/// # Example
+/// ```rust,ignore
+/// let operations = users
+///     .iter()
+///     .enumerate()
+///     .map(|(i, user)| {
+///         BatchOperation::patch(i.to_string(), format!("/users/{}", user.id), user.update())
+///     })
+///     .collect();
+///
+/// let results = BulkExecutor::new(&client)
+///     .concurrency(4)
+///     .execute(operations)
+///     .await?;
+///
+/// for result in results.iter().filter(|result| !result.is_success()) {
+///     println!("{} failed: {:#?}", result.id, result.error_message());
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct BulkExecutor {
+    client: Graph,
+    batch_size: usize,
+    concurrency: usize,
+    max_retries: usize,
+}
+
+impl BulkExecutor {
+    pub fn new(client: &Graph) -> BulkExecutor {
+        BulkExecutor {
+            client: client.clone(),
+            batch_size: MAX_BATCH_SIZE,
+            concurrency: 4,
+            max_retries: 3,
+        }
+    }
+
+    /// The maximum number of operations in a batch. Defaults to and cannot be more than 20.
+    pub fn batch_size(mut self, batch_size: usize) -> BulkExecutor {
+        self.batch_size = batch_size.clamp(1, MAX_BATCH_SIZE);
+        self
+    }
+
+    /// The number of batches sent at the same time. Defaults to 4.
+    pub fn concurrency(mut self, concurrency: usize) -> BulkExecutor {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// The number of times a batch or a failed operation is retried. Defaults to 3.
+    pub fn max_retries(mut self, max_retries: usize) -> BulkExecutor {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Execute the operations and return the result of each operation in the order of
+    /// the operations.
+    ///
+    /// Returns an error without sending any request if the ids of the operations are not
+    /// unique, an operation depends on an operation that does not exist, or more operations
+    /// depend on each other than fit in a batch. The operations of a batch that fails
+    /// as a whole are returned as failed results.
+    pub async fn execute(&self, operations: Vec<BatchOperation>) -> GraphResult<Vec<BulkResult>> {
+        let batches = self.split(&operations)?;
+
+        let mut results: HashMap<String, BulkResult> = HashMap::new();
+        let mut responses = futures::stream::iter(batches.iter())
+            .map(|batch| async move { (batch, self.send_batch(batch).await) })
+            .buffer_unordered(self.concurrency);
+        while let Some((batch, result)) = responses.next().await {
+            match result {
+                Ok(responses) => {
+                    for response in responses {
+                        results.insert(response.id.clone(), response);
+                    }
+                }
+                Err(error) => {
+                    for operation in batch {
+                        results.insert(
+                            operation.id.clone(),
+                            BulkResult::batch_failed(operation, &error),
+                        );
+                    }
+                }
+            }
+        }
+
+        for operation in batches.iter().flatten() {
+            self.retry_operation(operation, &mut results).await;
+        }
+
+        Ok(operations
+            .iter()
+            .map(|operation| {
+                results
+                    .remove(operation.id())
+                    .unwrap_or_else(|| BulkResult {
+                        id: operation.id.clone(),
+                        ..Default::default()
+                    })
+            })
+            .collect())
+    }
+
+    /// Split the operations into batches keeping operations that depend on each other
+    /// in the same batch with the operations they depend on first.
+    fn split(&self, operations: &[BatchOperation]) -> GraphResult<Vec<Vec<BatchOperation>>> {
+        let mut index = HashMap::new();
+        for (i, operation) in operations.iter().enumerate() {
+            if index.insert(operation.id(), i).is_some() {
+                return Err(GraphFailure::invalid("unique id for every batch operation"));
+            }
+        }
+
+        let mut groups: Vec<usize> = (0..operations.len()).collect();
+        fn find(groups: &mut [usize], i: usize) -> usize {
+            let mut root = i;
+            while groups[root] != root {
+                root = groups[root];
+            }
+            groups[i] = root;
+            root
+        }
+
+        for (i, operation) in operations.iter().enumerate() {
+            for id in operation.depends_on.iter() {
+                let j = *index.get(id.as_str()).ok_or_else(|| {
+                    GraphFailure::invalid("batch operation for every dependsOn id")
+                })?;
+                let (a, b) = (find(&mut groups, i), find(&mut groups, j));
+                groups[a.max(b)] = a.min(b);
+            }
+        }
+
+        let mut components: Vec<Vec<usize>> = Vec::new();
+        let mut component_of = HashMap::new();
+        for i in 0..operations.len() {
+            let root = find(&mut groups, i);
+            let component = *component_of.entry(root).or_insert_with(|| {
+                components.push(Vec::new());
+                components.len() - 1
+            });
+            components[component].push(i);
+        }
+
+        let mut batches: Vec<Vec<BatchOperation>> = Vec::new();
+        for component in components {
+            if component.len() > self.batch_size {
+                return Err(GraphFailure::invalid(
+                    "operations that depend on each other within the batch size",
+                ));
+            }
+
+            let ordered = BulkExecutor::dependencies_first(operations, &component, &index)?;
+            match batches.last_mut() {
+                Some(batch) if batch.len() + ordered.len() <= self.batch_size => {
+                    batch.extend(ordered)
+                }
+                _ => batches.push(ordered),
+            }
+        }
+
+        Ok(batches)
+    }
+
+    fn dependencies_first(
+        operations: &[BatchOperation],
+        component: &[usize],
+        index: &HashMap<&str, usize>,
+    ) -> GraphResult<Vec<BatchOperation>> {
+        let mut ordered = Vec::with_capacity(component.len());
+        let mut added = HashSet::new();
+
+        while ordered.len() < component.len() {
+            let ready: Vec<usize> = component
+                .iter()
+                .copied()
+                .filter(|i| !added.contains(i))
+                .filter(|i| {
+                    operations[*i]
+                        .depends_on
+                        .iter()
+                        .all(|id| added.contains(&index[id.as_str()]))
+                })
+                .collect();
+
+            if ready.is_empty() {
+                return Err(GraphFailure::invalid(
+                    "batch operations without circular dependencies",
+                ));
+            }
+
+            for i in ready {
+                added.insert(i);
+                ordered.push(operations[i].clone());
+            }
+        }
+
+        Ok(ordered)
+    }
+
+    /// Wait for the `Retry-After` of the last throttled response of the client.
+    async fn wait_for_throttling(&self) {
+        let retry_after_at = self.client.throttle_budget().state().retry_after_at;
+        if let Some(retry_after_at) = retry_after_at {
+            let now = Instant::now();
+            if retry_after_at > now {
+                tokio::time::sleep(retry_after_at - now).await;
+            }
+        }
+    }
+
+    async fn send_batch(&self, batch: &[BatchOperation]) -> GraphResult<Vec<BulkResult>> {
+        let body = serde_json::json!({ "requests": batch });
+        let mut attempt = 0;
+
+        loop {
+            self.wait_for_throttling().await;
+            let response = self.client.batch(&body).send().await?;
+            let status = response.status();
+
+            if matches!(
+                status,
+                StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE
+            ) && attempt < self.max_retries
+            {
+                let wait = retry_after(response.headers())
+                    .unwrap_or_else(|| Duration::from_secs(2u64.pow(attempt.min(6) as u32)));
+                tokio::time::sleep(wait).await;
+                attempt += 1;
+                continue;
+            }
+
+            let response = response.error_for_graph_status().await?;
+
+            let batch_response: BatchResponse = response.json().await?;
+            return Ok(batch_response
+                .responses
+                .into_iter()
+                .map(|result| BulkResult {
+                    attempts: 1,
+                    ..result
+                })
+                .collect());
+        }
+    }
+
+    /// Retry a failed operation on its own once the operations it depends on have
+    /// succeeded. The last result is kept if the operation cannot be sent.
+    async fn retry_operation(
+        &self,
+        operation: &BatchOperation,
+        results: &mut HashMap<String, BulkResult>,
+    ) {
+        let dependencies_succeeded = operation.depends_on.iter().all(|id| {
+            results
+                .get(id)
+                .map(|result| result.is_success())
+                .unwrap_or_default()
+        });
+        if !dependencies_succeeded {
+            return;
+        }
+
+        let Some(mut result) = results.remove(operation.id()) else {
+            return;
+        };
+
+        while result.is_retryable(operation) && result.attempts <= self.max_retries {
+            if result.status != 424 {
+                tokio::time::sleep(result.retry_after(result.attempts)).await;
+            }
+
+            match self.send_operation(operation).await {
+                Ok(retry) => {
+                    result = BulkResult {
+                        attempts: result.attempts + 1,
+                        ..retry
+                    }
+                }
+                Err(_) => break,
+            }
+        }
+
+        results.insert(operation.id.clone(), result);
+    }
+
+    async fn send_operation(&self, operation: &BatchOperation) -> GraphResult<BulkResult> {
+        let method = Method::from_bytes(operation.method.as_bytes())
+            .map_err(|_| GraphFailure::invalid("http method of batch operation"))?;
+        let url = Url::parse(
+            format!(
+                "{}{}",
+                self.client.url().as_str().trim_end_matches('/'),
+                operation.url
+            )
+            .as_str(),
+        )?;
+
+        let mut request = self.client.custom(
+            method,
+            operation
+                .body
+                .as_ref()
+                .map(|body| BodyRead::from(body.to_string())),
+        );
+        *request.as_mut() = url;
+        for (name, value) in operation.headers.iter() {
+            let name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|_| GraphFailure::invalid("header name of batch operation"))?;
+            let value = HeaderValue::from_str(value)
+                .map_err(|_| GraphFailure::invalid("header value of batch operation"))?;
+            request = request.header(name, value);
+        }
+
+        self.wait_for_throttling().await;
+        let response = request.send().await?;
+        let status = response.status().as_u16();
+        let headers = response
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| {
+                value
+                    .to_str()
+                    .ok()
+                    .map(|value| (name.as_str().to_owned(), value.to_owned()))
+            })
+            .collect();
+        let bytes = response.bytes().await?;
+        let body =
+            if bytes.is_empty() {
+                None
+            } else {
+                Some(serde_json::from_slice(&bytes).unwrap_or_else(|_| {
+                    Value::String(String::from_utf8_lossy(&bytes).into_owned())
+                }))
+            };
+
+        Ok(BulkResult {
+            id: operation.id.clone(),
+            status,
+            headers,
+            body,
+            attempts: 0,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn dependent_operations_are_in_the_same_batch() {
+        let executor = BulkExecutor::new(&Graph::new("token")).batch_size(3);
+        let operations = vec![
+            BatchOperation::get("1", "/users/1"),
+            BatchOperation::get("2", "/users/2"),
+            BatchOperation::patch("4", "/groups/1", serde_json::json!({})).depends_on("3"),
+            BatchOperation::post("3", "/groups", serde_json::json!({})).depends_on("2"),
+            BatchOperation::get("5", "/users/5"),
+        ];
+
+        let batches = executor.split(&operations).unwrap();
+        let ids: Vec<Vec<&str>> = batches
+            .iter()
+            .map(|batch| batch.iter().map(|operation| operation.id()).collect())
+            .collect();
+        assert_eq!(vec![vec!["1"], vec!["2", "3", "4"], vec!["5"]], ids);
+    }
+
+    #[test]
+    fn invalid_dependencies_are_rejected() {
+        let executor = BulkExecutor::new(&Graph::new("token"));
+        assert!(executor
+            .split(&[BatchOperation::get("1", "/users/1").depends_on("2")])
+            .is_err());
+        assert!(executor
+            .split(&[
+                BatchOperation::get("1", "/users/1").depends_on("2"),
+                BatchOperation::get("2", "/users/2").depends_on("1"),
+            ])
+            .is_err());
+        assert!(executor
+            .split(&[
+                BatchOperation::get("1", "/users/1"),
+                BatchOperation::get("1", "/users/2"),
+            ])
+            .is_err());
+    }
+}
@@ -1,3 +1,7 @@
+mod bulk_executor;
+
+pub use bulk_executor::*;
+
 use crate::api_default_imports::*;
 use crate::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
 
@@ -0,0 +1,30 @@
+use crate::api_default_imports::*;
+use crate::chats::*;
+use crate::types::ChatMessage;
+
+impl ChatsMessagesApiClient {
+    /// Send a new message in a channel or chat.
+    ///
+    /// Mentions and hosted content, such as inline images, are sent in the same request
+    /// as the message. See [ChatMessage] for building the message body.
+    pub fn send_chat_message(&self, message: &ChatMessage) -> RequestHandler {
+        self.create_messages(message)
+    }
+
+    /// Get the messages that were added, updated, or deleted since the delta token was
+    /// issued.
+    ///
+    /// The delta token is the `$deltatoken` query parameter of the `@odata.deltaLink`
+    /// returned by the last page of a previous delta request.
+    pub fn delta_from_token(&self, delta_token: impl AsRef<str>) -> RequestHandler {
+        self.delta()
+            .append_query_pair("$deltatoken", delta_token.as_ref())
+    }
+}
+
+impl ChatsMessagesIdApiClient {
+    /// Reply to the message in its thread.
+    pub fn send_reply(&self, message: &ChatMessage) -> RequestHandler {
+        self.replies().create_replies(message)
+    }
+}
@@ -1,5 +1,6 @@
 mod chats_messages;
 mod chats_messages_replies;
+mod manual_request;
 mod request;
 
 pub use chats_messages::*;
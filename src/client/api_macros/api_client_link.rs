@@ -71,16 +71,8 @@ macro_rules! api_client_link {
             let resource_identity = <$return_type as ResourceIdentifier>::resource_identifier();
             let mut resource_config = self.resource_config.clone();
 
-            if let Some(resource_identity_id) = resource_config.resource_identity_id.as_ref() {
-                resource_config.extend_path(&[
-                    resource_config.resource_identity.to_string(),
-                    resource_identity_id.to_string(),
-                ]);
-            } else {
-                resource_config.extend_path(&[resource_config.resource_identity.to_string()]);
-            }
-
-            resource_config.resource_identity_id = None;
+            resource_config.extend_path_with_id(resource_config.resource_identity.to_string());
+
             resource_config.resource_identity = resource_identity;
             <$return_type>::new(self.client.clone(), resource_config, Handlebars::new())
         }
@@ -90,16 +82,8 @@ macro_rules! api_client_link {
         pub fn $name(&self) -> $return_type {
             let mut resource_config = self.resource_config.clone();
 
-            if let Some(resource_identity_id) = resource_config.resource_identity_id.as_ref() {
-                resource_config.extend_path(&[
-                    resource_config.resource_identity.to_string(),
-                    resource_identity_id.to_string(),
-                ]);
-            } else {
-                resource_config.extend_path(&[resource_config.resource_identity.to_string()]);
-            }
+            resource_config.extend_path_with_id(resource_config.resource_identity.to_string());
 
-            resource_config.resource_identity_id = None;
             resource_config.resource_identity = $resource_identity;
             <$return_type>::new(self.client.clone(), resource_config, Handlebars::new())
         }
@@ -109,14 +93,8 @@ macro_rules! api_client_link {
         pub fn $name(&self) -> $return_type {
             let mut resource_config = self.resource_config.clone();
 
-            if let Some(resource_identity_id) = resource_config.resource_identity_id.as_ref() {
-                resource_config
-                    .extend_path(&[$resource_path.to_string(), resource_identity_id.to_string()]);
-            } else {
-                resource_config.extend_path(&[$resource_path.to_string()]);
-            }
+            resource_config.extend_path_with_id($resource_path.to_string());
 
-            resource_config.resource_identity_id = None;
             resource_config.resource_identity = $resource_identity;
             <$return_type>::new(self.client.clone(), resource_config, Handlebars::new())
         }
@@ -134,14 +112,7 @@ macro_rules! api_client_link_id {
             let resource_identity = <$return_type as ResourceIdentifier>::resource_identifier();
             let mut resource_config = self.resource_config.clone();
 
-            if let Some(resource_identity_id) = resource_config.resource_identity_id.as_ref() {
-                resource_config.extend_path(&[
-                    resource_config.resource_identity.to_string(),
-                    resource_identity_id.to_string(),
-                ]);
-            } else {
-                resource_config.extend_path(&[resource_config.resource_identity.to_string()]);
-            }
+            resource_config.extend_path_with_id(resource_config.resource_identity.to_string());
 
             let id_str = id.as_ref();
             resource_config.resource_identity = resource_identity;
@@ -158,14 +129,7 @@ macro_rules! api_client_link_id {
         pub fn $name<ID: AsRef<str>>(&self, id: ID) -> $return_type {
             let mut resource_config = self.resource_config.clone();
 
-            if let Some(resource_identity_id) = resource_config.resource_identity_id.as_ref() {
-                resource_config.extend_path(&[
-                    resource_config.resource_identity.to_string(),
-                    resource_identity_id.to_string(),
-                ]);
-            } else {
-                resource_config.extend_path(&[resource_config.resource_identity.to_string()]);
-            }
+            resource_config.extend_path_with_id(resource_config.resource_identity.to_string());
 
             let id_str = id.as_ref();
             resource_config.resource_identity = $resource_identity;
@@ -182,12 +146,7 @@ macro_rules! api_client_link_id {
         pub fn $name<ID: AsRef<str>>(&self, id: ID) -> $return_type {
             let mut resource_config = self.resource_config.clone();
 
-            if let Some(resource_identity_id) = resource_config.resource_identity_id.as_ref() {
-                resource_config
-                    .extend_path(&[$resource_path.to_string(), resource_identity_id.to_string()]);
-            } else {
-                resource_config.extend_path(&[$resource_path.to_string()]);
-            }
+            resource_config.extend_path_with_id($resource_path.to_string());
 
             let id_str = id.as_ref();
             resource_config.resource_identity = $resource_identity;
@@ -232,14 +191,7 @@ macro_rules! resource_id_tunnel {
         pub fn $name<ID: AsRef<str>>(&self, id: ID) -> $return_type {
             let mut resource_config = self.resource_config.clone();
 
-            if let Some(resource_identity_id) = resource_config.resource_identity_id.as_ref() {
-                resource_config.extend_path(&[
-                    resource_config.resource_identity.to_string(),
-                    resource_identity_id.to_string(),
-                ]);
-            } else {
-                resource_config.extend_path(&[resource_config.resource_identity.to_string()]);
-            }
+            resource_config.extend_path_with_id(resource_config.resource_identity.to_string());
 
             let id_str = id.as_ref();
             resource_config.resource_identity = $resource_identity;
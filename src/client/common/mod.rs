@@ -2,7 +2,10 @@ mod resource_provisioner;
 
 use graph_error::GraphResult;
 use graph_http::api_impl::{BodyRead, RequestComponents};
+pub(crate) use graph_http::api_impl::odata_string;
 pub(crate) use resource_provisioner::*;
+use time::format_description::well_known::Rfc3339;
+use time::{OffsetDateTime, UtcOffset};
 
 pub(crate) fn map_parameters(params: &[&str]) -> serde_json::Value {
     let mut map = serde_json::Map::new();
@@ -25,3 +28,11 @@ pub(crate) fn map_errors(
 ) -> GraphResult<(RequestComponents, BodyRead)> {
     Ok((rc_result?, body_result?))
 }
+
+/// Format a date and time in UTC for an OData `$filter`.
+pub(crate) fn odata_date_time(date_time: OffsetDateTime) -> String {
+    date_time
+        .to_offset(UtcOffset::UTC)
+        .format(&Rfc3339)
+        .unwrap_or_default()
+}
@@ -47,6 +47,7 @@ use crate::identity::{
 };
 use crate::identity_access::IdentityApiClient;
 use crate::identity_governance::IdentityGovernanceApiClient;
+use crate::identity_protection::IdentityProtectionApiClient;
 use crate::identity_providers::{IdentityProvidersApiClient, IdentityProvidersIdApiClient};
 use crate::invitations::InvitationsApiClient;
 use crate::me::MeApiClient;
@@ -60,6 +61,7 @@ use crate::planner::PlannerApiClient;
 use crate::policies::PoliciesApiClient;
 use crate::reports::ReportsApiClient;
 use crate::schema_extensions::{SchemaExtensionsApiClient, SchemaExtensionsIdApiClient};
+use crate::security::SecurityApiClient;
 use crate::service_principals::{ServicePrincipalsApiClient, ServicePrincipalsIdApiClient};
 use crate::sites::{SitesApiClient, SitesIdApiClient};
 use crate::solutions::SolutionsApiClient;
@@ -69,14 +71,12 @@ use crate::teams::{TeamsApiClient, TeamsIdApiClient};
 use crate::teams_templates::{TeamsTemplatesApiClient, TeamsTemplatesIdApiClient};
 use crate::teamwork::TeamworkApiClient;
 use crate::users::{UsersApiClient, UsersIdApiClient};
-use crate::{GRAPH_URL, GRAPH_URL_BETA};
+use crate::GRAPH_URL;
 use graph_core::identity::ForceTokenRefresh;
 use lazy_static::lazy_static;
 
 lazy_static! {
     static ref PARSED_GRAPH_URL: Url = Url::parse(GRAPH_URL).expect("Unable to set v1 endpoint");
-    static ref PARSED_GRAPH_URL_BETA: Url =
-        Url::parse(GRAPH_URL_BETA).expect("Unable to set beta endpoint");
 }
 
 // For backwards compatibility.
@@ -99,15 +99,12 @@ impl GraphClient {
     }
 
     pub fn from_client_app<CA: ClientApplication + 'static>(client_app: CA) -> GraphClient {
-        GraphClient {
-            client: Client::new(client_app),
-            endpoint: PARSED_GRAPH_URL.clone(),
-            allowed_host_validator: AllowedHostValidator::default(),
-        }
+        GraphClient::from(GraphClientConfiguration::new().client_application(client_app))
     }
 
     /// Use the v1 endpoint for the Microsoft Graph API. This is the default
-    /// endpoint used by the client.
+    /// endpoint used by the client. Only the api version segment of the endpoint
+    /// path is changed so the host of a national cloud or custom endpoint is kept.
     ///
     /// # Example
     /// ```rust,ignore
@@ -123,7 +120,7 @@ impl GraphClient {
     ///     .await?;
     /// ```
     pub fn v1(&mut self) -> &mut GraphClient {
-        self.endpoint = PARSED_GRAPH_URL.clone();
+        self.set_api_version(ApiVersion::V1);
         self
     }
 
@@ -142,10 +139,12 @@ impl GraphClient {
     /// assert_eq!(client.url().to_string(), GRAPH_URL.to_string())
     /// ```
     pub fn use_v1(&mut self) {
-        self.endpoint = PARSED_GRAPH_URL.clone();
+        self.set_api_version(ApiVersion::V1);
     }
 
-    /// Use the beta endpoint for the Microsoft Graph API
+    /// Use the beta endpoint for the Microsoft Graph API. Only the api version
+    /// segment of the endpoint path is changed so the host of a national cloud
+    /// or custom endpoint is kept.
     ///
     /// # Example
     /// ```rust,ignore
@@ -161,7 +160,7 @@ impl GraphClient {
     ///     .await?;
     /// ```
     pub fn beta(&mut self) -> &mut GraphClient {
-        self.endpoint = PARSED_GRAPH_URL_BETA.clone();
+        self.set_api_version(ApiVersion::Beta);
         self
     }
 
@@ -180,7 +179,34 @@ impl GraphClient {
     /// assert_eq!(client.url().to_string(), GRAPH_URL_BETA.to_string())
     /// ```
     pub fn use_beta(&mut self) {
-        self.endpoint = PARSED_GRAPH_URL_BETA.clone();
+        self.set_api_version(ApiVersion::Beta);
+    }
+
+    /// Replace the api version segment of the endpoint path, or append the api
+    /// version if the path does not have one.
+    fn set_api_version(&mut self, api_version: ApiVersion) {
+        let mut segments: Vec<String> = self
+            .endpoint
+            .path_segments()
+            .map(|segments| {
+                segments
+                    .filter(|segment| !segment.is_empty())
+                    .map(|segment| segment.to_owned())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        match segments
+            .iter()
+            .position(|segment| segment.parse::<ApiVersion>().is_ok())
+        {
+            Some(index) => segments[index] = api_version.as_str().to_owned(),
+            None => segments.push(api_version.as_str().to_owned()),
+        }
+
+        if let Ok(mut path) = self.endpoint.path_segments_mut() {
+            path.clear().extend(&segments);
+        }
     }
 
     pub fn url(&self) -> &Url {
@@ -199,6 +225,19 @@ impl GraphClient {
         self.client.with_force_token_refresh(force_token_refresh);
     }
 
+    /// The throttling budget of the client updated from the `RateLimit` and throttling
+    /// headers of every response. See [GraphClientConfiguration::throttle_threshold]
+    /// to delay requests when the budget is exhausted.
+    pub fn throttle_budget(&self) -> ThrottleBudget {
+        self.client.throttle_budget()
+    }
+
+    /// The per host request limiter of the client. See
+    /// [GraphClientConfiguration::concurrency_limit_per_host]
+    pub fn request_limiter(&self) -> Option<RequestLimiter> {
+        self.client.request_limiter()
+    }
+
     /// Set a custom endpoint for the Microsoft Graph API. Provide the scheme and host with an
     /// optional path. The path is not set by the sdk when using a custom endpoint.
     ///
@@ -418,6 +457,8 @@ impl GraphClient {
 
     api_client_impl!(identity_governance, IdentityGovernanceApiClient);
 
+    api_client_impl!(identity_protection, IdentityProtectionApiClient);
+
     api_client_impl!(
         identity_providers,
         IdentityProvidersApiClient,
@@ -465,6 +506,8 @@ impl GraphClient {
         SchemaExtensionsIdApiClient
     );
 
+    api_client_impl!(security, SecurityApiClient);
+
     api_client_impl!(
         service_principals,
         ServicePrincipalsApiClient,
@@ -558,15 +601,21 @@ impl From<String> for GraphClient {
 
 impl From<&Token> for GraphClient {
     fn from(token: &Token) -> Self {
-        GraphClient::from_client_app(BearerTokenCredential::from(token.access_token.clone()))
+        GraphClient::from_client_app(BearerTokenCredential::from(
+            token.access_token.expose_secret(),
+        ))
     }
 }
 
 impl From<GraphClientConfiguration> for GraphClient {
     fn from(graph_client_builder: GraphClientConfiguration) -> Self {
+        // The base url is set explicitly in the configuration so the host is not
+        // validated as it is for use_endpoint.
+        let client = Client::from(graph_client_builder);
+        let endpoint = client.endpoint();
         GraphClient {
-            client: Client::from(graph_client_builder),
-            endpoint: PARSED_GRAPH_URL.clone(),
+            client,
+            endpoint,
             allowed_host_validator: AllowedHostValidator::default(),
         }
     }
@@ -686,6 +735,26 @@ mod test {
         client.use_endpoint(&Url::parse("https://example.org/v1").unwrap());
     }
 
+    #[test]
+    fn api_version_keeps_host() {
+        let mut client = Graph::from(
+            GraphClientConfiguration::new()
+                .access_token("token")
+                .base_url(Url::parse("https://graph.microsoft.us").unwrap()),
+        );
+        assert_eq!("https://graph.microsoft.us/v1.0", client.url().as_str());
+
+        client.use_beta();
+        assert_eq!("https://graph.microsoft.us/beta", client.url().as_str());
+
+        client.v1();
+        assert_eq!("https://graph.microsoft.us/v1.0", client.url().as_str());
+
+        client.custom_endpoint(&Url::parse("https://dod-graph.microsoft.us").unwrap());
+        client.beta();
+        assert_eq!("https://dod-graph.microsoft.us/beta", client.url().as_str());
+    }
+
     #[test]
     fn try_valid_hosts() {
         let urls = [
@@ -764,4 +833,58 @@ mod test_util_feature {
         let status = response.status();
         assert_eq!(status.as_u16(), 200);
     }
+
+    #[tokio::test]
+    async fn paging_retries_throttled_next_link() {
+        let mock_server = MockServer::start().await;
+        let uri = mock_server.uri();
+
+        Mock::given(method("GET"))
+            .and(path("/chats/1/messages"))
+            .and(query_param("$skiptoken", "next"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/chats/1/messages"))
+            .and(query_param("$skiptoken", "next"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "value": [] })),
+            )
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/chats/1/messages"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "@odata.nextLink": format!("{uri}/chats/1/messages?$skiptoken=next"),
+                "value": []
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let graph_client_configuration = GraphClientConfiguration::new()
+            .access_token("token")
+            .https_only(false);
+
+        let mut client = Graph::from(graph_client_configuration);
+        client.use_test_endpoint(&Url::parse(uri.as_str()).unwrap());
+
+        let deque = client
+            .chat("1")
+            .messages()
+            .list_messages()
+            .paging()
+            .throttle_retries(1)
+            .json::<serde_json::Value>()
+            .await
+            .unwrap();
+
+        assert_eq!(deque.len(), 2);
+        for response in deque.iter() {
+            assert_eq!(response.status().as_u16(), 200);
+        }
+    }
 }
@@ -0,0 +1,10 @@
+use crate::default_drive::*;
+use crate::drives::drive_item_path;
+
+impl DefaultDriveApiClient {
+    /// Address a drive item by its path relative to the root of the drive
+    /// such as `Documents/Reports/Q1.xlsx`.
+    pub fn by_path(&self, path: impl AsRef<str>) -> DefaultDrivesItemsPathIdApiClient {
+        self.item_by_path(drive_item_path(path.as_ref()))
+    }
+}
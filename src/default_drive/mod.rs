@@ -1,4 +1,5 @@
 mod default_drive_path;
+mod manual_request;
 mod request;
 
 pub use default_drive_path::*;
@@ -0,0 +1,159 @@
+use crate::api_default_imports::*;
+use crate::directory_objects::*;
+use crate::groups::GroupsIdApiClient;
+use crate::me::MeApiClient;
+use crate::types::Collection;
+use crate::users::UsersIdApiClient;
+use std::collections::HashSet;
+
+/// The maximum number of group ids that can be sent in a single checkMemberGroups request.
+const CHECK_MEMBER_GROUPS_LIMIT: usize = 20;
+
+/// The url of a directory object used in `@odata.id` and `@odata.bind` references,
+/// such as `https://graph.microsoft.com/v1.0/directoryObjects/{id}`.
+///
+/// The host and api version are taken from the endpoint of the client so that references
+/// work with national clouds and the beta endpoint.
+pub(crate) fn directory_object_url(endpoint: &Url, directory_object_id: &str) -> String {
+    let mut url = endpoint.clone();
+    url.set_query(None);
+    url.set_fragment(None);
+
+    let version = endpoint
+        .path_segments()
+        .and_then(|mut segments| segments.next())
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| format!("/{segment}"))
+        .unwrap_or_default();
+    url.set_path(&format!("{version}/directoryObjects/{directory_object_id}"));
+    url.to_string()
+}
+
+async fn member_group_ids(request: RequestHandler) -> GraphResult<HashSet<String>> {
+    let response: GraphResponse<Collection<String>> = request.send_json().await?;
+    Ok(response.into_body().into_iter().collect())
+}
+
+/// Send checkMemberGroups once for every 20 group ids and combine the results.
+async fn check_member_groups_batched<F, I, T>(
+    request: F,
+    group_ids: I,
+) -> GraphResult<HashSet<String>>
+where
+    F: Fn(&serde_json::Value) -> RequestHandler,
+    I: IntoIterator<Item = T>,
+    T: ToString,
+{
+    let group_ids: Vec<String> = group_ids
+        .into_iter()
+        .map(|id| id.to_string())
+        .collect::<HashSet<String>>()
+        .into_iter()
+        .collect();
+
+    let mut member_groups = HashSet::new();
+    for chunk in group_ids.chunks(CHECK_MEMBER_GROUPS_LIMIT) {
+        let body = serde_json::json!({ "groupIds": chunk });
+        member_groups.extend(member_group_ids(request(&body)).await?);
+    }
+    Ok(member_groups)
+}
+
+/// Collect the ids of every page of a transitiveMemberOf request.
+async fn transitive_group_ids(request: RequestHandler) -> GraphResult<HashSet<String>> {
+    let pages = request
+        .select(&["id"])
+        .paging()
+        .json::<Collection<serde_json::Value>>()
+        .await?;
+
+    let mut group_ids = HashSet::new();
+    for page in pages {
+        let status = page.status();
+        let collection = page
+            .into_body()
+            .map_err(|err| GraphFailure::from_error_message(status, err))?;
+        group_ids.extend(
+            collection
+                .into_iter()
+                .filter_map(|group| group["id"].as_str().map(|id| id.to_owned())),
+        );
+    }
+    Ok(group_ids)
+}
+
+macro_rules! member_groups_impl {
+    ($($client:ident),*) => {
+        $(
+            impl $client {
+                /// Get the ids of every group that the directory object is a member of,
+                /// including transitive memberships, using the getMemberGroups action.
+                pub async fn member_group_ids(
+                    &self,
+                    security_enabled_only: bool,
+                ) -> GraphResult<HashSet<String>> {
+                    member_group_ids(self.get_member_groups(&serde_json::json!({
+                        "securityEnabledOnly": security_enabled_only
+                    })))
+                    .await
+                }
+
+                /// Check membership in any number of groups, including transitive memberships,
+                /// and return the ids of the groups that the directory object is a member of.
+                ///
+                /// The checkMemberGroups action accepts at most 20 group ids so the ids are
+                /// sent in as many requests as needed and the results are combined.
+                pub async fn check_member_group_ids<I: IntoIterator<Item = T>, T: ToString>(
+                    &self,
+                    group_ids: I,
+                ) -> GraphResult<HashSet<String>> {
+                    check_member_groups_batched(|body| self.check_member_groups(body), group_ids)
+                        .await
+                }
+            }
+        )*
+    };
+}
+
+member_groups_impl!(
+    DirectoryObjectsIdApiClient,
+    GroupsIdApiClient,
+    MeApiClient,
+    UsersIdApiClient
+);
+
+impl MeApiClient {
+    /// Get the ids of every group that the signed-in user is a direct or transitive member of.
+    /// Every page of the transitiveMemberOf response is requested.
+    pub async fn transitive_member_group_ids(&self) -> GraphResult<HashSet<String>> {
+        transitive_group_ids(self.transitive_member_of().as_group()).await
+    }
+}
+
+impl UsersIdApiClient {
+    /// Get the ids of every group that the user is a direct or transitive member of.
+    /// Every page of the transitiveMemberOf response is requested.
+    pub async fn transitive_member_group_ids(&self) -> GraphResult<HashSet<String>> {
+        transitive_group_ids(self.transitive_member_of().as_group()).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn directory_object_url_uses_endpoint_version() {
+        let endpoint = Url::parse("https://graph.microsoft.com/beta/groups/1?$select=id").unwrap();
+        assert_eq!(
+            "https://graph.microsoft.com/beta/directoryObjects/2",
+            directory_object_url(&endpoint, "2")
+        );
+
+        let endpoint = Url::parse("https://graph.microsoft.us/v1.0").unwrap();
+        assert_eq!(
+            "https://graph.microsoft.us/v1.0/directoryObjects/2",
+            directory_object_url(&endpoint, "2")
+        );
+    }
+}
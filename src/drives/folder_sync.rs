@@ -0,0 +1,1101 @@
+use crate::api_default_imports::*;
+use crate::client::Graph;
+use crate::types::{Collection, DriveItem};
+use futures::StreamExt;
+use graph_http::traits::{AsyncIterator, ResponseExt};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs::Metadata;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+/// Files up to this size are uploaded in a single request and larger files are uploaded
+/// using an upload session.
+const SIMPLE_UPLOAD_LIMIT: u64 = 4 * 1024 * 1024;
+
+/// Suffix of the temporary file a download is written to before it replaces the local file.
+const PARTIAL_SUFFIX: &str = ".sync-partial";
+
+/// The maximum number of parent folders followed when resolving the path of an item.
+const MAX_DEPTH: usize = 512;
+
+/// How a file that changed both locally and in the drive since the last sync is resolved.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ConflictPolicy {
+    /// Keep the local file and replace the file in the drive.
+    LocalWins,
+    /// Keep the file in the drive and replace the local file.
+    #[default]
+    RemoteWins,
+    /// Keep both files by renaming the local file, such as to `report (conflict).docx`,
+    /// and syncing it as a new file.
+    Rename,
+}
+
+/// A file as it was when it was last synced.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncedFile {
+    pub id: String,
+    pub e_tag: Option<String>,
+    pub c_tag: Option<String>,
+    /// The size of the local file.
+    pub size: u64,
+    /// The modified time of the local file in milliseconds since the unix epoch.
+    pub modified: u64,
+}
+
+/// A folder within the synced folder.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncedFolder {
+    pub parent_id: String,
+    pub name: String,
+}
+
+/// The state of a [FolderSync] that is kept between syncs so that only the changes
+/// since the last sync are transferred.
+///
+/// The state can be serialized to save it when the process exits and passed to
+/// [FolderSync::resume] to continue syncing.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FolderSyncState {
+    /// The id of the synced folder in the drive.
+    pub root_id: Option<String>,
+    /// The delta link of the last download.
+    pub delta_link: Option<String>,
+    /// The folders within the synced folder by id.
+    pub folders: BTreeMap<String, SyncedFolder>,
+    /// The synced files by path relative to the synced folder using `/` as the separator.
+    pub files: BTreeMap<String, SyncedFile>,
+}
+
+/// A file that changed both locally and in the drive.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SyncConflict {
+    /// The path of the file relative to the synced folder.
+    pub path: String,
+    pub resolution: ConflictPolicy,
+    /// The path the local file was renamed to when the resolution is
+    /// [ConflictPolicy::Rename].
+    pub renamed_to: Option<String>,
+}
+
+/// The files transferred by a [FolderSync].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FolderSyncReport {
+    pub downloaded: Vec<String>,
+    pub uploaded: Vec<String>,
+    /// Files deleted locally by a download or deleted in the drive by an upload.
+    pub deleted: Vec<String>,
+    pub conflicts: Vec<SyncConflict>,
+}
+
+/// The result of a transfer that is applied to the state once the transfer completes.
+#[derive(Debug, Default)]
+struct Outcome {
+    /// Files that are added to the state or, when None, removed from the state.
+    files: Vec<(String, Option<SyncedFile>)>,
+    transferred: Option<String>,
+    conflict: Option<SyncConflict>,
+}
+
+/// Mirrors a folder in a drive to a local folder and a local folder to a folder in a drive.
+///
+/// Changes in the drive are found using the delta of the drive so only the items that
+/// changed since the last download are requested. Local changes are found by comparing
+/// the size and modified time of local files with the files that were last synced.
+/// Files are downloaded and uploaded concurrently, up to [FolderSync::concurrency] at a
+/// time, and downloads are verified using the hashes reported by the drive.
+///
+/// A file that changed both locally and in the drive since the last sync is resolved
+/// using the [ConflictPolicy]. Uploads use the eTag of the last synced file so that
+/// changes in the drive that have not been downloaded yet are not overwritten.
+///
+/// Empty local folders are not created in the drive. If the delta link of the last
+/// download has expired the whole drive is enumerated again; items deleted in the drive
+/// while the delta link was expired are not deleted locally.
+///
+/// # Example
+/// ```rust,ignore
+/// let state: FolderSyncState = serde_json::from_str(&std::fs::read_to_string("./sync.json")?)?;
+///
+/// let mut folder_sync = FolderSync::new(&client, "DRIVE_ID", "Documents/Reports", "./reports")
+///     .concurrency(8)
+///     .conflict_policy(ConflictPolicy::Rename)
+///     .resume(state);
+///
+/// let report = folder_sync.sync().await?;
+/// println!("{report:#?}");
+///
+/// std::fs::write("./sync.json", serde_json::to_string(folder_sync.state())?)?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct FolderSync {
+    client: Graph,
+    drive_id: String,
+    remote_path: String,
+    local_dir: PathBuf,
+    concurrency: usize,
+    conflict_policy: ConflictPolicy,
+    state: FolderSyncState,
+}
+
+impl FolderSync {
+    /// Sync the folder at `remote_path`, relative to the root of the drive, with the
+    /// local folder `local_dir`. An empty `remote_path` syncs the whole drive.
+    pub fn new(
+        client: &Graph,
+        drive_id: impl AsRef<str>,
+        remote_path: impl AsRef<str>,
+        local_dir: impl AsRef<Path>,
+    ) -> FolderSync {
+        FolderSync {
+            client: client.clone(),
+            drive_id: drive_id.as_ref().to_owned(),
+            remote_path: remote_path.as_ref().trim_matches('/').to_owned(),
+            local_dir: local_dir.as_ref().to_path_buf(),
+            concurrency: 4,
+            conflict_policy: ConflictPolicy::default(),
+            state: FolderSyncState::default(),
+        }
+    }
+
+    /// The number of files transferred at the same time. Defaults to 4.
+    pub fn concurrency(mut self, concurrency: usize) -> FolderSync {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// How files that changed both locally and in the drive are resolved. Defaults to
+    /// [ConflictPolicy::RemoteWins].
+    pub fn conflict_policy(mut self, conflict_policy: ConflictPolicy) -> FolderSync {
+        self.conflict_policy = conflict_policy;
+        self
+    }
+
+    /// Continue from the state of a previous sync.
+    pub fn resume(mut self, state: FolderSyncState) -> FolderSync {
+        self.state = state;
+        self
+    }
+
+    /// The state to save and pass to [FolderSync::resume] for the next sync.
+    ///
+    /// The state is updated for every transfer that completes even when a sync returns
+    /// an error, so it can be saved and the sync continued later.
+    pub fn state(&self) -> &FolderSyncState {
+        &self.state
+    }
+
+    /// Download the changes in the drive and then upload the local changes.
+    pub async fn sync(&mut self) -> GraphResult<FolderSyncReport> {
+        let mut report = self.download().await?;
+        let upload = self.upload().await?;
+        report.uploaded = upload.uploaded;
+        report.deleted.extend(upload.deleted);
+        report.conflicts.extend(upload.conflicts);
+        Ok(report)
+    }
+
+    /// Apply the changes in the drive since the last download to the local folder.
+    ///
+    /// Folders that were renamed or moved in the drive are moved locally, items deleted
+    /// in the drive are deleted locally, and new or changed files are downloaded. Local
+    /// files that changed since the last sync are resolved using the [ConflictPolicy].
+    pub async fn download(&mut self) -> GraphResult<FolderSyncReport> {
+        let root_id = self.root_id().await?;
+        let (items, delta_link) = self.delta().await?;
+        tokio::fs::create_dir_all(&self.local_dir).await?;
+
+        // The same item can be returned more than once and the last one is current.
+        let mut latest: Vec<DriveItem> = Vec::new();
+        let mut index: HashMap<String, usize> = HashMap::new();
+        for item in items {
+            let Some(id) = item.id.clone() else {
+                continue;
+            };
+            match index.get(&id) {
+                Some(i) => latest[*i] = item,
+                None => {
+                    index.insert(id, latest.len());
+                    latest.push(item);
+                }
+            }
+        }
+
+        let mut local_folders: HashMap<String, SyncedFolder> =
+            self.state.folders.clone().into_iter().collect();
+        let mut folders = local_folders.clone();
+        for item in latest
+            .iter()
+            .filter(|item| item.is_folder() || item.is_deleted())
+        {
+            let id = item.id.clone().unwrap_or_default();
+            let parent_id = item
+                .parent_reference
+                .as_ref()
+                .and_then(|parent| parent.id.clone());
+            match parent_id {
+                Some(parent_id) if !item.is_deleted() && id != root_id => {
+                    let name = item.name.clone().unwrap_or_default();
+                    folders.insert(id, SyncedFolder { parent_id, name });
+                }
+                _ => {
+                    folders.remove(&id);
+                }
+            }
+        }
+
+        let mut report = FolderSyncReport::default();
+
+        // Folders are moved before the folders within them.
+        let mut folder_changes: Vec<(&DriveItem, Option<String>)> = latest
+            .iter()
+            .filter(|item| item.id.as_deref() != Some(root_id.as_str()))
+            .filter(|item| {
+                item.is_folder()
+                    || (item.is_deleted()
+                        && item
+                            .id
+                            .as_ref()
+                            .is_some_and(|id| local_folders.contains_key(id)))
+            })
+            .map(|item| {
+                let id = item.id.as_deref().unwrap_or_default();
+                if item.is_deleted() {
+                    (item, None)
+                } else {
+                    (item, folder_path(&folders, &root_id, id))
+                }
+            })
+            .collect();
+        folder_changes.sort_by_key(|(_, path)| {
+            path.as_ref()
+                .map_or(usize::MAX, |path| path.matches('/').count())
+        });
+
+        let mut removed_folders = Vec::new();
+        for (item, new_path) in folder_changes {
+            let id = item.id.clone().unwrap_or_default();
+            let old_path = folder_path(&local_folders, &root_id, &id);
+            match (old_path, new_path) {
+                (Some(old_path), Some(new_path)) if old_path != new_path => {
+                    self.move_folder(&old_path, &new_path).await?
+                }
+                (_, Some(new_path)) => {
+                    tokio::fs::create_dir_all(self.local_path(&new_path)).await?
+                }
+                (Some(old_path), None) => removed_folders.push(old_path),
+                (None, None) => {}
+            }
+
+            match folders.get(&id) {
+                Some(folder) => local_folders.insert(id, folder.clone()),
+                None => local_folders.remove(&id),
+            };
+        }
+
+        // Files are only deleted with the folder they are in if they did not change locally.
+        removed_folders.sort_by_key(|path| std::cmp::Reverse(path.matches('/').count()));
+        for folder in removed_folders.iter() {
+            let paths: Vec<String> = self
+                .state
+                .files
+                .keys()
+                .filter(|path| move_path(path, folder, "").is_some())
+                .cloned()
+                .collect();
+            for path in paths {
+                self.remove_local_file(&path, &mut report).await?;
+            }
+        }
+
+        let paths: HashMap<String, String> = self
+            .state
+            .files
+            .iter()
+            .map(|(path, file)| (file.id.clone(), path.clone()))
+            .collect();
+        let mut downloads = Vec::new();
+        for item in latest
+            .iter()
+            .filter(|item| item.is_file() || item.is_deleted())
+        {
+            let id = item.id.clone().unwrap_or_default();
+            let old_path = paths.get(&id).cloned();
+            let new_path = match (&item.parent_reference, &item.name) {
+                (Some(parent), Some(name)) if !item.is_deleted() => parent
+                    .id
+                    .as_deref()
+                    .and_then(|parent_id| folder_path(&folders, &root_id, parent_id))
+                    .map(|parent_path| join_path(&parent_path, name)),
+                _ => None,
+            };
+
+            let Some(new_path) = new_path else {
+                if let Some(old_path) = old_path {
+                    self.remove_local_file(&old_path, &mut report).await?;
+                }
+                continue;
+            };
+
+            if let Some(old_path) = old_path.filter(|old_path| *old_path != new_path) {
+                if self.move_file(&old_path, &new_path, item).await? {
+                    continue;
+                }
+                self.remove_local_file(&old_path, &mut report).await?;
+            }
+
+            if let Some(file) = self.state.files.get_mut(&new_path) {
+                if file.id == id && file.c_tag == item.c_tag {
+                    file.e_tag.clone_from(&item.e_tag);
+                    continue;
+                }
+            }
+
+            if self.resolve_download(&new_path, item, &mut report).await? {
+                downloads.push((new_path, item.clone()));
+            }
+        }
+
+        let results: Vec<GraphResult<(String, SyncedFile)>> = futures::stream::iter(downloads)
+            .map(|(path, item)| self.download_file(path, item))
+            .buffer_unordered(self.concurrency)
+            .collect()
+            .await;
+
+        let mut error = None;
+        for result in results {
+            match result {
+                Ok((path, file)) => {
+                    report.downloaded.push(path.clone());
+                    self.state.files.insert(path, file);
+                }
+                Err(err) => {
+                    error.get_or_insert(err);
+                }
+            }
+        }
+
+        // Only folders that are empty are removed.
+        for folder in removed_folders.iter() {
+            let _ = tokio::fs::remove_dir(self.local_path(folder)).await;
+        }
+
+        self.state.folders = folders
+            .iter()
+            .filter(|(id, _)| folder_path(&folders, &root_id, id).is_some())
+            .map(|(id, folder)| (id.clone(), folder.clone()))
+            .collect();
+
+        if let Some(err) = error {
+            return Err(err);
+        }
+        self.state.delta_link = Some(delta_link);
+        Ok(report)
+    }
+
+    /// Apply the local changes since the last sync to the folder in the drive.
+    ///
+    /// New and changed local files are uploaded and files that were deleted locally are
+    /// deleted in the drive. Files in the drive that changed since the last sync are
+    /// resolved using the [ConflictPolicy].
+    pub async fn upload(&mut self) -> GraphResult<FolderSyncReport> {
+        self.root_id().await?;
+        let local_files = self.local_files().await?;
+        let mut report = FolderSyncReport::default();
+
+        let uploads: Vec<(String, Option<SyncedFile>)> = local_files
+            .iter()
+            .filter(|(path, fingerprint)| {
+                !matches!(
+                    self.state.files.get(*path),
+                    Some(file) if (file.size, file.modified) == **fingerprint
+                )
+            })
+            .map(|(path, _)| (path.clone(), self.state.files.get(path).cloned()))
+            .collect();
+
+        let results: Vec<GraphResult<Outcome>> = futures::stream::iter(uploads)
+            .map(|(path, file)| self.upload_file(path, file))
+            .buffer_unordered(self.concurrency)
+            .collect()
+            .await;
+
+        let mut error = None;
+        for result in results {
+            match result {
+                Ok(outcome) => self.apply(outcome, &mut report.uploaded, &mut report.conflicts),
+                Err(err) => {
+                    error.get_or_insert(err);
+                }
+            }
+        }
+
+        let deleted: Vec<(String, SyncedFile)> = self
+            .state
+            .files
+            .iter()
+            .filter(|(path, _)| !local_files.contains_key(*path))
+            .map(|(path, file)| (path.clone(), file.clone()))
+            .collect();
+        for (path, file) in deleted {
+            match self.delete_remote(path, &file).await {
+                Ok(outcome) => self.apply(outcome, &mut report.deleted, &mut report.conflicts),
+                Err(err) => {
+                    error.get_or_insert(err);
+                }
+            }
+        }
+
+        match error {
+            Some(err) => Err(err),
+            None => Ok(report),
+        }
+    }
+
+    fn apply(
+        &mut self,
+        outcome: Outcome,
+        transferred: &mut Vec<String>,
+        conflicts: &mut Vec<SyncConflict>,
+    ) {
+        for (path, file) in outcome.files {
+            match file {
+                Some(file) => self.state.files.insert(path, file),
+                None => self.state.files.remove(&path),
+            };
+        }
+        transferred.extend(outcome.transferred);
+        conflicts.extend(outcome.conflict);
+    }
+
+    fn local_path(&self, path: &str) -> PathBuf {
+        path.split('/')
+            .fold(self.local_dir.clone(), |dir, name| dir.join(name))
+    }
+
+    fn remote_path(&self, path: &str) -> String {
+        join_path(&self.remote_path, path)
+    }
+
+    /// Get the id of the synced folder. The state is cleared if the folder is not the
+    /// folder of the state.
+    async fn root_id(&mut self) -> GraphResult<String> {
+        let response = self
+            .client
+            .drive(self.drive_id.as_str())
+            .by_path(self.remote_path.as_str())
+            .get_items()
+            .select(&["id"])
+            .send()
+            .await?;
+        let response = response.error_for_graph_status().await?;
+
+        let item: DriveItem = response.json().await?;
+        let id = item
+            .id
+            .ok_or_else(|| GraphFailure::invalid("id of the synced folder"))?;
+        if self.state.root_id.as_deref() != Some(id.as_str()) {
+            self.state = FolderSyncState {
+                root_id: Some(id.clone()),
+                ..Default::default()
+            };
+        }
+        Ok(id)
+    }
+
+    /// Get the items that changed since the last download and the delta link for the
+    /// next download. Starts over if the delta link has expired.
+    async fn delta(&self) -> GraphResult<(Vec<DriveItem>, String)> {
+        if let Some(delta_link) = self.state.delta_link.as_deref() {
+            if let Some(delta) = self.delta_pages(Some(delta_link)).await? {
+                return Ok(delta);
+            }
+        }
+
+        self.delta_pages(None)
+            .await?
+            .ok_or_else(|| GraphFailure::invalid("delta link that has not expired"))
+    }
+
+    /// Returns None if the delta link has expired.
+    async fn delta_pages(
+        &self,
+        delta_link: Option<&str>,
+    ) -> GraphResult<Option<(Vec<DriveItem>, String)>> {
+        let mut paging = self
+            .client
+            .drive(self.drive_id.as_str())
+            .delta()
+            .paging()
+            .throttle_retries(3);
+        if let Some(delta_link) = delta_link {
+            paging = paging.resume_from(delta_link)?;
+        }
+
+        let mut items = Vec::new();
+        while let Some(response) = paging.next_page::<Collection<DriveItem>>().await? {
+            let status = response.status();
+            match response.into_body() {
+                Ok(collection) => {
+                    items.extend(collection.value);
+                    if let Some(delta_link) = collection.delta_link {
+                        return Ok(Some((items, delta_link)));
+                    }
+                }
+                Err(_) if status == StatusCode::GONE => return Ok(None),
+                Err(error_message) => {
+                    return Err(GraphFailure::from_error_message(status, error_message))
+                }
+            }
+        }
+
+        Err(GraphFailure::invalid(
+            "delta link in the last page of the delta response",
+        ))
+    }
+
+    /// Whether the local file changed since it was synced. A file that does not exist has
+    /// not changed and a file that exists but was never synced has changed.
+    async fn is_local_changed(&self, path: &str, file: Option<&SyncedFile>) -> GraphResult<bool> {
+        let fingerprint = local_fingerprint(&self.local_path(path)).await?;
+        Ok(match (fingerprint, file) {
+            (None, _) => false,
+            (Some(_), None) => true,
+            (Some(fingerprint), Some(file)) => fingerprint != (file.size, file.modified),
+        })
+    }
+
+    /// Move a local folder, and the synced files within it, after the folder was renamed
+    /// or moved in the drive.
+    async fn move_folder(&mut self, from: &str, to: &str) -> GraphResult<()> {
+        let (source, target) = (self.local_path(from), self.local_path(to));
+        if !tokio::fs::try_exists(&source).await? || tokio::fs::try_exists(&target).await? {
+            tokio::fs::create_dir_all(&target).await?;
+            return Ok(());
+        }
+
+        if let Some(parent) = target.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        tokio::fs::rename(&source, &target).await?;
+
+        let files = std::mem::take(&mut self.state.files);
+        self.state.files = files
+            .into_iter()
+            .map(|(path, file)| (move_path(&path, from, to).unwrap_or(path), file))
+            .collect();
+        Ok(())
+    }
+
+    /// Move a local file that was renamed or moved in the drive without changing its
+    /// content. Returns false if the file has to be downloaded instead.
+    async fn move_file(&mut self, from: &str, to: &str, item: &DriveItem) -> GraphResult<bool> {
+        let Some(file) = self.state.files.get(from).cloned() else {
+            return Ok(false);
+        };
+        let (source, target) = (self.local_path(from), self.local_path(to));
+        if file.c_tag != item.c_tag
+            || self.is_local_changed(from, Some(&file)).await?
+            || !tokio::fs::try_exists(&source).await?
+            || tokio::fs::try_exists(&target).await?
+        {
+            return Ok(false);
+        }
+
+        if let Some(parent) = target.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        tokio::fs::rename(&source, &target).await?;
+
+        self.state.files.remove(from);
+        self.state.files.insert(
+            to.to_owned(),
+            SyncedFile {
+                e_tag: item.e_tag.clone(),
+                ..file
+            },
+        );
+        Ok(true)
+    }
+
+    /// Delete a local file that was deleted in the drive. A local file that changed
+    /// since it was synced is kept unless the conflict policy is
+    /// [ConflictPolicy::RemoteWins].
+    async fn remove_local_file(
+        &mut self,
+        path: &str,
+        report: &mut FolderSyncReport,
+    ) -> GraphResult<()> {
+        let file = self.state.files.remove(path);
+        if self.is_local_changed(path, file.as_ref()).await? {
+            report.conflicts.push(SyncConflict {
+                path: path.to_owned(),
+                resolution: self.conflict_policy,
+                renamed_to: None,
+            });
+            if self.conflict_policy != ConflictPolicy::RemoteWins {
+                return Ok(());
+            }
+        }
+
+        match tokio::fs::remove_file(self.local_path(path)).await {
+            Ok(()) => report.deleted.push(path.to_owned()),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err.into()),
+        }
+        Ok(())
+    }
+
+    /// Resolve a conflict with the local file before a file is downloaded. Returns false
+    /// if the local file is kept and the file should not be downloaded.
+    async fn resolve_download(
+        &mut self,
+        path: &str,
+        item: &DriveItem,
+        report: &mut FolderSyncReport,
+    ) -> GraphResult<bool> {
+        let file = self.state.files.get(path).cloned();
+        if !self.is_local_changed(path, file.as_ref()).await? {
+            return Ok(true);
+        }
+
+        let mut conflict = SyncConflict {
+            path: path.to_owned(),
+            resolution: self.conflict_policy,
+            renamed_to: None,
+        };
+        let download = match self.conflict_policy {
+            ConflictPolicy::LocalWins => {
+                // The version in the drive is recorded so the next upload replaces it.
+                let (size, modified) = file
+                    .map(|file| (file.size, file.modified))
+                    .unwrap_or_default();
+                self.state.files.insert(
+                    path.to_owned(),
+                    SyncedFile {
+                        id: item.id.clone().unwrap_or_default(),
+                        e_tag: item.e_tag.clone(),
+                        c_tag: item.c_tag.clone(),
+                        size,
+                        modified,
+                    },
+                );
+                false
+            }
+            ConflictPolicy::RemoteWins => true,
+            ConflictPolicy::Rename => {
+                conflict.renamed_to = Some(self.rename_local(path).await?);
+                true
+            }
+        };
+        report.conflicts.push(conflict);
+        Ok(download)
+    }
+
+    /// Rename a local file that is in conflict and return the new path.
+    async fn rename_local(&self, path: &str) -> GraphResult<String> {
+        let mut attempt = 1;
+        loop {
+            let renamed = conflict_path(path, attempt);
+            if !tokio::fs::try_exists(self.local_path(&renamed)).await? {
+                tokio::fs::rename(self.local_path(path), self.local_path(&renamed)).await?;
+                return Ok(renamed);
+            }
+            attempt += 1;
+        }
+    }
+
+    /// Download a file to a temporary file that replaces the local file once the
+    /// content is verified.
+    async fn download_file(
+        &self,
+        path: String,
+        item: DriveItem,
+    ) -> GraphResult<(String, SyncedFile)> {
+        let id = item.id.clone().unwrap_or_default();
+        let response = self
+            .client
+            .drive(self.drive_id.as_str())
+            .item(id.as_str())
+            .get_items_content()
+            .send()
+            .await?;
+        let response = response.error_for_graph_status().await?;
+
+        let target = self.local_path(&path);
+        if let Some(parent) = target.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        let file_name = target
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let partial = target.with_file_name(format!("{file_name}{PARTIAL_SUFFIX}"));
+
+        let mut writer = HashingWriter::new(tokio::fs::File::create(&partial).await?);
+        let verified = match response.download_to_writer(&mut writer).await {
+            Ok(_) => match item.file.as_ref().and_then(|file| file.hashes.as_ref()) {
+                Some(_) => item
+                    .verify_content(&writer.hashes())
+                    .map_err(GraphFailure::from),
+                None => Ok(()),
+            },
+            Err(err) => Err(GraphFailure::from(err)),
+        };
+        drop(writer.into_inner());
+
+        if let Err(err) = verified {
+            let _ = tokio::fs::remove_file(&partial).await;
+            return Err(err);
+        }
+        tokio::fs::rename(&partial, &target).await?;
+
+        let (size, modified) = local_fingerprint(&target).await?.unwrap_or_default();
+        Ok((
+            path,
+            SyncedFile {
+                id,
+                e_tag: item.e_tag,
+                c_tag: item.c_tag,
+                size,
+                modified,
+            },
+        ))
+    }
+
+    /// Upload a new or changed local file and resolve a conflict with the file in the drive.
+    async fn upload_file(&self, path: String, file: Option<SyncedFile>) -> GraphResult<Outcome> {
+        let fingerprint = local_fingerprint(&self.local_path(&path))
+            .await?
+            .unwrap_or_default();
+        let (e_tag, conflict_behavior) = match file.as_ref() {
+            Some(file) => (file.e_tag.as_deref(), "replace"),
+            None => (None, "fail"),
+        };
+
+        if let Some(item) = self.send_upload(&path, e_tag, conflict_behavior).await? {
+            return Ok(Outcome {
+                files: vec![(path.clone(), Some(synced_file(item, fingerprint)))],
+                transferred: Some(path),
+                conflict: None,
+            });
+        }
+
+        let mut outcome = Outcome {
+            conflict: Some(SyncConflict {
+                path: path.clone(),
+                resolution: self.conflict_policy,
+                renamed_to: None,
+            }),
+            ..Default::default()
+        };
+        match self.conflict_policy {
+            ConflictPolicy::LocalWins => {
+                let item = self
+                    .send_upload(&path, None, "replace")
+                    .await?
+                    .ok_or_else(|| {
+                        GraphFailure::invalid("file in the drive that can be replaced")
+                    })?;
+                outcome
+                    .files
+                    .push((path.clone(), Some(synced_file(item, fingerprint))));
+                outcome.transferred = Some(path);
+            }
+            ConflictPolicy::RemoteWins => {}
+            ConflictPolicy::Rename => {
+                let renamed = self.rename_local(&path).await?;
+                outcome.files.push((path, None));
+                if let Some(item) = self.send_upload(&renamed, None, "fail").await? {
+                    outcome
+                        .files
+                        .push((renamed.clone(), Some(synced_file(item, fingerprint))));
+                    outcome.transferred = Some(renamed.clone());
+                }
+                if let Some(conflict) = outcome.conflict.as_mut() {
+                    conflict.renamed_to = Some(renamed);
+                }
+            }
+        }
+        Ok(outcome)
+    }
+
+    /// Upload a local file to the same path in the drive. Returns None if the file in the
+    /// drive changed since the eTag or, for a new file, already exists.
+    async fn send_upload(
+        &self,
+        path: &str,
+        if_match: Option<&str>,
+        conflict_behavior: &str,
+    ) -> GraphResult<Option<DriveItem>> {
+        let local_path = self.local_path(path);
+        let size = tokio::fs::metadata(&local_path).await?.len();
+        let item = self
+            .client
+            .drive(self.drive_id.as_str())
+            .by_path(self.remote_path(path));
+
+        let response = if size <= SIMPLE_UPLOAD_LIMIT {
+            let body = BodyRead::from(tokio::fs::read(&local_path).await?);
+            let mut request = item
+                .update_items_content(body)
+                .append_query_pair("@microsoft.graph.conflictBehavior", conflict_behavior);
+            if let Some(e_tag) = if_match {
+                request = request.if_match(e_tag);
+            }
+            request.send().await?
+        } else {
+            let body = serde_json::json!({
+                "item": { "@microsoft.graph.conflictBehavior": conflict_behavior }
+            });
+            let mut request = item.create_upload_session(&body);
+            if let Some(e_tag) = if_match {
+                request = request.if_match(e_tag);
+            }
+
+            let response = request.send().await?;
+            if response.status().is_success() {
+                let file = tokio::fs::File::open(&local_path).await?;
+                let mut upload_session = response.into_upload_session_streamed(file, size).await?;
+                let mut last_response = None;
+                while let Some(result) = upload_session.next().await {
+                    last_response = Some(result?);
+                }
+                last_response.ok_or_else(|| {
+                    GraphFailure::invalid("response to the last range of the upload session")
+                })?
+            } else {
+                response
+            }
+        };
+
+        let status = response.status();
+        if matches!(
+            status,
+            StatusCode::CONFLICT | StatusCode::PRECONDITION_FAILED
+        ) {
+            return Ok(None);
+        }
+        let response = response.error_for_graph_status().await?;
+        Ok(Some(response.json().await?))
+    }
+
+    /// Delete a file in the drive that was deleted locally and resolve a conflict with
+    /// the file in the drive.
+    async fn delete_remote(&self, path: String, file: &SyncedFile) -> GraphResult<Outcome> {
+        let mut outcome = Outcome {
+            files: vec![(path.clone(), None)],
+            ..Default::default()
+        };
+        if self.send_delete(&file.id, file.e_tag.as_deref()).await? {
+            outcome.transferred = Some(path);
+            return Ok(outcome);
+        }
+
+        if self.conflict_policy == ConflictPolicy::LocalWins
+            && self.send_delete(&file.id, None).await?
+        {
+            outcome.transferred = Some(path.clone());
+        }
+        outcome.conflict = Some(SyncConflict {
+            path,
+            resolution: self.conflict_policy,
+            renamed_to: None,
+        });
+        Ok(outcome)
+    }
+
+    /// Returns false if the file in the drive changed since the eTag.
+    async fn send_delete(&self, id: &str, if_match: Option<&str>) -> GraphResult<bool> {
+        let mut request = self
+            .client
+            .drive(self.drive_id.as_str())
+            .item(id)
+            .delete_items();
+        if let Some(e_tag) = if_match {
+            request = request.if_match(e_tag);
+        }
+
+        let response = request.send().await?;
+        let status = response.status();
+        if status == StatusCode::PRECONDITION_FAILED {
+            return Ok(false);
+        }
+        if status != StatusCode::NOT_FOUND {
+            response.error_for_graph_status().await?;
+        }
+        Ok(true)
+    }
+
+    /// The size and modified time of the local files by path relative to the local folder.
+    async fn local_files(&self) -> GraphResult<BTreeMap<String, (u64, u64)>> {
+        let mut files = BTreeMap::new();
+        let mut folders = vec![(self.local_dir.clone(), String::new())];
+
+        while let Some((dir, dir_path)) = folders.pop() {
+            let mut entries = match tokio::fs::read_dir(&dir).await {
+                Ok(entries) => entries,
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(err) => return Err(err.into()),
+            };
+
+            while let Some(entry) = entries.next_entry().await? {
+                let Some(name) = entry.file_name().to_str().map(String::from) else {
+                    continue;
+                };
+                if name.ends_with(PARTIAL_SUFFIX) {
+                    continue;
+                }
+
+                let path = join_path(&dir_path, &name);
+                let file_type = entry.file_type().await?;
+                if file_type.is_dir() {
+                    folders.push((entry.path(), path));
+                } else if file_type.is_file() {
+                    files.insert(path, fingerprint(&entry.metadata().await?)?);
+                }
+            }
+        }
+
+        Ok(files)
+    }
+}
+
+fn synced_file(item: DriveItem, (size, modified): (u64, u64)) -> SyncedFile {
+    SyncedFile {
+        id: item.id.unwrap_or_default(),
+        e_tag: item.e_tag,
+        c_tag: item.c_tag,
+        size,
+        modified,
+    }
+}
+
+fn fingerprint(metadata: &Metadata) -> GraphResult<(u64, u64)> {
+    let modified = metadata
+        .modified()?
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or_default();
+    Ok((metadata.len(), modified))
+}
+
+/// The size and modified time of a local file or None if the file does not exist.
+async fn local_fingerprint(path: &Path) -> GraphResult<Option<(u64, u64)>> {
+    match tokio::fs::metadata(path).await {
+        Ok(metadata) => Ok(Some(fingerprint(&metadata)?)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+fn join_path(parent: &str, name: &str) -> String {
+    if parent.is_empty() {
+        name.to_owned()
+    } else {
+        format!("{parent}/{name}")
+    }
+}
+
+/// The path of a folder relative to the synced folder or None if the folder is not
+/// within the synced folder.
+fn folder_path(folders: &HashMap<String, SyncedFolder>, root_id: &str, id: &str) -> Option<String> {
+    let mut names = Vec::new();
+    let mut id = id;
+    for _ in 0..MAX_DEPTH {
+        if id == root_id {
+            names.reverse();
+            return Some(names.join("/"));
+        }
+        let folder = folders.get(id)?;
+        names.push(folder.name.as_str());
+        id = folder.parent_id.as_str();
+    }
+    None
+}
+
+/// Replace the folder `from` at the start of a path with `to`. Returns None if the
+/// path is not within the folder.
+fn move_path(path: &str, from: &str, to: &str) -> Option<String> {
+    if path == from {
+        return Some(to.to_owned());
+    }
+    path.strip_prefix(from)
+        .and_then(|rest| rest.strip_prefix('/'))
+        .map(|rest| join_path(to, rest))
+}
+
+/// The path a local file in conflict is renamed to, such as `report (conflict).docx`
+/// or `report (conflict 2).docx` for the second attempt.
+fn conflict_path(path: &str, attempt: usize) -> String {
+    let (parent, name) = path.rsplit_once('/').unwrap_or(("", path));
+    let suffix = match attempt {
+        0 | 1 => " (conflict)".to_owned(),
+        attempt => format!(" (conflict {attempt})"),
+    };
+    let name = match name.rsplit_once('.') {
+        Some((stem, extension)) if !stem.is_empty() => format!("{stem}{suffix}.{extension}"),
+        _ => format!("{name}{suffix}"),
+    };
+    join_path(parent, &name)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn folder(parent_id: &str, name: &str) -> SyncedFolder {
+        SyncedFolder {
+            parent_id: parent_id.to_owned(),
+            name: name.to_owned(),
+        }
+    }
+
+    #[test]
+    fn folder_paths_are_relative_to_the_synced_folder() {
+        let folders = HashMap::from([
+            ("a".to_owned(), folder("root", "Reports")),
+            ("b".to_owned(), folder("a", "2024")),
+            ("c".to_owned(), folder("outside", "Other")),
+        ]);
+
+        assert_eq!(Some(String::new()), folder_path(&folders, "root", "root"));
+        assert_eq!(
+            Some("Reports/2024".to_owned()),
+            folder_path(&folders, "root", "b")
+        );
+        assert_eq!(None, folder_path(&folders, "root", "c"));
+
+        let cycle = HashMap::from([
+            ("a".to_owned(), folder("b", "A")),
+            ("b".to_owned(), folder("a", "B")),
+        ]);
+        assert_eq!(None, folder_path(&cycle, "root", "a"));
+    }
+
+    #[test]
+    fn move_and_conflict_paths() {
+        assert_eq!(
+            Some("Archive/2024/Q1.xlsx".to_owned()),
+            move_path("Reports/2024/Q1.xlsx", "Reports", "Archive")
+        );
+        assert_eq!(None, move_path("Reports2/Q1.xlsx", "Reports", "Archive"));
+        assert_eq!(
+            Some("Q1.xlsx".to_owned()),
+            move_path("Reports/Q1.xlsx", "Reports", "")
+        );
+
+        assert_eq!(
+            "Reports/Q1 (conflict).xlsx",
+            conflict_path("Reports/Q1.xlsx", 1)
+        );
+        assert_eq!("Q1 (conflict 2).xlsx", conflict_path("Q1.xlsx", 2));
+        assert_eq!(".env (conflict)", conflict_path(".env", 1));
+        assert_eq!("README (conflict)", conflict_path("README", 1));
+    }
+}
@@ -1,7 +1,207 @@
 use crate::api_default_imports::*;
+use crate::default_drive::DefaultDriveApiClient;
 use crate::drives::*;
+use crate::types::{
+    AsyncJobStatus, Collection, CreateLink, DriveItem, DriveItemCopy, DriveItemInvite,
+    DriveRecipient, Permission, RevokeGrants,
+};
+use graph_http::traits::ResponseExt;
+use reqwest::header::LOCATION;
+use reqwest::StatusCode;
+use std::time::Duration;
+
+/// The `:/{path}:` segment that addresses a drive item by its path relative to the root
+/// of the drive. An empty path addresses the root folder.
+///
+/// Reserved characters such as spaces, `#`, and `%` are percent encoded when the
+/// segments are added to the request url.
+pub(crate) fn drive_item_path(path: &str) -> String {
+    let path = path.trim_matches('/');
+    if path.is_empty() {
+        String::new()
+    } else {
+        format!(":/{path}:")
+    }
+}
+
+/// The resource config of the items of a drive by id, `/drives/{drive-id}`, on the same
+/// endpoint and api version as `resource_config`.
+fn drive_resource_config(resource_config: &ResourceConfig, drive_id: &str) -> ResourceConfig {
+    let mut resource_config = resource_config.clone();
+    let segments: Vec<String> = resource_config
+        .url
+        .path_segments()
+        .map(|segments| segments.map(|segment| segment.to_owned()).collect())
+        .unwrap_or_default();
+    let endpoint_len = segments
+        .iter()
+        .position(|segment| segment.parse::<ApiVersion>().is_ok())
+        .map(|index| index + 1)
+        .unwrap_or_default();
+
+    if let Ok(mut path) = resource_config.url.path_segments_mut() {
+        path.clear().extend(&segments[..endpoint_len]);
+    }
+    resource_config.extend_path(&["drives", drive_id]);
+    resource_config
+}
+
+/// Send a copy request and wait for the copy to complete, then get the new item.
+///
+/// The new item is requested from the drive of the destination folder when the copy
+/// has a `parentReference.driveId`, otherwise from the drive of the source item.
+async fn copy_and_wait(
+    body: &DriveItemCopy,
+    copy: RequestHandler,
+    client: &Client,
+    resource_config: &ResourceConfig,
+    poll_interval: Duration,
+    timeout: Duration,
+) -> GraphResult<DriveItem> {
+    let response = copy.send().await?;
+    let response = response.error_for_graph_status().await?;
+
+    // The monitor url is pre-authenticated and must not be sent the access token.
+    let monitor_url = response
+        .headers()
+        .get(LOCATION)
+        .and_then(|location| location.to_str().ok())
+        .and_then(|location| Url::parse(location).ok())
+        .ok_or_else(|| GraphFailure::invalid("Location header with monitor url"))?;
+
+    let job_status: AsyncJobStatus = AsyncOperationMonitor::pre_authenticated(client, monitor_url)
+        .poll_interval(poll_interval)
+        .timeout(timeout)
+        .wait()
+        .await?;
+    let resource_id = job_status
+        .resource_id
+        .ok_or_else(|| GraphFailure::invalid("resourceId of copied item"))?;
+
+    let destination_drive_id = body
+        .parent_reference
+        .as_ref()
+        .and_then(|parent_reference| parent_reference.drive_id.as_deref());
+    let mut resource_config = match destination_drive_id {
+        Some(drive_id) => drive_resource_config(resource_config, drive_id),
+        None => resource_config.clone(),
+    };
+    resource_config.resource_identity = ResourceIdentity::DrivesItems;
+    resource_config.resource_identity_id = Some(resource_id.clone());
+    let response = DrivesItemsIdApiClient::new(
+        client.clone(),
+        resource_config,
+        ResourceProvisioner::registry_with_id(resource_id.as_str()),
+    )
+    .get_items()
+    .send()
+    .await?;
+    let response = response.error_for_graph_status().await?;
+    Ok(response.json().await?)
+}
+
+/// Get the short lived pre-authenticated download url of an item.
+async fn get_download_url(get_item: RequestHandler) -> GraphResult<String> {
+    let response = get_item
+        .select(&["id", "@microsoft.graph.downloadUrl"])
+        .send()
+        .await?;
+    let response = response.error_for_graph_status().await?;
+
+    let item: DriveItem = response.json().await?;
+    item.download_url
+        .ok_or_else(|| GraphFailure::invalid("@microsoft.graph.downloadUrl"))
+}
+
+/// Download the content of an item from its pre-authenticated download url.
+///
+/// The download url must not be sent the access token. If the download url has expired
+/// a new download url is requested once using `get_item`.
+async fn download_pre_authenticated(
+    client: &Client,
+    download_url: Option<String>,
+    get_item: impl Fn() -> RequestHandler,
+) -> GraphResult<reqwest::Response> {
+    let mut refreshed = download_url.is_none();
+    let mut download_url = match download_url {
+        Some(download_url) => download_url,
+        None => get_download_url(get_item()).await?,
+    };
+
+    loop {
+        let response = client
+            .send_pre_authenticated(Url::parse(download_url.as_str())?)
+            .await?;
+        let expired = matches!(
+            response.status(),
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN | StatusCode::GONE
+        );
+
+        if expired && !refreshed {
+            download_url = get_download_url(get_item()).await?;
+            refreshed = true;
+            continue;
+        }
+
+        return Ok(response.error_for_status()?);
+    }
+}
+
+/// Remove a permission of an item. Inherited permissions can only be removed from the
+/// item they are inherited from.
+async fn revoke_permission(delete_permission: RequestHandler) -> GraphResult<()> {
+    delete_permission
+        .send()
+        .await?
+        .error_for_graph_status()
+        .await?;
+    Ok(())
+}
+
+impl DrivesApiClient {
+    /// The default drive of a user, `/users/{id}/drive`.
+    pub fn for_user(&self, user_id: impl AsRef<str>) -> DefaultDriveApiClient {
+        self.default_drive_of(ResourceIdentity::Users, user_id.as_ref())
+    }
+
+    /// The default document library of a Microsoft 365 group, `/groups/{id}/drive`.
+    ///
+    /// The other drives of a group are listed with `client.group(id).list_drives()`.
+    pub fn for_group(&self, group_id: impl AsRef<str>) -> DefaultDriveApiClient {
+        self.default_drive_of(ResourceIdentity::Groups, group_id.as_ref())
+    }
+
+    /// The default document library of a site, `/sites/{id}/drive`. The site id may also
+    /// be a `{hostname}:/{path}:` id, see
+    /// [`SitesApiClient::by_hostname_path`](crate::sites::SitesApiClient::by_hostname_path).
+    pub fn for_site(&self, site_id: impl AsRef<str>) -> DefaultDriveApiClient {
+        let mut resource_config = self.resource_config.clone();
+        resource_config.set_path_id(site_id.as_ref());
+        resource_config.extend_path_with_id(ResourceIdentity::Sites.to_string());
+        resource_config.resource_identity = ResourceIdentity::Drive;
+        DefaultDriveApiClient::new(self.client.clone(), resource_config, Handlebars::new())
+    }
+
+    fn default_drive_of(
+        &self,
+        resource_identity: ResourceIdentity,
+        id: &str,
+    ) -> DefaultDriveApiClient {
+        let mut resource_config = self.resource_config.clone();
+        resource_config.extend_path(&[resource_identity.to_string().as_str(), id]);
+        resource_config.resource_identity = ResourceIdentity::Drive;
+        resource_config.resource_identity_id = None;
+        DefaultDriveApiClient::new(self.client.clone(), resource_config, Handlebars::new())
+    }
+}
 
 impl DrivesIdApiClient {
+    /// Address a drive item by its path relative to the root of the drive
+    /// such as `Documents/Reports/Q1.xlsx`.
+    pub fn by_path(&self, path: impl AsRef<str>) -> DrivesItemsPathIdApiClient {
+        self.item_by_path(drive_item_path(path.as_ref()))
+    }
+
     post!(
         doc: "Create drive item in root of drive",
         name: create_root_folder,
@@ -33,6 +233,242 @@ impl DrivesItemsIdApiClient {
         body: true,
         params: file_name
     );
+
+    /// Copy the item and wait for the copy to complete.
+    ///
+    /// Copying is an asynchronous action. The copy request returns a 202 Accepted response
+    /// with a monitor url in the Location header. The monitor url is polled every
+    /// `poll_interval`, or for the duration of a Retry-After header, until the copy has
+    /// completed and then the new item is returned. Items copied to a folder in another
+    /// drive are requested from the drive given in the `parentReference` of the copy.
+    ///
+    /// A copy that fails returns [`GraphRsError::AsyncOperationFailed`] and a copy that
+    /// does not complete within `timeout` returns [`GraphRsError::AsyncOperationTimeout`].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let item = client
+    ///     .drive("DRIVE_ID")
+    ///     .item("ITEM_ID")
+    ///     .copy_and_wait(
+    ///         &DriveItemCopy::new().with_name("copy.txt"),
+    ///         Duration::from_secs(1),
+    ///         Duration::from_secs(60),
+    ///     )
+    ///     .await?;
+    /// ```
+    pub async fn copy_and_wait(
+        &self,
+        body: &DriveItemCopy,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> GraphResult<DriveItem> {
+        copy_and_wait(
+            body,
+            self.copy(body),
+            &self.client,
+            &self.resource_config,
+            poll_interval,
+            timeout,
+        )
+        .await
+    }
+
+    /// Get the pre-authenticated `@microsoft.graph.downloadUrl` of the item.
+    ///
+    /// The url is only valid for a short period of time, usually an hour, and does not
+    /// require the Authorization header.
+    pub async fn download_url(&self) -> GraphResult<String> {
+        get_download_url(self.get_items()).await
+    }
+
+    /// Download the content of the item using its pre-authenticated download url.
+    ///
+    /// A download url previously returned by [`DrivesItemsIdApiClient::download_url`] can
+    /// be passed to avoid requesting the item again. If the download url has expired by
+    /// the time the download starts a new download url is requested and the download is
+    /// retried once. The Authorization header is never sent to the download url.
+    ///
+    /// The response can be streamed or written to a file using
+    /// [`ResponseExt::download`](graph_http::traits::ResponseExt::download).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let response = client
+    ///     .drive("DRIVE_ID")
+    ///     .item("ITEM_ID")
+    ///     .download_pre_authenticated(None)
+    ///     .await?;
+    ///
+    /// let path = response.download(&FileConfig::new("./examples")).await?;
+    /// ```
+    pub async fn download_pre_authenticated(
+        &self,
+        download_url: Option<String>,
+    ) -> GraphResult<reqwest::Response> {
+        download_pre_authenticated(&self.client, download_url, || self.get_items()).await
+    }
+
+    post!(
+        doc: "Invoke action revokeGrants",
+        name: revoke_grants,
+        path: "/items/{{RID}}/permissions/{{id}}/revokeGrants",
+        body: true,
+        params: permission_id
+    );
+
+    /// Create a sharing link for the item and return the permission of the link.
+    ///
+    /// If a link of the same type and scope already exists the existing link is
+    /// returned. The url of the link is in [Permission::link_url].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let permission = client
+    ///     .drive("DRIVE_ID")
+    ///     .item("ITEM_ID")
+    ///     .share_link(
+    ///         &CreateLink::new(SharingLinkType::View)
+    ///             .with_scope(SharingLinkScope::Organization)
+    ///             .with_expiration(OffsetDateTime::now_utc() + Duration::days(7)),
+    ///     )
+    ///     .await?;
+    ///
+    /// println!("{:#?}", permission.link_url());
+    /// ```
+    pub async fn share_link(&self, create_link: &CreateLink) -> GraphResult<Permission> {
+        Ok(self.create_link(create_link).send_json().await?.into_body())
+    }
+
+    /// Share the item with the recipients of the invite and return the permissions
+    /// that were granted.
+    pub async fn share_with(&self, invite: &DriveItemInvite) -> GraphResult<Vec<Permission>> {
+        let permissions: Collection<Permission> =
+            self.invite(invite).send_json().await?.into_body();
+        Ok(permissions.value)
+    }
+
+    /// List the permissions of the item including the permissions inherited from
+    /// parent folders. Use [Permission::grantees] to get who each permission is
+    /// granted to.
+    pub async fn sharing_permissions(&self) -> GraphResult<Vec<Permission>> {
+        let permissions: Collection<Permission> =
+            self.list_permissions().send_json().await?.into_body();
+        Ok(permissions.value)
+    }
+
+    /// Remove a permission, such as a sharing link, from the item.
+    pub async fn revoke_permission(&self, permission_id: impl AsRef<str>) -> GraphResult<()> {
+        revoke_permission(self.delete_permissions(permission_id)).await
+    }
+
+    /// Remove recipients from a sharing link and return the updated permission. The
+    /// link itself is kept.
+    pub async fn revoke_link_recipients(
+        &self,
+        permission_id: impl AsRef<str>,
+        recipients: Vec<DriveRecipient>,
+    ) -> GraphResult<Permission> {
+        Ok(self
+            .revoke_grants(permission_id, &RevokeGrants::new(recipients))
+            .send_json()
+            .await?
+            .into_body())
+    }
+}
+
+impl DrivesItemsPathIdApiClient {
+    /// Copy the item and wait for the copy to complete.
+    ///
+    /// See [`DrivesItemsIdApiClient::copy_and_wait`]
+    pub async fn copy_and_wait(
+        &self,
+        body: &DriveItemCopy,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> GraphResult<DriveItem> {
+        copy_and_wait(
+            body,
+            self.copy(body),
+            &self.client,
+            &self.resource_config,
+            poll_interval,
+            timeout,
+        )
+        .await
+    }
+
+    /// Get the pre-authenticated `@microsoft.graph.downloadUrl` of the item.
+    ///
+    /// See [`DrivesItemsIdApiClient::download_url`]
+    pub async fn download_url(&self) -> GraphResult<String> {
+        get_download_url(self.get_items()).await
+    }
+
+    /// Download the content of the item using its pre-authenticated download url.
+    ///
+    /// See [`DrivesItemsIdApiClient::download_pre_authenticated`]
+    pub async fn download_pre_authenticated(
+        &self,
+        download_url: Option<String>,
+    ) -> GraphResult<reqwest::Response> {
+        download_pre_authenticated(&self.client, download_url, || self.get_items()).await
+    }
+
+    post!(
+        doc: "Invoke action revokeGrants",
+        name: revoke_grants,
+        path: "/root{{RID}}/permissions/{{id}}/revokeGrants",
+        body: true,
+        params: permission_id
+    );
+
+    /// Create a sharing link for the item and return the permission of the link.
+    ///
+    /// See [`DrivesItemsIdApiClient::share_link`]
+    pub async fn share_link(&self, create_link: &CreateLink) -> GraphResult<Permission> {
+        Ok(self.create_link(create_link).send_json().await?.into_body())
+    }
+
+    /// Share the item with the recipients of the invite.
+    ///
+    /// See [`DrivesItemsIdApiClient::share_with`]
+    pub async fn share_with(&self, invite: &DriveItemInvite) -> GraphResult<Vec<Permission>> {
+        let permissions: Collection<Permission> =
+            self.invite(invite).send_json().await?.into_body();
+        Ok(permissions.value)
+    }
+
+    /// List the permissions of the item.
+    ///
+    /// See [`DrivesItemsIdApiClient::sharing_permissions`]
+    pub async fn sharing_permissions(&self) -> GraphResult<Vec<Permission>> {
+        let permissions: Collection<Permission> =
+            self.list_permissions().send_json().await?.into_body();
+        Ok(permissions.value)
+    }
+
+    /// Remove a permission from the item.
+    ///
+    /// See [`DrivesItemsIdApiClient::revoke_permission`]
+    pub async fn revoke_permission(&self, permission_id: impl AsRef<str>) -> GraphResult<()> {
+        revoke_permission(self.delete_permissions(permission_id)).await
+    }
+
+    /// Remove recipients from a sharing link.
+    ///
+    /// See [`DrivesItemsIdApiClient::revoke_link_recipients`]
+    pub async fn revoke_link_recipients(
+        &self,
+        permission_id: impl AsRef<str>,
+        recipients: Vec<DriveRecipient>,
+    ) -> GraphResult<Permission> {
+        Ok(self
+            .revoke_grants(permission_id, &RevokeGrants::new(recipients))
+            .send_json()
+            .await?
+            .into_body())
+    }
 }
 
 impl WorksheetsIdApiClient {
@@ -3,6 +3,7 @@ mod drives_items;
 mod drives_items_path;
 mod drives_list;
 mod drives_list_content_types;
+mod folder_sync;
 mod last_modified_by_user;
 mod manual_request;
 mod request;
@@ -28,9 +29,9 @@ pub use drives_items::*;
 pub use drives_items_path::*;
 pub use drives_list::*;
 pub use drives_list_content_types::*;
+pub use folder_sync::*;
 pub use last_modified_by_user::*;
-#[allow(unused_imports)]
-pub use manual_request::*;
+pub(crate) use manual_request::*;
 pub use request::*;
 pub use workbook::*;
 pub use workbook_functions::*;
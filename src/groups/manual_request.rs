@@ -0,0 +1,350 @@
+use crate::api_default_imports::*;
+use crate::directory_objects::directory_object_url;
+use crate::groups::*;
+use crate::types::{Group, Photo, PhotoSize, Team};
+use crate::users::{download_photo, upload_photo};
+use graph_http::traits::ResponseExt;
+use reqwest::StatusCode;
+use std::time::Duration;
+
+/// The maximum number of members that can be added to a group in a single request.
+const ADD_MEMBERS_LIMIT: usize = 20;
+
+/// The body of a request that creates a Microsoft 365 group together with the owners,
+/// members, and team of the group. See [`GroupsApiClient::provision`]
+///
+/// # Example
+/// ```rust,ignore
+/// let provisioning = GroupProvisioning::new("Project X", "projectx")
+///     .description("Everything about Project X")
+///     .owners(["OWNER_USER_ID"])
+///     .members(["USER_ID_1", "USER_ID_2"])
+///     .with_team();
+///
+/// let provisioned = client.groups().provision(&provisioning).await?;
+/// for (step, error) in provisioned.errors.iter() {
+///     eprintln!("{step:?} failed for group {:?}: {error}", provisioned.group.id);
+/// }
+/// println!("{:#?}", provisioned.team);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct GroupProvisioning {
+    group: Group,
+    owners: Vec<String>,
+    members: Vec<String>,
+    team: Option<serde_json::Value>,
+    max_retries: usize,
+    initial_delay: Duration,
+}
+
+impl GroupProvisioning {
+    /// A Microsoft 365 group with the given display name and mail nickname.
+    pub fn new(display_name: impl AsRef<str>, mail_nickname: impl AsRef<str>) -> GroupProvisioning {
+        GroupProvisioning {
+            group: Group {
+                display_name: Some(display_name.as_ref().to_owned()),
+                mail_nickname: Some(mail_nickname.as_ref().to_owned()),
+                group_types: vec!["Unified".to_owned()],
+                mail_enabled: Some(true),
+                security_enabled: Some(false),
+                ..Default::default()
+            },
+            owners: vec![],
+            members: vec![],
+            team: None,
+            max_retries: 5,
+            initial_delay: Duration::from_secs(5),
+        }
+    }
+
+    pub fn description(mut self, description: impl AsRef<str>) -> GroupProvisioning {
+        self.group.description = Some(description.as_ref().to_owned());
+        self
+    }
+
+    /// `Public` or `Private`. Groups are public by default.
+    pub fn visibility(mut self, visibility: impl AsRef<str>) -> GroupProvisioning {
+        self.group.visibility = Some(visibility.as_ref().to_owned());
+        self
+    }
+
+    /// The ids of the users that own the group. The owners are added when the group is
+    /// created. A team can only be created by an application for a group with an owner.
+    pub fn owners<I: IntoIterator<Item = T>, T: AsRef<str>>(
+        mut self,
+        owners: I,
+    ) -> GroupProvisioning {
+        self.owners = owners
+            .into_iter()
+            .map(|id| id.as_ref().to_owned())
+            .collect();
+        self
+    }
+
+    /// The ids of the directory objects that are added to the members of the group
+    /// after the group has been created.
+    pub fn members<I: IntoIterator<Item = T>, T: AsRef<str>>(
+        mut self,
+        members: I,
+    ) -> GroupProvisioning {
+        self.members = members
+            .into_iter()
+            .map(|id| id.as_ref().to_owned())
+            .collect();
+        self
+    }
+
+    /// Create a team for the group with the default team settings.
+    pub fn with_team(self) -> GroupProvisioning {
+        self.team(serde_json::json!({}))
+    }
+
+    /// Create a team for the group with the given team settings such as
+    /// `memberSettings` and `messagingSettings`.
+    pub fn team(mut self, team: serde_json::Value) -> GroupProvisioning {
+        self.team = Some(team);
+        self
+    }
+
+    /// The number of times a request that depends on the new group is retried while
+    /// the request returns 404 Not Found. Defaults to 5.
+    pub fn max_retries(mut self, max_retries: usize) -> GroupProvisioning {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// The delay before the first retry which is doubled for every following retry.
+    /// Defaults to 5 seconds.
+    pub fn initial_delay(mut self, initial_delay: Duration) -> GroupProvisioning {
+        self.initial_delay = initial_delay;
+        self
+    }
+
+    fn retry_delay(&self, attempt: usize) -> Duration {
+        self.initial_delay * 2u32.pow(attempt.min(6) as u32)
+    }
+}
+
+/// A step of [`GroupsApiClient::provision`] that depends on the created group.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ProvisioningStep {
+    AddMembers,
+    CreateTeam,
+}
+
+/// The group, and the team of the group if one was requested, created by
+/// [`GroupsApiClient::provision`].
+///
+/// The steps that failed after the group was created are in `errors`. The group is
+/// not deleted when a step fails so use the id of the group to retry or clean up.
+#[derive(Debug, Default)]
+pub struct ProvisionedGroup {
+    pub group: Group,
+    pub team: Option<Team>,
+    pub errors: Vec<(ProvisioningStep, GraphFailure)>,
+}
+
+impl ProvisionedGroup {
+    /// Every step of provisioning succeeded.
+    pub fn is_complete(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Send the request until it does not return 404 Not Found.
+///
+/// Creating a group is eventually consistent. Requests that depend on a new group, such
+/// as adding members or creating a team, return 404 Not Found until the group has
+/// replicated across the directory which can take several minutes.
+async fn send_until_replicated(
+    request: impl Fn() -> RequestHandler,
+    provisioning: &GroupProvisioning,
+) -> GraphResult<reqwest::Response> {
+    let mut attempt = 0;
+    loop {
+        let response = request().send().await?;
+        if response.status() != StatusCode::NOT_FOUND || attempt >= provisioning.max_retries {
+            return response.error_for_graph_status().await;
+        }
+
+        tokio::time::sleep(provisioning.retry_delay(attempt)).await;
+        attempt += 1;
+    }
+}
+
+impl GroupsApiClient {
+    /// Create a Microsoft 365 group, add the owners and members, and create a team for
+    /// the group if one was requested.
+    ///
+    /// The owners are added when the group is created. Adding the members and creating
+    /// the team depend on the new group and are retried with an exponential back-off
+    /// while they return 404 Not Found until the group has replicated.
+    ///
+    /// An error is only returned if the group could not be created. Once the group
+    /// exists the remaining steps are still attempted when a step fails and the error
+    /// of each failed step is returned in [`ProvisionedGroup::errors`]. The group is not
+    /// deleted when a step fails.
+    pub async fn provision(
+        &self,
+        provisioning: &GroupProvisioning,
+    ) -> GraphResult<ProvisionedGroup> {
+        let mut body = provisioning.group.clone();
+        if !provisioning.owners.is_empty() {
+            let owners: Vec<String> = provisioning
+                .owners
+                .iter()
+                .map(|id| directory_object_url(&self.resource_config.url, id))
+                .collect();
+            body.additional_fields
+                .insert("owners@odata.bind".to_owned(), serde_json::json!(owners));
+        }
+
+        let response = self
+            .create_group(&body)
+            .send()
+            .await?
+            .error_for_graph_status()
+            .await?;
+        let group: Group = response.json().await?;
+        let group_id = group
+            .id
+            .clone()
+            .ok_or_else(|| GraphFailure::invalid("id of created group"))?;
+
+        let mut resource_config = self.resource_config.clone();
+        resource_config.resource_identity_id = Some(group_id.clone());
+        let group_client = GroupsIdApiClient::new(
+            self.client.clone(),
+            resource_config,
+            ResourceProvisioner::registry_with_id(group_id.as_str()),
+        );
+
+        let members: Vec<String> = provisioning
+            .members
+            .iter()
+            .map(|id| directory_object_url(&self.resource_config.url, id))
+            .collect();
+        let mut errors = Vec::new();
+        for chunk in members.chunks(ADD_MEMBERS_LIMIT) {
+            let body = serde_json::json!({ "members@odata.bind": chunk });
+            if let Err(error) =
+                send_until_replicated(|| group_client.update_group(&body), provisioning).await
+            {
+                errors.push((ProvisioningStep::AddMembers, error));
+            }
+        }
+
+        let mut team = None;
+        if let Some(team_settings) = provisioning.team.as_ref() {
+            let created = send_until_replicated(
+                || {
+                    group_client
+                        .groups_team()
+                        .create_team_from_group(team_settings)
+                },
+                provisioning,
+            )
+            .await;
+            match created {
+                Ok(response) => match response.json().await {
+                    Ok(created) => team = Some(created),
+                    Err(error) => {
+                        errors.push((ProvisioningStep::CreateTeam, GraphFailure::from(error)))
+                    }
+                },
+                Err(error) => errors.push((ProvisioningStep::CreateTeam, error)),
+            }
+        }
+
+        Ok(ProvisionedGroup {
+            group,
+            team,
+            errors,
+        })
+    }
+}
+
+impl GroupsIdApiClient {
+    fn directory_object_reference(&self, directory_object_id: &str) -> serde_json::Value {
+        serde_json::json!({
+            "@odata.id": directory_object_url(&self.resource_config.url, directory_object_id)
+        })
+    }
+
+    /// Add a user, group, device, or service principal to the members of the group.
+    pub fn add_member(&self, directory_object_id: impl AsRef<str>) -> RequestHandler {
+        self.create_ref_members(&self.directory_object_reference(directory_object_id.as_ref()))
+    }
+
+    /// Add any number of directory objects to the members of the group.
+    ///
+    /// Members are added using `members@odata.bind` which accepts at most 20 members
+    /// so the members are added in as many requests as needed.
+    pub async fn add_members<I: IntoIterator<Item = T>, T: AsRef<str>>(
+        &self,
+        directory_object_ids: I,
+    ) -> GraphResult<()> {
+        let members: Vec<String> = directory_object_ids
+            .into_iter()
+            .map(|id| directory_object_url(&self.resource_config.url, id.as_ref()))
+            .collect();
+
+        for chunk in members.chunks(ADD_MEMBERS_LIMIT) {
+            let body = serde_json::json!({ "members@odata.bind": chunk });
+            self.update_group(&body)
+                .send()
+                .await?
+                .error_for_graph_status()
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Remove a member from the group. The directory object itself is not deleted.
+    pub fn remove_member(&self, directory_object_id: impl AsRef<str>) -> RequestHandler {
+        self.delete_ref_members(directory_object_id)
+    }
+
+    /// Add a user or service principal to the owners of the group.
+    pub fn add_owner(&self, directory_object_id: impl AsRef<str>) -> RequestHandler {
+        self.owners()
+            .create_ref_owners(&self.directory_object_reference(directory_object_id.as_ref()))
+    }
+
+    /// Remove an owner from the group. The directory object itself is not deleted.
+    pub fn remove_owner(&self, directory_object_id: impl AsRef<str>) -> RequestHandler {
+        self.owner(directory_object_id).delete_ref_owners()
+    }
+
+    get!(
+        doc: "Get media content for a size of the photo of the group",
+        name: get_photos_content,
+        path: "/groups/{{RID}}/photos/{{id}}/$value",
+        params: profile_photo_id
+    );
+
+    /// Download the photo of the group in its original size or in the given size.
+    /// Returns `None` if the group does not have a photo.
+    pub async fn download_photo(&self, size: Option<PhotoSize>) -> GraphResult<Option<Photo>> {
+        match size {
+            Some(size) => download_photo(self.get_photos_content(size.as_str())).await,
+            None => download_photo(self.get_photo_content()).await,
+        }
+    }
+
+    /// Upload the photo of the group with the Content-Type of the photo.
+    ///
+    /// Returns an error if the content type of the photo is not a valid header value.
+    pub fn upload_photo(&self, photo: &Photo) -> GraphResult<RequestHandler> {
+        upload_photo(|body| self.update_photo_content(body), photo)
+    }
+}
+
+impl GroupsTeamApiClient {
+    put!(
+        doc: "Create team from group",
+        name: create_team_from_group,
+        path: "/team",
+        body: true
+    );
+}
@@ -1,6 +1,7 @@
 mod conversations;
 mod groups_owners;
 mod groups_team;
+mod manual_request;
 mod members_with_license_errors;
 mod request;
 mod threads;
@@ -10,6 +11,7 @@ mod transitive_members;
 pub use conversations::*;
 pub use groups_owners::*;
 pub use groups_team::*;
+pub use manual_request::*;
 pub use members_with_license_errors::*;
 pub use request::*;
 pub use threads::*;
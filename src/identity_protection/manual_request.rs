@@ -0,0 +1,156 @@
+use crate::api_default_imports::*;
+use crate::client::{odata_date_time, odata_string};
+use crate::types::{RiskLevel, RiskState};
+use std::fmt::{Display, Formatter};
+use time::OffsetDateTime;
+
+api_client!(
+    IdentityProtectionApiClient,
+    ResourceIdentity::IdentityProtection
+);
+
+/// Builds the `$filter` of a risky users query. Every condition must match.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::identity_protection::RiskyUserFilter;
+/// use graph_rs_sdk::types::{Collection, RiskLevel, RiskState, RiskyUser};
+///
+/// let filter = RiskyUserFilter::new()
+///     .risk_level(RiskLevel::High)
+///     .risk_state(RiskState::AtRisk);
+///
+/// let response: GraphResponse<Collection<RiskyUser>> = client
+///     .identity_protection()
+///     .list_risky_users_filtered(&filter)
+///     .send_json()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RiskyUserFilter {
+    conditions: Vec<String>,
+}
+
+impl RiskyUserFilter {
+    pub fn new() -> RiskyUserFilter {
+        RiskyUserFilter::default()
+    }
+
+    pub fn risk_level(mut self, risk_level: RiskLevel) -> RiskyUserFilter {
+        self.conditions
+            .push(format!("riskLevel eq '{}'", risk_level.as_str()));
+        self
+    }
+
+    pub fn risk_state(mut self, risk_state: RiskState) -> RiskyUserFilter {
+        self.conditions
+            .push(format!("riskState eq '{}'", risk_state.as_str()));
+        self
+    }
+
+    /// Risky users whose risk was last updated at or after the given date and time.
+    pub fn updated_after(mut self, date_time: OffsetDateTime) -> RiskyUserFilter {
+        self.conditions.push(format!(
+            "riskLastUpdatedDateTime ge {}",
+            odata_date_time(date_time)
+        ));
+        self
+    }
+
+    pub fn user_principal_name(mut self, user_principal_name: impl AsRef<str>) -> RiskyUserFilter {
+        self.conditions.push(format!(
+            "userPrincipalName eq {}",
+            odata_string(user_principal_name.as_ref())
+        ));
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.conditions.is_empty()
+    }
+}
+
+impl Display for RiskyUserFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.conditions.join(" and "))
+    }
+}
+
+impl IdentityProtectionApiClient {
+    get!(
+        doc: "List riskyUsers",
+        name: list_risky_users,
+        path: "/identityProtection/riskyUsers"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_risky_users_count,
+        path: "/identityProtection/riskyUsers/$count"
+    );
+    get!(
+        doc: "Get riskyUser",
+        name: get_risky_users,
+        path: "/identityProtection/riskyUsers/{{id}}",
+        params: risky_user_id
+    );
+    get!(
+        doc: "List history of riskyUser",
+        name: list_risky_user_history,
+        path: "/identityProtection/riskyUsers/{{id}}/history",
+        params: risky_user_id
+    );
+    post!(
+        doc: "Invoke action confirmCompromised",
+        name: confirm_compromised,
+        path: "/identityProtection/riskyUsers/confirmCompromised",
+        body: true
+    );
+    post!(
+        doc: "Invoke action dismiss",
+        name: dismiss,
+        path: "/identityProtection/riskyUsers/dismiss",
+        body: true
+    );
+
+    /// List risky users that match the filter. Use [RequestHandler::paging] to stream
+    /// every page of the results.
+    pub fn list_risky_users_filtered(&self, filter: &RiskyUserFilter) -> RequestHandler {
+        let request = self.list_risky_users();
+        if filter.is_empty() {
+            return request;
+        }
+        request.filter(&[filter.to_string().as_str()])
+    }
+
+    /// Count the risky users that match the filter. `$count` requires the
+    /// `ConsistencyLevel: eventual` header which is set for the request.
+    pub fn count_risky_users(&self, filter: &RiskyUserFilter) -> RequestHandler {
+        let request = self.get_risky_users_count().consistency_level_eventual();
+        if filter.is_empty() {
+            return request;
+        }
+        request.filter(&[filter.to_string().as_str()])
+    }
+
+    /// Confirm that the accounts of the users are compromised which sets their risk
+    /// level to high.
+    pub fn confirm_compromised_users<I: IntoIterator<Item = T>, T: ToString>(
+        &self,
+        user_ids: I,
+    ) -> RequestHandler {
+        self.confirm_compromised(&user_ids_body(user_ids))
+    }
+
+    /// Dismiss the risk of the users which sets their risk level to none.
+    pub fn dismiss_risky_users<I: IntoIterator<Item = T>, T: ToString>(
+        &self,
+        user_ids: I,
+    ) -> RequestHandler {
+        self.dismiss(&user_ids_body(user_ids))
+    }
+}
+
+fn user_ids_body<I: IntoIterator<Item = T>, T: ToString>(user_ids: I) -> serde_json::Value {
+    let user_ids: Vec<String> = user_ids.into_iter().map(|id| id.to_string()).collect();
+    serde_json::json!({ "userIds": user_ids })
+}
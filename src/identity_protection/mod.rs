@@ -0,0 +1,3 @@
+mod manual_request;
+
+pub use manual_request::*;
@@ -0,0 +1,11 @@
+use crate::api_default_imports::*;
+use crate::invitations::*;
+use crate::types::Invitation;
+
+impl InvitationsApiClient {
+    /// Invite an external user to the organization. The response contains the
+    /// created guest user and the redeem url of the invitation.
+    pub fn invite_guest(&self, invitation: &Invitation) -> RequestHandler {
+        self.create_invitation(invitation)
+    }
+}
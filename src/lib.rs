@@ -225,6 +225,7 @@ pub mod groups;
 /// The main identity APIs with starting path `identity/`
 pub mod identity_access;
 pub mod identity_governance;
+pub mod identity_protection;
 pub mod identity_providers;
 pub mod invitations;
 pub mod me;
@@ -236,6 +237,7 @@ pub mod planner;
 pub mod policies;
 pub mod reports;
 pub mod schema_extensions;
+pub mod security;
 pub mod service_principals;
 pub mod sites;
 pub mod solutions;
@@ -244,6 +246,8 @@ pub mod subscriptions;
 pub mod teams;
 pub mod teams_templates;
 pub mod teamwork;
+/// Serde models for commonly used Graph resources.
+pub mod types;
 pub mod users;
 
 pub static GRAPH_URL: &str = "https://graph.microsoft.com/v1.0";
@@ -251,7 +255,8 @@ pub static GRAPH_URL_BETA: &str = "https://graph.microsoft.com/beta";
 
 pub use crate::client::{Graph, GraphClient};
 pub use graph_error::{GraphFailure, GraphResult};
-pub use graph_http::api_impl::{GraphClientConfiguration, ODataQuery};
+pub use graph_http::api_impl::{ApiVersion, GraphClientConfiguration, ODataQuery};
+pub use graph_http::filter;
 
 /// Reexport of graph-oauth crate.
 pub mod identity {
@@ -259,9 +264,39 @@ pub mod identity {
     pub use graph_oauth::*;
 }
 
+/// Fault injection for testing retry and resume logic.
+///
+/// Requires feature `fault-injection`
+#[cfg(feature = "fault-injection")]
+pub mod fault_injection {
+    pub use graph_http::fault_injection::*;
+}
+
+/// Capture the requests of a client session in an HTTP Archive (.har) file.
+///
+/// Requires feature `har-capture`
+#[cfg(feature = "har-capture")]
+pub mod har {
+    pub use graph_http::har::*;
+}
+
+/// Mock and recording transports for unit testing code that uses the Graph client.
+///
+/// Requires feature `test-util`
+#[cfg(feature = "test-util")]
+pub mod test_util {
+    pub use graph_http::test_util::*;
+}
+
 pub mod http {
     pub use graph_core::http::{HttpResponseBuilderExt, HttpResponseExt};
-    pub use graph_http::api_impl::{BodyRead, FileConfig, UploadSession};
+    pub use graph_http::api_impl::{
+        preference_applied, AsyncOperationMonitor, AsyncOperationResource, AsyncOperationState,
+        odata_string, BodyContentType, BodyRead, CacheStorage, CachedResponse, ContentHasher,
+        ContentHashes, DiskCacheStorage, FileConfig, GraphResponse, HashingWriter,
+        MemoryCacheStorage, MultipartFormData, ODataFilter, ODataLiteral, Prefer, QuickXorHash,
+        RateLimit, ResponseCache, UploadSession,
+    };
     pub use graph_http::traits::{
         AsyncIterator, ODataDeltaLink, ODataDownloadLink, ODataMetadataLink, ODataNextLink,
         ODataQuery, ResponseBlockingExt, ResponseExt, UploadSessionLink,
@@ -278,6 +313,43 @@ pub mod http {
     pub use url::Url;
 }
 
+/// Synchronous request execution for applications that do not use an async runtime.
+///
+/// Every typed request builder of the [Graph] client returns a
+/// [RequestHandler](graph_http::api_impl::RequestHandler) which can be converted into
+/// a [BlockingRequestHandler] with the same url, headers, and body using `into_blocking`.
+/// The blocking handler sends the request using a blocking client that is created once
+/// for each [Graph] client and shared by all of its requests.
+///
+/// The blocking client must not be used from within an async runtime.
+///
+/// The feature does not generate a synchronous copy of each api client. The typed
+/// request builders are shared by async and blocking code and only the sending of
+/// the request differs, so every request builder can be used without a runtime by
+/// calling `into_blocking` before `send`.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::blocking::*;
+/// use graph_rs_sdk::Graph;
+///
+/// let client = Graph::new("ACCESS_TOKEN");
+///
+/// let response = client
+///     .users()
+///     .list_user()
+///     .into_blocking()
+///     .send()?;
+///
+/// let users: serde_json::Value = response.json()?;
+/// ```
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    pub use graph_http::api_impl::{BlockingClient, BlockingRequestHandler, UploadSessionBlocking};
+    pub use graph_http::traits::ResponseBlockingExt;
+    pub use reqwest::blocking::{Body, Response};
+}
+
 /// Reexport of graph-error crate.
 pub mod error {
     pub use graph_error::*;
@@ -0,0 +1,59 @@
+use crate::api_default_imports::*;
+use crate::me::MeApiClient;
+use crate::types::{Photo, PhotoSize};
+use crate::users::{download_photo, search_people, upload_photo};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use reqwest::header::{HeaderValue, CONTENT_TYPE};
+
+impl MeApiClient {
+    /// Send a message in MIME format.
+    ///
+    /// The MIME content is base64 encoded and sent with a `text/plain` content type
+    /// as required by the sendMail action.
+    pub fn send_mime_mail(&self, mime: impl AsRef<[u8]>) -> RequestHandler {
+        self.send_mail(BodyRead::from(STANDARD.encode(mime.as_ref())))
+            .header(CONTENT_TYPE, HeaderValue::from_static("text/plain"))
+    }
+
+    /// Create a draft message in the mailbox from MIME content. The draft can be sent
+    /// with the send action of the message.
+    ///
+    /// The MIME content is base64 encoded and sent with a `text/plain` content type.
+    pub fn create_mime_message(&self, mime: impl AsRef<[u8]>) -> RequestHandler {
+        self.messages().create_mime_message(mime)
+    }
+
+    /// Download the photo of the signed in user in its original size or in the given
+    /// size. Returns `None` if the user does not have a photo.
+    pub async fn download_photo(&self, size: Option<PhotoSize>) -> GraphResult<Option<Photo>> {
+        match size {
+            Some(size) => download_photo(self.photo(size.as_str()).get_photos_content()).await,
+            None => download_photo(self.get_photo_content()).await,
+        }
+    }
+
+    /// Upload the photo of the signed in user with the Content-Type of the photo.
+    ///
+    /// Returns an error if the content type of the photo is not a valid header value.
+    pub fn upload_photo(&self, photo: &Photo) -> GraphResult<RequestHandler> {
+        upload_photo(|body| self.update_photo_content(body), photo)
+    }
+
+    /// List the people relevant to the signed in user whose name or email address
+    /// matches the search, in order of relevance. Deserialize the results as
+    /// [Person](crate::types::Person) to get the relevance score of each person.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let response: GraphResponse<Collection<Person>> = client
+    ///     .me()
+    ///     .search_people("adele")
+    ///     .top("10")
+    ///     .send_json()
+    ///     .await?;
+    /// ```
+    pub fn search_people(&self, search: impl AsRef<str>) -> RequestHandler {
+        search_people(self.list_people(), search.as_ref())
+    }
+}
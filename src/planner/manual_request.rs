@@ -0,0 +1,108 @@
+use crate::api_default_imports::*;
+use crate::planner::*;
+use graph_http::traits::ResponseExt;
+use reqwest::StatusCode;
+use serde::Serialize;
+
+/// The number of times a Planner update or delete is retried with a new ETag
+/// when the resource was changed by another client.
+const MAX_PRECONDITION_RETRIES: usize = 3;
+
+/// Get the current `@odata.etag` of a Planner resource and send the request with it
+/// in the `If-Match` header, which Planner requires for updates and deletes.
+///
+/// When the resource was changed between the two requests Planner responds with
+/// 412 Precondition Failed and the ETag is fetched again.
+async fn send_with_etag<G, R>(get: G, request: R) -> GraphResult<reqwest::Response>
+where
+    G: Fn() -> RequestHandler,
+    R: Fn() -> RequestHandler,
+{
+    let mut retries = 0;
+    loop {
+        let resource: GraphResponse<serde_json::Value> = get().send_json().await?;
+        let etag = resource
+            .odata_etag()
+            .ok_or_else(|| GraphFailure::not_found("@odata.etag"))?
+            .to_owned();
+
+        let response = request().if_match(etag).send().await?;
+        let status = response.status();
+
+        if status == StatusCode::PRECONDITION_FAILED && retries < MAX_PRECONDITION_RETRIES {
+            retries += 1;
+            continue;
+        }
+
+        return response.error_for_graph_status().await;
+    }
+}
+
+impl PlannerTasksIdApiClient {
+    /// Update the task using its current ETag.
+    ///
+    /// Planner rejects updates without an `If-Match` header. The ETag of the task is
+    /// fetched before the update and fetched again if the task was changed by another
+    /// client before the update was applied.
+    pub async fn update_task_with_etag<B: Serialize>(
+        &self,
+        body: &B,
+    ) -> GraphResult<reqwest::Response> {
+        send_with_etag(|| self.get_tasks(), || self.update_tasks(body)).await
+    }
+
+    /// Delete the task using its current ETag.
+    pub async fn delete_task_with_etag(&self) -> GraphResult<reqwest::Response> {
+        send_with_etag(|| self.get_tasks(), || self.delete_tasks()).await
+    }
+
+    /// Update the details of the task using the current ETag of the details.
+    pub async fn update_details_with_etag<B: Serialize>(
+        &self,
+        body: &B,
+    ) -> GraphResult<reqwest::Response> {
+        send_with_etag(|| self.get_details(), || self.update_details(body)).await
+    }
+}
+
+impl BucketsIdApiClient {
+    /// Update the bucket using its current ETag.
+    ///
+    /// See [`PlannerTasksIdApiClient::update_task_with_etag`]
+    pub async fn update_bucket_with_etag<B: Serialize>(
+        &self,
+        body: &B,
+    ) -> GraphResult<reqwest::Response> {
+        send_with_etag(|| self.get_buckets(), || self.update_buckets(body)).await
+    }
+
+    /// Delete the bucket using its current ETag.
+    pub async fn delete_bucket_with_etag(&self) -> GraphResult<reqwest::Response> {
+        send_with_etag(|| self.get_buckets(), || self.delete_buckets()).await
+    }
+}
+
+impl PlansIdApiClient {
+    /// Update the plan using its current ETag.
+    ///
+    /// See [`PlannerTasksIdApiClient::update_task_with_etag`]
+    pub async fn update_plan_with_etag<B: Serialize>(
+        &self,
+        body: &B,
+    ) -> GraphResult<reqwest::Response> {
+        send_with_etag(|| self.get_plans(), || self.update_plans(body)).await
+    }
+
+    /// Delete the plan using its current ETag.
+    pub async fn delete_plan_with_etag(&self) -> GraphResult<reqwest::Response> {
+        send_with_etag(|| self.get_plans(), || self.delete_plans()).await
+    }
+
+    /// Update the details of the plan using the current ETag of the details.
+    pub async fn update_details_with_etag<B: Serialize>(
+        &self,
+        body: &B,
+    ) -> GraphResult<reqwest::Response> {
+        send_with_etag(|| self.get_details(), || self.update_details(body)).await
+    }
+}
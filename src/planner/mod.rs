@@ -1,4 +1,5 @@
 mod buckets;
+mod manual_request;
 mod planner_tasks;
 mod plans;
 mod request;
@@ -1,6 +1,6 @@
 mod manual_request;
+mod report;
 mod request;
 
-#[allow(unused_imports)]
-pub use manual_request::*;
+pub use report::*;
 pub use request::*;
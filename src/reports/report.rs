@@ -0,0 +1,226 @@
+use crate::types::Collection;
+use graph_error::GraphResult;
+use graph_http::traits::ResponseExt;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+/// The number of days a usage report is aggregated over.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ReportPeriod {
+    D7,
+    D30,
+    D90,
+    D180,
+}
+
+impl ReportPeriod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReportPeriod::D7 => "D7",
+            ReportPeriod::D30 => "D30",
+            ReportPeriod::D90 => "D90",
+            ReportPeriod::D180 => "D180",
+        }
+    }
+}
+
+impl AsRef<str> for ReportPeriod {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for ReportPeriod {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The `$format` of a usage report. Reports are returned as CSV by default.
+///
+/// JSON reports are only available on the beta endpoint.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum ReportFormat {
+    #[default]
+    Csv,
+    Json,
+}
+
+impl ReportFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReportFormat::Csv => "text/csv",
+            ReportFormat::Json => "application/json",
+        }
+    }
+}
+
+impl AsRef<str> for ReportFormat {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for ReportFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Strip the UTF-8 byte order mark that report files start with.
+fn strip_bom(content: &str) -> &str {
+    content.strip_prefix('\u{feff}').unwrap_or(content)
+}
+
+/// The CSV content of a usage report.
+///
+/// Usage report functions respond with a 302 redirect to a short lived pre-authenticated
+/// url of the CSV file. The redirect is followed by the client and the Authorization
+/// header is not sent to the download url. Use [reqwest::Response::bytes_stream] on the
+/// response to stream the raw CSV instead of reading the report into memory.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::reports::{ReportCsv, ReportPeriod};
+///
+/// #[derive(Debug, Deserialize)]
+/// struct ActiveUserDetail {
+///     #[serde(rename = "User Principal Name")]
+///     user_principal_name: String,
+///     #[serde(rename = "Last Activity Date")]
+///     last_activity_date: Option<String>,
+/// }
+///
+/// let response = client
+///     .reports()
+///     .get_office_365_active_user_detail_by_period(ReportPeriod::D7)
+///     .send()
+///     .await?;
+///
+/// let rows: Vec<ActiveUserDetail> = ReportCsv::from_response(response).await?.rows()?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ReportCsv {
+    content: String,
+}
+
+impl ReportCsv {
+    pub fn new(content: impl Into<String>) -> ReportCsv {
+        let mut content = content.into();
+        if strip_bom(content.as_str()).len() != content.len() {
+            content.drain(..'\u{feff}'.len_utf8());
+        }
+        ReportCsv { content }
+    }
+
+    /// Read the report from the response of a report function.
+    pub async fn from_response(response: reqwest::Response) -> GraphResult<ReportCsv> {
+        let response = response.error_for_graph_status().await?;
+        Ok(ReportCsv::new(response.text().await?))
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.content.as_str()
+    }
+
+    fn reader(&self) -> csv::Reader<&[u8]> {
+        csv::ReaderBuilder::new()
+            .flexible(true)
+            .from_reader(self.content.as_bytes())
+    }
+
+    /// The column names of the report.
+    pub fn headers(&self) -> GraphResult<Vec<String>> {
+        let mut reader = self.reader();
+        let headers = reader.headers().map_err(std::io::Error::from)?;
+        Ok(headers.iter().map(|header| header.to_owned()).collect())
+    }
+
+    /// The rows of the report keyed by column name.
+    pub fn records(&self) -> GraphResult<Vec<HashMap<String, String>>> {
+        self.rows()
+    }
+
+    /// Deserialize the rows of the report. Fields are matched to the column names
+    /// of the report and empty values deserialize to `None`.
+    pub fn rows<T: DeserializeOwned>(&self) -> GraphResult<Vec<T>> {
+        let rows: Result<Vec<T>, csv::Error> = self.reader().deserialize().collect();
+        Ok(rows.map_err(std::io::Error::from)?)
+    }
+}
+
+impl AsRef<str> for ReportCsv {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// The rows of a usage report requested in the JSON format.
+///
+/// JSON reports are returned in the body of the response, instead of as a redirect to
+/// a CSV file, and use camel case property names such as `userPrincipalName`. Large
+/// reports are paged and the `@odata.nextLink` of the page is returned by
+/// [ReportJson::next_link].
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::reports::{ReportFormat, ReportJson, ReportPeriod};
+///
+/// #[derive(Debug, Deserialize)]
+/// #[serde(rename_all = "camelCase")]
+/// struct ActiveUserDetail {
+///     user_principal_name: String,
+///     last_activity_date: Option<String>,
+/// }
+///
+/// let response = client
+///     .beta()
+///     .reports()
+///     .get_office_365_active_user_detail_by_period(ReportPeriod::D7)
+///     .format(ReportFormat::Json)
+///     .send()
+///     .await?;
+///
+/// let rows: Vec<ActiveUserDetail> = ReportJson::from_response(response).await?.rows()?;
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ReportJson {
+    records: Vec<Value>,
+    next_link: Option<String>,
+}
+
+impl ReportJson {
+    pub fn new(content: &str) -> GraphResult<ReportJson> {
+        let collection: Collection<Value> = serde_json::from_str(strip_bom(content))?;
+        Ok(ReportJson {
+            records: collection.value,
+            next_link: collection.next_link,
+        })
+    }
+
+    /// Read the report from the response of a report function.
+    pub async fn from_response(response: reqwest::Response) -> GraphResult<ReportJson> {
+        let response = response.error_for_graph_status().await?;
+        ReportJson::new(response.text().await?.as_str())
+    }
+
+    /// The rows of the report as JSON objects.
+    pub fn records(&self) -> &[Value] {
+        self.records.as_slice()
+    }
+
+    /// Deserialize the rows of the report.
+    pub fn rows<T: DeserializeOwned>(&self) -> GraphResult<Vec<T>> {
+        self.records
+            .iter()
+            .map(|record| Ok(serde_json::from_value(record.clone())?))
+            .collect()
+    }
+
+    /// The url of the next page of the report, if any.
+    pub fn next_link(&self) -> Option<&str> {
+        self.next_link.as_deref()
+    }
+}
@@ -0,0 +1,572 @@
+use crate::api_default_imports::*;
+use crate::client::{odata_date_time, odata_string};
+use crate::types::{
+    AlertClassification, AlertSeverity, AlertStatus, AlertUpdate, CaseOperation, IncidentStatus,
+    IncidentUpdate,
+};
+use graph_http::traits::ResponseExt;
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+use time::OffsetDateTime;
+
+api_client!(SecurityApiClient, ResourceIdentity::Security);
+
+/// Builds the `$filter` of a security alerts query. Every condition must match.
+///
+/// # Example
+/// ```rust,ignore
+/// use futures::StreamExt;
+/// use graph_rs_sdk::security::AlertFilter;
+/// use graph_rs_sdk::types::{Alert, AlertSeverity, AlertStatus, Collection};
+///
+/// let filter = AlertFilter::new()
+///     .severity(AlertSeverity::High)
+///     .status(AlertStatus::New)
+///     .created_after(OffsetDateTime::now_utc() - Duration::hours(1));
+///
+/// let mut stream = client
+///     .security()
+///     .list_alerts_filtered(&filter)
+///     .paging()
+///     .stream::<Collection<Alert>>()?;
+///
+/// while let Some(result) = stream.next().await {
+///     for alert in result?.into_body()? {
+///         println!("{:#?}", alert.title);
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AlertFilter {
+    conditions: Vec<String>,
+}
+
+impl AlertFilter {
+    pub fn new() -> AlertFilter {
+        AlertFilter::default()
+    }
+
+    pub fn severity(mut self, severity: AlertSeverity) -> AlertFilter {
+        self.conditions
+            .push(format!("severity eq '{}'", severity.as_str()));
+        self
+    }
+
+    pub fn status(mut self, status: AlertStatus) -> AlertFilter {
+        self.conditions
+            .push(format!("status eq '{}'", status.as_str()));
+        self
+    }
+
+    pub fn classification(mut self, classification: AlertClassification) -> AlertFilter {
+        self.conditions
+            .push(format!("classification eq '{}'", classification.as_str()));
+        self
+    }
+
+    /// Alerts of a service such as `microsoftDefenderForEndpoint`.
+    pub fn service_source(mut self, service_source: impl AsRef<str>) -> AlertFilter {
+        self.conditions.push(format!(
+            "serviceSource eq {}",
+            odata_string(service_source.as_ref())
+        ));
+        self
+    }
+
+    pub fn incident_id(mut self, incident_id: impl AsRef<str>) -> AlertFilter {
+        self.conditions.push(format!(
+            "incidentId eq {}",
+            odata_string(incident_id.as_ref())
+        ));
+        self
+    }
+
+    /// Alerts created at or after the given date and time.
+    pub fn created_after(mut self, date_time: OffsetDateTime) -> AlertFilter {
+        self.conditions
+            .push(format!("createdDateTime ge {}", odata_date_time(date_time)));
+        self
+    }
+
+    /// Alerts created before the given date and time.
+    pub fn created_before(mut self, date_time: OffsetDateTime) -> AlertFilter {
+        self.conditions
+            .push(format!("createdDateTime lt {}", odata_date_time(date_time)));
+        self
+    }
+
+    /// Alerts updated at or after the given date and time such as the time of the
+    /// last poll of an automation.
+    pub fn updated_after(mut self, date_time: OffsetDateTime) -> AlertFilter {
+        self.conditions.push(format!(
+            "lastUpdateDateTime ge {}",
+            odata_date_time(date_time)
+        ));
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.conditions.is_empty()
+    }
+}
+
+impl Display for AlertFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.conditions.join(" and "))
+    }
+}
+
+/// Builds the `$filter` of a security incidents query. Every condition must match.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::security::IncidentFilter;
+/// use graph_rs_sdk::types::{AlertSeverity, Collection, Incident, IncidentStatus};
+///
+/// let filter = IncidentFilter::new()
+///     .severity(AlertSeverity::High)
+///     .status(IncidentStatus::Active);
+///
+/// let response: GraphResponse<Collection<Incident>> = client
+///     .security()
+///     .list_incidents_filtered(&filter)
+///     .send_json()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct IncidentFilter {
+    conditions: Vec<String>,
+}
+
+impl IncidentFilter {
+    pub fn new() -> IncidentFilter {
+        IncidentFilter::default()
+    }
+
+    pub fn severity(mut self, severity: AlertSeverity) -> IncidentFilter {
+        self.conditions
+            .push(format!("severity eq '{}'", severity.as_str()));
+        self
+    }
+
+    pub fn status(mut self, status: IncidentStatus) -> IncidentFilter {
+        self.conditions
+            .push(format!("status eq '{}'", status.as_str()));
+        self
+    }
+
+    pub fn classification(mut self, classification: AlertClassification) -> IncidentFilter {
+        self.conditions
+            .push(format!("classification eq '{}'", classification.as_str()));
+        self
+    }
+
+    pub fn assigned_to(mut self, assigned_to: impl AsRef<str>) -> IncidentFilter {
+        self.conditions.push(format!(
+            "assignedTo eq {}",
+            odata_string(assigned_to.as_ref())
+        ));
+        self
+    }
+
+    /// Incidents created at or after the given date and time.
+    pub fn created_after(mut self, date_time: OffsetDateTime) -> IncidentFilter {
+        self.conditions
+            .push(format!("createdDateTime ge {}", odata_date_time(date_time)));
+        self
+    }
+
+    /// Incidents updated at or after the given date and time.
+    pub fn updated_after(mut self, date_time: OffsetDateTime) -> IncidentFilter {
+        self.conditions.push(format!(
+            "lastUpdateDateTime ge {}",
+            odata_date_time(date_time)
+        ));
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.conditions.is_empty()
+    }
+}
+
+impl Display for IncidentFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.conditions.join(" and "))
+    }
+}
+
+/// Send an eDiscovery action that returns 202 Accepted and wait for the case operation
+/// given in the Location header to end.
+///
+/// Unlike the monitor url of a drive item copy the case operation url is a Graph API url
+/// and is sent the access token, so the url must be on the same host as the client.
+async fn send_and_wait(
+    action: RequestHandler,
+    client: &Client,
+    resource_config: &ResourceConfig,
+    poll_interval: Duration,
+    timeout: Duration,
+) -> GraphResult<CaseOperation> {
+    let response = action.send().await?;
+    let response = response.error_for_graph_status().await?;
+
+    AsyncOperationMonitor::from_response(client, &resource_config.url, &response)?
+        .poll_interval(poll_interval)
+        .timeout(timeout)
+        .wait()
+        .await
+}
+
+impl SecurityApiClient {
+    get!(
+        doc: "List alerts_v2",
+        name: list_alerts_v2,
+        path: "/security/alerts_v2"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_alerts_v2_count,
+        path: "/security/alerts_v2/$count"
+    );
+    get!(
+        doc: "Get alert",
+        name: get_alerts_v2,
+        path: "/security/alerts_v2/{{id}}",
+        params: alert_id
+    );
+    patch!(
+        doc: "Update alert",
+        name: update_alerts_v2,
+        path: "/security/alerts_v2/{{id}}",
+        body: true,
+        params: alert_id
+    );
+    post!(
+        doc: "Create comment for alert",
+        name: create_alert_comments,
+        path: "/security/alerts_v2/{{id}}/comments",
+        body: true,
+        params: alert_id
+    );
+    get!(
+        doc: "List ediscoveryCases",
+        name: list_ediscovery_cases,
+        path: "/security/cases/ediscoveryCases"
+    );
+    post!(
+        doc: "Create ediscoveryCase",
+        name: create_ediscovery_cases,
+        path: "/security/cases/ediscoveryCases",
+        body: true
+    );
+    get!(
+        doc: "Get ediscoveryCase",
+        name: get_ediscovery_cases,
+        path: "/security/cases/ediscoveryCases/{{id}}",
+        params: ediscovery_case_id
+    );
+    patch!(
+        doc: "Update ediscoveryCase",
+        name: update_ediscovery_cases,
+        path: "/security/cases/ediscoveryCases/{{id}}",
+        body: true,
+        params: ediscovery_case_id
+    );
+    delete!(
+        doc: "Delete ediscoveryCase",
+        name: delete_ediscovery_cases,
+        path: "/security/cases/ediscoveryCases/{{id}}",
+        params: ediscovery_case_id
+    );
+    post!(
+        doc: "Invoke action close",
+        name: close_ediscovery_case,
+        path: "/security/cases/ediscoveryCases/{{id}}/microsoft.graph.security.close",
+        params: ediscovery_case_id
+    );
+    post!(
+        doc: "Invoke action reopen",
+        name: reopen_ediscovery_case,
+        path: "/security/cases/ediscoveryCases/{{id}}/microsoft.graph.security.reopen",
+        params: ediscovery_case_id
+    );
+    get!(
+        doc: "List custodians",
+        name: list_case_custodians,
+        path: "/security/cases/ediscoveryCases/{{id}}/custodians",
+        params: ediscovery_case_id
+    );
+    post!(
+        doc: "Create ediscoveryCustodian",
+        name: create_case_custodians,
+        path: "/security/cases/ediscoveryCases/{{id}}/custodians",
+        body: true,
+        params: ediscovery_case_id
+    );
+    get!(
+        doc: "Get ediscoveryCustodian",
+        name: get_case_custodians,
+        path: "/security/cases/ediscoveryCases/{{id}}/custodians/{{id2}}",
+        params: ediscovery_case_id, ediscovery_custodian_id
+    );
+    post!(
+        doc: "Invoke action applyHold",
+        name: apply_custodian_hold,
+        path: "/security/cases/ediscoveryCases/{{id}}/custodians/{{id2}}/microsoft.graph.security.applyHold",
+        params: ediscovery_case_id, ediscovery_custodian_id
+    );
+    post!(
+        doc: "Invoke action removeHold",
+        name: remove_custodian_hold,
+        path: "/security/cases/ediscoveryCases/{{id}}/custodians/{{id2}}/microsoft.graph.security.removeHold",
+        params: ediscovery_case_id, ediscovery_custodian_id
+    );
+    post!(
+        doc: "Invoke action release",
+        name: release_custodian,
+        path: "/security/cases/ediscoveryCases/{{id}}/custodians/{{id2}}/microsoft.graph.security.release",
+        params: ediscovery_case_id, ediscovery_custodian_id
+    );
+    get!(
+        doc: "List operations",
+        name: list_case_operations,
+        path: "/security/cases/ediscoveryCases/{{id}}/operations",
+        params: ediscovery_case_id
+    );
+    get!(
+        doc: "Get caseOperation",
+        name: get_case_operations,
+        path: "/security/cases/ediscoveryCases/{{id}}/operations/{{id2}}",
+        params: ediscovery_case_id, case_operation_id
+    );
+    get!(
+        doc: "List searches",
+        name: list_case_searches,
+        path: "/security/cases/ediscoveryCases/{{id}}/searches",
+        params: ediscovery_case_id
+    );
+    post!(
+        doc: "Create ediscoverySearch",
+        name: create_case_searches,
+        path: "/security/cases/ediscoveryCases/{{id}}/searches",
+        body: true,
+        params: ediscovery_case_id
+    );
+    get!(
+        doc: "Get ediscoverySearch",
+        name: get_case_searches,
+        path: "/security/cases/ediscoveryCases/{{id}}/searches/{{id2}}",
+        params: ediscovery_case_id, ediscovery_search_id
+    );
+    patch!(
+        doc: "Update ediscoverySearch",
+        name: update_case_searches,
+        path: "/security/cases/ediscoveryCases/{{id}}/searches/{{id2}}",
+        body: true,
+        params: ediscovery_case_id, ediscovery_search_id
+    );
+    delete!(
+        doc: "Delete ediscoverySearch",
+        name: delete_case_searches,
+        path: "/security/cases/ediscoveryCases/{{id}}/searches/{{id2}}",
+        params: ediscovery_case_id, ediscovery_search_id
+    );
+    post!(
+        doc: "Invoke action estimateStatistics",
+        name: estimate_search_statistics,
+        path: "/security/cases/ediscoveryCases/{{id}}/searches/{{id2}}/microsoft.graph.security.estimateStatistics",
+        params: ediscovery_case_id, ediscovery_search_id
+    );
+    post!(
+        doc: "Invoke action purgeData",
+        name: purge_search_data,
+        path: "/security/cases/ediscoveryCases/{{id}}/searches/{{id2}}/microsoft.graph.security.purgeData",
+        body: true,
+        params: ediscovery_case_id, ediscovery_search_id
+    );
+    post!(
+        doc: "Invoke action exportReport",
+        name: export_search_report,
+        path: "/security/cases/ediscoveryCases/{{id}}/searches/{{id2}}/microsoft.graph.security.exportReport",
+        body: true,
+        params: ediscovery_case_id, ediscovery_search_id
+    );
+    post!(
+        doc: "Invoke action exportResult",
+        name: export_search_result,
+        path: "/security/cases/ediscoveryCases/{{id}}/searches/{{id2}}/microsoft.graph.security.exportResult",
+        body: true,
+        params: ediscovery_case_id, ediscovery_search_id
+    );
+    get!(
+        doc: "List incidents",
+        name: list_incidents,
+        path: "/security/incidents"
+    );
+    get!(
+        doc: "Get the number of the resource",
+        name: get_incidents_count,
+        path: "/security/incidents/$count"
+    );
+    get!(
+        doc: "Get incident",
+        name: get_incidents,
+        path: "/security/incidents/{{id}}",
+        params: incident_id
+    );
+    patch!(
+        doc: "Update incident",
+        name: update_incidents,
+        path: "/security/incidents/{{id}}",
+        body: true,
+        params: incident_id
+    );
+    get!(
+        doc: "Get alerts from security",
+        name: list_incident_alerts,
+        path: "/security/incidents/{{id}}/alerts",
+        params: incident_id
+    );
+
+    /// List alerts that match the filter. Use [RequestHandler::paging] to stream
+    /// every page of the results.
+    pub fn list_alerts_filtered(&self, filter: &AlertFilter) -> RequestHandler {
+        let request = self.list_alerts_v2();
+        if filter.is_empty() {
+            return request;
+        }
+        request.filter(&[filter.to_string().as_str()])
+    }
+
+    /// List incidents that match the filter with the alerts of each incident expanded.
+    /// Use [RequestHandler::paging] to stream every page of the results.
+    pub fn list_incidents_filtered(&self, filter: &IncidentFilter) -> RequestHandler {
+        let request = self.list_incidents().expand(&["alerts"]);
+        if filter.is_empty() {
+            return request;
+        }
+        request.filter(&[filter.to_string().as_str()])
+    }
+
+    /// Update the status, classification, determination, or owner of an alert.
+    pub fn update_alert<S: AsRef<str>>(&self, alert_id: S, update: &AlertUpdate) -> RequestHandler {
+        self.update_alerts_v2(alert_id, update)
+    }
+
+    /// Update the status, classification, determination, owner, or tags of an incident.
+    pub fn update_incident<S: AsRef<str>>(
+        &self,
+        incident_id: S,
+        update: &IncidentUpdate,
+    ) -> RequestHandler {
+        self.update_incidents(incident_id, update)
+    }
+
+    /// Estimate the number and size of the items matching a search and wait for the
+    /// estimate to complete.
+    ///
+    /// Estimating statistics is a long running operation. The action returns a 202
+    /// Accepted response with the url of a case operation in the Location header. The
+    /// case operation is polled every `poll_interval`, or for the duration of a
+    /// Retry-After header, until it has ended. The statistics such as `indexedItemCount`
+    /// are returned in the `additional_fields` of the operation.
+    ///
+    /// An operation that fails returns [`GraphRsError::AsyncOperationFailed`] and an
+    /// operation that does not end within `timeout` returns
+    /// [`GraphRsError::AsyncOperationTimeout`].
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let operation = client
+    ///     .security()
+    ///     .estimate_search_statistics_and_wait(
+    ///         "CASE_ID",
+    ///         "SEARCH_ID",
+    ///         Duration::from_secs(5),
+    ///         Duration::from_secs(600),
+    ///     )
+    ///     .await?;
+    ///
+    /// println!("{:#?}", operation.additional_fields.get("indexedItemCount"));
+    /// ```
+    pub async fn estimate_search_statistics_and_wait(
+        &self,
+        ediscovery_case_id: impl AsRef<str>,
+        ediscovery_search_id: impl AsRef<str>,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> GraphResult<CaseOperation> {
+        send_and_wait(
+            self.estimate_search_statistics(ediscovery_case_id, ediscovery_search_id),
+            &self.client,
+            &self.resource_config,
+            poll_interval,
+            timeout,
+        )
+        .await
+    }
+
+    /// Export the items matching a search and wait for the export to complete.
+    ///
+    /// See [`SecurityApiClient::estimate_search_statistics_and_wait`]
+    pub async fn export_search_result_and_wait<B: BodyExt>(
+        &self,
+        ediscovery_case_id: impl AsRef<str>,
+        ediscovery_search_id: impl AsRef<str>,
+        body: B,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> GraphResult<CaseOperation> {
+        send_and_wait(
+            self.export_search_result(ediscovery_case_id, ediscovery_search_id, body),
+            &self.client,
+            &self.resource_config,
+            poll_interval,
+            timeout,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::types::CaseOperationStatus;
+
+    #[test]
+    fn alert_filter() {
+        let filter = AlertFilter::new()
+            .severity(AlertSeverity::High)
+            .status(AlertStatus::New)
+            .service_source("microsoftDefenderForEndpoint")
+            .created_after(OffsetDateTime::from_unix_timestamp(1704067200).unwrap());
+
+        assert_eq!(
+            "severity eq 'high' and status eq 'new' \
+             and serviceSource eq 'microsoftDefenderForEndpoint' \
+             and createdDateTime ge 2024-01-01T00:00:00Z",
+            filter.to_string()
+        );
+    }
+
+    #[test]
+    fn case_operation_status() {
+        assert!(CaseOperationStatus::PartiallySucceeded.is_terminal());
+        assert!(!CaseOperationStatus::PartiallySucceeded.is_failure());
+        assert!(CaseOperationStatus::SubmissionFailed.is_failure());
+        assert!(!CaseOperationStatus::Running.is_terminal());
+    }
+
+    #[test]
+    fn incident_filter() {
+        let filter = IncidentFilter::new()
+            .status(IncidentStatus::InProgress)
+            .assigned_to("o'neil@contoso.com");
+
+        assert_eq!(
+            "status eq 'inProgress' and assignedTo eq 'o''neil@contoso.com'",
+            filter.to_string()
+        );
+    }
+}
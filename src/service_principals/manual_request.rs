@@ -0,0 +1,59 @@
+use crate::api_default_imports::*;
+use crate::service_principals::*;
+use crate::types::{AppRoleAssignment, PasswordCredential};
+
+impl ServicePrincipalsApiClient {
+    /// Create the service principal of an application registration in the tenant.
+    pub fn create_for_app_id(&self, app_id: impl AsRef<str>) -> RequestHandler {
+        self.create_service_principal(&serde_json::json!({ "appId": app_id.as_ref() }))
+    }
+}
+
+impl ServicePrincipalsIdApiClient {
+    /// Add a client secret to the service principal.
+    ///
+    /// The returned credential contains the `secret_text` which is only returned
+    /// once and cannot be retrieved later.
+    pub async fn add_client_secret(
+        &self,
+        password_credential: &PasswordCredential,
+    ) -> GraphResult<PasswordCredential> {
+        let response: GraphResponse<PasswordCredential> = self
+            .add_password(&serde_json::json!({ "passwordCredential": password_credential }))
+            .send_json()
+            .await?;
+        Ok(response.into_body())
+    }
+
+    /// Grant an app role of this resource service principal to a user, group, or the
+    /// service principal of a client application.
+    ///
+    /// Granting the app roles of an api, such as the Microsoft Graph service principal,
+    /// to a client service principal is how admin consent is given to application permissions.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// // Grant User.Read.All to the client application.
+    /// client
+    ///     .service_principal(MICROSOFT_GRAPH_SERVICE_PRINCIPAL_ID)
+    ///     .grant_app_role(CLIENT_SERVICE_PRINCIPAL_ID, "df021288-bdef-4463-88db-98f22de89214")
+    ///     .send()
+    ///     .await?;
+    /// ```
+    pub fn grant_app_role(
+        &self,
+        principal_id: impl AsRef<str>,
+        app_role_id: impl AsRef<str>,
+    ) -> RequestHandler {
+        let resource_id = self
+            .resource_config
+            .resource_identity_id
+            .clone()
+            .unwrap_or_default();
+        self.create_app_role_assigned_to(&AppRoleAssignment::new(
+            principal_id,
+            resource_id,
+            app_role_id,
+        ))
+    }
+}
@@ -1,3 +1,4 @@
+mod manual_request;
 mod request;
 mod service_principals_owners;
 
@@ -0,0 +1,125 @@
+use crate::api_default_imports::*;
+use crate::sites::*;
+use crate::types::ListItem;
+
+/// The id that addresses a site by the hostname of the SharePoint tenant and the server
+/// relative path of the site such as `contoso.sharepoint.com:/sites/Engineering:`.
+///
+/// A scheme in the hostname and leading or trailing slashes in either part are removed.
+/// An empty path addresses the root site of the tenant.
+pub(crate) fn site_path_id(hostname: &str, path: &str) -> String {
+    let hostname = hostname
+        .trim()
+        .trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .trim_matches('/');
+    let path = path.trim().trim_matches('/');
+    if path.is_empty() {
+        hostname.to_owned()
+    } else {
+        format!("{hostname}:/{path}:")
+    }
+}
+
+impl SitesApiClient {
+    /// Address the root site of a SharePoint tenant by its hostname such as
+    /// `contoso.sharepoint.com`.
+    pub fn by_hostname(&self, hostname: impl AsRef<str>) -> SitesIdApiClient {
+        self.id(site_path_id(hostname.as_ref(), ""))
+    }
+
+    /// Address a site by the hostname of the SharePoint tenant and the server relative
+    /// path of the site.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let response = client
+    ///     .sites()
+    ///     .by_hostname_path("contoso.sharepoint.com", "/sites/Engineering")
+    ///     .drive()
+    ///     .by_path("Specs/design.docx")
+    ///     .get_items()
+    ///     .send()
+    ///     .await?;
+    /// ```
+    pub fn by_hostname_path(
+        &self,
+        hostname: impl AsRef<str>,
+        path: impl AsRef<str>,
+    ) -> SitesIdApiClient {
+        let site_id = site_path_id(hostname.as_ref(), path.as_ref());
+        let mut site = self.id(site_id.as_str());
+        site.resource_config.set_path_id(site_id);
+        site
+    }
+}
+
+impl SitesItemsApiClient {
+    /// Enumerate the items in a list including the column values of each item.
+    ///
+    /// Deserialize the response into a [`Collection<ListItem<F>>`](crate::types::Collection)
+    /// to map the column values to your own type.
+    pub fn list_items_with_fields(&self) -> RequestHandler {
+        self.list_items().expand(&["fields"])
+    }
+
+    /// Create a new item in a list with the given column values.
+    pub fn create_list_item<F: serde::Serialize>(&self, fields: &F) -> RequestHandler {
+        self.create_items(&ListItem::new(fields))
+    }
+}
+
+impl SitesItemsIdApiClient {
+    /// Get a list item including its column values.
+    ///
+    /// Deserialize the response into a [`ListItem<F>`] to map the column values
+    /// to your own type.
+    pub fn get_list_item_with_fields(&self) -> RequestHandler {
+        self.get_items().expand(&["fields"])
+    }
+
+    /// Update the column values of a list item. Only the columns that are serialized
+    /// are updated.
+    pub fn update_list_item_fields<F: serde::Serialize>(&self, fields: &F) -> RequestHandler {
+        self.update_fields(fields)
+    }
+}
+
+impl SitesItemsVersionsApiClient {
+    /// List the versions of a list item including the column values of each version.
+    ///
+    /// Deserialize the response into a
+    /// [`Collection<ListItemVersion<F>>`](crate::types::ListItemVersion) to map the
+    /// column values to your own type.
+    pub fn list_versions_with_fields(&self) -> RequestHandler {
+        self.list_versions().expand(&["fields"])
+    }
+}
+
+impl SitesItemsVersionsIdApiClient {
+    /// Get a version of a list item including its column values.
+    pub fn get_version_with_fields(&self) -> RequestHandler {
+        self.get_versions().expand(&["fields"])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn site_path_id_normalizes_hostname_and_path() {
+        assert_eq!(
+            "contoso.sharepoint.com:/sites/Engineering:",
+            site_path_id("contoso.sharepoint.com", "/sites/Engineering")
+        );
+        assert_eq!(
+            "contoso.sharepoint.com:/sites/Engineering/Docs:",
+            site_path_id("https://contoso.sharepoint.com/", "sites/Engineering/Docs/")
+        );
+        assert_eq!(
+            "contoso.sharepoint.com",
+            site_path_id("contoso.sharepoint.com", "/")
+        );
+    }
+}
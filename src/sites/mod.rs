@@ -1,3 +1,4 @@
+mod manual_request;
 mod request;
 mod sites_content_types;
 mod sites_items;
@@ -0,0 +1,188 @@
+use crate::subscriptions::ClientStateStore;
+use crate::types::{ChangeNotification, ChangeNotificationCollection};
+use graph_core::crypto::ct_eq;
+use graph_error::{GraphFailure, GraphResult, GraphRsError};
+use std::sync::Arc;
+use url::Url;
+
+/// Get the validationToken from the url of a request sent to a notification url.
+///
+/// When a subscription is created Microsoft Graph sends a request with a validationToken
+/// query parameter to the notification url. The notification url must respond within
+/// 10 seconds with a 200 OK and the decoded token as the `text/plain` body.
+///
+/// # Example
+/// ```
+/// use graph_rs_sdk::subscriptions::validation_token;
+/// use url::Url;
+///
+/// let url = Url::parse("https://webhook.contoso.com/api/notifications?validationToken=Validation%3A%20Testing").unwrap();
+/// assert_eq!(Some("Validation: Testing".to_string()), validation_token(&url));
+/// ```
+pub fn validation_token(url: &Url) -> Option<String> {
+    url.query_pairs()
+        .find(|(key, _)| key == "validationToken")
+        .map(|(_, value)| value.into_owned())
+}
+
+/// Validates that change notifications were sent by Microsoft Graph by comparing the
+/// clientState of each notification to the clientState stored for the subscription.
+///
+/// # Example
+/// ```rust,ignore
+/// let validator = ChangeNotificationValidator::new(manager.client_state_store());
+///
+/// let notifications: ChangeNotificationCollection = serde_json::from_slice(&body)?;
+/// validator.validate_collection(&notifications)?;
+/// ```
+#[derive(Clone)]
+pub struct ChangeNotificationValidator {
+    client_state_store: Arc<dyn ClientStateStore>,
+}
+
+impl ChangeNotificationValidator {
+    pub fn new(client_state_store: Arc<dyn ClientStateStore>) -> ChangeNotificationValidator {
+        ChangeNotificationValidator { client_state_store }
+    }
+
+    /// Returns an error if the clientState of the notification does not match the
+    /// clientState stored for the subscription.
+    pub fn validate(&self, notification: &ChangeNotification) -> GraphResult<()> {
+        let subscription_id = notification.subscription_id.as_deref().unwrap_or_default();
+        let expected = self.client_state_store.get(subscription_id);
+
+        match (expected, notification.client_state.as_ref()) {
+            (Some(expected), Some(client_state)) if ct_eq(&expected, client_state) => Ok(()),
+            _ => Err(GraphFailure::internal(GraphRsError::InvalidClientState {
+                subscription_id: subscription_id.to_owned(),
+            })),
+        }
+    }
+
+    /// Validate every notification in the collection.
+    pub fn validate_collection(
+        &self,
+        collection: &ChangeNotificationCollection,
+    ) -> GraphResult<()> {
+        collection
+            .value
+            .iter()
+            .try_for_each(|notification| self.validate(notification))
+    }
+}
+
+impl std::fmt::Debug for ChangeNotificationValidator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChangeNotificationValidator").finish()
+    }
+}
+
+#[cfg(feature = "openssl")]
+pub use decryption::ResourceDataDecryptor;
+
+#[cfg(feature = "openssl")]
+mod decryption {
+    use crate::types::ChangeNotificationEncryptedContent;
+    use base64::engine::general_purpose::STANDARD;
+    use base64::Engine;
+    use graph_error::{GraphFailure, GraphResult, GraphRsError};
+    use openssl::hash::MessageDigest;
+    use openssl::pkey::{PKey, Private};
+    use openssl::rsa::Padding;
+    use openssl::sign::Signer;
+    use openssl::symm::Cipher;
+    use serde::de::DeserializeOwned;
+
+    fn crypto_error(_: openssl::error::ErrorStack) -> GraphFailure {
+        GraphFailure::CryptoError
+    }
+
+    /// Decrypts the resource data of change notifications for subscriptions that include
+    /// resource data.
+    ///
+    /// The data key is decrypted with the private key of the subscription encryption
+    /// certificate using RSA OAEP. The dataSignature, an HMAC-SHA256 of the data using
+    /// the data key, is validated before the data is decrypted using AES-256-CBC.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let decryptor = ResourceDataDecryptor::from_pem(std::fs::read("private_key.pem")?)?;
+    ///
+    /// for notification in notifications.value.iter() {
+    ///     if let Some(encrypted_content) = notification.encrypted_content.as_ref() {
+    ///         let message: serde_json::Value = decryptor.decrypt(encrypted_content)?;
+    ///     }
+    /// }
+    /// ```
+    pub struct ResourceDataDecryptor {
+        private_key: PKey<Private>,
+    }
+
+    impl ResourceDataDecryptor {
+        pub fn new(private_key: PKey<Private>) -> ResourceDataDecryptor {
+            ResourceDataDecryptor { private_key }
+        }
+
+        /// Create the decryptor from a PEM encoded private key.
+        pub fn from_pem(pem: impl AsRef<[u8]>) -> GraphResult<ResourceDataDecryptor> {
+            let private_key = PKey::private_key_from_pem(pem.as_ref()).map_err(crypto_error)?;
+            Ok(ResourceDataDecryptor::new(private_key))
+        }
+
+        fn decrypt_data_key(&self, data_key: &[u8]) -> GraphResult<Vec<u8>> {
+            let rsa = self.private_key.rsa().map_err(crypto_error)?;
+            let mut key = vec![0; rsa.size() as usize];
+            let len = rsa
+                .private_decrypt(data_key, &mut key, Padding::PKCS1_OAEP)
+                .map_err(crypto_error)?;
+            key.truncate(len);
+            Ok(key)
+        }
+
+        /// Decrypt the resource data and return the raw json bytes.
+        pub fn decrypt_to_vec(
+            &self,
+            encrypted_content: &ChangeNotificationEncryptedContent,
+        ) -> GraphResult<Vec<u8>> {
+            let data = STANDARD.decode(encrypted_content.data.as_bytes())?;
+            let data_key = STANDARD.decode(encrypted_content.data_key.as_bytes())?;
+            let data_signature = STANDARD.decode(encrypted_content.data_signature.as_bytes())?;
+            let key = self.decrypt_data_key(&data_key)?;
+
+            let hmac_key = PKey::hmac(&key).map_err(crypto_error)?;
+            let mut signer =
+                Signer::new(MessageDigest::sha256(), &hmac_key).map_err(crypto_error)?;
+            signer.update(&data).map_err(crypto_error)?;
+            let signature = signer.sign_to_vec().map_err(crypto_error)?;
+
+            if signature.len() != data_signature.len()
+                || !openssl::memcmp::eq(&signature, &data_signature)
+            {
+                return Err(GraphFailure::internal(GraphRsError::InvalidDataSignature));
+            }
+
+            if key.len() != Cipher::aes_256_cbc().key_len() {
+                return Err(GraphFailure::CryptoError);
+            }
+
+            // The initialization vector is the first 16 bytes of the symmetric key.
+            openssl::symm::decrypt(Cipher::aes_256_cbc(), &key, Some(&key[..16]), &data)
+                .map_err(crypto_error)
+        }
+
+        /// Decrypt the resource data and deserialize it.
+        pub fn decrypt<T: DeserializeOwned>(
+            &self,
+            encrypted_content: &ChangeNotificationEncryptedContent,
+        ) -> GraphResult<T> {
+            let data = self.decrypt_to_vec(encrypted_content)?;
+            Ok(serde_json::from_slice(&data)?)
+        }
+    }
+
+    impl std::fmt::Debug for ResourceDataDecryptor {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("ResourceDataDecryptor").finish()
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use crate::subscriptions::ChangeNotificationValidator;
+use crate::types::{ChangeNotification, ChangeNotificationCollection};
+use graph_error::GraphResult;
+
+/// Build the notificationUrl of a subscription that delivers change notifications to
+/// Azure Event Hubs instead of a webhook.
+///
+/// See [Receive change notifications through Azure Event Hubs](https://learn.microsoft.com/en-us/graph/change-notifications-delivery-event-hubs)
+///
+/// # Example
+/// ```
+/// use graph_rs_sdk::subscriptions::EventHubNotificationUrl;
+/// use graph_rs_sdk::types::Subscription;
+///
+/// let notification_url = EventHubNotificationUrl::managed_identity(
+///     "contoso-namespace",
+///     "graph-notifications",
+///     "contoso.onmicrosoft.com",
+/// );
+/// assert_eq!(
+///     "EventHub:https://contoso-namespace.servicebus.windows.net/eventhubname/graph-notifications?tenantId=contoso.onmicrosoft.com",
+///     notification_url
+/// );
+///
+/// let subscription = Subscription::new("users", "updated", notification_url);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct EventHubNotificationUrl;
+
+impl EventHubNotificationUrl {
+    /// Notification url for an Event Hub that Microsoft Graph sends to using the
+    /// Microsoft Graph Change Tracking application's managed identity.
+    pub fn managed_identity(
+        namespace: impl AsRef<str>,
+        event_hub_name: impl AsRef<str>,
+        tenant_id: impl AsRef<str>,
+    ) -> String {
+        format!(
+            "EventHub:https://{}.servicebus.windows.net/eventhubname/{}?tenantId={}",
+            namespace.as_ref(),
+            event_hub_name.as_ref(),
+            tenant_id.as_ref()
+        )
+    }
+
+    /// Notification url for an Event Hub whose connection string is kept as a secret
+    /// in Azure Key Vault.
+    pub fn key_vault(
+        key_vault_name: impl AsRef<str>,
+        secret_name: impl AsRef<str>,
+        tenant_id: impl AsRef<str>,
+    ) -> String {
+        format!(
+            "EventHub:https://{}.vault.azure.net/secrets/{}?tenantId={}",
+            key_vault_name.as_ref(),
+            secret_name.as_ref(),
+            tenant_id.as_ref()
+        )
+    }
+}
+
+/// Deserializes the change notifications in the body of Azure Event Hubs messages.
+///
+/// Event Hubs messages sent by Microsoft Graph have the same body as a webhook
+/// notification request. Use the adaptor with the Event Hubs consumer of your choice
+/// to get the notifications as [ChangeNotification]. When a validator is set the
+/// clientState of each notification is validated.
+///
+/// # Example
+/// ```rust,ignore
+/// let adaptor = EventHubNotificationAdaptor::new()
+///     .with_validator(ChangeNotificationValidator::new(manager.client_state_store()));
+///
+/// // event is a message received from the Event Hub.
+/// for notification in adaptor.notifications(event.body())? {
+///     println!("{:#?}", notification.resource);
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct EventHubNotificationAdaptor {
+    validator: Option<ChangeNotificationValidator>,
+}
+
+impl EventHubNotificationAdaptor {
+    pub fn new() -> EventHubNotificationAdaptor {
+        EventHubNotificationAdaptor::default()
+    }
+
+    pub fn with_validator(
+        mut self,
+        validator: ChangeNotificationValidator,
+    ) -> EventHubNotificationAdaptor {
+        self.validator = Some(validator);
+        self
+    }
+
+    /// Deserialize the notification collection of an Event Hubs message body.
+    pub fn collection(&self, body: impl AsRef<[u8]>) -> GraphResult<ChangeNotificationCollection> {
+        let collection: ChangeNotificationCollection = serde_json::from_slice(body.as_ref())?;
+        if let Some(validator) = self.validator.as_ref() {
+            validator.validate_collection(&collection)?;
+        }
+        Ok(collection)
+    }
+
+    /// Deserialize the notifications of an Event Hubs message body.
+    pub fn notifications(&self, body: impl AsRef<[u8]>) -> GraphResult<Vec<ChangeNotification>> {
+        Ok(self.collection(body)?.value)
+    }
+}
@@ -1,3 +1,9 @@
+mod change_notification_validation;
+mod event_hub;
 mod request;
+mod subscription_manager;
 
+pub use change_notification_validation::*;
+pub use event_hub::*;
 pub use request::*;
+pub use subscription_manager::*;
@@ -0,0 +1,421 @@
+use crate::client::Graph;
+use crate::types::Subscription;
+use graph_error::{GraphFailure, GraphResult};
+use graph_http::traits::ResponseExt;
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver};
+use tokio::task::JoinHandle;
+
+/// Storage for the clientState secret of each subscription.
+///
+/// The clientState is sent by Microsoft Graph with every change notification of the
+/// subscription and must be compared to the stored value to verify the notification.
+/// Implement this trait to keep the client states in storage shared by the instances
+/// of your application that receive notifications.
+pub trait ClientStateStore: Send + Sync {
+    fn insert(&self, subscription_id: &str, client_state: &str);
+
+    fn get(&self, subscription_id: &str) -> Option<String>;
+
+    fn remove(&self, subscription_id: &str);
+}
+
+/// The default [ClientStateStore] which keeps client states in memory.
+#[derive(Debug, Default)]
+pub struct InMemoryClientStateStore {
+    store: RwLock<HashMap<String, String>>,
+}
+
+impl InMemoryClientStateStore {
+    pub fn new() -> InMemoryClientStateStore {
+        InMemoryClientStateStore::default()
+    }
+}
+
+impl ClientStateStore for InMemoryClientStateStore {
+    fn insert(&self, subscription_id: &str, client_state: &str) {
+        let mut store = self.store.write().unwrap_or_else(|err| err.into_inner());
+        store.insert(subscription_id.to_owned(), client_state.to_owned());
+    }
+
+    fn get(&self, subscription_id: &str) -> Option<String> {
+        let store = self.store.read().unwrap_or_else(|err| err.into_inner());
+        store.get(subscription_id).cloned()
+    }
+
+    fn remove(&self, subscription_id: &str) {
+        let mut store = self.store.write().unwrap_or_else(|err| err.into_inner());
+        store.remove(subscription_id);
+    }
+}
+
+type Expirations = Arc<Mutex<HashMap<String, OffsetDateTime>>>;
+
+/// A subscription that the background renewal task of a [SubscriptionManager] failed
+/// to renew. The subscription is retried until it expires.
+#[derive(Debug)]
+pub struct RenewalFailure {
+    pub subscription_id: String,
+    pub error: GraphFailure,
+}
+
+/// Generate a random clientState for a subscription.
+pub fn generate_client_state() -> String {
+    uuid::Uuid::new_v4().simple().to_string()
+}
+
+fn format_expiration(expiration: OffsetDateTime) -> GraphResult<String> {
+    expiration
+        .format(&Rfc3339)
+        .map_err(|_| GraphFailure::invalid("expirationDateTime"))
+}
+
+fn parse_expiration(expiration: &str) -> GraphResult<OffsetDateTime> {
+    OffsetDateTime::parse(expiration, &Rfc3339)
+        .map_err(|_| GraphFailure::invalid("expirationDateTime"))
+}
+
+async fn json_or_error<T: DeserializeOwned>(response: reqwest::Response) -> GraphResult<T> {
+    Ok(response.error_for_graph_status().await?.json().await?)
+}
+
+async fn renew_subscription(
+    client: &Graph,
+    expirations: &Expirations,
+    subscription_id: &str,
+    lifetime: Duration,
+) -> GraphResult<Subscription> {
+    let expiration = format_expiration(OffsetDateTime::now_utc() + lifetime)?;
+    let response = client
+        .subscription(subscription_id)
+        .update_subscription(&serde_json::json!({
+            "expirationDateTime": expiration
+        }))
+        .send()
+        .await?;
+
+    // The subscription was deleted or has already expired and can no longer be renewed.
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        expirations
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .remove(subscription_id);
+    }
+
+    let subscription: Subscription = json_or_error(response).await?;
+    let expiration = parse_expiration(
+        subscription
+            .expiration_date_time
+            .as_deref()
+            .unwrap_or(expiration.as_str()),
+    )?;
+    expirations
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .insert(subscription_id.to_owned(), expiration);
+    Ok(subscription)
+}
+
+/// Creates change notification subscriptions and keeps them alive.
+///
+/// Subscriptions expire after a maximum lifetime that depends on the resource. The
+/// manager tracks the expirationDateTime of each subscription it creates and, once
+/// [SubscriptionManager::start_renewal] is called, renews them on a background task
+/// before they expire. Subscriptions are deleted by [SubscriptionManager::shutdown].
+/// Any subscriptions left when the manager is dropped are deleted on a background task
+/// of the current tokio runtime if there is one. Errors deleting subscriptions on drop
+/// can not be returned so call [SubscriptionManager::shutdown] to handle them.
+///
+/// A random clientState is generated for subscriptions that do not have one and is kept
+/// in the [ClientStateStore] of the manager.
+///
+/// The default lifetime of a subscription is 45 minutes which is valid for all resources.
+/// Use [SubscriptionManager::with_lifetime] to use a longer lifetime for resources that
+/// allow it. See [subscription lifetime](https://learn.microsoft.com/en-us/graph/api/resources/subscription#subscription-lifetime)
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::*;
+/// use graph_rs_sdk::subscriptions::SubscriptionManager;
+/// use graph_rs_sdk::types::Subscription;
+///
+/// let client = Graph::new("ACCESS_TOKEN");
+/// let mut manager = SubscriptionManager::new(client)
+///     .with_lifetime(Duration::from_secs(60 * 60 * 24));
+///
+/// let subscription = manager
+///     .create(Subscription::new(
+///         "me/mailFolders('Inbox')/messages",
+///         "created",
+///         "https://webhook.contoso.com/api/notifications",
+///     ))
+///     .await?;
+///
+/// let mut renewal_failures = manager.start_renewal();
+/// tokio::spawn(async move {
+///     while let Some(failure) = renewal_failures.recv().await {
+///         eprintln!("{}: {:?}", failure.subscription_id, failure.error);
+///     }
+/// });
+///
+/// // ...
+///
+/// manager.shutdown().await?;
+/// ```
+pub struct SubscriptionManager {
+    client: Graph,
+    lifetime: Duration,
+    renewal_window: Duration,
+    client_state_store: Arc<dyn ClientStateStore>,
+    expirations: Expirations,
+    renewal_task: Option<JoinHandle<()>>,
+}
+
+impl SubscriptionManager {
+    pub fn new(client: Graph) -> SubscriptionManager {
+        SubscriptionManager {
+            client,
+            lifetime: Duration::from_secs(45 * 60),
+            renewal_window: Duration::from_secs(15 * 60),
+            client_state_store: Arc::new(InMemoryClientStateStore::new()),
+            expirations: Default::default(),
+            renewal_task: None,
+        }
+    }
+
+    /// The lifetime of a subscription when it is created or renewed.
+    pub fn with_lifetime(mut self, lifetime: Duration) -> SubscriptionManager {
+        self.lifetime = lifetime;
+        self
+    }
+
+    /// How long before a subscription expires that it is renewed. Default is 15 minutes.
+    pub fn with_renewal_window(mut self, renewal_window: Duration) -> SubscriptionManager {
+        self.renewal_window = renewal_window;
+        self
+    }
+
+    pub fn with_client_state_store<T: ClientStateStore + 'static>(
+        mut self,
+        client_state_store: T,
+    ) -> SubscriptionManager {
+        self.client_state_store = Arc::new(client_state_store);
+        self
+    }
+
+    pub fn client_state_store(&self) -> Arc<dyn ClientStateStore> {
+        self.client_state_store.clone()
+    }
+
+    /// The expiration of a subscription tracked by the manager.
+    pub fn expiration(&self, subscription_id: &str) -> Option<OffsetDateTime> {
+        self.expirations
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .get(subscription_id)
+            .cloned()
+    }
+
+    /// The ids of the subscriptions tracked by the manager.
+    pub fn subscription_ids(&self) -> Vec<String> {
+        self.expirations
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .keys()
+            .cloned()
+            .collect()
+    }
+
+    /// Create a subscription and track it for renewal.
+    ///
+    /// A clientState is generated if the subscription does not have one and the
+    /// expirationDateTime is set using the lifetime of the manager if not set.
+    pub async fn create(&self, mut subscription: Subscription) -> GraphResult<Subscription> {
+        if subscription.client_state.is_none() {
+            subscription.client_state = Some(generate_client_state());
+        }
+
+        if subscription.expiration_date_time.is_none() {
+            subscription.expiration_date_time = Some(format_expiration(
+                OffsetDateTime::now_utc() + self.lifetime,
+            )?);
+        }
+
+        let response = self
+            .client
+            .subscriptions()
+            .create_subscription(&subscription)
+            .send()
+            .await?;
+        let created: Subscription = json_or_error(response).await?;
+
+        let id = created
+            .id
+            .clone()
+            .ok_or_else(|| GraphFailure::invalid("subscription id"))?;
+        let expiration = created
+            .expiration_date_time
+            .as_deref()
+            .or(subscription.expiration_date_time.as_deref())
+            .map(parse_expiration)
+            .transpose()?;
+
+        if let Some(client_state) = subscription.client_state.as_ref() {
+            self.client_state_store.insert(id.as_str(), client_state);
+        }
+
+        if let Some(expiration) = expiration {
+            self.expirations
+                .lock()
+                .unwrap_or_else(|err| err.into_inner())
+                .insert(id, expiration);
+        }
+
+        Ok(created)
+    }
+
+    /// Renew a subscription using the lifetime of the manager.
+    pub async fn renew(&self, subscription_id: &str) -> GraphResult<Subscription> {
+        renew_subscription(
+            &self.client,
+            &self.expirations,
+            subscription_id,
+            self.lifetime,
+        )
+        .await
+    }
+
+    /// Delete a subscription and stop tracking it.
+    ///
+    /// A subscription that fails to delete is still tracked and renewed.
+    pub async fn delete(&self, subscription_id: &str) -> GraphResult<()> {
+        let response = self
+            .client
+            .subscription(subscription_id)
+            .delete_subscription()
+            .send()
+            .await?;
+
+        if response.status() != reqwest::StatusCode::NOT_FOUND {
+            response.error_for_graph_status().await?;
+        }
+
+        self.expirations
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .remove(subscription_id);
+        self.client_state_store.remove(subscription_id);
+        Ok(())
+    }
+
+    /// Start renewing subscriptions on a background task before they expire.
+    ///
+    /// Must be called from within a tokio runtime. Subscriptions that fail to renew
+    /// are retried until they expire and each failure is sent to the returned receiver.
+    /// Calling this method again restarts the background task and only the receiver
+    /// returned by the last call is sent failures.
+    pub fn start_renewal(&mut self) -> UnboundedReceiver<RenewalFailure> {
+        if let Some(renewal_task) = self.renewal_task.take() {
+            renewal_task.abort();
+        }
+
+        let (sender, receiver) = unbounded_channel();
+        let client = self.client.clone();
+        let expirations = self.expirations.clone();
+        let lifetime = self.lifetime;
+        let renewal_window = self.renewal_window;
+        let interval = (renewal_window / 4).max(Duration::from_secs(1));
+
+        self.renewal_task = Some(tokio::spawn(async move {
+            loop {
+                let renew_before = OffsetDateTime::now_utc() + renewal_window;
+                let due: Vec<String> = expirations
+                    .lock()
+                    .unwrap_or_else(|err| err.into_inner())
+                    .iter()
+                    .filter(|(_, expiration)| **expiration <= renew_before)
+                    .map(|(id, _)| id.clone())
+                    .collect();
+
+                for id in due {
+                    if let Err(error) =
+                        renew_subscription(&client, &expirations, id.as_str(), lifetime).await
+                    {
+                        // The receiver may have been dropped by a caller that does not
+                        // handle renewal failures.
+                        let _ = sender.send(RenewalFailure {
+                            subscription_id: id,
+                            error,
+                        });
+                    }
+                }
+
+                tokio::time::sleep(interval).await;
+            }
+        }));
+        receiver
+    }
+
+    /// Stop renewing subscriptions and delete all subscriptions tracked by the manager.
+    ///
+    /// Every subscription is deleted even if deleting another subscription fails. The
+    /// first error is returned.
+    pub async fn shutdown(mut self) -> GraphResult<()> {
+        if let Some(renewal_task) = self.renewal_task.take() {
+            renewal_task.abort();
+        }
+
+        let mut result = Ok(());
+        for id in self.subscription_ids() {
+            let deleted = self.delete(id.as_str()).await;
+            if result.is_ok() {
+                result = deleted;
+            }
+        }
+        result
+    }
+}
+
+impl Debug for SubscriptionManager {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SubscriptionManager")
+            .field("lifetime", &self.lifetime)
+            .field("renewal_window", &self.renewal_window)
+            .field("subscriptions", &self.subscription_ids())
+            .finish()
+    }
+}
+
+impl Drop for SubscriptionManager {
+    fn drop(&mut self) {
+        if let Some(renewal_task) = self.renewal_task.take() {
+            renewal_task.abort();
+        }
+
+        let ids = self.subscription_ids();
+        if ids.is_empty() {
+            return;
+        }
+
+        if let Ok(handle) = tokio::runtime::Handle::try_current() {
+            let client = self.client.clone();
+            let client_state_store = self.client_state_store.clone();
+            handle.spawn(async move {
+                for id in ids {
+                    client_state_store.remove(id.as_str());
+                    let _ = client
+                        .subscription(id.as_str())
+                        .delete_subscription()
+                        .send()
+                        .await;
+                }
+            });
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use crate::api_default_imports::*;
+use crate::chats::*;
+use crate::teams::*;
+use crate::types::{ChatMessage, TeamsAsyncOperation};
+use crate::users::ChannelsIdApiClient;
+use graph_http::traits::ResponseExt;
+use std::time::Duration;
+
+/// Send a team action that returns 202 Accepted and wait for the Teams operation
+/// given in the Location header to end.
+async fn send_and_wait(
+    action: RequestHandler,
+    client: &Client,
+    resource_config: &ResourceConfig,
+    poll_interval: Duration,
+    timeout: Duration,
+) -> GraphResult<TeamsAsyncOperation> {
+    let response = action.send().await?;
+    let response = response.error_for_graph_status().await?;
+
+    AsyncOperationMonitor::from_response(client, &resource_config.url, &response)?
+        .poll_interval(poll_interval)
+        .timeout(timeout)
+        .wait()
+        .await
+}
+
+impl TeamsIdApiClient {
+    /// Archive the team and wait for the archive operation to complete.
+    ///
+    /// Archiving is a long running operation. The archive request returns a 202 Accepted
+    /// response with the url of a Teams operation in the Location header which is polled
+    /// every `poll_interval`, or for the duration of a Retry-After header, until the
+    /// operation has ended. When `set_spo_site_read_only_for_members` is true the
+    /// SharePoint site of the team is made read only for members.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let operation = client
+    ///     .team("TEAM_ID")
+    ///     .archive_and_wait(false, Duration::from_secs(5), Duration::from_secs(300))
+    ///     .await?;
+    /// ```
+    pub async fn archive_and_wait(
+        &self,
+        set_spo_site_read_only_for_members: bool,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> GraphResult<TeamsAsyncOperation> {
+        let body = serde_json::json!({
+            "shouldSetSpoSiteReadOnlyForMembers": set_spo_site_read_only_for_members
+        });
+        send_and_wait(
+            self.archive(&body),
+            &self.client,
+            &self.resource_config,
+            poll_interval,
+            timeout,
+        )
+        .await
+    }
+
+    /// Restore an archived team and wait for the unarchive operation to complete.
+    ///
+    /// See [`TeamsIdApiClient::archive_and_wait`]
+    pub async fn unarchive_and_wait(
+        &self,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> GraphResult<TeamsAsyncOperation> {
+        send_and_wait(
+            self.unarchive(),
+            &self.client,
+            &self.resource_config,
+            poll_interval,
+            timeout,
+        )
+        .await
+    }
+}
+
+/// List the root messages of a channel with the replies of each message expanded.
+fn list_messages_with_replies(messages: ChatsMessagesApiClient) -> RequestHandler {
+    messages.list_messages().expand(&["replies"])
+}
+
+impl ChannelsIdApiClient {
+    /// Send a new message in the channel.
+    ///
+    /// Mentions and hosted content, such as inline images, are sent in the same request
+    /// as the message. See [ChatMessage] for building the message body.
+    pub fn send_message(&self, message: &ChatMessage) -> RequestHandler {
+        self.messages().send_chat_message(message)
+    }
+
+    /// Reply to a message of the channel in its thread.
+    pub fn reply_to_message(
+        &self,
+        message_id: impl AsRef<str>,
+        message: &ChatMessage,
+    ) -> RequestHandler {
+        self.message(message_id.as_ref()).send_reply(message)
+    }
+
+    /// List the messages of the channel with the replies of each message. Channel
+    /// messages are only returned with replies when the replies are expanded.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let deque = client
+    ///     .team("TEAM_ID")
+    ///     .channel("CHANNEL_ID")
+    ///     .list_messages_with_replies()
+    ///     .paging()
+    ///     .throttle_retries(3)
+    ///     .json::<Collection<ChatMessage>>()
+    ///     .await?;
+    /// ```
+    pub fn list_messages_with_replies(&self) -> RequestHandler {
+        list_messages_with_replies(self.messages())
+    }
+
+    /// Get the messages of the channel that were added, updated, or deleted since the
+    /// delta token of a previous delta request was issued.
+    pub fn messages_delta_from_token(&self, delta_token: impl AsRef<str>) -> RequestHandler {
+        self.messages().delta_from_token(delta_token)
+    }
+}
+
+impl PrimaryChannelApiClient {
+    /// Send a new message in the general channel of the team.
+    ///
+    /// See [`ChannelsIdApiClient::send_message`]
+    pub fn send_message(&self, message: &ChatMessage) -> RequestHandler {
+        self.messages().send_chat_message(message)
+    }
+
+    /// Reply to a message of the general channel in its thread.
+    pub fn reply_to_message(
+        &self,
+        message_id: impl AsRef<str>,
+        message: &ChatMessage,
+    ) -> RequestHandler {
+        self.message(message_id.as_ref()).send_reply(message)
+    }
+
+    /// List the messages of the general channel with the replies of each message.
+    ///
+    /// See [`ChannelsIdApiClient::list_messages_with_replies`]
+    pub fn list_messages_with_replies(&self) -> RequestHandler {
+        list_messages_with_replies(self.messages())
+    }
+
+    /// Get the messages of the general channel that were added, updated, or deleted
+    /// since the delta token of a previous delta request was issued.
+    pub fn messages_delta_from_token(&self, delta_token: impl AsRef<str>) -> RequestHandler {
+        self.messages().delta_from_token(delta_token)
+    }
+}
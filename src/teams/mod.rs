@@ -1,3 +1,4 @@
+mod manual_request;
 mod primary_channel;
 mod request;
 mod schedule;
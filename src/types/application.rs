@@ -0,0 +1,245 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Represents an application registration in Microsoft Entra ID.
+///
+/// See [application resource type](https://learn.microsoft.com/en-us/graph/api/resources/application)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Application {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sign_in_audience: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web: Option<WebApplication>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spa: Option<RedirectUris>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_client: Option<RedirectUris>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub key_credentials: Vec<KeyCredential>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub password_credentials: Vec<PasswordCredential>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl Application {
+    pub fn new(display_name: impl AsRef<str>) -> Application {
+        Application {
+            display_name: Some(display_name.as_ref().to_owned()),
+            ..Default::default()
+        }
+    }
+
+    /// The Microsoft accounts that are supported by the application such as
+    /// `AzureADMyOrg`, `AzureADMultipleOrgs`, or `AzureADandPersonalMicrosoftAccount`.
+    pub fn with_sign_in_audience(mut self, sign_in_audience: impl AsRef<str>) -> Application {
+        self.sign_in_audience = Some(sign_in_audience.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_web_redirect_uris<T: ToString, I: IntoIterator<Item = T>>(
+        mut self,
+        redirect_uris: I,
+    ) -> Application {
+        self.web = Some(WebApplication {
+            redirect_uris: redirect_uris
+                .into_iter()
+                .map(|uri| uri.to_string())
+                .collect(),
+            ..Default::default()
+        });
+        self
+    }
+
+    pub fn with_spa_redirect_uris<T: ToString, I: IntoIterator<Item = T>>(
+        mut self,
+        redirect_uris: I,
+    ) -> Application {
+        self.spa = Some(RedirectUris::new(redirect_uris));
+        self
+    }
+
+    pub fn with_public_client_redirect_uris<T: ToString, I: IntoIterator<Item = T>>(
+        mut self,
+        redirect_uris: I,
+    ) -> Application {
+        self.public_client = Some(RedirectUris::new(redirect_uris));
+        self
+    }
+}
+
+/// Settings of an application that signs users in from a web server.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebApplication {
+    #[serde(default)]
+    pub redirect_uris: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub home_page_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logout_url: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+/// The redirect uris of a single page or public client application.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedirectUris {
+    #[serde(default)]
+    pub redirect_uris: Vec<String>,
+}
+
+impl RedirectUris {
+    pub fn new<T: ToString, I: IntoIterator<Item = T>>(redirect_uris: I) -> RedirectUris {
+        RedirectUris {
+            redirect_uris: redirect_uris
+                .into_iter()
+                .map(|uri| uri.to_string())
+                .collect(),
+        }
+    }
+}
+
+/// A client secret of an application or service principal.
+///
+/// The `secret_text` is only returned in the response of addPassword and cannot
+/// be retrieved later.
+///
+/// See [passwordCredential resource type](https://learn.microsoft.com/en-us/graph/api/resources/passwordcredential)
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PasswordCredential {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date_time: Option<String>,
+}
+
+impl PasswordCredential {
+    pub fn new(display_name: impl AsRef<str>) -> PasswordCredential {
+        PasswordCredential {
+            display_name: Some(display_name.as_ref().to_owned()),
+            ..Default::default()
+        }
+    }
+
+    /// The date and time the secret expires in ISO 8601 format. Default is two years.
+    pub fn with_end_date_time(mut self, end_date_time: impl AsRef<str>) -> PasswordCredential {
+        self.end_date_time = Some(end_date_time.as_ref().to_owned());
+        self
+    }
+}
+
+impl std::fmt::Debug for PasswordCredential {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PasswordCredential")
+            .field("key_id", &self.key_id)
+            .field("display_name", &self.display_name)
+            .field("hint", &self.hint)
+            .field("secret_text", &"[REDACTED]")
+            .field("start_date_time", &self.start_date_time)
+            .field("end_date_time", &self.end_date_time)
+            .finish()
+    }
+}
+
+/// A certificate of an application or service principal.
+///
+/// See [keyCredential resource type](https://learn.microsoft.com/en-us/graph/api/resources/keycredential)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyCredential {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_key_identifier: Option<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub key_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date_time: Option<String>,
+}
+
+impl KeyCredential {
+    /// The public key of a X.509 certificate in DER format which is used to verify
+    /// client assertions signed by the application.
+    pub fn asymmetric_x509_cert(
+        display_name: impl AsRef<str>,
+        certificate_der: impl AsRef<[u8]>,
+    ) -> KeyCredential {
+        KeyCredential {
+            display_name: Some(display_name.as_ref().to_owned()),
+            key_type: Some("AsymmetricX509Cert".to_owned()),
+            usage: Some("Verify".to_owned()),
+            key: Some(STANDARD.encode(certificate_der.as_ref())),
+            ..Default::default()
+        }
+    }
+}
+
+/// An app role granted to a user, group, or service principal.
+///
+/// See [appRoleAssignment resource type](https://learn.microsoft.com/en-us/graph/api/resources/approleassignment)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppRoleAssignment {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub principal_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub principal_display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub principal_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_role_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date_time: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl AppRoleAssignment {
+    pub fn new(
+        principal_id: impl AsRef<str>,
+        resource_id: impl AsRef<str>,
+        app_role_id: impl AsRef<str>,
+    ) -> AppRoleAssignment {
+        AppRoleAssignment {
+            principal_id: Some(principal_id.as_ref().to_owned()),
+            resource_id: Some(resource_id.as_ref().to_owned()),
+            app_role_id: Some(app_role_id.as_ref().to_owned()),
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use graph_http::api_impl::{AsyncOperationResource, AsyncOperationState};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use url::Url;
+
+/// The status of a long running operation.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AsyncOperationStatus {
+    NotStarted,
+    InProgress,
+    Completed,
+    Updating,
+    Failed,
+    DeletePending,
+    DeleteFailed,
+    Waiting,
+    #[serde(other)]
+    Unknown,
+}
+
+impl AsyncOperationStatus {
+    /// Returns true if the operation will not make any more progress.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            AsyncOperationStatus::Completed
+                | AsyncOperationStatus::Failed
+                | AsyncOperationStatus::DeleteFailed
+        )
+    }
+}
+
+/// The status of a long running operation returned by a monitor url, such as
+/// the monitor url returned in the Location header of a drive item copy.
+///
+/// See [Working with long running actions](https://learn.microsoft.com/en-us/graph/long-running-actions-overview)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AsyncJobStatus {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub percentage_complete: Option<f64>,
+    /// The id of the resource created by the operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<AsyncOperationStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<Value>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl AsyncOperationResource for AsyncJobStatus {
+    fn operation_state(&self) -> AsyncOperationState {
+        match self.status {
+            Some(AsyncOperationStatus::Completed) => AsyncOperationState::Completed,
+            Some(status @ (AsyncOperationStatus::Failed | AsyncOperationStatus::DeleteFailed)) => {
+                AsyncOperationState::Failed {
+                    status: format!("{status:?}"),
+                    message: self
+                        .status_description
+                        .clone()
+                        .or_else(|| self.error.as_ref().map(|error| error.to_string()))
+                        .unwrap_or_default(),
+                }
+            }
+            _ => AsyncOperationState::Running,
+        }
+    }
+
+    /// The monitor of a drive item copy may redirect to the new item once the copy
+    /// has completed.
+    fn from_redirect(location: &Url) -> Option<Self> {
+        let resource_id = location.path_segments()?.last()?.to_string();
+        Some(AsyncJobStatus {
+            status: Some(AsyncOperationStatus::Completed),
+            resource_id: Some(resource_id),
+            ..Default::default()
+        })
+    }
+}
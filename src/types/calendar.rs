@@ -0,0 +1,200 @@
+use crate::types::{Attendee, DateTimeTimeZone, Location};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// A time period with a start and end.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TimeSlot {
+    pub start: DateTimeTimeZone,
+    pub end: DateTimeTimeZone,
+}
+
+impl TimeSlot {
+    pub fn new(start: DateTimeTimeZone, end: DateTimeTimeZone) -> TimeSlot {
+        TimeSlot { start, end }
+    }
+}
+
+/// Request body for the accept, decline, and tentativelyAccept actions on an event.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_response: Option<bool>,
+    /// Only valid for decline and tentativelyAccept when the organizer allows new
+    /// time proposals.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proposed_new_time: Option<TimeSlot>,
+}
+
+impl EventResponse {
+    pub fn new(send_response: bool) -> EventResponse {
+        EventResponse {
+            comment: None,
+            send_response: Some(send_response),
+            proposed_new_time: None,
+        }
+    }
+
+    pub fn with_comment(mut self, comment: impl AsRef<str>) -> EventResponse {
+        self.comment = Some(comment.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_proposed_new_time(mut self, proposed_new_time: TimeSlot) -> EventResponse {
+        self.proposed_new_time = Some(proposed_new_time);
+        self
+    }
+}
+
+/// The nature of the activity a meeting time suggestion is for.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ActivityDomain {
+    Unknown,
+    Work,
+    Personal,
+    Unrestricted,
+}
+
+/// Restricts meeting time suggestions to the given time slots.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeConstraint {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_domain: Option<ActivityDomain>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub time_slots: Vec<TimeSlot>,
+}
+
+/// Restricts meeting time suggestions to the given locations.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocationConstraint {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_required: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggest_location: Option<bool>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub locations: Vec<Location>,
+}
+
+/// Request body for the findMeetingTimes action.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::*;
+/// use graph_rs_sdk::types::*;
+///
+/// let client = Graph::new("ACCESS_TOKEN");
+///
+/// let find_meeting_times = FindMeetingTimes::new("PT1H")
+///     .with_attendee(Attendee::new("alexw@contoso.onmicrosoft.com", AttendeeType::Required))
+///     .with_time_slot(TimeSlot::new(
+///         DateTimeTimeZone::new("2024-01-15T09:00:00", "Pacific Standard Time"),
+///         DateTimeTimeZone::new("2024-01-19T17:00:00", "Pacific Standard Time"),
+///     ));
+///
+/// let suggestions: MeetingTimeSuggestionsResult = client.me()
+///     .find_meeting_times(&find_meeting_times)
+///     .send()
+///     .await?
+///     .json()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FindMeetingTimes {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attendees: Vec<Attendee>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location_constraint: Option<LocationConstraint>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_constraint: Option<TimeConstraint>,
+    /// The length of the meeting as an ISO 8601 duration such as `PT1H`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meeting_duration: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_candidates: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_organizer_optional: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_suggestion_reasons: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum_attendee_percentage: Option<f64>,
+}
+
+impl FindMeetingTimes {
+    pub fn new(meeting_duration: impl AsRef<str>) -> FindMeetingTimes {
+        FindMeetingTimes {
+            meeting_duration: Some(meeting_duration.as_ref().to_owned()),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_attendee(mut self, attendee: Attendee) -> FindMeetingTimes {
+        self.attendees.push(attendee);
+        self
+    }
+
+    pub fn with_time_slot(mut self, time_slot: TimeSlot) -> FindMeetingTimes {
+        self.time_constraint
+            .get_or_insert_with(Default::default)
+            .time_slots
+            .push(time_slot);
+        self
+    }
+
+    pub fn with_activity_domain(mut self, activity_domain: ActivityDomain) -> FindMeetingTimes {
+        self.time_constraint
+            .get_or_insert_with(Default::default)
+            .activity_domain = Some(activity_domain);
+        self
+    }
+
+    pub fn with_location_constraint(
+        mut self,
+        location_constraint: LocationConstraint,
+    ) -> FindMeetingTimes {
+        self.location_constraint = Some(location_constraint);
+        self
+    }
+
+    pub fn with_max_candidates(mut self, max_candidates: i32) -> FindMeetingTimes {
+        self.max_candidates = Some(max_candidates);
+        self
+    }
+}
+
+/// A meeting time suggestion returned by the findMeetingTimes action.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MeetingTimeSuggestion {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meeting_time_slot: Option<TimeSlot>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organizer_availability: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggestion_reason: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub locations: Vec<Location>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+/// The response of the findMeetingTimes action.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MeetingTimeSuggestionsResult {
+    #[serde(default)]
+    pub meeting_time_suggestions: Vec<MeetingTimeSuggestion>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub empty_suggestions_reason: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// The encrypted resource data of a change notification for a subscription that
+/// includes resource data.
+///
+/// See [Set up change notifications that include resource data](https://learn.microsoft.com/en-us/graph/change-notifications-with-resource-data)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeNotificationEncryptedContent {
+    /// Base64 encoded resource data encrypted with the symmetric key.
+    pub data: String,
+    /// Base64 encoded symmetric key encrypted with the public key of the subscription
+    /// encryption certificate.
+    pub data_key: String,
+    /// Base64 encoded HMAC-SHA256 hash of the data.
+    pub data_signature: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encryption_certificate_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encryption_certificate_thumbprint: Option<String>,
+}
+
+/// A change notification sent to the notification url of a subscription.
+///
+/// See [changeNotification resource type](https://learn.microsoft.com/en-us/graph/api/resources/changenotification)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeNotification {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_expiration_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encrypted_content: Option<ChangeNotificationEncryptedContent>,
+    /// Set for lifecycle notifications such as `reauthorizationRequired`,
+    /// `subscriptionRemoved`, and `missed`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lifecycle_event: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tenant_id: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+/// The body of a request sent to the notification url of a subscription.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeNotificationCollection {
+    #[serde(default)]
+    pub value: Vec<ChangeNotification>,
+    /// Tokens that are sent with notifications that include resource data.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub validation_tokens: Vec<String>,
+}
@@ -0,0 +1,192 @@
+use crate::types::{Identity, IdentitySet, ItemBody};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// A mention of a user, application, team, or channel in a [ChatMessage].
+///
+/// The body of the message must contain a matching `<at id="{id}">` tag. Use
+/// [ChatMessageMention::at_tag] to get the tag for a mention.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatMessageMention {
+    pub id: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mention_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mentioned: Option<IdentitySet>,
+}
+
+impl ChatMessageMention {
+    /// Mention an Azure AD user.
+    pub fn user(
+        id: i32,
+        mention_text: impl AsRef<str>,
+        user_id: impl AsRef<str>,
+    ) -> ChatMessageMention {
+        let mut user = Identity {
+            id: Some(user_id.as_ref().to_owned()),
+            display_name: Some(mention_text.as_ref().to_owned()),
+            ..Default::default()
+        };
+        user.additional_fields.insert(
+            "userIdentityType".to_string(),
+            Value::String("aadUser".to_string()),
+        );
+
+        ChatMessageMention {
+            id,
+            mention_text: Some(mention_text.as_ref().to_owned()),
+            mentioned: Some(IdentitySet {
+                user: Some(user),
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// The `<at>` tag that must be included in the html body of the message.
+    pub fn at_tag(&self) -> String {
+        format!(
+            "<at id=\"{}\">{}</at>",
+            self.id,
+            self.mention_text.as_deref().unwrap_or_default()
+        )
+    }
+}
+
+/// Hosted content, such as an image, sent inline with a [ChatMessage].
+///
+/// The body of the message references the content using the temporary id. Use
+/// [ChatMessageHostedContent::img_tag] to get the `<img>` tag for the content.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatMessageHostedContent {
+    #[serde(
+        rename = "@microsoft.graph.temporaryId",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub temporary_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Base64 encoded content.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_bytes: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl ChatMessageHostedContent {
+    pub fn new(
+        temporary_id: impl AsRef<str>,
+        content_type: impl AsRef<str>,
+        content: impl AsRef<[u8]>,
+    ) -> ChatMessageHostedContent {
+        ChatMessageHostedContent {
+            temporary_id: Some(temporary_id.as_ref().to_owned()),
+            content_bytes: Some(STANDARD.encode(content.as_ref())),
+            content_type: Some(content_type.as_ref().to_owned()),
+            ..Default::default()
+        }
+    }
+
+    /// The `<img>` tag that references the hosted content in the html body of the message.
+    pub fn img_tag(&self) -> String {
+        format!(
+            "<img src=\"../hostedContents/{}/$value\">",
+            self.temporary_id.as_deref().unwrap_or_default()
+        )
+    }
+}
+
+/// An individual chat message within a channel or chat.
+///
+/// See [chatMessage resource type](https://learn.microsoft.com/en-us/graph/api/resources/chatmessage)
+///
+/// # Example
+/// ```
+/// use graph_rs_sdk::types::{ChatMessage, ChatMessageHostedContent, ChatMessageMention};
+///
+/// let mention = ChatMessageMention::user(0, "Megan Bowen", "8ea0e38b-efb3-4757-924a-5f94061cf8c2");
+/// let image = ChatMessageHostedContent::new("1", "image/png", b"PNG");
+///
+/// let message = ChatMessage::html(format!("Hi {}, take a look {}", mention.at_tag(), image.img_tag()))
+///     .with_mention(mention)
+///     .with_hosted_content(image);
+/// # assert_eq!(message.mentions.len(), 1);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<ItemBody>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<IdentitySet>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub importance: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mentions: Vec<ChatMessageMention>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hosted_contents: Vec<ChatMessageHostedContent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_modified_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_url: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl ChatMessage {
+    pub fn text(content: impl AsRef<str>) -> ChatMessage {
+        ChatMessage {
+            body: Some(ItemBody::text(content)),
+            ..Default::default()
+        }
+    }
+
+    /// Mentions and hosted content require an html body.
+    pub fn html(content: impl AsRef<str>) -> ChatMessage {
+        ChatMessage {
+            body: Some(ItemBody::html(content)),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_subject(mut self, subject: impl AsRef<str>) -> ChatMessage {
+        self.subject = Some(subject.as_ref().to_owned());
+        self
+    }
+
+    /// Set the importance of the message. One of `normal`, `high`, or `urgent`.
+    pub fn with_importance(mut self, importance: impl AsRef<str>) -> ChatMessage {
+        self.importance = Some(importance.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_mention(mut self, mention: ChatMessageMention) -> ChatMessage {
+        self.mentions.push(mention);
+        self
+    }
+
+    pub fn with_hosted_content(mut self, hosted_content: ChatMessageHostedContent) -> ChatMessage {
+        self.hosted_contents.push(hosted_content);
+        self
+    }
+}
@@ -0,0 +1,203 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use time::{OffsetDateTime, PrimitiveDateTime, UtcOffset};
+
+/// Represents an identity of an actor such as a user, device, or application.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Identity {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+/// A keyed collection of [Identity] resources used to represent a set of identities
+/// associated with various events for an item, such as created by or last modified by.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdentitySet {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application: Option<Identity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Identity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<Identity>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+/// Information needed to address a drive item, list item, or site.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ItemReference {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drive_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drive_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub share_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub site_id: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+/// The name and email address of a contact or message recipient.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailAddress {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+}
+
+impl EmailAddress {
+    pub fn new(address: impl AsRef<str>) -> EmailAddress {
+        EmailAddress {
+            name: None,
+            address: Some(address.as_ref().to_owned()),
+        }
+    }
+}
+
+/// Information about the sender or recipient of a message, event, or post.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Recipient {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<EmailAddress>,
+}
+
+impl Recipient {
+    pub fn new(address: impl AsRef<str>) -> Recipient {
+        Recipient {
+            email_address: Some(EmailAddress::new(address)),
+        }
+    }
+}
+
+impl From<&str> for Recipient {
+    fn from(address: &str) -> Self {
+        Recipient::new(address)
+    }
+}
+
+impl From<EmailAddress> for Recipient {
+    fn from(email_address: EmailAddress) -> Self {
+        Recipient {
+            email_address: Some(email_address),
+        }
+    }
+}
+
+/// The type of the content of an [ItemBody].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BodyType {
+    #[default]
+    Text,
+    Html,
+}
+
+/// The body content of a message, event, or post.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ItemBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<BodyType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+}
+
+impl ItemBody {
+    pub fn text(content: impl AsRef<str>) -> ItemBody {
+        ItemBody {
+            content_type: Some(BodyType::Text),
+            content: Some(content.as_ref().to_owned()),
+        }
+    }
+
+    pub fn html(content: impl AsRef<str>) -> ItemBody {
+        ItemBody {
+            content_type: Some(BodyType::Html),
+            content: Some(content.as_ref().to_owned()),
+        }
+    }
+}
+
+/// A date and time together with the time zone it is expressed in.
+///
+/// The `date_time` value is a combined date and time in the format
+/// `yyyy-MM-ddTHH:mm:ss` without an offset, and the `time_zone` is either a
+/// Windows or IANA time zone name such as `Pacific Standard Time` or `UTC`.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DateTimeTimeZone {
+    pub date_time: String,
+    pub time_zone: String,
+}
+
+impl DateTimeTimeZone {
+    pub fn new(date_time: impl AsRef<str>, time_zone: impl AsRef<str>) -> DateTimeTimeZone {
+        DateTimeTimeZone {
+            date_time: date_time.as_ref().to_owned(),
+            time_zone: time_zone.as_ref().to_owned(),
+        }
+    }
+
+    /// The date and time converted to UTC with the time zone set to `UTC`.
+    pub fn utc(date_time: OffsetDateTime) -> DateTimeTimeZone {
+        let date_time = date_time.to_offset(UtcOffset::UTC);
+        DateTimeTimeZone::local(
+            PrimitiveDateTime::new(date_time.date(), date_time.time()),
+            "UTC",
+        )
+    }
+
+    /// A date and time on the wall clock of the time zone, such as 9 AM in
+    /// `Pacific Standard Time`. The time zone is either a Windows or IANA time zone name.
+    pub fn local(date_time: PrimitiveDateTime, time_zone: impl AsRef<str>) -> DateTimeTimeZone {
+        DateTimeTimeZone::new(
+            format!(
+                "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+                date_time.year(),
+                u8::from(date_time.month()),
+                date_time.day(),
+                date_time.hour(),
+                date_time.minute(),
+                date_time.second()
+            ),
+            time_zone,
+        )
+    }
+
+    /// Parse the date and time without the time zone. The fractional seconds that are
+    /// returned by the API, such as in `2024-01-31T09:00:00.0000000`, are ignored.
+    pub fn to_primitive_date_time(&self) -> Option<PrimitiveDateTime> {
+        let format =
+            time::format_description::parse("[year]-[month]-[day]T[hour]:[minute]:[second]")
+                .ok()?;
+        PrimitiveDateTime::parse(self.date_time.get(..19)?, &format).ok()
+    }
+
+    /// Parse the date and time if the time zone is `UTC`. Request responses in UTC,
+    /// rather than the time zone of the mailbox, using the `Prefer: outlook.timezone="UTC"`
+    /// header where the API supports it.
+    pub fn to_utc(&self) -> Option<OffsetDateTime> {
+        if !self.time_zone.eq_ignore_ascii_case("UTC") {
+            return None;
+        }
+        Some(self.to_primitive_date_time()?.assume_utc())
+    }
+}
@@ -0,0 +1,239 @@
+use crate::types::EmailAddress;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// The street address of a contact, user, or location.
+///
+/// Contacts without an address are returned with an empty address object so use
+/// [PhysicalAddress::is_empty] rather than checking for `None` before mapping it.
+///
+/// See [physicalAddress resource type](https://learn.microsoft.com/en-us/graph/api/resources/physicaladdress)
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PhysicalAddress {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub street: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_or_region: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<String>,
+}
+
+impl PhysicalAddress {
+    /// Returns true if none of the parts of the address have a value.
+    pub fn is_empty(&self) -> bool {
+        [
+            &self.street,
+            &self.city,
+            &self.state,
+            &self.country_or_region,
+            &self.postal_code,
+        ]
+        .iter()
+        .all(|part| part.as_deref().unwrap_or_default().is_empty())
+    }
+}
+
+/// A contact in an Outlook contact folder.
+///
+/// See [contact resource type](https://learn.microsoft.com/en-us/graph/api/resources/contact)
+///
+/// # Example
+/// ```
+/// use graph_rs_sdk::types::*;
+///
+/// let contact = Contact::new("Pavel", "Bansky")
+///     .with_email(EmailAddress::new("pavelb@fabrikam.onmicrosoft.com"))
+///     .with_business_phone("+1 732 555 0102")
+///     .with_company_name("Fabrikam")
+///     .with_business_address(PhysicalAddress {
+///         street: Some("123 Main St".into()),
+///         city: Some("Redmond".into()),
+///         ..Default::default()
+///     });
+///
+/// assert_eq!(Some("pavelb@fabrikam.onmicrosoft.com"), contact.primary_email());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Contact {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_folder_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub given_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub middle_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub surname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nick_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub department: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub office_location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub manager: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub email_addresses: Vec<EmailAddress>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub im_addresses: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub business_phones: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub home_phones: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mobile_phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_address: Option<PhysicalAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub home_address: Option<PhysicalAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub other_address: Option<PhysicalAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_home_page: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub birthday: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub personal_notes: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub categories: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_modified_date_time: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl Contact {
+    pub fn new(given_name: impl AsRef<str>, surname: impl AsRef<str>) -> Contact {
+        Contact {
+            given_name: Some(given_name.as_ref().to_owned()),
+            surname: Some(surname.as_ref().to_owned()),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_display_name(mut self, display_name: impl AsRef<str>) -> Contact {
+        self.display_name = Some(display_name.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_email(mut self, email_address: EmailAddress) -> Contact {
+        self.email_addresses.push(email_address);
+        self
+    }
+
+    pub fn with_business_phone(mut self, phone: impl AsRef<str>) -> Contact {
+        self.business_phones.push(phone.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_mobile_phone(mut self, phone: impl AsRef<str>) -> Contact {
+        self.mobile_phone = Some(phone.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_company_name(mut self, company_name: impl AsRef<str>) -> Contact {
+        self.company_name = Some(company_name.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_job_title(mut self, job_title: impl AsRef<str>) -> Contact {
+        self.job_title = Some(job_title.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_business_address(mut self, address: PhysicalAddress) -> Contact {
+        self.business_address = Some(address);
+        self
+    }
+
+    pub fn with_home_address(mut self, address: PhysicalAddress) -> Contact {
+        self.home_address = Some(address);
+        self
+    }
+
+    /// The first email address of the contact.
+    pub fn primary_email(&self) -> Option<&str> {
+        self.email_addresses
+            .iter()
+            .find_map(|email_address| email_address.address.as_deref())
+    }
+
+    /// The business, home, and other addresses of the contact that are not empty.
+    pub fn addresses(&self) -> impl Iterator<Item = &PhysicalAddress> {
+        [
+            &self.business_address,
+            &self.home_address,
+            &self.other_address,
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|address| !address.is_empty())
+    }
+
+    /// Returns true if the contact has been removed in a delta response.
+    pub fn is_removed(&self) -> bool {
+        self.additional_fields.contains_key("@removed")
+    }
+}
+
+/// A folder that contains [Contact] resources and child contact folders.
+///
+/// See [contactFolder resource type](https://learn.microsoft.com/en-us/graph/api/resources/contactfolder)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContactFolder {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_folder_id: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl ContactFolder {
+    pub fn new(display_name: impl AsRef<str>) -> ContactFolder {
+        ContactFolder {
+            display_name: Some(display_name.as_ref().to_owned()),
+            ..Default::default()
+        }
+    }
+
+    /// Returns true if the folder has been removed in a delta response.
+    pub fn is_removed(&self) -> bool {
+        self.additional_fields.contains_key("@removed")
+    }
+}
+
+/// The contacts that changed since a previous delta request, returned by
+/// [`ContactsApiClient::sync_contacts`](crate::users::ContactsApiClient::sync_contacts).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ContactsDelta {
+    /// Contacts that were added or updated.
+    pub changed: Vec<Contact>,
+    /// The ids of contacts that were deleted or moved out of the folder.
+    pub removed: Vec<String>,
+    /// Pass the delta link to the next sync to only get the changes made after this one.
+    pub delta_link: String,
+}
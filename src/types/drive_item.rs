@@ -0,0 +1,198 @@
+use crate::types::{IdentitySet, ItemReference};
+use graph_error::IntegrityError;
+use graph_http::api_impl::ContentHashes;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Hashes of the file's binary content, if available.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Hashes {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crc32_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quick_xor_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sha1_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sha256_hash: Option<String>,
+}
+
+impl Hashes {
+    /// Compare the hashes of content computed locally with the `quickXorHash` and
+    /// `sha256Hash` reported by the drive. See [ContentHashes::verify].
+    pub fn verify(&self, content_hashes: &ContentHashes) -> Result<(), IntegrityError> {
+        content_hashes.verify(self.quick_xor_hash.as_deref(), self.sha256_hash.as_deref())
+    }
+}
+
+/// File metadata for a [DriveItem] that is a file.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct File {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hashes: Option<Hashes>,
+}
+
+/// Folder metadata for a [DriveItem] that is a folder.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Folder {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub child_count: Option<i64>,
+}
+
+/// Indicates that a [DriveItem] has been deleted, such as in a delta response.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Deleted {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+}
+
+/// Properties reported by the device's local file system for a [DriveItem].
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileSystemInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_accessed_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_modified_date_time: Option<String>,
+}
+
+/// Represents a file, folder, or other item stored in a drive.
+///
+/// See [driveItem resource type](https://learn.microsoft.com/en-us/graph/api/resources/driveitem)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DriveItem {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub e_tag: Option<String>,
+    #[serde(rename = "cTag", skip_serializing_if = "Option::is_none")]
+    pub c_tag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_modified_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<IdentitySet>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_modified_by: Option<IdentitySet>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_reference: Option<ItemReference>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<File>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub folder: Option<Folder>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_system_info: Option<FileSystemInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted: Option<Deleted>,
+    #[serde(
+        rename = "@microsoft.graph.downloadUrl",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub download_url: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl DriveItem {
+    pub fn is_file(&self) -> bool {
+        self.file.is_some()
+    }
+
+    pub fn is_folder(&self) -> bool {
+        self.folder.is_some()
+    }
+
+    /// Returns true if the item has been removed in a delta response.
+    pub fn is_deleted(&self) -> bool {
+        self.deleted.is_some()
+    }
+
+    /// Verify the content of the file after an upload or download using the size and
+    /// the `hashes` facet of the item. The hashes of the content are computed using
+    /// [ContentHashes::from_async_read] or a [HashingWriter](graph_http::api_impl::HashingWriter).
+    ///
+    /// Returns [IntegrityError::MissingHash] if the item does not report a
+    /// `quickXorHash` or `sha256Hash`, such as for folders.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let mut writer = HashingWriter::new(tokio::fs::File::create("./report.docx").await?);
+    /// response.download_to_writer(&mut writer).await?;
+    ///
+    /// drive_item.verify_content(&writer.hashes())?;
+    /// ```
+    pub fn verify_content(&self, content_hashes: &ContentHashes) -> Result<(), IntegrityError> {
+        if let Some(size) = self.size {
+            if size != content_hashes.size as i64 {
+                return Err(IntegrityError::Mismatch {
+                    algorithm: "size".to_owned(),
+                    expected: size.to_string(),
+                    computed: content_hashes.size.to_string(),
+                });
+            }
+        }
+
+        self.file
+            .as_ref()
+            .and_then(|file| file.hashes.as_ref())
+            .ok_or(IntegrityError::MissingHash)?
+            .verify(content_hashes)
+    }
+}
+
+/// Request body for the copy action on a [DriveItem].
+///
+/// Without a parent reference the copy is created in the same folder as the original
+/// item. A name is required in that case.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DriveItemCopy {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_reference: Option<ItemReference>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+impl DriveItemCopy {
+    pub fn new() -> DriveItemCopy {
+        DriveItemCopy::default()
+    }
+
+    /// Copy the item into the folder with the given id in the given drive.
+    pub fn with_parent(
+        mut self,
+        drive_id: impl AsRef<str>,
+        folder_id: impl AsRef<str>,
+    ) -> DriveItemCopy {
+        self.parent_reference = Some(ItemReference {
+            drive_id: Some(drive_id.as_ref().to_owned()),
+            id: Some(folder_id.as_ref().to_owned()),
+            ..Default::default()
+        });
+        self
+    }
+
+    pub fn with_name(mut self, name: impl AsRef<str>) -> DriveItemCopy {
+        self.name = Some(name.as_ref().to_owned());
+        self
+    }
+}
@@ -0,0 +1,222 @@
+use graph_http::api_impl::{AsyncOperationResource, AsyncOperationState};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// The status of an eDiscovery case.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EdiscoveryCaseStatus {
+    Unknown,
+    Active,
+    PendingDelete,
+    Closing,
+    Closed,
+    ClosedWithError,
+    UnknownFutureValue,
+}
+
+/// The status of a long running eDiscovery case operation such as estimating the
+/// statistics of a search.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CaseOperationStatus {
+    NotStarted,
+    SubmissionFailed,
+    Running,
+    Succeeded,
+    PartiallySucceeded,
+    Failed,
+    UnknownFutureValue,
+}
+
+impl CaseOperationStatus {
+    /// Returns true if the operation will not make any more progress.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            CaseOperationStatus::SubmissionFailed
+                | CaseOperationStatus::Succeeded
+                | CaseOperationStatus::PartiallySucceeded
+                | CaseOperationStatus::Failed
+        )
+    }
+
+    /// Returns true if the operation ended without producing a result.
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            CaseOperationStatus::SubmissionFailed | CaseOperationStatus::Failed
+        )
+    }
+}
+
+/// An eDiscovery (Premium) case used to identify, hold, and export content for
+/// legal investigations.
+///
+/// See [ediscoveryCase resource type](https://learn.microsoft.com/en-us/graph/api/resources/security-ediscoverycase)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EdiscoveryCase {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<EdiscoveryCaseStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_modified_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub closed_date_time: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl EdiscoveryCase {
+    /// The body of a request creating a case.
+    pub fn new(display_name: impl AsRef<str>) -> EdiscoveryCase {
+        EdiscoveryCase {
+            display_name: Some(display_name.as_ref().to_owned()),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_description(mut self, description: impl AsRef<str>) -> EdiscoveryCase {
+        self.description = Some(description.as_ref().to_owned());
+        self
+    }
+
+    /// The id of the case in an external case management system.
+    pub fn with_external_id(mut self, external_id: impl AsRef<str>) -> EdiscoveryCase {
+        self.external_id = Some(external_id.as_ref().to_owned());
+        self
+    }
+}
+
+/// A person whose mailbox and OneDrive are held and searched as part of a case.
+///
+/// See [ediscoveryCustodian resource type](https://learn.microsoft.com/en-us/graph/api/resources/security-ediscoverycustodian)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EdiscoveryCustodian {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hold_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acknowledged_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub released_date_time: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl EdiscoveryCustodian {
+    /// The body of a request adding the user with the given email address as a custodian.
+    pub fn new(email: impl AsRef<str>) -> EdiscoveryCustodian {
+        EdiscoveryCustodian {
+            email: Some(email.as_ref().to_owned()),
+            ..Default::default()
+        }
+    }
+}
+
+/// A search of the content of a case using a KQL query.
+///
+/// See [ediscoverySearch resource type](https://learn.microsoft.com/en-us/graph/api/resources/security-ediscoverysearch)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EdiscoverySearch {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_query: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub data_source_scopes: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_modified_date_time: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl EdiscoverySearch {
+    /// The body of a request creating a search with the given KQL query.
+    pub fn new(display_name: impl AsRef<str>, content_query: impl AsRef<str>) -> EdiscoverySearch {
+        EdiscoverySearch {
+            display_name: Some(display_name.as_ref().to_owned()),
+            content_query: Some(content_query.as_ref().to_owned()),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_description(mut self, description: impl AsRef<str>) -> EdiscoverySearch {
+        self.description = Some(description.as_ref().to_owned());
+        self
+    }
+}
+
+/// A long running operation of a case such as estimating the statistics of a search,
+/// adding results to a review set, or exporting.
+///
+/// The properties returned depend on the action of the operation and are kept in
+/// `additional_fields`, such as the `indexedItemCount` of an estimate statistics
+/// operation.
+///
+/// See [caseOperation resource type](https://learn.microsoft.com/en-us/graph/api/resources/security-caseoperation)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CaseOperation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<CaseOperationStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub percent_progress: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result_info: Option<Value>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl AsyncOperationResource for CaseOperation {
+    fn operation_state(&self) -> AsyncOperationState {
+        match self.status {
+            Some(status) if status.is_failure() => AsyncOperationState::Failed {
+                status: format!("{status:?}"),
+                message: self
+                    .result_info
+                    .as_ref()
+                    .map(|result_info| result_info.to_string())
+                    .unwrap_or_default(),
+            },
+            Some(status) if status.is_terminal() => AsyncOperationState::Completed,
+            _ => AsyncOperationState::Running,
+        }
+    }
+}
@@ -0,0 +1,260 @@
+use crate::types::{
+    DateTimeTimeZone, EmailAddress, Importance, ItemBody, PatternedRecurrence, Recipient,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// The type of an event attendee.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AttendeeType {
+    #[default]
+    Required,
+    Optional,
+    Resource,
+}
+
+/// The response status of an attendee or organizer for a meeting request.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseStatus {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<String>,
+}
+
+/// An event attendee.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Attendee {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<EmailAddress>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub attendee_type: Option<AttendeeType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ResponseStatus>,
+}
+
+impl Attendee {
+    pub fn new(address: impl AsRef<str>, attendee_type: AttendeeType) -> Attendee {
+        Attendee {
+            email_address: Some(EmailAddress::new(address)),
+            attendee_type: Some(attendee_type),
+            status: None,
+        }
+    }
+}
+
+/// The location of an event.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Location {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location_email_address: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl Location {
+    pub fn new(display_name: impl AsRef<str>) -> Location {
+        Location {
+            display_name: Some(display_name.as_ref().to_owned()),
+            ..Default::default()
+        }
+    }
+}
+
+/// The online meeting service provider of an event.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OnlineMeetingProviderType {
+    Unknown,
+    TeamsForBusiness,
+    SkypeForBusiness,
+    SkypeForConsumer,
+}
+
+/// Details for an attendee to join an online meeting.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OnlineMeetingInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conference_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub join_url: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+/// An event in a user calendar, or the default calendar of a Microsoft 365 group.
+///
+/// See [event resource type](https://learn.microsoft.com/en-us/graph/api/resources/event)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Event {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<ItemBody>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_preview: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<DateTimeTimeZone>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<DateTimeTimeZone>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attendees: Vec<Attendee>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organizer: Option<Recipient>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub importance: Option<Importance>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_all_day: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_cancelled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_online_meeting: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub online_meeting_provider: Option<OnlineMeetingProviderType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub online_meeting: Option<OnlineMeetingInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_new_time_proposals: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_reminder_on: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reminder_minutes_before_start: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurrence: Option<PatternedRecurrence>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_as: Option<String>,
+    #[serde(rename = "iCalUId", skip_serializing_if = "Option::is_none")]
+    pub i_cal_uid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub series_master_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_link: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl Event {
+    pub fn builder(subject: impl AsRef<str>) -> EventBuilder {
+        EventBuilder::new(subject)
+    }
+}
+
+/// Builder for the request body used to create or update an [Event].
+///
+/// # Example
+/// ```
+/// use graph_rs_sdk::types::*;
+///
+/// let event = Event::builder("Let's go for lunch")
+///     .with_body(ItemBody::html("Does noon work for you?"))
+///     .with_start(DateTimeTimeZone::new("2024-01-15T12:00:00", "Pacific Standard Time"))
+///     .with_end(DateTimeTimeZone::new("2024-01-15T14:00:00", "Pacific Standard Time"))
+///     .with_location(Location::new("Harry's Bar"))
+///     .with_attendee(Attendee::new("samanthab@contoso.onmicrosoft.com", AttendeeType::Required))
+///     .with_recurrence(PatternedRecurrence::new(
+///         RecurrencePattern::weekly(1, [DayOfWeek::Monday]),
+///         RecurrenceRange::end_date("2024-01-15", "2024-06-15"),
+///     ))
+///     .with_online_meeting(OnlineMeetingProviderType::TeamsForBusiness)
+///     .build();
+/// # assert_eq!(event.is_online_meeting, Some(true));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EventBuilder {
+    event: Event,
+}
+
+impl EventBuilder {
+    pub fn new(subject: impl AsRef<str>) -> EventBuilder {
+        EventBuilder {
+            event: Event {
+                subject: Some(subject.as_ref().to_owned()),
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn with_body(mut self, body: ItemBody) -> EventBuilder {
+        self.event.body = Some(body);
+        self
+    }
+
+    pub fn with_start(mut self, start: DateTimeTimeZone) -> EventBuilder {
+        self.event.start = Some(start);
+        self
+    }
+
+    pub fn with_end(mut self, end: DateTimeTimeZone) -> EventBuilder {
+        self.event.end = Some(end);
+        self
+    }
+
+    /// All day events must start and end at midnight in the same time zone.
+    pub fn with_all_day(mut self, is_all_day: bool) -> EventBuilder {
+        self.event.is_all_day = Some(is_all_day);
+        self
+    }
+
+    pub fn with_location(mut self, location: Location) -> EventBuilder {
+        self.event.location = Some(location);
+        self
+    }
+
+    pub fn with_attendee(mut self, attendee: Attendee) -> EventBuilder {
+        self.event.attendees.push(attendee);
+        self
+    }
+
+    pub fn with_attendees<I: IntoIterator<Item = Attendee>>(
+        mut self,
+        attendees: I,
+    ) -> EventBuilder {
+        self.event.attendees.extend(attendees);
+        self
+    }
+
+    pub fn with_importance(mut self, importance: Importance) -> EventBuilder {
+        self.event.importance = Some(importance);
+        self
+    }
+
+    pub fn with_recurrence(mut self, recurrence: PatternedRecurrence) -> EventBuilder {
+        self.event.recurrence = Some(recurrence);
+        self
+    }
+
+    /// Make the event an online meeting hosted by the given provider.
+    pub fn with_online_meeting(mut self, provider: OnlineMeetingProviderType) -> EventBuilder {
+        self.event.is_online_meeting = Some(true);
+        self.event.online_meeting_provider = Some(provider);
+        self
+    }
+
+    pub fn with_allow_new_time_proposals(mut self, allow: bool) -> EventBuilder {
+        self.event.allow_new_time_proposals = Some(allow);
+        self
+    }
+
+    pub fn with_reminder_minutes_before_start(mut self, minutes: i32) -> EventBuilder {
+        self.event.is_reminder_on = Some(true);
+        self.event.reminder_minutes_before_start = Some(minutes);
+        self
+    }
+
+    pub fn build(self) -> Event {
+        self.event
+    }
+}
@@ -0,0 +1,291 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// An email address of a person with the relevance of the address to the user.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoredEmailAddress {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    /// How relevant the address is to the user. A higher score is more relevant.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relevance_score: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selection_likelihood: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+/// The type of a person such as `Person` or `Group` and the subclass such as
+/// `OrganizationUser` or `UnifiedGroup`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonType {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subclass: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+/// A person relevant to the user aggregated from mail, contacts, and social networks.
+/// People are returned in order of relevance to the user.
+///
+/// See [person resource type](https://learn.microsoft.com/en-us/graph/api/resources/person)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Person {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub given_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub surname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_principal_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub department: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub office_location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub person_type: Option<PersonType>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scored_email_addresses: Vec<ScoredEmailAddress>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl Person {
+    /// The highest relevance score of the email addresses of the person.
+    pub fn relevance_score(&self) -> Option<f64> {
+        self.scored_email_addresses
+            .iter()
+            .filter_map(|address| address.relevance_score)
+            .reduce(f64::max)
+    }
+}
+
+/// The type of the resource of a trending, shared, or used insight.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum InsightResourceType {
+    PowerPoint,
+    Word,
+    Excel,
+    Pdf,
+    OneNote,
+    OneNotePage,
+    InfoPath,
+    Visio,
+    Publisher,
+    Project,
+    Access,
+    Mail,
+    Csv,
+    Archive,
+    Xps,
+    Audio,
+    Video,
+    Image,
+    Web,
+    Text,
+    Xml,
+    Story,
+    ExternalContent,
+    Folder,
+    Spsite,
+    Other,
+}
+
+impl InsightResourceType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InsightResourceType::PowerPoint => "PowerPoint",
+            InsightResourceType::Word => "Word",
+            InsightResourceType::Excel => "Excel",
+            InsightResourceType::Pdf => "Pdf",
+            InsightResourceType::OneNote => "OneNote",
+            InsightResourceType::OneNotePage => "OneNotePage",
+            InsightResourceType::InfoPath => "InfoPath",
+            InsightResourceType::Visio => "Visio",
+            InsightResourceType::Publisher => "Publisher",
+            InsightResourceType::Project => "Project",
+            InsightResourceType::Access => "Access",
+            InsightResourceType::Mail => "Mail",
+            InsightResourceType::Csv => "Csv",
+            InsightResourceType::Archive => "Archive",
+            InsightResourceType::Xps => "Xps",
+            InsightResourceType::Audio => "Audio",
+            InsightResourceType::Video => "Video",
+            InsightResourceType::Image => "Image",
+            InsightResourceType::Web => "Web",
+            InsightResourceType::Text => "Text",
+            InsightResourceType::Xml => "Xml",
+            InsightResourceType::Story => "Story",
+            InsightResourceType::ExternalContent => "ExternalContent",
+            InsightResourceType::Folder => "Folder",
+            InsightResourceType::Spsite => "Spsite",
+            InsightResourceType::Other => "Other",
+        }
+    }
+}
+
+/// The properties used to display the resource of an insight.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceVisualization {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The type of the resource such as `Word` or `PowerPoint`. Kept as a string
+    /// because the api may return types that are not in [InsightResourceType].
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub resource_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub media_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preview_image_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preview_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container_web_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container_display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container_type: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+/// The reference to the resource of an insight.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceReference {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub resource_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_url: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+/// A document trending around the user. Trending documents are returned in order of
+/// their weight.
+///
+/// See [trending resource type](https://learn.microsoft.com/en-us/graph/api/resources/insights-trending)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Trending {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// How much the document is trending. A higher weight is more relevant.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_modified_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_visualization: Option<ResourceVisualization>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_reference: Option<ResourceReference>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+/// The details of a time a document was shared.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SharingDetail {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shared_by: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shared_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sharing_subject: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sharing_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sharing_reference: Option<ResourceReference>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+/// A document shared with or by the user.
+///
+/// See [sharedInsight resource type](https://learn.microsoft.com/en-us/graph/api/resources/insights-shared)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SharedInsight {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_shared: Option<SharingDetail>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sharing_history: Vec<SharingDetail>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_visualization: Option<ResourceVisualization>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_reference: Option<ResourceReference>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+/// When a document was last accessed and modified by the user.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_accessed_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_modified_date_time: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+/// A document viewed or modified by the user. Used documents are returned with the
+/// most recently used document first.
+///
+/// See [usedInsight resource type](https://learn.microsoft.com/en-us/graph/api/resources/insights-used)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsedInsight {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_used: Option<UsageDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_visualization: Option<ResourceVisualization>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_reference: Option<ResourceReference>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn person_relevance_score_is_highest_address_score() {
+        let person: Person = serde_json::from_value(serde_json::json!({
+            "id": "person-id",
+            "displayName": "Adele Vance",
+            "scoredEmailAddresses": [
+                { "address": "adele@contoso.com", "relevanceScore": 8.0 },
+                { "address": "adele.vance@contoso.com", "relevanceScore": 12.5 }
+            ],
+            "personType": { "class": "Person", "subclass": "OrganizationUser" }
+        }))
+        .unwrap();
+
+        assert_eq!(Some(12.5), person.relevance_score());
+        assert_eq!(None, Person::default().relevance_score());
+    }
+}
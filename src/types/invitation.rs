@@ -0,0 +1,72 @@
+use crate::types::User;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// An invitation that adds an external user to the organization as a guest.
+///
+/// See [invitation resource type](https://learn.microsoft.com/en-us/graph/api/resources/invitation)
+///
+/// # Example
+/// ```
+/// use graph_rs_sdk::types::Invitation;
+///
+/// let invitation = Invitation::new("admin@fabrikam.com", "https://myapp.contoso.com")
+///     .with_invited_user_display_name("Fabrikam Admin")
+///     .with_send_invitation_message(true);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Invitation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invited_user_email_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invite_redirect_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invited_user_display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invited_user_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_invitation_message: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invite_redeem_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invited_user: Option<User>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl Invitation {
+    pub fn new(
+        invited_user_email_address: impl AsRef<str>,
+        invite_redirect_url: impl AsRef<str>,
+    ) -> Invitation {
+        Invitation {
+            invited_user_email_address: Some(invited_user_email_address.as_ref().to_owned()),
+            invite_redirect_url: Some(invite_redirect_url.as_ref().to_owned()),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_invited_user_display_name(mut self, display_name: impl AsRef<str>) -> Invitation {
+        self.invited_user_display_name = Some(display_name.as_ref().to_owned());
+        self
+    }
+
+    /// The user type of the invited user which is `Guest` by default. Use `Member`
+    /// to invite the user as a member of the organization.
+    pub fn with_invited_user_type(mut self, user_type: impl AsRef<str>) -> Invitation {
+        self.invited_user_type = Some(user_type.as_ref().to_owned());
+        self
+    }
+
+    /// Send an invitation email to the invited user. Default is false.
+    pub fn with_send_invitation_message(mut self, send_invitation_message: bool) -> Invitation {
+        self.send_invitation_message = Some(send_invitation_message);
+        self
+    }
+}
@@ -0,0 +1,92 @@
+use crate::types::IdentitySet;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// An item in a SharePoint list.
+///
+/// The column values of the item are in `fields`. Use your own serde type for `F` to map
+/// the columns of a list to a struct, or use the default [HashMap] to get the column values
+/// as json. The fields are only returned when the request uses `$expand=fields`.
+///
+/// See [listItem resource type](https://learn.microsoft.com/en-us/graph/api/resources/listitem)
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::*;
+/// use graph_rs_sdk::types::{Collection, ListItem};
+///
+/// #[derive(Debug, Serialize, Deserialize)]
+/// #[serde(rename_all = "PascalCase")]
+/// struct Task {
+///     title: String,
+///     status: Option<String>,
+/// }
+///
+/// let items: Collection<ListItem<Task>> = client
+///     .site("SITE_ID")
+///     .list("LIST_ID")
+///     .items()
+///     .list_items_with_fields()
+///     .send()
+///     .await?
+///     .json()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListItem<F = HashMap<String, Value>> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub e_tag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<IdentitySet>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_modified_by: Option<IdentitySet>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_modified_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<F>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl<F> ListItem<F> {
+    /// Request body for creating a list item with the given column values.
+    pub fn new(fields: F) -> ListItem<F> {
+        ListItem {
+            id: None,
+            e_tag: None,
+            created_by: None,
+            created_date_time: None,
+            last_modified_by: None,
+            last_modified_date_time: None,
+            web_url: None,
+            fields: Some(fields),
+            additional_fields: HashMap::new(),
+        }
+    }
+}
+
+/// A previous version of a [ListItem].
+///
+/// See [listItemVersion resource type](https://learn.microsoft.com/en-us/graph/api/resources/listitemversion)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListItemVersion<F = HashMap<String, Value>> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_modified_by: Option<IdentitySet>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_modified_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<F>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
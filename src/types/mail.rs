@@ -0,0 +1,259 @@
+use crate::types::{Message, Recipient};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use graph_error::{GraphFailure, GraphResult, GraphRsError};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// The maximum size of an attachment that can be added to a message in a single request.
+///
+/// Attachments larger than this must be uploaded using an attachment upload session.
+/// See [AttachmentUploadSession].
+pub const MAX_ATTACHMENT_SIZE: usize = 3 * 1024 * 1024;
+
+static FILE_ATTACHMENT_ODATA_TYPE: &str = "#microsoft.graph.fileAttachment";
+
+/// Request body for the sendMail action.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::*;
+/// use graph_rs_sdk::types::{ItemBody, Message, Recipient, SendMail};
+///
+/// let client = Graph::new("ACCESS_TOKEN");
+///
+/// let send_mail = SendMail::new(Message {
+///     subject: Some("Meet for lunch?".into()),
+///     body: Some(ItemBody::text("The new cafeteria is open.")),
+///     to_recipients: vec![Recipient::new("fannyd@contoso.onmicrosoft.com")],
+///     ..Default::default()
+/// });
+///
+/// let response = client.me()
+///     .send_mail(&send_mail)
+///     .send()
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendMail {
+    pub message: Message,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub save_to_sent_items: Option<bool>,
+}
+
+impl SendMail {
+    pub fn new(message: Message) -> SendMail {
+        SendMail {
+            message,
+            save_to_sent_items: None,
+        }
+    }
+
+    pub fn save_to_sent_items(mut self, save_to_sent_items: bool) -> SendMail {
+        self.save_to_sent_items = Some(save_to_sent_items);
+        self
+    }
+}
+
+/// Request body for the reply, replyAll, createReply, and createReplyAll actions.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplyMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<Message>,
+}
+
+impl ReplyMessage {
+    pub fn comment(comment: impl AsRef<str>) -> ReplyMessage {
+        ReplyMessage {
+            comment: Some(comment.as_ref().to_owned()),
+            message: None,
+        }
+    }
+
+    pub fn message(message: Message) -> ReplyMessage {
+        ReplyMessage {
+            comment: None,
+            message: Some(message),
+        }
+    }
+}
+
+/// Request body for the forward action on messages and events and the createForward
+/// action on messages.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ForwardMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub to_recipients: Vec<Recipient>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<Message>,
+}
+
+impl ForwardMessage {
+    pub fn new<T: Into<Recipient>, I: IntoIterator<Item = T>>(to_recipients: I) -> ForwardMessage {
+        ForwardMessage {
+            comment: None,
+            to_recipients: to_recipients.into_iter().map(|r| r.into()).collect(),
+            message: None,
+        }
+    }
+
+    pub fn with_comment(mut self, comment: impl AsRef<str>) -> ForwardMessage {
+        self.comment = Some(comment.as_ref().to_owned());
+        self
+    }
+}
+
+/// A file attached to a message, event, or post.
+///
+/// The content is sent base64 encoded as part of the request body, which limits the
+/// size of the attachment to [MAX_ATTACHMENT_SIZE]. Use [AttachmentUploadSession]
+/// for larger attachments.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileAttachment {
+    #[serde(rename = "@odata.type", default)]
+    pub odata_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_bytes: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_inline: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<i64>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl FileAttachment {
+    /// Create a file attachment from the raw bytes of the file.
+    ///
+    /// Returns an error if the content is larger than [MAX_ATTACHMENT_SIZE].
+    pub fn new(
+        name: impl AsRef<str>,
+        content_type: impl AsRef<str>,
+        content: impl AsRef<[u8]>,
+    ) -> GraphResult<FileAttachment> {
+        let content = content.as_ref();
+        if content.len() > MAX_ATTACHMENT_SIZE {
+            return Err(GraphFailure::internal(GraphRsError::AttachmentTooLarge {
+                size: content.len(),
+                max: MAX_ATTACHMENT_SIZE,
+            }));
+        }
+
+        Ok(FileAttachment {
+            odata_type: FILE_ATTACHMENT_ODATA_TYPE.to_owned(),
+            id: None,
+            name: Some(name.as_ref().to_owned()),
+            content_type: Some(content_type.as_ref().to_owned()),
+            content_bytes: Some(STANDARD.encode(content)),
+            content_id: None,
+            is_inline: None,
+            size: None,
+            additional_fields: Default::default(),
+        })
+    }
+
+    /// Mark the attachment as inline content referenced in the message body by `cid:{content_id}`.
+    pub fn inline(mut self, content_id: impl AsRef<str>) -> FileAttachment {
+        self.content_id = Some(content_id.as_ref().to_owned());
+        self.is_inline = Some(true);
+        self
+    }
+
+    /// Decode the base64 content of the attachment.
+    pub fn decode_content(&self) -> GraphResult<Option<Vec<u8>>> {
+        match self.content_bytes.as_ref() {
+            Some(content_bytes) => STANDARD
+                .decode(content_bytes)
+                .map(Some)
+                .map_err(|err| GraphFailure::invalid(&err.to_string())),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Describes the file to be uploaded in an attachment upload session.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachmentItem {
+    pub attachment_type: String,
+    pub name: String,
+    pub size: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_inline: Option<bool>,
+}
+
+/// Request body for the createUploadSession action on message and event attachments.
+///
+/// Attachments larger than [MAX_ATTACHMENT_SIZE] must be uploaded in chunks using an
+/// upload session. The response of the createUploadSession request can be turned into an
+/// [UploadSession](crate::http::UploadSession) which uploads the file in chunks.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs_sdk::*;
+/// use graph_rs_sdk::http::ResponseExt;
+/// use graph_rs_sdk::types::AttachmentUploadSession;
+///
+/// let client = Graph::new("ACCESS_TOKEN");
+/// let file = std::fs::File::open("./report.pdf")?;
+/// let size = file.metadata()?.len();
+///
+/// let response = client.me()
+///     .message("MESSAGE_ID")
+///     .attachments()
+///     .create_upload_session(&AttachmentUploadSession::file("report.pdf", size))
+///     .send()
+///     .await?;
+///
+/// let mut upload_session = response.into_upload_session(file).await?;
+/// while let Some(result) = upload_session.next().await {
+///     let response = result?;
+///     println!("{response:#?}");
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AttachmentUploadSession {
+    #[serde(rename = "AttachmentItem")]
+    pub attachment_item: AttachmentItem,
+}
+
+impl AttachmentUploadSession {
+    pub fn file(name: impl AsRef<str>, size: u64) -> AttachmentUploadSession {
+        AttachmentUploadSession {
+            attachment_item: AttachmentItem {
+                attachment_type: "file".to_owned(),
+                name: name.as_ref().to_owned(),
+                size,
+                content_type: None,
+                content_id: None,
+                is_inline: None,
+            },
+        }
+    }
+
+    pub fn with_content_type(mut self, content_type: impl AsRef<str>) -> AttachmentUploadSession {
+        self.attachment_item.content_type = Some(content_type.as_ref().to_owned());
+        self
+    }
+}
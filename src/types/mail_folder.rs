@@ -0,0 +1,486 @@
+use crate::types::{Importance, Recipient};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// A folder in a mailbox, such as Inbox or Drafts.
+///
+/// See [mailFolder resource type](https://learn.microsoft.com/en-us/graph/api/resources/mailfolder)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MailFolder {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_folder_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub child_folder_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unread_item_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_item_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_hidden: Option<bool>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl MailFolder {
+    pub fn new(display_name: impl AsRef<str>) -> MailFolder {
+        MailFolder {
+            display_name: Some(display_name.as_ref().to_owned()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a folder that is hidden from mail clients.
+    pub fn hidden(display_name: impl AsRef<str>) -> MailFolder {
+        MailFolder {
+            is_hidden: Some(true),
+            ..MailFolder::new(display_name)
+        }
+    }
+
+    /// Returns true if the folder has child folders. Folders where the child folder
+    /// count was not selected are assumed to have child folders.
+    pub fn has_child_folders(&self) -> bool {
+        self.child_folder_count.unwrap_or(1) > 0
+    }
+}
+
+/// The sensitivity of a message.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Sensitivity {
+    #[default]
+    Normal,
+    Personal,
+    Private,
+    Confidential,
+}
+
+/// The follow up flag of a message that a [MessageRulePredicates] matches.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MessageActionFlag {
+    Any,
+    Call,
+    DoNotForward,
+    FollowUp,
+    Fyi,
+    Forward,
+    NoResponseNecessary,
+    Read,
+    Reply,
+    ReplyToAll,
+    Review,
+}
+
+/// A range of message sizes in kilobytes.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SizeRange {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum_size: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum_size: Option<i32>,
+}
+
+/// The conditions of a [MessageRule], or the exceptions to the rule. A message matches
+/// when it meets all of the conditions that are set.
+///
+/// See [messageRulePredicates resource type](https://learn.microsoft.com/en-us/graph/api/resources/messagerulepredicates)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageRulePredicates {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub body_contains: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub body_or_subject_contains: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub categories: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub from_addresses: Vec<Recipient>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_attachments: Option<bool>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub header_contains: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub importance: Option<Importance>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_automatic_reply: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_encrypted: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_meeting_request: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_action_flag: Option<MessageActionFlag>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not_sent_to_me: Option<bool>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recipient_contains: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sender_contains: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sensitivity: Option<Sensitivity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sent_cc_me: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sent_only_to_me: Option<bool>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sent_to_addresses: Vec<Recipient>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sent_to_me: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sent_to_or_cc_me: Option<bool>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subject_contains: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub within_size_range: Option<SizeRange>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl MessageRulePredicates {
+    pub fn new() -> MessageRulePredicates {
+        MessageRulePredicates::default()
+    }
+
+    pub fn with_from(mut self, sender: impl Into<Recipient>) -> MessageRulePredicates {
+        self.from_addresses.push(sender.into());
+        self
+    }
+
+    pub fn with_sent_to(mut self, recipient: impl Into<Recipient>) -> MessageRulePredicates {
+        self.sent_to_addresses.push(recipient.into());
+        self
+    }
+
+    pub fn with_sender_contains(mut self, text: impl AsRef<str>) -> MessageRulePredicates {
+        self.sender_contains.push(text.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_subject_contains(mut self, text: impl AsRef<str>) -> MessageRulePredicates {
+        self.subject_contains.push(text.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_body_contains(mut self, text: impl AsRef<str>) -> MessageRulePredicates {
+        self.body_contains.push(text.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_body_or_subject_contains(mut self, text: impl AsRef<str>) -> MessageRulePredicates {
+        self.body_or_subject_contains.push(text.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_header_contains(mut self, text: impl AsRef<str>) -> MessageRulePredicates {
+        self.header_contains.push(text.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_category(mut self, category: impl AsRef<str>) -> MessageRulePredicates {
+        self.categories.push(category.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_has_attachments(mut self, has_attachments: bool) -> MessageRulePredicates {
+        self.has_attachments = Some(has_attachments);
+        self
+    }
+
+    pub fn with_importance(mut self, importance: Importance) -> MessageRulePredicates {
+        self.importance = Some(importance);
+        self
+    }
+
+    pub fn with_sensitivity(mut self, sensitivity: Sensitivity) -> MessageRulePredicates {
+        self.sensitivity = Some(sensitivity);
+        self
+    }
+
+    /// Match messages with a size in kilobytes within the range.
+    pub fn with_size_range(
+        mut self,
+        minimum_size: Option<i32>,
+        maximum_size: Option<i32>,
+    ) -> MessageRulePredicates {
+        self.within_size_range = Some(SizeRange {
+            minimum_size,
+            maximum_size,
+        });
+        self
+    }
+}
+
+/// The actions of a [MessageRule] that are taken on a message that matches the rule.
+///
+/// See [messageRuleActions resource type](https://learn.microsoft.com/en-us/graph/api/resources/messageruleactions)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageRuleActions {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub assign_categories: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub copy_to_folder: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delete: Option<bool>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub forward_as_attachment_to: Vec<Recipient>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub forward_to: Vec<Recipient>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mark_as_read: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mark_importance: Option<Importance>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub move_to_folder: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permanent_delete: Option<bool>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub redirect_to: Vec<Recipient>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_processing_rules: Option<bool>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl MessageRuleActions {
+    pub fn new() -> MessageRuleActions {
+        MessageRuleActions::default()
+    }
+
+    /// Move the message to the folder with the id, or well known name, of the folder.
+    pub fn with_move_to_folder(mut self, folder_id: impl AsRef<str>) -> MessageRuleActions {
+        self.move_to_folder = Some(folder_id.as_ref().to_owned());
+        self
+    }
+
+    /// Copy the message to the folder with the id, or well known name, of the folder.
+    pub fn with_copy_to_folder(mut self, folder_id: impl AsRef<str>) -> MessageRuleActions {
+        self.copy_to_folder = Some(folder_id.as_ref().to_owned());
+        self
+    }
+
+    /// Move the message to the Deleted Items folder.
+    pub fn with_delete(mut self) -> MessageRuleActions {
+        self.delete = Some(true);
+        self
+    }
+
+    pub fn with_mark_as_read(mut self) -> MessageRuleActions {
+        self.mark_as_read = Some(true);
+        self
+    }
+
+    pub fn with_mark_importance(mut self, importance: Importance) -> MessageRuleActions {
+        self.mark_importance = Some(importance);
+        self
+    }
+
+    pub fn with_assign_category(mut self, category: impl AsRef<str>) -> MessageRuleActions {
+        self.assign_categories.push(category.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_forward_to(mut self, recipient: impl Into<Recipient>) -> MessageRuleActions {
+        self.forward_to.push(recipient.into());
+        self
+    }
+
+    pub fn with_redirect_to(mut self, recipient: impl Into<Recipient>) -> MessageRuleActions {
+        self.redirect_to.push(recipient.into());
+        self
+    }
+
+    /// Do not evaluate the rules that have a higher sequence after this rule.
+    pub fn with_stop_processing_rules(mut self) -> MessageRuleActions {
+        self.stop_processing_rules = Some(true);
+        self
+    }
+}
+
+/// An Inbox rule that is run on incoming messages.
+///
+/// Rules are evaluated in the order of their sequence. Rules can only be created in the
+/// Inbox folder, `client.me().mail_folder("inbox")`.
+///
+/// See [messageRule resource type](https://learn.microsoft.com/en-us/graph/api/resources/messagerule)
+///
+/// # Example
+/// ```
+/// use graph_rs_sdk::types::*;
+///
+/// let rule = MessageRule::new("From partners to FYI folder", 2)
+///     .with_conditions(
+///         MessageRulePredicates::new()
+///             .with_sender_contains("adele")
+///             .with_has_attachments(true),
+///     )
+///     .with_exceptions(MessageRulePredicates::new().with_importance(Importance::High))
+///     .with_actions(
+///         MessageRuleActions::new()
+///             .with_move_to_folder("AAMkADIyAAAhrbPXAAA=")
+///             .with_forward_to(Recipient::new("alexw@contoso.onmicrosoft.com"))
+///             .with_stop_processing_rules(),
+///     );
+///
+/// let body = serde_json::to_value(&rule).unwrap();
+/// assert_eq!(serde_json::json!(["adele"]), body["conditions"]["senderContains"]);
+/// assert_eq!(serde_json::json!(true), body["actions"]["stopProcessingRules"]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageRule {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sequence: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_error: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_read_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<MessageRulePredicates>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exceptions: Option<MessageRulePredicates>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actions: Option<MessageRuleActions>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl MessageRule {
+    /// A new enabled rule. Rules with a lower sequence are evaluated first.
+    pub fn new(display_name: impl AsRef<str>, sequence: i32) -> MessageRule {
+        MessageRule {
+            display_name: Some(display_name.as_ref().to_owned()),
+            sequence: Some(sequence),
+            is_enabled: Some(true),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_enabled(mut self, is_enabled: bool) -> MessageRule {
+        self.is_enabled = Some(is_enabled);
+        self
+    }
+
+    pub fn with_conditions(mut self, conditions: MessageRulePredicates) -> MessageRule {
+        self.conditions = Some(conditions);
+        self
+    }
+
+    pub fn with_exceptions(mut self, exceptions: MessageRulePredicates) -> MessageRule {
+        self.exceptions = Some(exceptions);
+        self
+    }
+
+    pub fn with_actions(mut self, actions: MessageRuleActions) -> MessageRule {
+        self.actions = Some(actions);
+        self
+    }
+}
+
+/// The color of an [OutlookCategory]. The preset colors map to the colors shown in
+/// Outlook, `None` is a category without a color.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CategoryColor {
+    #[default]
+    None,
+    /// Red
+    Preset0,
+    /// Orange
+    Preset1,
+    /// Brown
+    Preset2,
+    /// Yellow
+    Preset3,
+    /// Green
+    Preset4,
+    /// Teal
+    Preset5,
+    /// Olive
+    Preset6,
+    /// Blue
+    Preset7,
+    /// Purple
+    Preset8,
+    /// Cranberry
+    Preset9,
+    /// Steel
+    Preset10,
+    /// DarkSteel
+    Preset11,
+    /// Gray
+    Preset12,
+    /// DarkGray
+    Preset13,
+    /// Black
+    Preset14,
+    /// DarkRed
+    Preset15,
+    /// DarkOrange
+    Preset16,
+    /// DarkBrown
+    Preset17,
+    /// DarkYellow
+    Preset18,
+    /// DarkGreen
+    Preset19,
+    /// DarkTeal
+    Preset20,
+    /// DarkOlive
+    Preset21,
+    /// DarkBlue
+    Preset22,
+    /// DarkPurple
+    Preset23,
+    /// DarkCranberry
+    Preset24,
+}
+
+/// A category in the master category list of a user.
+///
+/// The display name of a category can not be changed after the category is created,
+/// only the color.
+///
+/// See [outlookCategory resource type](https://learn.microsoft.com/en-us/graph/api/resources/outlookcategory)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutlookCategory {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<CategoryColor>,
+}
+
+impl OutlookCategory {
+    pub fn new(display_name: impl AsRef<str>, color: CategoryColor) -> OutlookCategory {
+        OutlookCategory {
+            id: None,
+            display_name: Some(display_name.as_ref().to_owned()),
+            color: Some(color),
+        }
+    }
+
+    /// The request body to change the color of an existing category.
+    pub fn color(color: CategoryColor) -> OutlookCategory {
+        OutlookCategory {
+            id: None,
+            display_name: None,
+            color: Some(color),
+        }
+    }
+}
@@ -0,0 +1,337 @@
+use crate::types::{DateTimeTimeZone, DayOfWeek};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use time::{OffsetDateTime, Time};
+
+/// Whether automatic replies are sent.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AutomaticRepliesStatus {
+    #[default]
+    Disabled,
+    AlwaysEnabled,
+    Scheduled,
+}
+
+/// The senders outside of the organization that are sent the external reply message.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExternalAudienceScope {
+    #[default]
+    None,
+    ContactsOnly,
+    All,
+}
+
+/// The automatic replies, also known as out of office replies, of a mailbox.
+///
+/// See [automaticRepliesSetting resource type](https://learn.microsoft.com/en-us/graph/api/resources/automaticrepliessetting)
+///
+/// # Example
+/// ```
+/// use graph_rs_sdk::types::*;
+/// use time::{Duration, OffsetDateTime};
+///
+/// let start = OffsetDateTime::from_unix_timestamp(1704096000).unwrap();
+/// let automatic_replies = AutomaticRepliesSetting::scheduled(
+///     start,
+///     start + Duration::days(14),
+///     "I am out of the office until the 15th.",
+/// )
+/// .with_external_reply(
+///     "I am out of the office until the 15th. For urgent requests contact support.",
+///     ExternalAudienceScope::ContactsOnly,
+/// );
+///
+/// let body = serde_json::to_value(&automatic_replies).unwrap();
+/// assert_eq!("scheduled", body["status"]);
+/// assert_eq!(
+///     serde_json::json!({ "dateTime": "2024-01-01T08:00:00", "timeZone": "UTC" }),
+///     body["scheduledStartDateTime"]
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutomaticRepliesSetting {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<AutomaticRepliesStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_audience: Option<ExternalAudienceScope>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduled_start_date_time: Option<DateTimeTimeZone>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduled_end_date_time: Option<DateTimeTimeZone>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub internal_reply_message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_reply_message: Option<String>,
+}
+
+impl AutomaticRepliesSetting {
+    /// Turn off automatic replies. The reply messages and schedule are kept.
+    pub fn disabled() -> AutomaticRepliesSetting {
+        AutomaticRepliesSetting {
+            status: Some(AutomaticRepliesStatus::Disabled),
+            ..Default::default()
+        }
+    }
+
+    /// Send the reply message to senders in the organization until automatic replies
+    /// are turned off.
+    pub fn always(internal_reply_message: impl AsRef<str>) -> AutomaticRepliesSetting {
+        AutomaticRepliesSetting {
+            status: Some(AutomaticRepliesStatus::AlwaysEnabled),
+            internal_reply_message: Some(internal_reply_message.as_ref().to_owned()),
+            ..Default::default()
+        }
+    }
+
+    /// Send the reply message to senders in the organization between the start and end.
+    /// The start and end are sent in UTC so the offset of each is kept.
+    ///
+    /// Use [AutomaticRepliesSetting::with_schedule] for a schedule in the time zone of
+    /// the mailbox.
+    pub fn scheduled(
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+        internal_reply_message: impl AsRef<str>,
+    ) -> AutomaticRepliesSetting {
+        AutomaticRepliesSetting {
+            status: Some(AutomaticRepliesStatus::Scheduled),
+            internal_reply_message: Some(internal_reply_message.as_ref().to_owned()),
+            ..Default::default()
+        }
+        .with_schedule(DateTimeTimeZone::utc(start), DateTimeTimeZone::utc(end))
+    }
+
+    pub fn with_schedule(
+        mut self,
+        start: DateTimeTimeZone,
+        end: DateTimeTimeZone,
+    ) -> AutomaticRepliesSetting {
+        self.scheduled_start_date_time = Some(start);
+        self.scheduled_end_date_time = Some(end);
+        self
+    }
+
+    /// Also reply to senders outside of the organization within the audience.
+    pub fn with_external_reply(
+        mut self,
+        external_reply_message: impl AsRef<str>,
+        external_audience: ExternalAudienceScope,
+    ) -> AutomaticRepliesSetting {
+        self.external_reply_message = Some(external_reply_message.as_ref().to_owned());
+        self.external_audience = Some(external_audience);
+        self
+    }
+
+    /// Returns true if replies are sent at the given time.
+    ///
+    /// A schedule that is not in UTC can not be compared and is treated as active.
+    pub fn is_active_at(&self, date_time: OffsetDateTime) -> bool {
+        match self.status.unwrap_or_default() {
+            AutomaticRepliesStatus::Disabled => false,
+            AutomaticRepliesStatus::AlwaysEnabled => true,
+            AutomaticRepliesStatus::Scheduled => {
+                let start = self
+                    .scheduled_start_date_time
+                    .as_ref()
+                    .and_then(DateTimeTimeZone::to_utc);
+                let end = self
+                    .scheduled_end_date_time
+                    .as_ref()
+                    .and_then(DateTimeTimeZone::to_utc);
+                !start.is_some_and(|start| date_time < start)
+                    && !end.is_some_and(|end| date_time >= end)
+            }
+        }
+    }
+}
+
+/// The time zone of [WorkingHours].
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeZoneBase {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+/// The days of the week and hours in a time zone that a user works.
+///
+/// The start and end times are in the format `HH:mm:ss.fffffff` such as `08:00:00.0000000`.
+///
+/// See [workingHours resource type](https://learn.microsoft.com/en-us/graph/api/resources/workinghours)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkingHours {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub days_of_week: Vec<DayOfWeek>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<TimeZoneBase>,
+}
+
+impl WorkingHours {
+    /// Working hours from the start to the end time on each of the days in the time zone.
+    /// The time zone is either a Windows or IANA time zone name.
+    pub fn new<I: IntoIterator<Item = DayOfWeek>>(
+        days_of_week: I,
+        start: Time,
+        end: Time,
+        time_zone: impl AsRef<str>,
+    ) -> WorkingHours {
+        WorkingHours {
+            days_of_week: days_of_week.into_iter().collect(),
+            start_time: Some(WorkingHours::format_time(start)),
+            end_time: Some(WorkingHours::format_time(end)),
+            time_zone: Some(TimeZoneBase {
+                name: Some(time_zone.as_ref().to_owned()),
+                additional_fields: HashMap::new(),
+            }),
+        }
+    }
+
+    pub fn start(&self) -> Option<Time> {
+        WorkingHours::parse_time(self.start_time.as_deref()?)
+    }
+
+    pub fn end(&self) -> Option<Time> {
+        WorkingHours::parse_time(self.end_time.as_deref()?)
+    }
+
+    fn format_time(time: Time) -> String {
+        format!(
+            "{:02}:{:02}:{:02}.0000000",
+            time.hour(),
+            time.minute(),
+            time.second()
+        )
+    }
+
+    fn parse_time(value: &str) -> Option<Time> {
+        let mut parts = value.get(..8)?.split(':').map(str::parse::<u8>);
+        let (hour, minute, second) = (
+            parts.next()?.ok()?,
+            parts.next()?.ok()?,
+            parts.next()?.ok()?,
+        );
+        Time::from_hms(hour, minute, second).ok()
+    }
+}
+
+/// A locale, such as the preferred language of a mailbox.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocaleInfo {
+    /// A locale code such as `en-US`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+}
+
+impl LocaleInfo {
+    pub fn new(locale: impl AsRef<str>) -> LocaleInfo {
+        LocaleInfo {
+            locale: Some(locale.as_ref().to_owned()),
+            display_name: None,
+        }
+    }
+}
+
+/// The settings of the primary mailbox of a user.
+///
+/// Only the settings that are set are changed when updating the mailbox settings.
+///
+/// See [mailboxSettings resource type](https://learn.microsoft.com/en-us/graph/api/resources/mailboxsettings)
+///
+/// # Example
+/// ```
+/// use graph_rs_sdk::types::*;
+/// use time::Time;
+///
+/// let settings = MailboxSettings::new()
+///     .with_time_zone("Pacific Standard Time")
+///     .with_language(LocaleInfo::new("en-US"))
+///     .with_working_hours(WorkingHours::new(
+///         [DayOfWeek::Monday, DayOfWeek::Tuesday, DayOfWeek::Wednesday],
+///         Time::from_hms(8, 0, 0).unwrap(),
+///         Time::from_hms(17, 0, 0).unwrap(),
+///         "Pacific Standard Time",
+///     ));
+///
+/// let body = serde_json::to_value(&settings).unwrap();
+/// assert_eq!("08:00:00.0000000", body["workingHours"]["startTime"]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MailboxSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automatic_replies_setting: Option<AutomaticRepliesSetting>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archive_folder: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<LocaleInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub working_hours: Option<WorkingHours>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delegate_meeting_message_delivery_options: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_purpose: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl MailboxSettings {
+    pub fn new() -> MailboxSettings {
+        MailboxSettings::default()
+    }
+
+    pub fn with_automatic_replies(
+        mut self,
+        automatic_replies: AutomaticRepliesSetting,
+    ) -> MailboxSettings {
+        self.automatic_replies_setting = Some(automatic_replies);
+        self
+    }
+
+    /// The default time zone of the mailbox, either a Windows or IANA time zone name.
+    pub fn with_time_zone(mut self, time_zone: impl AsRef<str>) -> MailboxSettings {
+        self.time_zone = Some(time_zone.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_language(mut self, language: LocaleInfo) -> MailboxSettings {
+        self.language = Some(language);
+        self
+    }
+
+    pub fn with_working_hours(mut self, working_hours: WorkingHours) -> MailboxSettings {
+        self.working_hours = Some(working_hours);
+        self
+    }
+
+    /// The date format of the mailbox such as `MM/dd/yyyy`.
+    pub fn with_date_format(mut self, date_format: impl AsRef<str>) -> MailboxSettings {
+        self.date_format = Some(date_format.as_ref().to_owned());
+        self
+    }
+
+    /// The time format of the mailbox such as `h:mm tt`.
+    pub fn with_time_format(mut self, time_format: impl AsRef<str>) -> MailboxSettings {
+        self.time_format = Some(time_format.as_ref().to_owned());
+        self
+    }
+}
@@ -0,0 +1,91 @@
+//! Serde models for the most commonly used Microsoft Graph resources.
+//!
+//! The models only cover the well known properties of each resource. Any property
+//! returned by the API that is not modeled, including open extensions and
+//! `@odata` annotations, is kept in the `additional_fields` map of the struct so
+//! that no data is lost when deserializing a response.
+//!
+//! # Example
+//! ```rust,ignore
+//! use graph_rs_sdk::*;
+//! use graph_rs_sdk::types::User;
+//!
+//! #[tokio::main]
+//! async fn main() -> GraphResult<()> {
+//!     let client = Graph::new("ACCESS_TOKEN");
+//!
+//!     let user: User = client.me()
+//!         .get_user()
+//!         .send()
+//!         .await?
+//!         .json()
+//!         .await?;
+//!
+//!     println!("{:#?}", user.display_name);
+//!     Ok(())
+//! }
+//! ```
+
+mod application;
+mod async_job;
+mod calendar;
+mod change_notification;
+mod channel;
+mod chat_message;
+mod collection;
+mod common;
+mod contact;
+mod drive_item;
+mod ediscovery;
+mod event;
+mod group;
+mod insights;
+mod invitation;
+mod list_item;
+mod mail;
+mod mail_folder;
+mod mailbox_settings;
+mod message;
+mod permission;
+mod photo;
+mod recurrence;
+mod security;
+mod service_health;
+mod sign_in;
+mod site;
+mod subscription;
+mod team;
+mod todo;
+mod user;
+
+pub use application::*;
+pub use async_job::*;
+pub use calendar::*;
+pub use change_notification::*;
+pub use channel::*;
+pub use chat_message::*;
+pub use collection::*;
+pub use common::*;
+pub use contact::*;
+pub use drive_item::*;
+pub use ediscovery::*;
+pub use event::*;
+pub use group::*;
+pub use insights::*;
+pub use invitation::*;
+pub use list_item::*;
+pub use mail::*;
+pub use mail_folder::*;
+pub use mailbox_settings::*;
+pub use message::*;
+pub use permission::*;
+pub use photo::*;
+pub use recurrence::*;
+pub use security::*;
+pub use service_health::*;
+pub use sign_in::*;
+pub use site::*;
+pub use subscription::*;
+pub use team::*;
+pub use todo::*;
+pub use user::*;
@@ -0,0 +1,528 @@
+use crate::client::odata_date_time;
+use crate::types::{Identity, IdentitySet, ItemReference};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use time::OffsetDateTime;
+
+/// The type of a sharing link which determines the access given by the link.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SharingLinkType {
+    View,
+    Edit,
+    Embed,
+    BlocksDownload,
+    CreateOnly,
+    AddressBar,
+    AdminDefault,
+    UnknownFutureValue,
+}
+
+impl SharingLinkType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SharingLinkType::View => "view",
+            SharingLinkType::Edit => "edit",
+            SharingLinkType::Embed => "embed",
+            SharingLinkType::BlocksDownload => "blocksDownload",
+            SharingLinkType::CreateOnly => "createOnly",
+            SharingLinkType::AddressBar => "addressBar",
+            SharingLinkType::AdminDefault => "adminDefault",
+            SharingLinkType::UnknownFutureValue => "unknownFutureValue",
+        }
+    }
+}
+
+/// Who can use a sharing link.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SharingLinkScope {
+    /// Anyone with the link, without signing in.
+    Anonymous,
+    /// Anyone signed in to the organization of the drive.
+    Organization,
+    /// Only the people the item is already shared with or the recipients of the link.
+    Users,
+    UnknownFutureValue,
+}
+
+impl SharingLinkScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SharingLinkScope::Anonymous => "anonymous",
+            SharingLinkScope::Organization => "organization",
+            SharingLinkScope::Users => "users",
+            SharingLinkScope::UnknownFutureValue => "unknownFutureValue",
+        }
+    }
+}
+
+/// The role given to the recipients of an invitation.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PermissionRole {
+    Read,
+    Write,
+    Owner,
+}
+
+impl PermissionRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PermissionRole::Read => "read",
+            PermissionRole::Write => "write",
+            PermissionRole::Owner => "owner",
+        }
+    }
+}
+
+/// A person an item is shared with, identified by email address, alias, or the object
+/// id of a user or group.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DriveRecipient {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object_id: Option<String>,
+}
+
+impl DriveRecipient {
+    pub fn email(email: impl AsRef<str>) -> DriveRecipient {
+        DriveRecipient {
+            email: Some(email.as_ref().to_owned()),
+            ..Default::default()
+        }
+    }
+
+    pub fn object_id(object_id: impl AsRef<str>) -> DriveRecipient {
+        DriveRecipient {
+            object_id: Some(object_id.as_ref().to_owned()),
+            ..Default::default()
+        }
+    }
+}
+
+/// Request body for the createLink action on a [DriveItem](crate::types::DriveItem).
+///
+/// Without a scope the default scope of the organization is used. A password and an
+/// expiration can only be set for anonymous links and only in OneDrive personal, or if
+/// they are allowed by the policies of the organization.
+///
+/// See [Create a sharing link for a DriveItem](https://learn.microsoft.com/en-us/graph/api/driveitem-createlink)
+///
+/// # Example
+/// ```
+/// use graph_rs_sdk::types::{CreateLink, SharingLinkScope, SharingLinkType};
+///
+/// let create_link = CreateLink::new(SharingLinkType::View)
+///     .with_scope(SharingLinkScope::Anonymous)
+///     .with_password("ThisIsMyPrivatePassword");
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateLink {
+    #[serde(rename = "type")]
+    pub link_type: SharingLinkType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<SharingLinkScope>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retain_inherited_permissions: Option<bool>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recipients: Vec<DriveRecipient>,
+}
+
+impl CreateLink {
+    pub fn new(link_type: SharingLinkType) -> CreateLink {
+        CreateLink {
+            link_type,
+            scope: None,
+            expiration_date_time: None,
+            password: None,
+            retain_inherited_permissions: None,
+            recipients: Vec::new(),
+        }
+    }
+
+    pub fn with_scope(mut self, scope: SharingLinkScope) -> CreateLink {
+        self.scope = Some(scope);
+        self
+    }
+
+    /// The time the link expires after which the permission is removed.
+    pub fn with_expiration(mut self, expiration: OffsetDateTime) -> CreateLink {
+        self.expiration_date_time = Some(odata_date_time(expiration));
+        self
+    }
+
+    pub fn with_password(mut self, password: impl AsRef<str>) -> CreateLink {
+        self.password = Some(password.as_ref().to_owned());
+        self
+    }
+
+    /// Keep the permissions inherited from the parent folder when the link is the first
+    /// permission of the item. Default is false which removes the inherited permissions.
+    pub fn with_retain_inherited_permissions(mut self, retain: bool) -> CreateLink {
+        self.retain_inherited_permissions = Some(retain);
+        self
+    }
+
+    /// Only the recipients can use the link. Requires the [SharingLinkScope::Users] scope.
+    pub fn with_recipient(mut self, recipient: DriveRecipient) -> CreateLink {
+        self.recipients.push(recipient);
+        self
+    }
+}
+
+/// Request body for the invite action on a [DriveItem](crate::types::DriveItem).
+///
+/// See [Send a sharing invitation](https://learn.microsoft.com/en-us/graph/api/driveitem-invite)
+///
+/// # Example
+/// ```
+/// use graph_rs_sdk::types::{DriveItemInvite, DriveRecipient, PermissionRole};
+///
+/// let invite = DriveItemInvite::new(PermissionRole::Write)
+///     .with_recipient(DriveRecipient::email("adele@contoso.com"))
+///     .with_message("Here's the file that we're collaborating on.")
+///     .with_send_invitation(true);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DriveItemInvite {
+    #[serde(default)]
+    pub recipients: Vec<DriveRecipient>,
+    #[serde(default)]
+    pub roles: Vec<PermissionRole>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub require_sign_in: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_invitation: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retain_inherited_permissions: Option<bool>,
+}
+
+impl DriveItemInvite {
+    pub fn new(role: PermissionRole) -> DriveItemInvite {
+        DriveItemInvite {
+            roles: vec![role],
+            require_sign_in: Some(true),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_recipient(mut self, recipient: DriveRecipient) -> DriveItemInvite {
+        self.recipients.push(recipient);
+        self
+    }
+
+    /// The message included in the invitation email. At most 2000 characters.
+    pub fn with_message(mut self, message: impl AsRef<str>) -> DriveItemInvite {
+        self.message = Some(message.as_ref().to_owned());
+        self
+    }
+
+    /// Whether the recipients must sign in to view the item. Default is true.
+    pub fn with_require_sign_in(mut self, require_sign_in: bool) -> DriveItemInvite {
+        self.require_sign_in = Some(require_sign_in);
+        self
+    }
+
+    /// Send the recipients an email or post. Without an invitation the permission is
+    /// only granted.
+    pub fn with_send_invitation(mut self, send_invitation: bool) -> DriveItemInvite {
+        self.send_invitation = Some(send_invitation);
+        self
+    }
+
+    /// The time the permission expires.
+    pub fn with_expiration(mut self, expiration: OffsetDateTime) -> DriveItemInvite {
+        self.expiration_date_time = Some(odata_date_time(expiration));
+        self
+    }
+
+    pub fn with_password(mut self, password: impl AsRef<str>) -> DriveItemInvite {
+        self.password = Some(password.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_retain_inherited_permissions(mut self, retain: bool) -> DriveItemInvite {
+        self.retain_inherited_permissions = Some(retain);
+        self
+    }
+}
+
+/// The sharing link facet of a [Permission].
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SharingLink {
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub link_type: Option<SharingLinkType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<SharingLinkScope>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_html: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prevents_download: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application: Option<Identity>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+/// The invitation facet of a [Permission] created by an invite that was not redeemed.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SharingInvitation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invited_by: Option<IdentitySet>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sign_in_required: Option<bool>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+/// An identity in a [SharePointIdentitySet]. SharePoint identities also have the
+/// email and login name of site users and groups.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SharePointIdentity {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub login_name: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+/// The identities a [Permission] is granted to.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SharePointIdentitySet {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application: Option<SharePointIdentity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<SharePointIdentity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<SharePointIdentity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<SharePointIdentity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub site_user: Option<SharePointIdentity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub site_group: Option<SharePointIdentity>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+/// The kind of a [Grantee] of a [Permission].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum GranteeKind {
+    User,
+    Group,
+    Application,
+    Device,
+    SiteUser,
+    SiteGroup,
+    /// The recipient of an invitation that has not been redeemed yet.
+    Invitee,
+}
+
+/// Someone a [Permission] is granted to. See [Permission::grantees].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Grantee {
+    pub kind: GranteeKind,
+    pub id: Option<String>,
+    pub display_name: Option<String>,
+    pub email: Option<String>,
+}
+
+impl Grantee {
+    fn new(kind: GranteeKind, identity: &SharePointIdentity) -> Grantee {
+        Grantee {
+            kind,
+            id: identity.id.clone(),
+            display_name: identity.display_name.clone(),
+            email: identity.email.clone(),
+        }
+    }
+}
+
+/// A sharing permission of a drive item, list item, or site.
+///
+/// A permission is either a sharing link, in the `link` facet, or access granted
+/// directly to users and groups. Who the permission is granted to is spread over
+/// several facets that differ between OneDrive personal, OneDrive for Business, and
+/// SharePoint. Use [Permission::grantees] to get them in one list.
+///
+/// See [permission resource type](https://learn.microsoft.com/en-us/graph/api/resources/permission)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Permission {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub roles: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link: Option<SharingLink>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub granted_to_v2: Option<SharePointIdentitySet>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub granted_to_identities_v2: Vec<SharePointIdentitySet>,
+    /// Replaced by `granted_to_v2` but still returned by OneDrive personal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub granted_to: Option<SharePointIdentitySet>,
+    /// Replaced by `granted_to_identities_v2` but still returned by OneDrive personal.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub granted_to_identities: Vec<SharePointIdentitySet>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invitation: Option<SharingInvitation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inherited_from: Option<ItemReference>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub share_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_password: Option<bool>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl Permission {
+    /// Returns true if the permission is a sharing link.
+    pub fn is_sharing_link(&self) -> bool {
+        self.link.is_some()
+    }
+
+    /// Returns true if the permission is inherited from a parent folder. Inherited
+    /// permissions cannot be removed from the item itself.
+    pub fn is_inherited(&self) -> bool {
+        self.inherited_from.is_some()
+    }
+
+    pub fn has_role(&self, role: PermissionRole) -> bool {
+        self.roles
+            .iter()
+            .any(|r| r.eq_ignore_ascii_case(role.as_str()))
+    }
+
+    /// Returns true if the permission allows changing the item.
+    pub fn can_edit(&self) -> bool {
+        self.has_role(PermissionRole::Write) || self.has_role(PermissionRole::Owner)
+    }
+
+    /// The web url of a sharing link.
+    pub fn link_url(&self) -> Option<&str> {
+        self.link.as_ref()?.web_url.as_deref()
+    }
+
+    /// Everyone the permission is granted to.
+    ///
+    /// The `grantedToV2` and `grantedToIdentitiesV2` facets are used and the deprecated
+    /// `grantedTo` and `grantedToIdentities` facets only if the newer ones are missing.
+    /// A SharePoint site user or site group is only included when the identity set
+    /// does not also have the user or group. The email of an invitation that has not
+    /// been redeemed is included as a [GranteeKind::Invitee].
+    pub fn grantees(&self) -> Vec<Grantee> {
+        let mut identity_sets: Vec<&SharePointIdentitySet> = self
+            .granted_to_v2
+            .iter()
+            .chain(self.granted_to_identities_v2.iter())
+            .collect();
+        if identity_sets.is_empty() {
+            identity_sets = self
+                .granted_to
+                .iter()
+                .chain(self.granted_to_identities.iter())
+                .collect();
+        }
+
+        let mut grantees = Vec::new();
+        for identity_set in identity_sets {
+            let identities = [
+                (GranteeKind::User, identity_set.user.as_ref()),
+                (GranteeKind::Group, identity_set.group.as_ref()),
+                (GranteeKind::Application, identity_set.application.as_ref()),
+                (GranteeKind::Device, identity_set.device.as_ref()),
+                (
+                    GranteeKind::SiteUser,
+                    identity_set
+                        .site_user
+                        .as_ref()
+                        .filter(|_| identity_set.user.is_none()),
+                ),
+                (
+                    GranteeKind::SiteGroup,
+                    identity_set
+                        .site_group
+                        .as_ref()
+                        .filter(|_| identity_set.group.is_none()),
+                ),
+            ];
+            for (kind, identity) in identities
+                .into_iter()
+                .filter_map(|(kind, identity)| identity.map(|identity| (kind, identity)))
+            {
+                let grantee = Grantee::new(kind, identity);
+                if !grantees.contains(&grantee) {
+                    grantees.push(grantee);
+                }
+            }
+        }
+
+        if let Some(email) = self.invitation.as_ref().and_then(|i| i.email.as_ref()) {
+            let granted = grantees.iter().any(|grantee| {
+                grantee
+                    .email
+                    .as_ref()
+                    .is_some_and(|e| e.eq_ignore_ascii_case(email))
+            });
+            if !granted {
+                grantees.push(Grantee {
+                    kind: GranteeKind::Invitee,
+                    id: None,
+                    display_name: None,
+                    email: Some(email.clone()),
+                });
+            }
+        }
+
+        grantees
+    }
+}
+
+/// Request body for the revokeGrants action on a sharing link [Permission].
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RevokeGrants {
+    #[serde(default)]
+    pub grantees: Vec<DriveRecipient>,
+}
+
+impl RevokeGrants {
+    pub fn new(grantees: Vec<DriveRecipient>) -> RevokeGrants {
+        RevokeGrants { grantees }
+    }
+}
@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// The sizes in which the photo of a user or group is available. The photo may not
+/// be available in every size if the original photo is smaller than the size.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum PhotoSize {
+    Size48x48,
+    Size64x64,
+    Size96x96,
+    Size120x120,
+    Size240x240,
+    Size360x360,
+    Size432x432,
+    Size504x504,
+    Size648x648,
+}
+
+impl PhotoSize {
+    /// The id of the photo of this size such as `48x48`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PhotoSize::Size48x48 => "48x48",
+            PhotoSize::Size64x64 => "64x64",
+            PhotoSize::Size96x96 => "96x96",
+            PhotoSize::Size120x120 => "120x120",
+            PhotoSize::Size240x240 => "240x240",
+            PhotoSize::Size360x360 => "360x360",
+            PhotoSize::Size432x432 => "432x432",
+            PhotoSize::Size504x504 => "504x504",
+            PhotoSize::Size648x648 => "648x648",
+        }
+    }
+}
+
+/// The metadata of the photo of a user or group.
+///
+/// See [profilePhoto resource type](https://learn.microsoft.com/en-us/graph/api/resources/profilephoto)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfilePhoto {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<i32>,
+    #[serde(
+        rename = "@odata.mediaContentType",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub media_content_type: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+/// The binary content of a photo and its media type such as `image/jpeg`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Photo {
+    pub content_type: String,
+    pub content: Vec<u8>,
+}
+
+impl Photo {
+    pub fn new(content_type: impl AsRef<str>, content: impl Into<Vec<u8>>) -> Photo {
+        Photo {
+            content_type: content_type.as_ref().to_owned(),
+            content: content.into(),
+        }
+    }
+
+    pub fn jpeg(content: impl Into<Vec<u8>>) -> Photo {
+        Photo::new("image/jpeg", content)
+    }
+
+    pub fn png(content: impl Into<Vec<u8>>) -> Photo {
+        Photo::new("image/png", content)
+    }
+}
@@ -0,0 +1,227 @@
+use serde::{Deserialize, Serialize};
+
+/// The day of the week.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DayOfWeek {
+    Sunday,
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+}
+
+/// The instance of the allowed days of the week a relative recurrence falls on.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WeekIndex {
+    First,
+    Second,
+    Third,
+    Fourth,
+    Last,
+}
+
+/// The type of a [RecurrencePattern].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RecurrencePatternType {
+    Daily,
+    Weekly,
+    AbsoluteMonthly,
+    RelativeMonthly,
+    AbsoluteYearly,
+    RelativeYearly,
+}
+
+/// The type of a [RecurrenceRange].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RecurrenceRangeType {
+    EndDate,
+    NoEnd,
+    Numbered,
+}
+
+/// The frequency of a recurrence.
+///
+/// Use one of the constructors to get a pattern with only the properties that
+/// are valid for the given pattern type.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecurrencePattern {
+    #[serde(rename = "type")]
+    pub pattern_type: RecurrencePatternType,
+    pub interval: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub month: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub day_of_month: Option<i32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub days_of_week: Vec<DayOfWeek>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_day_of_week: Option<DayOfWeek>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<WeekIndex>,
+}
+
+impl RecurrencePattern {
+    fn new(pattern_type: RecurrencePatternType, interval: i32) -> RecurrencePattern {
+        RecurrencePattern {
+            pattern_type,
+            interval,
+            month: None,
+            day_of_month: None,
+            days_of_week: Vec::new(),
+            first_day_of_week: None,
+            index: None,
+        }
+    }
+
+    /// Repeats every `interval` days.
+    pub fn daily(interval: i32) -> RecurrencePattern {
+        RecurrencePattern::new(RecurrencePatternType::Daily, interval)
+    }
+
+    /// Repeats on the given days of the week every `interval` weeks.
+    pub fn weekly<I: IntoIterator<Item = DayOfWeek>>(
+        interval: i32,
+        days_of_week: I,
+    ) -> RecurrencePattern {
+        let mut pattern = RecurrencePattern::new(RecurrencePatternType::Weekly, interval);
+        pattern.days_of_week = days_of_week.into_iter().collect();
+        pattern
+    }
+
+    /// Repeats on the given day of the month every `interval` months.
+    pub fn absolute_monthly(interval: i32, day_of_month: i32) -> RecurrencePattern {
+        let mut pattern = RecurrencePattern::new(RecurrencePatternType::AbsoluteMonthly, interval);
+        pattern.day_of_month = Some(day_of_month);
+        pattern
+    }
+
+    /// Repeats on the given day(s) of the week in the week of the month given by
+    /// `index` every `interval` months, such as the second Thursday of the month.
+    pub fn relative_monthly<I: IntoIterator<Item = DayOfWeek>>(
+        interval: i32,
+        days_of_week: I,
+        index: WeekIndex,
+    ) -> RecurrencePattern {
+        let mut pattern = RecurrencePattern::new(RecurrencePatternType::RelativeMonthly, interval);
+        pattern.days_of_week = days_of_week.into_iter().collect();
+        pattern.index = Some(index);
+        pattern
+    }
+
+    /// Repeats on the given day of the given month every `interval` years.
+    pub fn absolute_yearly(interval: i32, day_of_month: i32, month: i32) -> RecurrencePattern {
+        let mut pattern = RecurrencePattern::new(RecurrencePatternType::AbsoluteYearly, interval);
+        pattern.day_of_month = Some(day_of_month);
+        pattern.month = Some(month);
+        pattern
+    }
+
+    /// Repeats on the given day(s) of the week in the week of the given month
+    /// given by `index` every `interval` years.
+    pub fn relative_yearly<I: IntoIterator<Item = DayOfWeek>>(
+        interval: i32,
+        days_of_week: I,
+        index: WeekIndex,
+        month: i32,
+    ) -> RecurrencePattern {
+        let mut pattern = RecurrencePattern::new(RecurrencePatternType::RelativeYearly, interval);
+        pattern.days_of_week = days_of_week.into_iter().collect();
+        pattern.index = Some(index);
+        pattern.month = Some(month);
+        pattern
+    }
+
+    pub fn with_first_day_of_week(mut self, first_day_of_week: DayOfWeek) -> RecurrencePattern {
+        self.first_day_of_week = Some(first_day_of_week);
+        self
+    }
+}
+
+/// The duration of a recurrence.
+///
+/// Dates are in the format `yyyy-MM-dd`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecurrenceRange {
+    #[serde(rename = "type")]
+    pub range_type: RecurrenceRangeType,
+    pub start_date: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number_of_occurrences: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurrence_time_zone: Option<String>,
+}
+
+impl RecurrenceRange {
+    /// Repeats on all days that fit the pattern between the start and end date.
+    pub fn end_date(start_date: impl AsRef<str>, end_date: impl AsRef<str>) -> RecurrenceRange {
+        RecurrenceRange {
+            range_type: RecurrenceRangeType::EndDate,
+            start_date: start_date.as_ref().to_owned(),
+            end_date: Some(end_date.as_ref().to_owned()),
+            number_of_occurrences: None,
+            recurrence_time_zone: None,
+        }
+    }
+
+    /// Repeats on all days that fit the pattern starting at the start date.
+    pub fn no_end(start_date: impl AsRef<str>) -> RecurrenceRange {
+        RecurrenceRange {
+            range_type: RecurrenceRangeType::NoEnd,
+            start_date: start_date.as_ref().to_owned(),
+            end_date: None,
+            number_of_occurrences: None,
+            recurrence_time_zone: None,
+        }
+    }
+
+    /// Repeats a fixed number of times starting at the start date.
+    pub fn numbered(start_date: impl AsRef<str>, number_of_occurrences: i32) -> RecurrenceRange {
+        RecurrenceRange {
+            range_type: RecurrenceRangeType::Numbered,
+            start_date: start_date.as_ref().to_owned(),
+            end_date: None,
+            number_of_occurrences: Some(number_of_occurrences),
+            recurrence_time_zone: None,
+        }
+    }
+
+    pub fn with_time_zone(mut self, time_zone: impl AsRef<str>) -> RecurrenceRange {
+        self.recurrence_time_zone = Some(time_zone.as_ref().to_owned());
+        self
+    }
+}
+
+/// The recurrence pattern and range of a recurring event or task.
+///
+/// # Example
+/// ```
+/// use graph_rs_sdk::types::{DayOfWeek, PatternedRecurrence, RecurrencePattern, RecurrenceRange};
+///
+/// // Every other Monday and Wednesday for ten occurrences.
+/// let recurrence = PatternedRecurrence::new(
+///     RecurrencePattern::weekly(2, [DayOfWeek::Monday, DayOfWeek::Wednesday]),
+///     RecurrenceRange::numbered("2024-01-01", 10),
+/// );
+/// # assert_eq!(recurrence.range.number_of_occurrences, Some(10));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PatternedRecurrence {
+    pub pattern: RecurrencePattern,
+    pub range: RecurrenceRange,
+}
+
+impl PatternedRecurrence {
+    pub fn new(pattern: RecurrencePattern, range: RecurrenceRange) -> PatternedRecurrence {
+        PatternedRecurrence { pattern, range }
+    }
+}
@@ -0,0 +1,325 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// The severity of a security alert or incident.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AlertSeverity {
+    Unknown,
+    Informational,
+    Low,
+    Medium,
+    High,
+    UnknownFutureValue,
+}
+
+impl AlertSeverity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AlertSeverity::Unknown => "unknown",
+            AlertSeverity::Informational => "informational",
+            AlertSeverity::Low => "low",
+            AlertSeverity::Medium => "medium",
+            AlertSeverity::High => "high",
+            AlertSeverity::UnknownFutureValue => "unknownFutureValue",
+        }
+    }
+}
+
+/// The status of a security alert.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AlertStatus {
+    Unknown,
+    New,
+    InProgress,
+    Resolved,
+    UnknownFutureValue,
+}
+
+impl AlertStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AlertStatus::Unknown => "unknown",
+            AlertStatus::New => "new",
+            AlertStatus::InProgress => "inProgress",
+            AlertStatus::Resolved => "resolved",
+            AlertStatus::UnknownFutureValue => "unknownFutureValue",
+        }
+    }
+}
+
+/// The status of a security incident.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IncidentStatus {
+    Active,
+    Resolved,
+    InProgress,
+    Redirected,
+    UnknownFutureValue,
+    AwaitingAction,
+}
+
+impl IncidentStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IncidentStatus::Active => "active",
+            IncidentStatus::Resolved => "resolved",
+            IncidentStatus::InProgress => "inProgress",
+            IncidentStatus::Redirected => "redirected",
+            IncidentStatus::UnknownFutureValue => "unknownFutureValue",
+            IncidentStatus::AwaitingAction => "awaitingAction",
+        }
+    }
+}
+
+/// Whether a security alert or incident is a true or false positive.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AlertClassification {
+    Unknown,
+    FalsePositive,
+    TruePositive,
+    InformationalExpectedActivity,
+    UnknownFutureValue,
+}
+
+impl AlertClassification {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AlertClassification::Unknown => "unknown",
+            AlertClassification::FalsePositive => "falsePositive",
+            AlertClassification::TruePositive => "truePositive",
+            AlertClassification::InformationalExpectedActivity => "informationalExpectedActivity",
+            AlertClassification::UnknownFutureValue => "unknownFutureValue",
+        }
+    }
+}
+
+/// The result of the investigation of a security alert or incident.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AlertDetermination {
+    Unknown,
+    Apt,
+    Malware,
+    SecurityPersonnel,
+    SecurityTesting,
+    UnwantedSoftware,
+    Other,
+    MultiStagedAttack,
+    CompromisedAccount,
+    Phishing,
+    MaliciousUserActivity,
+    NotMalicious,
+    NotEnoughDataToValidate,
+    ConfirmedUserActivity,
+    LineOfBusinessApplication,
+    UnknownFutureValue,
+}
+
+/// A security alert raised by a Microsoft security product such as Microsoft
+/// Defender for Endpoint.
+///
+/// See [alert resource type](https://learn.microsoft.com/en-us/graph/api/resources/security-alert)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Alert {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_alert_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub incident_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<AlertSeverity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<AlertStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub classification: Option<AlertClassification>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub determination: Option<AlertDetermination>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_source: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detection_source: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assigned_to: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alert_web_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub incident_web_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tenant_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_update_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_activity_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_activity_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolved_date_time: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+/// A collection of correlated alerts that make up an attack.
+///
+/// See [incident resource type](https://learn.microsoft.com/en-us/graph/api/resources/security-incident)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Incident {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<AlertSeverity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<IncidentStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub classification: Option<AlertClassification>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub determination: Option<AlertDetermination>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assigned_to: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub custom_tags: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub incident_web_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tenant_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_update_date_time: Option<String>,
+    /// Only returned when the alerts are expanded.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub alerts: Vec<Alert>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+/// The body of a request updating the status, assignment, or investigation result
+/// of an alert. Only the properties that are set are updated.
+///
+/// # Example
+/// ```rust
+/// use graph_rs_sdk::types::{AlertClassification, AlertDetermination, AlertStatus, AlertUpdate};
+///
+/// let update = AlertUpdate::new()
+///     .status(AlertStatus::Resolved)
+///     .classification(AlertClassification::FalsePositive)
+///     .determination(AlertDetermination::NotMalicious);
+///
+/// assert_eq!(
+///     serde_json::json!({
+///         "status": "resolved",
+///         "classification": "falsePositive",
+///         "determination": "notMalicious"
+///     }),
+///     serde_json::to_value(&update).unwrap()
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<AlertStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub classification: Option<AlertClassification>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub determination: Option<AlertDetermination>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assigned_to: Option<String>,
+}
+
+impl AlertUpdate {
+    pub fn new() -> AlertUpdate {
+        AlertUpdate::default()
+    }
+
+    pub fn status(mut self, status: AlertStatus) -> AlertUpdate {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn classification(mut self, classification: AlertClassification) -> AlertUpdate {
+        self.classification = Some(classification);
+        self
+    }
+
+    pub fn determination(mut self, determination: AlertDetermination) -> AlertUpdate {
+        self.determination = Some(determination);
+        self
+    }
+
+    /// The user principal name of the owner of the alert.
+    pub fn assigned_to(mut self, assigned_to: impl AsRef<str>) -> AlertUpdate {
+        self.assigned_to = Some(assigned_to.as_ref().to_owned());
+        self
+    }
+}
+
+/// The body of a request updating the status, assignment, or investigation result
+/// of an incident. Only the properties that are set are updated.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IncidentUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<IncidentStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub classification: Option<AlertClassification>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub determination: Option<AlertDetermination>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assigned_to: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_tags: Option<Vec<String>>,
+}
+
+impl IncidentUpdate {
+    pub fn new() -> IncidentUpdate {
+        IncidentUpdate::default()
+    }
+
+    pub fn status(mut self, status: IncidentStatus) -> IncidentUpdate {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn classification(mut self, classification: AlertClassification) -> IncidentUpdate {
+        self.classification = Some(classification);
+        self
+    }
+
+    pub fn determination(mut self, determination: AlertDetermination) -> IncidentUpdate {
+        self.determination = Some(determination);
+        self
+    }
+
+    /// The user principal name of the owner of the incident.
+    pub fn assigned_to(mut self, assigned_to: impl AsRef<str>) -> IncidentUpdate {
+        self.assigned_to = Some(assigned_to.as_ref().to_owned());
+        self
+    }
+
+    /// Replaces the custom tags of the incident.
+    pub fn custom_tags<T: ToString, I: IntoIterator<Item = T>>(
+        mut self,
+        tags: I,
+    ) -> IncidentUpdate {
+        self.custom_tags = Some(tags.into_iter().map(|tag| tag.to_string()).collect());
+        self
+    }
+}
@@ -0,0 +1,235 @@
+use crate::types::ItemBody;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// The status of a Microsoft 365 service or of a service health issue.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ServiceHealthStatus {
+    ServiceOperational,
+    Investigating,
+    RestoringService,
+    VerifyingService,
+    ServiceRestored,
+    PostIncidentReviewPublished,
+    ServiceDegradation,
+    ServiceInterruption,
+    ExtendedRecovery,
+    FalsePositive,
+    InvestigationSuspended,
+    Resolved,
+    MitigatedExternal,
+    Mitigated,
+    ResolvedExternal,
+    Confirmed,
+    Reported,
+    UnknownFutureValue,
+}
+
+impl ServiceHealthStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ServiceHealthStatus::ServiceOperational => "serviceOperational",
+            ServiceHealthStatus::Investigating => "investigating",
+            ServiceHealthStatus::RestoringService => "restoringService",
+            ServiceHealthStatus::VerifyingService => "verifyingService",
+            ServiceHealthStatus::ServiceRestored => "serviceRestored",
+            ServiceHealthStatus::PostIncidentReviewPublished => "postIncidentReviewPublished",
+            ServiceHealthStatus::ServiceDegradation => "serviceDegradation",
+            ServiceHealthStatus::ServiceInterruption => "serviceInterruption",
+            ServiceHealthStatus::ExtendedRecovery => "extendedRecovery",
+            ServiceHealthStatus::FalsePositive => "falsePositive",
+            ServiceHealthStatus::InvestigationSuspended => "investigationSuspended",
+            ServiceHealthStatus::Resolved => "resolved",
+            ServiceHealthStatus::MitigatedExternal => "mitigatedExternal",
+            ServiceHealthStatus::Mitigated => "mitigated",
+            ServiceHealthStatus::ResolvedExternal => "resolvedExternal",
+            ServiceHealthStatus::Confirmed => "confirmed",
+            ServiceHealthStatus::Reported => "reported",
+            ServiceHealthStatus::UnknownFutureValue => "unknownFutureValue",
+        }
+    }
+
+    /// Returns true if the service is operating normally or has recovered.
+    pub fn is_healthy(&self) -> bool {
+        matches!(
+            self,
+            ServiceHealthStatus::ServiceOperational
+                | ServiceHealthStatus::ServiceRestored
+                | ServiceHealthStatus::PostIncidentReviewPublished
+                | ServiceHealthStatus::FalsePositive
+                | ServiceHealthStatus::Resolved
+                | ServiceHealthStatus::ResolvedExternal
+        )
+    }
+}
+
+/// Whether a service health issue is an advisory or an incident. Incidents are
+/// critical issues that usually involve noticeable user impact.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ServiceHealthClassificationType {
+    Advisory,
+    Incident,
+    UnknownFutureValue,
+}
+
+impl ServiceHealthClassificationType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ServiceHealthClassificationType::Advisory => "advisory",
+            ServiceHealthClassificationType::Incident => "incident",
+            ServiceHealthClassificationType::UnknownFutureValue => "unknownFutureValue",
+        }
+    }
+}
+
+/// The severity of a service update message.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ServiceUpdateSeverity {
+    Normal,
+    High,
+    Critical,
+    UnknownFutureValue,
+}
+
+impl ServiceUpdateSeverity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ServiceUpdateSeverity::Normal => "normal",
+            ServiceUpdateSeverity::High => "high",
+            ServiceUpdateSeverity::Critical => "critical",
+            ServiceUpdateSeverity::UnknownFutureValue => "unknownFutureValue",
+        }
+    }
+}
+
+/// The category of a service update message.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ServiceUpdateCategory {
+    PreventOrFixIssue,
+    PlanForChange,
+    StayInformed,
+    UnknownFutureValue,
+}
+
+impl ServiceUpdateCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ServiceUpdateCategory::PreventOrFixIssue => "preventOrFixIssue",
+            ServiceUpdateCategory::PlanForChange => "planForChange",
+            ServiceUpdateCategory::StayInformed => "stayInformed",
+            ServiceUpdateCategory::UnknownFutureValue => "unknownFutureValue",
+        }
+    }
+}
+
+/// The health of a Microsoft 365 service in the tenant.
+///
+/// See [serviceHealth resource type](https://learn.microsoft.com/en-us/graph/api/resources/servicehealth)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServiceHealth {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ServiceHealthStatus>,
+    /// Only returned when the issues are expanded.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub issues: Vec<ServiceHealthIssue>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+/// A post of a service health issue with the latest details of the issue.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServiceHealthIssuePost {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<ItemBody>,
+}
+
+/// An incident or advisory affecting a Microsoft 365 service.
+///
+/// See [serviceHealthIssue resource type](https://learn.microsoft.com/en-us/graph/api/resources/servicehealthissue)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServiceHealthIssue {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feature: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feature_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub classification: Option<ServiceHealthClassificationType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ServiceHealthStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impact_description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_resolved: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_modified_date_time: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub posts: Vec<ServiceHealthIssuePost>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl ServiceHealthIssue {
+    pub fn is_incident(&self) -> bool {
+        self.classification == Some(ServiceHealthClassificationType::Incident)
+    }
+}
+
+/// A message center announcement of a planned change or update to a Microsoft 365 service.
+///
+/// See [serviceUpdateMessage resource type](https://learn.microsoft.com/en-us/graph/api/resources/serviceupdatemessage)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServiceUpdateMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub services: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<ServiceUpdateCategory>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<ServiceUpdateSeverity>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_major_change: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_required_by_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_modified_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<ItemBody>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
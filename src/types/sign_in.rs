@@ -0,0 +1,145 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// The result of a sign-in. An error code of 0 is a successful sign-in.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignInStatus {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure_reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_details: Option<String>,
+}
+
+/// A sign-in of a user or application in the tenant.
+///
+/// See [signIn resource type](https://learn.microsoft.com/en-us/graph/api/resources/signin)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignIn {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_principal_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_app_used: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditional_access_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_interactive: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level_during_sign_in: Option<RiskLevel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_state: Option<RiskState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<SignInStatus>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl SignIn {
+    pub fn is_success(&self) -> bool {
+        self.status
+            .as_ref()
+            .and_then(|status| status.error_code)
+            .map(|error_code| error_code == 0)
+            .unwrap_or_default()
+    }
+}
+
+/// The risk level detected by Microsoft Entra ID Protection.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RiskLevel {
+    Low,
+    Medium,
+    High,
+    Hidden,
+    None,
+    UnknownFutureValue,
+}
+
+impl RiskLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RiskLevel::Low => "low",
+            RiskLevel::Medium => "medium",
+            RiskLevel::High => "high",
+            RiskLevel::Hidden => "hidden",
+            RiskLevel::None => "none",
+            RiskLevel::UnknownFutureValue => "unknownFutureValue",
+        }
+    }
+}
+
+/// The state of a risky user or sign-in.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RiskState {
+    None,
+    ConfirmedSafe,
+    Remediated,
+    Dismissed,
+    AtRisk,
+    ConfirmedCompromised,
+    UnknownFutureValue,
+}
+
+impl RiskState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RiskState::None => "none",
+            RiskState::ConfirmedSafe => "confirmedSafe",
+            RiskState::Remediated => "remediated",
+            RiskState::Dismissed => "dismissed",
+            RiskState::AtRisk => "atRisk",
+            RiskState::ConfirmedCompromised => "confirmedCompromised",
+            RiskState::UnknownFutureValue => "unknownFutureValue",
+        }
+    }
+}
+
+/// A user flagged as risky by Microsoft Entra ID Protection.
+///
+/// See [riskyUser resource type](https://learn.microsoft.com/en-us/graph/api/resources/riskyuser)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RiskyUser {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_principal_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_deleted: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_processing: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_level: Option<RiskLevel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_state: Option<RiskState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_detail: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_last_updated_date_time: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// A change notification subscription.
+///
+/// See [subscription resource type](https://learn.microsoft.com/en-us/graph/api/resources/subscription)
+///
+/// # Example
+/// ```
+/// use graph_rs_sdk::types::Subscription;
+///
+/// let subscription = Subscription::new(
+///     "me/mailFolders('Inbox')/messages",
+///     "created,updated",
+///     "https://webhook.contoso.com/api/notifications",
+/// )
+/// .with_lifecycle_notification_url("https://webhook.contoso.com/api/lifecycle");
+/// # assert_eq!(subscription.change_type.as_deref(), Some("created,updated"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Subscription {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource: Option<String>,
+    /// A comma separated list of the changes to subscribe to: created, updated, deleted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notification_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lifecycle_notification_url: Option<String>,
+    /// The date and time the subscription expires in ISO 8601 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration_date_time: Option<String>,
+    /// A secret sent with each change notification that is used to verify the notification
+    /// came from Microsoft Graph.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_resource_data: Option<bool>,
+    /// Base64 encoded public key certificate used to encrypt resource data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encryption_certificate: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encryption_certificate_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest_supported_tls_version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator_id: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl Subscription {
+    pub fn new(
+        resource: impl AsRef<str>,
+        change_type: impl AsRef<str>,
+        notification_url: impl AsRef<str>,
+    ) -> Subscription {
+        Subscription {
+            resource: Some(resource.as_ref().to_owned()),
+            change_type: Some(change_type.as_ref().to_owned()),
+            notification_url: Some(notification_url.as_ref().to_owned()),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_lifecycle_notification_url(mut self, url: impl AsRef<str>) -> Subscription {
+        self.lifecycle_notification_url = Some(url.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_expiration_date_time(mut self, expiration: impl AsRef<str>) -> Subscription {
+        self.expiration_date_time = Some(expiration.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_client_state(mut self, client_state: impl AsRef<str>) -> Subscription {
+        self.client_state = Some(client_state.as_ref().to_owned());
+        self
+    }
+
+    /// Include encrypted resource data in the change notifications. The certificate
+    /// is the base64 encoded public key used to encrypt the resource data.
+    pub fn with_resource_data(
+        mut self,
+        encryption_certificate: impl AsRef<str>,
+        encryption_certificate_id: impl AsRef<str>,
+    ) -> Subscription {
+        self.include_resource_data = Some(true);
+        self.encryption_certificate = Some(encryption_certificate.as_ref().to_owned());
+        self.encryption_certificate_id = Some(encryption_certificate_id.as_ref().to_owned());
+        self
+    }
+}
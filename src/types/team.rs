@@ -0,0 +1,90 @@
+use graph_http::api_impl::{AsyncOperationResource, AsyncOperationState};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// A team in Microsoft Teams.
+///
+/// See [team resource type](https://learn.microsoft.com/en-us/graph/api/resources/team)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Team {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub internal_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_archived: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date_time: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+/// The status of a long running Teams operation such as archiving or cloning a team.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TeamsAsyncOperationStatus {
+    Invalid,
+    NotStarted,
+    InProgress,
+    Succeeded,
+    Failed,
+    UnknownFutureValue,
+}
+
+/// A long running Teams operation returned by the url in the Location header of a
+/// team archive, unarchive, or clone.
+///
+/// See [teamsAsyncOperation resource type](https://learn.microsoft.com/en-us/graph/api/resources/teamsasyncoperation)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamsAsyncOperation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TeamsAsyncOperationStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attempts_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_resource_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_resource_location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_action_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<Value>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl AsyncOperationResource for TeamsAsyncOperation {
+    fn operation_state(&self) -> AsyncOperationState {
+        match self.status {
+            Some(TeamsAsyncOperationStatus::Succeeded) => AsyncOperationState::Completed,
+            Some(
+                status @ (TeamsAsyncOperationStatus::Failed | TeamsAsyncOperationStatus::Invalid),
+            ) => AsyncOperationState::Failed {
+                status: format!("{status:?}"),
+                message: self
+                    .error
+                    .as_ref()
+                    .map(|error| error.to_string())
+                    .unwrap_or_default(),
+            },
+            _ => AsyncOperationState::Running,
+        }
+    }
+}
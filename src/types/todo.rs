@@ -0,0 +1,281 @@
+use crate::types::{DateTimeTimeZone, Importance, ItemBody, PatternedRecurrence};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// The state of a [TodoTask].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TaskStatus {
+    #[default]
+    NotStarted,
+    InProgress,
+    Completed,
+    WaitingOnOthers,
+    Deferred,
+}
+
+/// The well known lists of Microsoft To Do.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WellknownListName {
+    #[default]
+    None,
+    DefaultList,
+    FlaggedEmails,
+    UnknownFutureValue,
+}
+
+/// A list in Microsoft To Do that contains [TodoTask] resources.
+///
+/// See [todoTaskList resource type](https://learn.microsoft.com/en-us/graph/api/resources/todotasklist)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TodoTaskList {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_owner: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wellknown_list_name: Option<WellknownListName>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl TodoTaskList {
+    pub fn new(display_name: impl AsRef<str>) -> TodoTaskList {
+        TodoTaskList {
+            display_name: Some(display_name.as_ref().to_owned()),
+            ..Default::default()
+        }
+    }
+}
+
+/// A subtask of a [TodoTask].
+///
+/// See [checklistItem resource type](https://learn.microsoft.com/en-us/graph/api/resources/checklistitem)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChecklistItem {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_checked: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checked_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date_time: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl ChecklistItem {
+    pub fn new(display_name: impl AsRef<str>) -> ChecklistItem {
+        ChecklistItem {
+            display_name: Some(display_name.as_ref().to_owned()),
+            ..Default::default()
+        }
+    }
+}
+
+/// An item in a partner application related to a [TodoTask] such as an email
+/// that the task was created from.
+///
+/// See [linkedResource resource type](https://learn.microsoft.com/en-us/graph/api/resources/linkedresource)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkedResource {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl LinkedResource {
+    pub fn new(
+        web_url: impl AsRef<str>,
+        application_name: impl AsRef<str>,
+        display_name: impl AsRef<str>,
+    ) -> LinkedResource {
+        LinkedResource {
+            web_url: Some(web_url.as_ref().to_owned()),
+            application_name: Some(application_name.as_ref().to_owned()),
+            display_name: Some(display_name.as_ref().to_owned()),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_external_id(mut self, external_id: impl AsRef<str>) -> LinkedResource {
+        self.external_id = Some(external_id.as_ref().to_owned());
+        self
+    }
+}
+
+/// A task in a Microsoft To Do [TodoTaskList].
+///
+/// See [todoTask resource type](https://learn.microsoft.com/en-us/graph/api/resources/todotask)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TodoTask {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<ItemBody>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TaskStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub importance: Option<Importance>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub categories: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date_time: Option<DateTimeTimeZone>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date_time: Option<DateTimeTimeZone>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_date_time: Option<DateTimeTimeZone>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_reminder_on: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reminder_date_time: Option<DateTimeTimeZone>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurrence: Option<PatternedRecurrence>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_attachments: Option<bool>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub checklist_items: Vec<ChecklistItem>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub linked_resources: Vec<LinkedResource>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_modified_date_time: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl TodoTask {
+    pub fn builder(title: impl AsRef<str>) -> TodoTaskBuilder {
+        TodoTaskBuilder::new(title)
+    }
+
+    /// Returns true if the task has been removed in a delta response.
+    pub fn is_removed(&self) -> bool {
+        self.additional_fields.contains_key("@removed")
+    }
+}
+
+/// Builder for the request body used to create or update a [TodoTask].
+///
+/// The recurrence of a task uses the same [PatternedRecurrence] as calendar events.
+/// Tasks with a recurrence must have a due date.
+///
+/// # Example
+/// ```
+/// use graph_rs_sdk::types::*;
+///
+/// let task = TodoTask::builder("Submit expense report")
+///     .with_body(ItemBody::text("Include the receipts from the conference."))
+///     .with_due(DateTimeTimeZone::new("2024-01-31T17:00:00", "Pacific Standard Time"))
+///     .with_reminder(DateTimeTimeZone::new("2024-01-31T09:00:00", "Pacific Standard Time"))
+///     .with_recurrence(PatternedRecurrence::new(
+///         RecurrencePattern::absolute_monthly(1, 31),
+///         RecurrenceRange::no_end("2024-01-31"),
+///     ))
+///     .with_checklist_item(ChecklistItem::new("Scan receipts"))
+///     .build();
+/// # assert_eq!(task.is_reminder_on, Some(true));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TodoTaskBuilder {
+    task: TodoTask,
+}
+
+impl TodoTaskBuilder {
+    pub fn new(title: impl AsRef<str>) -> TodoTaskBuilder {
+        TodoTaskBuilder {
+            task: TodoTask {
+                title: Some(title.as_ref().to_owned()),
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn with_body(mut self, body: ItemBody) -> TodoTaskBuilder {
+        self.task.body = Some(body);
+        self
+    }
+
+    pub fn with_status(mut self, status: TaskStatus) -> TodoTaskBuilder {
+        self.task.status = Some(status);
+        self
+    }
+
+    pub fn with_importance(mut self, importance: Importance) -> TodoTaskBuilder {
+        self.task.importance = Some(importance);
+        self
+    }
+
+    pub fn with_categories<T: ToString, I: IntoIterator<Item = T>>(
+        mut self,
+        categories: I,
+    ) -> TodoTaskBuilder {
+        self.task
+            .categories
+            .extend(categories.into_iter().map(|category| category.to_string()));
+        self
+    }
+
+    pub fn with_start(mut self, start: DateTimeTimeZone) -> TodoTaskBuilder {
+        self.task.start_date_time = Some(start);
+        self
+    }
+
+    pub fn with_due(mut self, due: DateTimeTimeZone) -> TodoTaskBuilder {
+        self.task.due_date_time = Some(due);
+        self
+    }
+
+    /// Turn on the reminder of the task at the given date and time.
+    pub fn with_reminder(mut self, reminder: DateTimeTimeZone) -> TodoTaskBuilder {
+        self.task.is_reminder_on = Some(true);
+        self.task.reminder_date_time = Some(reminder);
+        self
+    }
+
+    pub fn with_recurrence(mut self, recurrence: PatternedRecurrence) -> TodoTaskBuilder {
+        self.task.recurrence = Some(recurrence);
+        self
+    }
+
+    /// Checklist items can be created together with the task.
+    pub fn with_checklist_item(mut self, checklist_item: ChecklistItem) -> TodoTaskBuilder {
+        self.task.checklist_items.push(checklist_item);
+        self
+    }
+
+    /// Linked resources can be created together with the task.
+    pub fn with_linked_resource(mut self, linked_resource: LinkedResource) -> TodoTaskBuilder {
+        self.task.linked_resources.push(linked_resource);
+        self
+    }
+
+    pub fn build(self) -> TodoTask {
+        self.task
+    }
+}
@@ -0,0 +1,225 @@
+use graph_error::{GraphFailure, GraphResult};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Represents a Microsoft Entra user account.
+///
+/// See [user resource type](https://learn.microsoft.com/en-us/graph/api/resources/user)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct User {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_enabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub business_phones: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub department: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub given_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mail: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mail_nickname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mobile_phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub office_location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferred_language: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub surname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_principal_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_type: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+/// The characters that are not allowed in the mail nickname of a user or group.
+const MAIL_NICKNAME_INVALID_CHARS: &[char] = &[
+    '@', '(', ')', '\\', '[', ']', '"', ';', ':', '<', '>', ',', ' ',
+];
+
+const MAIL_NICKNAME_MAX_LEN: usize = 64;
+
+/// Check that a mail nickname is between 1 and 64 ASCII characters and does not
+/// contain any of the characters `@ ( ) \ [ ] " ; : < > ,` or spaces.
+pub fn validate_mail_nickname(mail_nickname: &str) -> GraphResult<()> {
+    if mail_nickname.is_empty() || mail_nickname.len() > MAIL_NICKNAME_MAX_LEN {
+        return Err(GraphFailure::invalid(
+            "mailNickname must be between 1 and 64 characters",
+        ));
+    }
+
+    if !mail_nickname.is_ascii() || mail_nickname.contains(MAIL_NICKNAME_INVALID_CHARS) {
+        return Err(GraphFailure::invalid(&format!(
+            "mailNickname contains invalid characters: {mail_nickname}"
+        )));
+    }
+
+    Ok(())
+}
+
+/// The password of a new user and whether it must be changed at the next sign in.
+///
+/// See [passwordProfile resource type](https://learn.microsoft.com/en-us/graph/api/resources/passwordprofile)
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PasswordProfile {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force_change_password_next_sign_in: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force_change_password_next_sign_in_with_mfa: Option<bool>,
+}
+
+impl PasswordProfile {
+    /// A password that must be changed the first time the user signs in.
+    pub fn new(password: impl AsRef<str>) -> PasswordProfile {
+        PasswordProfile {
+            password: Some(password.as_ref().to_owned()),
+            force_change_password_next_sign_in: Some(true),
+            force_change_password_next_sign_in_with_mfa: None,
+        }
+    }
+
+    pub fn with_force_change_password_next_sign_in(
+        mut self,
+        force_change: bool,
+    ) -> PasswordProfile {
+        self.force_change_password_next_sign_in = Some(force_change);
+        self
+    }
+
+    pub fn with_force_change_password_next_sign_in_with_mfa(
+        mut self,
+        force_change: bool,
+    ) -> PasswordProfile {
+        self.force_change_password_next_sign_in_with_mfa = Some(force_change);
+        self
+    }
+}
+
+impl std::fmt::Debug for PasswordProfile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PasswordProfile")
+            .field("password", &"[REDACTED]")
+            .field(
+                "force_change_password_next_sign_in",
+                &self.force_change_password_next_sign_in,
+            )
+            .field(
+                "force_change_password_next_sign_in_with_mfa",
+                &self.force_change_password_next_sign_in_with_mfa,
+            )
+            .finish()
+    }
+}
+
+/// The request body to create a user with the properties that are required by
+/// [Create user](https://learn.microsoft.com/en-us/graph/api/user-post-users).
+///
+/// # Example
+/// ```
+/// use graph_rs_sdk::types::{NewUser, PasswordProfile};
+///
+/// let user = NewUser::new(
+///     "Adele Vance",
+///     "AdeleV",
+///     "AdeleV@contoso.com",
+///     PasswordProfile::new("xWwvJ]6NMw+bWH-d"),
+/// )
+/// .unwrap()
+/// .with_given_name("Adele")
+/// .with_surname("Vance");
+/// # assert!(NewUser::new("Adele Vance", "Adele V", "AdeleV@contoso.com", PasswordProfile::default()).is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewUser {
+    pub account_enabled: bool,
+    pub display_name: String,
+    pub mail_nickname: String,
+    pub user_principal_name: String,
+    pub password_profile: PasswordProfile,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub given_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub surname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub department: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage_location: Option<String>,
+    #[serde(flatten)]
+    pub additional_fields: HashMap<String, Value>,
+}
+
+impl NewUser {
+    /// Returns an error if the mail nickname is not valid, see [validate_mail_nickname].
+    pub fn new(
+        display_name: impl AsRef<str>,
+        mail_nickname: impl AsRef<str>,
+        user_principal_name: impl AsRef<str>,
+        password_profile: PasswordProfile,
+    ) -> GraphResult<NewUser> {
+        validate_mail_nickname(mail_nickname.as_ref())?;
+        Ok(NewUser {
+            account_enabled: true,
+            display_name: display_name.as_ref().to_owned(),
+            mail_nickname: mail_nickname.as_ref().to_owned(),
+            user_principal_name: user_principal_name.as_ref().to_owned(),
+            password_profile,
+            given_name: None,
+            surname: None,
+            job_title: None,
+            department: None,
+            usage_location: None,
+            additional_fields: Default::default(),
+        })
+    }
+
+    pub fn with_account_enabled(mut self, account_enabled: bool) -> NewUser {
+        self.account_enabled = account_enabled;
+        self
+    }
+
+    pub fn with_given_name(mut self, given_name: impl AsRef<str>) -> NewUser {
+        self.given_name = Some(given_name.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_surname(mut self, surname: impl AsRef<str>) -> NewUser {
+        self.surname = Some(surname.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_job_title(mut self, job_title: impl AsRef<str>) -> NewUser {
+        self.job_title = Some(job_title.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_department(mut self, department: impl AsRef<str>) -> NewUser {
+        self.department = Some(department.as_ref().to_owned());
+        self
+    }
+
+    /// Two letter country code (ISO standard 3166). Required for users that are
+    /// assigned licenses.
+    pub fn with_usage_location(mut self, usage_location: impl AsRef<str>) -> NewUser {
+        self.usage_location = Some(usage_location.as_ref().to_owned());
+        self
+    }
+}
@@ -0,0 +1,564 @@
+use crate::api_default_imports::*;
+use crate::client::odata_string;
+use crate::types::{
+    AutomaticRepliesSetting, ChecklistItem, Collection, Contact, ContactFolder, ContactsDelta,
+    InsightResourceType, LinkedResource, MailFolder, MailboxSettings, MessageRule, NewUser,
+    OutlookCategory, Photo, PhotoSize, TaskStatus, TodoTask, TodoTaskList,
+};
+use crate::users::*;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use graph_http::traits::ResponseExt;
+use reqwest::header::{HeaderValue, CONTENT_TYPE};
+use reqwest::StatusCode;
+
+/// The error codes of a 404 Not Found response for a user or group without a photo,
+/// or without a photo of the requested size.
+const PHOTO_NOT_FOUND_CODES: [&str; 2] = ["ImageNotFound", "ErrorItemNotFound"];
+
+/// Download the content of a user or group photo. Users and groups without a photo,
+/// or without a photo of the requested size, return 404 Not Found with an
+/// `ImageNotFound` or `ErrorItemNotFound` error code which is returned as `None`.
+/// Any other error, such as 404 Not Found for a user that does not exist, is returned
+/// as an error.
+pub(crate) async fn download_photo(request: RequestHandler) -> GraphResult<Option<Photo>> {
+    let response = request.send().await?;
+    let status = response.status();
+    if !status.is_success() {
+        let error_message = response.into_graph_error_message().await?;
+        let photo_not_found = error_message
+            .code_property()
+            .is_some_and(|code| PHOTO_NOT_FOUND_CODES.contains(&code.as_str()));
+        if status == StatusCode::NOT_FOUND && photo_not_found {
+            return Ok(None);
+        }
+        return Err(GraphFailure::from_error_message(status, error_message));
+    }
+
+    // Photos without a Content-Type are JPEG which is the format the api stores photos in.
+    let content_type = match response.headers().get(CONTENT_TYPE) {
+        Some(value) => value
+            .to_str()
+            .map_err(|_| GraphFailure::invalid("Content-Type of photo"))?
+            .to_owned(),
+        None => "image/jpeg".to_owned(),
+    };
+    let content = response.bytes().await?.to_vec();
+    Ok(Some(Photo {
+        content_type,
+        content,
+    }))
+}
+
+/// Set the Content-Type of a photo upload to the media type of the photo. The media
+/// type is required by the api to store the photo in the correct format so a content
+/// type that is not a valid header value is an error.
+pub(crate) fn upload_photo(
+    update_photo_content: impl FnOnce(BodyRead) -> RequestHandler,
+    photo: &Photo,
+) -> GraphResult<RequestHandler> {
+    let content_type = HeaderValue::from_str(photo.content_type.as_str())
+        .map_err(|_| GraphFailure::invalid("Content-Type of photo"))?;
+    Ok(update_photo_content(BodyRead::from(photo.content.clone()))
+        .header(CONTENT_TYPE, content_type))
+}
+
+/// The `$search` of a people query. People are searched by name, email address, and
+/// topic with the search text quoted as required by the api.
+pub(crate) fn search_people(list_people: RequestHandler, search: &str) -> RequestHandler {
+    list_people.search(format!("\"{}\"", search.replace('"', "\\\"")))
+}
+
+impl UsersIdApiClient {
+    /// Send a message in MIME format.
+    ///
+    /// The MIME content is base64 encoded and sent with a `text/plain` content type
+    /// as required by the sendMail action.
+    pub fn send_mime_mail(&self, mime: impl AsRef<[u8]>) -> RequestHandler {
+        self.send_mail(BodyRead::from(STANDARD.encode(mime.as_ref())))
+            .header(CONTENT_TYPE, HeaderValue::from_static("text/plain"))
+    }
+
+    /// Create a draft message in the mailbox from MIME content. The draft can be sent
+    /// with the send action of the message.
+    ///
+    /// The MIME content is base64 encoded and sent with a `text/plain` content type.
+    pub fn create_mime_message(&self, mime: impl AsRef<[u8]>) -> RequestHandler {
+        self.messages().create_mime_message(mime)
+    }
+
+    /// Download the photo of the user in its original size or in the given size.
+    /// Returns `None` if the user does not have a photo.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// if let Some(photo) = client
+    ///     .user("USER_ID")
+    ///     .download_photo(Some(PhotoSize::Size96x96))
+    ///     .await?
+    /// {
+    ///     std::fs::write("./photo.jpg", photo.content)?;
+    /// }
+    /// ```
+    pub async fn download_photo(&self, size: Option<PhotoSize>) -> GraphResult<Option<Photo>> {
+        match size {
+            Some(size) => download_photo(self.photo(size.as_str()).get_photos_content()).await,
+            None => download_photo(self.get_photo_content()).await,
+        }
+    }
+
+    /// Upload the photo of the user with the Content-Type of the photo.
+    ///
+    /// Returns an error if the content type of the photo is not a valid header value.
+    pub fn upload_photo(&self, photo: &Photo) -> GraphResult<RequestHandler> {
+        upload_photo(|body| self.update_photo_content(body), photo)
+    }
+
+    /// List the people relevant to the user whose name or email address matches the
+    /// search, in order of relevance. Deserialize the results as
+    /// [Person](crate::types::Person) to get the relevance score of each person.
+    pub fn search_people(&self, search: impl AsRef<str>) -> RequestHandler {
+        search_people(self.list_people(), search.as_ref())
+    }
+}
+
+impl InsightsApiClient {
+    /// List the documents trending around the user of the given type such as
+    /// [InsightResourceType::PowerPoint]. Deserialize the results as
+    /// [Trending](crate::types::Trending).
+    pub fn list_trending_by_type(&self, resource_type: InsightResourceType) -> RequestHandler {
+        self.list_trending()
+            .filter(&[resource_type_filter(resource_type).as_str()])
+    }
+
+    /// List the documents shared with or by the user of the given type. Deserialize
+    /// the results as [SharedInsight](crate::types::SharedInsight).
+    pub fn list_shared_by_type(&self, resource_type: InsightResourceType) -> RequestHandler {
+        self.list_shared()
+            .filter(&[resource_type_filter(resource_type).as_str()])
+    }
+
+    /// List the documents most recently viewed or modified by the user of the given type.
+    /// Deserialize the results as [UsedInsight](crate::types::UsedInsight).
+    pub fn list_used_by_type(&self, resource_type: InsightResourceType) -> RequestHandler {
+        self.list_used()
+            .filter(&[resource_type_filter(resource_type).as_str()])
+    }
+}
+
+fn resource_type_filter(resource_type: InsightResourceType) -> String {
+    format!(
+        "resourceVisualization/type eq {}",
+        odata_string(resource_type.as_str())
+    )
+}
+
+impl UsersMessagesApiClient {
+    /// Create a draft message from MIME content.
+    ///
+    /// The MIME content is base64 encoded and sent with a `text/plain` content type.
+    pub fn create_mime_message(&self, mime: impl AsRef<[u8]>) -> RequestHandler {
+        self.create_messages(BodyRead::from(STANDARD.encode(mime.as_ref())))
+            .header(CONTENT_TYPE, HeaderValue::from_static("text/plain"))
+    }
+}
+
+impl CalendarViewApiClient {
+    /// List the occurrences, exceptions, and single instances of events in the
+    /// time range given by `start_date_time` and `end_date_time`.
+    ///
+    /// The date times are in ISO 8601 format such as `2024-01-15T00:00:00-08:00`.
+    /// Without an offset the values are interpreted as UTC. Use the `Prefer: outlook.timezone`
+    /// header to get the start and end of events in a specific time zone.
+    pub fn list_calendar_view_range(
+        &self,
+        start_date_time: impl AsRef<str>,
+        end_date_time: impl AsRef<str>,
+    ) -> RequestHandler {
+        self.list_calendar_view()
+            .append_query_pair("startDateTime", start_date_time.as_ref())
+            .append_query_pair("endDateTime", end_date_time.as_ref())
+    }
+}
+
+impl OnenotePagesApiClient {
+    /// Create a page from the HTML of the page.
+    ///
+    /// To create a page in a specific section use `section(id).pages()`.
+    pub fn create_page_html(&self, html: impl Into<String>) -> RequestHandler {
+        self.create_pages(BodyRead::from(html.into()))
+            .header(CONTENT_TYPE, HeaderValue::from_static("text/html"))
+    }
+
+    /// Create a page with images or files from a multipart body.
+    ///
+    /// The HTML of the page is the required `Presentation` part. Binary parts are
+    /// referenced in the HTML by the name of the part such as `<img src="name:image-block" />`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let form = MultipartFormData::new()
+    ///     .part(
+    ///         "Presentation",
+    ///         "text/html",
+    ///         r#"<html><head><title>Page</title></head><body><img src="name:image-block" /></body></html>"#,
+    ///     )
+    ///     .part("image-block", "image/png", std::fs::read("./image.png")?);
+    ///
+    /// let response = client
+    ///     .me()
+    ///     .onenote()
+    ///     .section("section-id")
+    ///     .pages()
+    ///     .create_page_multipart(form)
+    ///     .send()
+    ///     .await?;
+    /// ```
+    pub fn create_page_multipart(&self, form: MultipartFormData) -> RequestHandler {
+        self.create_pages(form)
+    }
+}
+
+impl OnenotePagesIdApiClient {
+    /// Get the HTML content of the page.
+    pub async fn get_page_html(&self) -> GraphResult<String> {
+        let response = self.get_pages_content().send().await?;
+        let response = response.error_for_graph_status().await?;
+
+        Ok(response.text().await?)
+    }
+}
+
+impl UsersApiClient {
+    /// Create a user with the required properties and password profile.
+    /// See [NewUser] for the mail nickname validation.
+    pub fn create_new_user(&self, user: &NewUser) -> RequestHandler {
+        self.create_user(user)
+    }
+}
+
+/// Get every page of a contacts delta request, starting at the delta link of a previous
+/// sync if there is one.
+async fn contacts_delta(
+    delta: RequestHandler,
+    delta_link: Option<&str>,
+) -> GraphResult<ContactsDelta> {
+    let mut paging = delta.paging().throttle_retries(3);
+    if let Some(delta_link) = delta_link {
+        paging = paging.resume_from(delta_link)?;
+    }
+
+    let mut contacts_delta = ContactsDelta::default();
+    while let Some(response) = paging.next_page::<Collection<Contact>>().await? {
+        let status = response.status();
+        let collection = response
+            .into_body()
+            .map_err(|error_message| GraphFailure::from_error_message(status, error_message))?;
+        for contact in collection.value {
+            if contact.is_removed() {
+                contacts_delta.removed.extend(contact.id);
+            } else {
+                contacts_delta.changed.push(contact);
+            }
+        }
+        if let Some(delta_link) = collection.delta_link {
+            contacts_delta.delta_link = delta_link;
+            return Ok(contacts_delta);
+        }
+    }
+
+    Err(GraphFailure::invalid(
+        "delta link in the last page of the delta response",
+    ))
+}
+
+/// Get every page of a list request.
+async fn list_all_pages<T: serde::de::DeserializeOwned>(
+    list: RequestHandler,
+) -> GraphResult<Vec<T>> {
+    let mut paging = list.paging().throttle_retries(3);
+    let mut values = Vec::new();
+    while let Some(response) = paging.next_page::<Collection<T>>().await? {
+        let status = response.status();
+        let collection = response
+            .into_body()
+            .map_err(|error_message| GraphFailure::from_error_message(status, error_message))?;
+        values.extend(collection.value);
+    }
+    Ok(values)
+}
+
+impl ContactsApiClient {
+    /// Create a contact in the folder, or in the default contacts folder when the
+    /// client is not for a contact folder.
+    pub fn create_contact(&self, contact: &Contact) -> RequestHandler {
+        self.create_contacts(contact)
+    }
+
+    /// Get the contacts that were added, updated, or deleted since the delta token
+    /// was issued. Deleted contacts have the `@removed` annotation, see [Contact::is_removed].
+    ///
+    /// The delta token is the `$deltatoken` query parameter of the `@odata.deltaLink`
+    /// returned by the last page of a previous delta request.
+    pub fn delta_from_token(&self, delta_token: impl AsRef<str>) -> RequestHandler {
+        self.delta()
+            .append_query_pair("$deltatoken", delta_token.as_ref())
+    }
+
+    /// Get all of the contacts that changed since the previous sync. Pass `None` for the
+    /// first sync, which returns every contact in the folder, and the
+    /// [ContactsDelta::delta_link] of the previous sync after that.
+    ///
+    /// Delta queries track a single folder. Use [ContactFoldersApiClient::list_all_folders]
+    /// to find the folders and sync each one separately.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let delta = client
+    ///     .me()
+    ///     .contacts()
+    ///     .sync_contacts(saved_delta_link.as_deref())
+    ///     .await?;
+    ///
+    /// for contact in delta.changed {
+    ///     crm.upsert(contact.id, contact.primary_email(), contact.addresses());
+    /// }
+    /// for id in delta.removed {
+    ///     crm.remove(id);
+    /// }
+    /// saved_delta_link = Some(delta.delta_link);
+    /// ```
+    pub async fn sync_contacts(&self, delta_link: Option<&str>) -> GraphResult<ContactsDelta> {
+        contacts_delta(self.delta(), delta_link).await
+    }
+}
+
+impl ContactFoldersApiClient {
+    /// Create a contact folder.
+    pub fn create_folder(&self, folder: &ContactFolder) -> RequestHandler {
+        self.create_contact_folders(folder)
+    }
+
+    /// Get the contact folders that were added, updated, or deleted since the delta
+    /// token was issued. Deleted folders have the `@removed` annotation, see
+    /// [ContactFolder::is_removed].
+    ///
+    /// The delta token is the `$deltatoken` query parameter of the `@odata.deltaLink`
+    /// returned by the last page of a previous delta request.
+    pub fn delta_from_token(&self, delta_token: impl AsRef<str>) -> RequestHandler {
+        self.delta()
+            .append_query_pair("$deltatoken", delta_token.as_ref())
+    }
+
+    /// List every contact folder including the child folders of each folder. A folder
+    /// is always listed after its parent folder.
+    ///
+    /// The default contacts folder is not included. Its contacts are listed directly
+    /// on the user, such as `client.me().contacts()`.
+    pub async fn list_all_folders(&self) -> GraphResult<Vec<ContactFolder>> {
+        let mut folders: Vec<ContactFolder> = list_all_pages(self.list_contact_folders()).await?;
+        let mut next = 0;
+        while let Some(folder_id) = folders.get(next).map(|folder| folder.id.clone()) {
+            if let Some(id) = folder_id {
+                let list_child_folders = self.id(id).child_folders().list_child_folders();
+                folders.extend(list_all_pages::<ContactFolder>(list_child_folders).await?);
+            }
+            next += 1;
+        }
+        Ok(folders)
+    }
+}
+
+impl MailFoldersApiClient {
+    /// List the mail folders including the folders that are hidden from mail clients.
+    pub fn list_mail_folders_including_hidden(&self) -> RequestHandler {
+        self.list_mail_folders()
+            .append_query_pair("includeHiddenFolders", "true")
+    }
+
+    /// List every mail folder including the child folders of each folder. A folder is
+    /// always listed after its parent folder.
+    ///
+    /// Set `include_hidden` to also list the folders that are hidden from mail clients,
+    /// and their child folders.
+    pub async fn list_all_folders(&self, include_hidden: bool) -> GraphResult<Vec<MailFolder>> {
+        let include_hidden = include_hidden.to_string();
+        let mut folders: Vec<MailFolder> = list_all_pages(
+            self.list_mail_folders()
+                .append_query_pair("includeHiddenFolders", include_hidden.as_str()),
+        )
+        .await?;
+
+        let mut next = 0;
+        while let Some(folder) = folders.get(next) {
+            if let Some(id) = folder.id.clone().filter(|_| folder.has_child_folders()) {
+                let list_child_folders = self
+                    .id(id)
+                    .child_folders()
+                    .list_child_folders()
+                    .append_query_pair("includeHiddenFolders", include_hidden.as_str());
+                folders.extend(list_all_pages::<MailFolder>(list_child_folders).await?);
+            }
+            next += 1;
+        }
+        Ok(folders)
+    }
+}
+
+impl MailFoldersIdApiClient {
+    /// Create an Inbox rule. Rules can only be created in the Inbox folder.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let rule = MessageRule::new("Move invoices", 1)
+    ///     .with_conditions(MessageRulePredicates::new().with_subject_contains("Invoice"))
+    ///     .with_actions(MessageRuleActions::new().with_move_to_folder(folder_id));
+    ///
+    /// let response = client
+    ///     .me()
+    ///     .mail_folder("inbox")
+    ///     .create_rule(&rule)
+    ///     .send()
+    ///     .await?;
+    /// ```
+    pub fn create_rule(&self, rule: &MessageRule) -> RequestHandler {
+        self.create_message_rules(rule)
+    }
+
+    /// Update an Inbox rule. Only the properties that are set are changed.
+    pub fn update_rule(&self, rule_id: impl AsRef<str>, rule: &MessageRule) -> RequestHandler {
+        self.update_message_rules(rule_id, rule)
+    }
+
+    /// List the Inbox rules in the order they are evaluated.
+    pub async fn list_rules(&self) -> GraphResult<Vec<MessageRule>> {
+        let rules: Collection<MessageRule> =
+            self.list_message_rules().send_json().await?.into_body();
+        let mut rules = rules.value;
+        rules.sort_by_key(|rule| rule.sequence.unwrap_or(i32::MAX));
+        Ok(rules)
+    }
+}
+
+impl OutlookApiClient {
+    /// Add a category to the master category list of the user.
+    pub fn create_category(&self, category: &OutlookCategory) -> RequestHandler {
+        self.create_master_categories(category)
+    }
+
+    /// Update a category. Only the color of a category can be changed, see
+    /// [OutlookCategory::color].
+    pub fn update_category(
+        &self,
+        category_id: impl AsRef<str>,
+        category: &OutlookCategory,
+    ) -> RequestHandler {
+        self.update_master_categories(category_id, category)
+    }
+
+    /// Get the master category list of the user.
+    pub async fn list_categories(&self) -> GraphResult<Vec<OutlookCategory>> {
+        let categories: Collection<OutlookCategory> =
+            self.list_master_categories().send_json().await?.into_body();
+        Ok(categories.value)
+    }
+}
+
+impl MailboxSettingsApiClient {
+    /// Get the mailbox settings.
+    pub async fn get_settings(&self) -> GraphResult<MailboxSettings> {
+        Ok(self.get_mailbox_settings().send_json().await?.into_body())
+    }
+
+    /// Update the mailbox settings. Only the settings that are set are changed.
+    pub fn update_settings(&self, settings: &MailboxSettings) -> RequestHandler {
+        self.update_mailbox_settings(settings)
+    }
+
+    /// Get the automatic replies setting.
+    pub async fn get_automatic_replies(&self) -> GraphResult<AutomaticRepliesSetting> {
+        Ok(self
+            .get_automatic_replies_setting()
+            .send_json()
+            .await?
+            .into_body())
+    }
+
+    /// Set the automatic replies, also known as out of office replies.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let response = client
+    ///     .me()
+    ///     .mailbox_settings()
+    ///     .set_automatic_replies(&AutomaticRepliesSetting::scheduled(
+    ///         OffsetDateTime::now_utc(),
+    ///         OffsetDateTime::now_utc() + Duration::days(7),
+    ///         "I am out of the office this week.",
+    ///     ))
+    ///     .send()
+    ///     .await?;
+    /// ```
+    pub fn set_automatic_replies(
+        &self,
+        automatic_replies: &AutomaticRepliesSetting,
+    ) -> RequestHandler {
+        self.update_settings(
+            &MailboxSettings::new().with_automatic_replies(automatic_replies.clone()),
+        )
+    }
+
+    /// Turn off automatic replies.
+    pub fn disable_automatic_replies(&self) -> RequestHandler {
+        self.set_automatic_replies(&AutomaticRepliesSetting::disabled())
+    }
+}
+
+impl TodoListsApiClient {
+    /// Create a task list.
+    pub fn create_list(&self, list: &TodoTaskList) -> RequestHandler {
+        self.create_lists(list)
+    }
+
+    /// Get the task lists that were added, updated, or deleted since the delta token
+    /// was issued.
+    ///
+    /// The delta token is the `$deltatoken` query parameter of the `@odata.deltaLink`
+    /// returned by the last page of a previous delta request.
+    pub fn delta_from_token(&self, delta_token: impl AsRef<str>) -> RequestHandler {
+        self.delta()
+            .append_query_pair("$deltatoken", delta_token.as_ref())
+    }
+}
+
+impl TodoListsTasksApiClient {
+    /// Create a task in the list. See [TodoTask::builder] for building the task.
+    pub fn create_task(&self, task: &TodoTask) -> RequestHandler {
+        self.create_tasks(task)
+    }
+
+    /// Get the tasks in the list that were added, updated, or deleted since the delta
+    /// token was issued. Deleted tasks have the `@removed` annotation, see [TodoTask::is_removed].
+    ///
+    /// The delta token is the `$deltatoken` query parameter of the `@odata.deltaLink`
+    /// returned by the last page of a previous delta request.
+    pub fn delta_from_token(&self, delta_token: impl AsRef<str>) -> RequestHandler {
+        self.delta()
+            .append_query_pair("$deltatoken", delta_token.as_ref())
+    }
+}
+
+impl TodoListsTasksIdApiClient {
+    /// Set the status of the task to completed.
+    pub fn complete_task(&self) -> RequestHandler {
+        self.update_tasks(&serde_json::json!({ "status": TaskStatus::Completed }))
+    }
+
+    pub fn add_checklist_item(&self, checklist_item: &ChecklistItem) -> RequestHandler {
+        self.create_checklist_items(checklist_item)
+    }
+
+    pub fn add_linked_resource(&self, linked_resource: &LinkedResource) -> RequestHandler {
+        self.create_linked_resources(linked_resource)
+    }
+}
@@ -24,6 +24,7 @@ mod mail_folders;
 mod mailbox_settings;
 mod managed_app_registrations;
 mod managed_devices;
+mod manual_request;
 mod member_of;
 mod onenote;
 mod onenote_notebooks;
@@ -74,6 +75,7 @@ pub use mail_folders::*;
 pub use mailbox_settings::*;
 pub use managed_app_registrations::*;
 pub use managed_devices::*;
+pub(crate) use manual_request::*;
 pub use member_of::*;
 pub use onenote::*;
 pub use onenote_notebooks::*;
@@ -0,0 +1,104 @@
+use graph_rs_sdk::{ApiVersion, Graph, GraphClientConfiguration};
+
+#[test]
+fn client_api_version() {
+    let client = Graph::from(
+        GraphClientConfiguration::new()
+            .access_token("")
+            .api_version(ApiVersion::Beta),
+    );
+
+    assert_eq!(
+        "https://graph.microsoft.com/beta/users",
+        client.users().list_user().url().as_str()
+    );
+}
+
+#[test]
+fn request_api_version_override() {
+    let client = Graph::new("");
+
+    let request = client.me().get_user().api_version(ApiVersion::Beta);
+    assert_eq!(
+        "https://graph.microsoft.com/beta/me",
+        request.url().as_str()
+    );
+
+    let client = Graph::from(
+        GraphClientConfiguration::new()
+            .access_token("")
+            .api_version(ApiVersion::Beta),
+    );
+    let request = client.users().list_user().api_version(ApiVersion::V1);
+    assert_eq!(
+        "https://graph.microsoft.com/v1.0/users",
+        request.url().as_str()
+    );
+}
+
+#[test]
+fn request_beta_and_v1() {
+    let client = Graph::new("");
+
+    let request = client.users().list_user().beta();
+    assert_eq!(
+        "https://graph.microsoft.com/beta/users",
+        request.url().as_str()
+    );
+
+    let request = client.users().list_user().beta().v1();
+    assert_eq!(
+        "https://graph.microsoft.com/v1.0/users",
+        request.url().as_str()
+    );
+}
+
+#[cfg(feature = "test-util")]
+mod paging_across_versions {
+    use graph_rs_sdk::http::Url;
+    use graph_rs_sdk::{Graph, GraphClientConfiguration};
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn next_link_to_other_version_is_followed() {
+        let mock_server = MockServer::start().await;
+        let uri = mock_server.uri();
+
+        Mock::given(method("GET"))
+            .and(path("/beta/users"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "value": [{ "id": "1" }],
+                "@odata.nextLink": format!("{uri}/v1.0/users/page2")
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1.0/users/page2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "value": [{ "id": "2" }]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let mut client = Graph::from(
+            GraphClientConfiguration::new()
+                .access_token("token")
+                .https_only(false),
+        );
+        client.use_test_endpoint(&Url::parse(format!("{uri}/v1.0").as_str()).unwrap());
+
+        let responses = client
+            .users()
+            .list_user()
+            .beta()
+            .paging()
+            .json::<serde_json::Value>()
+            .await
+            .unwrap();
+
+        assert_eq!(2, responses.len());
+        assert_eq!("2", responses[1].body().as_ref().unwrap()["value"][0]["id"]);
+    }
+}
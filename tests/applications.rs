@@ -0,0 +1,72 @@
+#![cfg(feature = "test-util")]
+
+mod common;
+
+use common::client;
+use graph_rs_sdk::http::Method;
+use graph_rs_sdk::test_util::{MockResponse, MockTransport, RequestMatcher};
+use graph_rs_sdk::types::PasswordCredential;
+
+const APPLICATION_ID: &str = "03ef14b0-ca33-4840-8f4f-d6e91916010e";
+const RESOURCE_ID: &str = "7ea9e944-71ce-443d-811c-71e8adf73b35";
+const PRINCIPAL_ID: &str = "4f2c5a8c-2a4e-4e6c-9e8e-2d1c4a1f6b3d";
+const APP_ROLE_ID: &str = "df021288-bdef-4463-88db-98f22de89214";
+
+#[tokio::test]
+async fn add_client_secret_returns_secret_text() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::POST)
+            .path(format!("/v1.0/applications/{APPLICATION_ID}/addPassword"))
+            .body_json(serde_json::json!({
+                "passwordCredential": { "displayName": "deployment" }
+            })),
+        MockResponse::json(
+            200,
+            &serde_json::json!({
+                "keyId": "f0b0b335-1d71-4883-8f98-567911bfdca6",
+                "displayName": "deployment",
+                "hint": "H~Q",
+                "secretText": "H~Q8Q~secret",
+                "endDateTime": "2026-01-01T00:00:00Z"
+            }),
+        ),
+    );
+
+    let client = client(&transport);
+    let credential = client
+        .application(APPLICATION_ID)
+        .add_client_secret(&PasswordCredential::new("deployment"))
+        .await
+        .unwrap();
+    assert_eq!(Some("H~Q8Q~secret".to_string()), credential.secret_text);
+    assert!(!format!("{credential:?}").contains("H~Q8Q~secret"));
+}
+
+#[tokio::test]
+async fn grant_app_role_uses_resource_id() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::POST)
+            .path(format!(
+                "/v1.0/servicePrincipals/{RESOURCE_ID}/appRoleAssignedTo"
+            ))
+            .body_json(serde_json::json!({
+                "principalId": PRINCIPAL_ID,
+                "resourceId": RESOURCE_ID,
+                "appRoleId": APP_ROLE_ID
+            })),
+        MockResponse::new(201),
+    );
+
+    let client = client(&transport);
+    let response = client
+        .service_principal(RESOURCE_ID)
+        .grant_app_role(PRINCIPAL_ID, APP_ROLE_ID)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(201, response.status().as_u16());
+}
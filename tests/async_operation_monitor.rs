@@ -0,0 +1,177 @@
+#![cfg(feature = "test-util")]
+
+use graph_error::{GraphFailure, GraphRsError};
+use graph_rs_sdk::http::{AsyncOperationMonitor, Method, Url};
+use graph_rs_sdk::test_util::{MockResponse, MockTransport, RequestMatcher};
+use graph_rs_sdk::types::{AsyncJobStatus, TeamsAsyncOperation, TeamsAsyncOperationStatus};
+use graph_rs_sdk::{Graph, GraphClientConfiguration};
+use http::header::{AUTHORIZATION, LOCATION};
+use http::HeaderValue;
+use std::time::Duration;
+
+const OPERATION_PATH: &str = "/v1.0/teams('team-id')/operations('operation-id')";
+
+fn configuration(transport: &MockTransport) -> GraphClientConfiguration {
+    GraphClientConfiguration::new()
+        .access_token("token")
+        .transport(transport.clone())
+}
+
+#[tokio::test]
+async fn archive_and_wait_polls_relative_location() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::POST)
+            .path("/v1.0/teams/team-id/archive")
+            .body_json(serde_json::json!({ "shouldSetSpoSiteReadOnlyForMembers": false })),
+        MockResponse::new(202).header(
+            LOCATION,
+            HeaderValue::from_static("/teams('team-id')/operations('operation-id')"),
+        ),
+    );
+    transport.mock_times(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path(OPERATION_PATH),
+        MockResponse::json(
+            200,
+            &serde_json::json!({ "id": "operation-id", "status": "inProgress" }),
+        ),
+        1,
+    );
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path(OPERATION_PATH),
+        MockResponse::json(
+            200,
+            &serde_json::json!({
+                "id": "operation-id",
+                "operationType": "archiveTeam",
+                "status": "succeeded",
+                "targetResourceId": "team-id"
+            }),
+        ),
+    );
+
+    let client = Graph::from(configuration(&transport));
+    let operation = client
+        .team("team-id")
+        .archive_and_wait(false, Duration::from_millis(10), Duration::from_secs(5))
+        .await
+        .unwrap();
+
+    assert_eq!(Some(TeamsAsyncOperationStatus::Succeeded), operation.status);
+    assert_eq!(Some("team-id".to_string()), operation.target_resource_id);
+    assert_eq!(3, transport.received_requests().len());
+}
+
+#[tokio::test]
+async fn failed_operation() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path(OPERATION_PATH),
+        MockResponse::json(
+            200,
+            &serde_json::json!({
+                "id": "operation-id",
+                "status": "failed",
+                "error": { "code": "Forbidden", "message": "Team is already archived" }
+            }),
+        ),
+    );
+
+    let client = configuration(&transport).build();
+    let endpoint = Url::parse("https://graph.microsoft.com/v1.0").unwrap();
+    let result = AsyncOperationMonitor::new(
+        &client,
+        &endpoint,
+        "/teams('team-id')/operations('operation-id')",
+    )
+    .unwrap()
+    .wait::<TeamsAsyncOperation>()
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(GraphFailure::GraphRsError(
+            GraphRsError::AsyncOperationFailed { .. }
+        ))
+    ));
+}
+
+#[tokio::test]
+async fn wait_or_cancel() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path(OPERATION_PATH),
+        MockResponse::json(
+            200,
+            &serde_json::json!({ "id": "operation-id", "status": "inProgress" }),
+        ),
+    );
+
+    let client = configuration(&transport).build();
+    let endpoint = Url::parse("https://graph.microsoft.com/v1.0").unwrap();
+    let monitor = AsyncOperationMonitor::new(
+        &client,
+        &endpoint,
+        "/teams('team-id')/operations('operation-id')",
+    )
+    .unwrap()
+    .poll_interval(Duration::from_millis(10));
+
+    let result = monitor
+        .wait_or_cancel::<TeamsAsyncOperation, _>(tokio::time::sleep(Duration::from_millis(50)))
+        .await;
+    assert!(matches!(
+        result,
+        Err(GraphFailure::GraphRsError(
+            GraphRsError::AsyncOperationCancelled
+        ))
+    ));
+
+    let result = monitor
+        .timeout(Duration::from_millis(50))
+        .wait::<TeamsAsyncOperation>()
+        .await;
+    assert!(matches!(
+        result,
+        Err(GraphFailure::GraphRsError(
+            GraphRsError::AsyncOperationTimeout { .. }
+        ))
+    ));
+}
+
+#[tokio::test]
+async fn pre_authenticated_monitor_uses_client_transport_without_token() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new().method(Method::GET).path("/monitor"),
+        MockResponse::json(
+            200,
+            &serde_json::json!({
+                "operation": "ItemCopy",
+                "resourceId": "item-id",
+                "status": "completed"
+            }),
+        ),
+    );
+
+    let client = configuration(&transport).build();
+    let monitor_url = Url::parse("https://contoso.sharepoint.com/monitor").unwrap();
+    let job_status: AsyncJobStatus = AsyncOperationMonitor::pre_authenticated(&client, monitor_url)
+        .wait()
+        .await
+        .unwrap();
+    assert_eq!(Some("item-id".to_string()), job_status.resource_id);
+
+    let requests = transport.received_requests();
+    assert_eq!(1, requests.len());
+    assert!(requests[0].headers.get(AUTHORIZATION).is_none());
+}
@@ -0,0 +1,213 @@
+#![cfg(feature = "test-util")]
+
+mod common;
+
+use common::client;
+use graph_rs_sdk::batch::{BatchOperation, BulkExecutor};
+use graph_rs_sdk::http::Method;
+use graph_rs_sdk::test_util::{MockResponse, MockTransport, RequestMatcher};
+
+#[tokio::test]
+async fn failed_steps_are_retried_individually() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::POST)
+            .path("/v1.0/$batch")
+            .body_json(serde_json::json!({
+                "requests": [
+                    { "id": "1", "method": "GET", "url": "/users/1" },
+                    { "id": "2", "method": "GET", "url": "/users/2" },
+                    {
+                        "id": "3",
+                        "method": "POST",
+                        "url": "/groups",
+                        "headers": { "Content-Type": "application/json" },
+                        "body": { "displayName": "Migrated" },
+                        "dependsOn": ["2"]
+                    }
+                ]
+            })),
+        MockResponse::json(
+            200,
+            &serde_json::json!({
+                "responses": [
+                    { "id": "1", "status": 200, "body": { "id": "1" } },
+                    { "id": "2", "status": 429, "headers": { "Retry-After": "0" } },
+                    { "id": "3", "status": 424 }
+                ]
+            }),
+        ),
+    );
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path("/v1.0/users/2"),
+        MockResponse::json(200, &serde_json::json!({ "id": "2" })),
+    );
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::POST)
+            .path("/v1.0/groups")
+            .body_json(serde_json::json!({ "displayName": "Migrated" })),
+        MockResponse::json(201, &serde_json::json!({ "id": "group-id" })),
+    );
+
+    let client = client(&transport);
+    let results = BulkExecutor::new(&client)
+        .execute(vec![
+            BatchOperation::get("1", "/users/1"),
+            BatchOperation::get("2", "users/2"),
+            BatchOperation::post(
+                "3",
+                "/groups",
+                serde_json::json!({ "displayName": "Migrated" }),
+            )
+            .depends_on("2"),
+        ])
+        .await
+        .unwrap();
+
+    let statuses: Vec<(&str, u16, usize)> = results
+        .iter()
+        .map(|result| (result.id.as_str(), result.status, result.attempts))
+        .collect();
+    assert_eq!(vec![("1", 200, 1), ("2", 200, 2), ("3", 201, 2)], statuses);
+    assert_eq!(3, transport.received_requests().len());
+}
+
+#[tokio::test]
+async fn dependency_that_still_fails_is_not_retried() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::POST)
+            .path("/v1.0/$batch"),
+        MockResponse::json(
+            200,
+            &serde_json::json!({
+                "responses": [
+                    {
+                        "id": "1",
+                        "status": 400,
+                        "body": { "error": { "code": "Request_BadRequest", "message": "Invalid value" } }
+                    },
+                    { "id": "2", "status": 424 }
+                ]
+            }),
+        ),
+    );
+
+    let client = client(&transport);
+    let results = BulkExecutor::new(&client)
+        .execute(vec![
+            BatchOperation::patch("1", "/users/1", serde_json::json!({ "jobTitle": "" })),
+            BatchOperation::delete("2", "/users/2").depends_on("1"),
+        ])
+        .await
+        .unwrap();
+
+    assert!(!results[0].is_success());
+    assert_eq!(
+        Some("Request_BadRequest".to_string()),
+        results[0]
+            .error_message()
+            .and_then(|message| message.error.code)
+    );
+    assert_eq!(424, results[1].status);
+    assert_eq!(1, transport.received_requests().len());
+}
+
+#[tokio::test]
+async fn failed_batch_fails_its_operations() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::POST)
+            .path("/v1.0/$batch")
+            .body_json(serde_json::json!({
+                "requests": [{ "id": "1", "method": "GET", "url": "/users/1" }]
+            })),
+        MockResponse::json(
+            400,
+            &serde_json::json!({ "error": { "code": "BadRequest", "message": "Invalid batch payload format." } }),
+        ),
+    );
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::POST)
+            .path("/v1.0/$batch")
+            .body_json(serde_json::json!({
+                "requests": [{ "id": "2", "method": "GET", "url": "/users/2" }]
+            })),
+        MockResponse::json(
+            200,
+            &serde_json::json!({ "responses": [{ "id": "2", "status": 200, "body": { "id": "2" } }] }),
+        ),
+    );
+
+    let client = client(&transport);
+    let results = BulkExecutor::new(&client)
+        .batch_size(1)
+        .execute(vec![
+            BatchOperation::get("1", "/users/1"),
+            BatchOperation::get("2", "/users/2"),
+        ])
+        .await
+        .unwrap();
+
+    assert!(!results[0].is_success());
+    assert_eq!(
+        Some("BadRequest".to_string()),
+        results[0]
+            .error_message()
+            .and_then(|message| message.error.code)
+    );
+    assert!(results[1].is_success());
+    assert_eq!(2, transport.received_requests().len());
+}
+
+#[tokio::test]
+async fn post_is_not_retried_after_server_error() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::POST)
+            .path("/v1.0/$batch"),
+        MockResponse::json(
+            200,
+            &serde_json::json!({
+                "responses": [
+                    { "id": "1", "status": 502 },
+                    { "id": "2", "status": 502 }
+                ]
+            }),
+        ),
+    );
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path("/v1.0/users/2"),
+        MockResponse::json(200, &serde_json::json!({ "id": "2" })),
+    );
+
+    let client = client(&transport);
+    let results = BulkExecutor::new(&client)
+        .execute(vec![
+            BatchOperation::post(
+                "1",
+                "/groups",
+                serde_json::json!({ "displayName": "Migrated" }),
+            ),
+            BatchOperation::get("2", "/users/2"),
+        ])
+        .await
+        .unwrap();
+
+    let statuses: Vec<(&str, u16, usize)> = results
+        .iter()
+        .map(|result| (result.id.as_str(), result.status, result.attempts))
+        .collect();
+    assert_eq!(vec![("1", 502, 1), ("2", 200, 2)], statuses);
+    assert_eq!(2, transport.received_requests().len());
+}
@@ -0,0 +1,147 @@
+use graph_rs_sdk::types::*;
+use graph_rs_sdk::Graph;
+
+#[test]
+fn event_builder_body() {
+    let event = Event::builder("Let's go for lunch")
+        .with_start(DateTimeTimeZone::new(
+            "2024-01-15T12:00:00",
+            "Pacific Standard Time",
+        ))
+        .with_end(DateTimeTimeZone::new(
+            "2024-01-15T14:00:00",
+            "Pacific Standard Time",
+        ))
+        .with_attendee(Attendee::new(
+            "samanthab@contoso.onmicrosoft.com",
+            AttendeeType::Required,
+        ))
+        .with_recurrence(PatternedRecurrence::new(
+            RecurrencePattern::relative_monthly(1, [DayOfWeek::Thursday], WeekIndex::Second),
+            RecurrenceRange::numbered("2024-01-11", 6),
+        ))
+        .with_online_meeting(OnlineMeetingProviderType::TeamsForBusiness)
+        .build();
+
+    assert_eq!(
+        serde_json::json!({
+            "subject": "Let's go for lunch",
+            "start": {
+                "dateTime": "2024-01-15T12:00:00",
+                "timeZone": "Pacific Standard Time"
+            },
+            "end": {
+                "dateTime": "2024-01-15T14:00:00",
+                "timeZone": "Pacific Standard Time"
+            },
+            "attendees": [
+                {
+                    "emailAddress": { "address": "samanthab@contoso.onmicrosoft.com" },
+                    "type": "required"
+                }
+            ],
+            "isOnlineMeeting": true,
+            "onlineMeetingProvider": "teamsForBusiness",
+            "recurrence": {
+                "pattern": {
+                    "type": "relativeMonthly",
+                    "interval": 1,
+                    "daysOfWeek": ["thursday"],
+                    "index": "second"
+                },
+                "range": {
+                    "type": "numbered",
+                    "startDate": "2024-01-11",
+                    "numberOfOccurrences": 6
+                }
+            }
+        }),
+        serde_json::to_value(&event).unwrap()
+    );
+}
+
+#[test]
+fn recurrence_from_json() {
+    let recurrence: PatternedRecurrence = serde_json::from_value(serde_json::json!({
+        "pattern": {
+            "type": "absoluteYearly",
+            "interval": 1,
+            "month": 3,
+            "dayOfMonth": 15,
+            "firstDayOfWeek": "sunday",
+            "index": "first"
+        },
+        "range": {
+            "type": "endDate",
+            "startDate": "2024-03-15",
+            "endDate": "2030-03-15",
+            "recurrenceTimeZone": "Pacific Standard Time",
+            "numberOfOccurrences": 0
+        }
+    }))
+    .unwrap();
+
+    assert_eq!(
+        RecurrencePatternType::AbsoluteYearly,
+        recurrence.pattern.pattern_type
+    );
+    assert_eq!(Some(15), recurrence.pattern.day_of_month);
+    assert_eq!(RecurrenceRangeType::EndDate, recurrence.range.range_type);
+    assert_eq!(Some("2030-03-15".to_string()), recurrence.range.end_date);
+}
+
+#[test]
+fn event_response_body() {
+    let response = EventResponse::new(true)
+        .with_comment("I won't be able to make it")
+        .with_proposed_new_time(TimeSlot::new(
+            DateTimeTimeZone::new("2024-01-16T12:00:00", "UTC"),
+            DateTimeTimeZone::new("2024-01-16T13:00:00", "UTC"),
+        ));
+
+    let value = serde_json::to_value(&response).unwrap();
+    assert_eq!(true, value["sendResponse"]);
+    assert_eq!("UTC", value["proposedNewTime"]["start"]["timeZone"]);
+}
+
+#[test]
+fn find_meeting_times_body() {
+    let find_meeting_times = FindMeetingTimes::new("PT1H")
+        .with_attendee(Attendee::new(
+            "alexw@contoso.onmicrosoft.com",
+            AttendeeType::Required,
+        ))
+        .with_activity_domain(ActivityDomain::Work)
+        .with_time_slot(TimeSlot::new(
+            DateTimeTimeZone::new("2024-01-15T09:00:00", "UTC"),
+            DateTimeTimeZone::new("2024-01-19T17:00:00", "UTC"),
+        ));
+
+    let value = serde_json::to_value(&find_meeting_times).unwrap();
+    assert_eq!("PT1H", value["meetingDuration"]);
+    assert_eq!("work", value["timeConstraint"]["activityDomain"]);
+    assert_eq!(
+        1,
+        value["timeConstraint"]["timeSlots"]
+            .as_array()
+            .unwrap()
+            .len()
+    );
+}
+
+#[test]
+fn calendar_view_range() {
+    let client = Graph::new("");
+
+    let url = client
+        .me()
+        .calendar_views()
+        .list_calendar_view_range("2024-01-15T00:00:00Z", "2024-01-22T00:00:00Z")
+        .url();
+
+    assert_eq!("/v1.0/me/calendarView", url.path());
+    assert_eq!(
+        Some("startDateTime=2024-01-15T00%3A00%3A00Z&endDateTime=2024-01-22T00%3A00%3A00Z"),
+        url.query()
+    );
+}
@@ -0,0 +1,115 @@
+use graph_rs_sdk::types::{ChatMessage, ChatMessageHostedContent, ChatMessageMention};
+use graph_rs_sdk::*;
+
+#[test]
+fn chat_message_with_mention_and_hosted_content() {
+    let mention =
+        ChatMessageMention::user(0, "Megan Bowen", "8ea0e38b-efb3-4757-924a-5f94061cf8c2");
+    let image = ChatMessageHostedContent::new("1", "image/png", b"content");
+
+    let message = ChatMessage::html(format!("Hi {} {}", mention.at_tag(), image.img_tag()))
+        .with_mention(mention)
+        .with_hosted_content(image);
+
+    assert_eq!(
+        serde_json::json!({
+            "body": {
+                "contentType": "html",
+                "content": "Hi <at id=\"0\">Megan Bowen</at> <img src=\"../hostedContents/1/$value\">"
+            },
+            "mentions": [
+                {
+                    "id": 0,
+                    "mentionText": "Megan Bowen",
+                    "mentioned": {
+                        "user": {
+                            "id": "8ea0e38b-efb3-4757-924a-5f94061cf8c2",
+                            "displayName": "Megan Bowen",
+                            "userIdentityType": "aadUser"
+                        }
+                    }
+                }
+            ],
+            "hostedContents": [
+                {
+                    "@microsoft.graph.temporaryId": "1",
+                    "contentBytes": "Y29udGVudA==",
+                    "contentType": "image/png"
+                }
+            ]
+        }),
+        serde_json::to_value(&message).unwrap()
+    );
+}
+
+#[test]
+fn chat_message_requests() {
+    let client = Graph::new("");
+    let message = ChatMessage::text("Hello");
+
+    assert_eq!(
+        "/v1.0/teams/1/channels/2/messages",
+        client
+            .team("1")
+            .channel("2")
+            .messages()
+            .send_chat_message(&message)
+            .url()
+            .path()
+    );
+    assert_eq!(
+        "/v1.0/teams/1/channels/2/messages/3/replies",
+        client
+            .team("1")
+            .channel("2")
+            .message("3")
+            .send_reply(&message)
+            .url()
+            .path()
+    );
+
+    let url = client.chat("1").messages().delta_from_token("token").url();
+    assert_eq!("/v1.0/chats/1/messages/delta()", url.path());
+    assert_eq!(Some("%24deltatoken=token"), url.query());
+}
+
+#[test]
+fn channel_message_requests() {
+    let client = Graph::new("");
+    let message = ChatMessage::text("Hello");
+
+    assert_eq!(
+        "/v1.0/teams/1/channels/2/messages",
+        client
+            .team("1")
+            .channel("2")
+            .send_message(&message)
+            .url()
+            .path()
+    );
+    assert_eq!(
+        "/v1.0/teams/1/primaryChannel/messages/3/replies",
+        client
+            .team("1")
+            .primary_channel()
+            .reply_to_message("3", &message)
+            .url()
+            .path()
+    );
+
+    let url = client
+        .team("1")
+        .channel("2")
+        .list_messages_with_replies()
+        .url();
+    assert_eq!("/v1.0/teams/1/channels/2/messages", url.path());
+    assert_eq!(Some("%24expand=replies"), url.query());
+
+    let url = client
+        .team("1")
+        .channel("2")
+        .messages_delta_from_token("token")
+        .url();
+    assert_eq!("/v1.0/teams/1/channels/2/messages/delta()", url.path());
+    assert_eq!(Some("%24deltatoken=token"), url.query());
+}
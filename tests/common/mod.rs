@@ -0,0 +1,11 @@
+use graph_rs_sdk::test_util::MockTransport;
+use graph_rs_sdk::{Graph, GraphClientConfiguration};
+
+/// A client that sends its requests to the mock transport.
+pub fn client(transport: &MockTransport) -> Graph {
+    Graph::from(
+        GraphClientConfiguration::new()
+            .access_token("token")
+            .transport(transport.clone()),
+    )
+}
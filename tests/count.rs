@@ -0,0 +1,80 @@
+#![cfg(feature = "test-util")]
+
+mod common;
+
+use common::client;
+use graph_rs_sdk::http::Method;
+use graph_rs_sdk::test_util::{MockResponse, MockTransport, RequestMatcher};
+use graph_rs_sdk::ODataQuery;
+use http::header::CONTENT_TYPE;
+use http::{HeaderName, HeaderValue};
+
+const CONSISTENCY_LEVEL: HeaderName = HeaderName::from_static("consistencylevel");
+
+fn count_response(count: &str) -> MockResponse {
+    MockResponse::new(200)
+        .header(CONTENT_TYPE, HeaderValue::from_static("text/plain"))
+        .body(format!("\u{feff}{count}"))
+}
+
+#[tokio::test]
+async fn count_only_adds_count_segment_and_consistency_level() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path("/v1.0/users/$count")
+            .query_param("$filter", "accountEnabled eq false")
+            .header(CONSISTENCY_LEVEL, HeaderValue::from_static("eventual")),
+        count_response("42"),
+    );
+
+    let client = client(&transport);
+    let count = client
+        .users()
+        .list_user()
+        .filter(&["accountEnabled eq false"])
+        .count_only()
+        .await
+        .unwrap();
+    assert_eq!(42, count);
+}
+
+#[tokio::test]
+async fn send_count_of_count_request() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path("/v1.0/users/$count")
+            .header(CONSISTENCY_LEVEL, HeaderValue::from_static("eventual")),
+        count_response("7"),
+    );
+
+    let client = client(&transport);
+    assert_eq!(
+        7,
+        client.users().get_users_count().send_count().await.unwrap()
+    );
+    assert_eq!(
+        7,
+        client.users().get_users_count().count_only().await.unwrap()
+    );
+
+    let requests = transport.received_requests();
+    assert_eq!("/v1.0/users/$count", requests[1].url.path());
+}
+
+#[tokio::test]
+async fn count_body_that_is_not_an_integer_is_an_error() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path("/v1.0/groups/$count"),
+        count_response("many"),
+    );
+
+    let client = client(&transport);
+    assert!(client.groups().list_group().count_only().await.is_err());
+}
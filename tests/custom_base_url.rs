@@ -0,0 +1,52 @@
+use graph_rs_sdk::http::Url;
+use graph_rs_sdk::{Graph, GraphClientConfiguration};
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn requests_use_custom_base_url() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1.0/me"))
+        .and(header("authorization", "Bearer token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "1"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = Graph::from(
+        GraphClientConfiguration::new()
+            .access_token("token")
+            .base_url(Url::parse(mock_server.uri().as_str()).unwrap())
+            .danger_accept_http(),
+    );
+    assert_eq!(format!("{}/v1.0", mock_server.uri()), client.url().as_str());
+
+    let response = client.me().get_user().send().await.unwrap();
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!("1", body["id"]);
+}
+
+#[tokio::test]
+async fn custom_base_url_host_is_not_validated() {
+    // Bind and drop a listener to get a local port that refuses connections.
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let base_url = Url::parse(format!("https://localhost:{port}").as_str()).unwrap();
+
+    let client = Graph::from(
+        GraphClientConfiguration::new()
+            .access_token("token")
+            .base_url(base_url),
+    );
+    assert_eq!(
+        format!("https://localhost:{port}/v1.0"),
+        client.url().as_str()
+    );
+    assert!(client.me().get_user().send().await.is_err());
+}
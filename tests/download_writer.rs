@@ -0,0 +1,48 @@
+#![cfg(feature = "test-util")]
+
+use graph_rs_sdk::http::{Method, ResponseExt};
+use graph_rs_sdk::test_util::{MockResponse, MockTransport, RequestMatcher};
+use graph_rs_sdk::{Graph, GraphClientConfiguration};
+use http::header::CONTENT_TYPE;
+use http::HeaderValue;
+
+#[tokio::test]
+async fn download_to_writer_returns_bytes_written_and_headers() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path("/v1.0/me/drive/items/ITEM_ID/content"),
+        MockResponse::new(200)
+            .header(
+                CONTENT_TYPE,
+                HeaderValue::from_static("application/octet-stream"),
+            )
+            .body(vec![1u8, 2, 3, 4, 5]),
+    );
+
+    let client = Graph::from(
+        GraphClientConfiguration::new()
+            .access_token("token")
+            .transport(transport.clone()),
+    );
+    let response = client
+        .me()
+        .drive()
+        .item("ITEM_ID")
+        .get_items_content()
+        .send()
+        .await
+        .unwrap();
+
+    let mut writer: Vec<u8> = Vec::new();
+    let response = response.download_to_writer(&mut writer).await.unwrap();
+
+    assert_eq!(5, *response.body());
+    assert_eq!(200, response.status().as_u16());
+    assert_eq!(
+        "application/octet-stream",
+        response.headers()[CONTENT_TYPE].to_str().unwrap()
+    );
+    assert_eq!(vec![1u8, 2, 3, 4, 5], writer);
+}
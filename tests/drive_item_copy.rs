@@ -0,0 +1,211 @@
+use graph_rs_sdk::types::{AsyncJobStatus, AsyncOperationStatus, DriveItemCopy};
+
+#[test]
+fn drive_item_copy_body() {
+    let copy = DriveItemCopy::new()
+        .with_parent("DRIVE_ID", "FOLDER_ID")
+        .with_name("copy.txt");
+
+    assert_eq!(
+        serde_json::json!({
+            "parentReference": {
+                "driveId": "DRIVE_ID",
+                "id": "FOLDER_ID"
+            },
+            "name": "copy.txt"
+        }),
+        serde_json::to_value(&copy).unwrap()
+    );
+}
+
+#[test]
+fn async_job_status_from_json() {
+    let job_status: AsyncJobStatus = serde_json::from_value(serde_json::json!({
+        "operation": "ItemCopy",
+        "percentageComplete": 100.0,
+        "resourceId": "01MOWKYVJML57KN2ANMBA3JZJS2MBGC7KM",
+        "status": "completed"
+    }))
+    .unwrap();
+
+    assert_eq!(Some(AsyncOperationStatus::Completed), job_status.status);
+    assert!(job_status.status.unwrap().is_terminal());
+
+    let job_status: AsyncJobStatus =
+        serde_json::from_value(serde_json::json!({ "status": "someNewStatus" })).unwrap();
+    assert_eq!(Some(AsyncOperationStatus::Unknown), job_status.status);
+}
+
+#[cfg(feature = "test-util")]
+mod copy_and_wait {
+    use graph_error::{GraphFailure, GraphRsError};
+    use graph_rs_sdk::http::Url;
+    use graph_rs_sdk::types::DriveItemCopy;
+    use graph_rs_sdk::{Graph, GraphClientConfiguration};
+    use std::time::Duration;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn client(mock_server: &MockServer) -> Graph {
+        let graph_client_configuration = GraphClientConfiguration::new()
+            .access_token("token")
+            .https_only(false);
+
+        let mut client = Graph::from(graph_client_configuration);
+        client.use_test_endpoint(&Url::parse(mock_server.uri().as_str()).unwrap());
+        client
+    }
+
+    #[tokio::test]
+    async fn copy_and_wait_returns_new_item() {
+        let mock_server = MockServer::start().await;
+        let uri = mock_server.uri();
+
+        Mock::given(method("POST"))
+            .and(path("/drives/DRIVE_ID/items/1/copy"))
+            .respond_with(
+                ResponseTemplate::new(202).insert_header("Location", format!("{uri}/monitor")),
+            )
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/monitor"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(serde_json::json!({
+                "operation": "ItemCopy",
+                "percentageComplete": 50.0,
+                "status": "inProgress"
+            })))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/monitor"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "operation": "ItemCopy",
+                "percentageComplete": 100.0,
+                "resourceId": "2",
+                "status": "completed"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/drives/DRIVE_ID/items/2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "2",
+                "name": "copy.txt"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = client(&mock_server).await;
+        let item = client
+            .drive("DRIVE_ID")
+            .item("1")
+            .copy_and_wait(
+                &DriveItemCopy::new().with_name("copy.txt"),
+                Duration::from_millis(10),
+                Duration::from_secs(5),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(Some("2".to_string()), item.id);
+        assert_eq!(Some("copy.txt".to_string()), item.name);
+    }
+
+    #[tokio::test]
+    async fn copy_and_wait_to_another_drive() {
+        let mock_server = MockServer::start().await;
+        let uri = mock_server.uri();
+
+        Mock::given(method("POST"))
+            .and(path("/drives/DRIVE_ID/items/1/copy"))
+            .respond_with(
+                ResponseTemplate::new(202).insert_header("Location", format!("{uri}/monitor")),
+            )
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/monitor"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "operation": "ItemCopy",
+                "percentageComplete": 100.0,
+                "resourceId": "2",
+                "status": "completed"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/drives/OTHER_DRIVE_ID/items/2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "2",
+                "name": "copy.txt",
+                "parentReference": { "driveId": "OTHER_DRIVE_ID", "id": "FOLDER_ID" }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = client(&mock_server).await;
+        let item = client
+            .drive("DRIVE_ID")
+            .item("1")
+            .copy_and_wait(
+                &DriveItemCopy::new()
+                    .with_parent("OTHER_DRIVE_ID", "FOLDER_ID")
+                    .with_name("copy.txt"),
+                Duration::from_millis(10),
+                Duration::from_secs(5),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(Some("2".to_string()), item.id);
+    }
+
+    #[tokio::test]
+    async fn copy_and_wait_failed() {
+        let mock_server = MockServer::start().await;
+        let uri = mock_server.uri();
+
+        Mock::given(method("POST"))
+            .and(path("/drives/DRIVE_ID/items/1/copy"))
+            .respond_with(
+                ResponseTemplate::new(202).insert_header("Location", format!("{uri}/monitor")),
+            )
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/monitor"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "operation": "ItemCopy",
+                "status": "failed",
+                "statusDescription": "Name already exists"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = client(&mock_server).await;
+        let result = client
+            .drive("DRIVE_ID")
+            .item("1")
+            .copy_and_wait(
+                &DriveItemCopy::new().with_name("copy.txt"),
+                Duration::from_millis(10),
+                Duration::from_secs(5),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(GraphFailure::GraphRsError(
+                GraphRsError::AsyncOperationFailed { .. }
+            ))
+        ));
+    }
+}
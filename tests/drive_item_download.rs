@@ -0,0 +1,96 @@
+#[cfg(feature = "test-util")]
+mod download_pre_authenticated {
+    use graph_rs_sdk::http::Url;
+    use graph_rs_sdk::{Graph, GraphClientConfiguration};
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn client(mock_server: &MockServer) -> Graph {
+        let graph_client_configuration = GraphClientConfiguration::new()
+            .access_token("token")
+            .https_only(false);
+
+        let mut client = Graph::from(graph_client_configuration);
+        client.use_test_endpoint(&Url::parse(mock_server.uri().as_str()).unwrap());
+        client
+    }
+
+    #[tokio::test]
+    async fn download_url_is_sent_without_authorization() {
+        let mock_server = MockServer::start().await;
+        let uri = mock_server.uri();
+
+        Mock::given(method("GET"))
+            .and(path("/drives/DRIVE_ID/items/1"))
+            .and(query_param("$select", "id,@microsoft.graph.downloadUrl"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "1",
+                "@microsoft.graph.downloadUrl": format!("{uri}/download/1?tempauth=new")
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/download/1"))
+            .and(query_param("tempauth", "expired"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/download/1"))
+            .and(query_param("tempauth", "new"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("file content"))
+            .mount(&mock_server)
+            .await;
+
+        let client = client(&mock_server).await;
+        let response = client
+            .drive("DRIVE_ID")
+            .item("1")
+            .download_pre_authenticated(Some(format!("{uri}/download/1?tempauth=expired")))
+            .await
+            .unwrap();
+        assert_eq!("file content", response.text().await.unwrap());
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let downloads: Vec<_> = requests
+            .iter()
+            .filter(|request| request.url.path() == "/download/1")
+            .collect();
+        assert_eq!(2, downloads.len());
+        assert!(downloads
+            .iter()
+            .all(|request| !request.headers.contains_key("authorization")));
+    }
+
+    #[tokio::test]
+    async fn expired_download_url_is_refreshed_once() {
+        let mock_server = MockServer::start().await;
+        let uri = mock_server.uri();
+
+        Mock::given(method("GET"))
+            .and(path("/drives/DRIVE_ID/items/1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "1",
+                "@microsoft.graph.downloadUrl": format!("{uri}/download/1")
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/download/1"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&mock_server)
+            .await;
+
+        let client = client(&mock_server).await;
+        let result = client
+            .drive("DRIVE_ID")
+            .item("1")
+            .download_pre_authenticated(Some(format!("{uri}/download/1")))
+            .await;
+        assert!(result.is_err());
+    }
+}
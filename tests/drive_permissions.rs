@@ -0,0 +1,212 @@
+#![cfg(feature = "test-util")]
+
+mod common;
+
+use common::client;
+use graph_rs_sdk::http::Method;
+use graph_rs_sdk::test_util::{MockResponse, MockTransport, RequestMatcher};
+use graph_rs_sdk::types::{
+    CreateLink, DriveItemInvite, DriveRecipient, Grantee, GranteeKind, PermissionRole,
+    SharingLinkScope, SharingLinkType,
+};
+use graph_rs_sdk::GraphFailure;
+
+#[tokio::test]
+async fn share_link_sends_link_options() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::POST)
+            .path("/v1.0/drives/DRIVE_ID/items/ITEM_ID/createLink")
+            .body_json(serde_json::json!({
+                "type": "edit",
+                "scope": "organization",
+                "password": "secret"
+            })),
+        MockResponse::json(
+            201,
+            &serde_json::json!({
+                "id": "LINK_ID",
+                "roles": ["write"],
+                "link": {
+                    "type": "edit",
+                    "scope": "organization",
+                    "webUrl": "https://contoso.sharepoint.com/:w:/s/link"
+                }
+            }),
+        ),
+    );
+
+    let permission = client(&transport)
+        .drive("DRIVE_ID")
+        .item("ITEM_ID")
+        .share_link(
+            &CreateLink::new(SharingLinkType::Edit)
+                .with_scope(SharingLinkScope::Organization)
+                .with_password("secret"),
+        )
+        .await
+        .unwrap();
+
+    assert!(permission.is_sharing_link());
+    assert!(permission.can_edit());
+    assert_eq!(
+        Some("https://contoso.sharepoint.com/:w:/s/link"),
+        permission.link_url()
+    );
+    let link = permission.link.unwrap();
+    assert_eq!(Some(SharingLinkType::Edit), link.link_type);
+    assert_eq!(Some(SharingLinkScope::Organization), link.scope);
+}
+
+#[tokio::test]
+async fn share_with_returns_granted_permissions() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::POST)
+            .path("/v1.0/drives/DRIVE_ID/items/ITEM_ID/invite")
+            .body_json(serde_json::json!({
+                "recipients": [{ "email": "adele@contoso.com" }],
+                "roles": ["read"],
+                "requireSignIn": true,
+                "sendInvitation": false
+            })),
+        MockResponse::json(
+            200,
+            &serde_json::json!({
+                "value": [{
+                    "id": "PERMISSION_ID",
+                    "roles": ["read"],
+                    "grantedToV2": {
+                        "user": { "id": "USER_ID", "displayName": "Adele Vance" }
+                    }
+                }]
+            }),
+        ),
+    );
+
+    let permissions = client(&transport)
+        .drive("DRIVE_ID")
+        .item("ITEM_ID")
+        .share_with(
+            &DriveItemInvite::new(PermissionRole::Read)
+                .with_recipient(DriveRecipient::email("adele@contoso.com"))
+                .with_send_invitation(false),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(1, permissions.len());
+    assert!(permissions[0].has_role(PermissionRole::Read));
+    assert!(!permissions[0].can_edit());
+}
+
+#[tokio::test]
+async fn sharing_permissions_parses_grantees() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path("/v1.0/drives/DRIVE_ID/root:/Documents/report.docx:/permissions"),
+        MockResponse::json(
+            200,
+            &serde_json::json!({
+                "value": [
+                    {
+                        "id": "OWNER_ID",
+                        "roles": ["owner"],
+                        "grantedToV2": {
+                            "user": { "id": "USER_ID", "displayName": "Megan Bowen", "email": "megan@contoso.com" },
+                            "siteUser": { "id": "7", "displayName": "Megan Bowen", "loginName": "i:0#.f|membership|megan@contoso.com" }
+                        },
+                        "inheritedFrom": { "driveId": "DRIVE_ID", "id": "FOLDER_ID", "path": "/drive/root:/Documents" }
+                    },
+                    {
+                        "id": "INVITE_ID",
+                        "roles": ["write"],
+                        "grantedTo": {
+                            "user": { "id": "GUEST_ID", "displayName": "Guest" }
+                        },
+                        "invitation": { "email": "guest@fabrikam.com", "signInRequired": true }
+                    }
+                ]
+            }),
+        ),
+    );
+
+    let permissions = client(&transport)
+        .drive("DRIVE_ID")
+        .by_path("Documents/report.docx")
+        .sharing_permissions()
+        .await
+        .unwrap();
+
+    assert_eq!(2, permissions.len());
+    assert!(permissions[0].is_inherited());
+    assert!(!permissions[0].is_sharing_link());
+    assert_eq!(
+        vec![Grantee {
+            kind: GranteeKind::User,
+            id: Some("USER_ID".to_owned()),
+            display_name: Some("Megan Bowen".to_owned()),
+            email: Some("megan@contoso.com".to_owned()),
+        }],
+        permissions[0].grantees()
+    );
+
+    let grantees = permissions[1].grantees();
+    assert_eq!(2, grantees.len());
+    assert_eq!(GranteeKind::User, grantees[0].kind);
+    assert_eq!(Some("GUEST_ID"), grantees[0].id.as_deref());
+    assert_eq!(GranteeKind::Invitee, grantees[1].kind);
+    assert_eq!(Some("guest@fabrikam.com"), grantees[1].email.as_deref());
+}
+
+#[tokio::test]
+async fn revoke_permission_and_link_recipients() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::DELETE)
+            .path("/v1.0/drives/DRIVE_ID/items/ITEM_ID/permissions/LINK_ID"),
+        MockResponse::new(204),
+    );
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::DELETE)
+            .path("/v1.0/drives/DRIVE_ID/items/ITEM_ID/permissions/INHERITED_ID"),
+        MockResponse::json(
+            400,
+            &serde_json::json!({ "error": { "code": "invalidRequest", "message": "Cannot delete an inherited permission" } }),
+        ),
+    );
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::POST)
+            .path("/v1.0/drives/DRIVE_ID/items/ITEM_ID/permissions/LINK_ID/revokeGrants")
+            .body_json(serde_json::json!({
+                "grantees": [{ "email": "adele@contoso.com" }]
+            })),
+        MockResponse::json(
+            200,
+            &serde_json::json!({
+                "id": "LINK_ID",
+                "roles": ["read"],
+                "link": { "type": "view", "scope": "users", "webUrl": "https://contoso.sharepoint.com/link" }
+            }),
+        ),
+    );
+
+    let client = client(&transport);
+    let item = client.drive("DRIVE_ID").item("ITEM_ID");
+    let permission = item
+        .revoke_link_recipients("LINK_ID", vec![DriveRecipient::email("adele@contoso.com")])
+        .await
+        .unwrap();
+    assert_eq!(Some("LINK_ID"), permission.id.as_deref());
+
+    item.revoke_permission("LINK_ID").await.unwrap();
+    let error = item.revoke_permission("INHERITED_ID").await.unwrap_err();
+    assert!(matches!(error, GraphFailure::ErrorMessage(_)));
+}
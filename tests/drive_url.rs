@@ -216,3 +216,83 @@ pub fn drives_item_workbook() {
             .path()
     );
 }
+
+#[test]
+fn drive_item_by_path() {
+    let client = Graph::new("");
+
+    assert_eq!(
+        "/v1.0/me/drive/root:/Documents/Reports/Q1.xlsx:".to_string(),
+        client
+            .me()
+            .drive()
+            .by_path("Documents/Reports/Q1.xlsx")
+            .get_items()
+            .url()
+            .path()
+    );
+    assert_eq!(
+        "/v1.0/drives/T5Y6RODPNfYICbtYWrofwUGBJWnaJkNwH9x/root:/Documents/Q1%20%231%20100%25.xlsx:"
+            .to_string(),
+        client
+            .drive(RID)
+            .by_path("/Documents/Q1 #1 100%.xlsx")
+            .get_items()
+            .url()
+            .path()
+    );
+    assert_eq!(
+        "/v1.0/users/T5Y6RODPNfYICbtYWrofwUGBJWnaJkNwH9x/drive/root".to_string(),
+        client
+            .user(RID)
+            .drive()
+            .by_path("/")
+            .get_items()
+            .url()
+            .path()
+    );
+}
+
+#[test]
+fn drives_for_owner() {
+    let client = Graph::new("");
+
+    assert_eq!(
+        "/v1.0/groups/T5Y6RODPNfYICbtYWrofwUGBJWnaJkNwH9x/drive".to_string(),
+        client.drives().for_group(RID).get_drive().url().path()
+    );
+    assert_eq!(
+        "/v1.0/users/T5Y6RODPNfYICbtYWrofwUGBJWnaJkNwH9x/drive/root:/Documents/Q1.xlsx:"
+            .to_string(),
+        client
+            .drives()
+            .for_user(RID)
+            .by_path("Documents/Q1.xlsx")
+            .get_items()
+            .url()
+            .path()
+    );
+    assert_eq!(
+        "/v1.0/sites/contoso.sharepoint.com:/sites/Engineering:/drive/root".to_string(),
+        client
+            .drives()
+            .for_site("contoso.sharepoint.com:/sites/Engineering:")
+            .get_root()
+            .url()
+            .path()
+    );
+}
+
+#[test]
+fn drive_of_id_with_separator_is_percent_encoded() {
+    let client = Graph::new("");
+
+    assert_eq!(
+        "/v1.0/users/a%2Fb/drive".to_string(),
+        client.drives().for_user("a/b").get_drive().url().path()
+    );
+    assert_eq!(
+        "/v1.0/users/a%2Fb/drive/root".to_string(),
+        client.user("a/b").drive().get_root().url().path()
+    );
+}
@@ -0,0 +1,111 @@
+#![cfg(feature = "test-util")]
+
+mod common;
+
+use common::client;
+use graph_rs_sdk::http::{Method, ResponseCache};
+use graph_rs_sdk::test_util::{fixtures, MockResponse, MockTransport, RequestMatcher};
+use graph_rs_sdk::{Graph, GraphClientConfiguration};
+use http::header::{ETAG, IF_MATCH, IF_NONE_MATCH};
+use http::HeaderValue;
+
+#[tokio::test]
+async fn if_match_uses_odata_etag() {
+    let transport = MockTransport::new();
+    let path = format!("/v1.0/me/events/{}", fixtures::EVENT_ID);
+    transport.mock(
+        RequestMatcher::new().method(Method::GET).path(&path),
+        MockResponse::json(200, &fixtures::event()),
+    );
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::PATCH)
+            .path(&path)
+            .header(IF_MATCH, HeaderValue::from_static(fixtures::EVENT_ETAG)),
+        MockResponse::json(200, &fixtures::event()),
+    );
+
+    let client = client(&transport);
+    let response = client
+        .me()
+        .event(fixtures::EVENT_ID)
+        .get_events()
+        .send_json::<serde_json::Value>()
+        .await
+        .unwrap();
+    let etag = response.odata_etag().unwrap();
+    assert_eq!(fixtures::EVENT_ETAG, etag);
+
+    let response = client
+        .me()
+        .event(fixtures::EVENT_ID)
+        .update_events(&serde_json::json!({ "subject": "Lunch" }))
+        .if_match(etag)
+        .send()
+        .await
+        .unwrap();
+    assert!(response.status().is_success());
+}
+
+#[tokio::test]
+async fn precondition_failed_error() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new().method(Method::PATCH),
+        MockResponse::json(
+            412,
+            &fixtures::error(
+                "preconditionFailed",
+                "The ETag of the resource does not match",
+            ),
+        ),
+    );
+
+    let client = client(&transport);
+    let result = client
+        .me()
+        .event(fixtures::EVENT_ID)
+        .update_events(&serde_json::json!({ "subject": "Lunch" }))
+        .if_match("W/\"outdated\"")
+        .send_json::<serde_json::Value>()
+        .await;
+    assert!(result.unwrap_err().is_precondition_failed());
+}
+
+#[tokio::test]
+async fn response_cache_revalidates_with_if_none_match() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path("/v1.0/me/drive/root")
+            .header(IF_NONE_MATCH, HeaderValue::from_static("\"root-etag\"")),
+        MockResponse::new(304),
+    );
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path("/v1.0/me/drive/root"),
+        MockResponse::json(200, &serde_json::json!({ "id": "root" }))
+            .header(ETAG, HeaderValue::from_static("\"root-etag\"")),
+    );
+
+    let client = Graph::from(
+        GraphClientConfiguration::new()
+            .access_token("token")
+            .transport(transport.clone())
+            .response_cache(ResponseCache::memory()),
+    );
+
+    for _ in 0..2 {
+        let response = client.me().drive().get_root().send().await.unwrap();
+        assert_eq!(200, response.status().as_u16());
+        let body: serde_json::Value = response.json().await.unwrap();
+        assert_eq!("root", body["id"]);
+    }
+
+    let requests = transport.received_requests();
+    assert_eq!(2, requests.len());
+    assert!(requests[0].headers.get(IF_NONE_MATCH).is_none());
+    assert_eq!("\"root-etag\"", requests[1].headers[IF_NONE_MATCH]);
+}
@@ -0,0 +1,114 @@
+#![cfg(feature = "test-util")]
+
+mod common;
+
+use common::client;
+use graph_rs_sdk::drives::{ConflictPolicy, FolderSync, SyncConflict};
+use graph_rs_sdk::http::Method;
+use graph_rs_sdk::test_util::{MockResponse, MockTransport, RequestMatcher};
+use http::header::IF_MATCH;
+use http::HeaderValue;
+
+fn drive_item(id: &str, e_tag: &str) -> serde_json::Value {
+    serde_json::json!({ "id": id, "eTag": e_tag, "cTag": e_tag })
+}
+
+#[tokio::test]
+async fn upload_local_changes_and_resolve_conflicts() {
+    let local_dir = std::env::temp_dir().join("graph-rs-sdk-folder-sync-upload");
+    let _ = std::fs::remove_dir_all(&local_dir);
+    std::fs::create_dir_all(local_dir.join("sub")).unwrap();
+    std::fs::write(local_dir.join("a.txt"), "a").unwrap();
+    std::fs::write(local_dir.join("sub").join("b.txt"), "b").unwrap();
+
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path("/v1.0/drives/DRIVE_ID/root:/Documents:"),
+        MockResponse::json(200, &serde_json::json!({ "id": "ROOT" })),
+    );
+    transport.mock_times(
+        RequestMatcher::new()
+            .method(Method::PUT)
+            .path("/v1.0/drives/DRIVE_ID/root:/Documents/a.txt:/content")
+            .header(IF_MATCH, HeaderValue::from_static("a1")),
+        MockResponse::json(
+            412,
+            &serde_json::json!({ "error": { "code": "resourceModified", "message": "ETag does not match" } }),
+        ),
+        2,
+    );
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::PUT)
+            .path("/v1.0/drives/DRIVE_ID/root:/Documents/a.txt:/content")
+            .query_param("@microsoft.graph.conflictBehavior", "fail"),
+        MockResponse::json(201, &drive_item("A", "a1")),
+    );
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::PUT)
+            .path("/v1.0/drives/DRIVE_ID/root:/Documents/a.txt:/content")
+            .query_param("@microsoft.graph.conflictBehavior", "replace"),
+        MockResponse::json(200, &drive_item("A", "a2")),
+    );
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::PUT)
+            .path("/v1.0/drives/DRIVE_ID/root:/Documents/sub/b.txt:/content")
+            .query_param("@microsoft.graph.conflictBehavior", "fail"),
+        MockResponse::json(201, &drive_item("B", "b1")),
+    );
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::DELETE)
+            .path("/v1.0/drives/DRIVE_ID/items/B")
+            .header(IF_MATCH, HeaderValue::from_static("b1")),
+        MockResponse::new(204),
+    );
+
+    let client = client(&transport);
+    let mut folder_sync = FolderSync::new(&client, "DRIVE_ID", "/Documents/", &local_dir);
+    let mut report = folder_sync.upload().await.unwrap();
+    report.uploaded.sort();
+    assert_eq!(vec!["a.txt", "sub/b.txt"], report.uploaded);
+    assert_eq!(
+        Some("a1"),
+        folder_sync.state().files["a.txt"].e_tag.as_deref()
+    );
+
+    // The file changed in the drive so the local change is not uploaded.
+    std::fs::write(local_dir.join("a.txt"), "changed").unwrap();
+    std::fs::remove_file(local_dir.join("sub").join("b.txt")).unwrap();
+    let report = folder_sync.upload().await.unwrap();
+    assert!(report.uploaded.is_empty());
+    assert_eq!(vec!["sub/b.txt"], report.deleted);
+    assert_eq!(
+        vec![SyncConflict {
+            path: "a.txt".to_owned(),
+            resolution: ConflictPolicy::RemoteWins,
+            renamed_to: None,
+        }],
+        report.conflicts
+    );
+
+    let mut folder_sync = FolderSync::new(&client, "DRIVE_ID", "Documents", &local_dir)
+        .conflict_policy(ConflictPolicy::LocalWins)
+        .resume(folder_sync.state().clone());
+    let report = folder_sync.upload().await.unwrap();
+    assert_eq!(vec!["a.txt"], report.uploaded);
+    assert_eq!(ConflictPolicy::LocalWins, report.conflicts[0].resolution);
+    assert_eq!(
+        Some("a2"),
+        folder_sync.state().files["a.txt"].e_tag.as_deref()
+    );
+    assert!(!folder_sync.state().files.contains_key("sub/b.txt"));
+
+    let requests = transport.received_requests();
+    let last = requests.last().unwrap();
+    assert_eq!(Some(b"changed".to_vec()), last.body.clone());
+    assert!(last.headers.get(IF_MATCH).is_none());
+
+    let _ = std::fs::remove_dir_all(&local_dir);
+}
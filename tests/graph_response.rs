@@ -0,0 +1,113 @@
+#[cfg(feature = "test-util")]
+mod graph_response {
+    use graph_rs_sdk::error::GraphFailure;
+    use graph_rs_sdk::http::{GraphResponse, RateLimit, Url};
+    use graph_rs_sdk::types::User;
+    use graph_rs_sdk::{Graph, GraphClientConfiguration};
+    use std::time::Duration;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn client(mock_server: &MockServer) -> Graph {
+        let graph_client_configuration = GraphClientConfiguration::new()
+            .access_token("token")
+            .https_only(false);
+
+        let mut client = Graph::from(graph_client_configuration);
+        client.use_test_endpoint(&Url::parse(mock_server.uri().as_str()).unwrap());
+        client
+    }
+
+    #[tokio::test]
+    async fn send_json_keeps_response_metadata() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/users/1"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("request-id", "0f2a8ba6-2fe9-4dd0-8e7d-0d4b1c6a6cf2")
+                    .insert_header("RateLimit-Limit", "1200")
+                    .insert_header("RateLimit-Remaining", "120")
+                    .insert_header("RateLimit-Reset", "30")
+                    .insert_header("Preference-Applied", "odata.maxpagesize=10")
+                    .set_body_json(serde_json::json!({
+                        "id": "1",
+                        "displayName": "Adele Vance"
+                    })),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = client(&mock_server).await;
+        let response: GraphResponse<User> = client.user("1").get_user().send_json().await.unwrap();
+
+        assert_eq!(200, response.status().as_u16());
+        assert_eq!("/users/1", response.url().path());
+        assert_eq!(
+            Some("0f2a8ba6-2fe9-4dd0-8e7d-0d4b1c6a6cf2"),
+            response.request_id()
+        );
+        assert_eq!(
+            Some(RateLimit {
+                limit: Some(1200),
+                remaining: Some(120),
+                reset: Some(Duration::from_secs(30)),
+            }),
+            response.rate_limit()
+        );
+        assert_eq!(
+            vec!["odata.maxpagesize=10".to_string()],
+            response.preference_applied()
+        );
+        assert_eq!(None, response.retry_after());
+        assert_eq!(
+            Some("Adele Vance".to_string()),
+            response.into_body().display_name
+        );
+    }
+
+    #[tokio::test]
+    async fn send_json_error_response() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/users/2"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "error": {
+                    "code": "Request_ResourceNotFound",
+                    "message": "Resource '2' does not exist."
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = client(&mock_server).await;
+        let result = client.user("2").get_user().send_json::<User>().await;
+
+        assert!(matches!(result, Err(GraphFailure::ErrorMessage(_))));
+    }
+
+    #[tokio::test]
+    async fn send_json_no_content_response() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("PATCH"))
+            .and(path("/users/3"))
+            .respond_with(ResponseTemplate::new(204).insert_header("request-id", "request-id"))
+            .mount(&mock_server)
+            .await;
+
+        let client = client(&mock_server).await;
+        let response: GraphResponse<Option<User>> = client
+            .user("3")
+            .update_user(&serde_json::json!({ "displayName": "Adele Vance" }))
+            .send_json()
+            .await
+            .unwrap();
+
+        assert_eq!(204, response.status().as_u16());
+        assert_eq!(Some("request-id"), response.request_id());
+        assert!(response.into_body().is_none());
+    }
+}
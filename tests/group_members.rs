@@ -0,0 +1,64 @@
+#![cfg(feature = "test-util")]
+
+mod common;
+
+use common::client;
+use graph_rs_sdk::http::Method;
+use graph_rs_sdk::test_util::{MockResponse, MockTransport, RequestMatcher};
+
+const GROUP_ID: &str = "02bd9fd6-8f93-4758-87c3-1fb73740a315";
+const USER_ID: &str = "87d349ed-44d7-43e1-9a83-5f2406dee5bd";
+
+#[tokio::test]
+async fn add_member_sends_odata_id() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::POST)
+            .path(format!("/v1.0/groups/{GROUP_ID}/members/$ref"))
+            .body_json(serde_json::json!({
+                "@odata.id": format!("https://graph.microsoft.com/v1.0/directoryObjects/{USER_ID}")
+            })),
+        MockResponse::new(204),
+    );
+
+    let client = client(&transport);
+    let response = client
+        .group(GROUP_ID)
+        .add_member(USER_ID)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(204, response.status().as_u16());
+}
+
+#[tokio::test]
+async fn add_members_batches_odata_bind() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::PATCH)
+            .path(format!("/v1.0/groups/{GROUP_ID}")),
+        MockResponse::new(204),
+    );
+
+    let member_ids: Vec<String> = (0..25).map(|i| format!("member-{i}")).collect();
+    let client = client(&transport);
+    client
+        .group(GROUP_ID)
+        .add_members(&member_ids)
+        .await
+        .unwrap();
+
+    let requests = transport.received_requests();
+    assert_eq!(2, requests.len());
+    let first = requests[0].body_json().unwrap();
+    let members = first["members@odata.bind"].as_array().unwrap();
+    assert_eq!(20, members.len());
+    assert_eq!(
+        "https://graph.microsoft.com/v1.0/directoryObjects/member-0",
+        members[0]
+    );
+    let second = requests[1].body_json().unwrap();
+    assert_eq!(5, second["members@odata.bind"].as_array().unwrap().len());
+}
@@ -0,0 +1,144 @@
+#![cfg(feature = "test-util")]
+
+mod common;
+
+use common::client;
+use graph_rs_sdk::groups::{GroupProvisioning, ProvisioningStep};
+use graph_rs_sdk::http::Method;
+use graph_rs_sdk::test_util::{MockResponse, MockTransport, RequestMatcher};
+use std::time::Duration;
+
+#[tokio::test]
+async fn provision_group_with_team_retries_until_replicated() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::POST)
+            .path("/v1.0/groups")
+            .body_json(serde_json::json!({
+                "displayName": "Project X",
+                "mailNickname": "projectx",
+                "groupTypes": ["Unified"],
+                "mailEnabled": true,
+                "securityEnabled": false,
+                "owners@odata.bind": ["https://graph.microsoft.com/v1.0/directoryObjects/owner-id"]
+            })),
+        MockResponse::json(
+            201,
+            &serde_json::json!({ "id": "group-id", "displayName": "Project X" }),
+        ),
+    );
+    transport.mock_times(
+        RequestMatcher::new()
+            .method(Method::PATCH)
+            .path("/v1.0/groups/group-id"),
+        MockResponse::json(
+            404,
+            &serde_json::json!({ "error": { "code": "Request_ResourceNotFound", "message": "Resource 'group-id' does not exist" } }),
+        ),
+        1,
+    );
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::PATCH)
+            .path("/v1.0/groups/group-id")
+            .body_json(serde_json::json!({
+                "members@odata.bind": ["https://graph.microsoft.com/v1.0/directoryObjects/member-id"]
+            })),
+        MockResponse::new(204),
+    );
+    transport.mock_times(
+        RequestMatcher::new()
+            .method(Method::PUT)
+            .path("/v1.0/groups/group-id/team"),
+        MockResponse::json(
+            404,
+            &serde_json::json!({ "error": { "code": "NotFound", "message": "No team found with Group Id group-id" } }),
+        ),
+        2,
+    );
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::PUT)
+            .path("/v1.0/groups/group-id/team"),
+        MockResponse::json(
+            201,
+            &serde_json::json!({ "id": "group-id", "displayName": "Project X" }),
+        ),
+    );
+
+    let client = client(&transport);
+    let provisioned = client
+        .groups()
+        .provision(
+            &GroupProvisioning::new("Project X", "projectx")
+                .owners(["owner-id"])
+                .members(["member-id"])
+                .with_team()
+                .initial_delay(Duration::from_millis(1)),
+        )
+        .await
+        .unwrap();
+
+    assert!(provisioned.is_complete());
+    assert_eq!(Some("group-id".to_string()), provisioned.group.id);
+    assert_eq!(
+        Some("group-id".to_string()),
+        provisioned.team.and_then(|team| team.id)
+    );
+    assert_eq!(6, transport.received_requests().len());
+}
+
+#[tokio::test]
+async fn provision_group_reports_failed_steps_with_the_group() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::POST)
+            .path("/v1.0/groups"),
+        MockResponse::json(201, &serde_json::json!({ "id": "group-id" })),
+    );
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::PATCH)
+            .path("/v1.0/groups/group-id"),
+        MockResponse::json(
+            400,
+            &serde_json::json!({ "error": { "code": "Request_BadRequest", "message": "Invalid object identifier 'member-id'." } }),
+        ),
+    );
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::PUT)
+            .path("/v1.0/groups/group-id/team"),
+        MockResponse::json(
+            404,
+            &serde_json::json!({ "error": { "code": "NotFound", "message": "No team found with Group Id group-id" } }),
+        ),
+    );
+
+    let client = client(&transport);
+    let provisioned = client
+        .groups()
+        .provision(
+            &GroupProvisioning::new("Project X", "projectx")
+                .members(["member-id"])
+                .with_team()
+                .max_retries(2)
+                .initial_delay(Duration::from_millis(1)),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(Some("group-id".to_string()), provisioned.group.id);
+    assert!(provisioned.team.is_none());
+    assert_eq!(
+        vec![ProvisioningStep::AddMembers, ProvisioningStep::CreateTeam],
+        provisioned
+            .errors
+            .iter()
+            .map(|(step, _)| *step)
+            .collect::<Vec<ProvisioningStep>>()
+    );
+    assert_eq!(5, transport.received_requests().len());
+}
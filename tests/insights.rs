@@ -0,0 +1,112 @@
+#![cfg(feature = "test-util")]
+
+mod common;
+
+use common::client;
+use graph_rs_sdk::http::{GraphResponse, Method};
+use graph_rs_sdk::test_util::{MockResponse, MockTransport, RequestMatcher};
+use graph_rs_sdk::types::{Collection, InsightResourceType, Person, Trending, UsedInsight};
+
+#[tokio::test]
+async fn search_people_in_order_of_relevance() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path("/v1.0/me/people")
+            .query_param("$search", "\"adele\""),
+        MockResponse::json(
+            200,
+            &serde_json::json!({
+                "value": [{
+                    "id": "person-id",
+                    "displayName": "Adele Vance",
+                    "scoredEmailAddresses": [{ "address": "adele@contoso.com", "relevanceScore": 8.0 }],
+                    "personType": { "class": "Person", "subclass": "OrganizationUser" }
+                }]
+            }),
+        ),
+    );
+
+    let client = client(&transport);
+    let response: GraphResponse<Collection<Person>> = client
+        .me()
+        .search_people("adele")
+        .send_json()
+        .await
+        .unwrap();
+    let people: Vec<Person> = response.into_body().into_iter().collect();
+    assert_eq!(Some(8.0), people[0].relevance_score());
+    assert_eq!(
+        Some("OrganizationUser"),
+        people[0]
+            .person_type
+            .as_ref()
+            .and_then(|person_type| person_type.subclass.as_deref())
+    );
+}
+
+#[tokio::test]
+async fn list_trending_and_used_by_type() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path("/v1.0/users/user-id/insights/trending")
+            .query_param("$filter", "resourceVisualization/type eq 'PowerPoint'"),
+        MockResponse::json(
+            200,
+            &serde_json::json!({
+                "value": [{
+                    "id": "trending-id",
+                    "weight": 1.5,
+                    "resourceVisualization": { "title": "Roadmap", "type": "PowerPoint" },
+                    "resourceReference": { "webUrl": "https://contoso.sharepoint.com/roadmap.pptx" }
+                }]
+            }),
+        ),
+    );
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path("/v1.0/me/insights/used")
+            .query_param("$filter", "resourceVisualization/type eq 'Word'"),
+        MockResponse::json(
+            200,
+            &serde_json::json!({
+                "value": [{
+                    "id": "used-id",
+                    "lastUsed": { "lastAccessedDateTime": "2024-01-15T10:00:00Z" },
+                    "resourceVisualization": { "title": "Notes", "type": "Word" }
+                }]
+            }),
+        ),
+    );
+
+    let client = client(&transport);
+    let response: GraphResponse<Collection<Trending>> = client
+        .user("user-id")
+        .insights()
+        .list_trending_by_type(InsightResourceType::PowerPoint)
+        .send_json()
+        .await
+        .unwrap();
+    let trending: Vec<Trending> = response.into_body().into_iter().collect();
+    assert_eq!(Some(1.5), trending[0].weight);
+
+    let response: GraphResponse<Collection<UsedInsight>> = client
+        .me()
+        .insights()
+        .list_used_by_type(InsightResourceType::Word)
+        .send_json()
+        .await
+        .unwrap();
+    let used: Vec<UsedInsight> = response.into_body().into_iter().collect();
+    assert_eq!(
+        Some("2024-01-15T10:00:00Z"),
+        used[0]
+            .last_used
+            .as_ref()
+            .and_then(|last_used| last_used.last_accessed_date_time.as_deref())
+    );
+}
@@ -0,0 +1,127 @@
+use graph_rs_sdk::types::{Collection, ListItem, ListItemVersion};
+use graph_rs_sdk::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Task {
+    title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<String>,
+}
+
+#[test]
+fn list_item_requests() {
+    let client = Graph::new("");
+
+    let url = client
+        .site("SITE")
+        .list("LIST")
+        .items()
+        .list_items_with_fields()
+        .url();
+    assert_eq!("/v1.0/sites/SITE/lists/LIST/items", url.path());
+    assert_eq!(Some("%24expand=fields"), url.query());
+
+    let url = client
+        .site("SITE")
+        .list("LIST")
+        .item("1")
+        .get_list_item_with_fields()
+        .url();
+    assert_eq!("/v1.0/sites/SITE/lists/LIST/items/1", url.path());
+    assert_eq!(Some("%24expand=fields"), url.query());
+
+    let url = client
+        .site("SITE")
+        .list("LIST")
+        .item("1")
+        .versions()
+        .list_versions_with_fields()
+        .url();
+    assert_eq!("/v1.0/sites/SITE/lists/LIST/items/1/versions", url.path());
+    assert_eq!(Some("%24expand=fields"), url.query());
+
+    let task = Task {
+        title: "Review".into(),
+        status: None,
+    };
+    assert_eq!(
+        "/v1.0/sites/SITE/lists/LIST/items",
+        client
+            .site("SITE")
+            .list("LIST")
+            .items()
+            .create_list_item(&task)
+            .url()
+            .path()
+    );
+    assert_eq!(
+        "/v1.0/sites/SITE/lists/LIST/items/1/fields",
+        client
+            .site("SITE")
+            .list("LIST")
+            .item("1")
+            .update_list_item_fields(&task)
+            .url()
+            .path()
+    );
+}
+
+#[test]
+fn create_list_item_body() {
+    let item = ListItem::new(Task {
+        title: "Review".into(),
+        status: Some("Not Started".into()),
+    });
+
+    assert_eq!(
+        serde_json::json!({
+            "fields": {
+                "Title": "Review",
+                "Status": "Not Started"
+            }
+        }),
+        serde_json::to_value(&item).unwrap()
+    );
+}
+
+#[test]
+fn list_items_typed_fields() {
+    let items: Collection<ListItem<Task>> = serde_json::from_value(serde_json::json!({
+        "value": [
+            {
+                "id": "1",
+                "eTag": "\"a1b2c3\",1",
+                "webUrl": "https://contoso.sharepoint.com/Lists/Tasks/1_.000",
+                "fields@odata.context": "https://graph.microsoft.com/v1.0/$metadata#fields",
+                "fields": {
+                    "@odata.etag": "\"a1b2c3\",1",
+                    "Title": "Review",
+                    "Status": "Completed"
+                }
+            }
+        ]
+    }))
+    .unwrap();
+
+    let item = items.value.first().unwrap();
+    assert_eq!(Some("1".to_string()), item.id);
+    assert_eq!(
+        Some(&Task {
+            title: "Review".into(),
+            status: Some("Completed".into())
+        }),
+        item.fields.as_ref()
+    );
+
+    let version: ListItemVersion = serde_json::from_value(serde_json::json!({
+        "id": "2.0",
+        "fields": { "Title": "Review" }
+    }))
+    .unwrap();
+    assert_eq!(
+        Some(&serde_json::json!("Review")),
+        version.fields.unwrap().get("Title")
+    );
+}
@@ -0,0 +1,125 @@
+use graph_rs_sdk::error::{GraphFailure, GraphRsError};
+use graph_rs_sdk::header::{HeaderValue, CONTENT_TYPE};
+use graph_rs_sdk::types::{
+    AttachmentUploadSession, FileAttachment, ForwardMessage, ItemBody, Message, Recipient,
+    SendMail, MAX_ATTACHMENT_SIZE,
+};
+use graph_rs_sdk::*;
+
+#[test]
+fn send_mail_body() {
+    let send_mail = SendMail::new(Message {
+        subject: Some("Meet for lunch?".into()),
+        body: Some(ItemBody::text("The new cafeteria is open.")),
+        to_recipients: vec![Recipient::new("fannyd@contoso.onmicrosoft.com")],
+        attachments: vec![FileAttachment::new("file.txt", "text/plain", b"content").unwrap()],
+        ..Default::default()
+    })
+    .save_to_sent_items(false);
+
+    assert_eq!(
+        serde_json::json!({
+            "message": {
+                "subject": "Meet for lunch?",
+                "body": {
+                    "contentType": "text",
+                    "content": "The new cafeteria is open."
+                },
+                "toRecipients": [
+                    { "emailAddress": { "address": "fannyd@contoso.onmicrosoft.com" } }
+                ],
+                "attachments": [
+                    {
+                        "@odata.type": "#microsoft.graph.fileAttachment",
+                        "name": "file.txt",
+                        "contentType": "text/plain",
+                        "contentBytes": "Y29udGVudA=="
+                    }
+                ]
+            },
+            "saveToSentItems": false
+        }),
+        serde_json::to_value(&send_mail).unwrap()
+    );
+}
+
+#[test]
+fn forward_message_body() {
+    let forward = ForwardMessage::new(vec!["danas@contoso.onmicrosoft.com"]).with_comment("FYI");
+
+    assert_eq!(
+        serde_json::json!({
+            "comment": "FYI",
+            "toRecipients": [
+                { "emailAddress": { "address": "danas@contoso.onmicrosoft.com" } }
+            ]
+        }),
+        serde_json::to_value(&forward).unwrap()
+    );
+}
+
+#[test]
+fn file_attachment_too_large() {
+    let content = vec![0u8; MAX_ATTACHMENT_SIZE + 1];
+    let result = FileAttachment::new("file.bin", "application/octet-stream", content);
+
+    assert!(matches!(
+        result,
+        Err(GraphFailure::GraphRsError(
+            GraphRsError::AttachmentTooLarge { .. }
+        ))
+    ));
+}
+
+#[test]
+fn attachment_upload_session_body() {
+    let body = AttachmentUploadSession::file("report.pdf", 4_000_000);
+
+    assert_eq!(
+        serde_json::json!({
+            "AttachmentItem": {
+                "attachmentType": "file",
+                "name": "report.pdf",
+                "size": 4_000_000
+            }
+        }),
+        serde_json::to_value(&body).unwrap()
+    );
+}
+
+#[test]
+fn send_mime_mail() {
+    let client = Graph::new("");
+
+    let mut request = client.me().send_mime_mail("MIME-Version: 1.0");
+    assert_eq!("/v1.0/me/sendMail", request.url().path());
+    assert_eq!(
+        Some(&HeaderValue::from_static("text/plain")),
+        request.headers_mut().get(CONTENT_TYPE)
+    );
+
+    let mut request = client
+        .user("ID")
+        .messages()
+        .create_mime_message("MIME-Version: 1.0");
+    assert_eq!("/v1.0/users/ID/messages", request.url().path());
+    assert_eq!(
+        Some(&HeaderValue::from_static("text/plain")),
+        request.headers_mut().get(CONTENT_TYPE)
+    );
+}
+
+#[test]
+fn create_mime_message() {
+    let client = Graph::new("");
+
+    let mut request = client.me().create_mime_message("MIME-Version: 1.0");
+    assert_eq!("/v1.0/me/messages", request.url().path());
+    assert_eq!(
+        Some(&HeaderValue::from_static("text/plain")),
+        request.headers_mut().get(CONTENT_TYPE)
+    );
+
+    let request = client.user("ID").create_mime_message("MIME-Version: 1.0");
+    assert_eq!("/v1.0/users/ID/messages", request.url().path());
+}
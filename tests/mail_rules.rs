@@ -0,0 +1,176 @@
+#![cfg(feature = "test-util")]
+
+mod common;
+
+use common::client;
+use graph_rs_sdk::http::Method;
+use graph_rs_sdk::test_util::{MockResponse, MockTransport, RequestMatcher};
+use graph_rs_sdk::types::{
+    CategoryColor, Importance, MessageRule, MessageRuleActions, MessageRulePredicates,
+    OutlookCategory, Recipient,
+};
+
+#[tokio::test]
+async fn create_and_list_inbox_rules() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::POST)
+            .path("/v1.0/me/mailFolders/inbox/messageRules")
+            .body_json(serde_json::json!({
+                "displayName": "From partners to FYI folder",
+                "sequence": 2,
+                "isEnabled": true,
+                "conditions": {
+                    "senderContains": ["adele"],
+                    "hasAttachments": true,
+                    "withinSizeRange": { "minimumSize": 100 }
+                },
+                "exceptions": { "importance": "high" },
+                "actions": {
+                    "moveToFolder": "AAMkADIyAAAhrbPXAAA=",
+                    "forwardTo": [{ "emailAddress": { "address": "alexw@contoso.onmicrosoft.com" } }],
+                    "stopProcessingRules": true
+                }
+            })),
+        MockResponse::json(201, &serde_json::json!({ "id": "RULE_ID" })),
+    );
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path("/v1.0/me/mailFolders/inbox/messageRules"),
+        MockResponse::json(
+            200,
+            &serde_json::json!({
+                "value": [
+                    {
+                        "id": "SECOND",
+                        "displayName": "Mark invoices",
+                        "sequence": 2,
+                        "actions": { "markImportance": "high", "assignCategories": ["Finance"] }
+                    },
+                    {
+                        "id": "FIRST",
+                        "displayName": "Delete spam",
+                        "sequence": 1,
+                        "conditions": { "fromAddresses": [{ "emailAddress": { "address": "spam@fabrikam.com" } }] },
+                        "actions": { "delete": true }
+                    }
+                ]
+            }),
+        ),
+    );
+
+    let client = client(&transport);
+    let rule = MessageRule::new("From partners to FYI folder", 2)
+        .with_conditions(
+            MessageRulePredicates::new()
+                .with_sender_contains("adele")
+                .with_has_attachments(true)
+                .with_size_range(Some(100), None),
+        )
+        .with_exceptions(MessageRulePredicates::new().with_importance(Importance::High))
+        .with_actions(
+            MessageRuleActions::new()
+                .with_move_to_folder("AAMkADIyAAAhrbPXAAA=")
+                .with_forward_to(Recipient::new("alexw@contoso.onmicrosoft.com"))
+                .with_stop_processing_rules(),
+        );
+    let response = client
+        .me()
+        .mail_folder("inbox")
+        .create_rule(&rule)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(201, response.status().as_u16());
+
+    let rules = client.me().mail_folder("inbox").list_rules().await.unwrap();
+    assert_eq!(
+        vec![Some("FIRST"), Some("SECOND")],
+        rules
+            .iter()
+            .map(|rule| rule.id.as_deref())
+            .collect::<Vec<_>>()
+    );
+    let conditions = rules[0].conditions.as_ref().unwrap();
+    assert_eq!(
+        Some("spam@fabrikam.com"),
+        conditions.from_addresses[0]
+            .email_address
+            .as_ref()
+            .and_then(|email_address| email_address.address.as_deref())
+    );
+    let actions = rules[1].actions.as_ref().unwrap();
+    assert_eq!(Some(Importance::High), actions.mark_importance);
+    assert_eq!(vec!["Finance"], actions.assign_categories);
+}
+
+#[tokio::test]
+async fn manage_master_categories() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::POST)
+            .path("/v1.0/me/outlook/masterCategories")
+            .body_json(serde_json::json!({ "displayName": "Project expenses", "color": "preset9" })),
+        MockResponse::json(
+            201,
+            &serde_json::json!({ "id": "CATEGORY_ID", "displayName": "Project expenses", "color": "preset9" }),
+        ),
+    );
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::PATCH)
+            .path("/v1.0/me/outlook/masterCategories/CATEGORY_ID")
+            .body_json(serde_json::json!({ "color": "preset4" })),
+        MockResponse::new(200),
+    );
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path("/v1.0/me/outlook/masterCategories"),
+        MockResponse::json(
+            200,
+            &serde_json::json!({
+                "value": [
+                    { "id": "CATEGORY_ID", "displayName": "Project expenses", "color": "preset4" },
+                    { "id": "OTHER_ID", "displayName": "Personal", "color": "none" }
+                ]
+            }),
+        ),
+    );
+
+    let client = client(&transport);
+    let category: OutlookCategory = client
+        .me()
+        .outlook()
+        .create_category(&OutlookCategory::new(
+            "Project expenses",
+            CategoryColor::Preset9,
+        ))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(Some("CATEGORY_ID"), category.id.as_deref());
+
+    let response = client
+        .me()
+        .outlook()
+        .update_category(
+            "CATEGORY_ID",
+            &OutlookCategory::color(CategoryColor::Preset4),
+        )
+        .send()
+        .await
+        .unwrap();
+    assert!(response.status().is_success());
+
+    let categories = client.me().outlook().list_categories().await.unwrap();
+    assert_eq!(2, categories.len());
+    assert_eq!(Some(CategoryColor::Preset4), categories[0].color);
+    assert_eq!(Some(CategoryColor::None), categories[1].color);
+}
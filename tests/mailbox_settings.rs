@@ -0,0 +1,146 @@
+#![cfg(feature = "test-util")]
+
+mod common;
+
+use common::client;
+use graph_rs_sdk::http::Method;
+use graph_rs_sdk::test_util::{MockResponse, MockTransport, RequestMatcher};
+use graph_rs_sdk::types::{
+    AutomaticRepliesSetting, AutomaticRepliesStatus, DayOfWeek, ExternalAudienceScope,
+};
+use time::{Duration, OffsetDateTime, Time, UtcOffset};
+
+#[tokio::test]
+async fn set_scheduled_automatic_replies() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::PATCH)
+            .path("/v1.0/me/mailboxSettings")
+            .body_json(serde_json::json!({
+                "automaticRepliesSetting": {
+                    "status": "scheduled",
+                    "externalAudience": "all",
+                    "scheduledStartDateTime": { "dateTime": "2024-01-01T07:00:00", "timeZone": "UTC" },
+                    "scheduledEndDateTime": { "dateTime": "2024-01-15T07:00:00", "timeZone": "UTC" },
+                    "internalReplyMessage": "Out of office",
+                    "externalReplyMessage": "Out of office, contact support"
+                }
+            })),
+        MockResponse::new(200),
+    );
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::PATCH)
+            .path("/v1.0/users/USER_ID/mailboxSettings")
+            .body_json(serde_json::json!({
+                "automaticRepliesSetting": { "status": "disabled" }
+            })),
+        MockResponse::new(200),
+    );
+
+    // 9 AM at UTC+2 is sent as 7 AM UTC.
+    let start = OffsetDateTime::from_unix_timestamp(1704096000)
+        .unwrap()
+        .replace_offset(UtcOffset::from_hms(2, 0, 0).unwrap())
+        + Duration::hours(1);
+    let automatic_replies =
+        AutomaticRepliesSetting::scheduled(start, start + Duration::days(14), "Out of office")
+            .with_external_reply("Out of office, contact support", ExternalAudienceScope::All);
+
+    let client = client(&transport);
+    let response = client
+        .me()
+        .mailbox_settings()
+        .set_automatic_replies(&automatic_replies)
+        .send()
+        .await
+        .unwrap();
+    assert!(response.status().is_success());
+
+    let response = client
+        .user("USER_ID")
+        .mailbox_settings()
+        .disable_automatic_replies()
+        .send()
+        .await
+        .unwrap();
+    assert!(response.status().is_success());
+
+    assert!(automatic_replies.is_active_at(start));
+    assert!(!automatic_replies.is_active_at(start - Duration::seconds(1)));
+    assert!(!automatic_replies.is_active_at(start + Duration::days(14)));
+}
+
+#[tokio::test]
+async fn get_mailbox_settings() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path("/v1.0/me/mailboxSettings"),
+        MockResponse::json(
+            200,
+            &serde_json::json!({
+                "@odata.context": "https://graph.microsoft.com/v1.0/$metadata#Me/mailboxSettings",
+                "archiveFolder": "AQMkAGI5",
+                "timeZone": "Pacific Standard Time",
+                "automaticRepliesSetting": {
+                    "status": "alwaysEnabled",
+                    "externalAudience": "none",
+                    "internalReplyMessage": "Out of office",
+                    "externalReplyMessage": "",
+                    "scheduledStartDateTime": { "dateTime": "2024-01-01T08:00:00.0000000", "timeZone": "UTC" },
+                    "scheduledEndDateTime": { "dateTime": "2024-01-02T08:00:00.0000000", "timeZone": "UTC" }
+                },
+                "language": { "locale": "en-US", "displayName": "English (United States)" },
+                "dateFormat": "M/d/yyyy",
+                "timeFormat": "h:mm tt",
+                "workingHours": {
+                    "daysOfWeek": ["monday", "tuesday", "wednesday", "thursday", "friday"],
+                    "startTime": "08:00:00.0000000",
+                    "endTime": "17:30:00.0000000",
+                    "timeZone": { "name": "Pacific Standard Time" }
+                }
+            }),
+        ),
+    );
+
+    let settings = client(&transport)
+        .me()
+        .mailbox_settings()
+        .get_settings()
+        .await
+        .unwrap();
+
+    assert_eq!(Some("Pacific Standard Time"), settings.time_zone.as_deref());
+    assert_eq!(
+        Some("en-US"),
+        settings.language.as_ref().unwrap().locale.as_deref()
+    );
+
+    let automatic_replies = settings.automatic_replies_setting.unwrap();
+    assert_eq!(
+        Some(AutomaticRepliesStatus::AlwaysEnabled),
+        automatic_replies.status
+    );
+    assert_eq!(
+        Some(OffsetDateTime::from_unix_timestamp(1704096000).unwrap()),
+        automatic_replies
+            .scheduled_start_date_time
+            .unwrap()
+            .to_utc()
+    );
+
+    let working_hours = settings.working_hours.unwrap();
+    assert_eq!(5, working_hours.days_of_week.len());
+    assert_eq!(DayOfWeek::Monday, working_hours.days_of_week[0]);
+    assert_eq!(
+        Some(Time::from_hms(8, 0, 0).unwrap()),
+        working_hours.start()
+    );
+    assert_eq!(
+        Some(Time::from_hms(17, 30, 0).unwrap()),
+        working_hours.end()
+    );
+}
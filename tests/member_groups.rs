@@ -0,0 +1,48 @@
+#![cfg(feature = "test-util")]
+
+mod common;
+
+use common::client;
+use graph_rs_sdk::http::Method;
+use graph_rs_sdk::test_util::{MockResponse, MockTransport, RequestMatcher};
+use std::collections::HashSet;
+
+const USER_ID: &str = "87d349ed-44d7-43e1-9a83-5f2406dee5bd";
+
+#[tokio::test]
+async fn check_member_group_ids_batches_group_ids() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::POST)
+            .path(format!("/v1.0/users/{USER_ID}/checkMemberGroups")),
+        MockResponse::json(
+            200,
+            &serde_json::json!({ "value": ["group-1", "group-30"] }),
+        ),
+    );
+
+    let group_ids: Vec<String> = (1..=45).map(|i| format!("group-{i}")).collect();
+    let client = client(&transport);
+    let member_groups = client
+        .user(USER_ID)
+        .check_member_group_ids(&group_ids)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        HashSet::from(["group-1".to_string(), "group-30".to_string()]),
+        member_groups
+    );
+
+    let requests = transport.received_requests();
+    assert_eq!(3, requests.len());
+    let mut sent = HashSet::new();
+    for request in requests {
+        let body = request.body_json().unwrap();
+        let ids = body["groupIds"].as_array().unwrap();
+        assert!(ids.len() <= 20);
+        sent.extend(ids.iter().map(|id| id.as_str().unwrap().to_owned()));
+    }
+    assert_eq!(45, sent.len());
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "test-util")]
+
+mod common;
+
+use common::client;
+use graph_rs_sdk::http::Method;
+use graph_rs_sdk::test_util::{fixtures, MockResponse, MockTransport, RequestMatcher};
+use graph_rs_sdk::ODataQuery;
+
+#[tokio::test]
+async fn mock_transport_responds_to_client_requests() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path("/v1.0/users")
+            .query_param("$top", "1"),
+        MockResponse::json(200, &fixtures::collection(vec![fixtures::user()], None)),
+    );
+
+    let client = client(&transport);
+    let response = client.users().list_user().top("1").send().await.unwrap();
+    assert!(response.status().is_success());
+
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(fixtures::USER_ID, body["value"][0]["id"]);
+
+    let requests = transport.received_requests();
+    assert_eq!(1, requests.len());
+    assert_eq!(
+        "Bearer token",
+        requests[0].headers.get("authorization").unwrap()
+    );
+}
+
+#[tokio::test]
+async fn mock_transport_records_request_body() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::POST)
+            .path("/v1.0/groups")
+            .body_json(serde_json::json!({ "displayName": "Library Assist" })),
+        MockResponse::json(201, &fixtures::group()),
+    );
+
+    let client = client(&transport);
+    let response = client
+        .groups()
+        .create_group(&serde_json::json!({ "displayName": "Library Assist" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(201, response.status().as_u16());
+    assert_eq!(
+        Some(serde_json::json!({ "displayName": "Library Assist" })),
+        transport.received_requests()[0].body_json()
+    );
+}
+
+#[tokio::test]
+async fn unmatched_request_returns_not_found() {
+    let transport = MockTransport::new();
+    let client = client(&transport);
+
+    let response = client.me().get_user().send().await.unwrap();
+    assert_eq!(404, response.status().as_u16());
+}
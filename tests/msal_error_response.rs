@@ -0,0 +1,103 @@
+use graph_rs_sdk::error::{MsalErrorClassification, MsalErrorResponse};
+
+fn error_response(value: serde_json::Value) -> MsalErrorResponse {
+    MsalErrorResponse::from_json(&value).unwrap()
+}
+
+#[test]
+fn aadsts_code_from_error_description() {
+    let error = error_response(serde_json::json!({
+        "error": "invalid_client",
+        "error_description": "AADSTS7000215: Invalid client secret provided.\r\nTrace ID: 0000aaaa-11bb-cccc-dd22-eeeeee333333\r\nCorrelation ID: aaaa0000-bb11-2222-33cc-444444dddddd",
+        "timestamp": "2024-01-15 18:45:06Z",
+        "trace_id": "0000aaaa-11bb-cccc-dd22-eeeeee333333",
+        "correlation_id": "aaaa0000-bb11-2222-33cc-444444dddddd"
+    }));
+
+    assert_eq!(Some(7000215), error.aadsts_code());
+    assert_eq!(Some("AADSTS7000215".to_string()), error.aadsts());
+    assert_eq!(
+        Some("aaaa0000-bb11-2222-33cc-444444dddddd".to_string()),
+        error.correlation_id
+    );
+    assert_eq!(
+        MsalErrorClassification::NonRetryable,
+        error.classification()
+    );
+}
+
+#[test]
+fn interaction_required_classification() {
+    let error = error_response(serde_json::json!({
+        "error": "invalid_grant",
+        "error_description": "AADSTS65001: The user or administrator has not consented to use the application.",
+        "error_codes": [65001],
+        "suberror": "consent_required"
+    }));
+    assert!(error.is_interaction_required());
+
+    let error = error_response(serde_json::json!({ "error": "interaction_required" }));
+    assert!(error.is_interaction_required());
+
+    let error = error_response(serde_json::json!({
+        "error": "invalid_grant",
+        "suberror": "additional_action"
+    }));
+    assert!(error.is_interaction_required());
+}
+
+#[test]
+fn other_suberror_is_not_interaction_required() {
+    let error = error_response(serde_json::json!({
+        "error": "invalid_grant",
+        "error_description": "AADSTS50000: There was an error issuing a token.",
+        "error_codes": [50000],
+        "suberror": "client_mismatch"
+    }));
+    assert!(!error.is_interaction_required());
+    assert_eq!(
+        MsalErrorClassification::NonRetryable,
+        error.classification()
+    );
+
+    let error = error_response(serde_json::json!({
+        "error": "invalid_grant",
+        "suberror": "bad_token"
+    }));
+    assert!(!error.is_interaction_required());
+}
+
+#[test]
+fn retryable_classification() {
+    let error = error_response(serde_json::json!({
+        "error": "temporarily_unavailable",
+        "error_description": "AADSTS90033: A transient error has occurred. Please try again.",
+        "error_codes": [90033]
+    }));
+    assert!(error.is_retryable());
+}
+
+#[test]
+fn resource_principal_not_found_is_not_retryable() {
+    let error = error_response(serde_json::json!({
+        "error": "invalid_resource",
+        "error_description": "AADSTS500011: The resource principal named https://graph.contoso.com was not found in the tenant.",
+        "error_codes": [500011]
+    }));
+    assert!(!error.is_retryable());
+    assert_eq!(
+        MsalErrorClassification::NonRetryable,
+        error.classification()
+    );
+}
+
+#[test]
+fn graph_error_is_not_token_error_response() {
+    let value = serde_json::json!({
+        "error": {
+            "code": "itemNotFound",
+            "message": "The resource could not be found."
+        }
+    });
+    assert!(MsalErrorResponse::from_json(&value).is_none());
+}
@@ -67,6 +67,28 @@ fn expand_filter_query() {
     );
 }
 
+#[test]
+fn filter_macro_escapes_bound_values() {
+    let client = Graph::new("");
+    let filter = filter!(
+        "displayName eq {} and mail eq {}",
+        "O'Brien",
+        "a&b@contoso.com"
+    )
+    .unwrap();
+
+    assert_eq!(
+        "https://graph.microsoft.com/v1.0/users?%24filter=displayName+eq+%27O%27%27Brien%27+and+mail+eq+%27a%26b%40contoso.com%27"
+            .to_string(),
+        client
+            .users()
+            .list_user()
+            .filter(&[filter.as_str()])
+            .url()
+            .to_string()
+    );
+}
+
 async fn filter_request(client: &Graph) -> GraphResult<reqwest::Response> {
     client
         .users()
@@ -160,3 +182,41 @@ async fn filter_query_request_v1() {
         validate_order_by_request(true, &mut client).await;
     }
 }
+
+#[test]
+fn page_size_query() {
+    let client = Graph::new("");
+
+    assert_eq!(
+        "https://graph.microsoft.com/v1.0/users?%24top=50".to_string(),
+        client.users().list_user().page_size(50).url().to_string()
+    );
+
+    let request = client.users().delta().page_size(50);
+    assert_eq!(
+        "https://graph.microsoft.com/v1.0/users/delta()",
+        request.url().as_str()
+    );
+    assert!(request
+        .dump_request()
+        .to_string()
+        .contains("prefer: odata.maxpagesize=50"));
+}
+
+#[test]
+fn paging_resume_from_next_link() {
+    let client = Graph::new("");
+
+    assert!(client
+        .users()
+        .list_user()
+        .paging()
+        .resume_from("https://graph.microsoft.com/v1.0/users?$skiptoken=token")
+        .is_ok());
+    assert!(client
+        .users()
+        .list_user()
+        .paging()
+        .resume_from("https://example.com/v1.0/users?$skiptoken=token")
+        .is_err());
+}
@@ -62,3 +62,35 @@ async fn paging_stream() {
         }
     }
 }
+
+#[tokio::test]
+async fn paging_prefetch() {
+    if Environment::is_local() {
+        let test_client = DEFAULT_CLIENT_CREDENTIALS_MUTEX4.lock().await;
+        let mut stream = test_client
+            .client
+            .users()
+            .delta()
+            .page_size(5)
+            .paging()
+            .throttle_retries(3)
+            .prefetch::<serde_json::Value>(2)
+            .await
+            .unwrap();
+
+        let mut deque = VecDeque::new();
+        while let Some(result) = stream.next().await {
+            match result {
+                Ok(response) => {
+                    assert!(response.status().is_success());
+                    deque.push_back(response.into_body().unwrap());
+                }
+                Err(err) => panic!("Error on prefetch users delta\n{err:#?}"),
+            }
+        }
+
+        assert!(deque.len() >= 2);
+        let last = deque.pop_back().unwrap();
+        assert!(last["@odata.deltaLink"].as_str().is_some());
+    }
+}
@@ -0,0 +1,95 @@
+#![cfg(feature = "test-util")]
+
+mod common;
+
+use common::client;
+use graph_rs_sdk::http::Method;
+use graph_rs_sdk::test_util::{MockResponse, MockTransport, RequestMatcher};
+use graph_rs_sdk::types::{Photo, PhotoSize};
+use http::header::CONTENT_TYPE;
+use http::HeaderValue;
+
+#[tokio::test]
+async fn download_photo_sizes_and_missing_photo() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path("/v1.0/users/user-id/photos/96x96/$value"),
+        MockResponse::new(200)
+            .header(CONTENT_TYPE, HeaderValue::from_static("image/png"))
+            .body(vec![137, 80, 78, 71]),
+    );
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path("/v1.0/groups/group-id/photo/$value"),
+        MockResponse::json(
+            404,
+            &serde_json::json!({ "error": { "code": "ImageNotFound", "message": "Image not found" } }),
+        ),
+    );
+
+    let client = client(&transport);
+    let photo = client
+        .user("user-id")
+        .download_photo(Some(PhotoSize::Size96x96))
+        .await
+        .unwrap();
+    assert_eq!(Some(Photo::png(vec![137, 80, 78, 71])), photo);
+
+    let photo = client.group("group-id").download_photo(None).await.unwrap();
+    assert_eq!(None, photo);
+}
+
+#[tokio::test]
+async fn download_photo_of_missing_user_is_an_error() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path("/v1.0/users/missing-user/photo/$value"),
+        MockResponse::json(
+            404,
+            &serde_json::json!({ "error": { "code": "Request_ResourceNotFound", "message": "Resource 'missing-user' does not exist" } }),
+        ),
+    );
+
+    let client = client(&transport);
+    let result = client.user("missing-user").download_photo(None).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn upload_photo_preserves_content_type() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::PUT)
+            .path("/v1.0/me/photo/$value")
+            .header(CONTENT_TYPE, HeaderValue::from_static("image/png")),
+        MockResponse::new(200),
+    );
+
+    let client = client(&transport);
+    let response = client
+        .me()
+        .upload_photo(&Photo::png(vec![137, 80, 78, 71]))
+        .unwrap()
+        .send()
+        .await
+        .unwrap();
+    assert!(response.status().is_success());
+
+    let requests = transport.received_requests();
+    assert_eq!(Some(vec![137, 80, 78, 71]), requests[0].body.clone());
+}
+
+#[test]
+fn upload_photo_with_invalid_content_type_is_an_error() {
+    let transport = MockTransport::new();
+    let client = client(&transport);
+    let photo = Photo::new("image/png\r\n", vec![137, 80, 78, 71]);
+    assert!(client.me().upload_photo(&photo).is_err());
+    assert!(transport.received_requests().is_empty());
+}
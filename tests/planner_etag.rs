@@ -0,0 +1,110 @@
+#![cfg(feature = "test-util")]
+
+mod common;
+
+use common::client;
+use graph_rs_sdk::http::Method;
+use graph_rs_sdk::test_util::{fixtures, MockResponse, MockTransport, RequestMatcher};
+
+const TASK_ID: &str = "01gzSlKkIUSUl6DF_EilrmQAKDhh";
+
+fn task(etag: &str) -> serde_json::Value {
+    serde_json::json!({
+        "@odata.etag": etag,
+        "id": TASK_ID,
+        "title": "Update client list"
+    })
+}
+
+#[tokio::test]
+async fn update_task_sends_if_match() {
+    let transport = MockTransport::new();
+    let path = format!("/v1.0/planner/tasks/{TASK_ID}");
+    transport.mock(
+        RequestMatcher::new().method(Method::GET).path(&path),
+        MockResponse::json(200, &task("W/\"etag-1\"")),
+    );
+    transport.mock(
+        RequestMatcher::new().method(Method::PATCH).path(&path),
+        MockResponse::new(204),
+    );
+
+    let client = client(&transport);
+    let response = client
+        .planner()
+        .task(TASK_ID)
+        .update_task_with_etag(&serde_json::json!({ "title": "Updated" }))
+        .await
+        .unwrap();
+    assert_eq!(204, response.status().as_u16());
+
+    let requests = transport.received_requests();
+    assert_eq!(2, requests.len());
+    assert_eq!("W/\"etag-1\"", requests[1].headers.get("if-match").unwrap());
+}
+
+#[tokio::test]
+async fn update_task_retries_precondition_failed() {
+    let transport = MockTransport::new();
+    let path = format!("/v1.0/planner/tasks/{TASK_ID}");
+    transport.mock_times(
+        RequestMatcher::new().method(Method::GET).path(&path),
+        MockResponse::json(200, &task("W/\"etag-1\"")),
+        1,
+    );
+    transport.mock(
+        RequestMatcher::new().method(Method::GET).path(&path),
+        MockResponse::json(200, &task("W/\"etag-2\"")),
+    );
+    transport.mock_times(
+        RequestMatcher::new().method(Method::PATCH).path(&path),
+        MockResponse::json(
+            412,
+            &fixtures::error("PreconditionFailed", "The ETag does not match"),
+        ),
+        1,
+    );
+    transport.mock(
+        RequestMatcher::new().method(Method::PATCH).path(&path),
+        MockResponse::new(204),
+    );
+
+    let client = client(&transport);
+    let response = client
+        .planner()
+        .task(TASK_ID)
+        .update_task_with_etag(&serde_json::json!({ "title": "Updated" }))
+        .await
+        .unwrap();
+    assert_eq!(204, response.status().as_u16());
+
+    let requests = transport.received_requests();
+    assert_eq!(4, requests.len());
+    assert_eq!("W/\"etag-2\"", requests[3].headers.get("if-match").unwrap());
+}
+
+#[tokio::test]
+async fn delete_task_fails_after_retries() {
+    let transport = MockTransport::new();
+    let path = format!("/v1.0/planner/tasks/{TASK_ID}");
+    transport.mock(
+        RequestMatcher::new().method(Method::GET).path(&path),
+        MockResponse::json(200, &task("W/\"etag-1\"")),
+    );
+    transport.mock(
+        RequestMatcher::new().method(Method::DELETE).path(&path),
+        MockResponse::json(
+            412,
+            &fixtures::error("PreconditionFailed", "The ETag does not match"),
+        ),
+    );
+
+    let client = client(&transport);
+    let err = client
+        .planner()
+        .task(TASK_ID)
+        .delete_task_with_etag()
+        .await
+        .unwrap_err();
+    assert!(err.is_precondition_failed());
+}
@@ -0,0 +1,60 @@
+use graph_rs_sdk::http::{BodyContentType, Prefer};
+use graph_rs_sdk::{Graph, GraphClientConfiguration};
+
+#[test]
+fn prefer_request_header() {
+    let client = Graph::new("");
+    let prefer = Prefer::new()
+        .outlook_timezone("Pacific Standard Time")
+        .outlook_body_content_type(BodyContentType::Text);
+
+    let mut request = client.me().messages().list_messages().prefer(&prefer);
+    assert_eq!(
+        "outlook.timezone=\"Pacific Standard Time\", outlook.body-content-type=\"text\"",
+        request.headers_mut().get("prefer").unwrap()
+    );
+}
+
+#[test]
+fn prefer_client_header() {
+    let client = Graph::from(
+        GraphClientConfiguration::new()
+            .access_token("")
+            .prefer(&Prefer::new().max_page_size(25))
+            .unwrap(),
+    );
+
+    let mut request = client.users().list_user();
+    assert_eq!(
+        "odata.maxpagesize=25",
+        request.headers_mut().get("prefer").unwrap()
+    );
+
+    let mut request = client
+        .users()
+        .create_user(&serde_json::json!({}))
+        .prefer(&Prefer::new().return_minimal());
+    assert_eq!(
+        "return=minimal",
+        request.headers_mut().get("prefer").unwrap()
+    );
+}
+
+#[test]
+fn invalid_prefer_header() {
+    let client = Graph::new("");
+    let request = client
+        .me()
+        .messages()
+        .list_messages()
+        .prefer(&Prefer::new().outlook_timezone("Pacific\nStandard Time"));
+    assert!(request.is_err());
+}
+
+#[test]
+fn invalid_prefer_client_header() {
+    let config = GraphClientConfiguration::new()
+        .access_token("")
+        .prefer(&Prefer::new().preference("odata.maxpagesize=\n25"));
+    assert!(config.is_err());
+}
@@ -0,0 +1,181 @@
+use graph_rs_sdk::reports::{ReportCsv, ReportFormat, ReportJson, ReportPeriod};
+use graph_rs_sdk::{Graph, ODataQuery};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+const REPORT: &str =
+    "\u{feff}Report Refresh Date,User Principal Name,Display Name,Last Activity Date,Report Period
+2024-01-14,adelev@contoso.onmicrosoft.com,Adele Vance,2024-01-12,7
+2024-01-14,alexw@contoso.onmicrosoft.com,Alex Wilber,,7
+";
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct ActiveUserDetail {
+    #[serde(rename = "User Principal Name")]
+    user_principal_name: String,
+    #[serde(rename = "Last Activity Date")]
+    last_activity_date: Option<String>,
+    #[serde(rename = "Report Period")]
+    report_period: u32,
+}
+
+#[test]
+fn report_period_url() {
+    let client = Graph::new("");
+
+    let url = client
+        .reports()
+        .get_office_365_active_user_detail_by_period(ReportPeriod::D7)
+        .url();
+
+    assert_eq!(
+        "/v1.0/reports/microsoft.graph.getOffice365ActiveUserDetail(period='D7')",
+        url.path()
+    );
+}
+
+#[test]
+fn report_csv_rows() {
+    let report = ReportCsv::new(REPORT);
+
+    assert_eq!(
+        vec![
+            "Report Refresh Date",
+            "User Principal Name",
+            "Display Name",
+            "Last Activity Date",
+            "Report Period"
+        ],
+        report.headers().unwrap()
+    );
+
+    let rows: Vec<ActiveUserDetail> = report.rows().unwrap();
+    assert_eq!(
+        vec![
+            ActiveUserDetail {
+                user_principal_name: "adelev@contoso.onmicrosoft.com".into(),
+                last_activity_date: Some("2024-01-12".into()),
+                report_period: 7,
+            },
+            ActiveUserDetail {
+                user_principal_name: "alexw@contoso.onmicrosoft.com".into(),
+                last_activity_date: None,
+                report_period: 7,
+            }
+        ],
+        rows
+    );
+
+    let records: Vec<HashMap<String, String>> = report.records().unwrap();
+    assert_eq!("Alex Wilber", records[1]["Display Name"]);
+}
+
+#[test]
+fn report_json_rows() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    struct ActiveUserDetail {
+        user_principal_name: String,
+        last_activity_date: Option<String>,
+    }
+
+    let client = Graph::new("");
+    let url = client
+        .reports()
+        .get_office_365_active_user_detail_by_period(ReportPeriod::D7)
+        .format(ReportFormat::Json)
+        .url();
+    assert_eq!(Some("%24format=application%2Fjson"), url.query());
+
+    let report = ReportJson::new(
+        &serde_json::json!({
+            "@odata.context": "https://graph.microsoft.com/beta/$metadata#Collection(microsoft.graph.office365ActiveUserDetail)",
+            "value": [
+                {
+                    "reportRefreshDate": "2024-01-14",
+                    "userPrincipalName": "adelev@contoso.onmicrosoft.com",
+                    "lastActivityDate": "2024-01-12",
+                    "reportPeriod": "7"
+                },
+                {
+                    "reportRefreshDate": "2024-01-14",
+                    "userPrincipalName": "alexw@contoso.onmicrosoft.com",
+                    "lastActivityDate": null,
+                    "reportPeriod": "7"
+                }
+            ],
+            "@odata.nextLink": "https://graph.microsoft.com/beta/reports/getOffice365ActiveUserDetail(period='D7')?$format=application%2fjson&$skiptoken=1"
+        })
+        .to_string(),
+    )
+    .unwrap();
+
+    let rows: Vec<ActiveUserDetail> = report.rows().unwrap();
+    assert_eq!(
+        vec![
+            ActiveUserDetail {
+                user_principal_name: "adelev@contoso.onmicrosoft.com".into(),
+                last_activity_date: Some("2024-01-12".into()),
+            },
+            ActiveUserDetail {
+                user_principal_name: "alexw@contoso.onmicrosoft.com".into(),
+                last_activity_date: None,
+            }
+        ],
+        rows
+    );
+    assert_eq!(2, report.records().len());
+    assert!(report.next_link().is_some());
+}
+
+#[cfg(feature = "test-util")]
+mod report_download {
+    use super::*;
+    use graph_rs_sdk::http::Url;
+    use graph_rs_sdk::GraphClientConfiguration;
+    use wiremock::matchers::{header_exists, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn follows_report_redirect() {
+        let mock_server = MockServer::start().await;
+        let download_url = format!("{}/download/report.csv", mock_server.uri());
+
+        Mock::given(method("GET"))
+            .and(path(
+                "/reports/microsoft.graph.getOffice365ActiveUserDetail(period='D30')",
+            ))
+            .and(header_exists("authorization"))
+            .respond_with(
+                ResponseTemplate::new(302).insert_header("Location", download_url.as_str()),
+            )
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/download/report.csv"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(REPORT))
+            .mount(&mock_server)
+            .await;
+
+        let graph_client_configuration = GraphClientConfiguration::new()
+            .access_token("token")
+            .https_only(false);
+        let mut client = Graph::from(graph_client_configuration);
+        client.use_test_endpoint(&Url::parse(mock_server.uri().as_str()).unwrap());
+
+        let response = client
+            .reports()
+            .get_office_365_active_user_detail_by_period(ReportPeriod::D30)
+            .send()
+            .await
+            .unwrap();
+
+        let rows: Vec<ActiveUserDetail> = ReportCsv::from_response(response)
+            .await
+            .unwrap()
+            .rows()
+            .unwrap();
+        assert_eq!(2, rows.len());
+    }
+}
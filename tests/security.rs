@@ -0,0 +1,236 @@
+#![cfg(feature = "test-util")]
+
+mod common;
+
+use common::client;
+use graph_rs_sdk::http::{GraphResponse, Method};
+use graph_rs_sdk::security::{AlertFilter, IncidentFilter};
+use graph_rs_sdk::test_util::{MockResponse, MockTransport, RequestMatcher};
+use graph_rs_sdk::types::{
+    Alert, AlertClassification, AlertDetermination, AlertSeverity, AlertStatus, AlertUpdate,
+    CaseOperationStatus, Collection, Incident, IncidentStatus, IncidentUpdate,
+};
+use http::header::{LOCATION, RETRY_AFTER};
+use http::HeaderValue;
+use std::time::Duration;
+
+const CASE_PATH: &str = "/v1.0/security/cases/ediscoveryCases/case-id";
+
+#[tokio::test]
+async fn list_alerts_and_incidents_filtered() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path("/v1.0/security/alerts_v2")
+            .query_param("$filter", "severity eq 'high' and status eq 'new'"),
+        MockResponse::json(
+            200,
+            &serde_json::json!({
+                "value": [{
+                    "id": "da637551227677560813_-961444813",
+                    "incidentId": "2972395",
+                    "severity": "high",
+                    "status": "new",
+                    "determination": "malware",
+                    "serviceSource": "microsoftDefenderForEndpoint"
+                }]
+            }),
+        ),
+    );
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path("/v1.0/security/incidents")
+            .query_param("$expand", "alerts")
+            .query_param("$filter", "status eq 'active'"),
+        MockResponse::json(
+            200,
+            &serde_json::json!({
+                "value": [{
+                    "id": "2972395",
+                    "status": "active",
+                    "severity": "medium",
+                    "customTags": ["demo"],
+                    "alerts": [{ "id": "da637551227677560813_-961444813", "status": "new" }]
+                }]
+            }),
+        ),
+    );
+
+    let client = client(&transport);
+    let response: GraphResponse<Collection<Alert>> = client
+        .security()
+        .list_alerts_filtered(
+            &AlertFilter::new()
+                .severity(AlertSeverity::High)
+                .status(AlertStatus::New),
+        )
+        .send_json()
+        .await
+        .unwrap();
+    let alerts: Vec<Alert> = response.into_body().into_iter().collect();
+    assert_eq!(Some(AlertSeverity::High), alerts[0].severity);
+    assert_eq!(Some(AlertDetermination::Malware), alerts[0].determination);
+
+    let response: GraphResponse<Collection<Incident>> = client
+        .security()
+        .list_incidents_filtered(&IncidentFilter::new().status(IncidentStatus::Active))
+        .send_json()
+        .await
+        .unwrap();
+    let incidents: Vec<Incident> = response.into_body().into_iter().collect();
+    assert_eq!(Some(AlertSeverity::Medium), incidents[0].severity);
+    assert_eq!(Some(AlertStatus::New), incidents[0].alerts[0].status);
+}
+
+#[tokio::test]
+async fn update_alert_and_incident() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::PATCH)
+            .path("/v1.0/security/alerts_v2/alert-id")
+            .body_json(serde_json::json!({
+                "status": "resolved",
+                "classification": "falsePositive",
+                "determination": "notMalicious"
+            })),
+        MockResponse::json(
+            200,
+            &serde_json::json!({ "id": "alert-id", "status": "resolved" }),
+        ),
+    );
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::PATCH)
+            .path("/v1.0/security/incidents/2972395")
+            .body_json(serde_json::json!({
+                "status": "resolved",
+                "assignedTo": "secops@contoso.com",
+                "customTags": ["reviewed"]
+            })),
+        MockResponse::json(
+            200,
+            &serde_json::json!({ "id": "2972395", "status": "resolved" }),
+        ),
+    );
+
+    let client = client(&transport);
+    let response: GraphResponse<Alert> = client
+        .security()
+        .update_alert(
+            "alert-id",
+            &AlertUpdate::new()
+                .status(AlertStatus::Resolved)
+                .classification(AlertClassification::FalsePositive)
+                .determination(AlertDetermination::NotMalicious),
+        )
+        .send_json()
+        .await
+        .unwrap();
+    assert_eq!(Some(AlertStatus::Resolved), response.into_body().status);
+
+    let response: GraphResponse<Incident> = client
+        .security()
+        .update_incident(
+            "2972395",
+            &IncidentUpdate::new()
+                .status(IncidentStatus::Resolved)
+                .assigned_to("secops@contoso.com")
+                .custom_tags(["reviewed"]),
+        )
+        .send_json()
+        .await
+        .unwrap();
+    assert_eq!(Some(IncidentStatus::Resolved), response.into_body().status);
+}
+
+#[tokio::test]
+async fn estimate_search_statistics_and_wait() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new().method(Method::POST).path(format!(
+            "{CASE_PATH}/searches/search-id/microsoft.graph.security.estimateStatistics"
+        )),
+        MockResponse::new(202).header(
+            LOCATION,
+            HeaderValue::from_static(
+                "https://graph.microsoft.com/v1.0/security/cases/ediscoveryCases/case-id/operations/operation-id",
+            ),
+        ),
+    );
+    transport.mock_times(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path(format!("{CASE_PATH}/operations/operation-id")),
+        MockResponse::json(
+            200,
+            &serde_json::json!({ "id": "operation-id", "status": "running", "percentProgress": 50 }),
+        )
+        .header(RETRY_AFTER, HeaderValue::from_static("0")),
+        1,
+    );
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path(format!("{CASE_PATH}/operations/operation-id")),
+        MockResponse::json(
+            200,
+            &serde_json::json!({
+                "id": "operation-id",
+                "action": "estimateStatistics",
+                "status": "succeeded",
+                "percentProgress": 100,
+                "indexedItemCount": 1024
+            }),
+        ),
+    );
+
+    let client = client(&transport);
+    let operation = client
+        .security()
+        .estimate_search_statistics_and_wait(
+            "case-id",
+            "search-id",
+            Duration::from_secs(5),
+            Duration::from_secs(60),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(Some(CaseOperationStatus::Succeeded), operation.status);
+    assert_eq!(
+        Some(&serde_json::json!(1024)),
+        operation.additional_fields.get("indexedItemCount")
+    );
+    assert_eq!(3, transport.received_requests().len());
+}
+
+#[tokio::test]
+async fn case_operation_on_another_host_is_not_sent_the_token() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new().method(Method::POST).path(format!(
+            "{CASE_PATH}/searches/search-id/microsoft.graph.security.estimateStatistics"
+        )),
+        MockResponse::new(202).header(
+            LOCATION,
+            HeaderValue::from_static("https://example.com/operations/operation-id"),
+        ),
+    );
+
+    let client = client(&transport);
+    let result = client
+        .security()
+        .estimate_search_statistics_and_wait(
+            "case-id",
+            "search-id",
+            Duration::from_secs(5),
+            Duration::from_secs(60),
+        )
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(1, transport.received_requests().len());
+}
@@ -0,0 +1,121 @@
+#![cfg(feature = "test-util")]
+
+mod common;
+
+use common::client;
+use graph_rs_sdk::admin::{ServiceHealthIssueFilter, ServiceUpdateMessageFilter};
+use graph_rs_sdk::http::{GraphResponse, Method};
+use graph_rs_sdk::test_util::{MockResponse, MockTransport, RequestMatcher};
+use graph_rs_sdk::types::{
+    Collection, ServiceHealth, ServiceHealthClassificationType, ServiceHealthIssue,
+    ServiceHealthStatus, ServiceUpdateMessage, ServiceUpdateSeverity,
+};
+
+#[tokio::test]
+async fn list_health_overviews_with_issues() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path("/v1.0/admin/serviceAnnouncement/healthOverviews")
+            .query_param("$expand", "issues"),
+        MockResponse::json(
+            200,
+            &serde_json::json!({
+                "value": [{
+                    "id": "Exchange",
+                    "service": "Exchange Online",
+                    "status": "serviceDegradation",
+                    "issues": [{
+                        "id": "EX123456",
+                        "classification": "incident",
+                        "status": "investigating",
+                        "isResolved": false
+                    }]
+                }]
+            }),
+        ),
+    );
+
+    let client = client(&transport);
+    let response: GraphResponse<Collection<ServiceHealth>> = client
+        .admin()
+        .list_health_overviews_with_issues()
+        .send_json()
+        .await
+        .unwrap();
+
+    let health: Vec<ServiceHealth> = response.into_body().into_iter().collect();
+    assert_eq!(
+        Some(ServiceHealthStatus::ServiceDegradation),
+        health[0].status
+    );
+    assert!(!health[0].status.unwrap().is_healthy());
+    assert!(health[0].issues[0].is_incident());
+}
+
+#[tokio::test]
+async fn list_issues_and_messages_filtered() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path("/v1.0/admin/serviceAnnouncement/issues")
+            .query_param(
+                "$filter",
+                "classification eq 'incident' and isResolved eq false",
+            ),
+        MockResponse::json(
+            200,
+            &serde_json::json!({
+                "value": [{ "id": "EX123456", "classification": "incident", "status": "serviceInterruption" }]
+            }),
+        ),
+    );
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path("/v1.0/admin/serviceAnnouncement/messages")
+            .query_param("$filter", "severity eq 'high'"),
+        MockResponse::json(
+            200,
+            &serde_json::json!({
+                "value": [{
+                    "id": "MC123456",
+                    "severity": "high",
+                    "category": "planForChange",
+                    "services": ["Microsoft Teams"]
+                }]
+            }),
+        ),
+    );
+
+    let client = client(&transport);
+    let response: GraphResponse<Collection<ServiceHealthIssue>> = client
+        .admin()
+        .list_issues_filtered(
+            &ServiceHealthIssueFilter::new()
+                .classification(ServiceHealthClassificationType::Incident)
+                .unresolved(),
+        )
+        .send_json()
+        .await
+        .unwrap();
+    let issues: Vec<ServiceHealthIssue> = response.into_body().into_iter().collect();
+    assert_eq!(
+        Some(ServiceHealthStatus::ServiceInterruption),
+        issues[0].status
+    );
+
+    let response: GraphResponse<Collection<ServiceUpdateMessage>> = client
+        .admin()
+        .list_messages_filtered(
+            &ServiceUpdateMessageFilter::new().severity(ServiceUpdateSeverity::High),
+        )
+        .send_json()
+        .await
+        .unwrap();
+    let messages: Vec<ServiceUpdateMessage> = response.into_body().into_iter().collect();
+    assert_eq!(Some(ServiceUpdateSeverity::High), messages[0].severity);
+    assert_eq!(vec!["Microsoft Teams".to_string()], messages[0].services);
+}
@@ -0,0 +1,87 @@
+#![cfg(feature = "test-util")]
+
+mod common;
+
+use common::client;
+use graph_rs_sdk::audit_logs::SignInFilter;
+use graph_rs_sdk::http::{GraphResponse, Method};
+use graph_rs_sdk::identity_protection::RiskyUserFilter;
+use graph_rs_sdk::test_util::{MockResponse, MockTransport, RequestMatcher};
+use graph_rs_sdk::types::{Collection, RiskLevel, RiskyUser, SignIn};
+use http::header::HeaderName;
+use http::HeaderValue;
+
+const APP_ID: &str = "de8bc8b5-d9f9-48b1-a8ad-b748da725064";
+
+#[tokio::test]
+async fn list_failed_sign_ins_sets_consistency_level() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path("/v1.0/auditLogs/signIns")
+            .query_param(
+                "$filter",
+                format!("appId eq '{APP_ID}' and status/errorCode ne 0"),
+            )
+            .header(
+                HeaderName::from_static("consistencylevel"),
+                HeaderValue::from_static("eventual"),
+            ),
+        MockResponse::json(
+            200,
+            &serde_json::json!({
+                "value": [{
+                    "id": "66ea54eb-6301-4ee5-be62-ff5a759b0100",
+                    "appId": APP_ID,
+                    "status": { "errorCode": 50126, "failureReason": "Invalid username or password." }
+                }]
+            }),
+        ),
+    );
+
+    let client = client(&transport);
+    let filter = SignInFilter::new().app_id(APP_ID).failed();
+    let response: GraphResponse<Collection<SignIn>> = client
+        .audit_logs()
+        .list_sign_ins_filtered(&filter)
+        .send_json()
+        .await
+        .unwrap();
+
+    let sign_ins: Vec<SignIn> = response.into_body().into_iter().collect();
+    assert_eq!(1, sign_ins.len());
+    assert!(!sign_ins[0].is_success());
+}
+
+#[tokio::test]
+async fn list_risky_users_filtered() {
+    let transport = MockTransport::new();
+    transport.mock(
+        RequestMatcher::new()
+            .method(Method::GET)
+            .path("/v1.0/identityProtection/riskyUsers")
+            .query_param("$filter", "riskLevel eq 'high'"),
+        MockResponse::json(
+            200,
+            &serde_json::json!({
+                "value": [{
+                    "id": "c2b6c2b9-dddc-acd0-2b39-d519d803dbc3",
+                    "riskLevel": "high",
+                    "riskState": "atRisk"
+                }]
+            }),
+        ),
+    );
+
+    let client = client(&transport);
+    let response: GraphResponse<Collection<RiskyUser>> = client
+        .identity_protection()
+        .list_risky_users_filtered(&RiskyUserFilter::new().risk_level(RiskLevel::High))
+        .send_json()
+        .await
+        .unwrap();
+
+    let risky_users: Vec<RiskyUser> = response.into_body().into_iter().collect();
+    assert_eq!(Some(RiskLevel::High), risky_users[0].risk_level);
+}
@@ -267,3 +267,55 @@ fn sites_items() {
             .path()
     );
 }
+
+#[test]
+pub fn site_by_hostname_path() {
+    let client = Graph::new("");
+
+    assert_eq!(
+        "/v1.0/sites/contoso.sharepoint.com:/sites/Engineering:",
+        client
+            .sites()
+            .by_hostname_path("https://contoso.sharepoint.com/", "/sites/Engineering/")
+            .get_site()
+            .url()
+            .path()
+    );
+
+    assert_eq!(
+        "/v1.0/sites/contoso.sharepoint.com:/sites/Engineering:/drive/root:/Specs/design.docx:",
+        client
+            .sites()
+            .by_hostname_path("contoso.sharepoint.com", "sites/Engineering")
+            .drive()
+            .by_path("Specs/design.docx")
+            .get_items()
+            .url()
+            .path()
+    );
+
+    assert_eq!(
+        format!(
+            "/v1.0/sites/contoso.sharepoint.com:/sites/Engineering:/lists/{}/items",
+            ID_VEC[0]
+        ),
+        client
+            .sites()
+            .by_hostname_path("contoso.sharepoint.com", "/sites/Engineering")
+            .list(ID_VEC[0].as_str())
+            .items()
+            .list_items()
+            .url()
+            .path()
+    );
+
+    assert_eq!(
+        "/v1.0/sites/contoso.sharepoint.com",
+        client
+            .sites()
+            .by_hostname("contoso.sharepoint.com")
+            .get_site()
+            .url()
+            .path()
+    );
+}
@@ -0,0 +1,356 @@
+use graph_rs_sdk::subscriptions::{
+    generate_client_state, ClientStateStore, InMemoryClientStateStore,
+};
+use graph_rs_sdk::types::Subscription;
+
+#[test]
+fn subscription_body() {
+    let subscription = Subscription::new(
+        "me/mailFolders('Inbox')/messages",
+        "created,updated",
+        "https://webhook.contoso.com/api/notifications",
+    )
+    .with_client_state("secret")
+    .with_expiration_date_time("2024-01-15T18:23:45.9356913Z");
+
+    assert_eq!(
+        serde_json::json!({
+            "resource": "me/mailFolders('Inbox')/messages",
+            "changeType": "created,updated",
+            "notificationUrl": "https://webhook.contoso.com/api/notifications",
+            "expirationDateTime": "2024-01-15T18:23:45.9356913Z",
+            "clientState": "secret"
+        }),
+        serde_json::to_value(&subscription).unwrap()
+    );
+}
+
+#[test]
+fn client_state_store() {
+    let client_state = generate_client_state();
+    assert_eq!(32, client_state.len());
+    assert_ne!(client_state, generate_client_state());
+
+    let store = InMemoryClientStateStore::new();
+    store.insert("1", client_state.as_str());
+    assert_eq!(Some(client_state), store.get("1"));
+    store.remove("1");
+    assert_eq!(None, store.get("1"));
+}
+
+#[cfg(feature = "test-util")]
+mod subscription_manager {
+    use graph_rs_sdk::error::GraphFailure;
+    use graph_rs_sdk::http::Url;
+    use graph_rs_sdk::subscriptions::SubscriptionManager;
+    use graph_rs_sdk::types::Subscription;
+    use graph_rs_sdk::{Graph, GraphClientConfiguration};
+    use std::time::Duration;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn subscription_json(expiration: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": "7f105c7d-2dc5-4530-97cd-4e7ae6534c07",
+            "resource": "me/mailFolders('Inbox')/messages",
+            "changeType": "created",
+            "notificationUrl": "https://webhook.contoso.com/api/notifications",
+            "expirationDateTime": expiration
+        })
+    }
+
+    #[tokio::test]
+    async fn create_renew_and_shutdown() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/subscriptions"))
+            .respond_with(
+                ResponseTemplate::new(201)
+                    .set_body_json(subscription_json("2024-01-15T18:23:45.9356913Z")),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("PATCH"))
+            .and(path("/subscriptions/7f105c7d-2dc5-4530-97cd-4e7ae6534c07"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(subscription_json("2024-01-16T18:23:45Z")),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/subscriptions/7f105c7d-2dc5-4530-97cd-4e7ae6534c07"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let graph_client_configuration = GraphClientConfiguration::new()
+            .access_token("token")
+            .https_only(false);
+        let mut client = Graph::from(graph_client_configuration);
+        client.use_test_endpoint(&Url::parse(mock_server.uri().as_str()).unwrap());
+
+        let manager = SubscriptionManager::new(client).with_lifetime(Duration::from_secs(60));
+        let subscription = manager
+            .create(Subscription::new(
+                "me/mailFolders('Inbox')/messages",
+                "created",
+                "https://webhook.contoso.com/api/notifications",
+            ))
+            .await
+            .unwrap();
+
+        let id = subscription.id.unwrap();
+        assert!(manager.client_state_store().get(id.as_str()).is_some());
+        let created_expiration = manager.expiration(id.as_str()).unwrap();
+
+        manager.renew(id.as_str()).await.unwrap();
+        assert!(manager.expiration(id.as_str()).unwrap() > created_expiration);
+
+        let client_state_store = manager.client_state_store();
+        manager.shutdown().await.unwrap();
+        assert!(client_state_store.get(id.as_str()).is_none());
+    }
+
+    #[tokio::test]
+    async fn renewal_and_delete_errors_are_returned() {
+        let mock_server = MockServer::start().await;
+        let error = serde_json::json!({
+            "error": { "code": "ExtensionError", "message": "Operation: Update; Exception: [Status Code: ServiceUnavailable]" }
+        });
+
+        Mock::given(method("POST"))
+            .and(path("/subscriptions"))
+            .respond_with(
+                ResponseTemplate::new(201)
+                    .set_body_json(subscription_json("2024-01-15T18:23:45.9356913Z")),
+            )
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("PATCH"))
+            .and(path("/subscriptions/7f105c7d-2dc5-4530-97cd-4e7ae6534c07"))
+            .respond_with(ResponseTemplate::new(500).set_body_json(error.clone()))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/subscriptions/7f105c7d-2dc5-4530-97cd-4e7ae6534c07"))
+            .respond_with(ResponseTemplate::new(412).set_body_json(error))
+            .mount(&mock_server)
+            .await;
+
+        let graph_client_configuration = GraphClientConfiguration::new()
+            .access_token("token")
+            .https_only(false);
+        let mut client = Graph::from(graph_client_configuration);
+        client.use_test_endpoint(&Url::parse(mock_server.uri().as_str()).unwrap());
+
+        let mut manager = SubscriptionManager::new(client);
+        let subscription = manager
+            .create(Subscription::new(
+                "me/mailFolders('Inbox')/messages",
+                "created",
+                "https://webhook.contoso.com/api/notifications",
+            ))
+            .await
+            .unwrap();
+        let id = subscription.id.unwrap();
+
+        let mut renewal_failures = manager.start_renewal();
+        let failure = tokio::time::timeout(Duration::from_secs(5), renewal_failures.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(id, failure.subscription_id);
+
+        assert!(matches!(
+            manager.delete(id.as_str()).await,
+            Err(GraphFailure::PreconditionFailed(_))
+        ));
+        assert_eq!(vec![id.clone()], manager.subscription_ids());
+        assert!(manager.client_state_store().get(id.as_str()).is_some());
+        assert!(manager.shutdown().await.is_err());
+    }
+}
+
+#[test]
+fn validate_change_notifications() {
+    use graph_rs_sdk::error::{GraphFailure, GraphRsError};
+    use graph_rs_sdk::subscriptions::ChangeNotificationValidator;
+    use graph_rs_sdk::types::ChangeNotificationCollection;
+    use std::sync::Arc;
+
+    let store = Arc::new(InMemoryClientStateStore::new());
+    store.insert("1", "secret");
+    let validator = ChangeNotificationValidator::new(store);
+
+    let notifications: ChangeNotificationCollection = serde_json::from_value(serde_json::json!({
+        "value": [
+            {
+                "subscriptionId": "1",
+                "clientState": "secret",
+                "changeType": "created",
+                "resource": "Users/1/Messages/2",
+                "tenantId": "84bd8158-6d4d-4958-8b9f-9d6445542f95"
+            }
+        ]
+    }))
+    .unwrap();
+    assert!(validator.validate_collection(&notifications).is_ok());
+
+    let mut notification = notifications.value[0].clone();
+    notification.client_state = Some("wrong".into());
+    assert!(matches!(
+        validator.validate(&notification),
+        Err(GraphFailure::GraphRsError(
+            GraphRsError::InvalidClientState { .. }
+        ))
+    ));
+
+    notification.client_state = None;
+    assert!(validator.validate(&notification).is_err());
+}
+
+#[test]
+fn validation_token() {
+    use graph_rs_sdk::http::Url;
+    use graph_rs_sdk::subscriptions::validation_token;
+
+    let url = Url::parse(
+        "https://webhook.contoso.com/api/notifications?validationToken=Validation%3A%20Testing",
+    )
+    .unwrap();
+    assert_eq!(
+        Some("Validation: Testing".to_string()),
+        validation_token(&url)
+    );
+
+    let url = Url::parse("https://webhook.contoso.com/api/notifications").unwrap();
+    assert_eq!(None, validation_token(&url));
+}
+
+#[test]
+fn event_hub_notification_url() {
+    use graph_rs_sdk::subscriptions::EventHubNotificationUrl;
+
+    assert_eq!(
+        "EventHub:https://contoso.servicebus.windows.net/eventhubname/graph?tenantId=contoso.onmicrosoft.com",
+        EventHubNotificationUrl::managed_identity("contoso", "graph", "contoso.onmicrosoft.com")
+    );
+    assert_eq!(
+        "EventHub:https://contoso-vault.vault.azure.net/secrets/graph-eventhub?tenantId=contoso.onmicrosoft.com",
+        EventHubNotificationUrl::key_vault(
+            "contoso-vault",
+            "graph-eventhub",
+            "contoso.onmicrosoft.com"
+        )
+    );
+}
+
+#[test]
+fn event_hub_notifications() {
+    use graph_rs_sdk::subscriptions::{ChangeNotificationValidator, EventHubNotificationAdaptor};
+    use std::sync::Arc;
+
+    let body = serde_json::json!({
+        "value": [
+            {
+                "subscriptionId": "7f105c7d-2dc5-4530-97cd-4e7ae6534c07",
+                "clientState": "secret",
+                "changeType": "created",
+                "resource": "Users/{user-id}/Messages/{message-id}",
+                "tenantId": "84bd8158-6d4d-4958-8b9f-9d6445542f95"
+            }
+        ]
+    })
+    .to_string();
+
+    let notifications = EventHubNotificationAdaptor::new()
+        .notifications(body.as_bytes())
+        .unwrap();
+    assert_eq!(1, notifications.len());
+    assert_eq!(Some("created".to_string()), notifications[0].change_type);
+
+    let store = Arc::new(InMemoryClientStateStore::new());
+    let adaptor = EventHubNotificationAdaptor::new()
+        .with_validator(ChangeNotificationValidator::new(store.clone()));
+    assert!(adaptor.notifications(body.as_bytes()).is_err());
+
+    store.insert("7f105c7d-2dc5-4530-97cd-4e7ae6534c07", "secret");
+    assert!(adaptor.notifications(body.as_bytes()).is_ok());
+}
+
+#[cfg(feature = "openssl")]
+mod resource_data_decryption {
+    use base64::engine::general_purpose::STANDARD;
+    use base64::Engine;
+    use graph_rs_sdk::error::{GraphFailure, GraphRsError};
+    use graph_rs_sdk::subscriptions::ResourceDataDecryptor;
+    use graph_rs_sdk::types::ChangeNotificationEncryptedContent;
+    use openssl::hash::MessageDigest;
+    use openssl::pkey::PKey;
+    use openssl::rsa::{Padding, Rsa};
+    use openssl::sign::Signer;
+    use openssl::symm::Cipher;
+
+    fn encrypt(
+        rsa: &Rsa<openssl::pkey::Private>,
+        data: &[u8],
+    ) -> ChangeNotificationEncryptedContent {
+        let mut key = [0u8; 32];
+        openssl::rand::rand_bytes(&mut key).unwrap();
+
+        let encrypted_data =
+            openssl::symm::encrypt(Cipher::aes_256_cbc(), &key, Some(&key[..16]), data).unwrap();
+
+        let hmac_key = PKey::hmac(&key).unwrap();
+        let mut signer = Signer::new(MessageDigest::sha256(), &hmac_key).unwrap();
+        signer.update(&encrypted_data).unwrap();
+        let signature = signer.sign_to_vec().unwrap();
+
+        let mut encrypted_key = vec![0; rsa.size() as usize];
+        let len = rsa
+            .public_encrypt(&key, &mut encrypted_key, Padding::PKCS1_OAEP)
+            .unwrap();
+        encrypted_key.truncate(len);
+
+        ChangeNotificationEncryptedContent {
+            data: STANDARD.encode(encrypted_data),
+            data_key: STANDARD.encode(encrypted_key),
+            data_signature: STANDARD.encode(signature),
+            encryption_certificate_id: None,
+            encryption_certificate_thumbprint: None,
+        }
+    }
+
+    #[test]
+    fn decrypt_resource_data() {
+        let rsa = Rsa::generate(2048).unwrap();
+        let content = encrypt(&rsa, br#"{"subject":"Hello"}"#);
+
+        let decryptor = ResourceDataDecryptor::from_pem(rsa.private_key_to_pem().unwrap()).unwrap();
+        let value: serde_json::Value = decryptor.decrypt(&content).unwrap();
+        assert_eq!("Hello", value["subject"]);
+    }
+
+    #[test]
+    fn invalid_data_signature() {
+        let rsa = Rsa::generate(2048).unwrap();
+        let mut content = encrypt(&rsa, br#"{"subject":"Hello"}"#);
+        content.data_signature = STANDARD.encode([0u8; 32]);
+
+        let decryptor = ResourceDataDecryptor::from_pem(rsa.private_key_to_pem().unwrap()).unwrap();
+        assert!(matches!(
+            decryptor.decrypt::<serde_json::Value>(&content),
+            Err(GraphFailure::GraphRsError(
+                GraphRsError::InvalidDataSignature
+            ))
+        ));
+    }
+}
@@ -0,0 +1,215 @@
+use graph_rs_sdk::types::{
+    Collection, Contact, DriveItem, Message, NewUser, PasswordProfile, RecurrencePatternType,
+    TaskStatus, TodoTask, User,
+};
+
+#[test]
+fn drive_item_from_json() {
+    let json = std::fs::read_to_string("./test_files/item_test/complete_drive_item.json").unwrap();
+    let drive_item: DriveItem = serde_json::from_str(&json).unwrap();
+
+    assert!(drive_item.is_file());
+    assert_eq!(Some("description".to_string()), drive_item.description);
+    assert_eq!(
+        Some("NOFRcTaJ6M4rMxw2Ws7xou8Lyi0=".to_string()),
+        drive_item.file.unwrap().hashes.unwrap().quick_xor_hash
+    );
+    assert!(drive_item.additional_fields.contains_key("@odata.type"));
+    assert!(drive_item.additional_fields.contains_key("audio"));
+}
+
+#[test]
+fn drive_item_collection_from_json() {
+    let json = std::fs::read_to_string("./test_files/item_test/drive_root_children.json").unwrap();
+    let collection: Collection<DriveItem> = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(38, collection.len());
+    assert!(collection.next_link.is_none());
+    assert!(collection.additional_fields.contains_key("@odata.context"));
+
+    let first = collection.into_iter().next().unwrap();
+    assert_eq!(Some("Attachments".to_string()), first.name);
+    assert!(first.is_folder());
+}
+
+#[test]
+fn open_extension_fields_round_trip() {
+    let user: User = serde_json::from_value(serde_json::json!({
+        "id": "87d349ed-44d7-43e1-9a83-5f2406dee5bd",
+        "displayName": "Adele Vance",
+        "businessPhones": ["+1 425 555 0109"],
+        "extension_fe2174665583431c953114ff7268b7b3_Education_Grade": "9"
+    }))
+    .unwrap();
+
+    assert_eq!(Some("Adele Vance".to_string()), user.display_name);
+    assert_eq!(vec!["+1 425 555 0109".to_string()], user.business_phones);
+
+    let value = serde_json::to_value(&user).unwrap();
+    assert_eq!(
+        "9",
+        value["extension_fe2174665583431c953114ff7268b7b3_Education_Grade"]
+    );
+    assert!(value.get("mail").is_none());
+}
+
+#[test]
+fn message_recipients_from_json() {
+    let message: Message = serde_json::from_value(serde_json::json!({
+        "subject": "Meet for lunch?",
+        "body": {
+            "contentType": "html",
+            "content": "The new cafeteria is open."
+        },
+        "toRecipients": [
+            { "emailAddress": { "address": "fannyd@contoso.onmicrosoft.com" } }
+        ]
+    }))
+    .unwrap();
+
+    assert_eq!(1, message.to_recipients.len());
+    assert_eq!(
+        Some("fannyd@contoso.onmicrosoft.com".to_string()),
+        message.to_recipients[0]
+            .email_address
+            .as_ref()
+            .unwrap()
+            .address
+    );
+}
+
+#[test]
+fn todo_task_delta_from_json() {
+    let collection: Collection<TodoTask> = serde_json::from_value(serde_json::json!({
+        "@odata.deltaLink": "https://graph.microsoft.com/v1.0/me/todo/lists/list-id/tasks/delta?$deltatoken=token",
+        "value": [
+            {
+                "id": "AAMkADA1MTU5ZjEyLWYwNGMtNDY0ZS1hMWNiLTNlMWRkMzc3MDJmOABGAAAAAAA",
+                "title": "Submit expense report",
+                "status": "inProgress",
+                "importance": "high",
+                "isReminderOn": true,
+                "reminderDateTime": {
+                    "dateTime": "2024-01-31T09:00:00.0000000",
+                    "timeZone": "UTC"
+                },
+                "recurrence": {
+                    "pattern": {
+                        "type": "absoluteMonthly",
+                        "interval": 1,
+                        "dayOfMonth": 31
+                    },
+                    "range": {
+                        "type": "noEnd",
+                        "startDate": "2024-01-31"
+                    }
+                }
+            },
+            {
+                "id": "AAMkADA1MTU5ZjEyLWYwNGMtNDY0ZS1hMWNiLTNlMWRkMzc3MDJmOABGAAAAAAB",
+                "@removed": { "reason": "deleted" }
+            }
+        ]
+    }))
+    .unwrap();
+
+    assert!(collection.delta_link.is_some());
+    let tasks: Vec<TodoTask> = collection.into_iter().collect();
+    assert_eq!(Some(TaskStatus::InProgress), tasks[0].status);
+    assert_eq!(
+        RecurrencePatternType::AbsoluteMonthly,
+        tasks[0].recurrence.as_ref().unwrap().pattern.pattern_type
+    );
+    assert!(!tasks[0].is_removed());
+    assert!(tasks[1].is_removed());
+}
+
+#[test]
+fn contact_delta_from_json() {
+    let collection: Collection<Contact> = serde_json::from_value(serde_json::json!({
+        "@odata.deltaLink": "https://graph.microsoft.com/v1.0/me/contactFolders/folder-id/contacts/delta?$deltatoken=token",
+        "value": [
+            {
+                "id": "AAMkAGI2THk0AAA=",
+                "parentFolderId": "folder-id",
+                "displayName": "Pavel Bansky",
+                "givenName": "Pavel",
+                "surname": "Bansky",
+                "emailAddresses": [
+                    { "name": "Pavel Bansky", "address": "pavelb@fabrikam.onmicrosoft.com" }
+                ],
+                "businessPhones": ["+1 732 555 0102"],
+                "businessAddress": {
+                    "street": "123 Main St",
+                    "city": "Redmond",
+                    "state": "WA",
+                    "countryOrRegion": "United States",
+                    "postalCode": "98052"
+                },
+                "homeAddress": {},
+                "otherAddress": {}
+            },
+            {
+                "id": "AAMkAGI2THk0AAB=",
+                "@removed": { "reason": "deleted" }
+            }
+        ]
+    }))
+    .unwrap();
+
+    assert!(collection.delta_link.is_some());
+    let contacts: Vec<Contact> = collection.into_iter().collect();
+    assert_eq!(
+        Some("pavelb@fabrikam.onmicrosoft.com"),
+        contacts[0].primary_email()
+    );
+    assert!(contacts[0].home_address.as_ref().unwrap().is_empty());
+    let addresses: Vec<_> = contacts[0].addresses().collect();
+    assert_eq!(1, addresses.len());
+    assert_eq!(Some("98052"), addresses[0].postal_code.as_deref());
+    assert!(!contacts[0].is_removed());
+    assert!(contacts[1].is_removed());
+}
+
+#[test]
+fn new_user_to_json() {
+    let user = NewUser::new(
+        "Adele Vance",
+        "AdeleV",
+        "AdeleV@contoso.com",
+        PasswordProfile::new("xWwvJ]6NMw+bWH-d"),
+    )
+    .unwrap()
+    .with_usage_location("US");
+
+    assert_eq!(
+        serde_json::json!({
+            "accountEnabled": true,
+            "displayName": "Adele Vance",
+            "mailNickname": "AdeleV",
+            "userPrincipalName": "AdeleV@contoso.com",
+            "passwordProfile": {
+                "password": "xWwvJ]6NMw+bWH-d",
+                "forceChangePasswordNextSignIn": true
+            },
+            "usageLocation": "US"
+        }),
+        serde_json::to_value(&user).unwrap()
+    );
+
+    for mail_nickname in [
+        "",
+        "Adele Vance",
+        "adele@contoso",
+        "adelé",
+        "a".repeat(65).as_str(),
+    ] {
+        assert!(NewUser::new(
+            "Adele Vance",
+            mail_nickname,
+            "AdeleV@contoso.com",
+            PasswordProfile::default()
+        )
+        .is_err());
+    }
+}